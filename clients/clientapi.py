@@ -25,6 +25,7 @@ from fastapi.middleware.gzip import GZipMiddleware
 from starlette.middleware.sessions import SessionMiddleware
 from starlette.requests import Request
 import secrets
+import hashlib
 from pydantic import BaseModel, Field, HttpUrl
 from typing import Dict
 from typing import List
@@ -35,11 +36,18 @@ import logging
 import argparse
 import sys
 from pyotp import TOTP, random_base32
+from webauthn import (generate_registration_options, verify_registration_response,
+                      generate_authentication_options, verify_authentication_response, options_to_json)
+from webauthn.helpers import base64url_to_bytes, bytes_to_base64url
+from webauthn.helpers.structs import (AuthenticatorSelectionCriteria, PublicKeyCredentialDescriptor,
+                                      ResidentKeyRequirement, UserVerificationRequirement)
 import base64
 import traceback
 import time
 import httpx
 import asyncio
+import ipaddress
+import socket
 import io
 import qrcode
 import qrcode.image.svg
@@ -71,6 +79,8 @@ secret_key_middle = secrets.token_hex(32)
 
 # Temporary storage for MFA secrets
 temp_mfa_secrets = {}
+# Outstanding passkey challenges, by the random ID handed to the client alongside them
+webauthn_challenges = {}
 
 app = FastAPI()
 security = HTTPBasic()
@@ -216,9 +226,119 @@ def get_api_key(request: Request, api_key: str = Depends(api_key_header),
     raise HTTPException(status_code=status.HTTP_401_UNAUTHORIZED, detail="Invalid API key")
 
 
-def get_api_key_from_header(api_key: str = Header(None, name="Api-Key")):
+# What an API key may do. "admin" keys can do anything their user can; the others are for
+# handing to dashboards and third-party players.
+API_KEY_SCOPES = ("read", "sync", "admin")
+# Everything a read-only key may call. Anything not listed here (or in PLAYBACK_SYNC_ROUTES for
+# sync keys) needs a full-access key, including GETs that hand out account secrets or kick off
+# refreshes.
+READ_ONLY_ROUTES = {
+    "/api/data/verify_key",
+    "/api/data/get_user",
+    "/api/data/config",
+    "/api/data/guest_status",
+    "/api/data/download_status",
+    "/api/data/get_pinepods_version",
+    "/api/data/self_service_status",
+    "/api/data/password_policy",
+    "/api/data/get_theme/{user_id}",
+    "/api/data/get_time_info",
+    "/api/data/get_stats",
+    "/api/data/get_user_episode_count",
+    "/api/data/return_pods/{user_id}",
+    "/api/data/check_podcast",
+    "/api/data/get_podcast_id",
+    "/api/data/get_podcast_id_from_ep_id",
+    "/api/data/get_podcast_id_from_ep_name",
+    "/api/data/get_podcast_details",
+    "/api/data/get_podcast_details_dynamic",
+    "/api/data/import_progress/{user_id}",
+    "/api/data/fetch_podcast_feed",
+    "/api/data/fetch_podcasting_2_data",
+    "/api/data/fetch_podcasting_2_pod_data",
+    "/api/data/fetch_transcript",
+    "/api/data/return_episodes/{user_id}",
+    "/api/data/podcast_episodes",
+    "/api/data/get_episode_id_ep_name",
+    "/api/data/check_episode_in_db/{user_id}",
+    "/api/data/episode_by_url/{url_key}",
+    "/api/data/stream/{episode_id}",
+    "/api/data/user_history/{user_id}",
+    "/api/data/saved_episode_list/{user_id}",
+    "/api/data/download_episode_list",
+    "/api/data/user/check_downloaded",
+    "/api/data/user/check_saved",
+    "/api/data/get_queued_episodes",
+    "/api/data/get_bookmarks",
+    "/api/data/get_playlists",
+    "/api/data/get_playlist_episodes",
+    "/api/data/check_episode_playback",
+    "/api/data/get_episode_id",
+    "/api/data/get_auto_download_status",
+    "/api/data/get_auto_queue_status",
+    "/api/data/get_auto_skip_times",
+    "/api/data/return_selected_episode",
+    "/api/data/get_episode_metadata",
+    "/api/data/get_all_episodes",
+    "/api/data/search_data",
+    "/ws/api/data/episode_stream/{user_id}",
+}
+# Writes that only record listening, for keys that sync playback from another player
+PLAYBACK_SYNC_ROUTES = {
+    "/api/data/increment_listen_time/{user_id}",
+    "/api/data/increment_time_saved/{user_id}",
+    "/api/data/increment_played/{user_id}",
+    "/api/data/record_podcast_history",
+    "/api/data/record_listen_duration",
+    "/api/data/mark_episode_completed",
+    "/api/data/mark_episode_uncompleted",
+    "/api/data/remove_episode_history",
+    "/api/data/queue_pod",
+    "/api/data/remove_queued_pod",
+    "/api/data/reorder_queue",
+    "/api/data/queue_bump",
+    "/api/data/save_episode",
+    "/api/data/remove_saved_episode",
+    "/api/data/add_bookmark",
+    "/api/data/delete_bookmark",
+}
+# Don't write LastUsed on every single request a busy key makes
+API_KEY_TOUCH_INTERVAL = 60
+api_key_last_touched = {}
+
+
+def api_key_scope_allows(scope, route_path):
+    if scope == "admin":
+        return True
+    if route_path in READ_ONLY_ROUTES:
+        return True
+    return scope == "sync" and route_path in PLAYBACK_SYNC_ROUTES
+
+
+def api_key_has_full_access(cnx, api_key):
+    # Keys without a scope row (the web key, keys from before scopes) keep full access
+    key_scope = database_functions.functions.get_api_key_scope(cnx, database_type, api_key)
+    return key_scope is None or key_scope["scope"] == "admin"
+
+
+def get_api_key_from_header(request: Request, api_key: str = Header(None, name="Api-Key"),
+                            cnx=Depends(get_database_connection)):
     if not api_key:
         raise HTTPException(status_code=status.HTTP_401_UNAUTHORIZED, detail="Not authenticated")
+
+    # Unknown keys are left to each endpoint's own verify_api_key check
+    key_scope = database_functions.functions.get_api_key_scope(cnx, database_type, api_key)
+    if key_scope is not None:
+        if key_scope["expired"]:
+            raise HTTPException(status_code=status.HTTP_401_UNAUTHORIZED, detail="This API key has expired")
+        route_path = getattr(request.scope.get("route"), "path", request.url.path)
+        if not api_key_scope_allows(key_scope["scope"], route_path):
+            raise HTTPException(status_code=status.HTTP_403_FORBIDDEN,
+                                detail=f"This API key's {key_scope['scope']} scope doesn't allow this request")
+        now = time.time()
+        if now - api_key_last_touched.get(key_scope["api_key_id"], 0) > API_KEY_TOUCH_INTERVAL:
+            api_key_last_touched[key_scope["api_key_id"]] = now
+            database_functions.functions.touch_api_key(cnx, database_type, key_scope["api_key_id"])
     return api_key
 
 
@@ -273,6 +393,9 @@ async def check_if_admin(api_key: str = Depends(get_api_key_from_header), cnx=De
     # If the user is not an admin, throw an exception
     if not is_admin:
         raise HTTPException(status_code=403, detail="User not authorized.")
+    # An admin's limited keys don't carry their admin rights
+    if not api_key_has_full_access(cnx, api_key):
+        raise HTTPException(status_code=403, detail="This API key's scope doesn't allow admin requests")
 
     # If all checks pass, allow the request (return True)
     return True
@@ -283,6 +406,8 @@ def check_if_admin_inner(api_key: str, cnx):
 
     if not user_id:
         return False
+    if not api_key_has_full_access(cnx, api_key):
+        return False
     return database_functions.functions.user_admin_check(cnx, database_type, user_id)
 
 
@@ -334,6 +459,211 @@ async def get_key(cnx=Depends(get_database_connection),
                             detail="Your credentials appear to be incorrect.")
 
 
+# Single sign-on through an OpenID Connect provider. The client runs the authorization-code flow
+# with PKCE and hands the code to us; we redeem it with the provider and answer with the API key
+# of the Pinepods user whose email matches.
+oidc_issuer_url = os.environ.get("OIDC_ISSUER_URL", "").rstrip("/")
+oidc_client_id = os.environ.get("OIDC_CLIENT_ID", "")
+oidc_client_secret = os.environ.get("OIDC_CLIENT_SECRET", "")
+oidc_provider_name = os.environ.get("OIDC_PROVIDER_NAME", "Single Sign-On")
+oidc_scopes = os.environ.get("OIDC_SCOPES", "openid email profile")
+# Accounts with MFA still have to pass it after the provider signs them in, unless the admin
+# says the provider already enforces its own
+oidc_trust_provider_mfa = os.environ.get("OIDC_TRUST_PROVIDER_MFA", "false").lower() == "true"
+# Some providers never send email_verified; only match on their emails if the admin opts in
+oidc_allow_unverified_email = os.environ.get("OIDC_ALLOW_UNVERIFIED_EMAIL", "false").lower() == "true"
+oidc_discovery = None
+# Sign-ins the provider has vouched for that are waiting on the user's second factor
+oidc_pending_mfa = {}
+OIDC_MFA_TIMEOUT = 300
+OIDC_MFA_ATTEMPTS = 5
+
+
+def oidc_enabled():
+    return bool(oidc_issuer_url and oidc_client_id)
+
+
+async def get_oidc_discovery():
+    global oidc_discovery
+    if oidc_discovery is None:
+        async with httpx.AsyncClient(follow_redirects=True) as client:
+            response = await client.get(f"{oidc_issuer_url}/.well-known/openid-configuration")
+            response.raise_for_status()
+            oidc_discovery = response.json()
+    return oidc_discovery
+
+
+@app.get('/api/auth/oidc/config')
+async def api_oidc_config():
+    if not oidc_enabled():
+        return {"enabled": False}
+    try:
+        discovery = await get_oidc_discovery()
+    except Exception as e:
+        logging.error(f"Unable to load OpenID Connect discovery document: {str(e)}")
+        return {"enabled": False}
+    return {
+        "enabled": True,
+        "provider_name": oidc_provider_name,
+        "authorization_endpoint": discovery["authorization_endpoint"],
+        "client_id": oidc_client_id,
+        "scopes": oidc_scopes,
+    }
+
+
+class OidcCallbackRequest(BaseModel):
+    code: str
+    code_verifier: str
+    redirect_uri: str
+
+
+@app.post('/api/auth/oidc/callback')
+async def api_oidc_callback(body: OidcCallbackRequest, cnx=Depends(get_database_connection)):
+    if not oidc_enabled():
+        raise HTTPException(status_code=404, detail="Single sign-on is not configured on this server")
+
+    try:
+        discovery = await get_oidc_discovery()
+        token_request = {
+            "grant_type": "authorization_code",
+            "code": body.code,
+            "redirect_uri": body.redirect_uri,
+            "client_id": oidc_client_id,
+            "code_verifier": body.code_verifier,
+        }
+        if oidc_client_secret:
+            token_request["client_secret"] = oidc_client_secret
+        async with httpx.AsyncClient(follow_redirects=True) as client:
+            token_response = await client.post(discovery["token_endpoint"], data=token_request)
+            if token_response.status_code != 200:
+                logging.error(f"OIDC token exchange failed: {token_response.text}")
+                raise HTTPException(status_code=403, detail="The identity provider rejected the sign-in")
+            access_token = token_response.json().get("access_token")
+
+            # Asking the provider directly saves validating the ID token's signature ourselves
+            userinfo_response = await client.get(discovery["userinfo_endpoint"],
+                                                 headers={"Authorization": f"Bearer {access_token}"})
+            userinfo_response.raise_for_status()
+            claims = userinfo_response.json()
+    except HTTPException:
+        raise
+    except Exception as e:
+        logging.error(f"OIDC sign-in failed: {str(e)}")
+        raise HTTPException(status_code=502, detail="Unable to reach the identity provider")
+
+    email = claims.get("email")
+    email_verified = claims.get("email_verified") is True or oidc_allow_unverified_email
+    if not email or not email_verified:
+        raise HTTPException(status_code=403, detail="The identity provider didn't share a verified email address")
+
+    username = database_functions.functions.get_username_from_email(cnx, database_type, email)
+    if username is None:
+        raise HTTPException(status_code=403, detail=f"No Pinepods user has the email address {email}")
+
+    user_id = database_functions.functions.get_user_id(cnx, database_type, username)
+    mfa_enabled = database_functions.functions.check_mfa_enabled(database_type, cnx, user_id)
+    passkey_enabled = bool(database_functions.functions.get_webauthn_credentials(cnx, database_type, user_id))
+    if (mfa_enabled or passkey_enabled) and not oidc_trust_provider_mfa:
+        # Same as a password login: no key until the second factor checks out
+        return {
+            "status": "mfa_required",
+            "username": username,
+            "mfa_token": store_oidc_pending_mfa(user_id, username),
+            "mfa_enabled": mfa_enabled,
+            "passkey_enabled": passkey_enabled,
+        }
+
+    retrieved_key = database_functions.functions.get_api_key(cnx, database_type, username)
+    return {"status": "success", "retrieved_key": retrieved_key, "username": username}
+
+
+def store_oidc_pending_mfa(user_id, username):
+    now = time.time()
+    for expired in [key for key, entry in oidc_pending_mfa.items() if entry["expires"] < now]:
+        del oidc_pending_mfa[expired]
+    mfa_token = secrets.token_urlsafe(32)
+    oidc_pending_mfa[mfa_token] = {
+        "user_id": user_id,
+        "username": username,
+        "attempts": 0,
+        "expires": now + OIDC_MFA_TIMEOUT,
+    }
+    return mfa_token
+
+
+def get_oidc_pending_mfa(mfa_token):
+    entry = oidc_pending_mfa.get(mfa_token)
+    if entry is None or entry["expires"] < time.time() or entry["attempts"] >= OIDC_MFA_ATTEMPTS:
+        oidc_pending_mfa.pop(mfa_token, None)
+        raise HTTPException(status_code=403, detail="Sign-in expired. Please try again.")
+    return entry
+
+
+def finish_oidc_pending_mfa(cnx, mfa_token):
+    entry = oidc_pending_mfa.pop(mfa_token)
+    retrieved_key = database_functions.functions.get_api_key(cnx, database_type, entry["username"])
+    return {"status": "success", "retrieved_key": retrieved_key, "username": entry["username"]}
+
+
+class OidcVerifyMfaRequest(BaseModel):
+    mfa_token: str
+    mfa_code: str
+
+
+@app.post('/api/auth/oidc/verify_mfa')
+async def api_oidc_verify_mfa(body: OidcVerifyMfaRequest, cnx=Depends(get_database_connection)):
+    entry = get_oidc_pending_mfa(body.mfa_token)
+    entry["attempts"] += 1
+    secret = database_functions.functions.get_mfa_secret(database_type, cnx, entry["user_id"])
+    verified = secret is not None and TOTP(secret).verify(body.mfa_code)
+    if not verified:
+        verified = database_functions.functions.use_mfa_recovery_code(
+            database_type, cnx, entry["user_id"], hash_recovery_code(body.mfa_code))
+        if verified:
+            logging.info(f"User {entry['user_id']} finished single sign-on with an MFA recovery code")
+    if not verified:
+        raise HTTPException(status_code=403, detail="That code isn't right")
+    return finish_oidc_pending_mfa(cnx, body.mfa_token)
+
+
+class OidcMfaTokenRequest(BaseModel):
+    mfa_token: str
+
+
+@app.post('/api/auth/oidc/passkey_options')
+async def api_oidc_passkey_options(body: OidcMfaTokenRequest, request: Request,
+                                   cnx=Depends(get_database_connection)):
+    entry = get_oidc_pending_mfa(body.mfa_token)
+    rp_id, origin = webauthn_relying_party(request)
+    credentials = database_functions.functions.get_webauthn_credentials(cnx, database_type, entry["user_id"])
+    if not credentials:
+        raise HTTPException(status_code=404, detail="No passkeys are registered for this user")
+    options = generate_authentication_options(
+        rp_id=rp_id,
+        allow_credentials=[PublicKeyCredentialDescriptor(id=base64url_to_bytes(c["credential_id"]))
+                           for c in credentials],
+        user_verification=UserVerificationRequirement.PREFERRED,
+    )
+    challenge_id = store_webauthn_challenge(options.challenge, rp_id, origin, entry["user_id"])
+    return {"challenge_id": challenge_id, "options": json.loads(options_to_json(options))}
+
+
+class OidcVerifyPasskeyRequest(BaseModel):
+    mfa_token: str
+    challenge_id: str
+    credential: dict
+
+
+@app.post('/api/auth/oidc/verify_passkey')
+async def api_oidc_verify_passkey(body: OidcVerifyPasskeyRequest, cnx=Depends(get_database_connection)):
+    entry = get_oidc_pending_mfa(body.mfa_token)
+    entry["attempts"] += 1
+    challenge = take_webauthn_challenge(body.challenge_id, entry["user_id"])
+    if verify_webauthn_assertion(cnx, challenge, body.credential, require_user_verification=False) != entry["user_id"]:
+        raise HTTPException(status_code=403, detail="That passkey belongs to a different account")
+    return finish_oidc_pending_mfa(cnx, body.mfa_token)
+
+
 @app.post("/api/data/clean_expired_sessions/")
 async def api_clean_expired_sessions(cnx=Depends(get_database_connection),
                                      api_key: str = Depends(get_api_key_from_header)):
@@ -970,6 +1300,104 @@ async def fetch_podcasting_2_data(episode_id: int, user_id: int, cnx=Depends(get
 
     return {"chapters": chapters_data, "transcripts": transcripts, "people": people}
 
+# Transcripts are text; anything far bigger than this isn't one
+TRANSCRIPT_MAX_BYTES = 5 * 1024 * 1024
+TRANSCRIPT_FETCH_TIMEOUT = httpx.Timeout(15.0)
+TRANSCRIPT_MAX_REDIRECTS = 5
+
+
+def is_public_http_url(url: str) -> bool:
+    """Whether a URL is http(s) and every address its host resolves to is on the public internet."""
+    parsed = urlparse(url)
+    if parsed.scheme not in ("http", "https") or not parsed.hostname:
+        return False
+    try:
+        addresses = socket.getaddrinfo(parsed.hostname, parsed.port or (443 if parsed.scheme == "https" else 80),
+                                       proto=socket.IPPROTO_TCP)
+    except (socket.gaierror, UnicodeError):
+        return False
+    for address in addresses:
+        if not ipaddress.ip_address(address[4][0].split("%")[0]).is_global:
+            return False
+    return True
+
+
+async def episode_transcript_urls(cnx, user_id: int, episode_id: int) -> Optional[set]:
+    """The transcript URLs the feed lists for one of the user's episodes, or None if it isn't theirs."""
+    try:
+        episode_metadata = database_functions.functions.get_episode_metadata(database_type, cnx, episode_id, user_id)
+    except ValueError:
+        return None
+    async with httpx.AsyncClient(follow_redirects=True, timeout=TRANSCRIPT_FETCH_TIMEOUT) as client:
+        response = await client.get(episode_metadata['feedurl'])
+        response.raise_for_status()
+    transcripts = parse_transcripts(response.text, episode_metadata['episodeurl'])
+    return {transcript["url"] for transcript in transcripts if transcript.get("url")}
+
+
+async def fetch_limited_text(url: str):
+    """GET a public URL, following redirects by hand so every hop is checked, and cap the body size."""
+    async with httpx.AsyncClient(follow_redirects=False, timeout=TRANSCRIPT_FETCH_TIMEOUT) as client:
+        for _ in range(TRANSCRIPT_MAX_REDIRECTS + 1):
+            if not await run_in_threadpool(is_public_http_url, url):
+                raise HTTPException(status_code=400, detail="Transcript URL must point to a public http(s) host")
+            async with client.stream("GET", url) as response:
+                if response.is_redirect:
+                    url = str(response.next_request.url) if response.next_request else url
+                    continue
+                if response.status_code >= 400:
+                    raise HTTPException(status_code=502,
+                                        detail=f"Transcript host answered with status {response.status_code}")
+                body = bytearray()
+                async for chunk in response.aiter_bytes():
+                    body.extend(chunk)
+                    if len(body) > TRANSCRIPT_MAX_BYTES:
+                        raise HTTPException(status_code=413, detail="Transcript is too large")
+                content_type = response.headers.get('content-type', '')
+                return body.decode(response.encoding or "utf-8", errors="replace"), content_type
+    raise HTTPException(status_code=502, detail="Transcript host redirected too many times")
+
+
+@app.get("/api/data/fetch_transcript")
+async def fetch_transcript(url: str = Query(...), episode_id: int = Query(...),
+                           cnx=Depends(get_database_connection),
+                           api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403, detail="Invalid API key or insufficient permissions")
+
+    # Only proxy transcripts the episode's own feed lists, so this can't be pointed anywhere else
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+    try:
+        transcript_urls = await episode_transcript_urls(cnx, key_id, episode_id)
+    except httpx.HTTPError as e:
+        logging.error(f"Could not read the feed for episode {episode_id}: {e}")
+        raise HTTPException(status_code=502, detail="Could not read the podcast feed")
+    if transcript_urls is None:
+        raise HTTPException(status_code=404, detail="Episode not found")
+    if url not in transcript_urls:
+        raise HTTPException(status_code=404, detail="This episode has no transcript at that URL")
+
+    # Transcript hosts rarely send CORS headers, so the web client fetches them through here
+    try:
+        text, content_type = await fetch_limited_text(url)
+    except httpx.HTTPError as e:
+        logging.error(f"Could not fetch transcript {url}: {e}")
+        raise HTTPException(status_code=502, detail="Could not fetch the transcript")
+
+    # Keep the text so search can look inside transcripts the user has opened. The route is open to
+    # read-only keys, so only full-access keys get to write the search index.
+    if api_key_has_full_access(cnx, api_key):
+        try:
+            plain_text = database_functions.functions.transcript_plain_text(text, content_type)
+            database_functions.functions.store_episode_transcript(cnx, database_type, key_id, episode_id, plain_text)
+        except Exception as e:
+            logging.error(f"Could not store transcript for episode {episode_id}: {e}")
+
+    # Never pass the host's content type through, so nothing it serves renders as a page on this origin
+    media_type = "application/json" if "json" in content_type.lower() else "text/plain"
+    return Response(content=text, media_type=media_type, headers={"X-Content-Type-Options": "nosniff"})
+
 
 
 def parse_podroll(feed_content: str) -> List[Dict[str, Optional[str]]]:
@@ -1227,6 +1655,41 @@ async def api_self_service_status(cnx=Depends(get_database_connection)):
     status = database_functions.functions.self_service_status(cnx, database_type)
     return {"status": status}
 
+
+class PasswordPolicy(BaseModel):
+    min_length: int = 6
+    require_upper: bool = False
+    require_lower: bool = False
+    require_digit: bool = False
+    require_symbol: bool = False
+    check_breached: bool = False
+
+
+# Not authenticated, the signup form needs it before there's an account
+@app.get("/api/data/password_policy")
+async def api_get_password_policy(cnx=Depends(get_database_connection)):
+    policy = database_functions.functions.get_password_policy(cnx, database_type)
+    policy["breached_list_loaded"] = len(database_functions.auth_functions.load_breached_passwords()) > 0
+    return policy
+
+
+@app.put("/api/data/password_policy")
+async def api_set_password_policy(policy: PasswordPolicy, is_admin: bool = Depends(check_if_admin),
+                                  cnx=Depends(get_database_connection)):
+    if policy.min_length < 1 or policy.min_length > 128:
+        raise HTTPException(status_code=400, detail="Minimum length must be between 1 and 128")
+    database_functions.functions.set_password_policy(cnx, database_type, policy.dict())
+    return {"detail": "Password policy updated."}
+
+
+def hash_password_with_policy(cnx, password: str) -> str:
+    """Check a new password against the server's policy and hash it for storage."""
+    policy = database_functions.functions.get_password_policy(cnx, database_type)
+    failures = database_functions.auth_functions.password_policy_failures(password, policy)
+    if failures:
+        raise HTTPException(status_code=400, detail="Password must " + ", ".join(failures))
+    return database_functions.auth_functions.hash_password(password)
+
 @app.put("/api/data/increment_listen_time/{user_id}")
 async def api_increment_listen_time(user_id: int, cnx=Depends(get_database_connection),
                                     api_key: str = Depends(get_api_key_from_header)):
@@ -1249,6 +1712,30 @@ async def api_increment_listen_time(user_id: int, cnx=Depends(get_database_conne
                             detail="You can only increment your own listen time.")
 
 
+class TimeSavedData(BaseModel):
+    seconds: int
+
+@app.put("/api/data/increment_time_saved/{user_id}")
+async def api_increment_time_saved(user_id: int, data: TimeSavedData, cnx=Depends(get_database_connection),
+                                   api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    if data.seconds <= 0:
+        raise HTTPException(status_code=400, detail="Time saved must be a positive number of seconds.")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == user_id:
+        database_functions.functions.increment_time_saved(cnx, database_type, user_id, data.seconds)
+        return {"detail": "Time saved incremented."}
+    else:
+        raise HTTPException(status_code=403,
+                            detail="You can only increment your own time saved.")
+
+
 @app.put("/api/data/increment_played/{user_id}")
 async def api_increment_played(user_id: int, cnx=Depends(get_database_connection),
                                api_key: str = Depends(get_api_key_from_header)):
@@ -1517,10 +2004,52 @@ async def api_get_auto_download_status(data: AutoDownloadStatusRequest, cnx=Depe
 
     return AutoDownloadStatusResponse(auto_download=status)
 
+class AutoQueueRequest(BaseModel):
+    podcast_id: int
+    auto_queue: bool
+    user_id: int
+
+@app.post("/api/data/enable_auto_queue")
+async def api_enable_auto_queue(data: AutoQueueRequest, cnx=Depends(get_database_connection),
+                                api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403, detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == data.user_id:
+        database_functions.functions.enable_auto_queue(cnx, database_type, data.podcast_id, data.user_id, data.auto_queue)
+        return {"detail": "Auto-queue status updated."}
+    else:
+        raise HTTPException(status_code=403, detail="You can only modify your own podcasts.")
+
+class AutoQueueStatusResponse(BaseModel):
+    auto_queue: bool
+
+@app.post("/api/data/get_auto_queue_status")
+async def api_get_auto_queue_status(data: AutoDownloadStatusRequest, cnx=Depends(get_database_connection),
+                                    api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403, detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+    if key_id != data.user_id:
+        raise HTTPException(status_code=403, detail="You can only get the status for your own podcast.")
+
+    status = database_functions.functions.call_get_auto_queue_status(cnx, database_type, data.podcast_id, data.user_id)
+    if status is None:
+        raise HTTPException(status_code=404, detail="Podcast not found")
+
+    return AutoQueueStatusResponse(auto_queue=status)
+
 class SkipTimesRequest(BaseModel):
     podcast_id: int
     start_skip: Optional[int] = 0
     end_skip: Optional[int] = 0
+    playback_speed: Optional[float] = None
+    volume_boost: Optional[int] = None
     user_id: int
 
 @app.post("/api/data/adjust_skip_times")
@@ -1536,7 +2065,26 @@ async def api_adjust_skip_times(data: SkipTimesRequest, cnx=Depends(get_database
     key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
 
     if key_id == data.user_id or is_web_key:
-        database_functions.functions.adjust_skip_times(cnx, database_type, data.podcast_id, data.start_skip, data.end_skip)
+        if data.playback_speed is not None and not 0.5 <= data.playback_speed <= 3.0:
+            raise HTTPException(status_code=400, detail="Playback speed must be between 0.5 and 3.0.")
+        if data.volume_boost is not None and not 100 <= data.volume_boost <= 300:
+            raise HTTPException(status_code=400, detail="Volume boost must be between 100 and 300 percent.")
+        # Only touch what the client sent, so older clients posting just the skip times keep the
+        # podcast's speed and boost
+        sent = data.dict(exclude_unset=True)
+        settings = {}
+        if "start_skip" in sent:
+            settings["StartSkip"] = data.start_skip or 0
+        if "end_skip" in sent:
+            settings["EndSkip"] = data.end_skip or 0
+        if "playback_speed" in sent:
+            settings["PlaybackSpeed"] = data.playback_speed
+        if "volume_boost" in sent:
+            settings["VolumeBoost"] = data.volume_boost or 100
+        updated = database_functions.functions.adjust_skip_times(cnx, database_type, data.podcast_id, data.user_id,
+                                                                 settings)
+        if not updated:
+            raise HTTPException(status_code=404, detail="Podcast not found")
         return {"detail": "Skip times updated."}
     else:
         raise HTTPException(status_code=403, detail="You can only modify your own podcasts.")
@@ -1548,6 +2096,8 @@ class AutoSkipTimesRequest(BaseModel):
 class AutoSkipTimesResponse(BaseModel):
     start_skip: int
     end_skip: int
+    playback_speed: Optional[float] = None
+    volume_boost: int = 100
 
 @app.post("/api/data/get_auto_skip_times")
 async def api_get_auto_skip_times(data: AutoSkipTimesRequest, cnx=Depends(get_database_connection),
@@ -1560,11 +2110,12 @@ async def api_get_auto_skip_times(data: AutoSkipTimesRequest, cnx=Depends(get_da
     if key_id != data.user_id:
         raise HTTPException(status_code=403, detail="You can only get the skip times for your own podcast.")
 
-    start_skip, end_skip = database_functions.functions.get_auto_skip_times(cnx, database_type, data.podcast_id, data.user_id)
+    start_skip, end_skip, playback_speed, volume_boost = database_functions.functions.get_auto_skip_times(cnx, database_type, data.podcast_id, data.user_id)
     if start_skip is None or end_skip is None:
         raise HTTPException(status_code=404, detail="Podcast not found")
 
-    return AutoSkipTimesResponse(start_skip=start_skip, end_skip=end_skip)
+    return AutoSkipTimesResponse(start_skip=start_skip, end_skip=end_skip, playback_speed=playback_speed,
+                                 volume_boost=volume_boost if volume_boost is not None else 100)
 
 
 class SaveEpisodeData(BaseModel):
@@ -1601,6 +2152,309 @@ async def api_save_episode(data: SaveEpisodeData, cnx=Depends(get_database_conne
                             detail="You can only save episodes of your own!")
 
 
+class AddBookmarkData(BaseModel):
+    episode_id: int
+    user_id: int
+    time: int
+    note: Optional[str] = None
+
+
+@app.post("/api/data/add_bookmark")
+async def api_add_bookmark(data: AddBookmarkData, cnx=Depends(get_database_connection),
+                           api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == data.user_id:
+        if data.time < 0:
+            raise HTTPException(status_code=400, detail="Bookmark time can't be negative.")
+        note = data.note.strip() if data.note else None
+        try:
+            bookmark_id = database_functions.functions.add_bookmark(cnx, database_type, data.user_id, data.episode_id,
+                                                                     data.time, note or None)
+        except ValueError as e:
+            raise HTTPException(status_code=404, detail=str(e))
+        return {"bookmark_id": bookmark_id}
+    else:
+        raise HTTPException(status_code=403,
+                            detail="You can only add bookmarks for yourself!")
+
+
+@app.get("/api/data/get_bookmarks")
+async def api_get_bookmarks(user_id: int, episode_id: int, cnx=Depends(get_database_connection),
+                            api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == user_id:
+        bookmarks = database_functions.functions.get_bookmarks(cnx, database_type, user_id, episode_id)
+        return {"bookmarks": bookmarks}
+    else:
+        raise HTTPException(status_code=403,
+                            detail="You can only view your own bookmarks!")
+
+
+class DeleteBookmarkData(BaseModel):
+    bookmark_id: int
+    user_id: int
+
+
+@app.post("/api/data/delete_bookmark")
+async def api_delete_bookmark(data: DeleteBookmarkData, cnx=Depends(get_database_connection),
+                              api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == data.user_id:
+        if database_functions.functions.delete_bookmark(cnx, database_type, data.user_id, data.bookmark_id):
+            return {"detail": "Bookmark deleted."}
+        raise HTTPException(status_code=404, detail="Bookmark not found.")
+    else:
+        raise HTTPException(status_code=403,
+                            detail="You can only delete your own bookmarks!")
+
+
+class PlaylistRules(BaseModel):
+    play_state: Optional[str] = "any"
+    min_minutes: Optional[int] = None
+    max_minutes: Optional[int] = None
+    released_within_days: Optional[int] = None
+    category: Optional[str] = None
+    podcast_id: Optional[int] = None
+    sort: Optional[str] = "newest"
+    limit: Optional[int] = None
+
+
+def playlist_rules_or_400(rules: Optional[PlaylistRules]):
+    if rules is None:
+        return None
+    try:
+        return database_functions.functions.normalize_playlist_rules(rules.dict())
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+
+def owned_playlist_or_404(cnx, user_id, playlist_id):
+    playlist = database_functions.functions.get_playlist(cnx, database_type, user_id, playlist_id)
+    if playlist is None:
+        raise HTTPException(status_code=404, detail="Playlist not found.")
+    return playlist
+
+
+@app.get("/api/data/get_playlists")
+async def api_get_playlists(user_id: int, cnx=Depends(get_database_connection),
+                            api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == user_id:
+        playlists = database_functions.functions.get_playlists(cnx, database_type, user_id)
+        return {"playlists": playlists}
+    else:
+        raise HTTPException(status_code=403,
+                            detail="You can only view your own playlists!")
+
+
+class CreatePlaylistData(BaseModel):
+    user_id: int
+    name: str
+    # Leave out for a manual playlist
+    rules: Optional[PlaylistRules] = None
+
+
+@app.post("/api/data/create_playlist")
+async def api_create_playlist(data: CreatePlaylistData, cnx=Depends(get_database_connection),
+                              api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == data.user_id:
+        name = data.name.strip()
+        if not name:
+            raise HTTPException(status_code=400, detail="Playlists need a name.")
+        rules = playlist_rules_or_400(data.rules)
+        playlist_id = database_functions.functions.create_playlist(cnx, database_type, data.user_id, name, rules)
+        return {"playlist_id": playlist_id}
+    else:
+        raise HTTPException(status_code=403,
+                            detail="You can only create playlists for yourself!")
+
+
+class UpdatePlaylistData(BaseModel):
+    playlist_id: int
+    user_id: int
+    name: str
+    rules: Optional[PlaylistRules] = None
+
+
+@app.post("/api/data/update_playlist")
+async def api_update_playlist(data: UpdatePlaylistData, cnx=Depends(get_database_connection),
+                              api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == data.user_id:
+        name = data.name.strip()
+        if not name:
+            raise HTTPException(status_code=400, detail="Playlists need a name.")
+        playlist = owned_playlist_or_404(cnx, data.user_id, data.playlist_id)
+        if playlist["is_smart"] and data.rules is None:
+            raise HTTPException(status_code=400, detail="Smart playlists need rules.")
+        rules = playlist_rules_or_400(data.rules)
+        database_functions.functions.update_playlist(cnx, database_type, data.user_id, data.playlist_id, name, rules)
+        return {"detail": "Playlist updated."}
+    else:
+        raise HTTPException(status_code=403,
+                            detail="You can only edit your own playlists!")
+
+
+class PlaylistData(BaseModel):
+    playlist_id: int
+    user_id: int
+
+
+@app.post("/api/data/delete_playlist")
+async def api_delete_playlist(data: PlaylistData, cnx=Depends(get_database_connection),
+                              api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == data.user_id:
+        if database_functions.functions.delete_playlist(cnx, database_type, data.user_id, data.playlist_id):
+            return {"detail": "Playlist deleted."}
+        raise HTTPException(status_code=404, detail="Playlist not found.")
+    else:
+        raise HTTPException(status_code=403,
+                            detail="You can only delete your own playlists!")
+
+
+@app.get("/api/data/get_playlist_episodes")
+async def api_get_playlist_episodes(user_id: int, playlist_id: int, cnx=Depends(get_database_connection),
+                                    api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == user_id:
+        playlist = owned_playlist_or_404(cnx, user_id, playlist_id)
+        episodes = database_functions.functions.get_playlist_episodes(cnx, database_type, user_id, playlist)
+        return {"playlist": playlist, "episodes": episodes}
+    else:
+        raise HTTPException(status_code=403,
+                            detail="You can only view your own playlists!")
+
+
+class PlaylistEpisodeData(BaseModel):
+    playlist_id: int
+    user_id: int
+    episode_id: int
+
+
+@app.post("/api/data/add_to_playlist")
+async def api_add_to_playlist(data: PlaylistEpisodeData, cnx=Depends(get_database_connection),
+                              api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == data.user_id:
+        playlist = owned_playlist_or_404(cnx, data.user_id, data.playlist_id)
+        if playlist["is_smart"]:
+            raise HTTPException(status_code=400, detail="Smart playlists pick their own episodes.")
+        try:
+            added = database_functions.functions.add_episode_to_playlist(cnx, database_type, data.user_id,
+                                                                         data.playlist_id, data.episode_id)
+        except ValueError as e:
+            raise HTTPException(status_code=404, detail=str(e))
+        if added:
+            return {"detail": "Episode added to playlist."}
+        raise HTTPException(status_code=409, detail="That episode is already in the playlist.")
+    else:
+        raise HTTPException(status_code=403,
+                            detail="You can only edit your own playlists!")
+
+
+@app.post("/api/data/remove_from_playlist")
+async def api_remove_from_playlist(data: PlaylistEpisodeData, cnx=Depends(get_database_connection),
+                                   api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == data.user_id:
+        owned_playlist_or_404(cnx, data.user_id, data.playlist_id)
+        if database_functions.functions.remove_episode_from_playlist(cnx, database_type, data.playlist_id,
+                                                                     data.episode_id):
+            return {"detail": "Episode removed from playlist."}
+        raise HTTPException(status_code=404, detail="That episode isn't in the playlist.")
+    else:
+        raise HTTPException(status_code=403,
+                            detail="You can only edit your own playlists!")
+
+
+class ReorderPlaylistData(BaseModel):
+    playlist_id: int
+    user_id: int
+    episode_ids: List[int]
+
+
+@app.post("/api/data/reorder_playlist")
+async def api_reorder_playlist(data: ReorderPlaylistData, cnx=Depends(get_database_connection),
+                               api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+
+    if key_id == data.user_id:
+        owned_playlist_or_404(cnx, data.user_id, data.playlist_id)
+        if database_functions.functions.reorder_playlist_episodes(cnx, database_type, data.playlist_id,
+                                                                  data.episode_ids):
+            return {"detail": "Playlist reordered."}
+        raise HTTPException(status_code=500, detail="Failed to reorder the playlist")
+    else:
+        raise HTTPException(status_code=403,
+                            detail="You can only edit your own playlists!")
+
 class RemoveSavedEpisodeData(BaseModel):
     episode_id: int
     user_id: int
@@ -1709,16 +2563,20 @@ async def api_refresh_pods(background_tasks: BackgroundTasks, is_admin: bool = D
     background_tasks.add_task(refresh_pods_task)
     return {"detail": "Refresh initiated."}
 
-def refresh_pods_task():
+async def refresh_pods_task():
     cnx = create_database_connection()
     try:
-        database_functions.functions.refresh_pods(cnx, database_type)
+        new_episodes = await asyncio.to_thread(database_functions.functions.refresh_pods, cnx, database_type, True)
     finally:
         if database_type == "postgresql":
             connection_pool.putconn(cnx)
         else:
             cnx.close()
 
+    for user_id, episodes in new_episodes.items():
+        for episode_data in episodes:
+            await notify_episode_subscribers(user_id, episode_data)
+
 
 # Store locks per user to prevent concurrent refresh jobs
 user_locks = {}
@@ -1726,25 +2584,99 @@ user_locks = {}
 # Store active WebSocket connections
 active_websockets = {}
 
+# Sockets subscribed to a user's new episodes, which stay open between refreshes
+episode_subscribers = {}
+
+
+async def authenticate_websocket(websocket: WebSocket, cnx, user_id: int, api_key: str, forbidden_detail: str):
+    # Browsers can't set headers on a WebSocket, so the key arrives as the first message rather
+    # than in the URL where proxies would log it. The api_key query parameter still works for
+    # older clients.
+    if api_key is None:
+        try:
+            message = await asyncio.wait_for(websocket.receive_json(), timeout=10.0)
+            api_key = message.get("api_key") if isinstance(message, dict) else None
+        except Exception:
+            api_key = None
+
+    is_valid_key = api_key is not None and database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        await websocket.send_json({"detail": "Invalid API key or insufficient permissions"})
+        await websocket.close()
+        return False
+
+    # Sockets skip get_api_key_from_header, so hold their key to the same scope rules here
+    key_scope = database_functions.functions.get_api_key_scope(cnx, database_type, api_key)
+    if key_scope is not None:
+        route_path = getattr(websocket.scope.get("route"), "path", websocket.url.path)
+        if key_scope["expired"]:
+            await websocket.send_json({"detail": "This API key has expired"})
+            await websocket.close()
+            return False
+        if not api_key_scope_allows(key_scope["scope"], route_path):
+            await websocket.send_json(
+                {"detail": f"This API key's {key_scope['scope']} scope doesn't allow this request"})
+            await websocket.close()
+            return False
+
+    is_web_key = api_key == base_webkey.web_key
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+    if key_id != user_id and not is_web_key:
+        await websocket.send_json({"detail": forbidden_detail})
+        await websocket.close()
+        return False
+    return True
+
+
+async def notify_episode_subscribers(user_id: int, episode_data: dict):
+    for ws in list(episode_subscribers.get(user_id, [])):
+        try:
+            await ws.send_json({"new_episode": episode_data})
+        except Exception as e:
+            print(f"Dropping episode subscriber for user {user_id}: {str(e)}")
+            if ws in episode_subscribers.get(user_id, []):
+                episode_subscribers[user_id].remove(ws)
+
+
+@app.websocket("/ws/api/data/episode_stream/{user_id}")
+async def episode_stream_endpoint(websocket: WebSocket, user_id: int, cnx=Depends(get_database_connection)):
+    await websocket.accept()
+    try:
+        authorized = await authenticate_websocket(websocket, cnx, user_id, None,
+                                                  "You can only subscribe to your own episodes")
+    finally:
+        if database_type == "postgresql":
+            connection_pool.putconn(cnx)
+        else:
+            cnx.close()
+    if not authorized:
+        return
+
+    episode_subscribers.setdefault(user_id, []).append(websocket)
+    try:
+        await websocket.send_json({"detail": "Subscribed to new episodes"})
+        # Nothing to do but wait; clients send the odd ping so proxies don't drop an idle socket
+        while True:
+            await websocket.receive_text()
+    except WebSocketDisconnect:
+        pass
+    except Exception as e:
+        print(f"Episode stream for user {user_id} closed: {str(e)}")
+    finally:
+        if websocket in episode_subscribers.get(user_id, []):
+            episode_subscribers[user_id].remove(websocket)
+            if not episode_subscribers[user_id]:
+                del episode_subscribers[user_id]
+
+
 @app.websocket("/ws/api/data/episodes/{user_id}")
 async def websocket_endpoint(websocket: WebSocket, user_id: int, cnx=Depends(get_database_connection), nextcloud_refresh: bool = Query(False), api_key: str = Query(None)):
     await websocket.accept()
 
     try:
         print(f"User {user_id} connected to WebSocket")
-        # Validate the API key
-        is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
-        if not is_valid_key:
-            await websocket.send_json({"detail": "Invalid API key or insufficient permissions"})
-            await websocket.close()
-            return
-        # Continue as normal for all other episode IDs
-        is_web_key = api_key == base_webkey.web_key
-        key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
-        print(f"User ID: {user_id}, Key ID: {key_id}, Web Key: {is_web_key}")
-        if key_id != user_id and not is_web_key:
-            await websocket.send_json({"detail": "You can only refresh your own podcasts"})
-            await websocket.close()
+        if not await authenticate_websocket(websocket, cnx, user_id, api_key,
+                                            "You can only refresh your own podcasts"):
             return
 
         if user_id in user_locks:
@@ -1830,6 +2762,7 @@ async def run_refresh_process(user_id, nextcloud_refresh, websocket):
             if user_id in active_websockets:
                 for ws in active_websockets[user_id]:
                     await ws.send_json({"new_episode": episode_data})
+            await notify_episode_subscribers(user_id, episode_data)
 
     except Exception as e:
         await websocket.send_json({"detail": f"Error during refresh: {e}"})
@@ -1979,6 +2912,20 @@ class RemovePodcastIDData(BaseModel):
     podcast_id: int
 
 
+def remove_podcasts_from_gpodder(cnx, user_id, podcast_ids):
+    """Drop the podcasts from the user's Nextcloud or gPodder sync, if they have one set up."""
+    if not database_functions.functions.check_gpodder_settings(database_type, cnx, user_id):
+        return
+    gpodder_url, gpodder_token, gpodder_login = database_functions.functions.get_nextcloud_settings(database_type, cnx, user_id)
+    gpod_type = database_functions.functions.get_gpodder_type(cnx, database_type, user_id)
+    for podcast_id in podcast_ids:
+        podcast_feed = database_functions.functions.get_podcast_feed_by_id(cnx, database_type, podcast_id)
+        if gpod_type == "nextcloud":
+            database_functions.functions.remove_podcast_from_nextcloud(cnx, database_type, gpodder_url, gpodder_login, gpodder_token, podcast_feed)
+        else:
+            database_functions.functions.remove_podcast_from_opodsync(cnx, database_type, gpodder_url, gpodder_login, gpodder_token, podcast_feed, "pinepods")
+
+
 @app.post("/api/data/remove_podcast_id")
 async def api_remove_podcast_route_id(data: RemovePodcastIDData = Body(...), cnx=Depends(get_database_connection),
                                    api_key: str = Depends(get_api_key_from_header)):
@@ -1998,16 +2945,7 @@ async def api_remove_podcast_route_id(data: RemovePodcastIDData = Body(...), cnx
             raise HTTPException(status_code=status.HTTP_403_FORBIDDEN,
                                 detail="You are not authorized to remove podcasts for other users")
     logging.info('check gpod')
-    if database_functions.functions.check_gpodder_settings(database_type, cnx, data.user_id):
-        logging.info('get cloud vals')
-        gpodder_url, gpodder_token, gpodder_login = database_functions.functions.get_nextcloud_settings(database_type, cnx, data.user_id)
-        logging.info('em cloud')
-        podcast_feed = database_functions.functions.get_podcast_feed_by_id(cnx, database_type, data.podcast_id)
-        gpod_type = database_functions.functions.get_gpodder_type(cnx, database_type, data.user_id)
-        if gpod_type == "nextcloud":
-            database_functions.functions.remove_podcast_from_nextcloud(cnx, database_type, gpodder_url, gpodder_login, gpodder_token, podcast_feed)
-        else:
-            database_functions.functions.remove_podcast_from_opodsync(cnx, database_type, gpodder_url, gpodder_login, gpodder_token, podcast_feed, "pinepods")
+    remove_podcasts_from_gpodder(cnx, data.user_id, [data.podcast_id])
     logging.info('rm pod id')
     database_functions.functions.remove_podcast_id(cnx, database_type, data.podcast_id, data.user_id)
     return {"success": True}
@@ -2129,15 +3067,16 @@ class UserValues(BaseModel):
     fullname: str
     username: str
     email: str
-    hash_pw: str
+    password: str
 
 
 
 @app.post("/api/data/add_user")
 async def api_add_user(is_admin: bool = Depends(check_if_admin), cnx=Depends(get_database_connection), api_key: str = Depends(get_api_key_from_header),
                        user_values: UserValues = Body(...)):
+    hash_pw = hash_password_with_policy(cnx, user_values.password)
     database_functions.functions.add_user(cnx, database_type, (
-        user_values.fullname, user_values.username.lower(), user_values.email, user_values.hash_pw))
+        user_values.fullname, user_values.username.lower(), user_values.email, hash_pw))
     return {"detail": "User added."}
 
 
@@ -2146,8 +3085,9 @@ async def api_add_user(cnx=Depends(get_database_connection),
                        user_values: UserValues = Body(...)):
     self_service = database_functions.functions.check_self_service(cnx, database_type)
     if self_service:
+        hash_pw = hash_password_with_policy(cnx, user_values.password)
         database_functions.functions.add_user(cnx, database_type, (
-            user_values.fullname, user_values.username.lower(), user_values.email, user_values.hash_pw))
+            user_values.fullname, user_values.username.lower(), user_values.email, hash_pw))
         return {"detail": "User added."}
     else:
         raise HTTPException(status_code=403,
@@ -2180,7 +3120,7 @@ async def api_set_fullname(user_id: int, new_name: str = Query(...), cnx=Depends
 
 
 class PasswordUpdateRequest(BaseModel):
-    hash_pw: str
+    password: str
 
 @app.put("/api/data/set_password/{user_id}")
 async def api_set_password(
@@ -2189,9 +3129,7 @@ async def api_set_password(
     cnx=Depends(get_database_connection),
     api_key: str = Depends(get_api_key_from_header)
 ):
-    hash_pw = request.hash_pw  # Extract the hash_pw from the request model
-
-    is_valid_key = database_functions.functions.verify_api_key(cnx, api_key)
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
 
     if not is_valid_key:
         raise HTTPException(status_code=403, detail="Your API key is either invalid or does not have correct permission")
@@ -2199,11 +3137,12 @@ async def api_set_password(
     elevated_access = await has_elevated_access(api_key, cnx)
 
     if not elevated_access:
-        user_id_from_api_key = database_functions.functions.id_from_api_key(cnx, api_key)
+        user_id_from_api_key = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
 
         if user_id != user_id_from_api_key:
             raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="You are not authorized to access these user details")
 
+    hash_pw = hash_password_with_policy(cnx, request.password)
     try:
         database_functions.functions.set_password(cnx, database_type, user_id, hash_pw)
         return {"detail": "Password updated."}
@@ -2347,17 +3286,37 @@ async def api_check_saved(user_id: int, title: str, url: str, cnx=Depends(get_da
                             detail="You can only check your own episodes!")
 
 
+class CreateApiKeyRequest(BaseModel):
+    user_id: int
+    name: Optional[str] = None
+    scope: str = "admin"
+    expires_in_days: Optional[int] = None
+
+
 @app.post("/api/data/create_api_key")
-async def api_create_api_key(user_id: int = Body(..., embed=True), cnx=Depends(get_database_connection),
+async def api_create_api_key(body: CreateApiKeyRequest, cnx=Depends(get_database_connection),
                              api_key: str = Depends(get_api_key_from_header)):
     is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
-    if is_valid_key:
-        new_api_key = database_functions.functions.create_api_key(cnx, database_type, user_id)
-        return {"api_key": new_api_key}
-    else:
+    if not is_valid_key:
         raise HTTPException(status_code=403,
                             detail="Your API key is either invalid or does not have correct permission")
 
+    elevated_access = await has_elevated_access(api_key, cnx)
+    if not elevated_access:
+        key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+        if key_id != body.user_id:
+            raise HTTPException(status_code=403, detail="You can only create API keys for yourself!")
+    if body.scope not in API_KEY_SCOPES:
+        raise HTTPException(status_code=400,
+                            detail=f"Scope must be one of: {', '.join(API_KEY_SCOPES)}")
+    if body.expires_in_days is not None and body.expires_in_days < 1:
+        raise HTTPException(status_code=400, detail="Keys have to last at least a day")
+
+    name = (body.name or "").strip()[:255] or None
+    new_api_key = database_functions.functions.create_api_key(
+        cnx, database_type, body.user_id, name, body.scope, body.expires_in_days)
+    return {"api_key": new_api_key}
+
 class SendTestEmailValues(BaseModel):
     server_name: str
     server_port: str
@@ -2593,7 +3552,8 @@ async def api_verify_and_reset_password_route(payload: ResetVerifyCodePayload, c
         raise HTTPException(status_code=400, detail="Code is invalid")
         # return {"code_valid": False}
 
-    message = database_functions.functions.reset_password_prompt(cnx, database_type, payload.email, payload.new_password)
+    hash_pw = hash_password_with_policy(cnx, payload.new_password)
+    message = database_functions.functions.reset_password_prompt(cnx, database_type, payload.email, hash_pw)
     if message is None:
         raise HTTPException(status_code=500, detail="Failed to reset password")
     return {"message": message}
@@ -2826,7 +3786,8 @@ async def api_check_mfa_enabled(user_id: int, cnx=Depends(get_database_connectio
     logging.info(f"Database Type: {database_type}, Connection: {cnx}, User ID: {user_id}")
 
     is_enabled = database_functions.functions.check_mfa_enabled(database_type, cnx, user_id)
-    return {"mfa_enabled": is_enabled}
+    passkey_enabled = bool(database_functions.functions.get_webauthn_credentials(cnx, database_type, user_id))
+    return {"mfa_enabled": is_enabled, "passkey_enabled": passkey_enabled}
 
 
 class VerifyMFABody(BaseModel):
@@ -2856,7 +3817,14 @@ async def api_verify_mfa(body: VerifyMFABody, cnx=Depends(get_database_connectio
         else:
             totp = TOTP(secret)
             verification_result = totp.verify(body.mfa_code)
-            return {"verified": verification_result}
+            if verification_result:
+                return {"verified": True}
+            # Not a current code, but it may be one of the user's recovery codes
+            recovery_used = database_functions.functions.use_mfa_recovery_code(
+                database_type, cnx, body.user_id, hash_recovery_code(body.mfa_code))
+            if recovery_used:
+                logging.info(f"User {body.user_id} signed in with an MFA recovery code")
+            return {"verified": recovery_used, "recovery_code_used": recovery_used}
     else:
         raise HTTPException(status_code=403,
                             detail="You can only verify your own login code!")
@@ -2887,11 +3855,288 @@ async def api_delete_mfa(body: UserIDBody, cnx=Depends(get_database_connection),
 
     result = database_functions.functions.delete_mfa_secret(database_type, cnx, body.user_id)
     if result:
+        # Recovery codes only make sense alongside an authenticator
+        database_functions.functions.replace_mfa_recovery_codes(database_type, cnx, body.user_id, [])
         return {"deleted": result}
     else:
         raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="User not found")
 
 
+# Recovery codes stand in for the authenticator app once each. Only their hashes are kept; the
+# codes themselves are shown to the user when generated and never again.
+RECOVERY_CODE_COUNT = 10
+RECOVERY_CODE_ALPHABET = "abcdefghjkmnpqrstuvwxyz23456789"
+
+
+def hash_recovery_code(code: str):
+    normalized = code.replace("-", "").replace(" ", "").strip().lower()
+    return hashlib.sha256(normalized.encode()).hexdigest()
+
+
+def new_recovery_code():
+    code = "".join(secrets.choice(RECOVERY_CODE_ALPHABET) for _ in range(10))
+    return f"{code[:5]}-{code[5:]}"
+
+
+@app.post("/api/data/mfa_recovery_codes")
+async def api_generate_mfa_recovery_codes(body: UserIDBody, cnx=Depends(get_database_connection),
+                                          api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+    if key_id != body.user_id:
+        raise HTTPException(status_code=403,
+                            detail="You can only generate recovery codes for yourself!")
+    if not database_functions.functions.check_mfa_enabled(database_type, cnx, body.user_id):
+        raise HTTPException(status_code=400, detail="Turn on MFA before generating recovery codes")
+
+    # Generating a new set always throws away the old one
+    codes = [new_recovery_code() for _ in range(RECOVERY_CODE_COUNT)]
+    database_functions.functions.replace_mfa_recovery_codes(
+        database_type, cnx, body.user_id, [hash_recovery_code(code) for code in codes])
+    return {"codes": codes}
+
+
+@app.get("/api/data/mfa_recovery_codes/{user_id}")
+async def api_count_mfa_recovery_codes(user_id: int, cnx=Depends(get_database_connection),
+                                       api_key: str = Depends(get_api_key_from_header)):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+    if key_id != user_id:
+        raise HTTPException(status_code=403,
+                            detail="You can only check your own recovery codes!")
+    remaining = database_functions.functions.count_mfa_recovery_codes(database_type, cnx, user_id)
+    return {"remaining": remaining}
+
+
+# Passkeys (WebAuthn). The relying party is the site the web client is served from unless
+# WEBAUTHN_RP_ID and WEBAUTHN_ORIGIN pin it, e.g. when the client is served from elsewhere.
+webauthn_rp_id = os.environ.get("WEBAUTHN_RP_ID")
+webauthn_origin = os.environ.get("WEBAUTHN_ORIGIN")
+WEBAUTHN_CHALLENGE_TIMEOUT = 300
+
+
+def webauthn_relying_party(request: Request):
+    origin = webauthn_origin or request.headers.get("origin")
+    if not origin:
+        raise HTTPException(status_code=400, detail="Passkeys need the request's Origin header")
+    rp_id = webauthn_rp_id or origin.split("://", 1)[-1].split(":", 1)[0]
+    return rp_id, origin
+
+
+def store_webauthn_challenge(challenge, rp_id, origin, user_id=None):
+    now = time.time()
+    for expired in [key for key, entry in webauthn_challenges.items() if entry["expires"] < now]:
+        del webauthn_challenges[expired]
+    challenge_id = secrets.token_urlsafe(16)
+    webauthn_challenges[challenge_id] = {
+        "challenge": challenge,
+        "rp_id": rp_id,
+        "origin": origin,
+        "user_id": user_id,
+        "expires": now + WEBAUTHN_CHALLENGE_TIMEOUT,
+    }
+    return challenge_id
+
+
+def take_webauthn_challenge(challenge_id, user_id=None):
+    entry = webauthn_challenges.pop(challenge_id, None)
+    if entry is None or entry["expires"] < time.time() or entry["user_id"] != user_id:
+        raise HTTPException(status_code=400, detail="Passkey request expired. Please try again.")
+    return entry
+
+
+def verify_webauthn_assertion(cnx, entry, credential: dict, require_user_verification: bool):
+    """Check a signed challenge against the stored passkey and return the user it belongs to."""
+    stored = database_functions.functions.get_webauthn_credential(cnx, database_type, credential.get("id"))
+    if stored is None:
+        raise HTTPException(status_code=403, detail="This passkey isn't registered with Pinepods")
+    try:
+        verification = verify_authentication_response(
+            credential=credential,
+            expected_challenge=entry["challenge"],
+            expected_rp_id=entry["rp_id"],
+            expected_origin=entry["origin"],
+            credential_public_key=base64url_to_bytes(stored["public_key"]),
+            credential_current_sign_count=stored["sign_count"],
+            require_user_verification=require_user_verification,
+        )
+    except Exception as e:
+        logging.warning(f"Passkey verification failed: {str(e)}")
+        raise HTTPException(status_code=403, detail="Passkey verification failed")
+    database_functions.functions.update_webauthn_sign_count(cnx, database_type, credential.get("id"),
+                                                           verification.new_sign_count)
+    return stored["user_id"]
+
+
+def check_own_passkeys(cnx, api_key, user_id):
+    is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
+    if not is_valid_key:
+        raise HTTPException(status_code=403,
+                            detail="Your API key is either invalid or does not have correct permission")
+    key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+    if key_id != user_id:
+        raise HTTPException(status_code=403, detail="You can only manage your own passkeys!")
+
+
+class WebAuthnUserBody(BaseModel):
+    user_id: int
+
+
+@app.post("/api/data/webauthn/register_options")
+async def api_webauthn_register_options(body: WebAuthnUserBody, request: Request,
+                                        cnx=Depends(get_database_connection),
+                                        api_key: str = Depends(get_api_key_from_header)):
+    check_own_passkeys(cnx, api_key, body.user_id)
+    rp_id, origin = webauthn_relying_party(request)
+    user_details = database_functions.functions.get_user_details_id(cnx, database_type, body.user_id)
+    if not user_details:
+        raise HTTPException(status_code=404, detail="User not found")
+
+    existing = database_functions.functions.get_webauthn_credentials(cnx, database_type, body.user_id)
+    options = generate_registration_options(
+        rp_id=rp_id,
+        rp_name="Pinepods",
+        user_id=str(body.user_id).encode(),
+        user_name=user_details['Username'],
+        user_display_name=user_details['Fullname'] or user_details['Username'],
+        exclude_credentials=[PublicKeyCredentialDescriptor(id=base64url_to_bytes(c["credential_id"]))
+                             for c in existing],
+        # Discoverable credentials are what make passwordless login possible
+        authenticator_selection=AuthenticatorSelectionCriteria(
+            resident_key=ResidentKeyRequirement.PREFERRED,
+            user_verification=UserVerificationRequirement.PREFERRED,
+        ),
+    )
+    challenge_id = store_webauthn_challenge(options.challenge, rp_id, origin, body.user_id)
+    return {"challenge_id": challenge_id, "options": json.loads(options_to_json(options))}
+
+
+class WebAuthnRegisterBody(BaseModel):
+    user_id: int
+    challenge_id: str
+    name: str
+    credential: dict
+
+
+@app.post("/api/data/webauthn/register")
+async def api_webauthn_register(body: WebAuthnRegisterBody, cnx=Depends(get_database_connection),
+                                api_key: str = Depends(get_api_key_from_header)):
+    check_own_passkeys(cnx, api_key, body.user_id)
+    entry = take_webauthn_challenge(body.challenge_id, body.user_id)
+    try:
+        verification = verify_registration_response(
+            credential=body.credential,
+            expected_challenge=entry["challenge"],
+            expected_rp_id=entry["rp_id"],
+            expected_origin=entry["origin"],
+        )
+    except Exception as e:
+        logging.warning(f"Passkey registration failed: {str(e)}")
+        raise HTTPException(status_code=400, detail="Passkey registration failed")
+
+    name = body.name.strip() or "Passkey"
+    database_functions.functions.add_webauthn_credential(
+        cnx, database_type, body.user_id, bytes_to_base64url(verification.credential_id),
+        bytes_to_base64url(verification.credential_public_key), verification.sign_count, name[:255])
+    return {"detail": "Passkey added"}
+
+
+@app.get("/api/data/webauthn/credentials/{user_id}")
+async def api_webauthn_credentials(user_id: int, cnx=Depends(get_database_connection),
+                                   api_key: str = Depends(get_api_key_from_header)):
+    check_own_passkeys(cnx, api_key, user_id)
+    credentials = database_functions.functions.get_webauthn_credentials(cnx, database_type, user_id)
+    return {"credentials": credentials}
+
+
+class WebAuthnDeleteBody(BaseModel):
+    user_id: int
+    credential_id: str
+
+
+@app.delete("/api/data/webauthn/credentials")
+async def api_webauthn_delete_credential(body: WebAuthnDeleteBody, cnx=Depends(get_database_connection),
+                                         api_key: str = Depends(get_api_key_from_header)):
+    check_own_passkeys(cnx, api_key, body.user_id)
+    if database_functions.functions.delete_webauthn_credential(cnx, database_type, body.user_id,
+                                                               body.credential_id):
+        return {"detail": "Passkey removed"}
+    raise HTTPException(status_code=404, detail="Passkey not found")
+
+
+@app.post("/api/data/webauthn/verify_options")
+async def api_webauthn_verify_options(body: WebAuthnUserBody, request: Request,
+                                      cnx=Depends(get_database_connection),
+                                      api_key: str = Depends(get_api_key_from_header)):
+    check_own_passkeys(cnx, api_key, body.user_id)
+    rp_id, origin = webauthn_relying_party(request)
+    credentials = database_functions.functions.get_webauthn_credentials(cnx, database_type, body.user_id)
+    if not credentials:
+        raise HTTPException(status_code=404, detail="No passkeys are registered for this user")
+    options = generate_authentication_options(
+        rp_id=rp_id,
+        allow_credentials=[PublicKeyCredentialDescriptor(id=base64url_to_bytes(c["credential_id"]))
+                           for c in credentials],
+        user_verification=UserVerificationRequirement.PREFERRED,
+    )
+    challenge_id = store_webauthn_challenge(options.challenge, rp_id, origin, body.user_id)
+    return {"challenge_id": challenge_id, "options": json.loads(options_to_json(options))}
+
+
+class WebAuthnVerifyBody(BaseModel):
+    user_id: int
+    challenge_id: str
+    credential: dict
+
+
+@app.post("/api/data/webauthn/verify")
+async def api_webauthn_verify(body: WebAuthnVerifyBody, cnx=Depends(get_database_connection),
+                              api_key: str = Depends(get_api_key_from_header)):
+    check_own_passkeys(cnx, api_key, body.user_id)
+    entry = take_webauthn_challenge(body.challenge_id, body.user_id)
+    user_id = verify_webauthn_assertion(cnx, entry, body.credential, require_user_verification=False)
+    return {"verified": user_id == body.user_id}
+
+
+@app.post("/api/auth/webauthn/login_options")
+async def api_webauthn_login_options(request: Request):
+    rp_id, origin = webauthn_relying_party(request)
+    # No allowed credentials, so the browser offers whichever passkeys it holds for this site
+    options = generate_authentication_options(
+        rp_id=rp_id,
+        user_verification=UserVerificationRequirement.REQUIRED,
+    )
+    challenge_id = store_webauthn_challenge(options.challenge, rp_id, origin)
+    return {"challenge_id": challenge_id, "options": json.loads(options_to_json(options))}
+
+
+class WebAuthnLoginBody(BaseModel):
+    challenge_id: str
+    credential: dict
+
+
+@app.post("/api/auth/webauthn/login")
+async def api_webauthn_login(body: WebAuthnLoginBody, cnx=Depends(get_database_connection)):
+    entry = take_webauthn_challenge(body.challenge_id)
+    # A passkey on its own stands in for both factors, so the authenticator must have checked
+    # the person holding it
+    user_id = verify_webauthn_assertion(cnx, entry, body.credential, require_user_verification=True)
+    user_details = database_functions.functions.get_user_details_id(cnx, database_type, user_id)
+    if not user_details:
+        raise HTTPException(status_code=404, detail="User not found")
+    username = user_details['Username']
+    retrieved_key = database_functions.functions.get_api_key(cnx, database_type, username)
+    return {"status": "success", "retrieved_key": retrieved_key, "username": username}
+
+
 class AllEpisodes(BaseModel):
     pod_feed: str
 
@@ -3071,13 +4316,12 @@ async def delete_selected_podcasts(data: SelectedPodcastsDelete, cnx=Depends(get
 
     # Allow the action if the API key belongs to the user or it's the web API key
     if key_id == data.user_id or is_web_key:
-        if is_valid_key:
-            delete_status = database_functions.functions.delete_selected_podcasts(cnx, database_type, data.delete_list,
-                                                                                  data.user_id)
-            return {"status": delete_status}
-        else:
-            raise HTTPException(status_code=403,
-                                detail="Your API key is either invalid or does not have correct permission")
+        podcast_ids = database_functions.functions.user_podcast_ids(cnx, database_type, data.user_id,
+                                                                    data.delete_list)
+        remove_podcasts_from_gpodder(cnx, data.user_id, podcast_ids)
+        deleted = database_functions.functions.delete_selected_podcasts(cnx, database_type, podcast_ids,
+                                                                        data.user_id)
+        return {"status": "success", "deleted": deleted}
     else:
         raise HTTPException(status_code=403,
                             detail="You can only delete your own selected podcasts!")
@@ -3086,6 +4330,17 @@ async def delete_selected_podcasts(data: SelectedPodcastsDelete, cnx=Depends(get
 class SearchPodcastData(BaseModel):
     search_term: str
     user_id: int
+    # Filters set here win over the matching qualifiers typed into search_term
+    podcast: Optional[str] = None
+    person: Optional[str] = None
+    before: Optional[str] = None
+    after: Optional[str] = None
+    listen_state: Optional[str] = None
+    min_minutes: Optional[int] = None
+    max_minutes: Optional[int] = None
+    fields: Optional[List[str]] = None
+    page: int = 1
+    page_size: int = 25
 
 
 @app.post("/api/data/search_data")
@@ -3093,8 +4348,26 @@ async def search_data(data: SearchPodcastData, cnx=Depends(get_database_connecti
                       api_key: str = Depends(get_api_key_from_header)):
     is_valid_key = database_functions.functions.verify_api_key(cnx, database_type, api_key)
     if is_valid_key:
-        result = database_functions.functions.search_data(database_type, cnx, data.search_term, data.user_id)
-        return {"data": result}
+        is_web_key = api_key == base_webkey.web_key
+        key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
+        if key_id != data.user_id and not is_web_key:
+            raise HTTPException(status_code=403,
+                                detail="You can only search your own podcasts!")
+        filters = {
+            "podcast": data.podcast,
+            "person": data.person,
+            "before": data.before,
+            "after": data.after,
+            "listen_state": data.listen_state,
+            "min_minutes": data.min_minutes,
+            "max_minutes": data.max_minutes,
+            "fields": data.fields,
+        }
+        try:
+            return database_functions.functions.search_data(database_type, cnx, data.search_term, data.user_id,
+                                                            filters, data.page, data.page_size)
+        except ValueError as e:
+            raise HTTPException(status_code=400, detail=str(e))
     else:
         raise HTTPException(status_code=403,
                             detail="Your API key is either invalid or does not have correct permission")
@@ -3103,6 +4376,7 @@ async def search_data(data: SearchPodcastData, cnx=Depends(get_database_connecti
 class QueuePodData(BaseModel):
     episode_id: int
     user_id: int
+    position: Optional[str] = "last"  # "next" puts the episode at the head of the queue
 
 
 @app.post("/api/data/queue_pod")
@@ -3121,10 +4395,15 @@ async def queue_pod(data: QueuePodData, cnx=Depends(get_database_connection),
     # Allow the action if the API key belongs to the user or it's the web API key
     if key_id == data.user_id or is_web_key:
         ep_status = database_functions.functions.check_queued(database_type, cnx, data.episode_id, data.user_id)
-        if ep_status:
+        if ep_status and data.position == "next":
+            # Playing next an episode that's already queued just moves it to the front
+            database_functions.functions.remove_queued_pod(database_type, cnx, data.episode_id, data.user_id)
+            result = database_functions.functions.queue_pod(database_type, cnx, data.episode_id, data.user_id, "next")
+            return {"data": result}
+        elif ep_status:
             return {"data": "Episode already in queue"}
         else:
-            result = database_functions.functions.queue_pod(database_type, cnx, data.episode_id, data.user_id)
+            result = database_functions.functions.queue_pod(database_type, cnx, data.episode_id, data.user_id, data.position)
             return {"data": result}
 
     else:
@@ -3238,7 +4517,8 @@ async def get_pinepods_version(cnx=Depends(get_database_connection),
     return {"data": result}
 
 @app.post("/api/data/share_episode/{episode_id}")
-async def share_episode(episode_id: int, cnx=Depends(get_database_connection),
+async def share_episode(episode_id: int, start_time: Optional[int] = None, end_time: Optional[int] = None,
+                        cnx=Depends(get_database_connection),
                         api_key: str = Depends(get_api_key_from_header)):
     import uuid
     from datetime import datetime, timedelta
@@ -3252,12 +4532,19 @@ async def share_episode(episode_id: int, cnx=Depends(get_database_connection),
 
     key_id = database_functions.functions.id_from_api_key(cnx, database_type, api_key)
 
+    # Clip bounds are optional, but an end needs something before it to play
+    if start_time is not None and start_time < 0:
+        raise HTTPException(status_code=400, detail="Clip start time can't be negative.")
+    if end_time is not None and end_time <= (start_time or 0):
+        raise HTTPException(status_code=400, detail="Clip end time must be after the start time.")
+
     # Generate the URL key and expiration date
     url_key = str(uuid.uuid4())  # Generates a unique URL key
     expiration_date = datetime.utcnow() + timedelta(days=60)  # Expire in 60 days
 
     # Call database function to insert the shared episode entry
-    result = database_functions.functions.add_shared_episode(database_type, cnx, episode_id, url_key, expiration_date)
+    result = database_functions.functions.add_shared_episode(database_type, cnx, episode_id, url_key, expiration_date,
+                                                             start_time, end_time)
 
     if result:
         return {"url_key": url_key}
@@ -3276,7 +4563,8 @@ async def get_episode_by_url_key(url_key: str, cnx=Depends(get_database_connecti
     # Now retrieve the episode metadata using the episode_id
     try:
         episode_data = database_functions.functions.get_episode_metadata_id(database_type, cnx, episode_id)  # UserID is None because we are bypassing normal user auth for shared links
-        return {"episode": episode_data}
+        start_time, end_time = database_functions.functions.get_shared_episode_clip(database_type, cnx, url_key)
+        return {"episode": episode_data, "start_time": start_time, "end_time": end_time}
     except ValueError as e:
         raise HTTPException(status_code=404, detail=str(e))
 
@@ -1,3 +1,7 @@
+import hashlib
+import os
+import string
+
 from passlib.context import CryptContext
 
 # Create a Passlib context for Argon2
@@ -8,6 +12,57 @@ def hash_password(password: str):
     hashed_password = pwd_context.hash(password)
     return hashed_password
 
+_breached_hashes = None
+
+def load_breached_passwords():
+    """SHA-1 hashes from the list named by BREACHED_PASSWORDS_FILE, read once and kept.
+
+    Each line is either a plaintext password or an uppercase or lowercase SHA-1 hex digest,
+    optionally followed by ':count' as in the Have I Been Pwned downloads.
+    """
+    global _breached_hashes
+    if _breached_hashes is not None:
+        return _breached_hashes
+    hashes = set()
+    path = os.environ.get("BREACHED_PASSWORDS_FILE")
+    if path:
+        try:
+            with open(path, encoding="utf-8", errors="ignore") as breached_file:
+                for line in breached_file:
+                    entry = line.rstrip("\r\n")
+                    if not entry:
+                        continue
+                    digest = entry.split(":", 1)[0].strip()
+                    if len(digest) == 40 and all(c in string.hexdigits for c in digest):
+                        hashes.add(digest.upper())
+                    else:
+                        hashes.add(hashlib.sha1(entry.encode("utf-8")).hexdigest().upper())
+            print(f"Loaded {len(hashes)} breached passwords from {path}")
+        except OSError as e:
+            print(f"Couldn't read breached password list {path}: {e}")
+    _breached_hashes = hashes
+    return _breached_hashes
+
+def is_breached_password(password: str) -> bool:
+    return hashlib.sha1(password.encode("utf-8")).hexdigest().upper() in load_breached_passwords()
+
+def password_policy_failures(password: str, policy: dict) -> list:
+    """Everything the password is missing under the policy. Empty means it's acceptable."""
+    failures = []
+    if len(password) < policy["min_length"]:
+        failures.append(f"be at least {policy['min_length']} characters long")
+    if policy["require_upper"] and not any(c.isupper() for c in password):
+        failures.append("contain an uppercase letter")
+    if policy["require_lower"] and not any(c.islower() for c in password):
+        failures.append("contain a lowercase letter")
+    if policy["require_digit"] and not any(c.isdigit() for c in password):
+        failures.append("contain a number")
+    if policy["require_symbol"] and all(c.isalnum() or c.isspace() for c in password):
+        failures.append("contain a symbol")
+    if policy["check_breached"] and is_breached_password(password):
+        failures.append("not be a password that has appeared in a known data breach")
+    return failures
+
 def verify_password(cnx, database_type, username: str, password: str) -> bool:
     print("preparing pw check")
     if database_type == "postgresql":
@@ -1,5 +1,7 @@
 import random
 import string
+import html
+import json
 import mysql.connector
 from mysql.connector import errorcode
 import mysql.connector.pooling
@@ -472,6 +474,10 @@ def add_episodes(cnx, database_type, podcast_id, feed_url, artwork_url, auto_dow
 
     episode_dump = feedparser.parse(content)
 
+    # New episodes from auto-queue podcasts go straight to the end of the owner's queue
+    podcast_user_id = get_user_id_from_pod_id(cnx, database_type, podcast_id)
+    auto_queue = call_get_auto_queue_status(cnx, database_type, podcast_id, podcast_user_id)
+
     cursor = cnx.cursor()
 
     def parse_duration(duration_string: str) -> int:
@@ -506,6 +512,8 @@ def add_episodes(cnx, database_type, podcast_id, feed_url, artwork_url, auto_dow
         cnx.commit()
 
     new_episodes = []
+    # Live subscribers show the episode straight away, so they need the show's name too
+    podcast_name = get_podcast_name_from_id(cnx, database_type, podcast_id) if websocket else None
 
     for entry in episode_dump.entries:
         # Check necessary fields are present
@@ -516,6 +524,10 @@ def add_episodes(cnx, database_type, podcast_id, feed_url, artwork_url, auto_dow
         parsed_title = entry.title
         parsed_description = entry.get('content', [{}])[0].get('value', entry.summary)
         parsed_audio_url = entry.enclosures[0].href if entry.enclosures else ""
+        # Keep the feed's declared MIME type, clients can't reliably tell video apart from the URL
+        parsed_enclosure_type = (entry.enclosures[0].get('type') or None) if entry.enclosures else None
+        if parsed_enclosure_type:
+            parsed_enclosure_type = parsed_enclosure_type[:255]
         parsed_release_datetime = dateutil.parser.parse(entry.published).strftime("%Y-%m-%d %H:%M:%S")
 
         # Artwork prioritizing episode-specific artwork, then falling back to the feed's artwork if necessary
@@ -561,17 +573,17 @@ def add_episodes(cnx, database_type, podcast_id, feed_url, artwork_url, auto_dow
         if database_type == "postgresql":
             episode_insert_query = """
                 INSERT INTO "Episodes"
-                (PodcastID, EpisodeTitle, EpisodeDescription, EpisodeURL, EpisodeArtwork, EpisodePubDate, EpisodeDuration)
-                VALUES (%s, %s, %s, %s, %s, %s, %s)
+                (PodcastID, EpisodeTitle, EpisodeDescription, EpisodeURL, EpisodeArtwork, EpisodePubDate, EpisodeDuration, EnclosureType)
+                VALUES (%s, %s, %s, %s, %s, %s, %s, %s)
             """
         else:  # MySQL or MariaDB
             episode_insert_query = """
                 INSERT INTO Episodes
-                (PodcastID, EpisodeTitle, EpisodeDescription, EpisodeURL, EpisodeArtwork, EpisodePubDate, EpisodeDuration)
-                VALUES (%s, %s, %s, %s, %s, %s, %s)
+                (PodcastID, EpisodeTitle, EpisodeDescription, EpisodeURL, EpisodeArtwork, EpisodePubDate, EpisodeDuration, EnclosureType)
+                VALUES (%s, %s, %s, %s, %s, %s, %s, %s)
             """
 
-        cursor.execute(episode_insert_query, (podcast_id, parsed_title, parsed_description, parsed_audio_url, parsed_artwork_url, parsed_release_datetime, parsed_duration))
+        cursor.execute(episode_insert_query, (podcast_id, parsed_title, parsed_description, parsed_audio_url, parsed_artwork_url, parsed_release_datetime, parsed_duration, parsed_enclosure_type))
         print('episodes inserted')
         update_episode_count(cnx, database_type, cursor, podcast_id)
         # Get the EpisodeID for the newly added episode
@@ -588,7 +600,8 @@ def add_episodes(cnx, database_type, podcast_id, feed_url, artwork_url, auto_dow
                     "artwork_url": parsed_artwork_url,
                     "release_datetime": parsed_release_datetime,
                     "duration": parsed_duration,
-                    "completed": False  # Assuming this is the default for new episodes
+                    "completed": False,  # Assuming this is the default for new episodes
+                    "podcast_name": podcast_name
                 }
                 new_episodes.append(episode_data)
             if auto_download:  # Check if auto-download is enabled
@@ -597,6 +610,9 @@ def add_episodes(cnx, database_type, podcast_id, feed_url, artwork_url, auto_dow
                 user_id = get_user_id_from_pod_id(cnx, database_type, podcast_id)
                 # Call your download function here
                 download_podcast(cnx, database_type, episode_id, user_id)
+            if auto_queue:
+                episode_id = get_episode_id(cnx, database_type, podcast_id, parsed_title, parsed_audio_url)
+                queue_pod(database_type, cnx, episode_id, podcast_user_id)
 
     cnx.commit()
 
@@ -1033,20 +1049,23 @@ def return_pods(database_type, cnx, user_id):
     else:  # Assuming MariaDB/MySQL if not PostgreSQL
         cursor = cnx.cursor(dictionary=True)
 
-    if database_type == "postgresql":
-        query = (
-            'SELECT PodcastID, PodcastName, ArtworkURL, Description, EpisodeCount, WebsiteURL, FeedURL, Author, Categories, Explicit '
-            'FROM "Podcasts" '
-            'WHERE UserID = %s'
-        )
-    else:  # MySQL or MariaDB
-        query = (
-            "SELECT PodcastID, PodcastName, ArtworkURL, Description, EpisodeCount, WebsiteURL, FeedURL, Author, Categories, Explicit "
-            "FROM Podcasts "
-            "WHERE UserID = %s"
-        )
+    # The library page sorts on these, so each podcast carries its own listening stats
+    t = playlist_tables(database_type)
+    query = (
+        "SELECT P.PodcastID, P.PodcastName, P.ArtworkURL, P.Description, P.EpisodeCount, P.WebsiteURL, "
+        "P.FeedURL, P.Author, P.Categories, P.Explicit, P.AutoDownload, "
+        f"(SELECT MAX(E.EpisodePubDate) FROM {t['Episodes']} E WHERE E.PodcastID = P.PodcastID) AS LastEpisodeDate, "
+        f"(SELECT COUNT(*) FROM {t['Episodes']} E "
+        f"LEFT JOIN {t['UserEpisodeHistory']} H ON E.EpisodeID = H.EpisodeID AND H.UserID = %s "
+        "WHERE E.PodcastID = P.PodcastID AND E.Completed = FALSE AND COALESCE(H.ListenDuration, 0) = 0) AS UnplayedCount, "
+        f"(SELECT COALESCE(SUM(H.ListenDuration), 0) FROM {t['UserEpisodeHistory']} H "
+        f"INNER JOIN {t['Episodes']} E ON H.EpisodeID = E.EpisodeID "
+        "WHERE E.PodcastID = P.PodcastID AND H.UserID = %s) AS TotalListenTime "
+        f"FROM {t['Podcasts']} P "
+        "WHERE P.UserID = %s"
+    )
 
-    cursor.execute(query, (user_id,))
+    cursor.execute(query, (user_id, user_id, user_id))
     rows = cursor.fetchall()
 
     cursor.close()
@@ -1058,6 +1077,11 @@ def return_pods(database_type, cnx, user_id):
         # Convert column names to lowercase for MySQL
         rows = [{k.lower(): v for k, v in row.items()} for row in rows]
 
+    for row in rows:
+        # MySQL sums come back as Decimal, which would reach the client as a float
+        row["unplayedcount"] = int(row["unplayedcount"] or 0)
+        row["totallistentime"] = int(row["totallistentime"] or 0)
+
     return rows
 
 
@@ -1122,21 +1146,24 @@ def refresh_pods_for_user(cnx, database_type, user_id):
 
 
 
-def refresh_pods(cnx, database_type):
+def refresh_pods(cnx, database_type, collect_new=False):
     print('refresh begin')
     cursor = cnx.cursor()
     if database_type == "postgresql":
-        select_podcasts = 'SELECT PodcastID, FeedURL, ArtworkURL, AutoDownload, Username, Password FROM "Podcasts"'
+        select_podcasts = 'SELECT PodcastID, FeedURL, ArtworkURL, AutoDownload, Username, Password, UserID FROM "Podcasts"'
     else:  # MySQL or MariaDB
-        select_podcasts = "SELECT PodcastID, FeedURL, ArtworkURL, AutoDownload, Username, Password FROM Podcasts"
+        select_podcasts = "SELECT PodcastID, FeedURL, ArtworkURL, AutoDownload, Username, Password, UserID FROM Podcasts"
 
     cursor.execute(select_podcasts)
     result_set = cursor.fetchall()  # fetch the result set
 
+    # New episodes per owning user, for pushing to anyone subscribed to their feed
+    new_episodes = {}
+
     for result in result_set:
         try:
             if isinstance(result, tuple):
-                podcast_id, feed_url, artwork_url, auto_download, username, password = result
+                podcast_id, feed_url, artwork_url, auto_download, username, password, user_id = result
             elif isinstance(result, dict):
                 if database_type == "postgresql":
                     podcast_id = result["podcastid"]
@@ -1145,6 +1172,7 @@ def refresh_pods(cnx, database_type):
                     auto_download = result["autodownload"]
                     username = result["username"]
                     password = result["password"]
+                    user_id = result["userid"]
                 else:
                     podcast_id = result["PodcastID"]
                     feed_url = result["FeedURL"]
@@ -1152,11 +1180,14 @@ def refresh_pods(cnx, database_type):
                     auto_download = result["AutoDownload"]
                     username = result["Username"]
                     password = result["Password"]
+                    user_id = result["UserID"]
             else:
                 raise ValueError(f"Unexpected result type: {type(result)}")
 
             print(f'Running for: {podcast_id}')
-            add_episodes(cnx, database_type, podcast_id, feed_url, artwork_url, auto_download, username, password)
+            episodes = add_episodes(cnx, database_type, podcast_id, feed_url, artwork_url, auto_download, username, password, websocket=collect_new)
+            if collect_new:
+                new_episodes.setdefault(user_id, []).extend(episodes or [])
         except Exception as e:
             print(f"Error refreshing podcast {podcast_id}: {str(e)}")
             # Optionally, you could update a status field in your database to mark this podcast as having issues
@@ -1166,6 +1197,7 @@ def refresh_pods(cnx, database_type):
     cursor.close()
     # Don't close the connection here if it's managed outside this function
     # cnx.close()
+    return new_episodes
 
 
 def remove_unavailable_episodes(cnx, database_type):
@@ -1411,6 +1443,24 @@ def get_user_id_from_pod_id(cnx, database_type, podcast_id):
     return user_id
 
 
+def get_podcast_name_from_id(cnx, database_type, podcast_id):
+    cursor = cnx.cursor()
+    if database_type == "postgresql":
+        query = 'SELECT PodcastName FROM "Podcasts" WHERE PodcastID = %s'
+    else:
+        query = "SELECT PodcastName FROM Podcasts WHERE PodcastID = %s"
+
+    cursor.execute(query, (podcast_id,))
+    result = cursor.fetchone()
+    cursor.close()
+
+    if not result:
+        return None
+    if isinstance(result, dict):
+        return result.get("podcastname")
+    return result[0]
+
+
 def get_user_details(cnx, database_type, username):
     cursor = cnx.cursor()
     if database_type == "postgresql":
@@ -1764,14 +1814,14 @@ def call_get_auto_download_status(cnx, database_type, podcast_id, user_id):
 
 
 
-def adjust_skip_times(cnx, database_type, podcast_id, start_skip, end_skip):
+def enable_auto_queue(cnx, database_type, podcast_id, user_id, auto_queue):
     cursor = cnx.cursor()
     try:
         if database_type == "postgresql":
-            query = 'UPDATE "Podcasts" SET StartSkip = %s, EndSkip = %s WHERE PodcastID = %s'
+            query = 'UPDATE "Podcasts" SET AutoQueue = %s WHERE PodcastID = %s AND UserID = %s'
         else:  # MySQL or MariaDB
-            query = "UPDATE Podcasts SET StartSkip = %s, EndSkip = %s WHERE PodcastID = %s"
-        cursor.execute(query, (start_skip, end_skip, podcast_id))
+            query = "UPDATE Podcasts SET AutoQueue = %s WHERE PodcastID = %s AND UserID = %s"
+        cursor.execute(query, (auto_queue, podcast_id, user_id))
         cnx.commit()
     except Exception as e:
         cnx.rollback()
@@ -1779,19 +1829,60 @@ def adjust_skip_times(cnx, database_type, podcast_id, start_skip, end_skip):
     finally:
         cursor.close()
 
+def call_get_auto_queue_status(cnx, database_type, podcast_id, user_id):
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'SELECT AutoQueue FROM "Podcasts" WHERE PodcastID = %s AND UserID = %s'
+        else:  # MySQL or MariaDB
+            query = "SELECT AutoQueue FROM Podcasts WHERE PodcastID = %s AND UserID = %s"
+
+        cursor.execute(query, (podcast_id, user_id))
+        result = cursor.fetchone()
+
+        if result:
+            return result[0] if isinstance(result, tuple) else result.get("autoqueue")
+        else:
+            return None
+    finally:
+        cursor.close()
+
+def adjust_skip_times(cnx, database_type, podcast_id, user_id, settings):
+    """Update the given per-podcast playback settings, keyed by column name.
+
+    Columns that aren't in settings keep their value. Returns False when the user has no such podcast.
+    """
+    columns = ("StartSkip", "EndSkip", "PlaybackSpeed", "VolumeBoost")
+    updates = [(column, settings[column]) for column in columns if column in settings]
+    if not updates:
+        return True
+    set_clause = ", ".join(f"{column} = %s" for column, _ in updates)
+    table = '"Podcasts"' if database_type == "postgresql" else "Podcasts"
+    query = f"UPDATE {table} SET {set_clause} WHERE PodcastID = %s AND UserID = %s"
+    cursor = cnx.cursor()
+    try:
+        cursor.execute(query, tuple(value for _, value in updates) + (podcast_id, user_id))
+        cnx.commit()
+        return cursor.rowcount > 0
+    except Exception as e:
+        cnx.rollback()
+        raise e
+    finally:
+        cursor.close()
+
 def get_auto_skip_times(cnx, database_type, podcast_id, user_id):
     cursor = cnx.cursor()
     logging.error(f"Error retrieving DownloadedLocation: {podcast_id}, {user_id}")
     try:
         if database_type == "postgresql":
             query = """
-                SELECT StartSkip, EndSkip
+                SELECT StartSkip, EndSkip, PlaybackSpeed, VolumeBoost
                 FROM "Podcasts"
                 WHERE PodcastID = %s AND UserID = %s
             """
         else:  # MySQL or MariaDB
             query = """
-                SELECT StartSkip, EndSkip
+                SELECT StartSkip, EndSkip, PlaybackSpeed, VolumeBoost
                 FROM Podcasts
                 WHERE PodcastID = %s AND UserID = %s
             """
@@ -1800,10 +1891,10 @@ def get_auto_skip_times(cnx, database_type, podcast_id, user_id):
 
         if result:
             if isinstance(result, dict):
-                return result.get("startskip"), result.get("endskip")
+                return result.get("startskip"), result.get("endskip"), result.get("playbackspeed"), result.get("volumeboost")
             elif isinstance(result, tuple):
-                return result[0], result[1]
-        return None, None
+                return result[0], result[1], result[2], result[3]
+        return None, None, None, None
     finally:
         cursor.close()
 
@@ -2511,14 +2602,16 @@ def get_api_info(database_type, cnx, user_id):
         cnx.row_factory = dict_row
         cursor = cnx.cursor()
         query = (
-            'SELECT APIKeyID, "APIKeys".UserID, Username, RIGHT(APIKey, 4) as LastFourDigits, Created '
+            'SELECT APIKeyID, "APIKeys".UserID, Username, RIGHT(APIKey, 4) as LastFourDigits, Created, '
+            'Name, Scope, Expires, LastUsed '
             'FROM "APIKeys" '
             'JOIN "Users" ON "APIKeys".UserID = "Users".UserID '
         )
     else:  # MySQL or MariaDB
         cursor = cnx.cursor(dictionary=True)
         query = (
-            "SELECT APIKeyID, APIKeys.UserID, Username, RIGHT(APIKey, 4) as LastFourDigits, Created "
+            "SELECT APIKeyID, APIKeys.UserID, Username, RIGHT(APIKey, 4) as LastFourDigits, Created, "
+            "Name, Scope, Expires, LastUsed "
             "FROM APIKeys "
             "JOIN Users ON APIKeys.UserID = Users.UserID "
         )
@@ -2545,25 +2638,66 @@ def get_api_info(database_type, cnx, user_id):
 
 
 
-def create_api_key(cnx, database_type, user_id):
+def create_api_key(cnx, database_type, user_id, name=None, scope="admin", expires_in_days=None):
     import secrets
     import string
     alphabet = string.ascii_letters + string.digits
     api_key = ''.join(secrets.choice(alphabet) for _ in range(64))
 
     cursor = cnx.cursor()
+    # Expiry is worked out by the database so it compares against the same clock later
     if database_type == "postgresql":
-        query = 'INSERT INTO "APIKeys" (UserID, APIKey) VALUES (%s, %s)'
+        expires = 'CURRENT_TIMESTAMP + make_interval(days => %s)' if expires_in_days else 'NULL'
+        query = f'INSERT INTO "APIKeys" (UserID, APIKey, Name, Scope, Expires) VALUES (%s, %s, %s, %s, {expires})'
     else:  # MySQL or MariaDB
-        query = "INSERT INTO APIKeys (UserID, APIKey) VALUES (%s, %s)"
+        expires = 'DATE_ADD(CURRENT_TIMESTAMP, INTERVAL %s DAY)' if expires_in_days else 'NULL'
+        query = f"INSERT INTO APIKeys (UserID, APIKey, Name, Scope, Expires) VALUES (%s, %s, %s, %s, {expires})"
 
-    cursor.execute(query, (user_id, api_key))
+    params = (user_id, api_key, name, scope)
+    if expires_in_days:
+        params += (int(expires_in_days),)
+    cursor.execute(query, params)
     cnx.commit()
     cursor.close()
 
     return api_key
 
 
+def get_api_key_scope(cnx, database_type, api_key):
+    """The scope of a key and whether it has expired, or None if there's no such key."""
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = ('SELECT APIKeyID, Scope, (Expires IS NOT NULL AND Expires < CURRENT_TIMESTAMP) AS Expired '
+                     'FROM "APIKeys" WHERE APIKey = %s')
+        else:  # MySQL or MariaDB
+            query = ("SELECT APIKeyID, Scope, (Expires IS NOT NULL AND Expires < CURRENT_TIMESTAMP) AS Expired "
+                     "FROM APIKeys WHERE APIKey = %s")
+        cursor.execute(query, (api_key,))
+        row = cursor.fetchone()
+        if row is None:
+            return None
+        if isinstance(row, dict):
+            row = {k.lower(): v for k, v in row.items()}
+            row = (row["apikeyid"], row["scope"], row["expired"])
+        return {"api_key_id": row[0], "scope": row[1] or "admin", "expired": bool(row[2])}
+    finally:
+        cursor.close()
+
+
+def touch_api_key(cnx, database_type, api_key_id):
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'UPDATE "APIKeys" SET LastUsed = CURRENT_TIMESTAMP WHERE APIKeyID = %s'
+        else:  # MySQL or MariaDB
+            query = "UPDATE APIKeys SET LastUsed = CURRENT_TIMESTAMP WHERE APIKeyID = %s"
+        cursor.execute(query, (api_key_id,))
+        cnx.commit()
+    finally:
+        cursor.close()
+
+
 def is_same_api_key(cnx, database_type, api_id, api_key):
     if database_type == "postgresql":
         cursor = cnx.cursor()
@@ -2919,14 +3053,62 @@ def enable_disable_self_service(cnx, database_type):
     cursor.close()
 
 
+PASSWORD_POLICY_COLUMNS = (
+    ("min_length", "PasswordMinLength"),
+    ("require_upper", "PasswordRequireUpper"),
+    ("require_lower", "PasswordRequireLower"),
+    ("require_digit", "PasswordRequireDigit"),
+    ("require_symbol", "PasswordRequireSymbol"),
+    ("check_breached", "PasswordCheckBreached"),
+)
+
+
+def get_password_policy(cnx, database_type):
+    cursor = cnx.cursor()
+    try:
+        columns = ", ".join(column for _, column in PASSWORD_POLICY_COLUMNS)
+        if database_type == "postgresql":
+            query = f'SELECT {columns} FROM "AppSettings" WHERE AppSettingsID = 1'
+        else:  # MySQL or MariaDB
+            query = f"SELECT {columns} FROM AppSettings WHERE AppSettingsID = 1"
+        cursor.execute(query)
+        row = cursor.fetchone()
+        if row is None:
+            return {"min_length": 6, "require_upper": False, "require_lower": False,
+                    "require_digit": False, "require_symbol": False, "check_breached": False}
+        if isinstance(row, dict):
+            row = {k.lower(): v for k, v in row.items()}
+            row = tuple(row[column.lower()] for _, column in PASSWORD_POLICY_COLUMNS)
+        policy = {"min_length": int(row[0] if row[0] is not None else 6)}
+        for (name, _), value in zip(PASSWORD_POLICY_COLUMNS[1:], row[1:]):
+            policy[name] = bool(value)
+        return policy
+    finally:
+        cursor.close()
+
+
+def set_password_policy(cnx, database_type, policy):
+    cursor = cnx.cursor()
+    try:
+        assignments = ", ".join(f"{column} = %s" for _, column in PASSWORD_POLICY_COLUMNS)
+        if database_type == "postgresql":
+            query = f'UPDATE "AppSettings" SET {assignments} WHERE AppSettingsID = 1'
+        else:  # MySQL or MariaDB
+            query = f"UPDATE AppSettings SET {assignments} WHERE AppSettingsID = 1"
+        cursor.execute(query, tuple(policy[name] for name, _ in PASSWORD_POLICY_COLUMNS))
+        cnx.commit()
+    finally:
+        cursor.close()
+
+
 
 def verify_api_key(cnx, database_type, passed_key):
     print(f'heres your key {passed_key} (length: {len(passed_key)})')
     cursor = cnx.cursor()
     if database_type == "postgresql":
-        query = 'SELECT * FROM "APIKeys" WHERE APIKey = %s'
+        query = 'SELECT * FROM "APIKeys" WHERE APIKey = %s AND (Expires IS NULL OR Expires > CURRENT_TIMESTAMP)'
     else:
-        query = "SELECT * FROM APIKeys WHERE APIKey = %s"
+        query = "SELECT * FROM APIKeys WHERE APIKey = %s AND (Expires IS NULL OR Expires > CURRENT_TIMESTAMP)"
 
     try:
         cursor.execute(query, (passed_key,))
@@ -2964,11 +3146,14 @@ def get_api_key(cnx, database_type, username):
     # is_admin = is_admin_result[0] if isinstance(is_admin_result, tuple) else is_admin_result["IsAdmin"] if is_admin_result else 0
 
 
-        # Get the API Key using the fetched UserID, and limit the results to 1
+        # Get the API Key using the fetched UserID, and limit the results to 1. Logins need a
+        # full-access key that won't expire, never one the user made for something else
         if database_type == "postgresql":
-            query = 'SELECT APIKey FROM "APIKeys" WHERE UserID = %s LIMIT 1'
+            query = ('SELECT APIKey FROM "APIKeys" WHERE UserID = %s AND (Scope IS NULL OR Scope = \'admin\') '
+                     'AND Expires IS NULL ORDER BY APIKeyID LIMIT 1')
         else:  # MySQL or MariaDB
-            query = "SELECT APIKey FROM APIKeys WHERE UserID = %s LIMIT 1"
+            query = ("SELECT APIKey FROM APIKeys WHERE UserID = %s AND (Scope IS NULL OR Scope = 'admin') "
+                     "AND Expires IS NULL ORDER BY APIKeyID LIMIT 1")
         cursor.execute(query, (user_id,))
         result = cursor.fetchone()
 
@@ -2988,6 +3173,23 @@ def get_api_key(cnx, database_type, username):
         return f"An error occurred: {str(e)}"
 
 
+def get_username_from_email(cnx, database_type, email):
+    cursor = cnx.cursor()
+    if database_type == "postgresql":
+        query = 'SELECT Username FROM "Users" WHERE LOWER(Email) = LOWER(%s)'
+    else:  # MySQL or MariaDB
+        query = "SELECT Username FROM Users WHERE LOWER(Email) = LOWER(%s)"
+    cursor.execute(query, (email,))
+    results = cursor.fetchall()
+    cursor.close()
+
+    # An address shared by several accounts can't say which one is signing in
+    if len(results) != 1:
+        return None
+    result = results[0]
+    return result[0] if isinstance(result, tuple) else result["username"]
+
+
 def get_api_user(cnx, database_type, api_key):
     try:
         cursor = cnx.cursor()
@@ -3083,9 +3285,9 @@ def get_stats(cnx, database_type, user_id):
     logging.info(f"Fetching stats for user ID: {user_id}, database type: {database_type}")
     cursor = cnx.cursor()
     if database_type == "postgresql":
-        query = 'SELECT UserCreated, PodcastsPlayed, TimeListened, PodcastsAdded, EpisodesSaved, EpisodesDownloaded FROM "UserStats" WHERE UserID = %s'
+        query = 'SELECT UserCreated, PodcastsPlayed, TimeListened, PodcastsAdded, EpisodesSaved, EpisodesDownloaded, TimeSaved FROM "UserStats" WHERE UserID = %s'
     else:  # MySQL or MariaDB
-        query = "SELECT UserCreated, PodcastsPlayed, TimeListened, PodcastsAdded, EpisodesSaved, EpisodesDownloaded FROM UserStats WHERE UserID = %s"
+        query = "SELECT UserCreated, PodcastsPlayed, TimeListened, PodcastsAdded, EpisodesSaved, EpisodesDownloaded, TimeSaved FROM UserStats WHERE UserID = %s"
     print('gettings stats')
     cursor.execute(query, (user_id,))
     results = cursor.fetchall()
@@ -3107,7 +3309,8 @@ def get_stats(cnx, database_type, user_id):
                 "TimeListened": result['timelistened'],
                 "PodcastsAdded": result['podcastsadded'],
                 "EpisodesSaved": result['episodessaved'],
-                "EpisodesDownloaded": result['episodesdownloaded']
+                "EpisodesDownloaded": result['episodesdownloaded'],
+                "TimeSaved": result['timesaved']
             }
         else:
             stats = {
@@ -3116,7 +3319,8 @@ def get_stats(cnx, database_type, user_id):
                 "TimeListened": result['TimeListened'],
                 "PodcastsAdded": result['PodcastsAdded'],
                 "EpisodesSaved": result['EpisodesSaved'],
-                "EpisodesDownloaded": result['EpisodesDownloaded']
+                "EpisodesDownloaded": result['EpisodesDownloaded'],
+                "TimeSaved": result['TimeSaved']
             }
     else:  # Assume it's a tuple
         stats = {
@@ -3125,7 +3329,8 @@ def get_stats(cnx, database_type, user_id):
             "TimeListened": result[2],
             "PodcastsAdded": result[3],
             "EpisodesSaved": result[4],
-            "EpisodesDownloaded": result[5]
+            "EpisodesDownloaded": result[5],
+            "TimeSaved": result[6]
         }
     logging.info(f"Fetched stats: {stats}")
 
@@ -3197,6 +3402,491 @@ def save_episode(cnx, database_type, episode_id, user_id):
 
 
 
+def add_bookmark(cnx, database_type, user_id, episode_id, time, note):
+    """Bookmark a moment in one of the user's episodes. Raises ValueError if the episode isn't theirs."""
+    if not user_owns_episode(cnx, database_type, user_id, episode_id):
+        raise ValueError("Episode not found.")
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'INSERT INTO "Bookmarks" (UserID, EpisodeID, BookmarkTime, Note) VALUES (%s, %s, %s, %s) RETURNING BookmarkID'
+            cursor.execute(query, (user_id, episode_id, time, note))
+            result = cursor.fetchone()
+            bookmark_id = result["bookmarkid"] if isinstance(result, dict) else result[0]
+        else:  # MySQL or MariaDB
+            query = "INSERT INTO Bookmarks (UserID, EpisodeID, BookmarkTime, Note) VALUES (%s, %s, %s, %s)"
+            cursor.execute(query, (user_id, episode_id, time, note))
+            bookmark_id = cursor.lastrowid
+        cnx.commit()
+        return bookmark_id
+    except Exception as e:
+        cnx.rollback()
+        raise e
+    finally:
+        cursor.close()
+
+
+def get_bookmarks(cnx, database_type, user_id, episode_id):
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'SELECT BookmarkID, EpisodeID, BookmarkTime, Note FROM "Bookmarks" WHERE UserID = %s AND EpisodeID = %s ORDER BY BookmarkTime'
+        else:  # MySQL or MariaDB
+            query = "SELECT BookmarkID, EpisodeID, BookmarkTime, Note FROM Bookmarks WHERE UserID = %s AND EpisodeID = %s ORDER BY BookmarkTime"
+        cursor.execute(query, (user_id, episode_id))
+        bookmarks = []
+        for row in cursor.fetchall():
+            if isinstance(row, dict):
+                row = {k.lower(): v for k, v in row.items()}
+                bookmarks.append({
+                    "bookmark_id": row["bookmarkid"],
+                    "episode_id": row["episodeid"],
+                    "time": row["bookmarktime"],
+                    "note": row["note"],
+                })
+            else:
+                bookmarks.append({
+                    "bookmark_id": row[0],
+                    "episode_id": row[1],
+                    "time": row[2],
+                    "note": row[3],
+                })
+        return bookmarks
+    finally:
+        cursor.close()
+
+
+def delete_bookmark(cnx, database_type, user_id, bookmark_id):
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'DELETE FROM "Bookmarks" WHERE BookmarkID = %s AND UserID = %s'
+        else:  # MySQL or MariaDB
+            query = "DELETE FROM Bookmarks WHERE BookmarkID = %s AND UserID = %s"
+        cursor.execute(query, (bookmark_id, user_id))
+        deleted = cursor.rowcount > 0
+        cnx.commit()
+        return deleted
+    finally:
+        cursor.close()
+
+
+PLAYLIST_PLAY_STATES = ("any", "unplayed", "in_progress", "played")
+PLAYLIST_SORTS = {
+    "newest": "E.EpisodePubDate DESC",
+    "oldest": "E.EpisodePubDate ASC",
+    "shortest": "E.EpisodeDuration ASC",
+    "longest": "E.EpisodeDuration DESC",
+}
+SMART_PLAYLIST_MAX_EPISODES = 500
+
+
+def normalize_playlist_rules(rules):
+    """Fill in defaults and drop anything a smart playlist doesn't understand."""
+    rules = rules or {}
+    play_state = rules.get("play_state") or "any"
+    sort = rules.get("sort") or "newest"
+    if play_state not in PLAYLIST_PLAY_STATES:
+        raise ValueError(f"Unknown play state: {play_state}")
+    if sort not in PLAYLIST_SORTS:
+        raise ValueError(f"Unknown sort order: {sort}")
+
+    def optional_int(key):
+        value = rules.get(key)
+        if value is None or value == "":
+            return None
+        value = int(value)
+        if value < 0:
+            raise ValueError(f"{key} can't be negative")
+        return value
+
+    limit = optional_int("limit") or 100
+    return {
+        "play_state": play_state,
+        "min_minutes": optional_int("min_minutes"),
+        "max_minutes": optional_int("max_minutes"),
+        "released_within_days": optional_int("released_within_days"),
+        "category": (rules.get("category") or "").strip() or None,
+        "podcast_id": optional_int("podcast_id"),
+        "sort": sort,
+        "limit": min(limit, SMART_PLAYLIST_MAX_EPISODES),
+    }
+
+
+def playlist_cursor(cnx, database_type):
+    if database_type == "postgresql":
+        return cnx.cursor(row_factory=dict_row)
+    return cnx.cursor(dictionary=True)
+
+
+def playlist_tables(database_type):
+    """Table names quoted the way each database wants them, for the queries built below."""
+    names = ("Playlists", "PlaylistEpisodes", "Episodes", "Podcasts", "UserEpisodeHistory")
+    if database_type == "postgresql":
+        return {name: f'"{name}"' for name in names}
+    return {name: name for name in names}
+
+
+def playlist_row(row):
+    row = {k.lower(): v for k, v in row.items()}
+    rules = json.loads(row["rules"]) if row.get("rules") else None
+    return {
+        "playlist_id": row["playlistid"],
+        "name": row["name"],
+        "is_smart": bool(row["issmart"]),
+        "rules": rules,
+    }
+
+
+def get_playlist(cnx, database_type, user_id, playlist_id):
+    t = playlist_tables(database_type)
+    cursor = playlist_cursor(cnx, database_type)
+    try:
+        cursor.execute(
+            f"SELECT PlaylistID, Name, IsSmart, Rules FROM {t['Playlists']} WHERE PlaylistID = %s AND UserID = %s",
+            (playlist_id, user_id),
+        )
+        row = cursor.fetchone()
+        return playlist_row(row) if row else None
+    finally:
+        cursor.close()
+
+
+def get_playlists(cnx, database_type, user_id):
+    t = playlist_tables(database_type)
+    cursor = playlist_cursor(cnx, database_type)
+    try:
+        cursor.execute(
+            f"SELECT PlaylistID, Name, IsSmart, Rules FROM {t['Playlists']} WHERE UserID = %s ORDER BY Name",
+            (user_id,),
+        )
+        playlists = [playlist_row(row) for row in cursor.fetchall()]
+    finally:
+        cursor.close()
+
+    counts = count_playlist_episodes(cnx, database_type, user_id, playlists)
+    for playlist in playlists:
+        playlist["episode_count"] = counts.get(playlist["playlist_id"], 0)
+    return playlists
+
+
+def create_playlist(cnx, database_type, user_id, name, rules):
+    t = playlist_tables(database_type)
+    is_smart = rules is not None
+    rules_json = json.dumps(rules) if is_smart else None
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            cursor.execute(
+                f"INSERT INTO {t['Playlists']} (UserID, Name, IsSmart, Rules) VALUES (%s, %s, %s, %s) RETURNING PlaylistID",
+                (user_id, name, is_smart, rules_json),
+            )
+            result = cursor.fetchone()
+            playlist_id = result["playlistid"] if isinstance(result, dict) else result[0]
+        else:  # MySQL or MariaDB
+            cursor.execute(
+                f"INSERT INTO {t['Playlists']} (UserID, Name, IsSmart, Rules) VALUES (%s, %s, %s, %s)",
+                (user_id, name, int(is_smart), rules_json),
+            )
+            playlist_id = cursor.lastrowid
+        cnx.commit()
+        return playlist_id
+    except Exception as e:
+        cnx.rollback()
+        raise e
+    finally:
+        cursor.close()
+
+
+def update_playlist(cnx, database_type, user_id, playlist_id, name, rules):
+    """Rename a playlist and, for smart playlists, replace its rules. A playlist keeps its kind."""
+    t = playlist_tables(database_type)
+    cursor = cnx.cursor()
+    try:
+        cursor.execute(
+            f"UPDATE {t['Playlists']} SET Name = %s, Rules = CASE WHEN IsSmart THEN %s ELSE NULL END "
+            "WHERE PlaylistID = %s AND UserID = %s",
+            (name, json.dumps(rules) if rules is not None else None, playlist_id, user_id),
+        )
+        updated = cursor.rowcount > 0
+        cnx.commit()
+        return updated
+    finally:
+        cursor.close()
+
+
+def delete_playlist(cnx, database_type, user_id, playlist_id):
+    t = playlist_tables(database_type)
+    cursor = cnx.cursor()
+    try:
+        cursor.execute(
+            f"DELETE FROM {t['Playlists']} WHERE PlaylistID = %s AND UserID = %s",
+            (playlist_id, user_id),
+        )
+        deleted = cursor.rowcount > 0
+        cnx.commit()
+        return deleted
+    finally:
+        cursor.close()
+
+
+def playlist_episode_source(database_type, user_id, playlist):
+    """The FROM/WHERE part of a playlist's episode query, its parameters and its ORDER BY/LIMIT."""
+    t = playlist_tables(database_type)
+    joins = (
+        f"INNER JOIN {t['Podcasts']} P ON E.PodcastID = P.PodcastID "
+        f"LEFT JOIN {t['UserEpisodeHistory']} H ON E.EpisodeID = H.EpisodeID AND H.UserID = %s "
+    )
+    params = [user_id]
+
+    if not playlist["is_smart"]:
+        source = (
+            f"FROM {t['PlaylistEpisodes']} PE "
+            f"INNER JOIN {t['Episodes']} E ON PE.EpisodeID = E.EpisodeID " + joins +
+            "WHERE PE.PlaylistID = %s AND P.UserID = %s"
+        )
+        params += [playlist["playlist_id"], user_id]
+        return source, params, "ORDER BY PE.Position", []
+
+    rules = normalize_playlist_rules(playlist["rules"])
+    conditions = ["P.UserID = %s"]
+    params.append(user_id)
+    if rules["play_state"] == "unplayed":
+        conditions.append("E.Completed = FALSE AND COALESCE(H.ListenDuration, 0) = 0")
+    elif rules["play_state"] == "in_progress":
+        conditions.append("E.Completed = FALSE AND H.ListenDuration > 0")
+    elif rules["play_state"] == "played":
+        conditions.append("E.Completed = TRUE")
+    if rules["min_minutes"] is not None:
+        conditions.append("E.EpisodeDuration >= %s")
+        params.append(rules["min_minutes"] * 60)
+    if rules["max_minutes"] is not None:
+        conditions.append("E.EpisodeDuration <= %s")
+        params.append(rules["max_minutes"] * 60)
+    if rules["released_within_days"] is not None:
+        conditions.append("E.EpisodePubDate >= %s")
+        params.append(datetime.datetime.now() - datetime.timedelta(days=rules["released_within_days"]))
+    if rules["category"]:
+        # Categories are stored as a comma separated list on the podcast
+        conditions.append("LOWER(P.Categories) LIKE %s")
+        params.append(search_like(rules["category"]))
+    if rules["podcast_id"] is not None:
+        conditions.append("P.PodcastID = %s")
+        params.append(rules["podcast_id"])
+    source = f"FROM {t['Episodes']} E " + joins + "WHERE " + " AND ".join(conditions)
+    return source, params, f"ORDER BY {PLAYLIST_SORTS[rules['sort']]} LIMIT %s", [rules["limit"]]
+
+
+def get_playlist_episodes(cnx, database_type, user_id, playlist):
+    """Episodes of a playlist in play order. Smart playlists are evaluated against the library on every call."""
+    source, params, order, order_params = playlist_episode_source(database_type, user_id, playlist)
+    query = (
+        "SELECT P.PodcastName, E.EpisodeTitle, E.EpisodePubDate, E.EpisodeDescription, E.EpisodeID, "
+        "E.EpisodeArtwork, E.EpisodeURL, E.EpisodeDuration, H.ListenDuration, E.Completed "
+        f"{source} {order}"
+    )
+
+    cursor = playlist_cursor(cnx, database_type)
+    try:
+        cursor.execute(query, tuple(params + order_params))
+        return lowercase_keys(cursor.fetchall())
+    finally:
+        cursor.close()
+
+
+def count_playlist_episodes(cnx, database_type, user_id, playlists):
+    """Episode counts keyed by playlist ID, counted for all the playlists in one query."""
+    if not playlists:
+        return {}
+    parts = []
+    params = []
+    limits = {}
+    for playlist in playlists:
+        source, source_params, _, order_params = playlist_episode_source(database_type, user_id, playlist)
+        playlist_id = int(playlist["playlist_id"])
+        parts.append(f"SELECT {playlist_id} AS playlistid, COUNT(*) AS episodecount {source}")
+        params += source_params
+        if order_params:
+            limits[playlist_id] = order_params[0]
+    cursor = playlist_cursor(cnx, database_type)
+    try:
+        cursor.execute(" UNION ALL ".join(parts), tuple(params))
+        rows = [{k.lower(): v for k, v in row.items()} for row in cursor.fetchall()]
+    finally:
+        cursor.close()
+    counts = {}
+    for row in rows:
+        playlist_id = int(row["playlistid"])
+        # A smart playlist only ever plays up to its limit
+        counts[playlist_id] = min(row["episodecount"], limits.get(playlist_id, row["episodecount"]))
+    return counts
+
+
+def user_owns_episode(cnx, database_type, user_id, episode_id):
+    """Whether the episode belongs to one of the user's podcasts."""
+    t = playlist_tables(database_type)
+    cursor = cnx.cursor()
+    try:
+        cursor.execute(
+            f"SELECT 1 FROM {t['Episodes']} E INNER JOIN {t['Podcasts']} P ON E.PodcastID = P.PodcastID "
+            "WHERE E.EpisodeID = %s AND P.UserID = %s",
+            (episode_id, user_id),
+        )
+        return cursor.fetchone() is not None
+    finally:
+        cursor.close()
+
+
+def add_episode_to_playlist(cnx, database_type, user_id, playlist_id, episode_id):
+    """Append an episode to a manual playlist. Returns False if it's already there and raises
+    ValueError if the episode isn't one of the user's."""
+    if not user_owns_episode(cnx, database_type, user_id, episode_id):
+        raise ValueError("Episode not found.")
+    t = playlist_tables(database_type)
+    cursor = playlist_cursor(cnx, database_type)
+    try:
+        cursor.execute(
+            f"SELECT 1 FROM {t['PlaylistEpisodes']} WHERE PlaylistID = %s AND EpisodeID = %s",
+            (playlist_id, episode_id),
+        )
+        if cursor.fetchone():
+            return False
+        cursor.execute(
+            f"SELECT COALESCE(MAX(Position), 0) AS maxposition FROM {t['PlaylistEpisodes']} WHERE PlaylistID = %s",
+            (playlist_id,),
+        )
+        row = {k.lower(): v for k, v in cursor.fetchone().items()}
+        cursor.execute(
+            f"INSERT INTO {t['PlaylistEpisodes']} (PlaylistID, EpisodeID, Position) VALUES (%s, %s, %s)",
+            (playlist_id, episode_id, row["maxposition"] + 1),
+        )
+        cnx.commit()
+        return True
+    except Exception as e:
+        cnx.rollback()
+        raise e
+    finally:
+        cursor.close()
+
+
+def remove_episode_from_playlist(cnx, database_type, playlist_id, episode_id):
+    t = playlist_tables(database_type)
+    cursor = cnx.cursor()
+    try:
+        cursor.execute(
+            f"DELETE FROM {t['PlaylistEpisodes']} WHERE PlaylistID = %s AND EpisodeID = %s",
+            (playlist_id, episode_id),
+        )
+        removed = cursor.rowcount > 0
+        cnx.commit()
+        return removed
+    finally:
+        cursor.close()
+
+
+def reorder_playlist_episodes(cnx, database_type, playlist_id, episode_ids):
+    t = playlist_tables(database_type)
+    cursor = cnx.cursor()
+    try:
+        for position, episode_id in enumerate(episode_ids, start=1):
+            cursor.execute(
+                f"UPDATE {t['PlaylistEpisodes']} SET Position = %s WHERE PlaylistID = %s AND EpisodeID = %s",
+                (position, playlist_id, episode_id),
+            )
+        cnx.commit()
+        return True
+    except Exception as e:
+        cnx.rollback()
+        logging.error(f"Error reordering playlist episodes: {e}")
+        return False
+    finally:
+        cursor.close()
+
+
+def add_webauthn_credential(cnx, database_type, user_id, credential_id, public_key, sign_count, name):
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'INSERT INTO "WebAuthnCredentials" (CredentialID, UserID, PublicKey, SignCount, Name) VALUES (%s, %s, %s, %s, %s)'
+        else:  # MySQL or MariaDB
+            query = "INSERT INTO WebAuthnCredentials (CredentialID, UserID, PublicKey, SignCount, Name) VALUES (%s, %s, %s, %s, %s)"
+        cursor.execute(query, (credential_id, user_id, public_key, sign_count, name))
+        cnx.commit()
+    finally:
+        cursor.close()
+
+
+def get_webauthn_credentials(cnx, database_type, user_id):
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'SELECT CredentialID, Name, Created, LastUsed FROM "WebAuthnCredentials" WHERE UserID = %s ORDER BY Created'
+        else:  # MySQL or MariaDB
+            query = "SELECT CredentialID, Name, Created, LastUsed FROM WebAuthnCredentials WHERE UserID = %s ORDER BY Created"
+        cursor.execute(query, (user_id,))
+        credentials = []
+        for row in cursor.fetchall():
+            if isinstance(row, dict):
+                row = {k.lower(): v for k, v in row.items()}
+                row = (row["credentialid"], row["name"], row["created"], row["lastused"])
+            credentials.append({
+                "credential_id": row[0],
+                "name": row[1],
+                "created": row[2].strftime('%Y-%m-%dT%H:%M:%S') if row[2] else None,
+                "last_used": row[3].strftime('%Y-%m-%dT%H:%M:%S') if row[3] else None,
+            })
+        return credentials
+    finally:
+        cursor.close()
+
+
+def get_webauthn_credential(cnx, database_type, credential_id):
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'SELECT UserID, PublicKey, SignCount FROM "WebAuthnCredentials" WHERE CredentialID = %s'
+        else:  # MySQL or MariaDB
+            query = "SELECT UserID, PublicKey, SignCount FROM WebAuthnCredentials WHERE CredentialID = %s"
+        cursor.execute(query, (credential_id,))
+        row = cursor.fetchone()
+        if row is None:
+            return None
+        if isinstance(row, dict):
+            row = {k.lower(): v for k, v in row.items()}
+            row = (row["userid"], row["publickey"], row["signcount"])
+        return {"user_id": row[0], "public_key": row[1], "sign_count": row[2]}
+    finally:
+        cursor.close()
+
+
+def update_webauthn_sign_count(cnx, database_type, credential_id, sign_count):
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'UPDATE "WebAuthnCredentials" SET SignCount = %s, LastUsed = CURRENT_TIMESTAMP WHERE CredentialID = %s'
+        else:  # MySQL or MariaDB
+            query = "UPDATE WebAuthnCredentials SET SignCount = %s, LastUsed = CURRENT_TIMESTAMP WHERE CredentialID = %s"
+        cursor.execute(query, (sign_count, credential_id))
+        cnx.commit()
+    finally:
+        cursor.close()
+
+
+def delete_webauthn_credential(cnx, database_type, user_id, credential_id):
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'DELETE FROM "WebAuthnCredentials" WHERE CredentialID = %s AND UserID = %s'
+        else:  # MySQL or MariaDB
+            query = "DELETE FROM WebAuthnCredentials WHERE CredentialID = %s AND UserID = %s"
+        cursor.execute(query, (credential_id, user_id))
+        deleted = cursor.rowcount > 0
+        cnx.commit()
+        return deleted
+    finally:
+        cursor.close()
+
+
 def check_saved(cnx, database_type, user_id, episode_id):
     cursor = cnx.cursor()
     try:
@@ -3511,6 +4201,21 @@ def increment_listen_time(cnx, database_type, user_id):
     # cnx.close()
 
 
+def increment_time_saved(cnx, database_type, user_id, seconds):
+    cursor = cnx.cursor()
+
+    # Seconds shaved off playback by silence trimming in the player
+    if database_type == "postgresql":
+        query = ('UPDATE "UserStats" SET TimeSaved = COALESCE(TimeSaved, 0) + %s '
+                "WHERE UserID = %s")
+    else:
+        query = ("UPDATE UserStats SET TimeSaved = COALESCE(TimeSaved, 0) + %s "
+                "WHERE UserID = %s")
+    cursor.execute(query, (seconds, user_id))
+    cnx.commit()
+
+    cursor.close()
+
 
 def get_user_episode_count(cnx, database_type, user_id):
     cursor = cnx.cursor()
@@ -3924,7 +4629,7 @@ def get_episode_metadata(database_type, cnx, episode_id, user_id):
         query = (
             'SELECT "Podcasts".PodcastID, "Podcasts".FeedURL, "Podcasts".PodcastName, "Podcasts".ArtworkURL, "Episodes".EpisodeTitle, "Episodes".EpisodePubDate, '
             '"Episodes".EpisodeDescription, "Episodes".EpisodeArtwork, "Episodes".EpisodeURL, "Episodes".EpisodeDuration, "Episodes".EpisodeID, '
-            '"Podcasts".WebsiteURL, "UserEpisodeHistory".ListenDuration, "Episodes".Completed '
+            '"Podcasts".WebsiteURL, "UserEpisodeHistory".ListenDuration, "Episodes".Completed, "Episodes".EnclosureType '
             'FROM "Episodes" '
             'INNER JOIN "Podcasts" ON "Episodes".PodcastID = "Podcasts".PodcastID '
             'LEFT JOIN "UserEpisodeHistory" ON "Episodes".EpisodeID = "UserEpisodeHistory".EpisodeID AND "Podcasts".UserID = "UserEpisodeHistory".UserID '
@@ -3935,7 +4640,7 @@ def get_episode_metadata(database_type, cnx, episode_id, user_id):
         query = (
             "SELECT Podcasts.PodcastID, Podcasts.FeedURL, Podcasts.PodcastName, Podcasts.ArtworkURL, Episodes.EpisodeTitle, Episodes.EpisodePubDate, "
             "Episodes.EpisodeDescription, Episodes.EpisodeArtwork, Episodes.EpisodeURL, Episodes.EpisodeDuration, Episodes.EpisodeID, "
-            "Podcasts.WebsiteURL, UserEpisodeHistory.ListenDuration, Episodes.Completed "
+            "Podcasts.WebsiteURL, UserEpisodeHistory.ListenDuration, Episodes.Completed, Episodes.EnclosureType "
             "FROM Episodes "
             "INNER JOIN Podcasts ON Episodes.PodcastID = Podcasts.PodcastID "
             "LEFT JOIN UserEpisodeHistory ON Episodes.EpisodeID = UserEpisodeHistory.EpisodeID AND Podcasts.UserID = UserEpisodeHistory.UserID "
@@ -3963,7 +4668,7 @@ def get_episode_metadata_id(database_type, cnx, episode_id):
         query = (
             'SELECT "Podcasts".PodcastID, "Podcasts".FeedURL, "Podcasts".PodcastName, "Podcasts".ArtworkURL, "Episodes".EpisodeTitle, "Episodes".EpisodePubDate, '
             '"Episodes".EpisodeDescription, "Episodes".EpisodeArtwork, "Episodes".EpisodeURL, "Episodes".EpisodeDuration, "Episodes".EpisodeID, '
-            '"Podcasts".WebsiteURL, "UserEpisodeHistory".ListenDuration, "Episodes".Completed '
+            '"Podcasts".WebsiteURL, "UserEpisodeHistory".ListenDuration, "Episodes".Completed, "Episodes".EnclosureType '
             'FROM "Episodes" '
             'INNER JOIN "Podcasts" ON "Episodes".PodcastID = "Podcasts".PodcastID '
             'LEFT JOIN "UserEpisodeHistory" ON "Episodes".EpisodeID = "UserEpisodeHistory".EpisodeID AND "Podcasts".UserID = "UserEpisodeHistory".UserID '
@@ -3974,7 +4679,7 @@ def get_episode_metadata_id(database_type, cnx, episode_id):
         query = (
             "SELECT Podcasts.PodcastID, Podcasts.FeedURL, Podcasts.PodcastName, Podcasts.ArtworkURL, Episodes.EpisodeTitle, Episodes.EpisodePubDate, "
             "Episodes.EpisodeDescription, Episodes.EpisodeArtwork, Episodes.EpisodeURL, Episodes.EpisodeDuration, Episodes.EpisodeID, "
-            "Podcasts.WebsiteURL, UserEpisodeHistory.ListenDuration, Episodes.Completed "
+            "Podcasts.WebsiteURL, UserEpisodeHistory.ListenDuration, Episodes.Completed, Episodes.EnclosureType "
             "FROM Episodes "
             "INNER JOIN Podcasts ON Episodes.PodcastID = Podcasts.PodcastID "
             "LEFT JOIN UserEpisodeHistory ON Episodes.EpisodeID = UserEpisodeHistory.EpisodeID AND Podcasts.UserID = UserEpisodeHistory.UserID "
@@ -4096,6 +4801,54 @@ def delete_mfa_secret(database_type, cnx, user_id):
 
 
 
+def replace_mfa_recovery_codes(database_type, cnx, user_id, code_hashes):
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            delete_query = 'DELETE FROM "MFARecoveryCodes" WHERE UserID = %s'
+            insert_query = 'INSERT INTO "MFARecoveryCodes" (UserID, CodeHash) VALUES (%s, %s)'
+        else:  # MySQL or MariaDB
+            delete_query = "DELETE FROM MFARecoveryCodes WHERE UserID = %s"
+            insert_query = "INSERT INTO MFARecoveryCodes (UserID, CodeHash) VALUES (%s, %s)"
+        cursor.execute(delete_query, (user_id,))
+        for code_hash in code_hashes:
+            cursor.execute(insert_query, (user_id, code_hash))
+        cnx.commit()
+    finally:
+        cursor.close()
+
+
+def use_mfa_recovery_code(database_type, cnx, user_id, code_hash):
+    """Spend one of the user's recovery codes. Returns False if it isn't one of theirs or was already used."""
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'DELETE FROM "MFARecoveryCodes" WHERE UserID = %s AND CodeHash = %s'
+        else:  # MySQL or MariaDB
+            query = "DELETE FROM MFARecoveryCodes WHERE UserID = %s AND CodeHash = %s"
+        cursor.execute(query, (user_id, code_hash))
+        cnx.commit()
+        return cursor.rowcount > 0
+    finally:
+        cursor.close()
+
+
+def count_mfa_recovery_codes(database_type, cnx, user_id):
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'SELECT COUNT(*) AS remaining FROM "MFARecoveryCodes" WHERE UserID = %s'
+        else:  # MySQL or MariaDB
+            query = "SELECT COUNT(*) AS remaining FROM MFARecoveryCodes WHERE UserID = %s"
+        cursor.execute(query, (user_id,))
+        result = cursor.fetchone()
+        if isinstance(result, dict):
+            return result["remaining"]
+        return result[0] if result else 0
+    finally:
+        cursor.close()
+
+
 def get_all_episodes(database_type, cnx, pod_feed):
     if database_type == "postgresql":
         from psycopg.rows import dict_row
@@ -4279,9 +5032,31 @@ def delete_selected_episodes(cnx, database_type, selected_episodes, user_id):
 
 
 
+def user_podcast_ids(cnx, database_type, user_id, podcast_ids):
+    """The IDs out of podcast_ids that are podcasts the user is subscribed to."""
+    if not podcast_ids:
+        return []
+    cursor = cnx.cursor()
+    try:
+        if database_type == "postgresql":
+            query = 'SELECT PodcastID FROM "Podcasts" WHERE UserID = %s AND PodcastID = ANY(%s)'
+            cursor.execute(query, (user_id, list(podcast_ids)))
+        else:  # MySQL or MariaDB
+            placeholders = ", ".join(["%s"] * len(podcast_ids))
+            query = f"SELECT PodcastID FROM Podcasts WHERE UserID = %s AND PodcastID IN ({placeholders})"
+            cursor.execute(query, (user_id, *podcast_ids))
+        owned = {row["podcastid"] if isinstance(row, dict) else row[0] for row in cursor.fetchall()}
+    finally:
+        cursor.close()
+    return [podcast_id for podcast_id in podcast_ids if podcast_id in owned]
+
+
 def delete_selected_podcasts(cnx, database_type, delete_list, user_id):
+    """Unsubscribe the user from the podcasts in delete_list, deleting their downloaded files too.
+    Podcasts that aren't the user's are skipped. Returns the IDs that were removed."""
+    removed = []
     cursor = cnx.cursor()
-    for podcast_id in delete_list:
+    for podcast_id in user_podcast_ids(cnx, database_type, user_id, delete_list):
         # Get the download IDs and locations from the DownloadedEpisodes table
         query = (
             'SELECT "DownloadedEpisodes".DownloadID, "DownloadedEpisodes".DownloadedLocation '
@@ -4295,17 +5070,15 @@ def delete_selected_podcasts(cnx, database_type, delete_list, user_id):
         )
         cursor.execute(query, (podcast_id, user_id))
 
-        results = cursor.fetchall()
-
-        if not results:
-            print(f"No matching downloads found for podcast ID {podcast_id}")
-            continue
-
-        for result in results:
-            download_id, downloaded_location = result
+        for result in cursor.fetchall():
+            if isinstance(result, dict):
+                download_id, downloaded_location = result["downloadid"], result["downloadedlocation"]
+            else:
+                download_id, downloaded_location = result
 
             # Delete the downloaded file
-            os.remove(downloaded_location)
+            if os.path.exists(downloaded_location):
+                os.remove(downloaded_location)
 
             # Remove the entry from the DownloadedEpisodes table
             query = (
@@ -4313,8 +5086,6 @@ def delete_selected_podcasts(cnx, database_type, delete_list, user_id):
                 "DELETE FROM DownloadedEpisodes WHERE DownloadID = %s"
             )
             cursor.execute(query, (download_id,))
-            cnx.commit()
-            print(f"Removed {cursor.rowcount} entry from the DownloadedEpisodes table.")
 
             # Update UserStats table to decrement EpisodesDownloaded count
             query = (
@@ -4323,9 +5094,13 @@ def delete_selected_podcasts(cnx, database_type, delete_list, user_id):
                 "UPDATE UserStats SET EpisodesDownloaded = EpisodesDownloaded - 1 WHERE UserID = %s"
             )
             cursor.execute(query, (user_id,))
+        cnx.commit()
+
+        remove_podcast_id(cnx, database_type, podcast_id, user_id)
+        removed.append(podcast_id)
 
     cursor.close()
-    return "success"
+    return removed
 
 
 
@@ -4333,60 +5108,268 @@ import time
 
 
 
-def search_data(database_type, cnx, search_term, user_id):
-    if database_type == "postgresql":
-        from psycopg.rows import dict_row
-        cnx.row_factory = dict_row
-        cursor = cnx.cursor()
-        query = (
-            'SELECT * FROM "Podcasts" '
-            'INNER JOIN "Episodes" ON "Podcasts".PodcastID = "Episodes".PodcastID '
-            'WHERE "Podcasts".UserID = %s AND '
-            '"Episodes".EpisodeTitle ILIKE %s'
-        )
-    else:  # MySQL or MariaDB
-        cursor = cnx.cursor(dictionary=True)
-        query = (
-            "SELECT * FROM Podcasts "
-            "INNER JOIN Episodes ON Podcasts.PodcastID = Episodes.PodcastID "
-            "WHERE Podcasts.UserID = %s AND "
-            "Episodes.EpisodeTitle LIKE %s"
-        )
+SEARCH_LISTEN_STATES = ("any", "unplayed", "in_progress", "played")
+SEARCH_FIELDS = {
+    "title": "E.EpisodeTitle",
+    "notes": "E.EpisodeDescription",
+    "transcript": "T.TranscriptText",
+}
+SEARCH_MAX_PAGE_SIZE = 100
+SEARCH_SNIPPET_RADIUS = 80
+# A token is an optional -, an optional qualifier: and either a "quoted phrase" or a bare word
+SEARCH_TOKEN = re.compile(r'(-?)(?:(\w+):)?(?:"([^"]*)"|(\S+))')
 
-    # Add wildcards for the LIKE clause
-    search_term = f"%{search_term}%"
 
+def parse_search_date(value):
+    if isinstance(value, datetime.date):
+        return value
+    try:
+        return datetime.date.fromisoformat(value)
+    except ValueError:
+        raise ValueError(f"Dates must be written as YYYY-MM-DD, got '{value}'")
+
+
+def parse_search_query(search_term):
+    """Split a search box query into free text and field qualifiers.
+
+    Plain terms are ANDed, OR between two terms puts them in the same group, a leading -
+    excludes a term and double quotes keep a phrase together. Qualifiers are podcast:,
+    person:, before:, after: (YYYY-MM-DD), is:played|unplayed|in_progress, longer: and
+    shorter: (minutes) and in:title|notes|transcript. Unknown qualifiers are searched as text.
+    """
+    query = {
+        "groups": [],
+        "exclude": [],
+        "podcast": None,
+        "person": None,
+        "before": None,
+        "after": None,
+        "listen_state": None,
+        "min_minutes": None,
+        "max_minutes": None,
+        "fields": [],
+    }
+    join_next = False
+    for negate, qualifier, quoted, bare in SEARCH_TOKEN.findall(search_term or ""):
+        value = (quoted or bare).strip()
+        if not value:
+            continue
+        qualifier = qualifier.lower()
+        if qualifier in ("podcast", "person"):
+            query[qualifier] = value
+            continue
+        if qualifier in ("before", "after"):
+            query[qualifier] = parse_search_date(value)
+            continue
+        if qualifier == "is":
+            if value.lower() not in SEARCH_LISTEN_STATES:
+                raise ValueError(f"is: must be one of {', '.join(SEARCH_LISTEN_STATES[1:])}")
+            query["listen_state"] = value.lower()
+            continue
+        if qualifier in ("longer", "shorter"):
+            if not value.isdigit():
+                raise ValueError(f"{qualifier}: takes a number of minutes")
+            query["min_minutes" if qualifier == "longer" else "max_minutes"] = int(value)
+            continue
+        if qualifier == "in":
+            if value.lower() not in SEARCH_FIELDS:
+                raise ValueError(f"in: must be one of {', '.join(SEARCH_FIELDS)}")
+            query["fields"].append(value.lower())
+            continue
+        if qualifier:
+            value = f"{qualifier}:{value}"
+        elif not quoted and value == "OR":
+            join_next = bool(query["groups"])
+            continue
+
+        if negate:
+            query["exclude"].append(value)
+        elif join_next:
+            query["groups"][-1].append(value)
+        else:
+            query["groups"].append([value])
+        join_next = False
+    return query
+
+
+def search_like(value):
+    # Escape LIKE wildcards so a search for 100% only matches the literal text
+    escaped = value.lower().replace("\\", "\\\\").replace("%", "\\%").replace("_", "\\_")
+    return f"%{escaped}%"
+
+
+def search_plain_text(text):
+    text = html.unescape(re.sub(r"<[^>]+>", " ", text or ""))
+    return re.sub(r"\s+", " ", text).strip()
+
+
+def search_snippet(text, terms):
+    """The part of text around the first matching term, trimmed to a couple of lines."""
+    lowered = text.lower()
+    positions = [lowered.find(term.lower()) for term in terms]
+    positions = [position for position in positions if position >= 0]
+    if not positions:
+        return None
+    first = min(positions)
+    start = max(0, first - SEARCH_SNIPPET_RADIUS)
+    end = min(len(text), first + SEARCH_SNIPPET_RADIUS * 2)
+    snippet = text[start:end].strip()
+    return ("…" if start > 0 else "") + snippet + ("…" if end < len(text) else "")
+
+
+def search_data(database_type, cnx, search_term, user_id, filters=None, page=1, page_size=25):
+    """Structured search over the user's episodes.
+
+    The query string is parsed by parse_search_query; anything set in filters overrides the
+    matching qualifier. Titles, show notes and any transcripts stored by fetch_transcript are
+    searched unless the fields are narrowed down. There is no people index, so person: matches
+    the podcast author and mentions in the show notes. Returns one page of results together
+    with the total count and the terms to highlight.
+    """
+    query = parse_search_query(search_term)
+    for key, value in (filters or {}).items():
+        if value not in (None, "", []):
+            query[key] = value
+    for key in ("before", "after"):
+        if query[key] is not None:
+            query[key] = parse_search_date(query[key])
+    if query["listen_state"] not in (None, *SEARCH_LISTEN_STATES):
+        raise ValueError(f"listen_state must be one of {', '.join(SEARCH_LISTEN_STATES)}")
+    fields = [field for field in SEARCH_FIELDS if field in query["fields"]] or list(SEARCH_FIELDS)
+    page = max(1, page)
+    page_size = min(max(1, page_size), SEARCH_MAX_PAGE_SIZE)
+
+    t = playlist_tables(database_type)
+    transcripts = '"EpisodeTranscripts"' if database_type == "postgresql" else "EpisodeTranscripts"
+    joins = (
+        f"FROM {t['Podcasts']} P INNER JOIN {t['Episodes']} E ON P.PodcastID = E.PodcastID "
+        f"LEFT JOIN {t['UserEpisodeHistory']} H ON E.EpisodeID = H.EpisodeID AND H.UserID = %s "
+        f"LEFT JOIN {transcripts} T ON E.EpisodeID = T.EpisodeID "
+    )
+    conditions = ["P.UserID = %s"]
+    params = [user_id, user_id]
+
+    def term_condition(term, columns):
+        params.extend([search_like(term)] * len(columns))
+        return "(" + " OR ".join(f"LOWER(COALESCE({column}, '')) LIKE %s" for column in columns) + ")"
+
+    columns = [SEARCH_FIELDS[field] for field in fields]
+    for group in query["groups"]:
+        conditions.append("(" + " OR ".join(term_condition(term, columns) for term in group) + ")")
+    for term in query["exclude"]:
+        conditions.append("NOT " + term_condition(term, columns))
+    if query["podcast"]:
+        conditions.append(term_condition(query["podcast"], ["P.PodcastName"]))
+    if query["person"]:
+        conditions.append(term_condition(query["person"], ["P.Author", "E.EpisodeDescription"]))
+    if query["before"] is not None:
+        conditions.append("E.EpisodePubDate < %s")
+        params.append(query["before"])
+    if query["after"] is not None:
+        conditions.append("E.EpisodePubDate >= %s")
+        params.append(query["after"])
+    if query["listen_state"] == "unplayed":
+        conditions.append("E.Completed = FALSE AND COALESCE(H.ListenDuration, 0) = 0")
+    elif query["listen_state"] == "in_progress":
+        conditions.append("E.Completed = FALSE AND H.ListenDuration > 0")
+    elif query["listen_state"] == "played":
+        conditions.append("E.Completed = TRUE")
+    if query["min_minutes"] is not None:
+        conditions.append("E.EpisodeDuration >= %s")
+        params.append(int(query["min_minutes"]) * 60)
+    if query["max_minutes"] is not None:
+        conditions.append("E.EpisodeDuration <= %s")
+        params.append(int(query["max_minutes"]) * 60)
+    where = "WHERE " + " AND ".join(conditions) + " "
+
+    cursor = playlist_cursor(cnx, database_type)
     try:
         start = time.time()
-        logging.info(f"Executing query: {query}")
-        logging.info(f"Search term: {search_term}, User ID: {user_id}")
-        cursor.execute(query, (user_id, search_term))
-        result = cursor.fetchall()
-        end = time.time()
-        logging.info(f"Query executed in {end - start} seconds.")
-        logging.info(f"Query result: {result}")
+        cursor.execute("SELECT COUNT(*) AS total " + joins + where, tuple(params))
+        total = {k.lower(): v for k, v in cursor.fetchone().items()}["total"]
+        cursor.execute(
+            "SELECT P.PodcastID, P.PodcastName, P.ArtworkURL, P.Author, P.Categories, P.Description, "
+            "P.EpisodeCount, P.FeedURL, P.WebsiteURL, P.Explicit, P.UserID, E.EpisodeID, E.EpisodeTitle, "
+            "E.EpisodeDescription, E.EpisodeURL, E.EpisodeArtwork, E.EpisodePubDate, E.EpisodeDuration, "
+            "H.ListenDuration, E.Completed, T.TranscriptText " + joins + where +
+            "ORDER BY E.EpisodePubDate DESC LIMIT %s OFFSET %s",
+            tuple(params + [page_size, (page - 1) * page_size]),
+        )
+        rows = lowercase_keys(cursor.fetchall())
+        logging.info(f"Search for user {user_id} matched {total} episodes in {time.time() - start} seconds.")
+    finally:
         cursor.close()
 
-        if not result:
-            return []
+    terms = [term for group in query["groups"] for term in group]
+    for row in rows:
+        # Postgres hands back a real boolean, the client expects 0 or 1 like MySQL sends
+        row["explicit"] = 1 if row.get("explicit") else 0
+        texts = {
+            "title": row["episodetitle"] or "",
+            "notes": search_plain_text(row["episodedescription"]),
+            "transcript": row.pop("transcripttext", None) or "",
+        }
+        row["matched_in"] = [
+            field for field in fields
+            if any(term.lower() in texts[field].lower() for term in terms)
+        ]
+        snippet_field = next((field for field in ("transcript", "notes") if field in row["matched_in"]), None)
+        row["snippet"] = search_snippet(texts[snippet_field], terms) if snippet_field else None
 
-        # Convert column names to lowercase for MySQL
-        result = lowercase_keys(result)
+    return {"data": rows, "total": total, "page": page, "page_size": page_size, "terms": terms}
 
-        # Post-process the results to cast boolean to integer for the 'explicit' field
-        if database_type == "postgresql":
-            for row in result:
-                if 'explicit' in row:
-                    row['explicit'] = 1 if row['explicit'] else 0
 
-        return result
-    except Exception as e:
-        logging.error(f"Error retrieving Podcast Episodes: {e}")
-        return None
+def transcript_plain_text(content, media_type):
+    """Reduce a transcript in any of the podcast namespace formats to searchable plain text."""
+    if "json" in (media_type or "") or content.lstrip().startswith("{"):
+        try:
+            segments = json.loads(content).get("segments", [])
+            return " ".join(str(segment.get("body", "")).strip() for segment in segments)
+        except (ValueError, AttributeError):
+            pass
+    lines = []
+    for line in content.splitlines():
+        line = line.strip()
+        # Skip the WEBVTT header, cue numbers and timestamp lines of VTT and SRT files
+        if not line or line.startswith("WEBVTT") or line.isdigit() or "-->" in line:
+            continue
+        lines.append(line)
+    return search_plain_text(" ".join(lines))
 
 
+def store_episode_transcript(cnx, database_type, user_id, episode_id, text):
+    """Keep the transcript text of one of the user's episodes so search can find it.
 
-def queue_pod(database_type, cnx, episode_id, user_id):
+    Only pass text fetched from one of the transcript URLs the episode's own feed lists.
+    """
+    t = playlist_tables(database_type)
+    if database_type == "postgresql":
+        query = (
+            'INSERT INTO "EpisodeTranscripts" (EpisodeID, TranscriptText) '
+            f"SELECT E.EpisodeID, %s FROM {t['Episodes']} E "
+            f"INNER JOIN {t['Podcasts']} P ON E.PodcastID = P.PodcastID "
+            "WHERE E.EpisodeID = %s AND P.UserID = %s "
+            "ON CONFLICT (EpisodeID) DO UPDATE SET TranscriptText = EXCLUDED.TranscriptText, "
+            "Fetched = CURRENT_TIMESTAMP"
+        )
+    else:
+        query = (
+            "INSERT INTO EpisodeTranscripts (EpisodeID, TranscriptText) "
+            f"SELECT E.EpisodeID, %s FROM {t['Episodes']} E "
+            f"INNER JOIN {t['Podcasts']} P ON E.PodcastID = P.PodcastID "
+            "WHERE E.EpisodeID = %s AND P.UserID = %s "
+            "ON DUPLICATE KEY UPDATE TranscriptText = VALUES(TranscriptText), Fetched = CURRENT_TIMESTAMP"
+        )
+    cursor = cnx.cursor()
+    try:
+        cursor.execute(query, (text, episode_id, user_id))
+        cnx.commit()
+        return cursor.rowcount > 0
+    finally:
+        cursor.close()
+
+
+def queue_pod(database_type, cnx, episode_id, user_id, position="last"):
     if database_type == "postgresql":
         from psycopg.rows import dict_row
         cnx.row_factory = dict_row
@@ -4395,16 +5378,23 @@ def queue_pod(database_type, cnx, episode_id, user_id):
             'SELECT MAX(QueuePosition) AS max_pos FROM "EpisodeQueue" '
             'WHERE UserID = %s'
         )
+        query_shift_queue = 'UPDATE "EpisodeQueue" SET QueuePosition = QueuePosition + 1 WHERE UserID = %s'
     else:  # MySQL or MariaDB
         cursor = cnx.cursor(dictionary=True)
         query_get_max_pos = (
             "SELECT MAX(QueuePosition) AS max_pos FROM EpisodeQueue "
             "WHERE UserID = %s"
         )
+        query_shift_queue = "UPDATE EpisodeQueue SET QueuePosition = QueuePosition + 1 WHERE UserID = %s"
 
-    cursor.execute(query_get_max_pos, (user_id,))
-    result = cursor.fetchone()
-    max_pos = result['max_pos'] if result['max_pos'] else 0
+    if position == "next":
+        # Make room at the head of the queue so this episode is the next one played
+        cursor.execute(query_shift_queue, (user_id,))
+        max_pos = 0
+    else:
+        cursor.execute(query_get_max_pos, (user_id,))
+        result = cursor.fetchone()
+        max_pos = result['max_pos'] if result['max_pos'] else 0
 
     # Insert the new episode into the queue
     query_queue_pod = (
@@ -4655,22 +5645,22 @@ def check_episode_exists(cnx, database_type, user_id, episode_title, episode_url
         raise TypeError("Unexpected type for 'result'")
 
 
-def add_shared_episode(database_type, cnx, episode_id, url_key, expiration_date):
+def add_shared_episode(database_type, cnx, episode_id, url_key, expiration_date, start_time=None, end_time=None):
     cursor = cnx.cursor()
 
     if database_type == "postgresql":
         query = '''
-            INSERT INTO "SharedEpisodes" (EpisodeID, UrlKey, ExpirationDate)
-            VALUES (%s, %s, %s)
+            INSERT INTO "SharedEpisodes" (EpisodeID, UrlKey, ExpirationDate, StartTime, EndTime)
+            VALUES (%s, %s, %s, %s, %s)
         '''
     else:  # MySQL/MariaDB version
         query = '''
-            INSERT INTO SharedEpisodes (EpisodeID, UrlKey, ExpirationDate)
-            VALUES (%s, %s, %s)
+            INSERT INTO SharedEpisodes (EpisodeID, UrlKey, ExpirationDate, StartTime, EndTime)
+            VALUES (%s, %s, %s, %s, %s)
         '''
 
     try:
-        cursor.execute(query, (episode_id, url_key, expiration_date))
+        cursor.execute(query, (episode_id, url_key, expiration_date, start_time, end_time))
         cnx.commit()  # Commit the changes
         cursor.close()
         return True
@@ -4719,6 +5709,33 @@ def get_episode_id_by_url_key(database_type, cnx, url_key):
         return None
 
 
+def get_shared_episode_clip(database_type, cnx, url_key):
+    """Returns the (start_time, end_time) a share link was created with. Either may be None."""
+    cursor = cnx.cursor()
+
+    query = '''
+        SELECT StartTime, EndTime FROM "SharedEpisodes" WHERE UrlKey = %s
+    ''' if database_type == "postgresql" else '''
+        SELECT StartTime, EndTime FROM SharedEpisodes WHERE UrlKey = %s
+    '''
+
+    try:
+        cursor.execute(query, (url_key,))
+        result = cursor.fetchone()
+        cursor.close()
+        if not result:
+            return None, None
+        if isinstance(result, dict):
+            if database_type == 'postgresql':
+                return result['starttime'], result['endtime']
+            return result['StartTime'], result['EndTime']
+        return result[0], result[1]
+    except Exception as e:
+        print(f"Error retrieving shared clip bounds: {e}")
+        cursor.close()
+        return None, None
+
+
 
 def add_gpodder_settings(database_type, cnx, user_id, gpodder_url, gpodder_token, login_name, pod_sync_type):
     print("Adding gPodder settings")
@@ -5437,23 +6454,64 @@ def queue_bump(database_type, cnx, ep_url, title, user_id):
 def backup_user(database_type, cnx, user_id):
     if database_type == "postgresql":
         cursor = cnx.cursor(row_factory=psycopg.rows.dict_row)
-        query_fetch_podcasts = 'SELECT PodcastName, FeedURL FROM "Podcasts" WHERE UserID = %s'
+        query_fetch_podcasts = 'SELECT PodcastID, PodcastName, FeedURL FROM "Podcasts" WHERE UserID = %s'
     else:
         cursor = cnx.cursor(dictionary=True)
-        query_fetch_podcasts = "SELECT PodcastName, FeedURL FROM Podcasts WHERE UserID = %s"
+        query_fetch_podcasts = "SELECT PodcastID, PodcastName, FeedURL FROM Podcasts WHERE UserID = %s"
 
     cursor.execute(query_fetch_podcasts, (user_id,))
     podcasts = cursor.fetchall()
+
+    # Bookmarks ride along as nested outlines under their podcast; OPML readers skip them
+    if database_type == "postgresql":
+        query_fetch_bookmarks = (
+            'SELECT "Episodes".PodcastID, "Episodes".EpisodeTitle, "Episodes".EpisodeURL, "Bookmarks".BookmarkTime, "Bookmarks".Note '
+            'FROM "Bookmarks" INNER JOIN "Episodes" ON "Bookmarks".EpisodeID = "Episodes".EpisodeID '
+            'WHERE "Bookmarks".UserID = %s ORDER BY "Episodes".EpisodeID, "Bookmarks".BookmarkTime'
+        )
+    else:
+        query_fetch_bookmarks = (
+            "SELECT Episodes.PodcastID, Episodes.EpisodeTitle, Episodes.EpisodeURL, Bookmarks.BookmarkTime, Bookmarks.Note "
+            "FROM Bookmarks INNER JOIN Episodes ON Bookmarks.EpisodeID = Episodes.EpisodeID "
+            "WHERE Bookmarks.UserID = %s ORDER BY Episodes.EpisodeID, Bookmarks.BookmarkTime"
+        )
+    cursor.execute(query_fetch_bookmarks, (user_id,))
+    bookmarks_by_podcast = {}
+    for bookmark in cursor.fetchall():
+        bookmark = {k.lower(): v for k, v in bookmark.items()}
+        bookmarks_by_podcast.setdefault(bookmark["podcastid"], []).append(bookmark)
     cursor.close()
 
     opml_content = '<?xml version="1.0" encoding="UTF-8"?>\n<opml version="2.0">\n  <head>\n    <title>Podcast Subscriptions</title>\n  </head>\n  <body>\n'
 
-    if database_type == "postgresql":
-        for podcast in podcasts:
+    for podcast in podcasts:
+        podcast = {k.lower(): v for k, v in podcast.items()}
+        bookmarks = bookmarks_by_podcast.get(podcast["podcastid"], [])
+        if not bookmarks:
             opml_content += f'    <outline text="{podcast["podcastname"]}" title="{podcast["podcastname"]}" type="rss" xmlUrl="{podcast["feedurl"]}" />\n'
-    else:
-        for podcast in podcasts:
-            opml_content += f'    <outline text="{podcast["PodcastName"]}" title="{podcast["PodcastName"]}" type="rss" xmlUrl="{podcast["FeedURL"]}" />\n'
+            continue
+        opml_content += f'    <outline text="{podcast["podcastname"]}" title="{podcast["podcastname"]}" type="rss" xmlUrl="{podcast["feedurl"]}">\n'
+        for bookmark in bookmarks:
+            note = html.escape(bookmark["note"] or "", quote=True)
+            episode_title = html.escape(bookmark["episodetitle"] or "", quote=True)
+            episode_url = html.escape(bookmark["episodeurl"] or "", quote=True)
+            opml_content += f'      <outline text="{note}" type="pinepods-bookmark" episodeTitle="{episode_title}" episodeUrl="{episode_url}" time="{bookmark["bookmarktime"]}" />\n'
+        opml_content += '    </outline>\n'
+
+    # Playlists are kept as their own outlines: smart ones carry their rules, manual ones their episodes
+    for playlist in get_playlists(cnx, database_type, user_id):
+        name = html.escape(playlist["name"], quote=True)
+        if playlist["is_smart"]:
+            rules = html.escape(json.dumps(playlist["rules"]), quote=True)
+            opml_content += f'    <outline text="{name}" type="pinepods-smart-playlist" rules="{rules}" />\n'
+            continue
+        opml_content += f'    <outline text="{name}" type="pinepods-playlist">\n'
+        for episode in get_playlist_episodes(cnx, database_type, user_id, playlist):
+            episode_title = html.escape(episode["episodetitle"] or "", quote=True)
+            episode_url = html.escape(episode["episodeurl"] or "", quote=True)
+            podcast_name = html.escape(episode["podcastname"] or "", quote=True)
+            opml_content += f'      <outline text="{episode_title}" type="pinepods-playlist-episode" podcast="{podcast_name}" episodeUrl="{episode_url}" />\n'
+        opml_content += '    </outline>\n'
 
     opml_content += '  </body>\n</opml>'
 
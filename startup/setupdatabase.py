@@ -114,6 +114,12 @@ try:
                         FOREIGN KEY (UserID) REFERENCES Users(UserID) ON DELETE CASCADE
                     )""")
 
+    # Keys from before scopes existed keep full access
+    add_pod_sync_if_not_exists(cursor, 'APIKeys', 'Name', 'VARCHAR(255) DEFAULT NULL')
+    add_pod_sync_if_not_exists(cursor, 'APIKeys', 'Scope', 'VARCHAR(20) DEFAULT \'admin\'')
+    add_pod_sync_if_not_exists(cursor, 'APIKeys', 'Expires', 'TIMESTAMP NULL DEFAULT NULL')
+    add_pod_sync_if_not_exists(cursor, 'APIKeys', 'LastUsed', 'TIMESTAMP NULL DEFAULT NULL')
+
     cursor.execute("""CREATE TABLE IF NOT EXISTS UserStats (
                         UserStatsID INT AUTO_INCREMENT PRIMARY KEY,
                         UserID INT,
@@ -123,9 +129,18 @@ try:
                         PodcastsAdded INT DEFAULT 0,
                         EpisodesSaved INT DEFAULT 0,
                         EpisodesDownloaded INT DEFAULT 0,
+                        TimeSaved INT DEFAULT 0,
                         FOREIGN KEY (UserID) REFERENCES Users(UserID)
                     )""")
 
+    cursor.execute("SHOW COLUMNS FROM UserStats LIKE 'TimeSaved'")
+    result = cursor.fetchone()
+    if not result:
+        cursor.execute("""
+            ALTER TABLE UserStats
+            ADD COLUMN TimeSaved INT DEFAULT 0
+        """)
+
     # Generate a key
     key = Fernet.generate_key()
 
@@ -149,6 +164,14 @@ try:
             VALUES (0, 1, %s)
         """, (key,))
 
+    # Password policy. The defaults match the old six character minimum
+    add_pod_sync_if_not_exists(cursor, 'AppSettings', 'PasswordMinLength', 'INT DEFAULT 6')
+    add_pod_sync_if_not_exists(cursor, 'AppSettings', 'PasswordRequireUpper', 'TINYINT(1) DEFAULT 0')
+    add_pod_sync_if_not_exists(cursor, 'AppSettings', 'PasswordRequireLower', 'TINYINT(1) DEFAULT 0')
+    add_pod_sync_if_not_exists(cursor, 'AppSettings', 'PasswordRequireDigit', 'TINYINT(1) DEFAULT 0')
+    add_pod_sync_if_not_exists(cursor, 'AppSettings', 'PasswordRequireSymbol', 'TINYINT(1) DEFAULT 0')
+    add_pod_sync_if_not_exists(cursor, 'AppSettings', 'PasswordCheckBreached', 'TINYINT(1) DEFAULT 0')
+
     cursor.execute("""
         CREATE TABLE IF NOT EXISTS EmailSettings (
             EmailSettingsID INT AUTO_INCREMENT PRIMARY KEY,
@@ -320,8 +343,11 @@ try:
                         Explicit TINYINT(1),
                         UserID INT,
                         AutoDownload TINYINT(1) DEFAULT 0,
+                        AutoQueue TINYINT(1) DEFAULT 0,
                         StartSkip INT DEFAULT 0,
                         EndSkip INT DEFAULT 0,
+                        PlaybackSpeed FLOAT DEFAULT NULL,
+                        VolumeBoost INT DEFAULT 100,
                         Username TEXT,
                         Password TEXT,
                         FOREIGN KEY (UserID) REFERENCES Users(UserID)
@@ -372,6 +398,23 @@ try:
             ADD COLUMN StartSkip INT DEFAULT 0,
             ADD COLUMN EndSkip INT DEFAULT 0
         """)
+
+    cursor.execute("SHOW COLUMNS FROM Podcasts LIKE 'AutoQueue'")
+    result = cursor.fetchone()
+    if not result:
+        cursor.execute("""
+            ALTER TABLE Podcasts
+            ADD COLUMN AutoQueue TINYINT(1) DEFAULT 0
+        """)
+
+    cursor.execute("SHOW COLUMNS FROM Podcasts LIKE 'PlaybackSpeed'")
+    result = cursor.fetchone()
+    if not result:
+        cursor.execute("""
+            ALTER TABLE Podcasts
+            ADD COLUMN PlaybackSpeed FLOAT DEFAULT NULL,
+            ADD COLUMN VolumeBoost INT DEFAULT 100
+        """)
     cursor.execute("""CREATE TABLE IF NOT EXISTS Episodes (
                         EpisodeID INT AUTO_INCREMENT PRIMARY KEY,
                         PodcastID INT,
@@ -382,6 +425,7 @@ try:
                         EpisodePubDate DATETIME,
                         EpisodeDuration INT,
                         Completed TINYINT(1) DEFAULT 0,
+                        EnclosureType VARCHAR(255) DEFAULT NULL,
                         FOREIGN KEY (PodcastID) REFERENCES Podcasts(PodcastID)
                     )""")
     # Check if the Completed column exists, and add it if it doesn't
@@ -393,6 +437,14 @@ try:
             ADD COLUMN Completed TINYINT(1) DEFAULT 0
         """)
 
+    cursor.execute("SHOW COLUMNS FROM Episodes LIKE 'EnclosureType'")
+    result = cursor.fetchone()
+    if not result:
+        cursor.execute("""
+            ALTER TABLE Episodes
+            ADD COLUMN EnclosureType VARCHAR(255) DEFAULT NULL
+        """)
+
 
     def create_index_if_not_exists(cursor, index_name, table_name, column_name):
         cursor.execute(f"SELECT COUNT(1) IndexIsThere FROM INFORMATION_SCHEMA.STATISTICS WHERE table_schema = DATABASE() AND index_name = '{index_name}'")
@@ -411,6 +463,8 @@ try:
                 EpisodeID INT,
                 UrlKey TEXT,
                 ExpirationDate DATETIME,
+                StartTime INT DEFAULT NULL,
+                EndTime INT DEFAULT NULL,
                 FOREIGN KEY (EpisodeID) REFERENCES Episodes(EpisodeID)
             )
         """)
@@ -418,6 +472,15 @@ try:
     except Exception as e:
         print(f"Error creating SharedEpisodes table: {e}")
 
+    cursor.execute("SHOW COLUMNS FROM SharedEpisodes LIKE 'StartTime'")
+    result = cursor.fetchone()
+    if not result:
+        cursor.execute("""
+            ALTER TABLE SharedEpisodes
+            ADD COLUMN StartTime INT DEFAULT NULL,
+            ADD COLUMN EndTime INT DEFAULT NULL
+        """)
+
 
 
     cursor.execute("""CREATE TABLE IF NOT EXISTS UserSettings (
@@ -450,6 +513,65 @@ try:
                     )""")
 
 
+    cursor.execute("""CREATE TABLE IF NOT EXISTS Bookmarks (
+                        BookmarkID INT AUTO_INCREMENT PRIMARY KEY,
+                        UserID INT,
+                        EpisodeID INT,
+                        BookmarkTime INT,
+                        Note TEXT,
+                        Created TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                        FOREIGN KEY (UserID) REFERENCES Users(UserID) ON DELETE CASCADE,
+                        FOREIGN KEY (EpisodeID) REFERENCES Episodes(EpisodeID) ON DELETE CASCADE
+                    )""")
+
+    # Plain text of transcripts fetched through the server, so search can look inside them
+    cursor.execute("""CREATE TABLE IF NOT EXISTS EpisodeTranscripts (
+                        EpisodeID INT PRIMARY KEY,
+                        TranscriptText LONGTEXT,
+                        Fetched TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                        FOREIGN KEY (EpisodeID) REFERENCES Episodes(EpisodeID) ON DELETE CASCADE
+                    )""")
+
+    # Manual playlists keep their episodes in PlaylistEpisodes; smart ones store their rules as JSON
+    cursor.execute("""CREATE TABLE IF NOT EXISTS Playlists (
+                        PlaylistID INT AUTO_INCREMENT PRIMARY KEY,
+                        UserID INT,
+                        Name VARCHAR(255) NOT NULL,
+                        IsSmart TINYINT(1) DEFAULT 0,
+                        Rules TEXT,
+                        Created TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                        FOREIGN KEY (UserID) REFERENCES Users(UserID) ON DELETE CASCADE
+                    )""")
+
+    cursor.execute("""CREATE TABLE IF NOT EXISTS PlaylistEpisodes (
+                        PlaylistID INT,
+                        EpisodeID INT,
+                        Position INT NOT NULL DEFAULT 0,
+                        DateAdded TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                        PRIMARY KEY (PlaylistID, EpisodeID),
+                        FOREIGN KEY (PlaylistID) REFERENCES Playlists(PlaylistID) ON DELETE CASCADE,
+                        FOREIGN KEY (EpisodeID) REFERENCES Episodes(EpisodeID) ON DELETE CASCADE
+                    )""")
+
+    cursor.execute("""CREATE TABLE IF NOT EXISTS WebAuthnCredentials (
+                        CredentialID VARCHAR(255) PRIMARY KEY,
+                        UserID INT,
+                        PublicKey TEXT,
+                        SignCount BIGINT DEFAULT 0,
+                        Name VARCHAR(255),
+                        Created TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                        LastUsed TIMESTAMP NULL,
+                        FOREIGN KEY (UserID) REFERENCES Users(UserID) ON DELETE CASCADE
+                    )""")
+
+    cursor.execute("""CREATE TABLE IF NOT EXISTS MFARecoveryCodes (
+                        CodeID INT AUTO_INCREMENT PRIMARY KEY,
+                        UserID INT,
+                        CodeHash CHAR(64),
+                        Created TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                        FOREIGN KEY (UserID) REFERENCES Users(UserID) ON DELETE CASCADE
+                    )""")
+
     # Create the DownloadedEpisodes table
     cursor.execute("""CREATE TABLE IF NOT EXISTS DownloadedEpisodes (
                     DownloadID INT AUTO_INCREMENT PRIMARY KEY,
@@ -136,6 +136,19 @@ try:
                         FOREIGN KEY (UserID) REFERENCES "Users"(UserID) ON DELETE CASCADE
                     )""")
 
+    # Keys from before scopes existed keep full access
+    try:
+        cursor.execute("""
+            ALTER TABLE "APIKeys"
+            ADD COLUMN IF NOT EXISTS Name VARCHAR(255) DEFAULT NULL,
+            ADD COLUMN IF NOT EXISTS Scope VARCHAR(20) DEFAULT 'admin',
+            ADD COLUMN IF NOT EXISTS Expires TIMESTAMP DEFAULT NULL,
+            ADD COLUMN IF NOT EXISTS LastUsed TIMESTAMP DEFAULT NULL
+        """)
+        cnx.commit()
+    except Exception as e:
+        print(f"Error adding scope columns to APIKeys table: {e}")
+
     cursor.execute("""CREATE TABLE IF NOT EXISTS "UserStats" (
                         UserStatsID SERIAL PRIMARY KEY,
                         UserID INT UNIQUE,
@@ -145,9 +158,19 @@ try:
                         PodcastsAdded INT DEFAULT 0,
                         EpisodesSaved INT DEFAULT 0,
                         EpisodesDownloaded INT DEFAULT 0,
+                        TimeSaved INT DEFAULT 0,
                         FOREIGN KEY (UserID) REFERENCES "Users"(UserID)
                     )""")
 
+    try:
+        cursor.execute("""
+            ALTER TABLE "UserStats"
+            ADD COLUMN IF NOT EXISTS TimeSaved INT DEFAULT 0
+        """)
+        cnx.commit()
+    except Exception as e:
+        print(f"Error adding TimeSaved column to UserStats table: {e}")
+
 
     # Generate a key
     key = Fernet.generate_key()
@@ -172,6 +195,21 @@ try:
             VALUES (false, true, %s)
         """, (key,))
 
+    # Password policy. The defaults match the old six character minimum
+    try:
+        cursor.execute("""
+            ALTER TABLE "AppSettings"
+            ADD COLUMN IF NOT EXISTS PasswordMinLength INT DEFAULT 6,
+            ADD COLUMN IF NOT EXISTS PasswordRequireUpper BOOLEAN DEFAULT false,
+            ADD COLUMN IF NOT EXISTS PasswordRequireLower BOOLEAN DEFAULT false,
+            ADD COLUMN IF NOT EXISTS PasswordRequireDigit BOOLEAN DEFAULT false,
+            ADD COLUMN IF NOT EXISTS PasswordRequireSymbol BOOLEAN DEFAULT false,
+            ADD COLUMN IF NOT EXISTS PasswordCheckBreached BOOLEAN DEFAULT false
+        """)
+        cnx.commit()
+    except Exception as e:
+        print(f"Error adding password policy columns to AppSettings table: {e}")
+
     try:
         cursor.execute("""
             CREATE TABLE IF NOT EXISTS "EmailSettings" (
@@ -331,8 +369,11 @@ try:
                 Explicit BOOLEAN,
                 UserID INT,
                 AutoDownload BOOLEAN DEFAULT FALSE,
+                AutoQueue BOOLEAN DEFAULT FALSE,
                 StartSkip INT DEFAULT 0,
                 EndSkip INT DEFAULT 0,
+                PlaybackSpeed REAL DEFAULT NULL,
+                VolumeBoost INT DEFAULT 100,
                 Username TEXT,
                 Password TEXT,
                 FOREIGN KEY (UserID) REFERENCES "Users"(UserID)
@@ -377,6 +418,25 @@ try:
     # Usage
     add_user_pass_columns_if_not_exist(cursor, cnx)
 
+    try:
+        cursor.execute("""
+            ALTER TABLE "Podcasts"
+            ADD COLUMN IF NOT EXISTS AutoQueue BOOLEAN DEFAULT FALSE
+        """)
+        cnx.commit()
+    except Exception as e:
+        print(f"Error adding AutoQueue column to Podcasts table: {e}")
+
+    try:
+        cursor.execute("""
+            ALTER TABLE "Podcasts"
+            ADD COLUMN IF NOT EXISTS PlaybackSpeed REAL DEFAULT NULL,
+            ADD COLUMN IF NOT EXISTS VolumeBoost INT DEFAULT 100
+        """)
+        cnx.commit()
+    except Exception as e:
+        print(f"Error adding playback columns to Podcasts table: {e}")
+
 
 
     cursor.execute("SELECT to_regclass('public.\"Podcasts\"')")
@@ -393,6 +453,7 @@ try:
                 EpisodePubDate TIMESTAMP,
                 EpisodeDuration INT,
                 Completed BOOLEAN DEFAULT FALSE,
+                EnclosureType VARCHAR(255) DEFAULT NULL,
                 FOREIGN KEY (PodcastID) REFERENCES "Podcasts"(PodcastID)
             )
         """)
@@ -401,6 +462,15 @@ try:
     except Exception as e:
         print(f"Error adding Episodes table: {e}")
 
+    try:
+        cursor.execute("""
+            ALTER TABLE "Episodes"
+            ADD COLUMN IF NOT EXISTS EnclosureType VARCHAR(255) DEFAULT NULL
+        """)
+        cnx.commit()
+    except Exception as e:
+        print(f"Error adding EnclosureType column to Episodes table: {e}")
+
     def create_index_if_not_exists(cursor, index_name, table_name, column_name):
         cursor.execute(f"""
             SELECT 1
@@ -421,6 +491,8 @@ try:
                 EpisodeID INT,
                 UrlKey TEXT,
                 ExpirationDate TIMESTAMP,
+                StartTime INT DEFAULT NULL,
+                EndTime INT DEFAULT NULL,
                 FOREIGN KEY (EpisodeID) REFERENCES "Episodes"(EpisodeID)
             )
         """)
@@ -428,6 +500,16 @@ try:
     except Exception as e:
         print(f"Error creating SharedEpisodes table: {e}")
 
+    try:
+        cursor.execute("""
+            ALTER TABLE "SharedEpisodes"
+            ADD COLUMN IF NOT EXISTS StartTime INT DEFAULT NULL,
+            ADD COLUMN IF NOT EXISTS EndTime INT DEFAULT NULL
+        """)
+        cnx.commit()
+    except Exception as e:
+        print(f"Error adding clip columns to SharedEpisodes table: {e}")
+
 
 
     try:
@@ -463,6 +545,65 @@ try:
                     )""")
 
 
+    cursor.execute("""CREATE TABLE IF NOT EXISTS "Bookmarks" (
+                        BookmarkID SERIAL PRIMARY KEY,
+                        UserID INT,
+                        EpisodeID INT,
+                        BookmarkTime INT,
+                        Note TEXT,
+                        Created TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                        FOREIGN KEY (UserID) REFERENCES "Users"(UserID) ON DELETE CASCADE,
+                        FOREIGN KEY (EpisodeID) REFERENCES "Episodes"(EpisodeID) ON DELETE CASCADE
+                    )""")
+
+    # Plain text of transcripts fetched through the server, so search can look inside them
+    cursor.execute("""CREATE TABLE IF NOT EXISTS "EpisodeTranscripts" (
+                        EpisodeID INT PRIMARY KEY,
+                        TranscriptText TEXT,
+                        Fetched TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                        FOREIGN KEY (EpisodeID) REFERENCES "Episodes"(EpisodeID) ON DELETE CASCADE
+                    )""")
+
+    # Manual playlists keep their episodes in PlaylistEpisodes; smart ones store their rules as JSON
+    cursor.execute("""CREATE TABLE IF NOT EXISTS "Playlists" (
+                        PlaylistID SERIAL PRIMARY KEY,
+                        UserID INT,
+                        Name VARCHAR(255) NOT NULL,
+                        IsSmart BOOLEAN DEFAULT FALSE,
+                        Rules TEXT,
+                        Created TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                        FOREIGN KEY (UserID) REFERENCES "Users"(UserID) ON DELETE CASCADE
+                    )""")
+
+    cursor.execute("""CREATE TABLE IF NOT EXISTS "PlaylistEpisodes" (
+                        PlaylistID INT,
+                        EpisodeID INT,
+                        Position INT NOT NULL DEFAULT 0,
+                        DateAdded TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                        PRIMARY KEY (PlaylistID, EpisodeID),
+                        FOREIGN KEY (PlaylistID) REFERENCES "Playlists"(PlaylistID) ON DELETE CASCADE,
+                        FOREIGN KEY (EpisodeID) REFERENCES "Episodes"(EpisodeID) ON DELETE CASCADE
+                    )""")
+
+    cursor.execute("""CREATE TABLE IF NOT EXISTS "WebAuthnCredentials" (
+                        CredentialID VARCHAR(255) PRIMARY KEY,
+                        UserID INT,
+                        PublicKey TEXT,
+                        SignCount BIGINT DEFAULT 0,
+                        Name VARCHAR(255),
+                        Created TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                        LastUsed TIMESTAMP NULL,
+                        FOREIGN KEY (UserID) REFERENCES "Users"(UserID) ON DELETE CASCADE
+                    )""")
+
+    cursor.execute("""CREATE TABLE IF NOT EXISTS "MFARecoveryCodes" (
+                        CodeID SERIAL PRIMARY KEY,
+                        UserID INT,
+                        CodeHash CHAR(64),
+                        Created TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                        FOREIGN KEY (UserID) REFERENCES "Users"(UserID) ON DELETE CASCADE
+                    )""")
+
     # Create the DownloadedEpisodes table
     cursor.execute("""CREATE TABLE IF NOT EXISTS "DownloadedEpisodes" (
                     DownloadID SERIAL PRIMARY KEY,
@@ -322,6 +322,89 @@ async fn start_file_server(filepath: String) -> Result<String, String> {
     Ok("http://127.0.0.1:3030".to_string())
 }
 
+// Listener waiting for the identity provider to send the browser back after single sign-on
+static OAUTH_LISTENER: std::sync::Mutex<Option<tokio::net::TcpListener>> =
+    std::sync::Mutex::new(None);
+
+#[tauri::command]
+async fn start_oauth_listener() -> Result<u16, String> {
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0))
+        .await
+        .map_err(|e| e.to_string())?;
+    let port = listener.local_addr().map_err(|e| e.to_string())?.port();
+    *OAUTH_LISTENER.lock().map_err(|e| e.to_string())? = Some(listener);
+    Ok(port)
+}
+
+fn open_in_browser(url: &str) -> std::io::Result<()> {
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        // `cmd /C start` would treat the `&` between query parameters as a command separator
+        let mut command = std::process::Command::new("rundll32");
+        command.args(["url.dll,FileProtocolHandler", url]);
+        command
+    };
+    #[cfg(target_os = "macos")]
+    let mut command = {
+        let mut command = std::process::Command::new("open");
+        command.arg(url);
+        command
+    };
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let mut command = {
+        let mut command = std::process::Command::new("xdg-open");
+        command.arg(url);
+        command
+    };
+    command.spawn().map(|_| ())
+}
+
+/// Open the provider's sign-in page in the system browser and wait for it to redirect back to
+/// the loopback listener. Returns the query string of that redirect.
+#[tauri::command]
+async fn await_oauth_redirect(url: String) -> Result<String, String> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = OAUTH_LISTENER
+        .lock()
+        .map_err(|e| e.to_string())?
+        .take()
+        .ok_or("The sign-in listener isn't running")?;
+    open_in_browser(&url).map_err(|e| format!("Couldn't open the browser: {}", e))?;
+
+    let wait = async {
+        loop {
+            let (mut stream, _) = listener.accept().await.map_err(|e| e.to_string())?;
+            let mut buffer = vec![0u8; 8192];
+            let read = stream.read(&mut buffer).await.map_err(|e| e.to_string())?;
+            let request = String::from_utf8_lossy(&buffer[..read]).to_string();
+            // The request line looks like "GET /callback?code=...&state=... HTTP/1.1"
+            let target = request.split_whitespace().nth(1).unwrap_or_default();
+            let Some(query) = target
+                .strip_prefix("/callback")
+                .and_then(|rest| rest.strip_prefix('?'))
+            else {
+                // Browsers also ask for things like /favicon.ico
+                let _ = stream
+                    .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n")
+                    .await;
+                continue;
+            };
+            let body = "<html><body><p>Signed in. You can close this window and return to Pinepods.</p></body></html>";
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+            return Ok::<String, String>(query.to_string());
+        }
+    };
+    tokio::time::timeout(std::time::Duration::from_secs(300), wait)
+        .await
+        .map_err(|_| "Timed out waiting for the sign-in to finish".to_string())?
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -337,7 +420,9 @@ pub fn run() {
             get_local_episodes,
             list_app_files,
             get_local_file,
-            start_file_server
+            start_file_server,
+            start_oauth_listener,
+            await_oauth_redirect
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -1,5 +1,8 @@
 use super::routes::Route;
 use crate::components::context::AppState;
+use crate::components::i18n::use_i18n;
+use crate::components::playlists::load_playlists;
+use crate::components::server_profiles::ServerProfiles;
 use crate::requests::pod_req::connect_to_episode_websocket;
 use wasm_bindgen_futures::spawn_local;
 use web_sys::window;
@@ -13,6 +16,7 @@ pub fn app_drawer() -> Html {
     // let selection = use_state(|| "".to_string());
     // let (state, _dispatch) = use_store::<AppState>();
 
+    let i18n = use_i18n();
     let is_drawer_open = use_state(|| false);
     let (state, _dispatch) = use_store::<AppState>();
     let (post_state, _post_dispatch) = use_store::<AppState>();
@@ -45,6 +49,34 @@ pub fn app_drawer() -> Html {
         }
     };
 
+    let close_drawer = {
+        let is_drawer_open = is_drawer_open.clone();
+        Callback::from(move |_| {
+            is_drawer_open.set(false);
+            if let Some(body) = window().and_then(|w| w.document()).and_then(|d| d.body()) {
+                let _ = body.class_list().remove_1("no-scroll");
+            }
+        })
+    };
+
+    // Playlists are listed under their own entry; the playlists page keeps the list current
+    {
+        let dispatch = _dispatch.clone();
+        let needs_playlists = state.playlists.is_none();
+        let api_key = api_key.clone().flatten();
+        use_effect_with(
+            (server_name.clone(), user_id, needs_playlists),
+            move |(server_name, user_id, needs_playlists)| {
+                if let (Some(server_name), Some(user_id), true) =
+                    (server_name.clone(), *user_id, *needs_playlists)
+                {
+                    load_playlists(dispatch, server_name, api_key, user_id);
+                }
+                || ()
+            },
+        );
+    }
+
     let on_refresh_click = {
         let server_name = server_name.clone();
         let user_id = user_id.clone();
@@ -98,13 +130,31 @@ pub fn app_drawer() -> Html {
     let show_home_button = current_path != "/home";
     let show_refresh_button = current_path == "/home";
 
+    let playlist_links = state
+        .playlists
+        .iter()
+        .flatten()
+        .map(|playlist| {
+            html! {
+                <div class="drawer-playlist-link flex items-center space-x-3">
+                    <div onclick={toggle_drawer.clone()} class="drawer-text flex items-center space-x-3 cursor-pointer">
+                        <Link<Route> to={Route::Playlist { playlist_id: playlist.playlist_id }}>
+                            <span class="material-icons icon-space">{ if playlist.is_smart { "auto_awesome" } else { "queue_music" } }</span>
+                            <span class="text-md">{ &playlist.name }</span>
+                        </Link<Route>>
+                    </div>
+                </div>
+            }
+        })
+        .collect::<Html>();
+
     #[cfg(not(feature = "server_build"))]
     let local_download_link = html! {
         <div class="flex items-center space-x-3">
             <div onclick={toggle_drawer.clone()} class="drawer-text flex items-center space-x-3 cursor-pointer">
                 <Link<Route> to={Route::LocalDownloads}>
                     <span class="material-icons icon-space">{"folder_open"}</span>
-                    <span class="text-lg">{"Local Downloads"}</span>
+                    <span class="text-lg">{ i18n.t("nav.local_downloads") }</span>
                 </Link<Route>>
             </div>
         </div>
@@ -129,7 +179,7 @@ pub fn app_drawer() -> Html {
                                         src={state.gravatar_url.clone().unwrap_or_else(|| "/static/assets/favicon.png".to_string())}
                                         style="width: 25px; height: 25px;"
                                         class="icon-size rounded-full" // Added rounded-full for circular image
-                                        alt="User Avatar"
+                                        alt={i18n.t("nav.user_avatar_alt")}
                                     />
                                     <span class="drawer-text text-lg text-adjust" style="margin-top: 7px; margin-left: -2px;">
                                         {username} // Displaying the username
@@ -137,13 +187,14 @@ pub fn app_drawer() -> Html {
                                 </div>
                             </Link<Route>>
                         </div>
+                        <ServerProfiles title={i18n.t("nav.switch_account")} on_switched={close_drawer} />
 
                             // Other Links
                             <div class="m-0 p-0 flex items-center space-x-3">
                                 <div onclick={toggle_drawer.clone()} class="drawer-text flex items-center space-x-3 cursor-pointer">
                                     <Link<Route> to={Route::Home}>
                                         <span class="material-standard-color material-icons icon-space">{"home"}</span>
-                                        <span class="text-lg">{"Home"}</span>
+                                        <span class="text-lg">{ i18n.t("nav.home") }</span>
                                     </Link<Route>>
                                 </div>
                             </div>
@@ -151,7 +202,7 @@ pub fn app_drawer() -> Html {
                                 <div onclick={toggle_drawer.clone()} class="drawer-text flex items-center space-x-3 cursor-pointer">
                                     <Link<Route> to={Route::Search}>
                                         <span class="material-icons icon-space">{"search"}</span>
-                                        <span class="text-lg">{"Search Podcasts"}</span>
+                                        <span class="text-lg">{ i18n.t("nav.search") }</span>
                                     </Link<Route>>
                                 </div>
                             </div>
@@ -159,7 +210,7 @@ pub fn app_drawer() -> Html {
                                 <div onclick={toggle_drawer.clone()} class="drawer-text flex items-center space-x-3 cursor-pointer">
                                     <Link<Route> to={Route::Queue}>
                                     <span class="material-icons icon-space">{"queue"}</span>
-                                    <span class="text-lg">{"Queue"}</span>
+                                    <span class="text-lg">{ i18n.t("nav.queue") }</span>
                                     </Link<Route>>
                                 </div>
                             </div>
@@ -167,15 +218,24 @@ pub fn app_drawer() -> Html {
                                 <div onclick={toggle_drawer.clone()} class="drawer-text flex items-center space-x-3 cursor-pointer">
                                     <Link<Route> to={Route::Saved}>
                                         <span class="material-icons icon-space">{"star"}</span>
-                                        <span class="text-lg">{"Saved"}</span>
+                                        <span class="text-lg">{ i18n.t("nav.saved") }</span>
+                                    </Link<Route>>
+                                </div>
+                            </div>
+                            <div class="flex items-center space-x-3">
+                                <div onclick={toggle_drawer.clone()} class="drawer-text flex items-center space-x-3 cursor-pointer">
+                                    <Link<Route> to={Route::Playlists}>
+                                        <span class="material-icons icon-space">{"playlist_play"}</span>
+                                        <span class="text-lg">{ i18n.t("nav.playlists") }</span>
                                     </Link<Route>>
                                 </div>
                             </div>
+                            { playlist_links }
                             <div class="flex items-center space-x-3">
                                 <div onclick={toggle_drawer.clone()} class="drawer-text flex items-center space-x-3 cursor-pointer">
                                     <Link<Route> to={Route::PodHistory}>
                                         <span class="material-icons icon-space">{"history"}</span>
-                                        <span class="text-lg">{"History"}</span>
+                                        <span class="text-lg">{ i18n.t("nav.history") }</span>
                                     </Link<Route>>
                                 </div>
                             </div>
@@ -183,7 +243,7 @@ pub fn app_drawer() -> Html {
                             <div onclick={toggle_drawer.clone()} class="drawer-text flex items-center space-x-3 cursor-pointer">
                                 <Link<Route> to={Route::Downloads}>
                                     <span class="material-icons icon-space">{"download"}</span>
-                                    <span class="text-lg">{"Server Downloads"}</span>
+                                    <span class="text-lg">{ i18n.t("nav.server_downloads") }</span>
                                 </Link<Route>>
                             </div>
                         </div>
@@ -196,7 +256,7 @@ pub fn app_drawer() -> Html {
                                 <div onclick={toggle_drawer.clone()} class="drawer-text flex items-center space-x-3 cursor-pointer">
                                     <Link<Route> to={Route::Podcasts}>
                                         <span class="material-icons icon-space">{"podcasts"}</span>
-                                        <span class="text-lg">{"Podcasts"}</span>
+                                        <span class="text-lg">{ i18n.t("nav.podcasts") }</span>
                                     </Link<Route>>
                                 </div>
                             </div>
@@ -204,7 +264,7 @@ pub fn app_drawer() -> Html {
                                 <div onclick={toggle_drawer.clone()} class="drawer-text flex items-center space-x-3 cursor-pointer">
                                     <Link<Route> to={Route::Settings}>
                                         <span class="material-icons icon-space">{"settings"}</span>
-                                        <span class="text-lg">{"Settings"}</span>
+                                        <span class="text-lg">{ i18n.t("nav.settings") }</span>
                                     </Link<Route>>
                                 </div>
                             </div>
@@ -215,7 +275,7 @@ pub fn app_drawer() -> Html {
                     <div onclick={toggle_drawer.clone()} class="drawer-text flex items-center space-x-3 cursor-pointer">
                         <Link<Route> to={Route::LogOut}>
                             <span class="material-icons icon-space">{"logout"}</span>
-                            <span class="text-lg">{"Sign Out"}</span>
+                            <span class="text-lg">{ i18n.t("nav.sign_out") }</span>
                         </Link<Route>>
                     </div>
                 </div>
@@ -277,7 +337,7 @@ pub fn app_drawer() -> Html {
                                     <path d="M100 50.5908C100 78.2051 77.6142 100.591 50 100.591C22.3858 100.591 0 78.2051 0 50.5908C0 22.9766 22.3858 0.59082 50 0.59082C77.6142 0.59082 100 22.9766 100 50.5908ZM9.08144 50.5908C9.08144 73.1895 27.4013 91.5094 50 91.5094C72.5987 91.5094 90.9186 73.1895 90.9186 50.5908C90.9186 27.9921 72.5987 9.67226 50 9.67226C27.4013 9.67226 9.08144 27.9921 9.08144 50.5908Z" fill="currentColor"/>
                                     <path d="M93.9676 39.0409C96.393 38.4038 97.8624 35.9116 97.0079 33.5539C95.2932 28.8227 92.871 24.3692 89.8167 20.348C85.8452 15.1192 80.8826 10.7238 75.2124 7.41289C69.5422 4.10194 63.2754 1.94025 56.7698 1.05124C51.7666 0.367541 46.6976 0.446843 41.7345 1.27873C39.2613 1.69328 37.813 4.19778 38.4501 6.62326C39.0873 9.04874 41.5694 10.4717 44.0505 10.1071C47.8511 9.54855 51.7191 9.52689 55.5402 10.0491C60.8642 10.7766 65.9928 12.5457 70.6331 15.2552C75.2735 17.9648 79.3347 21.5619 82.5849 25.841C84.9175 28.9121 86.7997 32.2913 88.1811 35.8758C89.083 38.2158 91.5421 39.6781 93.9676 39.0409Z" fill="currentFill"/>
                                 </svg>
-                                <span class="sr-only">{"Loading..."}</span>
+                                <span class="sr-only">{ i18n.t("common.loading") }</span>
                             </div>
                         },
                         _ => html! {}, // Covers both Some(false) and None
@@ -1,36 +1,51 @@
-use crate::components::context::{AppState, UIState};
+use crate::components::audio_graph::{AudioProcessing, SilenceTrimmer};
+use crate::components::context::{
+    load_auto_play_next, store_auto_play_next, store_default_playback_speed, AppState,
+    SavedPlayerState, UIState,
+};
 #[cfg(not(feature = "server_build"))]
 use crate::components::downloads_tauri::start_local_file_server;
 use crate::components::gen_funcs::format_time_rm_hour;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, notify_info};
+use crate::components::transcript::TranscriptViewer;
 #[cfg(not(feature = "server_build"))]
 use crate::requests::pod_req::EpisodeDownload;
-use crate::requests::pod_req::FetchPodcasting2DataRequest;
+use crate::requests::pod_req::{
+    call_add_bookmark, call_create_share_link, call_get_episode_metadata, AddBookmarkRequest,
+    Chapter, EpisodeRequest, FetchPodcasting2DataRequest,
+};
 use crate::requests::pod_req::{
     call_add_history, call_check_episode_in_db, call_fetch_podcasting_2_data,
     call_get_auto_skip_times, call_get_episode_id, call_get_podcast_id_from_ep,
     call_get_queued_episodes, call_increment_listen_time, call_increment_played,
+    call_increment_time_saved,
     call_mark_episode_completed, call_queue_episode, call_record_listen_duration,
-    call_remove_queued_episode, HistoryAddRequest, MarkEpisodeCompletedRequest,
-    QueuePodcastRequest, RecordListenDurationRequest,
+    call_remove_queued_episode, HistoryAddRequest, MarkEpisodeCompletedRequest, PlaylistEpisode,
+    QueueInsert, QueuePodcastRequest, QueuedEpisode, RecordListenDurationRequest,
 };
 use gloo_timers::callback::Interval;
-use std::cell::Cell;
+use serde::{Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
 #[cfg(not(feature = "server_build"))]
 use std::path::Path;
 use std::rc::Rc;
 use std::string::String;
 use wasm_bindgen::closure::Closure;
+use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::JsValue;
-use wasm_bindgen_futures::spawn_local;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
 use web_sys::HtmlElement;
-use web_sys::{window, HtmlAudioElement, HtmlInputElement};
+use web_sys::{
+    window, Document, HtmlAudioElement, HtmlInputElement, HtmlMediaElement, HtmlVideoElement,
+};
 use yew::prelude::*;
 use yew::{function_component, html, Callback, Html};
 use yew_router::history::{BrowserHistory, History};
 use yewdux::prelude::*;
 
-#[derive(Properties, PartialEq, Debug, Clone)]
+#[derive(Properties, PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct AudioPlayerProps {
     pub src: String,
     pub title: String,
@@ -41,10 +56,175 @@ pub struct AudioPlayerProps {
     pub start_pos_sec: f64,
     pub end_pos_sec: f64,
     pub offline: bool,
+    /// MIME type from the feed's enclosure, when the server has one for the episode.
+    #[serde(default)]
+    #[prop_or_default]
+    pub enclosure_type: Option<String>,
+}
+
+// Picture-in-picture is still behind web-sys' unstable APIs, so bind the few calls we need directly
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = HtmlVideoElement)]
+    type PipVideoElement;
+    #[wasm_bindgen(method, js_name = requestPictureInPicture)]
+    fn request_picture_in_picture(this: &PipVideoElement) -> js_sys::Promise;
+
+    #[wasm_bindgen(extends = Document)]
+    type PipDocument;
+    #[wasm_bindgen(method, getter, js_name = pictureInPictureEnabled)]
+    fn picture_in_picture_enabled(this: &PipDocument) -> Option<bool>;
+    #[wasm_bindgen(method, getter, js_name = pictureInPictureElement)]
+    fn picture_in_picture_element(this: &PipDocument) -> Option<web_sys::Element>;
+    #[wasm_bindgen(method, js_name = exitPictureInPicture)]
+    fn exit_picture_in_picture(this: &PipDocument) -> js_sys::Promise;
+}
+
+/// How often silence trimming samples the audio level, in milliseconds.
+const SILENCE_POLL_MS: u32 = 100;
+/// How often a playing shared clip checks whether it reached its end, in milliseconds.
+const CLIP_END_POLL_MS: u32 = 250;
+
+// Chapters marked `toc: false` still drive artwork, but are skipped in the list, markers and navigation
+fn toc_chapters(chapters: &[Chapter]) -> Vec<Chapter> {
+    let mut toc: Vec<Chapter> = chapters
+        .iter()
+        .filter(|chapter| chapter.toc != Some(false) && chapter.startTime.is_some())
+        .cloned()
+        .collect();
+    toc.sort_by_key(|chapter| chapter.startTime);
+    toc
+}
+
+// The chapter playing at `time`, if any. A chapter with an `endTime` stops applying once it has passed.
+fn chapter_index_at(chapters: &[Chapter], time: f64) -> Option<usize> {
+    chapters
+        .iter()
+        .rposition(|chapter| chapter.startTime.map_or(false, |start| start as f64 <= time))
+        .filter(|&i| chapters[i].endTime.map_or(true, |end| time < end as f64))
+}
+
+fn chapter_markers(chapters: &[Chapter], duration: f64) -> Html {
+    if duration <= 0.0 {
+        return html! {};
+    }
+    html! {
+        <div class="chapter-markers">
+            { for chapters.iter().filter_map(|chapter| chapter.startTime).filter(|&start| start > 0).map(|start| {
+                let left = (start as f64 / duration * 100.0).min(100.0);
+                html! { <div class="chapter-marker" style={format!("left: {}%;", left)}></div> }
+            })}
+        </div>
+    }
+}
+
+/// How far ahead (in seconds) the server's listen position has to be before it replaces the
+/// locally saved one, i.e. the episode was played further on another device.
+const SERVER_POSITION_LEAD: f64 = 30.0;
+
+/// Bring back the player saved before a reload, paused where it left off, once the user is
+/// logged back in.
+pub fn restore_saved_player(
+    audio_dispatch: Dispatch<UIState>,
+    server_name: String,
+    api_key: Option<String>,
+    user_id: i32,
+) {
+    let Some(mut saved) = SavedPlayerState::load().filter(|saved| saved.user_id == user_id) else {
+        return;
+    };
+    if audio_dispatch.get().currently_playing.is_some() {
+        return;
+    }
+    spawn_local(async move {
+        if saved.episode.episode_id != 0 {
+            let request = EpisodeRequest {
+                episode_id: saved.episode.episode_id,
+                user_id,
+            };
+            // If the server can't be reached the local position is the best we have
+            if let Ok(info) = call_get_episode_metadata(&server_name, api_key, &request).await {
+                if info.completed {
+                    SavedPlayerState::clear();
+                    return;
+                }
+                let listened = info.listenduration.unwrap_or(0) as f64;
+                if listened > saved.position + SERVER_POSITION_LEAD {
+                    saved.position = listened;
+                }
+            }
+        }
+        audio_dispatch.reduce_mut(move |state| state.restore_saved_player(saved));
+    });
+}
+
+/// The episode that should follow `current_episode_id`: the lowest queue position that isn't
+/// the episode currently loaded.
+fn next_queued_episode(
+    episodes: &[QueuedEpisode],
+    current_episode_id: Option<i32>,
+) -> Option<&QueuedEpisode> {
+    episodes
+        .iter()
+        .filter(|ep| Some(ep.episodeid) != current_episode_id)
+        .min_by_key(|ep| ep.queueposition.unwrap_or(i32::MAX))
+}
+
+/// Start a queued episode through the regular play path so per-podcast skip times are applied.
+fn play_queued_episode(
+    episode: &QueuedEpisode,
+    api_key: String,
+    user_id: i32,
+    server_name: String,
+    audio_dispatch: Dispatch<UIState>,
+    audio_state: Rc<UIState>,
+) {
+    on_play_click(
+        episode.episodeurl.clone(),
+        episode.episodetitle.clone(),
+        episode.episodeartwork.clone(),
+        episode.episodeduration,
+        episode.episodeid,
+        episode.listenduration,
+        api_key,
+        user_id,
+        server_name,
+        audio_dispatch,
+        audio_state,
+        None,
+    )
+    .emit(MouseEvent::new("click").unwrap());
+}
+
+/// Start an episode of the playlist being played through, the same way a queued one starts.
+pub fn play_playlist_episode(
+    episode: &PlaylistEpisode,
+    api_key: String,
+    user_id: i32,
+    server_name: String,
+    audio_dispatch: Dispatch<UIState>,
+    audio_state: Rc<UIState>,
+) {
+    on_play_click(
+        episode.episodeurl.clone(),
+        episode.episodetitle.clone(),
+        episode.episodeartwork.clone(),
+        episode.episodeduration,
+        episode.episodeid,
+        episode.listenduration,
+        api_key,
+        user_id,
+        server_name,
+        audio_dispatch,
+        audio_state,
+        None,
+    )
+    .emit(MouseEvent::new("click").unwrap());
 }
 
 #[function_component(AudioPlayer)]
 pub fn audio_player(props: &AudioPlayerProps) -> Html {
+    let i18n = use_i18n();
     let audio_ref = use_node_ref();
     let (state, _dispatch) = use_store::<AppState>();
     let (audio_state, _audio_dispatch) = use_store::<UIState>();
@@ -74,6 +254,8 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
     };
 
     let container_ref = use_node_ref();
+    let video_surface_ref = use_node_ref();
+    let is_video = audio_state.is_video();
 
     let title_click = {
         let audio_dispatch = _audio_dispatch.clone();
@@ -120,20 +302,11 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
             audio_state.current_time_seconds,
             move |&current_time_seconds| {
                 if let Some(chapters) = &audio_state.episode_chapters {
-                    let mut image_updated = false;
-                    for chapter in chapters.iter().rev() {
-                        if let Some(start_time) = chapter.startTime {
-                            if start_time as f64 <= current_time_seconds {
-                                if let Some(img) = &chapter.img {
-                                    current_chapter_image.set(img.clone());
-                                    image_updated = true;
-                                }
-                                break;
-                            }
-                        }
-                    }
-                    if !image_updated {
-                        current_chapter_image.set(original_image_url.clone());
+                    let chapter_image = chapter_index_at(chapters, current_time_seconds)
+                        .and_then(|i| chapters[i].img.clone());
+                    match chapter_image {
+                        Some(img) => current_chapter_image.set(img),
+                        None => current_chapter_image.set(original_image_url.clone()),
                     }
                 } else {
                     current_chapter_image.set(original_image_url.clone());
@@ -464,107 +637,181 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
         }
     });
 
-    // Effect for managing queued episodes
-    use_effect_with(audio_ref.clone(), {
-        let audio_dispatch = _audio_dispatch.clone();
-        let server_name = server_name.clone();
-        let api_key = api_key.clone();
-        let user_id = user_id.clone();
-        let current_episode_id = episode_id.clone(); // Assuming this is correctly obtained elsewhere
-        let audio_state = audio_state.clone();
-        let audio_state_cloned = audio_state.clone();
-        let offline_status = offline_status.clone();
-
-        move |_| {
-            if let Some(audio_element) = audio_state_cloned.audio_element.clone() {
-                // if let Some(audio_element) = audio_ref.cast::<HtmlAudioElement>() {
-                // Clone all necessary data to be used inside the closure to avoid FnOnce limitation.
-
-                let ended_closure = Closure::wrap(Box::new(move || {
-                    let server_name = server_name.clone();
-                    let api_key = api_key.clone();
-                    let user_id = user_id.clone();
-                    let audio_dispatch = audio_dispatch.clone();
-                    let current_episode_id = current_episode_id.clone();
-                    let audio_state = audio_state.clone();
-                    let offline_status_loop = offline_status.unwrap_or(false);
-                    // Closure::wrap(Box::new(move |_| {
-                    if offline_status_loop {
-                        // If offline, do not perform any action
-                    } else {
-                        wasm_bindgen_futures::spawn_local(async move {
-                            let queued_episodes_result = call_get_queued_episodes(
-                                &server_name.clone().unwrap(),
-                                &api_key.clone().unwrap(),
-                                &user_id.clone().unwrap(),
-                            )
-                            .await;
-                            match queued_episodes_result {
-                                Ok(episodes) => {
-                                    if let Some(current_episode) = episodes
-                                        .iter()
-                                        .find(|ep| ep.episodeid == current_episode_id.unwrap())
-                                    {
-                                        let current_queue_position =
-                                            current_episode.queueposition.unwrap_or_default();
-                                        // Remove the currently playing episode from the queue
-                                        let request = QueuePodcastRequest {
-                                            episode_id: current_episode_id.clone().unwrap(),
-                                            user_id: user_id.clone().unwrap(), // replace with the actual user ID
-                                        };
-                                        let remove_result = call_remove_queued_episode(
-                                            &server_name.clone().unwrap(),
-                                            &api_key.clone().unwrap(),
-                                            &request,
-                                        )
-                                        .await;
-                                        match remove_result {
-                                            Ok(_) => {
-                                                // web_sys::console::log_1(&"Successfully removed episode from queue".into());
-                                            }
-                                            Err(_e) => {
-                                                // web_sys::console::log_1(&format!("Failed to remove episode from queue: {:?}", e).into());
-                                            }
-                                        }
-                                        if let Some(next_episode) = episodes.iter().find(|ep| {
-                                            ep.queueposition == Some(current_queue_position + 1)
-                                        }) {
-                                            on_play_click(
-                                                next_episode.episodeurl.clone(),
-                                                next_episode.episodetitle.clone(),
-                                                next_episode.episodeartwork.clone(),
-                                                next_episode.episodeduration,
-                                                next_episode.episodeid,
-                                                next_episode.listenduration,
-                                                api_key.clone().unwrap().unwrap(),
-                                                user_id.unwrap(),
-                                                server_name.clone().unwrap(),
-                                                audio_dispatch.clone(),
-                                                audio_state.clone(),
-                                                None,
-                                            )
-                                            .emit(MouseEvent::new("click").unwrap());
-                                        } else {
-                                            audio_dispatch.reduce_mut(|state| {
-                                                state.audio_playing = Some(false);
-                                            });
+    // Effect for managing queued episodes. Re-registered whenever the episode or the audio
+    // element changes so the ended handler never acts on a stale episode id.
+    let auto_play_next = audio_state
+        .auto_play_next
+        .unwrap_or_else(load_auto_play_next);
+    use_effect_with(
+        (
+            episode_id.clone(),
+            audio_state.audio_element.clone(),
+            auto_play_next,
+        ),
+        {
+            let audio_dispatch = _audio_dispatch.clone();
+            let server_name = server_name.clone();
+            let api_key = api_key.clone();
+            let user_id = user_id.clone();
+            let audio_state = audio_state.clone();
+            let offline_status = offline_status.clone();
+
+            move |(current_episode_id, audio_element, auto_play_next): &(
+                Option<i32>,
+                Option<HtmlMediaElement>,
+                bool,
+            )| {
+                let current_episode_id = *current_episode_id;
+                let auto_play_next = *auto_play_next;
+                let audio_element = audio_element.clone();
+                let ended_closure = audio_element.as_ref().map(|audio_element| {
+                    let ended_closure = Closure::wrap(Box::new(move || {
+                        let server_name = server_name.clone();
+                        let api_key = api_key.clone();
+                        let user_id = user_id.clone();
+                        let audio_dispatch = audio_dispatch.clone();
+                        let audio_state = audio_state.clone();
+                        let offline_status_loop = offline_status.unwrap_or(false);
+                        if offline_status_loop {
+                            // If offline, do not perform any action
+                            return;
+                        }
+                        let (Some(server_name), Some(api_key), Some(user_id)) =
+                            (server_name, api_key, user_id)
+                        else {
+                            return;
+                        };
+                        // A playlist started with play all runs to its end before the queue takes over
+                        if let Some(playback) = audio_state
+                            .playlist_playback
+                            .as_ref()
+                            .filter(|playback| playback.contains(current_episode_id))
+                        {
+                            match playback.next_after(current_episode_id) {
+                                Some(next_episode) if auto_play_next => {
+                                    play_playlist_episode(
+                                        next_episode,
+                                        api_key.unwrap_or_default(),
+                                        user_id,
+                                        server_name,
+                                        audio_dispatch,
+                                        audio_state.clone(),
+                                    );
+                                }
+                                next_episode => {
+                                    let finished = next_episode.is_none();
+                                    SavedPlayerState::clear();
+                                    audio_dispatch.reduce_mut(move |state| {
+                                        state.audio_playing = Some(false);
+                                        if finished {
+                                            state.playlist_playback = None;
                                         }
+                                    });
+                                }
+                            }
+                            return;
+                        }
+                        wasm_bindgen_futures::spawn_local(async move {
+                            let episodes =
+                                match call_get_queued_episodes(&server_name, &api_key, &user_id)
+                                    .await
+                                {
+                                    Ok(episodes) => episodes,
+                                    Err(_e) => {
+                                        // web_sys::console::log_1(&format!("Failed to fetch queued episodes: {:?}", e).into());
+                                        return;
                                     }
+                                };
+                            // Remove the finished episode from the queue
+                            if let Some(current_episode_id) = current_episode_id {
+                                if episodes.iter().any(|ep| ep.episodeid == current_episode_id) {
+                                    let request = QueuePodcastRequest {
+                                        episode_id: current_episode_id,
+                                        user_id,
+                                    };
+                                    let _ =
+                                        call_remove_queued_episode(&server_name, &api_key, &request)
+                                            .await;
+                                }
+                            }
+                            match next_queued_episode(&episodes, current_episode_id)
+                                .filter(|_| auto_play_next)
+                            {
+                                Some(next_episode) => {
+                                    play_queued_episode(
+                                        next_episode,
+                                        api_key.unwrap_or_default(),
+                                        user_id,
+                                        server_name,
+                                        audio_dispatch,
+                                        audio_state,
+                                    );
                                 }
-                                Err(_e) => {
-                                    // web_sys::console::log_1(&format!("Failed to fetch queued episodes: {:?}", e).into());
+                                None => {
+                                    SavedPlayerState::clear();
+                                    audio_dispatch.reduce_mut(|state| {
+                                        state.audio_playing = Some(false);
+                                    });
                                 }
                             }
                         });
+                    }) as Box<dyn FnMut()>);
+                    audio_element.set_onended(Some(ended_closure.as_ref().unchecked_ref()));
+                    ended_closure
+                });
+
+                move || {
+                    if let Some(audio_element) = audio_element {
+                        audio_element.set_onended(None);
                     }
-                    // }) as Box<dyn FnMut()>);
-                }) as Box<dyn FnMut()>);
-                // Setting and forgetting the closure must be done within the same scope
-                audio_element.set_onended(Some(ended_closure.as_ref().unchecked_ref()));
-                ended_closure.forget(); // This will indeed cause a memory leak if the component mounts multiple times
+                    drop(ended_closure);
+                }
             }
+        },
+    );
 
-            || ()
+    // Keep a snapshot of the player in local storage so a reload can bring it back paused
+    use_effect_with(
+        (
+            audio_state.currently_playing.clone(),
+            audio_state.current_time_seconds.floor() as i64,
+            audio_state.playback_speed.to_bits(),
+            audio_state.audio_volume.to_bits(),
+            audio_state.is_expanded,
+        ),
+        {
+            let audio_state = audio_state.clone();
+            let user_id = user_id.clone();
+            move |_| {
+                if let Some(saved) =
+                    user_id.and_then(|user_id| SavedPlayerState::capture(&audio_state, user_id))
+                {
+                    saved.store();
+                }
+                || ()
+            }
+        },
+    );
+
+    // A graph-routed element can't play sources that refuse CORS; fall back to plain playback
+    use_effect_with(audio_state.audio_element.clone(), {
+        let audio_dispatch = _audio_dispatch.clone();
+        move |audio_element: &Option<HtmlMediaElement>| {
+            let audio_element = audio_element.clone();
+            let error_closure = audio_element.as_ref().map(|audio_element| {
+                let error_closure = Closure::wrap(Box::new(move || {
+                    audio_dispatch.reduce_mut(UIState::fall_back_to_direct_playback);
+                }) as Box<dyn FnMut()>);
+                audio_element.set_onerror(Some(error_closure.as_ref().unchecked_ref()));
+                error_closure
+            });
+
+            move || {
+                if let Some(audio_element) = audio_element {
+                    audio_element.set_onerror(None);
+                }
+                drop(error_closure);
+            }
         }
     });
 
@@ -615,8 +862,13 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
             speed_dispatch.reduce_mut(|speed_state| {
                 speed_state.playback_speed = speed;
                 if let Some(audio_element) = &speed_state.audio_element {
+                    audio_element.set_default_playback_rate(speed);
                     audio_element.set_playback_rate(speed);
                 }
+                // Podcasts with their own speed don't move the remembered default
+                if speed_state.podcast_playback_speed.is_none() {
+                    store_default_playback_speed(speed);
+                }
             });
         })
     };
@@ -684,58 +936,421 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
         let audio_state = audio_state.clone();
 
         Callback::from(move |_: MouseEvent| {
-            let server_name = server_name.clone();
-            let api_key = api_key.clone();
+            let (Some(server_name), Some(api_key), Some(user_id)) =
+                (server_name.clone(), api_key.clone(), user_id)
+            else {
+                return;
+            };
             let audio_dispatch = audio_dispatch.clone();
             let audio_state = audio_state.clone();
+            if let Some(playback) = audio_state
+                .playlist_playback
+                .as_ref()
+                .filter(|playback| playback.contains(current_episode_id))
+            {
+                match playback.next_after(current_episode_id) {
+                    Some(next_episode) => play_playlist_episode(
+                        next_episode,
+                        api_key.unwrap_or_default(),
+                        user_id,
+                        server_name,
+                        audio_dispatch,
+                        audio_state.clone(),
+                    ),
+                    None => audio_dispatch.reduce_mut(|state| {
+                        state.audio_playing = Some(false);
+                        state.playlist_playback = None;
+                    }),
+                }
+                return;
+            }
             wasm_bindgen_futures::spawn_local(async move {
-                let episodes_result = call_get_queued_episodes(
-                    &server_name.clone().unwrap(),
-                    &api_key.clone().unwrap(),
-                    &user_id.clone().unwrap(),
-                )
-                .await;
+                let episodes_result =
+                    call_get_queued_episodes(&server_name, &api_key, &user_id).await;
                 if let Ok(episodes) = episodes_result {
-                    if let Some(current_episode) = episodes
-                        .iter()
-                        .find(|ep| ep.episodeid == current_episode_id.unwrap())
+                    if let Some(next_episode) = next_queued_episode(&episodes, current_episode_id)
                     {
-                        let current_queue_position =
-                            current_episode.queueposition.unwrap_or_default();
+                        play_queued_episode(
+                            next_episode,
+                            api_key.unwrap_or_default(),
+                            user_id,
+                            server_name,
+                            audio_dispatch,
+                            audio_state,
+                        );
+                    } else {
+                        audio_dispatch.reduce_mut(|state| {
+                            state.audio_playing = Some(false);
+                        });
+                    }
+                } else {
+                    // Handle the error, maybe log it or show a user-facing message
+                    web_sys::console::log_1(&"Failed to fetch queued episodes".into());
+                }
+            });
+        })
+    };
+
+    let processing = audio_state.audio_processing();
+    let processing_toggle = |toggle: fn(&mut AudioProcessing)| {
+        let audio_dispatch = _audio_dispatch.clone();
+        Callback::from(move |_: MouseEvent| {
+            audio_dispatch.reduce_mut(|state| {
+                let mut processing = state.audio_processing();
+                toggle(&mut processing);
+                state.set_audio_processing(processing);
+            });
+        })
+    };
+    let toggle_trim_silence = processing_toggle(|p| p.trim_silence = !p.trim_silence);
+    let toggle_voice_boost = processing_toggle(|p| p.voice_boost = !p.voice_boost);
+    let toggle_mono = processing_toggle(|p| p.mono = !p.mono);
 
-                        if let Some(next_episode) = episodes
-                            .iter()
-                            .find(|ep| ep.queueposition == Some(current_queue_position + 1))
+    // Run through silences while trimming is on, banking the time it saves into user stats
+    use_effect_with(
+        (audio_state.audio_graph.is_some(), processing.trim_silence),
+        {
+            let audio_dispatch = _audio_dispatch.clone();
+            let server_name = server_name.clone();
+            let api_key = api_key.clone();
+            let user_id = user_id.clone();
+            let offline_status = offline_status.clone();
+
+            move |&(has_graph, trim_silence): &(bool, bool)| {
+                let trimmer = Rc::new(RefCell::new(SilenceTrimmer::default()));
+                // Seconds not yet shown in the player / not yet sent to the server
+                let undisplayed = Rc::new(Cell::new(0.0f64));
+                let unreported = Rc::new(Cell::new(0.0f64));
+                let report = {
+                    let unreported = unreported.clone();
+                    move || {
+                        let seconds = unreported.get().floor();
+                        if seconds < 1.0 || offline_status.unwrap_or(false) {
+                            return;
+                        }
+                        if let (Some(server_name), Some(Some(api_key)), Some(user_id)) =
+                            (server_name.clone(), api_key.clone(), user_id)
                         {
-                            on_play_click(
-                                next_episode.episodeurl.clone(),
-                                next_episode.episodetitle.clone(),
-                                next_episode.episodeartwork.clone(),
-                                next_episode.episodeduration,
-                                next_episode.episodeid,
-                                next_episode.listenduration,
-                                api_key.clone().unwrap().unwrap(),
-                                user_id.unwrap(),
-                                server_name.clone().unwrap(),
-                                audio_dispatch.clone(),
-                                audio_state.clone(),
-                                None,
-                            )
-                            .emit(MouseEvent::new("click").unwrap());
-                        } else {
-                            audio_dispatch.reduce_mut(|state| {
-                                state.audio_playing = Some(false);
+                            unreported.set(unreported.get() - seconds);
+                            spawn_local(async move {
+                                if let Err(e) = call_increment_time_saved(
+                                    &server_name,
+                                    &api_key,
+                                    user_id,
+                                    seconds as i32,
+                                )
+                                .await
+                                {
+                                    web_sys::console::log_1(
+                                        &format!("Failed to record time saved: {:?}", e).into(),
+                                    );
+                                }
                             });
                         }
                     }
-                } else {
-                    // Handle the error, maybe log it or show a user-facing message
-                    web_sys::console::log_1(&"Failed to fetch queued episodes".into());
+                };
+
+                let interval = (has_graph && trim_silence).then(|| {
+                    let audio_dispatch = audio_dispatch.clone();
+                    let trimmer = trimmer.clone();
+                    let undisplayed = undisplayed.clone();
+                    let unreported = unreported.clone();
+                    let report = report.clone();
+                    Interval::new(SILENCE_POLL_MS, move || {
+                        let state = audio_dispatch.get();
+                        let (Some(graph), Some(audio)) =
+                            (state.audio_graph.as_ref(), state.audio_element.as_ref())
+                        else {
+                            return;
+                        };
+                        let saved = trimmer.borrow_mut().tick(
+                            graph,
+                            audio,
+                            state.playback_speed,
+                            SILENCE_POLL_MS as f64 / 1000.0,
+                        );
+                        if saved <= 0.0 {
+                            return;
+                        }
+                        undisplayed.set(undisplayed.get() + saved);
+                        unreported.set(unreported.get() + saved);
+                        if undisplayed.get() >= 1.0 {
+                            let seconds = undisplayed.replace(0.0);
+                            audio_dispatch.reduce_mut(|state| state.silence_time_saved += seconds);
+                        }
+                        if unreported.get() >= 30.0 {
+                            report();
+                        }
+                    })
+                });
+
+                move || {
+                    drop(interval);
+                    let state = audio_dispatch.get();
+                    if let Some(audio) = state.audio_element.as_ref() {
+                        trimmer.borrow_mut().restore(audio, state.playback_speed);
+                    }
+                    let seconds = undisplayed.replace(0.0);
+                    if seconds > 0.0 {
+                        audio_dispatch.reduce_mut(|state| state.silence_time_saved += seconds);
+                    }
+                    report();
+                }
+            }
+        },
+    );
+
+    // Shared clips stop at their end point instead of running on through the episode
+    let clip_end = audio_state
+        .clip_end
+        .filter(|(clip_episode_id, _)| Some(*clip_episode_id) == episode_id)
+        .map(|(_, end)| end);
+    use_effect_with(clip_end.map(f64::to_bits), {
+        let audio_dispatch = _audio_dispatch.clone();
+        move |_| {
+            let interval = clip_end.map(|end| {
+                Interval::new(CLIP_END_POLL_MS, move || {
+                    let reached = audio_dispatch
+                        .get()
+                        .audio_element
+                        .as_ref()
+                        .map_or(false, |audio| audio.current_time() >= end);
+                    if reached {
+                        audio_dispatch.reduce_mut(|state| {
+                            if let Some(audio) = state.audio_element.as_ref() {
+                                let _ = audio.pause();
+                            }
+                            state.audio_playing = Some(false);
+                            state.clip_end = None;
+                        });
+                    }
+                })
+            });
+            move || drop(interval)
+        }
+    });
+
+    // Video episodes play in a `<video>` element owned by the store; mount it in the expanded
+    // player. Moving it between pages can pause it, so pick playback back up if it should be going.
+    use_effect_with(audio_state.audio_element.clone(), {
+        let video_surface_ref = video_surface_ref.clone();
+        let audio_dispatch = _audio_dispatch.clone();
+        move |media: &Option<HtmlMediaElement>| {
+            if let (Some(media), Some(surface)) = (
+                media.as_ref().filter(|media| media.dyn_ref::<HtmlVideoElement>().is_some()),
+                video_surface_ref.cast::<HtmlElement>(),
+            ) {
+                if !surface.contains(Some(media)) {
+                    let _ = surface.append_child(media);
+                }
+                if media.paused() && audio_dispatch.get().audio_playing.unwrap_or(false) {
+                    let _ = media.play();
+                }
+            }
+            || ()
+        }
+    });
+
+    let pip_supported = window()
+        .and_then(|window| window.document())
+        .and_then(|document| {
+            document
+                .unchecked_ref::<PipDocument>()
+                .picture_in_picture_enabled()
+        })
+        .unwrap_or(false);
+
+    let toggle_picture_in_picture = {
+        let audio_dispatch = _audio_dispatch.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(document) = window().and_then(|window| window.document()) else {
+                return;
+            };
+            let document = document.unchecked_into::<PipDocument>();
+            let promise = if document.picture_in_picture_element().is_some() {
+                document.exit_picture_in_picture()
+            } else if let Some(video) = audio_dispatch
+                .get()
+                .audio_element
+                .as_ref()
+                .and_then(|media| media.dyn_ref::<HtmlVideoElement>())
+            {
+                video
+                    .unchecked_ref::<PipVideoElement>()
+                    .request_picture_in_picture()
+            } else {
+                return;
+            };
+            spawn_local(async move {
+                if let Err(e) = JsFuture::from(promise).await {
+                    notify_error(I18n::current().tf("audio.pip_failed", &[("error", &format!("{:?}", e))]));
                 }
             });
         })
     };
 
+    let toggle_auto_play_next = {
+        let audio_dispatch = _audio_dispatch.clone();
+        Callback::from(move |_: MouseEvent| {
+            audio_dispatch.reduce_mut(|state| {
+                let enabled = !state.auto_play_next.unwrap_or_else(load_auto_play_next);
+                store_auto_play_next(enabled);
+                state.auto_play_next = Some(enabled);
+            });
+        })
+    };
+
+    // Position captured when the bookmark button was pressed, while the note is being typed
+    let pending_bookmark: UseStateHandle<Option<i32>> = use_state(|| None);
+    let bookmark_note = use_state(String::new);
+
+    let on_bookmark_click = {
+        let pending_bookmark = pending_bookmark.clone();
+        let bookmark_note = bookmark_note.clone();
+        let current_time = audio_state.current_time_seconds;
+        Callback::from(move |_: MouseEvent| {
+            bookmark_note.set(String::new());
+            pending_bookmark.set(Some(current_time.floor() as i32));
+        })
+    };
+
+    let on_bookmark_note_input = {
+        let bookmark_note = bookmark_note.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            bookmark_note.set(input.value());
+        })
+    };
+
+    let on_bookmark_cancel = {
+        let pending_bookmark = pending_bookmark.clone();
+        Callback::from(move |_: MouseEvent| pending_bookmark.set(None))
+    };
+
+    let on_bookmark_save = {
+        let pending_bookmark = pending_bookmark.clone();
+        let bookmark_note = bookmark_note.clone();
+        let audio_dispatch = _audio_dispatch.clone();
+        let server_name = server_name.clone();
+        let api_key = api_key.clone().flatten();
+        Callback::from(move |_: MouseEvent| {
+            if let (Some(time), Some(episode_id), Some(user_id), Some(server_name)) =
+                (*pending_bookmark, episode_id, user_id, server_name.clone())
+            {
+                let note = bookmark_note.trim().to_string();
+                let request = AddBookmarkRequest {
+                    episode_id,
+                    user_id,
+                    time,
+                    note: if note.is_empty() { None } else { Some(note) },
+                };
+                let api_key = api_key.clone();
+                let audio_dispatch = audio_dispatch.clone();
+                pending_bookmark.set(None);
+                spawn_local(async move {
+                    match call_add_bookmark(&server_name, &api_key, &request).await {
+                        Ok(_) => audio_dispatch.reduce_mut(|state| {
+                            state.bookmarks_version = state.bookmarks_version.wrapping_add(1);
+                            notify_info(I18n::current().t("audio.bookmark_added"));
+                        }),
+                        Err(e) => notify_error(format!("{}", e)),
+                    }
+                });
+            }
+        })
+    };
+
+    // Clip picker: start and optional end (in seconds) and the link created for them
+    let clip_picker_open = use_state(|| false);
+    let clip_start: UseStateHandle<i32> = use_state(|| 0);
+    let clip_end_mark: UseStateHandle<Option<i32>> = use_state(|| None);
+    let clip_url: UseStateHandle<Option<String>> = use_state(|| None);
+
+    let toggle_clip_picker = {
+        let clip_picker_open = clip_picker_open.clone();
+        let clip_start = clip_start.clone();
+        let clip_end_mark = clip_end_mark.clone();
+        let clip_url = clip_url.clone();
+        let current_time = audio_state.current_time_seconds;
+        Callback::from(move |_: MouseEvent| {
+            if !*clip_picker_open {
+                clip_start.set(current_time.floor() as i32);
+                clip_end_mark.set(None);
+                clip_url.set(None);
+            }
+            clip_picker_open.set(!*clip_picker_open);
+        })
+    };
+
+    let on_clip_mark_start = {
+        let clip_start = clip_start.clone();
+        let clip_url = clip_url.clone();
+        let current_time = audio_state.current_time_seconds;
+        Callback::from(move |_: MouseEvent| {
+            clip_start.set(current_time.floor() as i32);
+            clip_url.set(None);
+        })
+    };
+
+    let on_clip_mark_end = {
+        let clip_end_mark = clip_end_mark.clone();
+        let clip_url = clip_url.clone();
+        let current_time = audio_state.current_time_seconds;
+        Callback::from(move |_: MouseEvent| {
+            clip_end_mark.set(Some(current_time.ceil() as i32));
+            clip_url.set(None);
+        })
+    };
+
+    let on_clip_clear_end = {
+        let clip_end_mark = clip_end_mark.clone();
+        let clip_url = clip_url.clone();
+        Callback::from(move |_: MouseEvent| {
+            clip_end_mark.set(None);
+            clip_url.set(None);
+        })
+    };
+
+    let on_create_clip = {
+        let clip_start = clip_start.clone();
+        let clip_end_mark = clip_end_mark.clone();
+        let clip_url = clip_url.clone();
+        let server_name = server_name.clone();
+        let api_key = api_key.clone().flatten();
+        Callback::from(move |_: MouseEvent| {
+            let start = *clip_start;
+            let end = *clip_end_mark;
+            if end.map_or(false, |end| end <= start) {
+                notify_error(I18n::current().t("audio.clip_end_before_start"));
+                return;
+            }
+            if let (Some(episode_id), Some(server_name), Some(api_key)) =
+                (episode_id, server_name.clone(), api_key.clone())
+            {
+                let clip_url = clip_url.clone();
+                spawn_local(async move {
+                    match call_create_share_link(
+                        &server_name,
+                        &api_key,
+                        episode_id,
+                        Some(start),
+                        end,
+                    )
+                    .await
+                    {
+                        Ok(url_key) => {
+                            clip_url.set(Some(format!(
+                                "{}/shared_episode/{}",
+                                server_name, url_key
+                            )));
+                        }
+                        Err(e) => notify_error(format!("{}", e)),
+                    }
+                });
+            }
+        })
+    };
+
     let on_chapter_click = {
         let audio_dispatch = _audio_dispatch.clone();
         Callback::from(move |start_time: i32| {
@@ -756,6 +1371,55 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
         })
     };
 
+    let nav_chapters = audio_state
+        .episode_chapters
+        .as_deref()
+        .map(toc_chapters)
+        .unwrap_or_default();
+    let current_chapter_index = chapter_index_at(&nav_chapters, audio_state.current_time_seconds);
+
+    let on_next_chapter = {
+        let on_chapter_click = on_chapter_click.clone();
+        let nav_chapters = nav_chapters.clone();
+        let audio_element = audio_state.audio_element.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(audio_element) = audio_element.as_ref() {
+                let current_time = audio_element.current_time();
+                if let Some(next_start) = nav_chapters
+                    .iter()
+                    .filter_map(|chapter| chapter.startTime)
+                    .find(|&start| start as f64 > current_time)
+                {
+                    on_chapter_click.emit(next_start);
+                }
+            }
+        })
+    };
+
+    let on_previous_chapter = {
+        let on_chapter_click = on_chapter_click.clone();
+        let nav_chapters = nav_chapters.clone();
+        let audio_element = audio_state.audio_element.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(audio_element) = audio_element.as_ref() {
+                let current_time = audio_element.current_time();
+                let starts: Vec<i32> = nav_chapters
+                    .iter()
+                    .filter_map(|chapter| chapter.startTime)
+                    .collect();
+                if let Some(i) = starts.iter().rposition(|&start| start as f64 <= current_time) {
+                    // Like a CD player: restart the chapter unless we're right at its beginning
+                    let target = if current_time - starts[i] as f64 > 3.0 || i == 0 {
+                        starts[i]
+                    } else {
+                        starts[i - 1]
+                    };
+                    on_chapter_click.emit(target);
+                }
+            }
+        })
+    };
+
     #[derive(Clone, PartialEq)]
     enum PageState {
         Hidden,
@@ -785,34 +1449,44 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Chapters"}
+                            { i18n.t("audio.chapters") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <ul class="chapters-list">
-                            { if let Some(chapters) = &audio_state.episode_chapters {
-                                chapters.iter().map(|chapter| {
-                                    let start_time_click = chapter.startTime.clone().unwrap_or_default();
-                                    let start_time = format_time_rm_hour(chapter.startTime.clone().unwrap_or_default() as f64);
-                                    let click_start_time = start_time_click.clone();
-                                    let on_chapter_click = on_chapter_click.clone();
-                                    html! {
-                                        <li onclick={Callback::from(move |_| {
+                            { nav_chapters.iter().enumerate().map(|(index, chapter)| {
+                                let start_time_click = chapter.startTime.clone().unwrap_or_default();
+                                let start_time = format_time_rm_hour(chapter.startTime.clone().unwrap_or_default() as f64);
+                                let click_start_time = start_time_click.clone();
+                                let on_chapter_click = on_chapter_click.clone();
+                                let chapter_class = if current_chapter_index == Some(index) { "current-chapter" } else { "" };
+                                html! {
+                                    <li class={chapter_class}>
+                                        <span onclick={Callback::from(move |_| {
                                             on_chapter_click.emit(click_start_time.clone());
                                         })}>
                                             { format!("{} - {}", start_time, chapter.title) }
-                                        </li>
-                                    }
-                                }).collect::<Html>()
-                            } else {
-                                html! {}
-                            }}
+                                        </span>
+                                        {
+                                            if let Some(url) = &chapter.url {
+                                                html! {
+                                                    <a href={url.clone()} target="_blank" rel="noopener noreferrer" class="chapter-link" title={i18n.t("audio.open_chapter_link")}>
+                                                        <span class="material-icons">{"link"}</span>
+                                                    </a>
+                                                }
+                                            } else {
+                                                html! {}
+                                            }
+                                        }
+                                    </li>
+                                }
+                            }).collect::<Html>() }
                         </ul>
                     </div>
                 </div>
@@ -887,6 +1561,8 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
         );
         let update_volume_closure = update_playback_volume.clone();
         let update_playback_closure = update_playback_speed.clone();
+        let current_chapter = current_chapter_index.map(|i| nav_chapters[i].clone());
+        let has_chapters = !nav_chapters.is_empty();
         html! {
             <>
             {
@@ -901,21 +1577,56 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
                     <button onclick={title_click.clone()} class="retract-button">
                         <span class="material-icons">{"expand_more"}</span>
                     </button>
-                    <div class="audio-image-container">
-                        <img onclick={title_click.clone()} src={(*current_chapter_image).clone()} />
-                    </div>
+                    {
+                        if is_video {
+                            html! {
+                                <div class="video-surface" ref={video_surface_ref.clone()}></div>
+                            }
+                        } else {
+                            html! {
+                                <div class="audio-image-container">
+                                    <img onclick={title_click.clone()} src={(*current_chapter_image).clone()} />
+                                </div>
+                            }
+                        }
+                    }
                     <div class="title" onclick={title_click.clone()}>{ &audio_props.title }
                     </div>
+                    {
+                        if let Some(chapter) = &current_chapter {
+                            html! {
+                                <div class="current-chapter-title">
+                                    { &chapter.title }
+                                    {
+                                        if let Some(url) = &chapter.url {
+                                            html! {
+                                                <a href={url.clone()} target="_blank" rel="noopener noreferrer" class="chapter-link" title={i18n.t("audio.open_chapter_link")}>
+                                                    <i class="material-icons">{"link"}</i>
+                                                </a>
+                                            }
+                                        } else {
+                                            html! {}
+                                        }
+                                    }
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
                     <div class="scrub-bar">
                         <span>{audio_state.current_time_formatted.clone()}</span>
-                        <input type="range"
-                            class="flex-grow h-1 cursor-pointer"
-                            min="0.0"
-                            max={audio_props.duration_sec.to_string().clone()}
-                            value={audio_state.current_time_seconds.to_string()}
-                            oninput={update_time.clone()}
-                            style={progress_style}
-                        />
+                        <div class="scrub-track">
+                            <input type="range"
+                                class="flex-grow h-1 cursor-pointer"
+                                min="0.0"
+                                max={audio_props.duration_sec.to_string().clone()}
+                                value={audio_state.current_time_seconds.to_string()}
+                                oninput={update_time.clone()}
+                                style={progress_style}
+                            />
+                            { chapter_markers(&nav_chapters, audio_props.duration_sec) }
+                        </div>
                         <span>{formatted_duration.clone()}</span>
                     </div>
 
@@ -932,6 +1643,17 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
                                 </>
                             }
                         }
+                        {
+                            if has_chapters {
+                                html! {
+                                    <button onclick={on_previous_chapter.clone()} title={i18n.t("audio.previous_chapter")} class="skip-button audio-top-button selector-button font-bold py-2 px-4 rounded-full w-10 h-10 flex items-center justify-center">
+                                        <span class="material-icons">{"navigate_before"}</span>
+                                    </button>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
                         <button onclick={skip_backward.clone()} class="rewind-button audio-top-button selector-button font-bold py-2 px-4 rounded-full w-10 h-10 flex items-center justify-center">
                             <span class="material-icons">{"fast_rewind"}</span>
                         </button>
@@ -943,6 +1665,17 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
                         <button onclick={skip_forward.clone()} class="skip-button audio-top-button selector-button font-bold py-2 px-4 rounded-full w-10 h-10 flex items-center justify-center">
                             <span class="material-icons">{"fast_forward"}</span>
                         </button>
+                        {
+                            if has_chapters {
+                                html! {
+                                    <button onclick={on_next_chapter.clone()} title={i18n.t("audio.next_chapter")} class="skip-button audio-top-button selector-button font-bold py-2 px-4 rounded-full w-10 h-10 flex items-center justify-center">
+                                        <span class="material-icons">{"navigate_next"}</span>
+                                    </button>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
                         <button onclick={skip_episode.clone()} class="skip-button audio-top-button selector-button font-bold py-2 px-4 rounded-full w-10 h-10 flex items-center justify-center">
                             <span class="material-icons">{"skip_next"}</span>
                         </button>
@@ -958,7 +1691,7 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
                                     type="range"
                                     class="slider"  // Center this slider independently
                                     min="0.5"
-                                    max="2.0"
+                                    max="3.0"
                                     step="0.1"
                                     value={audio_state.playback_speed.to_string()}
                                     oninput={Callback::from(move |event: InputEvent| {
@@ -980,20 +1713,16 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
                                     on_shownotes_click.emit(e.clone());
                                     title_click_emit.emit(e);
                                 })} class="audio-top-button audio-full-button border-solid border selector-button font-bold py-2 px-4 mt-3 rounded-full flex items-center justify-center">
-                                    { "Shownotes" }
+                                    { i18n.t("audio.shownotes") }
                                 </button>
                                 {
-                                    if let Some(chapters) = &audio_state.episode_chapters {
-                                        if !chapters.is_empty() {
-                                            html! {
-                                                <button onclick={Callback::from(move |_: MouseEvent| {
-                                                    on_chapter_select.emit(());
-                                                })} class="audio-top-button audio-full-button border-solid border selector-button font-bold py-2 px-4 mt-3 rounded-full flex items-center justify-center">
-                                                    { "Chapters" }
-                                                </button>
-                                            }
-                                        } else {
-                                            html! {}
+                                    if has_chapters {
+                                        html! {
+                                            <button onclick={Callback::from(move |_: MouseEvent| {
+                                                on_chapter_select.emit(());
+                                            })} class="audio-top-button audio-full-button border-solid border selector-button font-bold py-2 px-4 mt-3 rounded-full flex items-center justify-center">
+                                                { i18n.t("audio.chapters") }
+                                            </button>
                                         }
                                     } else {
                                         html! {}
@@ -1004,11 +1733,41 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
                         } else {
                             html! {
                                 <button disabled=true class="item-container-button audio-full-button border-solid border selector-button font-bold py-2 px-4 rounded-full flex items-center justify-center opacity-50 cursor-not-allowed">
-                                    { "Shownotes (Unavailable)" }
+                                    { i18n.t("audio.shownotes_unavailable") }
+                                </button>
+                            }
+                        }
+                    }
+                    {
+                        if episode_in_db {
+                            html! {
+                                <>
+                                <button onclick={on_bookmark_click} title={i18n.t("audio.bookmark_title")} class="skip-button audio-top-button selector-button font-bold py-2 px-4 rounded-full w-10 h-10 flex items-center justify-center">
+                                    <span class="material-icons">{"bookmark_add"}</span>
+                                </button>
+                                <button onclick={toggle_clip_picker} title={i18n.t("audio.share_clip")} class="skip-button audio-top-button selector-button font-bold py-2 px-4 rounded-full w-10 h-10 flex items-center justify-center">
+                                    <span class="material-icons">{"share"}</span>
+                                </button>
+                                </>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    {
+                        if is_video && pip_supported {
+                            html! {
+                                <button onclick={toggle_picture_in_picture} title={i18n.t("audio.picture_in_picture")} class="skip-button audio-top-button selector-button font-bold py-2 px-4 rounded-full w-10 h-10 flex items-center justify-center">
+                                    <span class="material-icons">{"picture_in_picture_alt"}</span>
                                 </button>
                             }
+                        } else {
+                            html! {}
                         }
                     }
+                    <button onclick={toggle_auto_play_next} title={i18n.t(if auto_play_next { "audio.auto_play_next_on" } else { "audio.auto_play_next_off" })} class={classes!("skip-button", "audio-top-button", "selector-button", "font-bold", "py-2", "px-4", "rounded-full", "w-10", "h-10", "flex", "items-center", "justify-center", "auto-play-next-button", if auto_play_next {"active"} else {""})}>
+                        <span class="material-icons">{"playlist_play"}</span>
+                    </button>
                     <button onclick={on_volume_control_click.clone()} class="skip-button audio-top-button selector-button font-bold py-2 px-4 rounded-full w-10 h-10 flex items-center justify-center custom-volume-button">
                         <span class="material-icons">{"volume_up"}</span>
                     </button>
@@ -1033,6 +1792,117 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
                         </div>
                     </div>
                     </div>
+                    {
+                        if let Some(time) = *pending_bookmark {
+                            html! {
+                                <div class="bookmark-note-entry flex items-center justify-center">
+                                    <span class="bookmark-time">{ format_time_rm_hour(time as f64) }</span>
+                                    <input
+                                        type="text"
+                                        class="search-bar-input border text-sm rounded-lg p-2.5"
+                                        placeholder={i18n.t("audio.bookmark_note_placeholder")}
+                                        value={(*bookmark_note).clone()}
+                                        oninput={on_bookmark_note_input}
+                                    />
+                                    <button onclick={on_bookmark_save} class="audio-processing-button active">
+                                        <span class="material-icons">{"bookmark"}</span>
+                                        { i18n.t("common.save") }
+                                    </button>
+                                    <button onclick={on_bookmark_cancel} class="audio-processing-button">
+                                        { i18n.t("common.cancel") }
+                                    </button>
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    {
+                        if *clip_picker_open && episode_in_db {
+                            html! {
+                                <div class="clip-picker">
+                                    <div class="clip-picker-row flex items-center justify-center">
+                                        <span class="clip-picker-label">{ i18n.t("audio.clip_start") }</span>
+                                        <span class="bookmark-time">{ format_time_rm_hour(*clip_start as f64) }</span>
+                                        <button onclick={on_clip_mark_start} class="audio-processing-button">{ i18n.t("audio.clip_set_now") }</button>
+                                    </div>
+                                    <div class="clip-picker-row flex items-center justify-center">
+                                        <span class="clip-picker-label">{ i18n.t("audio.clip_end") }</span>
+                                        <span class="bookmark-time">
+                                            { clip_end_mark.map(|end| format_time_rm_hour(end as f64)).unwrap_or_else(|| i18n.t("audio.clip_not_set")) }
+                                        </span>
+                                        <button onclick={on_clip_mark_end} class="audio-processing-button">{ i18n.t("audio.clip_set_now") }</button>
+                                        {
+                                            if clip_end_mark.is_some() {
+                                                html! {
+                                                    <button onclick={on_clip_clear_end} class="audio-processing-button">{ i18n.t("audio.clip_clear") }</button>
+                                                }
+                                            } else {
+                                                html! {}
+                                            }
+                                        }
+                                    </div>
+                                    <div class="clip-picker-row flex items-center justify-center">
+                                        <button onclick={on_create_clip} class="audio-processing-button active">
+                                            <span class="material-icons">{"link"}</span>
+                                            { i18n.t("audio.clip_create_link") }
+                                        </button>
+                                    </div>
+                                    {
+                                        if let Some(url) = (*clip_url).clone() {
+                                            html! {
+                                                <input type="text" class="input-black w-full px-3 py-2 border border-gray-300 rounded-md" value={url} readonly=true />
+                                            }
+                                        } else {
+                                            html! {}
+                                        }
+                                    }
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    <div class="audio-processing-controls flex items-center justify-center">
+                        <button onclick={toggle_trim_silence} title={i18n.t("audio.trim_silence_title")} class={classes!("audio-processing-button", processing.trim_silence.then_some("active"))}>
+                            <span class="material-icons">{"content_cut"}</span>
+                            { i18n.t("audio.trim_silence") }
+                        </button>
+                        <button onclick={toggle_voice_boost} title={i18n.t("audio.voice_boost_title")} class={classes!("audio-processing-button", processing.voice_boost.then_some("active"))}>
+                            <span class="material-icons">{"record_voice_over"}</span>
+                            { i18n.t("audio.voice_boost") }
+                        </button>
+                        <button onclick={toggle_mono} title={i18n.t("audio.mono_title")} class={classes!("audio-processing-button", processing.mono.then_some("active"))}>
+                            <span class="material-icons">{"hearing"}</span>
+                            { i18n.t("audio.mono") }
+                        </button>
+                        {
+                            if audio_state.silence_time_saved >= 1.0 {
+                                html! {
+                                    <span class="time-saved">
+                                        { i18n.tf("audio.time_saved", &[("time", &format_time_rm_hour(audio_state.silence_time_saved))]) }
+                                    </span>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                    </div>
+                    {
+                        if let Some(transcripts) = &audio_state.episode_transcript {
+                            if audio_state.is_expanded && !transcripts.is_empty() {
+                                html! {
+                                    <div class="audio-transcript-container">
+                                        <TranscriptViewer transcripts={transcripts.clone()} episode_id={audio_props.episode_id} />
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
                     </div>
 
                 </div>
@@ -1041,6 +1911,13 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
                     <img class={artwork_class} src={audio_props.artwork_url.clone()} />
                     <div class="title" onclick={title_click.clone()}>
                         <span>{ &audio_props.title }</span>
+                        {
+                            if let Some(chapter) = &current_chapter {
+                                html! { <span class="line-chapter-title">{ &chapter.title }</span> }
+                            } else {
+                                html! {}
+                            }
+                        }
                     </div>
                 </div>
                 <div class="right-group">
@@ -1055,12 +1932,15 @@ pub fn audio_player(props: &AudioPlayerProps) -> Html {
                     <div class="flex-grow flex items-center sm:block hidden">
                         <div class="flex items-center flex-nowrap">
                             <span class="time-display px-2">{audio_state.current_time_formatted.clone()}</span>
-                            <input type="range"
-                                class="flex-grow h-1 cursor-pointer"
-                                min="0.0"
-                                max={audio_props.duration_sec.to_string().clone()}
-                                value={audio_state.current_time_seconds.to_string()}
-                                oninput={update_time.clone()} />
+                            <div class="scrub-track">
+                                <input type="range"
+                                    class="flex-grow h-1 cursor-pointer"
+                                    min="0.0"
+                                    max={audio_props.duration_sec.to_string().clone()}
+                                    value={audio_state.current_time_seconds.to_string()}
+                                    oninput={update_time.clone()} />
+                                { chapter_markers(&nav_chapters, audio_props.duration_sec) }
+                            </div>
                             <span class="time-display px-2">{formatted_duration}</span>
                         </div>
                     </div>
@@ -1226,7 +2106,12 @@ pub fn on_play_click(
 
                 let queue_api = Option::from(queue_api_key);
 
-                let add_queue_future = call_queue_episode(&queue_server_name, &queue_api, &request);
+                let add_queue_future = call_queue_episode(
+                    &queue_server_name,
+                    &queue_api,
+                    &request,
+                    QueueInsert::Last,
+                );
                 match add_queue_future.await {
                     Ok(_) => {
                         // web_sys::console::log_1(&"Successfully Added Episode to Queue".into());
@@ -1273,6 +2158,18 @@ pub fn on_play_click(
         wasm_bindgen_futures::spawn_local(async move {
             if episode_id != 0 {
                 web_sys::console::log_1(&JsValue::from_str("must not be zero"));
+                // The feed's declared type decides between the audio and video player
+                let enclosure_type = call_get_episode_metadata(
+                    &server_name,
+                    Some(api_key.clone()),
+                    &EpisodeRequest {
+                        episode_id,
+                        user_id,
+                    },
+                )
+                .await
+                .ok()
+                .and_then(|episode| episode.enclosuretype);
                 match call_get_podcast_id_from_ep(
                     &server_name,
                     &Some(api_key.clone()),
@@ -1290,14 +2187,19 @@ pub fn on_play_click(
                         )
                         .await
                         {
-                            Ok((start_skip, end_skip)) => {
-                                let start_pos_sec =
-                                    listen_duration_for_closure.unwrap_or(0).max(start_skip) as f64;
-                                let end_pos_sec = end_skip as f64;
+                            Ok(settings) => {
+                                let start_pos_sec = listen_duration_for_closure
+                                    .unwrap_or(0)
+                                    .max(settings.start_skip)
+                                    as f64;
+                                let end_pos_sec = settings.end_skip as f64;
 
                                 audio_dispatch.reduce_mut(move |audio_state| {
                                     audio_state.audio_playing = Some(true);
-                                    audio_state.playback_speed = 1.0;
+                                    audio_state.apply_podcast_playback_settings(
+                                        settings.playback_speed,
+                                        settings.volume_boost,
+                                    );
                                     audio_state.audio_volume = 100.0;
                                     audio_state.offline = Some(false);
                                     audio_state.currently_playing = Some(AudioPlayerProps {
@@ -1310,6 +2212,7 @@ pub fn on_play_click(
                                         start_pos_sec,
                                         end_pos_sec: end_pos_sec as f64,
                                         offline: false,
+                                        enclosure_type: enclosure_type.clone(),
                                     });
                                     audio_state.set_audio_source(src.to_string());
                                     if let Some(audio) = &audio_state.audio_element {
@@ -1336,7 +2239,7 @@ pub fn on_play_click(
                 web_sys::console::log_1(&JsValue::from_str("must be zero"));
                 audio_dispatch.reduce_mut(move |audio_state| {
                     audio_state.audio_playing = Some(true);
-                    audio_state.playback_speed = 1.0;
+                    audio_state.apply_podcast_playback_settings(None, 100);
                     audio_state.audio_volume = 100.0;
                     audio_state.offline = Some(false);
                     audio_state.currently_playing = Some(AudioPlayerProps {
@@ -1349,6 +2252,7 @@ pub fn on_play_click(
                         start_pos_sec: 0.0,
                         end_pos_sec: 0.0,
                         offline: false,
+                        enclosure_type: None,
                     });
                     audio_state.set_audio_source(src.to_string());
                     if let Some(audio) = &audio_state.audio_element {
@@ -1389,7 +2293,7 @@ pub fn on_play_click_offline(
 
                     audio_dispatch.reduce_mut(move |audio_state| {
                         audio_state.audio_playing = Some(true);
-                        audio_state.playback_speed = 1.0;
+                        audio_state.apply_podcast_playback_settings(None, 100);
                         audio_state.audio_volume = 100.0;
                         audio_state.offline = Some(true);
                         audio_state.currently_playing = Some(AudioPlayerProps {
@@ -1402,6 +2306,7 @@ pub fn on_play_click_offline(
                             start_pos_sec: listen_duration_for_closure.unwrap_or(0) as f64,
                             end_pos_sec: 0.0,
                             offline: true,
+                            enclosure_type: None,
                         });
                         audio_state.set_audio_source(src.to_string());
                         if let Some(audio) = &audio_state.audio_element {
@@ -1428,6 +2333,8 @@ pub fn on_play_click_shared(
     episode_artwork: String,
     episode_duration: i32,
     episode_id: i32,
+    start_time: Option<i32>,
+    end_time: Option<i32>,
     audio_dispatch: Dispatch<UIState>,
 ) -> Callback<MouseEvent> {
     Callback::from(move |_: MouseEvent| {
@@ -1445,11 +2352,14 @@ pub fn on_play_click_shared(
         web_sys::console::log_1(&JsValue::from_str(&episode_duration.to_string()));
         web_sys::console::log_1(&JsValue::from_str(&episode_id.to_string()));
 
+        // Shared clips start at their own offset, full episodes from the beginning
+        let start_pos_sec = start_time.unwrap_or(0) as f64;
+
         // No user-specific checks or DB operations needed, just play the episode
         wasm_bindgen_futures::spawn_local(async move {
             audio_dispatch.reduce_mut(move |audio_state| {
                 audio_state.audio_playing = Some(true);
-                audio_state.playback_speed = 1.0;
+                audio_state.apply_podcast_playback_settings(None, 100);
                 audio_state.audio_volume = 100.0;
                 audio_state.offline = Some(false);
                 audio_state.currently_playing = Some(AudioPlayerProps {
@@ -1459,14 +2369,18 @@ pub fn on_play_click_shared(
                     duration: episode_duration.to_string(),
                     episode_id: episode_id,
                     duration_sec: episode_duration as f64,
-                    start_pos_sec: 0.0, // Start playing from the beginning
+                    start_pos_sec,
                     end_pos_sec: 0.0,
                     offline: true,
+                    enclosure_type: None,
                 });
+                audio_state.clip_end = end_time.map(|end| (episode_id, end as f64));
                 audio_state.set_audio_source(episode_url.clone());
                 if let Some(audio) = &audio_state.audio_element {
+                    audio.set_current_time(start_pos_sec);
                     let _ = audio.play();
                 }
+                audio_state.update_current_time(start_pos_sec);
                 audio_state.audio_playing = Some(true);
             });
         });
@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+use web_sys::{
+    window, AnalyserNode, AudioContext, ChannelCountMode, ChannelInterpretation,
+    DynamicsCompressorNode, GainNode, HtmlMediaElement,
+};
+
+const AUDIO_PROCESSING_KEY: &str = "audio_processing";
+
+/// RMS level below which the signal counts as silence (roughly -40 dBFS).
+const SILENCE_THRESHOLD: f32 = 0.01;
+/// How long the level has to stay below the threshold before trimming kicks in.
+const SILENCE_MIN_SECONDS: f64 = 0.3;
+/// Playback rate used to run through silence. Browsers mute playback above 4x, which is fine
+/// here, but rates past 16x are rejected outright.
+const SILENCE_RATE: f64 = 4.0;
+
+/// Optional processing the listener can toggle from the expanded player.
+#[derive(Serialize, Deserialize, Default, Clone, Copy, Debug, PartialEq)]
+pub struct AudioProcessing {
+    pub trim_silence: bool,
+    pub voice_boost: bool,
+    pub mono: bool,
+}
+
+impl AudioProcessing {
+    pub fn any(&self) -> bool {
+        self.trim_silence || self.voice_boost || self.mono
+    }
+
+    pub fn load() -> Self {
+        window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(AUDIO_PROCESSING_KEY).ok().flatten())
+            .and_then(|stored| serde_json::from_str(&stored).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn store(&self) {
+        if let (Some(storage), Ok(serialized)) = (
+            window().and_then(|window| window.local_storage().ok().flatten()),
+            serde_json::to_string(self),
+        ) {
+            let _ = storage.set_item(AUDIO_PROCESSING_KEY, &serialized);
+        }
+    }
+}
+
+/// Web Audio routing for the shared player element:
+/// source -> compressor -> downmix -> analyser -> gain -> speakers.
+///
+/// A media element can only be wired into one `AudioContext` and stays routed through it for
+/// the rest of its life, so the graph is built lazily the first time a feature needs it. Audio
+/// from another origin is only audible through the graph when the host sends CORS headers; see
+/// `UIState::fall_back_to_direct_playback` for what happens when it doesn't.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AudioGraph {
+    context: AudioContext,
+    compressor: DynamicsCompressorNode,
+    downmix: GainNode,
+    analyser: AnalyserNode,
+    gain: GainNode,
+}
+
+impl AudioGraph {
+    pub fn attach(audio: &HtmlMediaElement) -> Result<Self, JsValue> {
+        let context = AudioContext::new()?;
+        let source = context.create_media_element_source(audio)?;
+        let compressor = context.create_dynamics_compressor()?;
+        let downmix = context.create_gain()?;
+        let analyser = context.create_analyser()?;
+        analyser.set_fft_size(2048);
+        let gain = context.create_gain()?;
+        source.connect_with_audio_node(&compressor)?;
+        compressor.connect_with_audio_node(&downmix)?;
+        downmix.connect_with_audio_node(&analyser)?;
+        analyser.connect_with_audio_node(&gain)?;
+        gain.connect_with_audio_node(&context.destination())?;
+        Ok(Self {
+            context,
+            compressor,
+            downmix,
+            analyser,
+            gain,
+        })
+    }
+
+    /// Apply the volume boost (linear gain, 1.0 is untouched) and the processing toggles.
+    pub fn configure(&self, volume_boost: f64, processing: &AudioProcessing) {
+        // A flat compressor (ratio 1) passes audio through unchanged
+        let (threshold, knee, ratio, makeup) = if processing.voice_boost {
+            (-50.0, 40.0, 12.0, 2.0)
+        } else {
+            (0.0, 0.0, 1.0, 1.0)
+        };
+        self.compressor.threshold().set_value(threshold);
+        self.compressor.knee().set_value(knee);
+        self.compressor.ratio().set_value(ratio);
+        self.compressor.attack().set_value(0.003);
+        self.compressor.release().set_value(0.25);
+
+        if processing.mono {
+            self.downmix.set_channel_count(1);
+            self.downmix
+                .set_channel_count_mode(ChannelCountMode::Explicit);
+            self.downmix
+                .set_channel_interpretation(ChannelInterpretation::Speakers);
+        } else {
+            self.downmix.set_channel_count(2);
+            self.downmix.set_channel_count_mode(ChannelCountMode::Max);
+        }
+
+        self.gain.gain().set_value((volume_boost * makeup) as f32);
+    }
+
+    /// Contexts created outside a user gesture start suspended and must be resumed on play.
+    pub fn resume(&self) {
+        let _ = self.context.resume();
+    }
+
+    /// Current RMS level of the processed signal, before the output gain.
+    fn level(&self) -> f32 {
+        let mut samples = vec![0.0f32; self.analyser.fft_size() as usize];
+        self.analyser.get_float_time_domain_data(&mut samples);
+        let sum: f32 = samples.iter().map(|sample| sample * sample).sum();
+        (sum / samples.len().max(1) as f32).sqrt()
+    }
+}
+
+/// Speeds playback through stretches of silence, tracking the listening time it saves.
+#[derive(Default)]
+pub struct SilenceTrimmer {
+    silent_for: f64,
+    trimming: bool,
+}
+
+impl SilenceTrimmer {
+    /// Sample the graph once, `elapsed` seconds after the previous tick. Returns the wall-clock
+    /// seconds saved since the previous tick.
+    pub fn tick(
+        &mut self,
+        graph: &AudioGraph,
+        audio: &HtmlMediaElement,
+        speed: f64,
+        elapsed: f64,
+    ) -> f64 {
+        if audio.paused() || speed <= 0.0 {
+            self.restore(audio, speed);
+            return 0.0;
+        }
+        let saved = if self.trimming {
+            elapsed * (SILENCE_RATE / speed - 1.0)
+        } else {
+            0.0
+        };
+
+        if graph.level() < SILENCE_THRESHOLD {
+            self.silent_for += elapsed;
+            if !self.trimming && self.silent_for >= SILENCE_MIN_SECONDS && speed < SILENCE_RATE {
+                self.trimming = true;
+                audio.set_playback_rate(SILENCE_RATE);
+            }
+        } else {
+            self.restore(audio, speed);
+        }
+        saved.max(0.0)
+    }
+
+    /// Return to the listener's speed, e.g. when speech resumes or trimming is switched off.
+    pub fn restore(&mut self, audio: &HtmlMediaElement, speed: f64) {
+        self.silent_for = 0.0;
+        if self.trimming {
+            self.trimming = false;
+            if speed > 0.0 {
+                audio.set_playback_rate(speed);
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::components::context::{AppState, UIState};
+use crate::components::gen_funcs::format_time_rm_hour;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::notify_error;
+use crate::requests::pod_req::{
+    call_delete_bookmark, call_get_bookmarks, Bookmark, DeleteBookmarkRequest,
+};
+use yew::prelude::*;
+use yewdux::prelude::*;
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct BookmarksPanelProps {
+    pub episode_id: i32,
+    /// Starts the episode at the given second when it isn't already in the player.
+    pub on_play_at: Callback<i32>,
+}
+
+#[function_component(BookmarksPanel)]
+pub fn bookmarks_panel(props: &BookmarksPanelProps) -> Html {
+    let i18n = use_i18n();
+    let (state, _dispatch) = use_store::<AppState>();
+    let (audio_state, audio_dispatch) = use_store::<UIState>();
+    let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
+    let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
+    let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
+
+    let bookmarks: UseStateHandle<Vec<Bookmark>> = use_state(Vec::new);
+
+    {
+        let bookmarks = bookmarks.clone();
+        let api_key = api_key.clone();
+        let server_name = server_name.clone();
+        use_effect_with(
+            (props.episode_id, audio_state.bookmarks_version),
+            move |(episode_id, _)| {
+                if let (Some(api_key), Some(server_name), Some(user_id)) =
+                    (api_key, server_name, user_id)
+                {
+                    let episode_id = *episode_id;
+                    wasm_bindgen_futures::spawn_local(async move {
+                        match call_get_bookmarks(&server_name, &api_key, user_id, episode_id).await
+                        {
+                            Ok(fetched) => bookmarks.set(fetched),
+                            Err(e) => web_sys::console::log_1(
+                                &format!("Error fetching bookmarks: {}", e).into(),
+                            ),
+                        }
+                    });
+                }
+                || ()
+            },
+        );
+    }
+
+    let is_current_episode = audio_state
+        .currently_playing
+        .as_ref()
+        .map_or(false, |playing| playing.episode_id == props.episode_id);
+
+    let on_bookmark_click = {
+        let audio_dispatch = audio_dispatch.clone();
+        let on_play_at = props.on_play_at.clone();
+        Callback::from(move |time: i32| {
+            if is_current_episode {
+                audio_dispatch.reduce_mut(move |state| {
+                    if let Some(audio_element) = state.audio_element.as_ref() {
+                        audio_element.set_current_time(time as f64);
+                    }
+                    state.update_current_time(time as f64);
+                });
+            } else {
+                on_play_at.emit(time);
+            }
+        })
+    };
+
+    let on_delete = {
+        let bookmarks = bookmarks.clone();
+        Callback::from(move |bookmark_id: i32| {
+            if let (Some(api_key), Some(server_name), Some(user_id)) =
+                (api_key.clone(), server_name.clone(), user_id)
+            {
+                let bookmarks = bookmarks.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let request = DeleteBookmarkRequest {
+                        bookmark_id,
+                        user_id,
+                    };
+                    match call_delete_bookmark(&server_name, &api_key, &request).await {
+                        Ok(_) => {
+                            let remaining = bookmarks
+                                .iter()
+                                .filter(|bookmark| bookmark.bookmark_id != bookmark_id)
+                                .cloned()
+                                .collect();
+                            bookmarks.set(remaining);
+                        }
+                        Err(e) => notify_error(
+                            I18n::current().tf("bookmarks.delete_error", &[("error", &e.to_string())]),
+                        ),
+                    }
+                });
+            }
+        })
+    };
+
+    if bookmarks.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <div class="bookmarks-panel header-info pb-2 pt-2">
+            <p class="item_container-text font-bold">{ i18n.t("bookmarks.title") }</p>
+            <ul class="bookmark-list">
+                { for bookmarks.iter().map(|bookmark| {
+                    let onclick = {
+                        let on_bookmark_click = on_bookmark_click.clone();
+                        let time = bookmark.time;
+                        Callback::from(move |_: MouseEvent| on_bookmark_click.emit(time))
+                    };
+                    let ondelete = {
+                        let on_delete = on_delete.clone();
+                        let bookmark_id = bookmark.bookmark_id;
+                        Callback::from(move |e: MouseEvent| {
+                            e.stop_propagation();
+                            on_delete.emit(bookmark_id);
+                        })
+                    };
+                    html! {
+                        <li class="bookmark-item" {onclick}>
+                            <span class="bookmark-time">{ format_time_rm_hour(bookmark.time as f64) }</span>
+                            <span class="bookmark-note item_container-text">
+                                { bookmark.note.clone().unwrap_or_default() }
+                            </span>
+                            <button class="bookmark-delete-button" title={i18n.t("bookmarks.delete")} onclick={ondelete}>
+                                <i class="material-icons">{ "delete" }</i>
+                            </button>
+                        </li>
+                    }
+                })}
+            </ul>
+        </div>
+    }
+}
@@ -1,28 +1,34 @@
 use crate::components::audio::AudioPlayerProps;
+use crate::components::audio_graph::{AudioGraph, AudioProcessing};
+use crate::components::episode_filter::EpisodeFilter;
+use crate::components::gen_funcs::is_video_enclosure;
+use crate::components::i18n::I18n;
+use crate::components::notifications::notify_info;
 use crate::components::podcast_layout::ClickedFeedURL;
-use crate::components::podcasts::PodcastLayout;
+use crate::components::podcasts::{LibrarySort, PodcastLayout};
 use crate::requests::login_requests::AddUserRequest;
 use crate::requests::login_requests::GetUserDetails;
 use crate::requests::login_requests::LoginServerRequest;
 use crate::requests::login_requests::{GetApiDetails, TimeZoneInfo};
 use crate::requests::pod_req::{
     Chapter, Episode, EpisodeDownloadResponse, EpisodeMetadataResponse, Funding,
-    HistoryDataResponse, Person, Podcast, PodcastResponse, PodrollItem, QueuedEpisodesResponse,
-    RecentEps, SavedEpisodesResponse, SharedEpisodeResponse, Transcript, Value,
+    HistoryDataResponse, Person, Playlist, PlaylistEpisode, PlaylistEpisodesResponse, Podcast,
+    PodcastResponse, PodrollItem, QueuedEpisodesResponse, RecentEps, SavedEpisodesResponse,
+    SharedEpisodeResponse, Transcript, Value,
 };
 use crate::requests::search_pods::{
-    PeopleFeedResult, PodcastFeedResult, PodcastSearchResult, SearchResponse,
+    PeopleFeedResult, PodcastFeedResult, PodcastSearchResult, SearchRequest, SearchResponse,
 };
 use crate::requests::setting_reqs::{AddSettingsUserRequest, EditSettingsUserRequest};
 use crate::requests::stat_reqs::UserStats;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{from_str, json};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
 use web_sys::window;
-use web_sys::HtmlAudioElement;
+use web_sys::{HtmlAudioElement, HtmlMediaElement, HtmlVideoElement};
 use yewdux::prelude::*;
 
 #[allow(dead_code)]
@@ -76,8 +82,6 @@ pub struct AppState {
     pub user_details: Option<GetUserDetails>,
     pub auth_details: Option<LoginServerRequest>,
     pub server_details: Option<GetApiDetails>,
-    pub error_message: Option<String>,
-    pub info_message: Option<String>,
     pub search_results: Option<PodcastSearchResult>,
     pub podcast_feed_results: Option<PodcastFeedResult>,
     pub people_feed_results: Option<PeopleFeedResult>,
@@ -87,6 +91,7 @@ pub struct AppState {
     pub episode_history: Option<HistoryDataResponse>,
     pub downloaded_episodes: Option<EpisodeDownloadResponse>,
     pub search_episodes: Option<SearchResponse>,
+    pub search_request: Option<SearchRequest>,
     pub episodes: Option<Episode>,
     pub clicked_podcast_info: Option<ClickedFeedURL>,
     pub pods: Option<Podcast>,
@@ -115,7 +120,10 @@ pub struct AppState {
     pub date_format: Option<String>,
     pub podcast_added: Option<bool>,
     pub completed_episodes: Option<Vec<i32>>,
-    pub podcast_layout: Option<PodcastLayout>,
+    /// The user's playlists, listed in the drawer and on the playlists page.
+    pub playlists: Option<Vec<Playlist>>,
+    /// The playlist open on the playlist page, with its episodes in play order.
+    pub playlist_episodes: Option<PlaylistEpisodesResponse>,
 }
 
 #[derive(Default, Deserialize, Clone, PartialEq, Store, Debug)]
@@ -133,13 +141,12 @@ pub struct SettingsState {
 pub struct UIState {
     pub audio_playing: Option<bool>,
     pub currently_playing: Option<AudioPlayerProps>,
-    pub audio_element: Option<HtmlAudioElement>,
+    /// The shared player element: an `<audio>`, or a `<video>` for video enclosures.
+    pub audio_element: Option<HtmlMediaElement>,
     pub current_time_seconds: f64,
     pub current_time_formatted: String,
     pub duration: f64,
     pub duration_formatted: String,
-    pub error_message: Option<String>,
-    pub info_message: Option<String>,
     pub is_expanded: bool,
     pub episode_in_db: Option<bool>,
     pub playback_speed: f64,
@@ -159,6 +166,236 @@ pub struct UIState {
     pub podcast_podroll: Option<Vec<PodrollItem>>,
     pub podcast_value4value: Option<Vec<Value>>,
     pub is_mobile: Option<bool>,
+    pub auto_play_next: Option<bool>,
+    /// Speed saved for the podcast that's playing; `None` means the remembered default applies.
+    pub podcast_playback_speed: Option<f64>,
+    /// Linear gain for the playing podcast; `None` is unboosted.
+    pub volume_boost: Option<f64>,
+    pub audio_graph: Option<AudioGraph>,
+    /// Silence trimming, voice boost and mono toggles; `None` until read from local storage.
+    pub audio_processing: Option<AudioProcessing>,
+    /// Source that failed through the audio graph and has to be played directly.
+    pub direct_playback_src: Option<String>,
+    /// Listening time saved by silence trimming this session, in seconds.
+    pub silence_time_saved: f64,
+    /// Bumped whenever a bookmark is added from the player so open bookmark lists refetch.
+    pub bookmarks_version: u32,
+    /// Episode id and position in seconds where a shared clip stops playing.
+    pub clip_end: Option<(i32, f64)>,
+    /// Playlist started with play all; its episodes play through before the queue is used.
+    pub playlist_playback: Option<PlaylistPlayback>,
+}
+
+/// A playlist being played through from its play all button.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PlaylistPlayback {
+    pub playlist_id: i32,
+    pub episodes: Vec<PlaylistEpisode>,
+}
+
+impl PlaylistPlayback {
+    pub fn contains(&self, episode_id: Option<i32>) -> bool {
+        self.episodes
+            .iter()
+            .any(|ep| Some(ep.episodeid) == episode_id)
+    }
+
+    /// The episode after `episode_id`, or `None` once the playlist is done.
+    pub fn next_after(&self, episode_id: Option<i32>) -> Option<&PlaylistEpisode> {
+        let index = self
+            .episodes
+            .iter()
+            .position(|ep| Some(ep.episodeid) == episode_id)?;
+        self.episodes.get(index + 1)
+    }
+}
+
+const DEFAULT_PLAYBACK_SPEED_KEY: &str = "default_playback_speed";
+const AUTO_PLAY_NEXT_KEY: &str = "auto_play_next";
+const PLAYER_STATE_KEY: &str = "playerState";
+
+/// Snapshot of the player kept in local storage so a reload can bring it back.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SavedPlayerState {
+    pub user_id: i32,
+    pub episode: AudioPlayerProps,
+    pub position: f64,
+    pub playback_speed: f64,
+    pub podcast_playback_speed: Option<f64>,
+    pub volume_boost: Option<f64>,
+    pub audio_volume: f64,
+    pub is_expanded: bool,
+}
+
+impl SavedPlayerState {
+    /// Snapshot the current player, if there's anything worth bringing back. Local files are
+    /// skipped since the local file server they stream from doesn't outlive the app.
+    pub fn capture(state: &UIState, user_id: i32) -> Option<Self> {
+        let episode = state.currently_playing.clone().filter(|props| !props.offline)?;
+        Some(Self {
+            user_id,
+            episode,
+            position: state.current_time_seconds,
+            playback_speed: state.playback_speed,
+            podcast_playback_speed: state.podcast_playback_speed,
+            volume_boost: state.volume_boost,
+            audio_volume: state.audio_volume,
+            is_expanded: state.is_expanded,
+        })
+    }
+
+    pub fn load() -> Option<Self> {
+        window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(PLAYER_STATE_KEY).ok().flatten())
+            .and_then(|stored| from_str(&stored).ok())
+    }
+
+    pub fn store(&self) {
+        if let (Some(storage), Ok(serialized)) = (
+            window().and_then(|window| window.local_storage().ok().flatten()),
+            serde_json::to_string(self),
+        ) {
+            let _ = storage.set_item(PLAYER_STATE_KEY, &serialized);
+        }
+    }
+
+    pub fn clear() {
+        if let Some(storage) = window().and_then(|window| window.local_storage().ok().flatten()) {
+            let _ = storage.remove_item(PLAYER_STATE_KEY);
+        }
+    }
+}
+
+/// The speed the player falls back to for podcasts without their own setting.
+pub fn load_default_playback_speed() -> f64 {
+    window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(DEFAULT_PLAYBACK_SPEED_KEY).ok().flatten())
+        .and_then(|speed| speed.parse::<f64>().ok())
+        .filter(|speed| *speed > 0.0)
+        .unwrap_or(1.0)
+}
+
+pub fn store_default_playback_speed(speed: f64) {
+    if let Some(storage) = window().and_then(|window| window.local_storage().ok().flatten()) {
+        let _ = storage.set_item(DEFAULT_PLAYBACK_SPEED_KEY, &speed.to_string());
+    }
+}
+
+pub fn load_auto_play_next() -> bool {
+    window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(AUTO_PLAY_NEXT_KEY).ok().flatten())
+        .and_then(|value| value.parse::<bool>().ok())
+        .unwrap_or(true)
+}
+
+pub fn store_auto_play_next(enabled: bool) {
+    if let Some(storage) = window().and_then(|window| window.local_storage().ok().flatten()) {
+        let _ = storage.set_item(AUTO_PLAY_NEXT_KEY, &enabled.to_string());
+    }
+}
+
+const LIBRARY_VIEW_KEY: &str = "libraryView";
+
+/// How the podcast library is shown. Kept in local storage for each account, so people
+/// sharing a browser don't overwrite each other's choice.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+#[serde(default)]
+pub struct LibraryView {
+    pub layout: PodcastLayout,
+    pub sort: LibrarySort,
+    pub descending: bool,
+    pub group_by_category: bool,
+    pub category: Option<String>,
+}
+
+impl LibraryView {
+    fn key(server_name: &str, user_id: i32) -> String {
+        format!("{}:{}:{}", LIBRARY_VIEW_KEY, server_name, user_id)
+    }
+
+    pub fn load(server_name: &str, user_id: i32) -> Self {
+        window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .and_then(|storage| {
+                storage
+                    .get_item(&Self::key(server_name, user_id))
+                    .ok()
+                    .flatten()
+            })
+            .and_then(|stored| from_str(&stored).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn store(&self, server_name: &str, user_id: i32) {
+        if let (Some(storage), Ok(serialized)) = (
+            window().and_then(|window| window.local_storage().ok().flatten()),
+            serde_json::to_string(self),
+        ) {
+            let _ = storage.set_item(&Self::key(server_name, user_id), &serialized);
+        }
+    }
+}
+
+pub const SERVER_PROFILES_KEY: &str = "serverProfiles";
+
+/// An account someone has signed in with, remembered so they can switch back to it without
+/// typing their password again. Only the API key is kept, never the password.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ServerProfile {
+    pub server_name: String,
+    pub username: String,
+    pub api_key: String,
+}
+
+impl ServerProfile {
+    pub fn is_same_account(&self, server_name: &str, username: &str) -> bool {
+        self.server_name == server_name && self.username == username
+    }
+
+    /// Most recently used first.
+    pub fn load_all() -> Vec<Self> {
+        window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(SERVER_PROFILES_KEY).ok().flatten())
+            .and_then(|stored| from_str(&stored).ok())
+            .unwrap_or_default()
+    }
+
+    fn store_all(profiles: &[Self]) {
+        if let (Some(storage), Ok(serialized)) = (
+            window().and_then(|window| window.local_storage().ok().flatten()),
+            serde_json::to_string(profiles),
+        ) {
+            let _ = storage.set_item(SERVER_PROFILES_KEY, &serialized);
+        }
+    }
+
+    /// Save the signed-in account, moving it to the top of the list.
+    pub fn remember(auth_details: &LoginServerRequest) {
+        let Some(api_key) = auth_details.api_key.clone() else {
+            return;
+        };
+        let mut profiles = Self::load_all();
+        profiles.retain(|p| !p.is_same_account(&auth_details.server_name, &auth_details.username));
+        profiles.insert(
+            0,
+            Self {
+                server_name: auth_details.server_name.clone(),
+                username: auth_details.username.clone(),
+                api_key,
+            },
+        );
+        Self::store_all(&profiles);
+    }
+
+    pub fn forget(server_name: &str, username: &str) {
+        let mut profiles = Self::load_all();
+        profiles.retain(|p| !p.is_same_account(server_name, username));
+        Self::store_all(&profiles);
+    }
 }
 
 impl UIState {
@@ -177,15 +414,55 @@ impl UIState {
                 let _ = audio.pause();
                 self.audio_playing = Some(false);
             } else {
+                if let Some(graph) = &self.audio_graph {
+                    graph.resume();
+                }
                 let _ = audio.play();
                 self.audio_playing = Some(true);
             }
         }
     }
 
+    /// Whether the player element is a `<video>`, i.e. the episode has a video enclosure.
+    pub fn is_video(&self) -> bool {
+        self.audio_element
+            .as_ref()
+            .map_or(false, |media| media.dyn_ref::<HtmlVideoElement>().is_some())
+    }
+
+    fn create_media_element(video: bool) -> Option<HtmlMediaElement> {
+        if video {
+            let video = window()?
+                .document()?
+                .create_element("video")
+                .ok()?
+                .dyn_into::<HtmlVideoElement>()
+                .ok()?;
+            // Keep iOS from taking over the whole screen as soon as playback starts
+            let _ = video.set_attribute("playsinline", "");
+            video.set_class_name("video-player");
+            Some(video.into())
+        } else {
+            HtmlAudioElement::new().ok().map(Into::into)
+        }
+    }
+
     pub fn set_audio_source(&mut self, src: String) {
+        let enclosure_type = self
+            .currently_playing
+            .as_ref()
+            .and_then(|props| props.enclosure_type.as_deref());
+        let wants_video = is_video_enclosure(enclosure_type, &src);
+        if self.audio_element.is_some() && self.is_video() != wants_video {
+            // Audio and video need different elements, and the graph belongs to the old one
+            if let Some(old_media) = self.audio_element.take() {
+                let _ = old_media.pause();
+                old_media.remove();
+            }
+            self.audio_graph = None;
+        }
         if self.audio_element.is_none() {
-            self.audio_element = HtmlAudioElement::new().ok();
+            self.audio_element = Self::create_media_element(wants_video);
             if let Some(audio) = &self.audio_element {
                 let closure = Closure::wrap(Box::new(move || {
                     // Code to handle the audio being ready to play
@@ -196,11 +473,133 @@ impl UIState {
                 closure.forget(); // Prevents the closure from being garbage collected
             }
         }
+        let processing = self.audio_processing();
         if let Some(audio) = &self.audio_element {
+            let volume_boost = self.volume_boost.unwrap_or(1.0);
+            let needs_graph = (volume_boost > 1.0 || processing.any() || self.audio_graph.is_some())
+                && self.direct_playback_src.as_deref() != Some(src.as_str());
+            if needs_graph {
+                // Cross-origin audio is silent inside an AudioContext unless fetched with CORS
+                audio.set_cross_origin(Some("anonymous"));
+            }
             audio.set_src(&src);
+            if self.playback_speed > 0.0 {
+                audio.set_default_playback_rate(self.playback_speed);
+                audio.set_playback_rate(self.playback_speed);
+            }
+            if needs_graph && self.audio_graph.is_none() {
+                self.audio_graph = AudioGraph::attach(audio).ok();
+            }
+            if let Some(graph) = &self.audio_graph {
+                graph.configure(volume_boost, &processing);
+                graph.resume();
+            }
         }
     }
 
+    pub fn audio_processing(&self) -> AudioProcessing {
+        self.audio_processing.unwrap_or_else(AudioProcessing::load)
+    }
+
+    /// Switch processing on or off for the current episode, routing it through the audio
+    /// graph (and reloading it with CORS) the first time a toggle needs it.
+    pub fn set_audio_processing(&mut self, processing: AudioProcessing) {
+        processing.store();
+        self.audio_processing = Some(processing);
+        if let Some(audio) = &self.audio_element {
+            let src = audio.src();
+            if processing.any()
+                && self.audio_graph.is_none()
+                && self.direct_playback_src.as_deref() != Some(src.as_str())
+            {
+                if !src.is_empty() {
+                    let position = audio.current_time();
+                    let playing = !audio.paused();
+                    audio.set_cross_origin(Some("anonymous"));
+                    audio.set_src(&src);
+                    audio.set_current_time(position);
+                    if self.playback_speed > 0.0 {
+                        audio.set_playback_rate(self.playback_speed);
+                    }
+                    if playing {
+                        let _ = audio.play();
+                    }
+                }
+                self.audio_graph = AudioGraph::attach(audio).ok();
+            }
+        }
+        if let Some(graph) = &self.audio_graph {
+            graph.configure(self.volume_boost.unwrap_or(1.0), &processing);
+            graph.resume();
+        }
+    }
+
+    /// Load a podcast's saved speed and volume boost ahead of `set_audio_source`.
+    pub fn apply_podcast_playback_settings(
+        &mut self,
+        playback_speed: Option<f64>,
+        volume_boost: i32,
+    ) {
+        self.podcast_playback_speed = playback_speed;
+        self.playback_speed = playback_speed.unwrap_or_else(load_default_playback_speed);
+        self.volume_boost = Some(volume_boost as f64 / 100.0).filter(|gain| *gain > 1.0);
+    }
+
+    /// Swap a graph-routed element whose source refused the CORS request for a plain one, so
+    /// the episode still plays, just without volume boost or processing.
+    pub fn fall_back_to_direct_playback(&mut self) {
+        if self.audio_graph.take().is_none() {
+            return;
+        }
+        let Some(old_audio) = self.audio_element.take() else {
+            return;
+        };
+        let src = old_audio.src();
+        let _ = old_audio.pause();
+        old_audio.remove();
+        let position = match old_audio.current_time() {
+            time if time > 0.0 => time,
+            _ => self
+                .currently_playing
+                .as_ref()
+                .map(|props| props.start_pos_sec)
+                .unwrap_or(0.0),
+        };
+        self.direct_playback_src = Some(src.clone());
+        self.set_audio_source(src);
+        if let Some(audio) = &self.audio_element {
+            audio.set_volume(self.audio_volume / 100.0);
+            audio.set_current_time(position);
+            if self.audio_playing.unwrap_or(false) {
+                let _ = audio.play();
+            }
+        }
+        notify_info(I18n::current().t("audio.processing_unavailable"));
+    }
+
+    /// Load a saved player paused at its saved position, without starting playback.
+    pub fn restore_saved_player(&mut self, saved: SavedPlayerState) {
+        let src = saved.episode.src.clone();
+        self.episode_in_db = Some(saved.episode.episode_id != 0);
+        self.currently_playing = Some(AudioPlayerProps {
+            start_pos_sec: saved.position,
+            ..saved.episode
+        });
+        self.playback_speed = saved.playback_speed;
+        self.podcast_playback_speed = saved.podcast_playback_speed;
+        self.volume_boost = saved.volume_boost;
+        self.audio_volume = saved.audio_volume;
+        self.is_expanded = saved.is_expanded;
+        self.offline = Some(false);
+        self.audio_playing = Some(false);
+        self.set_audio_source(src);
+        if let Some(audio) = &self.audio_element {
+            audio.set_volume(self.audio_volume / 100.0);
+            audio.set_current_time(saved.position);
+        }
+        self.update_current_time(saved.position);
+    }
+
     pub fn toggle_expanded(&mut self) {
         self.is_expanded = !self.is_expanded;
     }
@@ -225,6 +624,9 @@ impl AppState {
                 let _ = local_storage.set_item(server_key, &server_state);
             }
         }
+        if let Some(auth_details) = &self.auth_details {
+            ServerProfile::remember(auth_details);
+        }
     }
 
     // pub fn load_app_state(key: &str) -> Option<AppState> {
@@ -243,4 +645,6 @@ impl AppState {
 pub struct FilterState {
     pub selected_category: Option<String>,
     pub category_filter_list: Option<Vec<String>>,
+    /// Episode filters by page, see `episode_filter::use_episode_filter`.
+    pub episode_filters: HashMap<String, EpisodeFilter>,
 }
@@ -5,9 +5,16 @@ use super::gen_components::{
 use crate::components::audio::on_play_click;
 use crate::components::audio::AudioPlayer;
 use crate::components::context::{AppState, ExpandedDescriptions, UIState};
+use crate::components::episode_filter::{
+    no_matching_episodes, podcast_names, use_episode_filter, EpisodeFilterBar, FilterContext,
+};
+use crate::components::i18n::{use_i18n, I18n};
 use crate::components::gen_funcs::{
     format_datetime, match_date_format, parse_date, sanitize_html_with_blank_target,
 };
+use crate::components::notifications::notify_info;
+#[cfg(feature = "server_build")]
+use crate::components::offline::BrowserSavedEpisodes;
 use crate::requests::pod_req::{
     call_get_episode_downloads, call_get_podcasts, call_remove_downloaded_episode,
     DownloadEpisodeRequest, EpisodeDownload, EpisodeDownloadResponse, Podcast, PodcastResponse,
@@ -17,15 +24,21 @@ use yew::{function_component, html, Html};
 use yew_router::history::BrowserHistory;
 use yewdux::prelude::*;
 // use crate::components::gen_funcs::check_auth;
-use crate::components::episodes_layout::UIStateMsg;
 use crate::requests::login_requests::use_check_authentication;
 use std::borrow::Borrow;
 use std::collections::HashMap;
 use std::rc::Rc;
-use wasm_bindgen::closure::Closure;
 use wasm_bindgen::prelude::wasm_bindgen;
-use wasm_bindgen::JsCast;
-use web_sys::window;
+
+#[cfg(feature = "server_build")]
+fn browser_saved_episodes() -> Html {
+    html! { <BrowserSavedEpisodes /> }
+}
+
+#[cfg(not(feature = "server_build"))]
+fn browser_saved_episodes() -> Html {
+    html! {}
+}
 
 fn group_episodes_by_podcast(episodes: Vec<EpisodeDownload>) -> HashMap<i32, Vec<EpisodeDownload>> {
     let mut grouped: HashMap<i32, Vec<EpisodeDownload>> = HashMap::new();
@@ -40,6 +53,8 @@ fn group_episodes_by_podcast(episodes: Vec<EpisodeDownload>) -> HashMap<i32, Vec
 
 #[function_component(Downloads)]
 pub fn downloads() -> Html {
+    let i18n = use_i18n();
+    let episode_filter = use_episode_filter("downloads");
     let (state, dispatch) = use_store::<AppState>();
     let (desc_state, desc_dispatch) = use_store::<ExpandedDescriptions>();
     let effect_dispatch = dispatch.clone();
@@ -83,8 +98,6 @@ pub fn downloads() -> Html {
     let error = use_state(|| None);
     let (post_state, _post_dispatch) = use_store::<AppState>();
     let (audio_state, audio_dispatch) = use_store::<UIState>();
-    let error_message = audio_state.error_message.clone();
-    let info_message = audio_state.info_message.clone();
     let page_state = use_state(|| PageState::Normal);
     let api_key = post_state
         .auth_details
@@ -97,30 +110,6 @@ pub fn downloads() -> Html {
         .map(|ud| ud.server_name.clone());
     let loading = use_state(|| true);
 
-    {
-        let ui_dispatch = audio_dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                ui_dispatch.apply(UIStateMsg::ClearErrorMessage);
-                ui_dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
 
     // Fetch episodes on component mount
     let loading_ep = loading.clone();
@@ -273,7 +262,7 @@ pub fn downloads() -> Html {
                                         .episodes
                                         .retain(|ep| ep.episodeid != episode_id);
                                 }
-                                state.info_message = Some(success_message);
+                                notify_info(success_message);
                             });
                         }
                     });
@@ -319,7 +308,7 @@ pub fn downloads() -> Html {
                     {
                         html! {
                             <div>
-                                <h1 class="text-2xl item_container-text font-bold text-center mb-6">{"Downloaded Episodes"}</h1>
+                                <h1 class="text-2xl item_container-text font-bold text-center mb-6">{ i18n.t("downloads.title") }</h1>
                                 <div class="flex justify-between">
                                     {
                                         if **page_state.borrow() == PageState::Normal {
@@ -327,7 +316,7 @@ pub fn downloads() -> Html {
                                                 <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center"
                                                     onclick={delete_mode_enable.clone()}>
                                                     <span class="material-icons icon-space">{"check_box"}</span>
-                                                    <span class="text-lg">{"Select Multiple"}</span>
+                                                    <span class="text-lg">{ i18n.t("downloads.select_multiple") }</span>
                                                 </button>
                                             }
                                         } else {
@@ -336,12 +325,12 @@ pub fn downloads() -> Html {
                                                 <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center"
                                                     onclick={delete_mode_disable.clone()}>
                                                     <span class="material-icons icon-space">{"cancel"}</span>
-                                                    <span class="text-lg">{"Cancel"}</span>
+                                                    <span class="text-lg">{ i18n.t("common.cancel") }</span>
                                                 </button>
                                                 <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center"
                                                     onclick={delete_selected_episodes.clone()}>
                                                     <span class="material-icons icon-space">{"delete"}</span>
-                                                    <span class="text-lg">{"Delete"}</span>
+                                                    <span class="text-lg">{ i18n.t("common.delete") }</span>
                                                 </button>
                                                 </>
                                             }
@@ -352,6 +341,10 @@ pub fn downloads() -> Html {
                         }
                     }
 
+                    {
+                        browser_saved_episodes()
+                    }
+
                     {
                     if let Some(download_eps) = state.downloaded_episodes.clone() {
                         let int_download_eps = download_eps.clone();
@@ -361,14 +354,23 @@ pub fn downloads() -> Html {
                             if int_download_eps.episodes.is_empty() {
                                 // Render "No Recent Episodes Found" if episodes list is empty
                                 empty_message(
-                                    "No Downloaded Episodes Found",
-                                    "This is where episode downloads will appear. To download an episode you can open the context menu on an episode and select Download Episode. It will then download the the server and show up here!"
+                                    &i18n.t("downloads.empty_title"),
+                                    &i18n.t("downloads.empty_body")
                                 )
                             } else {
-                                let grouped_episodes = group_episodes_by_podcast(int_download_eps.episodes);
+                                let podcasts = podcast_names(&int_download_eps.episodes);
+                                let episodes = episode_filter.apply(
+                                    int_download_eps.episodes,
+                                    &FilterContext::from_state(&state),
+                                );
+                                let grouped_episodes = group_episodes_by_podcast(episodes);
 
                                 html! {
                                     <>
+                                        <EpisodeFilterBar page="downloads" {podcasts} show_download_state={false} />
+                                        if grouped_episodes.is_empty() {
+                                            { no_matching_episodes() }
+                                        }
                                         { for state.podcast_feed_return.as_ref().unwrap().pods.as_ref().unwrap().iter().filter_map(|podcast| {
                                             let episodes = grouped_episodes.get(&podcast.podcastid).unwrap_or(&Vec::new()).clone();
                                             if episodes.is_empty() {
@@ -412,8 +414,8 @@ pub fn downloads() -> Html {
 
                         } else {
                             empty_message(
-                                "No Episode Downloads Found",
-                                "This is where episode downloads will appear. To download an episode you can open the context menu on an episode and select Download Episode. It will then download to the server and show up here!"
+                                &i18n.t("downloads.empty_title"),
+                                &i18n.t("downloads.empty_body")
                             )
                         }
                     }
@@ -425,13 +427,6 @@ pub fn downloads() -> Html {
                 html! {}
             }
         }
-        // Conditional rendering for the error banner
-        if let Some(error) = error_message {
-            <div class="error-snackbar">{ error }</div>
-        }
-        if let Some(info) = info_message {
-            <div class="info-snackbar">{ info }</div>
-        }
         </div>
         <App_drawer />
         </>
@@ -453,6 +448,7 @@ pub fn render_podcast_with_episodes(
     audio_dispatch: Dispatch<UIState>,
     on_checkbox_change: Callback<i32>,
 ) -> Html {
+    let i18n = I18n::current();
     let history_clone = BrowserHistory::new();
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
@@ -464,7 +460,7 @@ pub fn render_podcast_with_episodes(
                 <div class="flex flex-col w-auto object-cover pl-4">
                     <img
                         src={podcast.artworkurl.clone()}
-                        alt={format!("Cover for {}", podcast.podcastname.clone())}
+                        alt={i18n.tf("podcast.cover_alt", &[("podcast", &podcast.podcastname)])}
                         class="episode-image"
                     />
                 </div>
@@ -473,7 +469,7 @@ pub fn render_podcast_with_episodes(
                         { &podcast.podcastname }
                     </p>
                     <hr class="my-2 border-t hidden md:block"/>
-                    <p class="item_container-text">{ format!("Downloaded Episode Count: {}", downloaded_episode_count) }</p>
+                    <p class="item_container-text">{ i18n.tn("downloads.episode_count", downloaded_episode_count as i64, &[]) }</p>
                 </div>
             </div>
             { if is_expanded {
@@ -5,9 +5,14 @@ use super::gen_components::{
 use crate::components::audio::on_play_click_offline;
 use crate::components::audio::AudioPlayer;
 use crate::components::context::{AppState, ExpandedDescriptions, UIState};
+use crate::components::episode_filter::{
+    no_matching_episodes, podcast_names, use_episode_filter, EpisodeFilterBar, FilterContext,
+};
 use crate::components::gen_funcs::{
     format_datetime, match_date_format, parse_date, sanitize_html_with_blank_target,
 };
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::notify_info;
 use crate::requests::pod_req::{
     call_remove_downloaded_episode, DownloadEpisodeRequest, EpisodeDownload,
     EpisodeDownloadResponse, EpisodeInfo, Podcast, PodcastDetails, PodcastResponse,
@@ -17,18 +22,14 @@ use yew::{function_component, html, Html};
 use yew_router::history::BrowserHistory;
 use yewdux::prelude::*;
 // use crate::components::gen_funcs::check_auth;
-use crate::components::episodes_layout::UIStateMsg;
 use crate::requests::login_requests::use_check_authentication;
 use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::collections::HashMap;
 use std::rc::Rc;
 use tauri_sys::core;
-use wasm_bindgen::closure::Closure;
 use wasm_bindgen::prelude::wasm_bindgen;
-use wasm_bindgen::JsCast;
 use wasm_bindgen::JsValue;
-use web_sys::window;
 
 fn group_episodes_by_podcast(episodes: Vec<EpisodeDownload>) -> HashMap<i32, Vec<EpisodeDownload>> {
     let mut grouped: HashMap<i32, Vec<EpisodeDownload>> = HashMap::new();
@@ -158,6 +159,8 @@ struct FileEntry {
 
 #[function_component(Downloads)]
 pub fn downloads() -> Html {
+    let i18n = use_i18n();
+    let episode_filter = use_episode_filter("local_downloads");
     let (state, dispatch) = use_store::<AppState>();
     let (desc_state, desc_dispatch) = use_store::<ExpandedDescriptions>();
     let effect_dispatch = dispatch.clone();
@@ -201,8 +204,6 @@ pub fn downloads() -> Html {
     let error = use_state(|| None);
     let (post_state, _post_dispatch) = use_store::<AppState>();
     let (audio_state, audio_dispatch) = use_store::<UIState>();
-    let error_message = audio_state.error_message.clone();
-    let info_message = audio_state.info_message.clone();
     let app_offline_mode = audio_state.app_offline_mode;
     let page_state = use_state(|| PageState::Normal);
     let api_key = post_state
@@ -216,30 +217,6 @@ pub fn downloads() -> Html {
         .map(|ud| ud.server_name.clone());
     let loading = use_state(|| true);
 
-    {
-        let ui_dispatch = audio_dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                ui_dispatch.apply(UIStateMsg::ClearErrorMessage);
-                ui_dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
 
     // Fetch episodes on component mount
     let loading_ep = loading.clone();
@@ -378,7 +355,7 @@ pub fn downloads() -> Html {
                                         .episodes
                                         .retain(|ep| ep.episodeid != episode_id);
                                 }
-                                state.info_message = Some(success_message);
+                                notify_info(success_message);
                             });
                         }
                     });
@@ -418,11 +395,11 @@ pub fn downloads() -> Html {
     };
     let h1_top = if app_offline_mode.unwrap_or(false) {
         html! {
-            <h1 class="text-2xl item_container-text font-bold text-center mb-6 pt-6">{"Locally Downloaded Episodes"}</h1>
+            <h1 class="text-2xl item_container-text font-bold text-center mb-6 pt-6">{ i18n.t("downloads.local_title") }</h1>
         }
     } else {
         html! {
-            <h1 class="text-2xl item_container-text font-bold text-center mb-6">{"Locally Downloaded Episodes"}</h1>
+            <h1 class="text-2xl item_container-text font-bold text-center mb-6">{ i18n.t("downloads.local_title") }</h1>
         }
     };
 
@@ -456,7 +433,7 @@ pub fn downloads() -> Html {
                                                 <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center"
                                                     onclick={delete_mode_enable.clone()}>
                                                     <span class="material-icons icon-space">{"check_box"}</span>
-                                                    <span class="text-lg">{"Select Multiple"}</span>
+                                                    <span class="text-lg">{ i18n.t("downloads.select_multiple") }</span>
                                                 </button>
                                             }
                                         } else {
@@ -465,12 +442,12 @@ pub fn downloads() -> Html {
                                                 <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center"
                                                     onclick={delete_mode_disable.clone()}>
                                                     <span class="material-icons icon-space">{"cancel"}</span>
-                                                    <span class="text-lg">{"Cancel"}</span>
+                                                    <span class="text-lg">{ i18n.t("common.cancel") }</span>
                                                 </button>
                                                 <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center"
                                                     onclick={delete_selected_episodes.clone()}>
                                                     <span class="material-icons icon-space">{"delete"}</span>
-                                                    <span class="text-lg">{"Delete"}</span>
+                                                    <span class="text-lg">{ i18n.t("common.delete") }</span>
                                                 </button>
                                                 </>
                                             }
@@ -490,14 +467,23 @@ pub fn downloads() -> Html {
                             if int_download_eps.episodes.is_empty() {
                                 // Render "No Recent Episodes Found" if episodes list is empty
                                 empty_message(
-                                    "No Downloaded Episodes Found",
-                                    "This is where episode downloads will appear. To download an episode you can open the context menu on an episode and select Download Episode. It will then download the the server and show up here!"
+                                    &i18n.t("downloads.empty_title"),
+                                    &i18n.t("downloads.empty_body")
                                 )
                             } else {
-                                let grouped_episodes = group_episodes_by_podcast(int_download_eps.episodes);
+                                let podcasts = podcast_names(&int_download_eps.episodes);
+                                let episodes = episode_filter.apply(
+                                    int_download_eps.episodes,
+                                    &FilterContext::from_state(&state),
+                                );
+                                let grouped_episodes = group_episodes_by_podcast(episodes);
 
                                 html! {
                                     <>
+                                        <EpisodeFilterBar page="local_downloads" {podcasts} show_download_state={false} />
+                                        if grouped_episodes.is_empty() {
+                                            { no_matching_episodes() }
+                                        }
                                         { for state.podcast_feed_return.as_ref().unwrap().pods.as_ref().unwrap().iter().filter_map(|podcast| {
                                             let episodes = grouped_episodes.get(&podcast.podcastid).unwrap_or(&Vec::new()).clone();
                                             if episodes.is_empty() {
@@ -537,8 +523,8 @@ pub fn downloads() -> Html {
 
                         } else {
                             empty_message(
-                                "No Episode Downloads Found",
-                                "This is where episode downloads will appear. To download an episode you can open the context menu on an episode and select Download Episode. It will then download to the server and show up here!"
+                                &i18n.t("downloads.empty_title"),
+                                &i18n.t("downloads.empty_body")
                             )
                         }
                     }
@@ -550,13 +536,6 @@ pub fn downloads() -> Html {
                 html! {}
             }
         }
-        // Conditional rendering for the error banner
-        if let Some(error) = error_message {
-            <div class="error-snackbar">{ error }</div>
-        }
-        if let Some(info) = info_message {
-            <div class="info-snackbar">{ info }</div>
-        }
         </div>
         {drawer_options}
         </>
@@ -577,6 +556,7 @@ pub fn render_podcast_with_episodes(
     on_checkbox_change: Callback<i32>,
 ) -> Html {
     let history_clone = BrowserHistory::new();
+    let i18n = I18n::current();
 
     html! {
         <div key={podcast.podcastid}>
@@ -584,7 +564,7 @@ pub fn render_podcast_with_episodes(
                 <div class="flex flex-col w-auto object-cover pl-4">
                     <img
                         src={podcast.artworkurl.clone()}
-                        alt={format!("Cover for {}", podcast.podcastname.clone())}
+                        alt={i18n.tf("podcast.cover_alt", &[("podcast", &podcast.podcastname)])}
                         class="object-cover align-top-cover w-full item-container img"
                     />
                 </div>
@@ -593,7 +573,7 @@ pub fn render_podcast_with_episodes(
                         { &podcast.podcastname }
                     </p>
                     <hr class="my-2 border-t hidden md:block"/>
-                    <p class="item_container-text">{ format!("Episode Count: {}", &podcast.episodecount) }</p>
+                    <p class="item_container-text">{ i18n.tf("podcast.episode_count", &[("count", &i18n.number(podcast.episodecount as f64))]) }</p>
                 </div>
             </div>
             { if is_expanded {
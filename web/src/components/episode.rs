@@ -2,21 +2,25 @@ use super::app_drawer::App_drawer;
 use super::gen_components::{empty_message, Search_nav, UseScrollToTop};
 use crate::components::audio::on_play_click;
 use crate::components::audio::AudioPlayer;
+use crate::components::bookmarks::BookmarksPanel;
 use crate::components::click_events::create_on_title_click;
 use crate::components::context::{AppState, UIState};
 use crate::components::episodes_layout::SafeHtml;
-use crate::components::episodes_layout::{HostDropdown, UIStateMsg};
+use crate::components::episodes_layout::HostDropdown;
 use crate::components::gen_funcs::{
     convert_time_to_seconds, format_datetime, format_time, match_date_format, parse_date,
     sanitize_html_with_blank_target,
 };
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, notify_info};
+use crate::components::transcript::TranscriptViewer;
 use crate::requests::login_requests::use_check_authentication;
 use crate::requests::pod_req;
 use crate::requests::pod_req::{
     call_download_episode, call_fetch_podcasting_2_data, call_get_episode_id,
     call_mark_episode_completed, call_create_share_link, call_mark_episode_uncompleted, call_queue_episode,
     call_save_episode, DownloadEpisodeRequest, EpisodeInfo, EpisodeMetadataResponse,
-    EpisodeRequest, FetchPodcasting2DataRequest, MarkEpisodeCompletedRequest, QueuePodcastRequest,
+    EpisodeRequest, FetchPodcasting2DataRequest, MarkEpisodeCompletedRequest, QueueInsert, QueuePodcastRequest,
     SavePodcastRequest,
 };
 use crate::requests::search_pods::call_parse_podcast_url;
@@ -37,6 +41,7 @@ fn get_current_url() -> String {
 
 #[function_component(Episode)]
 pub fn epsiode() -> Html {
+    let i18n = use_i18n();
     let (state, dispatch) = use_store::<AppState>();
 
     let session_dispatch = dispatch.clone();
@@ -88,8 +93,6 @@ pub fn epsiode() -> Html {
         .auth_details
         .as_ref()
         .map(|ud| ud.server_name.clone());
-    let error_message = audio_state.error_message.clone();
-    let info_message = audio_state.info_message.clone();
     let history = BrowserHistory::new();
     let episode_id = state.selected_episode_id.clone();
     let ep_in_db = use_state(|| false);
@@ -126,30 +129,6 @@ pub fn epsiode() -> Html {
         });
     }
 
-    {
-        let ui_dispatch = audio_dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                ui_dispatch.apply(UIStateMsg::ClearErrorMessage);
-                ui_dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
 
     // Fetch episode on component mount
     {
@@ -266,6 +245,7 @@ pub fn epsiode() -> Html {
                                                                     ),
                                                                     episodeid: fetched_episode_id, // Set the fetched episode ID here
                                                                     completed: false,
+                                                                    enclosuretype: None,
                                                                 },
                                                             });
                                                         state.selected_episode_id =
@@ -358,6 +338,7 @@ pub fn epsiode() -> Html {
                                                                     ),
                                                                     episodeid: 0, // Set the episode ID to 0
                                                                     completed: false,
+                                                                    enclosuretype: None,
                                                                 },
                                                             });
                                                         state.selected_episode_id = Some(0); // Set the episode ID to 0
@@ -499,6 +480,7 @@ pub fn epsiode() -> Html {
                                                                 ),
                                                                 episodeid: 0,
                                                                 completed: false,
+                                                                enclosuretype: None,
                                                             },
                                                         });
                                                 });
@@ -734,6 +716,8 @@ pub fn epsiode() -> Html {
                         &server_name,
                         &api_key_copy.unwrap().unwrap(),
                         ep_id_deref,
+                        None,
+                        None,
                     )
                     .await
                     {
@@ -759,23 +743,23 @@ pub fn epsiode() -> Html {
                     <div class="modal-container relative rounded-lg shadow">
                         <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                             <h3 class="text-xl font-semibold">
-                                {"Copy Shared Link"}
+                                { i18n.t("episode_page.share_title") }
                             </h3>
                             <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                                 <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                     <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                                 </svg>
-                                <span class="sr-only">{"Close modal"}</span>
+                                <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                             </button>
                         </div>
                         <div class="p-4 md:p-5">
                             <div>
-                                <label for="share_link" class="block mb-2 text-sm font-medium">{"Share this link with anyone you'd like to be able to listen to this episode. They don't even need an account on the server to use this!"}</label>
+                                <label for="share_link" class="block mb-2 text-sm font-medium">{ i18n.t("episode_page.share_body") }</label>
                                 <input type="text" id="share_link" class="input-black w-full px-3 py-2 border border-gray-300 rounded-md" value={shared_url.as_ref().map(|url| url.clone()).unwrap_or_else(|| "".to_string())} readonly=true />
                                 // <button class="copy-button" onclick={copy_to_clipboard.clone()}>{ "Copy to clipboard" }</button>
                             </div>
                             <div>
-                                <label for="share_link" class="block mb-2 text-sm font-medium">{"If they do have an account you can just send the user the current link on this web page:"}</label>
+                                <label for="share_link" class="block mb-2 text-sm font-medium">{ i18n.t("episode_page.share_account_body") }</label>
                                 <input type="text" id="share_link" class="input-black w-full px-3 py-2 border border-gray-300 rounded-md" value={get_current_url()} readonly=true />
                                 // <button class="copy-button" onclick={copy_to_clipboard.clone()}>{ "Copy to clipboard" }</button>
                             </div>
@@ -833,6 +817,37 @@ pub fn epsiode() -> Html {
                         let api_key_play = api_key.clone();
                         let audio_dispatch = audio_dispatch.clone();
 
+                        let on_play_at = {
+                            let episode_url = episode_url_for_closure.clone();
+                            let episode_title = episode_title_for_closure.clone();
+                            let episode_artwork = episode_artwork_for_closure.clone();
+                            let api_key = api_key.clone().flatten();
+                            let server_name = server_name.clone();
+                            let audio_dispatch = audio_dispatch.clone();
+                            let audio_state = audio_state.clone();
+                            Callback::from(move |time: i32| {
+                                if let (Some(api_key), Some(user_id), Some(server_name)) =
+                                    (api_key.clone(), user_id, server_name.clone())
+                                {
+                                    on_play_click(
+                                        episode_url.clone(),
+                                        episode_title.clone(),
+                                        episode_artwork.clone(),
+                                        episode_duration_for_closure,
+                                        episode_id_for_closure,
+                                        Some(time),
+                                        api_key,
+                                        user_id,
+                                        server_name,
+                                        audio_dispatch.clone(),
+                                        audio_state.clone(),
+                                        None,
+                                    )
+                                    .emit(MouseEvent::new("click").unwrap());
+                                }
+                            })
+                        };
+
                         let on_play_click = on_play_click(
                             episode_url_for_closure.clone(),
                             episode_title_for_closure.clone(),
@@ -851,13 +866,13 @@ pub fn epsiode() -> Html {
                         let user_id_queue = user_id.clone();
                         let server_name_queue = server_name.clone();
                         let api_key_queue = api_key.clone();
-                        let audio_dispatch_queue = audio_dispatch.clone();
 
-                        let on_add_to_queue = {
+                        let queue_episode_at = move |position: QueueInsert| {
+                            let server_name_queue = server_name_queue.clone();
+                            let api_key_queue = api_key_queue.clone();
                             Callback::from(move |_: MouseEvent| {
                                 let server_name_copy = server_name_queue.clone();
                                 let api_key_copy = api_key_queue.clone();
-                                let queue_post = audio_dispatch_queue.clone();
                                 let request = QueuePodcastRequest {
                                     episode_id: episode_id_for_closure,
                                     user_id: user_id_queue.unwrap(), // replace with the actual user ID
@@ -867,12 +882,12 @@ pub fn epsiode() -> Html {
                                 let future = async move {
                                     // let _ = call_queue_episode(&server_name.unwrap(), &api_key.flatten(), &request).await;
                                     // queue_post.reduce_mut(|state| state.info_message = Option::from(format!("Episode added to Queue!")));
-                                    match call_queue_episode(&server_name.unwrap(), &api_key.flatten(), &request).await {
+                                    match call_queue_episode(&server_name.unwrap(), &api_key.flatten(), &request, position).await {
                                         Ok(success_message) => {
-                                            queue_post.reduce_mut(|state| state.info_message = Option::from(format!("{}", success_message)));
+                                            notify_info(format!("{}", success_message));
                                         },
                                         Err(e) => {
-                                            queue_post.reduce_mut(|state| state.error_message = Option::from(format!("{}", e)));
+                                            notify_error(format!("{}", e));
                                             // Handle error, e.g., display the error message
                                         }
                                     }
@@ -882,16 +897,17 @@ pub fn epsiode() -> Html {
                             })
                         };
 
+                        let on_add_to_queue = queue_episode_at(QueueInsert::Last);
+                        let on_play_next = queue_episode_at(QueueInsert::Next);
+
                         let saved_server_name = server_name.clone();
                         let saved_api_key = api_key.clone();
-                        let save_post = audio_dispatch.clone();
                         let user_id_save = user_id.clone();
 
                         let on_save_episode = {
                             Callback::from(move |_: MouseEvent| {
                                 let server_name_copy = saved_server_name.clone();
                                 let api_key_copy = saved_api_key.clone();
-                                let post_state = save_post.clone();
                                 let request = SavePodcastRequest {
                                     episode_id: episode_id_for_closure, // changed from episode_title
                                     user_id: user_id_save.unwrap(), // replace with the actual user ID
@@ -903,10 +919,10 @@ pub fn epsiode() -> Html {
                                     // post_state.reduce_mut(|state| state.info_message = Option::from(format!("Episode saved successfully")));
                                     match call_save_episode(&server_name.unwrap(), &api_key.flatten(), &request).await {
                                         Ok(success_message) => {
-                                            post_state.reduce_mut(|state| state.info_message = Option::from(format!("{}", success_message)));
+                                            notify_info(format!("{}", success_message));
                                         },
                                         Err(e) => {
-                                            post_state.reduce_mut(|state| state.error_message = Option::from(format!("{}", e)));
+                                            notify_error(format!("{}", e));
                                             // Handle error, e.g., display the error message
                                         }
                                     }
@@ -918,12 +934,10 @@ pub fn epsiode() -> Html {
 
                         let download_server_name = server_name.clone();
                         let download_api_key = api_key.clone();
-                        let download_post = audio_dispatch.clone();
                         let user_id_download = user_id.clone();
 
                         let on_download_episode = {
                             Callback::from(move |_: MouseEvent| {
-                                let post_state = download_post.clone();
                                 let server_name_copy = download_server_name.clone();
                                 let api_key_copy = download_api_key.clone();
                                 let request = DownloadEpisodeRequest {
@@ -937,10 +951,10 @@ pub fn epsiode() -> Html {
                                     // post_state.reduce_mut(|state| state.info_message = Option::from(format!("Episode now downloading!")));
                                     match call_download_episode(&server_name.unwrap(), &api_key.flatten(), &request).await {
                                         Ok(success_message) => {
-                                            post_state.reduce_mut(|state| state.info_message = Option::from(format!("{}", success_message)));
+                                            notify_info(format!("{}", success_message));
                                         },
                                         Err(e) => {
-                                            post_state.reduce_mut(|state| state.error_message = Option::from(format!("{}", e)));
+                                            notify_error(format!("{}", e));
                                             // Handle error, e.g., display the error message
                                         }
                                     }
@@ -993,13 +1007,11 @@ pub fn epsiode() -> Html {
                                                 } else {
                                                     state.completed_episodes = Some(vec![episode_id_for_closure]);
                                                 }
-                                                state.info_message = Some(format!("{}", success_message));
+                                                notify_info(format!("{}", success_message));
                                             });
                                         }
                                         Err(e) => {
-                                            post_dispatch.reduce_mut(|state| {
-                                                state.error_message = Option::from(format!("{}", e))
-                                            });
+                                            notify_error(format!("{}", e));
                                             // Handle error, e.g., display the error message
                                         }
                                     }
@@ -1052,13 +1064,11 @@ pub fn epsiode() -> Html {
                                                 } else {
                                                     state.completed_episodes = Some(vec![episode_id_for_closure]);
                                                 }
-                                                state.info_message = Some(format!("{}", success_message));
+                                                notify_info(format!("{}", success_message));
                                             });
                                         }
                                         Err(e) => {
-                                            post_dispatch.reduce_mut(|state| {
-                                                state.error_message = Option::from(format!("{}", e))
-                                            });
+                                            notify_error(format!("{}", e));
                                             // Handle error, e.g., display the error message
                                         }
                                     }
@@ -1127,9 +1137,7 @@ pub fn epsiode() -> Html {
                                         },
                                         Err(error) => {
                                             web_sys::console::log_1(&format!("Error fetching podcast details: {}", error).into());
-                                            dispatch.reduce_mut(move |state| {
-                                                state.error_message = Some(format!("Failed to load details: {}", error));
-                                            });
+                                            notify_error(I18n::current().tf("episode_page.details_failed", &[("error", &error.to_string())]));
                                         }
                                     }
                                 });
@@ -1140,10 +1148,6 @@ pub fn epsiode() -> Html {
                         web_sys::console::log_1(&format!("Episode URL: {}", episode_url_check).into());
                         web_sys::console::log_1(&format!("Should show buttons: {}", should_show_buttons).into());
 
-                        let open_in_new_tab = Callback::from(move |url: String| {
-                            let window = web_sys::window().unwrap();
-                            window.open_with_url_and_target(&url, "_blank").unwrap();
-                        });
                         // let format_duration = format!("Duration: {} minutes", e / 60); // Assuming duration is in seconds
                         // let format_release = format!("Released on: {}", &episode.episode.EpisodePubDate);
                         let layout = if audio_state.is_mobile.unwrap_or(false) {
@@ -1189,25 +1193,8 @@ pub fn epsiode() -> Html {
                                             {
                                                 if let Some(transcript) = &audio_state.episode_page_transcript {
                                                     if !transcript.is_empty() {
-                                                        let transcript_clone = transcript.clone();
                                                         html! {
-                                                            <>
-                                                            { for transcript_clone.iter().map(|transcript| {
-                                                                let open_in_new_tab = open_in_new_tab.clone();
-                                                                let url = transcript.url.clone();
-                                                                html! {
-                                                                    <div class="header-info pb-2 pt-2">
-                                                                        <button
-                                                                            onclick={Callback::from(move |_| open_in_new_tab.emit(url.clone()))}
-                                                                            title={"Transcript"}
-                                                                            class="font-bold item-container-button"
-                                                                        >
-                                                                            { "Episode Transcript" }
-                                                                        </button>
-                                                                    </div>
-                                                                }
-                                                            })}
-                                                            </>
+                                                            <TranscriptViewer transcripts={transcript.clone()} episode_id={episode.episode.episodeid} />
                                                         }
                                                     } else {
                                                         html! {}
@@ -1216,12 +1203,13 @@ pub fn epsiode() -> Html {
                                                     html! {}
                                                 }
                                             }
+                                            <BookmarksPanel episode_id={episode.episode.episodeid} on_play_at={on_play_at.clone()} />
                                             {
                                                 if let Some(people) = &audio_state.episode_page_people {
                                                     if !people.is_empty() {
                                                         html! {
                                                             <div class="header-info-episode">
-                                                                <HostDropdown title="In This Episode" hosts={people.clone()} />
+                                                                <HostDropdown title={i18n.t("episode_page.people")} hosts={people.clone()} />
                                                             </div>
                                                         }
                                                     } else {
@@ -1241,25 +1229,29 @@ pub fn epsiode() -> Html {
                                                     <div class="button-row">
                                                         <button onclick={on_play_click} class="play-button">
                                                             <i class="material-icons">{ "play_arrow" }</i>
-                                                            {"Play"}
+                                                            { i18n.t("episode_page.play") }
                                                         </button>
                                                         <button onclick={on_add_to_queue} class="queue-button">
                                                             <i class="material-icons">{ "playlist_add" }</i>
-                                                            {"Queue"}
+                                                            { i18n.t("episode_page.queue") }
+                                                        </button>
+                                                        <button onclick={on_play_next} class="queue-button">
+                                                            <i class="material-icons">{ "queue_play_next" }</i>
+                                                            { i18n.t("episode.play_next") }
                                                         </button>
                                                         <button onclick={on_save_episode} class="save-button">
                                                             <i class="material-icons">{ "favorite" }</i>
-                                                            {"Save"}
+                                                            { i18n.t("common.save") }
                                                         </button>
                                                     </div>
                                                     <div class="button-row">
                                                         <button onclick={on_download_episode} class="download-button-ep">
                                                             <i class="material-icons">{ "download" }</i>
-                                                            {"Download"}
+                                                            { i18n.t("episode_page.download") }
                                                         </button>
                                                         <button onclick={toggle_completion} class="download-button-ep">
                                                             <i class="material-icons">{ if *completion_status { "check_circle_outline" } else { "check_circle" } }</i>
-                                                            { if *completion_status { "Mark Incomplete" } else { "Mark Complete" } }
+                                                            { i18n.t(if *completion_status { "episode_page.mark_incomplete" } else { "episode_page.mark_complete" }) }
                                                         </button>
                                                     </div>
                                                     </>
@@ -1267,14 +1259,14 @@ pub fn epsiode() -> Html {
                                             } else {
                                                 html! {
                                                     <p class="no-media-warning item_container-text play-button">
-                                                        {"Add podcast to display actions"}
+                                                        { i18n.t("episode_page.add_podcast_for_actions") }
                                                     </p>
                                                 }
                                             }
                                         } else {
                                             html! {
                                                 <p class="no-media-warning item_container-text play-button">
-                                                    {"This item contains no media file"}
+                                                    { i18n.t("episode_page.no_media") }
                                                 </p>
                                             }
                                         }
@@ -1315,25 +1307,8 @@ pub fn epsiode() -> Html {
                                             {
                                                 if let Some(transcript) = &audio_state.episode_page_transcript {
                                                     if !transcript.is_empty() {
-                                                        let transcript_clone = transcript.clone();
                                                         html! {
-                                                            <>
-                                                            { for transcript_clone.iter().map(|transcript| {
-                                                                let open_in_new_tab = open_in_new_tab.clone();
-                                                                let url = transcript.url.clone();
-                                                                html! {
-                                                                    <div class="header-info pb-2 pt-2">
-                                                                        <button
-                                                                            onclick={Callback::from(move |_| open_in_new_tab.emit(url.clone()))}
-                                                                            title={"Transcript"}
-                                                                            class="font-bold item-container-button"
-                                                                        >
-                                                                            { "Episode Transcript" }
-                                                                        </button>
-                                                                    </div>
-                                                                }
-                                                            })}
-                                                            </>
+                                                            <TranscriptViewer transcripts={transcript.clone()} episode_id={episode.episode.episodeid} />
                                                         }
                                                     } else {
                                                         html! {}
@@ -1342,12 +1317,13 @@ pub fn epsiode() -> Html {
                                                     html! {}
                                                 }
                                             }
+                                            <BookmarksPanel episode_id={episode.episode.episodeid} on_play_at={on_play_at.clone()} />
                                             {
                                                 if let Some(people) = &audio_state.episode_page_people {
                                                     if !people.is_empty() {
                                                         html! {
                                                             <div class="header-info">
-                                                                <HostDropdown title="In This Episode" hosts={people.clone()} />
+                                                                <HostDropdown title={i18n.t("episode_page.people")} hosts={people.clone()} />
                                                             </div>
                                                         }
                                                     } else {
@@ -1361,7 +1337,7 @@ pub fn epsiode() -> Html {
                                                 class="share-button font-bold py-2 px-4 rounded"
                                                 onclick={create_share_link.clone()}
                                             >
-                                                {"Share Episode"}
+                                                { i18n.t("episode_page.share") }
                                             </button>
                                         </div>
                                     </div>
@@ -1373,37 +1349,41 @@ pub fn epsiode() -> Html {
                                                 <>
                                                 <button onclick={on_play_click} class="play-button">
                                                     <i class="material-icons">{ "play_arrow" }</i>
-                                                    {"Play"}
+                                                    { i18n.t("episode_page.play") }
                                                 </button>
                                                 <button onclick={on_add_to_queue} class="queue-button">
                                                     <i class="material-icons">{ "playlist_add" }</i>
-                                                    {"Queue"}
+                                                    { i18n.t("episode_page.queue") }
+                                                </button>
+                                                <button onclick={on_play_next} class="queue-button">
+                                                    <i class="material-icons">{ "queue_play_next" }</i>
+                                                    { i18n.t("episode.play_next") }
                                                 </button>
                                                 <button onclick={on_save_episode} class="save-button">
                                                     <i class="material-icons">{ "favorite" }</i>
-                                                    {"Save"}
+                                                    { i18n.t("common.save") }
                                                 </button>
                                                 <button onclick={on_download_episode} class="download-button-ep">
                                                     <i class="material-icons">{ "download" }</i>
-                                                    {"Download"}
+                                                    { i18n.t("episode_page.download") }
                                                 </button>
                                                 <button onclick={toggle_completion} class="download-button-ep">
                                                     <i class="material-icons">{ if *completion_status { "check_circle_outline" } else { "check_circle" } }</i>
-                                                    { if *completion_status { "Mark Episode Incomplete" } else { "Mark Episode Complete" } }
+                                                    { i18n.t(if *completion_status { "episode.mark_incomplete" } else { "episode.mark_complete" }) }
                                                 </button>
                                                 </>
                                             }
                                             } else {
                                                 html! {
                                                     <p class="no-media-warning item_container-text play-button">
-                                                        {"Add podcast to display actions"}
+                                                        { i18n.t("episode_page.add_podcast_for_actions") }
                                                     </p>
                                                 }
                                             }
                                         } else {
                                             html! {
                                                 <p class="no-media-warning item_container-text play-button">
-                                                    {"This item contains no media file"}
+                                                    { i18n.t("episode_page.no_media") }
                                                 </p>
                                             }
                                         }
@@ -1425,8 +1405,8 @@ pub fn epsiode() -> Html {
                         layout
                     } else {
                         empty_message(
-                            "Unable to display episode",
-                            "Something seems to have gone wrong. A straightup server disconnect maybe? Did you browse here directly? That's not how this app works. It needs the context to browse around. I honestly don't have anything else for you as this shouldn't happen. This is embarrasing."
+                            &i18n.t("episode_page.unavailable_title"),
+                            &i18n.t("episode_page.unavailable_body")
                         )
                     }
                 }
@@ -1438,13 +1418,6 @@ pub fn epsiode() -> Html {
                 html! {}
             }
         }
-        // Conditional rendering for the error banner
-        if let Some(error) = error_message {
-            <div class="error-snackbar">{ error }</div>
-        }
-        if let Some(info) = info_message {
-            <div class="info-snackbar">{ info }</div>
-        }
         </div>
         <App_drawer />
         </>
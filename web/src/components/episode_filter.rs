@@ -0,0 +1,587 @@
+use crate::components::context::{AppState, FilterState};
+use crate::components::gen_funcs::{convert_time_to_seconds, parse_date};
+use crate::components::i18n::use_i18n;
+use crate::requests::pod_req::Episode as RecentEpisode;
+use crate::requests::pod_req::{
+    EpisodeDownload, EpisodeDownloadResponse, HistoryEpisode, PlaylistEpisode, QueuedEpisode,
+    SavedEpisode,
+};
+use crate::requests::search_pods::Episode as FeedEpisode;
+use chrono::NaiveDate;
+use std::collections::HashSet;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+use yewdux::prelude::*;
+
+/// What the filter needs to know about an episode, whichever list it came from.
+pub trait FilterableEpisode {
+    fn filter_id(&self) -> i32;
+    fn filter_title(&self) -> &str;
+    fn filter_podcast(&self) -> &str;
+    fn filter_pub_date(&self) -> &str;
+    fn filter_duration_sec(&self) -> i32;
+    fn filter_listened_sec(&self) -> i32;
+    fn filter_completed(&self) -> bool;
+}
+
+// The server's episode lists all share these fields
+macro_rules! impl_filterable_episode {
+    ($($episode:ty),*) => {
+        $(impl FilterableEpisode for $episode {
+            fn filter_id(&self) -> i32 {
+                self.episodeid
+            }
+            fn filter_title(&self) -> &str {
+                &self.episodetitle
+            }
+            fn filter_podcast(&self) -> &str {
+                &self.podcastname
+            }
+            fn filter_pub_date(&self) -> &str {
+                &self.episodepubdate
+            }
+            fn filter_duration_sec(&self) -> i32 {
+                self.episodeduration
+            }
+            fn filter_listened_sec(&self) -> i32 {
+                self.listenduration.unwrap_or(0)
+            }
+            fn filter_completed(&self) -> bool {
+                self.completed
+            }
+        })*
+    };
+}
+
+impl_filterable_episode!(
+    RecentEpisode,
+    QueuedEpisode,
+    SavedEpisode,
+    HistoryEpisode,
+    EpisodeDownload,
+    PlaylistEpisode
+);
+
+/// Episodes of a podcast's feed. Ones the server hasn't stored yet have no id or progress.
+impl FilterableEpisode for FeedEpisode {
+    fn filter_id(&self) -> i32 {
+        self.episode_id.unwrap_or(0)
+    }
+    fn filter_title(&self) -> &str {
+        self.title.as_deref().unwrap_or_default()
+    }
+    fn filter_podcast(&self) -> &str {
+        ""
+    }
+    fn filter_pub_date(&self) -> &str {
+        self.pub_date.as_deref().unwrap_or_default()
+    }
+    fn filter_duration_sec(&self) -> i32 {
+        self.duration
+            .as_deref()
+            .and_then(|duration| convert_time_to_seconds(duration).ok())
+            .unwrap_or(0) as i32
+    }
+    fn filter_listened_sec(&self) -> i32 {
+        0
+    }
+    fn filter_completed(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PlayState {
+    #[default]
+    Any,
+    Unplayed,
+    InProgress,
+    Played,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DownloadState {
+    #[default]
+    Any,
+    Downloaded,
+    NotDownloaded,
+}
+
+/// `Default` keeps the order the page got the episodes in, like queue order.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum EpisodeSort {
+    #[default]
+    Default,
+    Date,
+    Duration,
+    Remaining,
+    Title,
+}
+
+impl PlayState {
+    const ALL: [PlayState; 4] = [
+        PlayState::Any,
+        PlayState::Unplayed,
+        PlayState::InProgress,
+        PlayState::Played,
+    ];
+
+    fn code(self) -> &'static str {
+        match self {
+            PlayState::Any => "any",
+            PlayState::Unplayed => "unplayed",
+            PlayState::InProgress => "in_progress",
+            PlayState::Played => "played",
+        }
+    }
+}
+
+impl DownloadState {
+    const ALL: [DownloadState; 3] = [
+        DownloadState::Any,
+        DownloadState::Downloaded,
+        DownloadState::NotDownloaded,
+    ];
+
+    fn code(self) -> &'static str {
+        match self {
+            DownloadState::Any => "any",
+            DownloadState::Downloaded => "downloaded",
+            DownloadState::NotDownloaded => "not_downloaded",
+        }
+    }
+}
+
+impl EpisodeSort {
+    const ALL: [EpisodeSort; 5] = [
+        EpisodeSort::Default,
+        EpisodeSort::Date,
+        EpisodeSort::Duration,
+        EpisodeSort::Remaining,
+        EpisodeSort::Title,
+    ];
+
+    fn code(self) -> &'static str {
+        match self {
+            EpisodeSort::Default => "default",
+            EpisodeSort::Date => "date",
+            EpisodeSort::Duration => "duration",
+            EpisodeSort::Remaining => "remaining",
+            EpisodeSort::Title => "title",
+        }
+    }
+}
+
+/// One page's episode filter and sort. Everything left at its default lets all episodes
+/// through in the page's own order.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct EpisodeFilter {
+    /// Matched case-insensitively against the title and podcast name.
+    pub text: String,
+    pub podcast: Option<String>,
+    pub play_state: PlayState,
+    pub download_state: DownloadState,
+    /// Inclusive, in the user's time zone.
+    pub released_after: Option<NaiveDate>,
+    pub released_before: Option<NaiveDate>,
+    pub min_minutes: Option<u32>,
+    pub max_minutes: Option<u32>,
+    pub sort: EpisodeSort,
+    pub descending: bool,
+}
+
+/// What the filter reads from the rest of the app rather than the episode itself.
+pub struct FilterContext {
+    completed: HashSet<i32>,
+    downloaded: HashSet<i32>,
+    user_tz: Option<String>,
+}
+
+impl FilterContext {
+    pub fn from_state(state: &AppState) -> Self {
+        Self {
+            completed: state.completed_episodes.iter().flatten().copied().collect(),
+            downloaded: state
+                .downloaded_episodes
+                .iter()
+                .flat_map(|downloads| downloads.episodes.iter().map(|ep| ep.episodeid))
+                .collect(),
+            user_tz: state.user_tz.clone(),
+        }
+    }
+
+    fn play_state<E: FilterableEpisode>(&self, episode: &E) -> PlayState {
+        if episode.filter_completed() || self.completed.contains(&episode.filter_id()) {
+            PlayState::Played
+        } else if episode.filter_listened_sec() > 0 {
+            PlayState::InProgress
+        } else {
+            PlayState::Unplayed
+        }
+    }
+
+    fn release_date<E: FilterableEpisode>(&self, episode: &E) -> NaiveDate {
+        parse_date(episode.filter_pub_date(), &self.user_tz).date_naive()
+    }
+}
+
+impl EpisodeFilter {
+    pub fn is_active(&self) -> bool {
+        *self != Self::default()
+    }
+
+    fn matches<E: FilterableEpisode>(&self, episode: &E, context: &FilterContext) -> bool {
+        let text = self.text.trim().to_lowercase();
+        if !text.is_empty()
+            && !episode.filter_title().to_lowercase().contains(&text)
+            && !episode.filter_podcast().to_lowercase().contains(&text)
+        {
+            return false;
+        }
+        if let Some(podcast) = &self.podcast {
+            if episode.filter_podcast() != podcast {
+                return false;
+            }
+        }
+        if self.play_state != PlayState::Any && context.play_state(episode) != self.play_state {
+            return false;
+        }
+        let downloaded = context.downloaded.contains(&episode.filter_id());
+        match self.download_state {
+            DownloadState::Downloaded if !downloaded => return false,
+            DownloadState::NotDownloaded if downloaded => return false,
+            _ => {}
+        }
+        if self.released_after.is_some() || self.released_before.is_some() {
+            let released = context.release_date(episode);
+            if self.released_after.map_or(false, |after| released < after)
+                || self
+                    .released_before
+                    .map_or(false, |before| released > before)
+            {
+                return false;
+            }
+        }
+        let minutes = episode.filter_duration_sec() as u32 / 60;
+        if self.min_minutes.map_or(false, |min| minutes < min)
+            || self.max_minutes.map_or(false, |max| minutes > max)
+        {
+            return false;
+        }
+        true
+    }
+
+    /// The episodes that pass, in the chosen order. Ties keep the page's order.
+    pub fn apply<E: FilterableEpisode>(&self, episodes: Vec<E>, context: &FilterContext) -> Vec<E> {
+        let mut episodes: Vec<E> = episodes
+            .into_iter()
+            .filter(|episode| self.matches(episode, context))
+            .collect();
+        match self.sort {
+            EpisodeSort::Default => {}
+            EpisodeSort::Date => episodes.sort_by_key(|episode| {
+                parse_date(episode.filter_pub_date(), &context.user_tz).timestamp()
+            }),
+            EpisodeSort::Duration => episodes.sort_by_key(|episode| episode.filter_duration_sec()),
+            EpisodeSort::Remaining => episodes.sort_by_key(|episode| {
+                if context.play_state(episode) == PlayState::Played {
+                    0
+                } else {
+                    (episode.filter_duration_sec() - episode.filter_listened_sec()).max(0)
+                }
+            }),
+            EpisodeSort::Title => {
+                episodes.sort_by_cached_key(|episode| episode.filter_title().to_lowercase())
+            }
+        }
+        if self.descending {
+            episodes.reverse();
+        }
+        episodes
+    }
+}
+
+/// The podcasts in a list, for the podcast picker.
+pub fn podcast_names<E: FilterableEpisode>(episodes: &[E]) -> Vec<String> {
+    let mut names: Vec<String> = episodes
+        .iter()
+        .map(|episode| episode.filter_podcast().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+    names.sort_by_key(|name| name.to_lowercase());
+    names.dedup();
+    names
+}
+
+/// The filter for one page, like "history" or "queue". Each page keeps its own for the session.
+#[hook]
+pub fn use_episode_filter(page: &str) -> EpisodeFilter {
+    let filters = use_store_value::<FilterState>();
+    filters
+        .episode_filters
+        .get(page)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// The server only tells the downloads page what's downloaded, so other pages ask for the list
+/// the first time someone filters on it.
+fn load_downloaded_episodes(state: &AppState, dispatch: Dispatch<AppState>) {
+    if state.downloaded_episodes.is_some() {
+        return;
+    }
+    let (Some(auth), Some(user)) = (state.auth_details.clone(), state.user_details.clone()) else {
+        return;
+    };
+    wasm_bindgen_futures::spawn_local(async move {
+        #[cfg(feature = "server_build")]
+        let fetched = crate::requests::pod_req::call_get_episode_downloads(
+            &auth.server_name,
+            &auth.api_key,
+            &user.UserID,
+        )
+        .await
+        .map_err(|e| e.to_string());
+        #[cfg(not(feature = "server_build"))]
+        let fetched = {
+            let _ = (&auth, &user);
+            crate::components::downloads_tauri::fetch_local_episodes()
+                .await
+                .map_err(|e| format!("{:?}", e))
+        };
+        match fetched {
+            Ok(episodes) => dispatch.reduce_mut(|state| {
+                state.downloaded_episodes = Some(EpisodeDownloadResponse { episodes })
+            }),
+            Err(e) => web_sys::console::log_1(
+                &format!("Unable to load downloaded episodes: {}", e).into(),
+            ),
+        }
+    });
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct EpisodeFilterBarProps {
+    /// Which page's filter this edits.
+    pub page: AttrValue,
+    /// Podcasts to offer in the picker. Left empty on pages showing a single podcast.
+    #[prop_or_default]
+    pub podcasts: Vec<String>,
+    /// Off on the download pages, where every episode is downloaded.
+    #[prop_or(true)]
+    pub show_download_state: bool,
+}
+
+/// The filter button and, once opened, the controls for a page's episode filter.
+#[function_component(EpisodeFilterBar)]
+pub fn episode_filter_bar(props: &EpisodeFilterBarProps) -> Html {
+    let i18n = use_i18n();
+    let filter = use_episode_filter(&props.page);
+    let (_filter_state, filter_dispatch) = use_store::<FilterState>();
+    let (state, dispatch) = use_store::<AppState>();
+    let show_filters = use_state(|| false);
+
+    let needs_downloads = filter.download_state != DownloadState::Any;
+    use_effect_with(needs_downloads, move |needs_downloads| {
+        if *needs_downloads {
+            load_downloaded_episodes(&state, dispatch);
+        }
+        || ()
+    });
+
+    let toggle_filters = {
+        let show_filters = show_filters.clone();
+        Callback::from(move |_: MouseEvent| show_filters.set(!*show_filters))
+    };
+
+    // Every control edits the page's filter through this
+    let update = {
+        let page = props.page.to_string();
+        move |edit: fn(&mut EpisodeFilter, String)| {
+            let page = page.clone();
+            let filter_dispatch = filter_dispatch.clone();
+            move |value: String| {
+                filter_dispatch.reduce_mut(|filters| {
+                    edit(
+                        filters.episode_filters.entry(page.clone()).or_default(),
+                        value,
+                    )
+                });
+            }
+        }
+    };
+    let on_input = |edit: fn(&mut EpisodeFilter, String)| {
+        let update = update(edit);
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                update(input.value());
+            }
+        })
+    };
+    let on_change = |edit: fn(&mut EpisodeFilter, String)| {
+        let update = update(edit);
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                update(select.value());
+            } else if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                update(input.value());
+            }
+        })
+    };
+
+    let on_text = on_input(|filter, value| filter.text = value);
+    let on_podcast =
+        on_change(|filter, value| filter.podcast = Some(value).filter(|value| !value.is_empty()));
+    let on_play_state = on_change(|filter, value| {
+        filter.play_state = PlayState::ALL
+            .into_iter()
+            .find(|state| state.code() == value)
+            .unwrap_or_default()
+    });
+    let on_download_state = on_change(|filter, value| {
+        filter.download_state = DownloadState::ALL
+            .into_iter()
+            .find(|state| state.code() == value)
+            .unwrap_or_default()
+    });
+    let on_released_after = on_change(|filter, value| {
+        filter.released_after = NaiveDate::parse_from_str(&value, "%Y-%m-%d").ok()
+    });
+    let on_released_before = on_change(|filter, value| {
+        filter.released_before = NaiveDate::parse_from_str(&value, "%Y-%m-%d").ok()
+    });
+    let on_min_minutes = on_input(|filter, value| filter.min_minutes = value.parse().ok());
+    let on_max_minutes = on_input(|filter, value| filter.max_minutes = value.parse().ok());
+    let on_sort = on_change(|filter, value| {
+        filter.sort = EpisodeSort::ALL
+            .into_iter()
+            .find(|sort| sort.code() == value)
+            .unwrap_or_default()
+    });
+    let toggle_direction = {
+        let update = update(|filter, _| filter.descending = !filter.descending);
+        Callback::from(move |_: MouseEvent| update(String::new()))
+    };
+    let clear_filters = {
+        let update = update(|filter, _| *filter = EpisodeFilter::default());
+        Callback::from(move |_: MouseEvent| update(String::new()))
+    };
+
+    let date_value = |date: Option<NaiveDate>| {
+        date.map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_default()
+    };
+    let number_value = |minutes: Option<u32>| minutes.map(|m| m.to_string()).unwrap_or_default();
+
+    html! {
+        <div class="episode-filter mb-4">
+            <div class="flex items-center space-x-2">
+                <button class={classes!("download-button", "font-bold", "py-2", "px-4", "rounded", "inline-flex", "items-center", filter.is_active().then_some("filter-active"))} onclick={toggle_filters}>
+                    <span class="material-icons icon-space">{"filter_alt"}</span>
+                    <span class="text-lg">{ i18n.t("filter.button") }</span>
+                </button>
+                if filter.is_active() {
+                    <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center" onclick={clear_filters}>
+                        <span class="material-icons icon-space">{"clear"}</span>
+                        <span class="text-lg">{ i18n.t("filter.clear") }</span>
+                    </button>
+                }
+            </div>
+            if *show_filters {
+                <div class="episode-filter-panel filter-dropdown">
+                    <input
+                        type="search"
+                        class="search-bar-input border text-sm rounded-lg p-2.5"
+                        placeholder={i18n.t("filter.text_placeholder")}
+                        value={filter.text.clone()}
+                        oninput={on_text}
+                    />
+                    if !props.podcasts.is_empty() {
+                        <select onchange={on_podcast} title={i18n.t("filter.podcast")}>
+                            <option value="" selected={filter.podcast.is_none()}>{ i18n.t("filter.all_podcasts") }</option>
+                            { for props.podcasts.iter().map(|name| html! {
+                                <option value={name.clone()} selected={filter.podcast.as_ref() == Some(name)}>{ name }</option>
+                            })}
+                        </select>
+                    }
+                    <select onchange={on_play_state} title={i18n.t("filter.play_state")}>
+                        { for PlayState::ALL.iter().map(|state| html! {
+                            <option value={state.code()} selected={*state == filter.play_state}>
+                                { i18n.t(&format!("filter.play_state.{}", state.code())) }
+                            </option>
+                        })}
+                    </select>
+                    if props.show_download_state {
+                        <select onchange={on_download_state} title={i18n.t("filter.download_state")}>
+                            { for DownloadState::ALL.iter().map(|state| html! {
+                                <option value={state.code()} selected={*state == filter.download_state}>
+                                    { i18n.t(&format!("filter.download_state.{}", state.code())) }
+                                </option>
+                            })}
+                        </select>
+                    }
+                    <label class="episode-filter-range item_container-text">
+                        <span>{ i18n.t("filter.released") }</span>
+                        <input type="date" class="search-bar-input border text-sm rounded-lg p-2" value={date_value(filter.released_after)} onchange={on_released_after} title={i18n.t("filter.released_after")} />
+                        <span>{"–"}</span>
+                        <input type="date" class="search-bar-input border text-sm rounded-lg p-2" value={date_value(filter.released_before)} onchange={on_released_before} title={i18n.t("filter.released_before")} />
+                    </label>
+                    <label class="episode-filter-range item_container-text">
+                        <span>{ i18n.t("filter.length") }</span>
+                        <input type="number" min="0" class="search-bar-input border text-sm rounded-lg p-2" placeholder={i18n.t("filter.min_minutes")} value={number_value(filter.min_minutes)} oninput={on_min_minutes} />
+                        <span>{"–"}</span>
+                        <input type="number" min="0" class="search-bar-input border text-sm rounded-lg p-2" placeholder={i18n.t("filter.max_minutes")} value={number_value(filter.max_minutes)} oninput={on_max_minutes} />
+                    </label>
+                    <div class="flex items-center space-x-2">
+                        <select onchange={on_sort} title={i18n.t("filter.sort")}>
+                            { for EpisodeSort::ALL.iter().map(|sort| html! {
+                                <option value={sort.code()} selected={*sort == filter.sort}>
+                                    { i18n.t(&format!("filter.sort.{}", sort.code())) }
+                                </option>
+                            })}
+                        </select>
+                        <button class="download-button rounded p-2 inline-flex items-center" onclick={toggle_direction}
+                            title={i18n.t(if filter.descending { "filter.descending" } else { "filter.ascending" })}>
+                            <span class="material-icons">{ if filter.descending { "arrow_downward" } else { "arrow_upward" } }</span>
+                        </button>
+                    </div>
+                </div>
+            }
+        </div>
+    }
+}
+
+/// A page's filter bar over its episodes, followed by the ones that pass, each drawn by
+/// `render`.
+pub fn filtered_episode_list<E: FilterableEpisode>(
+    page: &'static str,
+    filter: &EpisodeFilter,
+    state: &AppState,
+    episodes: Vec<E>,
+    render: impl FnMut(E) -> Html,
+) -> Html {
+    let podcasts = podcast_names(&episodes);
+    let episodes = filter.apply(episodes, &FilterContext::from_state(state));
+    html! {
+        <>
+            <EpisodeFilterBar {page} {podcasts} />
+            if episodes.is_empty() {
+                { no_matching_episodes() }
+            } else {
+                { for episodes.into_iter().map(render) }
+            }
+        </>
+    }
+}
+
+/// Shown in place of a list when there are episodes but the filter hides all of them.
+pub fn no_matching_episodes() -> Html {
+    let i18n = crate::components::i18n::I18n::current();
+    html! {
+        <div class="empty-episodes-container">
+            <img src="static/assets/favicon.png" alt={i18n.t("common.logo_alt")} class="logo"/>
+            <h1 class="page-subtitles">{ i18n.t("filter.no_matches_title") }</h1>
+            <p class="page-paragraphs">{ i18n.t("filter.no_matches_body") }</p>
+        </div>
+    }
+}
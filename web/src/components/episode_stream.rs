@@ -0,0 +1,52 @@
+use crate::components::context::AppState;
+use crate::requests::pod_req::{subscribe_to_new_episodes, Episode};
+use std::cell::Cell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yewdux::prelude::*;
+
+/// Keeps the home feed current while the app is open by listening for episodes the server
+/// finds during its scheduled refreshes. Renders nothing.
+#[function_component(EpisodeStream)]
+pub fn episode_stream() -> Html {
+    let (state, dispatch) = use_store::<AppState>();
+    let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
+    let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
+    let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
+
+    use_effect_with(
+        (server_name, api_key, user_id),
+        move |(server_name, api_key, user_id)| {
+            let active = Rc::new(Cell::new(true));
+            if let (Some(server_name), Some(Some(api_key)), Some(user_id)) =
+                (server_name.clone(), api_key.clone(), *user_id)
+            {
+                let is_active = {
+                    let active = active.clone();
+                    move || active.get()
+                };
+                wasm_bindgen_futures::spawn_local(async move {
+                    subscribe_to_new_episodes(server_name, user_id, api_key, is_active, {
+                        move |new_episode| {
+                            let episode = Episode::from(new_episode);
+                            dispatch.reduce_mut(move |state| {
+                                // Only a feed that's already loaded needs updating; the next
+                                // fetch picks the episode up otherwise
+                                if let Some(feed) = state.server_feed_results.as_mut() {
+                                    let episodes = feed.episodes.get_or_insert_with(Vec::new);
+                                    if !episodes.iter().any(|e| e.episodeid == episode.episodeid) {
+                                        episodes.insert(0, episode);
+                                    }
+                                }
+                            });
+                        }
+                    })
+                    .await;
+                });
+            }
+            move || active.set(false)
+        },
+    );
+
+    html! {}
+}
@@ -4,19 +4,23 @@ use super::gen_funcs::{format_datetime, match_date_format, parse_date};
 use crate::components::audio::{on_play_click, AudioPlayer};
 use crate::components::click_events::create_on_title_click;
 use crate::components::context::{AppState, UIState};
+use crate::components::episode_filter::{filtered_episode_list, use_episode_filter};
 use crate::components::gen_funcs::format_time;
 use futures::future::join_all;
 use crate::components::gen_funcs::{
     convert_time_to_seconds, sanitize_html_with_blank_target, truncate_description,
 };
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, notify_info};
 use crate::components::podcast_layout::ClickedFeedURL;
 use crate::requests::login_requests::use_check_authentication;
 use crate::requests::pod_req::{
     call_add_category, call_add_podcast, call_adjust_skip_times, call_check_podcast,
-    call_download_all_podcast, call_enable_auto_download, call_fetch_podcasting_2_pod_data,
-    call_get_auto_download_status, call_get_auto_skip_times, call_get_podcast_details,
-    call_get_podcast_id, call_get_podcast_id_from_ep, call_get_podcast_id_from_ep_name,
-    call_remove_category, call_remove_podcasts_name, AddCategoryRequest, AutoDownloadRequest,
+    call_download_all_podcast, call_enable_auto_download, call_enable_auto_queue,
+    call_fetch_podcasting_2_pod_data, call_get_auto_download_status, call_get_auto_queue_status,
+    call_get_auto_skip_times, call_get_podcast_details, call_get_podcast_id,
+    call_get_podcast_id_from_ep, call_get_podcast_id_from_ep_name, call_remove_category,
+    call_remove_podcasts_name, AddCategoryRequest, AutoDownloadRequest, AutoQueueRequest,
     DownloadAllPodcastRequest, FetchPodcasting2PodDataRequest, Person, Podcast, PodcastDetails,
     PodcastResponse, PodcastValues, RemoveCategoryRequest, RemovePodcastValuesName,
     SkipTimesRequest,
@@ -38,7 +42,7 @@ use web_sys::{window, Event, HtmlInputElement, MouseEvent, UrlSearchParams};
 use yew::prelude::*;
 use yew::Properties;
 use yew::{
-    function_component, html, use_effect, use_effect_with, use_node_ref, Callback, Html, TargetCast,
+    function_component, html, use_effect_with, use_node_ref, Callback, Html, TargetCast,
 };
 use yew_router::history::{BrowserHistory, History};
 use yewdux::prelude::*;
@@ -142,28 +146,6 @@ impl Reducer<AppState> for AppStateMsg {
     }
 }
 
-pub enum UIStateMsg {
-    ClearErrorMessage,
-    ClearInfoMessage,
-}
-
-impl Reducer<UIState> for UIStateMsg {
-    fn apply(self, mut state: Rc<UIState>) -> Rc<UIState> {
-        let state = Rc::make_mut(&mut state);
-
-        match self {
-            UIStateMsg::ClearErrorMessage => {
-                state.error_message = None;
-            }
-            UIStateMsg::ClearInfoMessage => {
-                state.info_message = None;
-            }
-        }
-
-        (*state).clone().into()
-    }
-}
-
 #[derive(Properties, PartialEq, Clone)]
 pub struct HostDropdownProps {
     pub title: String,
@@ -189,6 +171,10 @@ fn map_podcast_details_to_podcast(details: PodcastDetails) -> Podcast {
         author: Some(details.author),
         categories: details.categories,
         explicit: details.explicit,
+        lastepisodedate: None,
+        unplayedcount: 0,
+        totallistentime: 0,
+        autodownload: false,
     }
 }
 
@@ -353,6 +339,10 @@ pub fn host_dropdown(HostDropdownProps { title, hosts }: &HostDropdownProps) ->
                                                                 .map(|cat_map| cat_map.values().cloned().collect::<Vec<_>>().join(", "))
                                                                 .unwrap_or_else(|| "{}".to_string()),
                                                             explicit: clicked_feed_url.podcast_explicit,
+                                                            lastepisodedate: None,
+                                                            unplayedcount: 0,
+                                                            totallistentime: 0,
+                                                            autodownload: false,
                                                         });
                                                     }
                                                 }
@@ -375,7 +365,7 @@ pub fn host_dropdown(HostDropdownProps { title, hosts }: &HostDropdownProps) ->
                                     } else {
                                         // Handle error
                                         dispatch.reduce_mut(|state| {
-                                            state.error_message = Some("Failed to fetch person info".to_string());
+                                            notify_error(I18n::current().t("podcast.person_info_failed"));
                                             state.is_loading = Some(false);
                                         });
                                     }
@@ -406,7 +396,9 @@ pub fn host_dropdown(HostDropdownProps { title, hosts }: &HostDropdownProps) ->
 
 #[function_component(EpisodeLayout)]
 pub fn episode_layout() -> Html {
+    let i18n = use_i18n();
     let is_added = use_state(|| false);
+    let episode_filter = use_episode_filter("podcast");
     let (state, _dispatch) = use_store::<UIState>();
     let (search_state, _search_dispatch) = use_store::<AppState>();
     let podcast_feed_results = search_state.podcast_feed_results.clone();
@@ -697,20 +689,26 @@ pub fn episode_layout() -> Html {
     });
 
     let download_status = use_state(|| false);
+    let queue_status = use_state(|| false);
     let podcast_id = use_state(|| 0);
     let start_skip = use_state(|| 0);
     let end_skip = use_state(|| 0);
+    let podcast_speed = use_state(|| None::<f64>);
+    let volume_boost = use_state(|| 100);
 
     {
         let api_key = api_key.clone();
         let server_name = server_name.clone();
         let podcast_id = podcast_id.clone();
         let download_status = download_status.clone();
+        let queue_status = queue_status.clone();
         // let episode_name = episode_name_pre.clone();
         // let episode_url = episode_url_pre.clone();
         let user_id = search_state.user_details.as_ref().map(|ud| ud.UserID);
         let effect_start_skip = start_skip.clone();
         let effect_end_skip = end_skip.clone();
+        let effect_podcast_speed = podcast_speed.clone();
+        let effect_volume_boost = volume_boost.clone();
         let effect_added = is_added.clone();
         let audio_dispatch = _dispatch.clone();
         let click_state = search_state.clone();
@@ -740,6 +738,7 @@ pub fn episode_layout() -> Html {
                     let server_name = server_name.clone();
                     let podcast_id = podcast_id.clone();
                     let download_status = download_status.clone();
+                    let queue_status = queue_status.clone();
                     let episode_name = episode_name;
                     let episode_url = episode_url;
                     let user_id = user_id.unwrap();
@@ -782,6 +781,27 @@ pub fn episode_layout() -> Html {
                                                 );
                                             }
                                         }
+                                        match call_get_auto_queue_status(
+                                            &server_name,
+                                            user_id,
+                                            &Some(api_key.clone().unwrap()),
+                                            id,
+                                        )
+                                        .await
+                                        {
+                                            Ok(status) => {
+                                                queue_status.set(status);
+                                            }
+                                            Err(e) => {
+                                                web_sys::console::log_1(
+                                                    &format!(
+                                                        "Error getting auto-queue status: {}",
+                                                        e
+                                                    )
+                                                    .into(),
+                                                );
+                                            }
+                                        }
                                         match call_get_auto_skip_times(
                                             &server_name,
                                             &Some(api_key.clone().unwrap()),
@@ -790,9 +810,11 @@ pub fn episode_layout() -> Html {
                                         )
                                         .await
                                         {
-                                            Ok((start, end)) => {
-                                                effect_start_skip.set(start);
-                                                effect_end_skip.set(end);
+                                            Ok(settings) => {
+                                                effect_start_skip.set(settings.start_skip);
+                                                effect_end_skip.set(settings.end_skip);
+                                                effect_podcast_speed.set(settings.playback_speed);
+                                                effect_volume_boost.set(settings.volume_boost);
                                             }
                                             Err(e) => {
                                                 web_sys::console::log_1(
@@ -900,33 +922,8 @@ pub fn episode_layout() -> Html {
         || ()
     });
 
-    {
-        let dispatch = _dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                dispatch.apply(UIStateMsg::ClearErrorMessage);
-                dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
 
     let delete_all_click = {
-        let add_dispatch = _dispatch.clone();
         let pod_values = clicked_podcast_info.clone();
 
         let user_id_og = user_id.clone();
@@ -944,7 +941,6 @@ pub fn episode_layout() -> Html {
             let pod_feed_url_og = pod_values.clone().unwrap().podcast_url.clone();
             app_dispatch.reduce_mut(|state| state.is_loading = Some(true));
             let is_added_inner = call_is_added.clone();
-            let call_dispatch = add_dispatch.clone();
             let pod_title = pod_title_og.clone();
             let pod_feed_url = pod_feed_url_og.clone();
             let user_id = user_id_og.clone().unwrap();
@@ -957,7 +953,6 @@ pub fn episode_layout() -> Html {
             let server_name_call = server_name_clone.clone();
             let app_dispatch = app_dispatch.clone();
             wasm_bindgen_futures::spawn_local(async move {
-                let dispatch_wasm = call_dispatch.clone();
                 let api_key_wasm = api_key_call.clone().unwrap();
                 let server_name_wasm = server_name_call.clone();
                 let pod_values_clone = podcast_values.clone(); // Make sure you clone the podcast values
@@ -970,10 +965,7 @@ pub fn episode_layout() -> Html {
                 {
                     Ok(success) => {
                         if success {
-                            dispatch_wasm.reduce_mut(|state| {
-                                state.info_message =
-                                    Option::from("Podcast successfully removed".to_string())
-                            });
+                            notify_info(I18n::current().t("podcast.removed"));
                             app_dispatch.reduce_mut(|state| state.is_loading = Some(false));
                             is_added_inner.set(false);
                             web_sys::console::log_1(&"adjusting podcast added".into());
@@ -981,19 +973,13 @@ pub fn episode_layout() -> Html {
                                 state.podcast_added = Some(podcast_added);
                             });
                         } else {
-                            dispatch_wasm.reduce_mut(|state| {
-                                state.error_message =
-                                    Option::from("Failed to remove podcast".to_string())
-                            });
+                            notify_error(I18n::current().t("podcast.remove_failed"));
                             app_dispatch.reduce_mut(|state| state.is_loading = Some(false));
                         }
                         page_state.set(PageState::Hidden);
                     }
                     Err(e) => {
-                        dispatch_wasm.reduce_mut(|state| {
-                            state.error_message =
-                                Option::from(format!("Error removing podcast: {:?}", e))
-                        });
+                        notify_error(I18n::current().tf("podcast.remove_error", &[("error", &format!("{:?}", e))]));
                         app_dispatch.reduce_mut(|state| state.is_loading = Some(false));
                     }
                 }
@@ -1003,11 +989,9 @@ pub fn episode_layout() -> Html {
 
     let download_server_name = server_name.clone();
     let download_api_key = api_key.clone();
-    let download_dispatch = _dispatch.clone();
     let app_state = search_state.clone();
 
     let download_all_click = {
-        let call_dispatch = download_dispatch.clone();
         let server_name_copy = download_server_name.clone();
         let api_key_copy = download_api_key.clone();
         let user_id_copy = user_id.clone();
@@ -1018,7 +1002,6 @@ pub fn episode_layout() -> Html {
             let server_name = server_name_copy.clone();
             let api_key = api_key_copy.clone();
             let search_state = search_call_state.clone();
-            let call_down_dispatch = call_dispatch.clone();
             wasm_bindgen_futures::spawn_local(async move {
                 let episode_id = match search_state
                     .podcast_feed_results
@@ -1057,23 +1040,15 @@ pub fn episode_layout() -> Html {
                         .await
                         {
                             Ok(success_message) => {
-                                call_down_dispatch.reduce_mut(|state| {
-                                    state.info_message =
-                                        Option::from(format!("{}", success_message))
-                                });
+                                notify_info(format!("{}", success_message));
                             }
                             Err(e) => {
-                                call_down_dispatch.reduce_mut(|state| {
-                                    state.error_message = Option::from(format!("{}", e))
-                                });
+                                notify_error(format!("{}", e));
                             }
                         }
                     }
                     Err(e) => {
-                        call_down_dispatch.reduce_mut(|state| {
-                            state.error_message =
-                                Option::from(format!("Failed to get podcast ID: {}", e))
-                        });
+                        notify_error(I18n::current().tf("podcast.id_failed", &[("error", &e.to_string())]));
                     }
                 }
             });
@@ -1173,27 +1148,71 @@ pub fn episode_layout() -> Html {
         })
     };
 
+    let toggle_auto_queue = {
+        let api_key = api_key.clone();
+        let server_name = server_name.clone();
+        let queue_status = queue_status.clone();
+        let podcast_id = podcast_id.clone();
+        let user_id = user_id.clone();
+
+        Callback::from(move |_| {
+            let api_key = api_key.clone();
+            let server_name = server_name.clone();
+            let queue_status = queue_status.clone();
+            let auto_queue = !*queue_status;
+            let pod_id_deref = *podcast_id.clone();
+            let user_id = user_id.clone().unwrap();
+
+            let request_data = AutoQueueRequest {
+                podcast_id: pod_id_deref,
+                user_id,
+                auto_queue,
+            };
+
+            wasm_bindgen_futures::spawn_local(async move {
+                if let (Some(api_key), Some(server_name)) = (api_key.as_ref(), server_name.as_ref())
+                {
+                    match call_enable_auto_queue(&server_name, &api_key.clone().unwrap(), &request_data)
+                        .await
+                    {
+                        Ok(_) => {
+                            queue_status.set(auto_queue);
+                        }
+                        Err(e) => {
+                            web_sys::console::log_1(
+                                &format!("Error enabling/disabling auto-queue: {}", e).into(),
+                            );
+                        }
+                    }
+                }
+            });
+        })
+    };
+
     let start_skip_call = start_skip.clone();
     let end_skip_call = end_skip.clone();
     let start_skip_call_button = start_skip.clone();
     let end_skip_call_button = end_skip.clone();
-    let skip_dispatch = _dispatch.clone();
+    let podcast_speed_call = podcast_speed.clone();
+    let volume_boost_call = volume_boost.clone();
 
     // Save the skip times to the server
     let save_skip_times = {
         let start_skip = start_skip.clone();
         let end_skip = end_skip.clone();
+        let podcast_speed = podcast_speed.clone();
+        let volume_boost = volume_boost.clone();
         let api_key = api_key.clone();
         let user_id = user_id.clone();
         let server_name = server_name.clone();
         let podcast_id = podcast_id.clone();
-        let skip_dispatch = skip_dispatch.clone();
 
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
-            let skip_call_dispatch = skip_dispatch.clone();
             let start_skip = *start_skip;
             let end_skip = *end_skip;
+            let playback_speed = *podcast_speed;
+            let volume_boost = *volume_boost;
             let api_key = api_key.clone();
             let user_id = user_id.clone().unwrap();
             let server_name = server_name.clone();
@@ -1206,23 +1225,20 @@ pub fn episode_layout() -> Html {
                         podcast_id,
                         start_skip,
                         end_skip,
+                        playback_speed,
+                        volume_boost,
                         user_id,
                     };
 
                     match call_adjust_skip_times(&server_name, &api_key, &request).await {
                         Ok(_) => {
-                            skip_call_dispatch.reduce_mut(|state| {
-                                state.info_message = Option::from("Skip times Adjusted".to_string())
-                            });
+                            notify_info(I18n::current().t("podcast.playback_adjusted"));
                         }
                         Err(e) => {
                             web_sys::console::log_1(
                                 &format!("Error updating skip times: {}", e).into(),
                             );
-                            skip_call_dispatch.reduce_mut(|state| {
-                                state.error_message =
-                                    Option::from("Error Adjusting Skip Times".to_string())
-                            });
+                            notify_error(I18n::current().t("podcast.playback_adjust_failed"));
                         }
                     }
                 }
@@ -1417,29 +1433,36 @@ pub fn episode_layout() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Podcast Options"}
+                            { i18n.t("podcast.options") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <div>
-                                <label for="download_schedule" class="block mb-2 text-sm font-medium">{"Download Future Episodes Automatically:"}</label>
+                                <label for="download_schedule" class="block mb-2 text-sm font-medium">{ i18n.t("podcast.auto_download") }</label>
                                 <label class="inline-flex relative items-center cursor-pointer">
                                     <input type="checkbox" checked={*download_status} class="sr-only peer" onclick={toggle_download} />
                                     <div class="w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-blue-300 dark:peer-focus:ring-blue-800 rounded-full peer dark:bg-gray-700 peer-checked:after:translate-x-full rtl:peer-checked:after:-translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:start-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all dark:border-gray-600 peer-checked:bg-blue-600"></div>
                                 </label>
                             </div>
+                            <div>
+                                <label for="auto_queue" class="block mb-2 text-sm font-medium">{ i18n.t("podcast.auto_queue") }</label>
+                                <label class="inline-flex relative items-center cursor-pointer">
+                                    <input type="checkbox" checked={*queue_status} class="sr-only peer" onclick={toggle_auto_queue} />
+                                    <div class="w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-blue-300 dark:peer-focus:ring-blue-800 rounded-full peer dark:bg-gray-700 peer-checked:after:translate-x-full rtl:peer-checked:after:-translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:start-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all dark:border-gray-600 peer-checked:bg-blue-600"></div>
+                                </label>
+                            </div>
                             <div class="mt-4">
-                                <label for="auto-skip" class="block mb-2 text-sm font-medium">{"Auto Skip Intros and Outros:"}</label>
+                                <label for="auto-skip" class="block mb-2 text-sm font-medium">{ i18n.t("podcast.auto_skip") }</label>
                                 <div class="flex items-center space-x-2">
                                     <div class="flex items-center space-x-2">
-                                        <label for="start-skip" class="block text-sm font-medium">{"Start Skip (seconds):"}</label>
+                                        <label for="start-skip" class="block text-sm font-medium">{ i18n.t("podcast.start_skip") }</label>
                                         <input
                                             type="number"
                                             id="start-skip"
@@ -1454,7 +1477,7 @@ pub fn episode_layout() -> Html {
                                         />
                                     </div>
                                     <div class="flex items-center space-x-2">
-                                        <label for="end-skip" class="block text-sm font-medium">{"End Skip (seconds):"}</label>
+                                        <label for="end-skip" class="block text-sm font-medium">{ i18n.t("podcast.end_skip") }</label>
                                         <input
                                             type="number"
                                             id="end-skip"
@@ -1468,17 +1491,66 @@ pub fn episode_layout() -> Html {
                                             })}
                                         />
                                     </div>
+                                    <button
+                                        class="download-button font-bold py-2 px-4 rounded"
+                                        onclick={save_skip_times.clone()}
+                                    >
+                                        { i18n.t("common.confirm") }
+                                    </button>
+                                </div>
+                            </div>
+                            <div class="mt-4">
+                                <label for="playback-settings" class="block mb-2 text-sm font-medium">{ i18n.t("podcast.playback_settings") }</label>
+                                <div class="flex items-center space-x-2">
+                                    <div class="flex items-center space-x-2">
+                                        <label for="podcast-speed" class="block text-sm font-medium">{ i18n.t("podcast.speed") }</label>
+                                        <input
+                                            type="number"
+                                            id="podcast-speed"
+                                            min="0.5"
+                                            max="3.0"
+                                            step="0.1"
+                                            placeholder={i18n.t("podcast.speed_default")}
+                                            value={podcast_speed.map(|speed| speed.to_string()).unwrap_or_default()}
+                                            class="email-input border text-sm rounded-lg p-2.5 w-20"
+                                            onchange={Callback::from(move |e: Event| {
+                                                if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                                                    // An empty field falls back to the player's default speed
+                                                    let value = input.value().parse::<f64>().ok().map(|speed| speed.clamp(0.5, 3.0));
+                                                    podcast_speed_call.set(value);
+                                                }
+                                            })}
+                                        />
+                                    </div>
+                                    <div class="flex items-center space-x-2">
+                                        <label for="volume-boost" class="block text-sm font-medium">{ i18n.t("podcast.volume_boost") }</label>
+                                        <input
+                                            type="number"
+                                            id="volume-boost"
+                                            min="100"
+                                            max="300"
+                                            step="10"
+                                            value={volume_boost.to_string()}
+                                            class="email-input border text-sm rounded-lg p-2.5 w-20"
+                                            onchange={Callback::from(move |e: Event| {
+                                                if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                                                    let value = input.value().parse::<i32>().unwrap_or(100).clamp(100, 300);
+                                                    volume_boost_call.set(value);
+                                                }
+                                            })}
+                                        />
+                                    </div>
                                     <button
                                         class="download-button font-bold py-2 px-4 rounded"
                                         onclick={save_skip_times}
                                     >
-                                        {"Confirm"}
+                                        { i18n.t("common.confirm") }
                                     </button>
                                 </div>
                             </div>
                             <div>
                                 <label for="category_adjust" class="block mb-2 text-sm font-medium">
-                                    {"Adjust Podcast Categories:"}
+                                    { i18n.t("podcast.adjust_categories") }
                                 </label>
                                 <div class="flex flex-wrap items-center space-x-2">
                                 {
@@ -1507,11 +1579,11 @@ pub fn episode_layout() -> Html {
                                                 </>
                                             }
                                         } else {
-                                            html! { <p>{ "No categories available" }</p> }
+                                            html! { <p>{ i18n.t("podcasts.no_categories") }</p> }
                                         }
                                     } else {
                                         // If clicked_feed is None, show a loading state or an empty placeholder
-                                        html! { <p>{ "Loading..." }</p> }
+                                        html! { <p>{ i18n.t("common.loading") }</p> }
                                     }
                                 }
                                 
@@ -1521,7 +1593,7 @@ pub fn episode_layout() -> Html {
                                     <input type="text"
                                         id="new_category"
                                         class="category-input w-full md:w-auto px-3 py-2 border rounded-md mr-2"
-                                        placeholder="New category"
+                                        placeholder={i18n.t("podcast.new_category_placeholder")}
                                         value={(*new_category).clone()}
                                         oninput={new_category_input.clone()} // Input handler to update `new_category`
                                     />
@@ -1529,7 +1601,7 @@ pub fn episode_layout() -> Html {
                                         class="add-category-button text-sm font-medium rounded-lg border focus:ring-4 focus:outline-none search-btn py-2 px-4 rounded-md"
                                         onclick={onclick_add}
                                     >
-                                        {"New Category"}
+                                        { i18n.t("podcast.new_category") }
                                     </button>
                                 </div>
                             </div>
@@ -1547,25 +1619,25 @@ pub fn episode_layout() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Verify Downloads"}
+                            { i18n.t("podcast.verify_downloads") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <div>
-                                <label for="download_schedule" class="block mb-2 text-sm font-medium">{"Are you sure you want to download all episodes from the current podcast to the server? If the podcast has a lot of episodes this might take awhile."}</label>
+                                <label for="download_schedule" class="block mb-2 text-sm font-medium">{ i18n.t("podcast.download_all_body") }</label>
                                 <div class="flex justify-between space-x-4">
                                     <button onclick={download_all_click} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                                        {"Yes, Download All"}
+                                        { i18n.t("podcast.download_all_confirm") }
                                     </button>
                                     <button onclick={on_close_modal.clone()} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                                        {"No, take me back"}
+                                        { i18n.t("podcasts.delete_cancel") }
                                     </button>
                                 </div>
                             </div>
@@ -1583,25 +1655,25 @@ pub fn episode_layout() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Delete Podcast"}
+                            { i18n.tn("podcasts.delete_title", 1, &[]) }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <div>
-                                <label for="download_schedule" class="block mb-2 text-sm font-medium">{"Are you sure you want to delete the podcast from the database? This will remove it from every aspect of the app. Meaning this will remove any saved, downloaded, or queued episodes for this podcast. It will also remove any history that includes it."}</label>
+                                <label for="download_schedule" class="block mb-2 text-sm font-medium">{ i18n.tn("podcasts.delete_body", 1, &[]) }</label>
                                 <div class="flex justify-between space-x-4">
                                     <button onclick={delete_all_click} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                                        {"Yes, Delete Podcast"}
+                                        { i18n.tn("podcasts.delete_confirm", 1, &[]) }
                                     </button>
                                     <button onclick={on_close_modal.clone()} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                                        {"No, take me back"}
+                                        { i18n.t("podcasts.delete_cancel") }
                                     </button>
                                 </div>
                             </div>
@@ -1635,7 +1707,6 @@ pub fn episode_layout() -> Html {
     };
 
     let toggle_podcast = {
-        let add_dispatch = _dispatch.clone();
         let pod_values = clicked_podcast_info.clone();
         let user_id_og = user_id.clone();
 
@@ -1670,7 +1741,6 @@ pub fn episode_layout() -> Html {
                 let app_dispatch = app_dispatch.clone();
                 app_dispatch.reduce_mut(|state| state.is_loading = Some(true));
                 let is_added_inner = is_added.clone();
-                let call_dispatch = add_dispatch.clone();
                 let pod_title = pod_title_og.clone();
                 let pod_artwork = pod_artwork_og.clone();
                 let pod_author = pod_author_og.clone();
@@ -1698,7 +1768,6 @@ pub fn episode_layout() -> Html {
                 let user_id_call = user_id_clone.clone();
 
                 wasm_bindgen_futures::spawn_local(async move {
-                    let dispatch_wasm = call_dispatch.clone();
                     let api_key_wasm = api_key_call.clone().unwrap();
                     let user_id_wasm = user_id_call.clone().unwrap();
                     let server_name_wasm = server_name_call.clone();
@@ -1714,10 +1783,7 @@ pub fn episode_layout() -> Html {
                     {
                         Ok(response_body) => {
                             if response_body.success {
-                                dispatch_wasm.reduce_mut(|state| {
-                                    state.info_message =
-                                        Option::from("Podcast successfully added".to_string())
-                                });
+                                notify_info(I18n::current().t("podcast.added"));
                                 app_dispatch.reduce_mut(|state| state.is_loading = Some(false));
                                 is_added_inner.set(true);
                                 web_sys::console::log_1(&"adjusting podcast added".into());
@@ -1776,18 +1842,12 @@ pub fn episode_layout() -> Html {
                                     state.podcast_added = Some(podcast_added);
                                 });
                             } else {
-                                dispatch_wasm.reduce_mut(|state| {
-                                    state.error_message =
-                                        Option::from("Failed to add podcast".to_string())
-                                });
+                                notify_error(I18n::current().t("podcast.add_failed"));
                                 app_dispatch.reduce_mut(|state| state.is_loading = Some(false));
                             }
                         }
                         Err(e) => {
-                            dispatch_wasm.reduce_mut(|state| {
-                                state.error_message =
-                                    Option::from(format!("Error adding podcast: {:?}", e))
-                            });
+                            notify_error(I18n::current().tf("podcast.add_error", &[("error", &format!("{:?}", e))]));
                             app_dispatch.reduce_mut(|state| state.is_loading = Some(false));
                         }
                     }
@@ -1859,7 +1919,7 @@ pub fn episode_layout() -> Html {
                                             <div class="mobile-layout">
                                                 <div class="button-container">
                                                     <button onclick={Callback::from(move |_| web_link.emit(podcast_info.podcast_link.clone()))}
-                                                        title="Visit external podcast website" class="item-container-button font-bold rounded-full self-center mr-4">
+                                                        title={i18n.t("podcast.visit_website")} class="item-container-button font-bold rounded-full self-center mr-4">
                                                         { website_icon }
                                                     </button>
                                                     {
@@ -1895,7 +1955,7 @@ pub fn episode_layout() -> Html {
                                                         if search_state.podcast_added.unwrap() {
                                                             web_sys::console::log_1(&"toggling on".into());
                                                             html! {
-                                                                <button onclick={toggle_download} title="Click to download all episodes for this podcast" class="item-container-button font-bold rounded-full self-center mr-4">
+                                                                <button onclick={toggle_download} title={i18n.t("podcast.download_all_title")} class="item-container-button font-bold rounded-full self-center mr-4">
                                                                     { download_all }
                                                                 </button>
                                                             }
@@ -1904,13 +1964,13 @@ pub fn episode_layout() -> Html {
                                                             html! {}
                                                         }
                                                     }
-                                                    <button onclick={toggle_podcast} title="Click to add or remove podcast from feed" class="item-container-button font-bold rounded-full self-center mr-4">
+                                                    <button onclick={toggle_podcast} title={i18n.t("podcast.toggle_subscription")} class="item-container-button font-bold rounded-full self-center mr-4">
                                                         { button_content }
                                                     </button>
                                                     {
                                                         if search_state.podcast_added.unwrap() {
                                                             html! {
-                                                                <button onclick={toggle_settings} title="Click to setup podcast specific settings" class="item-container-button font-bold rounded-full self-center mr-4">
+                                                                <button onclick={toggle_settings} title={i18n.t("podcast.settings_title")} class="item-container-button font-bold rounded-full self-center mr-4">
                                                                     { setting_content }
                                                                 </button>
                                                             }
@@ -1920,7 +1980,7 @@ pub fn episode_layout() -> Html {
                                                     }
                                                 </div>
                                                 <div class="item-header-mobile-cover-container">
-                                                    <img src={podcast_info.podcast_artwork.clone()} alt={format!("Cover for {}", &podcast_info.podcast_title)} class="item-header-mobile-cover"/>
+                                                    <img src={podcast_info.podcast_artwork.clone()} alt={i18n.tf("podcast.cover_alt", &[("podcast", &podcast_info.podcast_title)])} class="item-header-mobile-cover"/>
                                                 </div>
 
                                                 <h2 class="item-header-title">{ &podcast_info.podcast_title }</h2>
@@ -1928,15 +1988,15 @@ pub fn episode_layout() -> Html {
                                                     { sanitized_description }
                                                     <button class="toggle-desc-btn" onclick={toggle_description}>{ "" }</button>
                                                 </div>
-                                                <p class="header-info">{ format!("Episode Count: {}", &podcast_info.podcast_episode_count) }</p>
-                                                <p class="header-info">{ format!("Authors: {}", &podcast_info.podcast_author) }</p>
-                                                <p class="header-info">{ format!("Explicit: {}", if podcast_info.podcast_explicit { "Yes" } else { "No" }) }</p>
+                                                <p class="header-info">{ i18n.tf("podcast.episode_count", &[("count", &i18n.number(podcast_info.podcast_episode_count as f64))]) }</p>
+                                                <p class="header-info">{ i18n.tf("podcast.authors", &[("authors", &podcast_info.podcast_author)]) }</p>
+                                                <p class="header-info">{ i18n.tf("podcast.explicit", &[("explicit", &i18n.t(if podcast_info.podcast_explicit { "common.yes" } else { "common.no" }))]) }</p>
                                                 {
                                                     if let Some(people) = &state.podcast_people {
                                                         if !people.is_empty() {
                                                             html! {
                                                                 <div class="header-info">
-                                                                    <HostDropdown title="Hosts" hosts={people.clone()} />
+                                                                    <HostDropdown title={i18n.t("podcast.hosts")} hosts={people.clone()} />
                                                                 </div>
                                                             }
                                                         } else {
@@ -1967,14 +2027,14 @@ pub fn episode_layout() -> Html {
                                     } else {
                                         html! {
                                             <div class="item-header">
-                                                <img src={podcast_info.podcast_artwork.clone()} alt={format!("Cover for {}", &podcast_info.podcast_title)} class="item-header-cover"/>
+                                                <img src={podcast_info.podcast_artwork.clone()} alt={i18n.tf("podcast.cover_alt", &[("podcast", &podcast_info.podcast_title)])} class="item-header-cover"/>
                                                 <div class="item-header-info">
                                                     <div class="title-button-container">
                                                         <h2 class="item-header-title">{ &podcast_info.podcast_title }</h2>
                                                         {
                                                             if search_state.podcast_added.unwrap() {
                                                                 html! {
-                                                                    <button onclick={toggle_download} title="Click to download all episodes for this podcast" class="item-container-button font-bold rounded-full self-center mr-4">
+                                                                    <button onclick={toggle_download} title={i18n.t("podcast.download_all_title")} class="item-container-button font-bold rounded-full self-center mr-4">
                                                                         { download_all }
                                                                     </button>
                                                                 }
@@ -1982,13 +2042,13 @@ pub fn episode_layout() -> Html {
                                                                 html! {}
                                                             }
                                                         }
-                                                        <button onclick={toggle_podcast} title="Click to add or remove podcast from feed" class={"item-container-button selector-button font-bold py-2 px-4 rounded-full self-center mr-8"} style="width: 60px; height: 60px;">
+                                                        <button onclick={toggle_podcast} title={i18n.t("podcast.toggle_subscription")} class={"item-container-button selector-button font-bold py-2 px-4 rounded-full self-center mr-8"} style="width: 60px; height: 60px;">
                                                             { button_content }
                                                         </button>
                                                         {
                                                             if search_state.podcast_added.unwrap() {
                                                                 html! {
-                                                                    <button onclick={toggle_settings} title="Click to setup podcast specific settings" class="item-container-button font-bold rounded-full self-center mr-4">
+                                                                    <button onclick={toggle_settings} title={i18n.t("podcast.settings_title")} class="item-container-button font-bold rounded-full self-center mr-4">
                                                                         { setting_content }
                                                                     </button>
                                                                 }
@@ -2005,7 +2065,7 @@ pub fn episode_layout() -> Html {
                                                     </div>
                                                     <button
                                                         onclick={Callback::from(move |_| web_link.clone().emit(podcast_info.podcast_link.to_string()))}
-                                                        title="Visit external podcast website" class={"item-container-button font-bold rounded-full self-center mr-4"} style="width: 30px; height: 30px;">
+                                                        title={i18n.t("podcast.visit_website")} class={"item-container-button font-bold rounded-full self-center mr-4"} style="width: 30px; height: 30px;">
                                                         { website_icon }
                                                     </button>
                                                     {
@@ -2040,14 +2100,14 @@ pub fn episode_layout() -> Html {
                                                     }
                                                     <div class="item-header-info">
 
-                                                        <p class="header-text">{ format!("Episode Count: {}", &podcast_info.podcast_episode_count) }</p>
-                                                        <p class="header-text">{ format!("Authors: {}", &podcast_info.podcast_author) }</p>
-                                                        <p class="header-text">{ format!("Explicit: {}", if podcast_info.podcast_explicit { "Yes" } else { "No" }) }</p>
+                                                        <p class="header-text">{ i18n.tf("podcast.episode_count", &[("count", &i18n.number(podcast_info.podcast_episode_count as f64))]) }</p>
+                                                        <p class="header-text">{ i18n.tf("podcast.authors", &[("authors", &podcast_info.podcast_author)]) }</p>
+                                                        <p class="header-text">{ i18n.tf("podcast.explicit", &[("explicit", &i18n.t(if podcast_info.podcast_explicit { "common.yes" } else { "common.no" }))]) }</p>
                                                         {
                                                             if let Some(people) = &state.podcast_people {
                                                                 if !people.is_empty() {
                                                                     html! {
-                                                                        <HostDropdown title="Hosts" hosts={people.clone()} />
+                                                                        <HostDropdown title={i18n.t("podcast.hosts")} hosts={people.clone()} />
                                                                     }
                                                                 } else {
                                                                     html! {}
@@ -2087,7 +2147,7 @@ pub fn episode_layout() -> Html {
                                     let podcast_title = clicked_podcast_info.clone().unwrap().podcast_title.clone();
                                     html! {
                                         <div>
-                                            { for results.episodes.iter().map(|episode| {
+                                            { filtered_episode_list("podcast", &episode_filter, &search_state, results.episodes.clone(), |episode| {
                                                 let history_clone = history.clone();
                                                 let dispatch = _dispatch.clone();
                                                 let search_dispatch = _search_dispatch.clone();
@@ -2187,7 +2247,7 @@ pub fn episode_layout() -> Html {
                                                     <div class="item-container flex items-center mb-4 shadow-md rounded-lg">
                                                         <img
                                                             src={episode.artwork.clone().unwrap_or_default()}
-                                                            alt={format!("Cover for {}", &episode.title.clone().unwrap_or_default())}
+                                                            alt={i18n.tf("podcast.cover_alt", &[("podcast", &episode.title.clone().unwrap_or_default())])}
                                                             class="episode-image"/>
                                                         <div class="flex flex-col p-4 space-y-2 flex-grow md:w-7/12">
                                                             <p class="item_container-text episode-title font-semibold" onclick={on_shownotes_click(history_clone.clone(), search_dispatch.clone(), Some(episode_id_shownotes), Some(podcast_link_clone), Some(shownotes_episode_url), Some(podcast_title), db_added)}>{ &episode.title.clone().unwrap_or_default() }</p>
@@ -2255,9 +2315,9 @@ pub fn episode_layout() -> Html {
                                 } else {
                                     html! {
                                         <div class="empty-episodes-container" id="episode-container">
-                                            <img src="static/assets/favicon.png" alt="Logo" class="logo"/>
-                                            <h1 class="page-subtitles">{ "No Episodes Found" }</h1>
-                                            <p class="page-paragraphs">{"This podcast strangely doesn't have any episodes. Try a more mainstream one maybe?"}</p>
+                                            <img src="static/assets/favicon.png" alt={i18n.t("common.logo_alt")} class="logo"/>
+                                            <h1 class="page-subtitles">{ i18n.t("podcast.no_episodes_title") }</h1>
+                                            <p class="page-paragraphs">{ i18n.t("podcast.no_episodes_body") }</p>
                                         </div>
                                     }
                                 }
@@ -2267,21 +2327,6 @@ pub fn episode_layout() -> Html {
                 }
             }
         <App_drawer />
-        // Conditional rendering for the error banner
-        {
-            if state.error_message.as_ref().map_or(false, |msg| !msg.is_empty()) {
-                html! { <div class="error-snackbar">{ &state.error_message }</div> }
-            } else {
-                html! {}
-            }
-        }
-        {
-        if state.info_message.as_ref().map_or(false, |msg| !msg.is_empty()) {
-                html! { <div class="info-snackbar">{ &state.info_message }</div> }
-            } else {
-                html! {}
-            }
-        }
         {
             if let Some(audio_props) = &state.currently_playing {
                 html! { <AudioPlayer src={audio_props.src.clone()} title={audio_props.title.clone()} artwork_url={audio_props.artwork_url.clone()} duration={audio_props.duration.clone()} episode_id={audio_props.episode_id.clone()} duration_sec={audio_props.duration_sec.clone()} start_pos_sec={audio_props.start_pos_sec.clone()} end_pos_sec={audio_props.end_pos_sec.clone()} offline={audio_props.offline.clone()} /> }
@@ -1,21 +1,30 @@
-use crate::components::context::{AppState, UIState};
+use crate::components::context::AppState;
+#[cfg(not(feature = "server_build"))]
+use crate::components::context::UIState;
 #[cfg(not(feature = "server_build"))]
 use crate::components::downloads_tauri::{
     download_file, remove_episode_from_local_db, update_local_database, update_podcast_database,
 };
 use crate::components::episodes_layout::SafeHtml;
-use crate::components::gen_funcs::format_time;
+use crate::components::gen_funcs::{format_time, highlight_segments};
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, notify_info, notify_success, Toast};
 use crate::requests::pod_req::{
     call_download_episode, call_mark_episode_completed, call_mark_episode_uncompleted,
     call_queue_episode, call_remove_downloaded_episode, call_remove_queued_episode,
-    call_remove_saved_episode, call_save_episode, DownloadEpisodeRequest, Episode, EpisodeDownload,
-    HistoryEpisode, MarkEpisodeCompletedRequest, QueuePodcastRequest, QueuedEpisode,
-    SavePodcastRequest, SavedEpisode,
+    call_remove_saved_episode, call_reorder_queue, call_save_episode, DownloadEpisodeRequest,
+    Episode, EpisodeDownload, HistoryEpisode, MarkEpisodeCompletedRequest, QueueInsert,
+    QueuePodcastRequest, QueuedEpisode, SavePodcastRequest, SavedEpisode,
 };
-#[cfg(not(feature = "server_build"))]
 use crate::requests::pod_req::{
-    call_get_episode_metadata, call_get_podcast_details, EpisodeRequest,
+    call_add_to_playlist, call_remove_from_playlist, call_reorder_playlist, PlaylistEpisode,
+    PlaylistEpisodeRequest, ReorderPlaylistRequest,
 };
+#[cfg(feature = "server_build")]
+use crate::components::offline::OfflineEpisode;
+use crate::requests::pod_req::{call_get_episode_metadata, EpisodeRequest};
+#[cfg(not(feature = "server_build"))]
+use crate::requests::pod_req::call_get_podcast_details;
 use crate::requests::search_pods::Episode as SearchNewEpisode;
 use crate::requests::search_pods::SearchEpisode;
 use crate::requests::search_pods::{call_get_podcast_info, test_connection, PeopleEpisode};
@@ -25,17 +34,12 @@ use wasm_bindgen::closure::Closure;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::JsValue;
-use web_sys::{console, window, HtmlInputElement, MouseEvent};
+use web_sys::{console, HtmlInputElement, MouseEvent};
 use yew::prelude::*;
 use yew::Callback;
 use yew_router::history::{BrowserHistory, History};
 use yewdux::prelude::*;
 
-#[derive(Properties, PartialEq, Clone)]
-pub struct ErrorMessageProps {
-    pub error_message: UseStateHandle<Option<String>>,
-}
-
 #[function_component(UseScrollToTop)]
 pub fn use_scroll_to_top() -> Html {
     let history = BrowserHistory::new();
@@ -54,56 +58,10 @@ pub fn use_scroll_to_top() -> Html {
     html! {}
 }
 
-#[function_component(ErrorMessage)]
-pub fn error_message(props: &ErrorMessageProps) -> Html {
-    // Your existing logic here...
-    let error_message = use_state(|| None::<String>);
-    let (_state, _dispatch) = use_store::<AppState>();
-
-    {
-        let error_message = error_message.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let error_message_clone = error_message.clone();
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                error_message_clone.set(None);
-            }) as Box<dyn Fn(_)>);
-
-            if error_message.is_some() {
-                document
-                    .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-            }
-
-            // Return cleanup function
-            move || {
-                if error_message.is_some() {
-                    document
-                        .remove_event_listener_with_callback(
-                            "click",
-                            closure.as_ref().unchecked_ref(),
-                        )
-                        .unwrap();
-                }
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
-
-    if let Some(error) = props.error_message.as_ref() {
-        html! {
-            <div class="error-snackbar">{ error }</div>
-        }
-    } else {
-        html! {}
-    }
-}
-
 #[allow(non_camel_case_types)]
 #[function_component(Search_nav)]
 pub fn search_bar() -> Html {
+    let i18n = use_i18n();
     let history = BrowserHistory::new();
     let dispatch = Dispatch::<AppState>::global();
     let state: Rc<AppState> = dispatch.get();
@@ -271,7 +229,7 @@ pub fn search_bar() -> Html {
                         type="search"
                         id="search-dropdown"
                         class="search-input block p-2.5 w-full z-20 text-sm rounded-r-lg border hidden md:inline-flex"
-                        placeholder="Search"
+                        placeholder={i18n.t("search.placeholder")}
                         required=true
                         oninput={on_input_change.clone()}
                     />
@@ -315,13 +273,13 @@ pub fn search_bar() -> Html {
                                 <input
                                     type="text"
                                     class="search-input shorter-input block p-2.5 w-full text-sm rounded-lg mb-2"
-                                    placeholder="Search"
+                                    placeholder={i18n.t("search.placeholder")}
                                     value={(*podcast_value).clone()}
                                     oninput={on_input_change.clone()}
                                 />
                                 // Search button
                                 <button class="search-btn border-0 no-margin mt-4 font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline" onclick={on_submit_click.clone()}>
-                                    {"Search"}
+                                    { i18n.t("search.button") }
                                 </button>
                             </div>
                         }
@@ -343,8 +301,10 @@ pub struct ContextButtonProps {
 
 #[function_component(ContextButton)]
 pub fn context_button(props: &ContextButtonProps) -> Html {
+    let i18n = use_i18n();
     let dropdown_open = use_state(|| false);
     let (post_state, post_dispatch) = use_store::<AppState>();
+    #[cfg(not(feature = "server_build"))]
     let (_audio_state, audio_dispatch) = use_store::<UIState>();
     let api_key = post_state
         .auth_details
@@ -415,107 +375,113 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
 
     let queue_api_key = api_key.clone();
     let queue_server_name = server_name.clone();
-    let queue_post = audio_dispatch.clone();
     // let server_name = server_name.clone();
-    let on_add_to_queue = {
+    let queue_episode_at = {
         let episode = props.episode.clone();
-        Callback::from(move |_| {
-            let server_name_copy = queue_server_name.clone();
-            let api_key_copy = queue_api_key.clone();
-            let queue_post = queue_post.clone();
-            let request = QueuePodcastRequest {
-                episode_id: episode.get_episode_id(Some(0)),
-                user_id: user_id.unwrap(), // replace with the actual user ID
-            };
-            let server_name = server_name_copy; // replace with the actual server name
-            let api_key = api_key_copy; // replace with the actual API key
-            let future = async move {
-                // let _ = call_queue_episode(&server_name.unwrap(), &api_key.flatten(), &request).await;
-                // queue_post.reduce_mut(|state| state.info_message = Option::from(format!("Episode added to Queue!")));
-                match call_queue_episode(&server_name.unwrap(), &api_key.flatten(), &request).await
-                {
-                    Ok(success_message) => {
-                        queue_post.reduce_mut(|state| {
-                            state.info_message = Option::from(format!("{}", success_message))
-                        });
-                    }
-                    Err(e) => {
-                        queue_post.reduce_mut(|state| {
-                            state.error_message = Option::from(format!("{}", e))
-                        });
-                        // Handle error, e.g., display the error message
+        move |position: QueueInsert| {
+            let episode = episode.clone();
+            let queue_server_name = queue_server_name.clone();
+            let queue_api_key = queue_api_key.clone();
+            Callback::from(move |_: MouseEvent| {
+                let server_name_copy = queue_server_name.clone();
+                let api_key_copy = queue_api_key.clone();
+                let request = QueuePodcastRequest {
+                    episode_id: episode.get_episode_id(Some(0)),
+                    user_id: user_id.unwrap(), // replace with the actual user ID
+                };
+                let server_name = server_name_copy; // replace with the actual server name
+                let api_key = api_key_copy; // replace with the actual API key
+                let future = async move {
+                    // let _ = call_queue_episode(&server_name.unwrap(), &api_key.flatten(), &request).await;
+                    // queue_post.reduce_mut(|state| state.info_message = Option::from(format!("Episode added to Queue!")));
+                    match call_queue_episode(
+                        &server_name.unwrap(),
+                        &api_key.flatten(),
+                        &request,
+                        position,
+                    )
+                    .await
+                    {
+                        Ok(success_message) => {
+                            notify_info(format!("{}", success_message));
+                        }
+                        Err(e) => {
+                            notify_error(format!("{}", e));
+                            // Handle error, e.g., display the error message
+                        }
                     }
-                }
-            };
-            wasm_bindgen_futures::spawn_local(future);
-            // dropdown_open.set(false);
-        })
+                };
+                wasm_bindgen_futures::spawn_local(future);
+                // dropdown_open.set(false);
+            })
+        }
     };
 
+    let on_add_to_queue = queue_episode_at(QueueInsert::Last);
+    let on_play_next = queue_episode_at(QueueInsert::Next);
+
     let remove_queue_api_key = api_key.clone();
     let remove_queue_server_name = server_name.clone();
-    let remove_queue_post = audio_dispatch.clone();
     let dispatch_clone = post_dispatch.clone();
-    // let server_name = server_name.clone();
     let on_remove_queued_episode = {
         let episode = props.episode.clone();
         let episode_id = props.episode.get_episode_id(Some(0));
         Callback::from(move |_| {
             let post_dispatch = dispatch_clone.clone();
-            let server_name_copy = remove_queue_server_name.clone();
-            let api_key_copy = remove_queue_api_key.clone();
-            let queue_post = remove_queue_post.clone();
+            let server_name = remove_queue_server_name.clone().unwrap();
+            let api_key = remove_queue_api_key.clone().flatten();
+            let user_id = user_id.unwrap();
             let request = QueuePodcastRequest {
-                episode_id: episode.get_episode_id(Some(0)),
-                user_id: user_id.unwrap(), // replace with the actual user ID
+                episode_id,
+                user_id,
             };
-            let server_name = server_name_copy; // replace with the actual server name
-            let api_key = api_key_copy; // replace with the actual API key
+            let title = episode.get_episode_title();
+            // The queue as it stood, so undo can put the episode back in its old spot
+            let queue_before = post_dispatch
+                .get()
+                .queued_episodes
+                .as_ref()
+                .map(|queued| queued.episodes.clone())
+                .unwrap_or_default();
             let future = async move {
-                // let _ = call_queue_episode(&server_name.unwrap(), &api_key.flatten(), &request).await;
-                // queue_post.reduce_mut(|state| state.info_message = Option::from(format!("Episode added to Queue!")));
-                match call_remove_queued_episode(
-                    &server_name.unwrap(),
-                    &api_key.flatten(),
-                    &request,
-                )
-                .await
-                {
-                    Ok(success_message) => {
-                        // queue_post.reduce_mut(|state| state.info_message = Option::from(format!("{}", success_message)));
+                match call_remove_queued_episode(&server_name, &api_key, &request).await {
+                    Ok(_) => {
                         post_dispatch.reduce_mut(|state| {
-                            // Here, you should remove the episode from the queued_episodes
                             if let Some(ref mut queued_episodes) = state.queued_episodes {
                                 queued_episodes
                                     .episodes
                                     .retain(|ep| ep.get_episode_id(Some(0)) != episode_id);
                             }
-                            // Optionally, you can update the info_message with success message
-                            state.info_message = Some(format!("{}", success_message).to_string());
                         });
+                        let i18n = I18n::current();
+                        Toast::success(i18n.tf("queue.removed", &[("title", &title)]))
+                            .with_action(i18n.t("common.undo"), move || {
+                                undo_queue_removal(
+                                    post_dispatch.clone(),
+                                    server_name.clone(),
+                                    api_key.clone(),
+                                    request.clone(),
+                                    queue_before.clone(),
+                                )
+                            })
+                            .show();
                     }
                     Err(e) => {
-                        queue_post.reduce_mut(|state| {
-                            state.error_message = Option::from(format!("{}", e))
-                        });
-                        // Handle error, e.g., display the error message
+                        notify_error(format!("{}", e));
                     }
                 }
             };
             wasm_bindgen_futures::spawn_local(future);
-            // dropdown_open.set(false);
         })
     };
 
     let saved_api_key = api_key.clone();
     let saved_server_name = server_name.clone();
-    let save_post = audio_dispatch.clone();
     let on_save_episode = {
         let episode = props.episode.clone();
         Callback::from(move |_| {
             let server_name_copy = saved_server_name.clone();
             let api_key_copy = saved_api_key.clone();
-            let post_state = save_post.clone();
             let request = SavePodcastRequest {
                 episode_id: episode.get_episode_id(Some(0)), // changed from episode_title
                 user_id: user_id.unwrap(),                   // replace with the actual user ID
@@ -527,14 +493,10 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
                 // post_state.reduce_mut(|state| state.info_message = Option::from(format!("Episode saved successfully")));
                 match call_save_episode(&server_name.unwrap(), &api_key.flatten(), &request).await {
                     Ok(success_message) => {
-                        post_state.reduce_mut(|state| {
-                            state.info_message = Option::from(format!("{}", success_message))
-                        });
+                        notify_info(format!("{}", success_message));
                     }
                     Err(e) => {
-                        post_state.reduce_mut(|state| {
-                            state.error_message = Option::from(format!("{}", e))
-                        });
+                        notify_error(format!("{}", e));
                         // Handle error, e.g., display the error message
                     }
                 }
@@ -546,7 +508,6 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
 
     let remove_saved_api_key = api_key.clone();
     let remove_saved_server_name = server_name.clone();
-    let remove_save_post = audio_dispatch.clone();
     let dispatch_clone = post_dispatch.clone();
     let on_remove_saved_episode = {
         let episode = props.episode.clone();
@@ -555,7 +516,6 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
             let post_dispatch = dispatch_clone.clone();
             let server_name_copy = remove_saved_server_name.clone();
             let api_key_copy = remove_saved_api_key.clone();
-            let post_state = remove_save_post.clone();
             let request = SavePodcastRequest {
                 episode_id: episode.get_episode_id(Some(0)),
                 user_id: user_id.unwrap(),
@@ -576,13 +536,11 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
                                     .retain(|ep| ep.get_episode_id(Some(0)) != episode_id);
                             }
                             // Optionally, you can update the info_message with success message
-                            state.info_message = Some(format!("{}", success_message).to_string());
+                            notify_info(format!("{}", success_message).to_string());
                         });
                     }
                     Err(e) => {
-                        post_state.reduce_mut(|state| {
-                            state.error_message = Option::from(format!("{}", e))
-                        });
+                        notify_error(format!("{}", e));
                         // Handle error, e.g., display the error message
                     }
                 }
@@ -594,11 +552,9 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
 
     let download_api_key = api_key.clone();
     let download_server_name = server_name.clone();
-    let download_post = audio_dispatch.clone();
     let on_download_episode = {
         let episode = props.episode.clone();
         Callback::from(move |_| {
-            let post_state = download_post.clone();
             let server_name_copy = download_server_name.clone();
             let api_key_copy = download_api_key.clone();
             let request = DownloadEpisodeRequest {
@@ -614,14 +570,10 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
                     .await
                 {
                     Ok(success_message) => {
-                        post_state.reduce_mut(|state| {
-                            state.info_message = Option::from(format!("{}", success_message))
-                        });
+                        notify_info(format!("{}", success_message));
                     }
                     Err(e) => {
-                        post_state.reduce_mut(|state| {
-                            state.error_message = Option::from(format!("{}", e))
-                        });
+                        notify_error(format!("{}", e));
                         // Handle error, e.g., display the error message
                     }
                 }
@@ -630,16 +582,55 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
             // dropdown_open.set(false);
         })
     };
+    // Keep the episode in this browser's cache so it can be played without a connection
+    #[cfg(feature = "server_build")]
+    let saved_in_browser = OfflineEpisode::is_saved(props.episode.get_episode_id(Some(0)));
+    #[cfg(feature = "server_build")]
+    let on_toggle_browser_save = {
+        let episode_id = props.episode.get_episode_id(Some(0));
+        let server_name = server_name.clone();
+        let api_key = api_key.clone().flatten();
+        let dropdown_open = dropdown_open.clone();
+        Callback::from(move |_| {
+            let server_name = server_name.clone().unwrap_or_default();
+            let api_key = api_key.clone();
+            dropdown_open.set(false);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = if saved_in_browser {
+                    OfflineEpisode::remove(episode_id)
+                        .await
+                        .map(|_| I18n::current().t("offline.removed"))
+                } else {
+                    notify_info(I18n::current().t("offline.saving"));
+                    let request = EpisodeRequest {
+                        episode_id,
+                        user_id: user_id.unwrap_or_default(),
+                    };
+                    match call_get_episode_metadata(&server_name, api_key, &request).await {
+                        Ok(info) => OfflineEpisode::save(&info)
+                            .await
+                            .map(|_| I18n::current().t("offline.saved")),
+                        Err(e) => Err(e),
+                    }
+                };
+                match result {
+                    Ok(message) => notify_info(message),
+                    Err(e) => notify_error(
+                        I18n::current().tf("offline.save_error", &[("error", &e.to_string())]),
+                    ),
+                }
+            });
+        })
+    };
+
     #[cfg(not(feature = "server_build"))]
     let on_local_episode_download = {
         let episode = props.episode.clone();
-        let download_local_post = audio_dispatch.clone();
         let server_name_copy = server_name.clone();
         let api_key_copy = api_key.clone();
         let user_id_copy = user_id.clone();
 
         Callback::from(move |_| {
-            let post_state = download_local_post.clone();
             let episode_id = episode.get_episode_id(Some(0));
             let request = EpisodeRequest {
                 episode_id,
@@ -657,34 +648,32 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
                         let podcast_id = episode_info.podcastid.clone();
                         let filename = format!("episode_{}.mp3", episode_id);
                         let artwork_filename = format!("artwork_{}.jpg", episode_id);
-                        post_state.reduce_mut(|state| {
-                            state.info_message = Some(format!("Episode download queued!"))
-                        });
+                        notify_info(I18n::current().t("local_download.queued"));
                         // Download audio
                         match download_file(audio_url, filename.clone()).await {
                             Ok(_) => {}
                             Err(e) => {
-                                post_state.reduce_mut(|state| {
-                                    state.error_message =
-                                        Some(format!("Failed to download episode audio: {:?}", e))
-                                });
+                                notify_error(I18n::current().tf(
+                                    "local_download.audio_error",
+                                    &[("error", &format!("{:?}", e))],
+                                ));
                             }
                         }
 
                         // Download artwork
                         if let Err(e) = download_file(artwork_url, artwork_filename.clone()).await {
-                            post_state.reduce_mut(|state| {
-                                state.error_message =
-                                    Some(format!("Failed to download episode artwork: {:?}", e))
-                            });
+                            notify_error(I18n::current().tf(
+                                "local_download.artwork_error",
+                                &[("error", &format!("{:?}", e))],
+                            ));
                         }
 
                         // Update local JSON database
                         if let Err(e) = update_local_database(episode_info).await {
-                            post_state.reduce_mut(|state| {
-                                state.error_message =
-                                    Some(format!("Failed to update local database: {:?}", e))
-                            });
+                            notify_error(I18n::current().tf(
+                                "local_download.database_error",
+                                &[("error", &format!("{:?}", e))],
+                            ));
                         }
 
                         // Fetch and update local podcast metadata
@@ -698,25 +687,25 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
                         {
                             Ok(podcast_details) => {
                                 if let Err(e) = update_podcast_database(podcast_details).await {
-                                    post_state.reduce_mut(|state| {
-                                        state.error_message = Some(format!(
-                                            "Failed to update podcast database: {:?}",
-                                            e
-                                        ))
-                                    });
+                                    notify_error(I18n::current().tf(
+                                        "local_download.podcast_database_error",
+                                        &[("error", &format!("{:?}", e))],
+                                    ));
                                 }
                             }
                             Err(e) => {
-                                post_state.reduce_mut(|state| {
-                                    state.error_message =
-                                        Some(format!("Failed to fetch podcast metadata: {:?}", e))
-                                });
+                                notify_error(I18n::current().tf(
+                                    "local_download.podcast_error",
+                                    &[("error", &format!("{:?}", e))],
+                                ));
                             }
                         }
                     }
                     Err(e) => {
-                        post_state
-                            .reduce_mut(|state| state.error_message = Some(format!("s {:?}", e)));
+                        notify_error(I18n::current().tf(
+                            "local_download.episode_error",
+                            &[("error", &format!("{:?}", e))],
+                        ));
                     }
                 }
             };
@@ -735,14 +724,10 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
             let episode_id = episode.get_episode_id(Some(0));
 
             let future = async move {
-                let filename = format!("episode_{}.mp3", episode_id);
-
-                // Download audio
                 match remove_episode_from_local_db(episode_id).await {
                     Ok(_) => {
                         post_state.reduce_mut(|state| {
-                            state.info_message =
-                                Some(format!("Episode {} downloaded locally!", filename));
+                            notify_info(I18n::current().t("local_download.removed"));
                             if let Some(increment) = state.local_download_increment.as_mut() {
                                 *increment += 1;
                             } else {
@@ -751,10 +736,10 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
                         });
                     }
                     Err(e) => {
-                        post_state.reduce_mut(|state| {
-                            state.error_message =
-                                Some(format!("Failed to download episode audio: {:?}", e))
-                        });
+                        notify_error(I18n::current().tf(
+                            "local_download.remove_error",
+                            &[("error", &format!("{:?}", e))],
+                        ));
                     }
                 }
             };
@@ -765,14 +750,12 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
 
     let remove_download_api_key = api_key.clone();
     let remove_download_server_name = server_name.clone();
-    let remove_download_post = audio_dispatch.clone();
     let dispatch_clone = post_dispatch.clone();
     let on_remove_downloaded_episode = {
         let episode = props.episode.clone();
         let episode_id = props.episode.get_episode_id(Some(0));
         Callback::from(move |_| {
             let post_dispatch = dispatch_clone.clone();
-            let post_state = remove_download_post.clone();
             let server_name_copy = remove_download_server_name.clone();
             let api_key_copy = remove_download_api_key.clone();
             let request = DownloadEpisodeRequest {
@@ -801,13 +784,11 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
                                     .retain(|ep| ep.get_episode_id(Some(0)) != episode_id);
                             }
                             // Optionally, you can update the info_message with success message
-                            state.info_message = Some(format!("{}", success_message).to_string());
+                            notify_info(format!("{}", success_message).to_string());
                         });
                     }
                     Err(e) => {
-                        post_state.reduce_mut(|state| {
-                            state.error_message = Option::from(format!("{}", e))
-                        });
+                        notify_error(format!("{}", e));
                         // Handle error, e.g., display the error message
                     }
                 }
@@ -819,14 +800,12 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
 
     let uncomplete_api_key = api_key.clone();
     let uncomplete_server_name = server_name.clone();
-    let uncomplete_download_post = audio_dispatch.clone();
     let uncomplete_dispatch_clone = post_dispatch.clone();
     let on_uncomplete_episode = {
         let episode = props.episode.clone();
         let episode_id = props.episode.get_episode_id(Some(0));
         Callback::from(move |_| {
             let post_dispatch = uncomplete_dispatch_clone.clone();
-            let post_state = uncomplete_download_post.clone();
             let server_name_copy = uncomplete_server_name.clone();
             let api_key_copy = uncomplete_api_key.clone();
             let request = MarkEpisodeCompletedRequest {
@@ -859,13 +838,11 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
                             } else {
                                 state.completed_episodes = Some(vec![episode_id]);
                             }
-                            state.info_message = Some(format!("{}", success_message));
+                            notify_info(format!("{}", success_message));
                         });
                     }
                     Err(e) => {
-                        post_state.reduce_mut(|state| {
-                            state.error_message = Option::from(format!("{}", e))
-                        });
+                        notify_error(format!("{}", e));
                         // Handle error, e.g., display the error message
                     }
                 }
@@ -877,14 +854,12 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
 
     let complete_api_key = api_key.clone();
     let complete_server_name = server_name.clone();
-    let complete_download_post = audio_dispatch.clone();
     let dispatch_clone = post_dispatch.clone();
     let on_complete_episode = {
         let episode = props.episode.clone();
         let episode_id = props.episode.get_episode_id(Some(0));
         Callback::from(move |_| {
             let post_dispatch = dispatch_clone.clone();
-            let post_state = complete_download_post.clone();
             let server_name_copy = complete_server_name.clone();
             let api_key_copy = complete_api_key.clone();
             let request = MarkEpisodeCompletedRequest {
@@ -917,13 +892,11 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
                             } else {
                                 state.completed_episodes = Some(vec![episode_id]);
                             }
-                            state.info_message = Some(format!("{}", success_message));
+                            notify_info(format!("{}", success_message));
                         });
                     }
                     Err(e) => {
-                        post_state.reduce_mut(|state| {
-                            state.error_message = Option::from(format!("{}", e))
-                        });
+                        notify_error(format!("{}", e));
                         // Handle error, e.g., display the error message
                     }
                 }
@@ -956,57 +929,223 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
 
     #[cfg(feature = "server_build")]
     let download_button = html! {
-        <li class="dropdown-option" onclick={on_download_episode.clone()}>{ "Download Episode" }</li>
+        <>
+            <li class="dropdown-option" onclick={on_download_episode.clone()}>{ i18n.t("episode.download") }</li>
+            <li class="dropdown-option" onclick={on_toggle_browser_save.clone()}>{ if saved_in_browser { i18n.t("episode.remove_offline") } else { i18n.t("episode.save_offline") } }</li>
+        </>
     };
 
     #[cfg(not(feature = "server_build"))]
     let download_button = html! {
         <>
-            <li class="dropdown-option" onclick={on_download_episode.clone()}>{ "Server Download" }</li>
-            <li class="dropdown-option" onclick={on_local_episode_download.clone()}>{ "Local Download" }</li>
+            <li class="dropdown-option" onclick={on_download_episode.clone()}>{ i18n.t("episode.server_download") }</li>
+            <li class="dropdown-option" onclick={on_local_episode_download.clone()}>{ i18n.t("episode.local_download") }</li>
         </>
     };
 
     #[cfg(not(feature = "server_build"))]
     let local_download_options = html! {
         <>
-            <li class="dropdown-option" onclick={on_add_to_queue.clone()}>{ "Queue Episode" }</li>
-            <li class="dropdown-option" onclick={on_save_episode.clone()}>{ "Save Episode" }</li>
-            <li class="dropdown-option" onclick={on_remove_locally_downloaded_episode.clone()}>{ "Remove Downloaded Episode" }</li>
-            <li class="dropdown-option" onclick={on_toggle_complete.clone()}>{ if is_completed { "Mark Episode Incomplete" } else { "Mark Episode Complete" } }</li>
+            <li class="dropdown-option" onclick={on_play_next.clone()}>{ i18n.t("episode.play_next") }</li>
+            <li class="dropdown-option" onclick={on_add_to_queue.clone()}>{ i18n.t("episode.queue") }</li>
+            <li class="dropdown-option" onclick={on_save_episode.clone()}>{ i18n.t("episode.save") }</li>
+            <li class="dropdown-option" onclick={on_remove_locally_downloaded_episode.clone()}>{ i18n.t("episode.remove_downloaded") }</li>
+            <li class="dropdown-option" onclick={on_toggle_complete.clone()}>{ if is_completed { i18n.t("episode.mark_incomplete") } else { i18n.t("episode.mark_complete") } }</li>
         </>
     };
 
     #[cfg(feature = "server_build")]
     let local_download_options = html! {};
 
+    // Manual playlists the episode can be added to, and on a playlist's own page, its ordering
+    let add_to_playlist = {
+        let episode_id = props.episode.get_episode_id(Some(0));
+        let server_name = server_name.clone();
+        let api_key = api_key.clone().flatten();
+        let dispatch = post_dispatch.clone();
+        move |playlist_id: i32| {
+            let server_name = server_name.clone();
+            let api_key = api_key.clone();
+            let dispatch = dispatch.clone();
+            Callback::from(move |_: MouseEvent| {
+                let (Some(server_name), Some(user_id)) = (server_name.clone(), user_id) else {
+                    return;
+                };
+                let api_key = api_key.clone();
+                let dispatch = dispatch.clone();
+                let request = PlaylistEpisodeRequest {
+                    playlist_id,
+                    user_id,
+                    episode_id,
+                };
+                wasm_bindgen_futures::spawn_local(async move {
+                    match call_add_to_playlist(&server_name, &api_key, &request).await {
+                        Ok(success_message) => {
+                            dispatch.reduce_mut(|state| {
+                                if let Some(playlist) = state
+                                    .playlists
+                                    .iter_mut()
+                                    .flatten()
+                                    .find(|playlist| playlist.playlist_id == playlist_id)
+                                {
+                                    playlist.episode_count += 1;
+                                }
+                            });
+                            notify_success(success_message);
+                        }
+                        Err(e) => notify_error(format!("{}", e)),
+                    }
+                });
+            })
+        }
+    };
+    let playlist_options = post_state
+        .playlists
+        .iter()
+        .flatten()
+        .filter(|playlist| !playlist.is_smart)
+        .map(|playlist| {
+            html! {
+                <li class="dropdown-option" onclick={add_to_playlist(playlist.playlist_id)}>
+                    { i18n.tf("playlist.add_to", &[("name", &playlist.name)]) }
+                </li>
+            }
+        })
+        .collect::<Html>();
+
+    let open_playlist = post_state
+        .playlist_episodes
+        .as_ref()
+        .filter(|open| !open.playlist.is_smart);
+    let edit_open_playlist = {
+        let episode_id = props.episode.get_episode_id(Some(0));
+        let server_name = server_name.clone();
+        let api_key = api_key.clone().flatten();
+        let dispatch = post_dispatch.clone();
+        let open_playlist = open_playlist.cloned();
+        move |offset: Option<isize>| {
+            let server_name = server_name.clone();
+            let api_key = api_key.clone();
+            let dispatch = dispatch.clone();
+            let open_playlist = open_playlist.clone();
+            Callback::from(move |_: MouseEvent| {
+                let (Some(server_name), Some(user_id), Some(open_playlist)) =
+                    (server_name.clone(), user_id, open_playlist.clone())
+                else {
+                    return;
+                };
+                let api_key = api_key.clone();
+                let dispatch = dispatch.clone();
+                let playlist_id = open_playlist.playlist.playlist_id;
+                let mut episodes = open_playlist.episodes;
+                let Some(index) = episodes.iter().position(|ep| ep.episodeid == episode_id) else {
+                    return;
+                };
+                wasm_bindgen_futures::spawn_local(async move {
+                    let result = match offset {
+                        // Moving up or down a place
+                        Some(offset) => {
+                            let target = index as isize + offset;
+                            if target < 0 || target as usize >= episodes.len() {
+                                return;
+                            }
+                            episodes.swap(index, target as usize);
+                            let request = ReorderPlaylistRequest {
+                                playlist_id,
+                                user_id,
+                                episode_ids: episodes.iter().map(|ep| ep.episodeid).collect(),
+                            };
+                            call_reorder_playlist(&server_name, &api_key, &request).await
+                        }
+                        None => {
+                            episodes.remove(index);
+                            let request = PlaylistEpisodeRequest {
+                                playlist_id,
+                                user_id,
+                                episode_id,
+                            };
+                            call_remove_from_playlist(&server_name, &api_key, &request).await
+                        }
+                    };
+                    match result {
+                        Ok(()) => dispatch.reduce_mut(move |state| {
+                            if let Some(playlist) = state
+                                .playlists
+                                .iter_mut()
+                                .flatten()
+                                .find(|playlist| playlist.playlist_id == playlist_id)
+                            {
+                                playlist.episode_count = episodes.len();
+                            }
+                            if let Some(ref mut open) = state.playlist_episodes {
+                                if open.playlist.playlist_id == playlist_id {
+                                    open.episodes = episodes;
+                                }
+                            }
+                        }),
+                        Err(e) => notify_error(format!("{}", e)),
+                    }
+                });
+            })
+        }
+    };
+    let playlist_order_options = if open_playlist.is_some() {
+        html! {
+            <>
+                <li class="dropdown-option" onclick={edit_open_playlist(Some(-1))}>{ i18n.t("playlist.move_up") }</li>
+                <li class="dropdown-option" onclick={edit_open_playlist(Some(1))}>{ i18n.t("playlist.move_down") }</li>
+                <li class="dropdown-option" onclick={edit_open_playlist(None)}>{ i18n.t("playlist.remove_from") }</li>
+            </>
+        }
+    } else {
+        html! {}
+    };
+
     let action_buttons = match props.page_type.as_str() {
         "saved" => html! {
             <>
-                <li class="dropdown-option" onclick={on_add_to_queue.clone()}>{ "Queue Episode" }</li>
-                <li class="dropdown-option" onclick={on_remove_saved_episode.clone()}>{ "Remove Saved Episode" }</li>
+                <li class="dropdown-option" onclick={on_play_next.clone()}>{ i18n.t("episode.play_next") }</li>
+                <li class="dropdown-option" onclick={on_add_to_queue.clone()}>{ i18n.t("episode.queue") }</li>
+                <li class="dropdown-option" onclick={on_remove_saved_episode.clone()}>{ i18n.t("episode.remove_saved") }</li>
+                {
+                    download_button.clone()
+                }
+                <li class="dropdown-option" onclick={on_toggle_complete.clone()}>{ if is_completed { i18n.t("episode.mark_incomplete") } else { i18n.t("episode.mark_complete") } }</li>
+                { playlist_options.clone() }
+            </>
+        },
+        "playlist" => html! {
+            <>
+                { playlist_order_options }
+                <li class="dropdown-option" onclick={on_play_next.clone()}>{ i18n.t("episode.play_next") }</li>
+                <li class="dropdown-option" onclick={on_add_to_queue.clone()}>{ i18n.t("episode.queue") }</li>
+                <li class="dropdown-option" onclick={on_save_episode.clone()}>{ i18n.t("episode.save") }</li>
                 {
                     download_button.clone()
                 }
-                <li class="dropdown-option" onclick={on_toggle_complete.clone()}>{ if is_completed { "Mark Episode Incomplete" } else { "Mark Episode Complete" } }</li>
+                <li class="dropdown-option" onclick={on_toggle_complete.clone()}>{ if is_completed { i18n.t("episode.mark_incomplete") } else { i18n.t("episode.mark_complete") } }</li>
+                { playlist_options.clone() }
             </>
         },
         "queue" => html! {
             <>
-                <li class="dropdown-option" onclick={on_save_episode.clone()}>{ "Save Episode" }</li>
-                <li class="dropdown-option" onclick={on_remove_queued_episode.clone()}>{ "Remove from Queue" }</li>
+                <li class="dropdown-option" onclick={on_save_episode.clone()}>{ i18n.t("episode.save") }</li>
+                <li class="dropdown-option" onclick={on_remove_queued_episode.clone()}>{ i18n.t("episode.remove_from_queue") }</li>
                 {
                     download_button.clone()
                 }
-                <li class="dropdown-option" onclick={on_toggle_complete.clone()}>{ if is_completed { "Mark Episode Incomplete" } else { "Mark Episode Complete" } }</li>
+                <li class="dropdown-option" onclick={on_toggle_complete.clone()}>{ if is_completed { i18n.t("episode.mark_incomplete") } else { i18n.t("episode.mark_complete") } }</li>
+                { playlist_options.clone() }
             </>
         },
         "downloads" => html! {
             <>
-                <li class="dropdown-option" onclick={on_add_to_queue.clone()}>{ "Queue Episode" }</li>
-                <li class="dropdown-option" onclick={on_save_episode.clone()}>{ "Save Episode" }</li>
-                <li class="dropdown-option" onclick={on_remove_downloaded_episode.clone()}>{ "Remove Downloaded Episode" }</li>
-                <li class="dropdown-option" onclick={on_toggle_complete.clone()}>{ if is_completed { "Mark Episode Incomplete" } else { "Mark Episode Complete" } }</li>
+                <li class="dropdown-option" onclick={on_play_next.clone()}>{ i18n.t("episode.play_next") }</li>
+                <li class="dropdown-option" onclick={on_add_to_queue.clone()}>{ i18n.t("episode.queue") }</li>
+                <li class="dropdown-option" onclick={on_save_episode.clone()}>{ i18n.t("episode.save") }</li>
+                <li class="dropdown-option" onclick={on_remove_downloaded_episode.clone()}>{ i18n.t("episode.remove_downloaded") }</li>
+                <li class="dropdown-option" onclick={on_toggle_complete.clone()}>{ if is_completed { i18n.t("episode.mark_incomplete") } else { i18n.t("episode.mark_complete") } }</li>
+                { playlist_options.clone() }
             </>
         },
         "local_downloads" => html! {
@@ -1016,12 +1155,14 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
         _ => html! {
             // Default set of buttons for other page types
             <>
-                <li class="dropdown-option" onclick={on_add_to_queue.clone()}>{ "Queue Episode" }</li>
-                <li class="dropdown-option" onclick={on_save_episode.clone()}>{ "Save Episode" }</li>
+                <li class="dropdown-option" onclick={on_play_next.clone()}>{ i18n.t("episode.play_next") }</li>
+                <li class="dropdown-option" onclick={on_add_to_queue.clone()}>{ i18n.t("episode.queue") }</li>
+                <li class="dropdown-option" onclick={on_save_episode.clone()}>{ i18n.t("episode.save") }</li>
                 {
                     download_button.clone()
                 }
-                <li class="dropdown-option" onclick={on_toggle_complete.clone()}>{ if is_completed { "Mark Episode Incomplete" } else { "Mark Episode Complete" } }</li>
+                <li class="dropdown-option" onclick={on_toggle_complete.clone()}>{ if is_completed { i18n.t("episode.mark_incomplete") } else { i18n.t("episode.mark_complete") } }</li>
+                { playlist_options.clone() }
             </>
         },
     };
@@ -1058,16 +1199,63 @@ pub fn context_button(props: &ContextButtonProps) -> Html {
 pub fn empty_message(header: &str, paragraph: &str) -> Html {
     html! {
         <div class="empty-episodes-container">
-            <img src="static/assets/favicon.png" alt="Logo" class="logo"/>
+            <img src="static/assets/favicon.png" alt={I18n::current().t("common.logo_alt")} class="logo"/>
             <h1 class="page-paragraphs">{ header }</h1>
             <p class="page-paragraphs">{ paragraph }</p>
         </div>
     }
 }
 
+/// Queues an episode again after it was removed, back where it sat in `queue_before`. If the
+/// queue wasn't loaded when it was removed, the episode goes to the end.
+fn undo_queue_removal(
+    dispatch: Dispatch<AppState>,
+    server_name: String,
+    api_key: Option<String>,
+    request: QueuePodcastRequest,
+    queue_before: Vec<QueuedEpisode>,
+) {
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Err(e) =
+            call_queue_episode(&server_name, &api_key, &request, QueueInsert::Last).await
+        {
+            notify_error(format!("{}", e));
+            return;
+        }
+        let Some(index) = queue_before
+            .iter()
+            .position(|ep| ep.episodeid == request.episode_id)
+        else {
+            return;
+        };
+        let order: Vec<i32> = queue_before.iter().map(|ep| ep.episodeid).collect();
+        if let Err(e) = call_reorder_queue(&server_name, &api_key, &request.user_id, &order).await {
+            notify_error(format!("{}", e));
+            return;
+        }
+        let restored = queue_before[index].clone();
+        dispatch.reduce_mut(move |state| {
+            if let Some(ref mut queued_episodes) = state.queued_episodes {
+                if !queued_episodes
+                    .episodes
+                    .iter()
+                    .any(|ep| ep.episodeid == restored.episodeid)
+                {
+                    let index = index.min(queued_episodes.episodes.len());
+                    queued_episodes.episodes.insert(index, restored);
+                }
+            }
+        });
+    });
+}
+
 pub trait EpisodeTrait {
     fn get_episode_artwork(&self) -> String;
     fn get_episode_title(&self) -> String;
+    // The title as it should appear in an episode list, search results override this to highlight matches
+    fn get_episode_title_html(&self) -> Html {
+        html! { self.get_episode_title() }
+    }
     fn get_episode_id(&self, fallback_id: Option<i32>) -> i32;
     fn clone_box(&self) -> Box<dyn EpisodeTrait>;
     // fn eq(&self, other: &dyn EpisodeTrait) -> bool;
@@ -1165,6 +1353,28 @@ impl EpisodeTrait for SavedEpisode {
     }
 }
 
+impl EpisodeTrait for PlaylistEpisode {
+    fn get_episode_artwork(&self) -> String {
+        self.episodeartwork.clone()
+    }
+
+    fn get_episode_title(&self) -> String {
+        self.episodetitle.clone()
+    }
+
+    fn clone_box(&self) -> Box<dyn EpisodeTrait> {
+        Box::new(self.clone())
+    }
+
+    fn get_episode_id(&self, _fallback_id: Option<i32>) -> i32 {
+        self.episodeid.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 impl EpisodeTrait for HistoryEpisode {
     fn get_episode_artwork(&self) -> String {
         self.episodeartwork.clone()
@@ -1218,6 +1428,19 @@ impl EpisodeTrait for SearchEpisode {
         self.episodetitle.clone()
     }
 
+    fn get_episode_title_html(&self) -> Html {
+        highlight_segments(&self.episodetitle, &self.highlight_terms)
+            .into_iter()
+            .map(|(run, hit)| {
+                if hit {
+                    html! { <mark class="search-highlight">{ run }</mark> }
+                } else {
+                    html! { run }
+                }
+            })
+            .collect::<Html>()
+    }
+
     fn get_episode_id(&self, _fallback_id: Option<i32>) -> i32 {
         self.episodeid.clone()
     }
@@ -1370,14 +1593,14 @@ pub fn episode_item(
                 <div class="flex flex-col w-auto object-cover pl-4">
                     <img
                         src={episode.get_episode_artwork()}
-                        alt={format!("Cover for {}", episode.get_episode_title())}
+                        alt={I18n::current().tf("podcast.cover_alt", &[("podcast", &episode.get_episode_title())])}
                         class="episode-image"
                     />
                 </div>
                 <div class="flex flex-col p-4 space-y-2 flex-grow md:w-7/12">
                     <div class="flex items-center space-x-2 cursor-pointer" onclick={on_shownotes_click}>
                         <p class="item_container-text episode-title font-semibold">
-                            { episode.get_episode_title() }
+                            { episode.get_episode_title_html() }
                         </p>
                         {
                             if completed.clone() {
@@ -1515,14 +1738,14 @@ pub fn download_episode_item(
                 <div class="flex flex-col w-auto object-cover pl-4">
                     <img
                         src={episode.get_episode_artwork()}
-                        alt={format!("Cover for {}", episode.get_episode_title())}
+                        alt={I18n::current().tf("podcast.cover_alt", &[("podcast", &episode.get_episode_title())])}
                         class="episode-image"
                     />
                 </div>
                 <div class="flex flex-col p-4 space-y-2 flex-grow md:w-7/12">
                     <div class="flex items-center space-x-2 cursor-pointer" onclick={on_shownotes_click}>
                         <p class="item_container-text episode-title font-semibold">
-                            { episode.get_episode_title() }
+                            { episode.get_episode_title_html() }
                         </p>
                         {
                             if completed.clone() {
@@ -1681,14 +1904,14 @@ pub fn queue_episode_item(
                 <div class="flex flex-col w-auto object-cover pl-4">
                     <img
                         src={episode.get_episode_artwork()}
-                        alt={format!("Cover for {}", episode.get_episode_title())}
+                        alt={I18n::current().tf("podcast.cover_alt", &[("podcast", &episode.get_episode_title())])}
                         class="episode-image"
                     />
                 </div>
                 <div class="flex flex-col p-4 space-y-2 flex-grow md:w-7/12">
                     <div class="flex items-center space-x-2 cursor-pointer" onclick={on_shownotes_click}>
                         <p class="item_container-text episode-title font-semibold">
-                            { episode.get_episode_title() }
+                            { episode.get_episode_title_html() }
                         </p>
                         {
                             if completed.clone() {
@@ -1778,6 +2001,7 @@ pub struct LoadingModalProps {
 
 #[function_component(LoadingModal)]
 pub fn loading_modal(props: &LoadingModalProps) -> Html {
+    let i18n = use_i18n();
     if !props.is_visible {
         return html! {};
     }
@@ -1786,8 +2010,8 @@ pub fn loading_modal(props: &LoadingModalProps) -> Html {
         <div class="modal-overlay flex items-center justify-center">
             <div class="modal-content text-center">
                 <div class="spinner mx-auto mb-4"></div>
-                <p class="modal-title">{ format!("Searching everywhere for {}...", props.name) }</p>
-                <p class="modal-subtitle mt-2">{"This may take a moment"}</p>
+                <p class="modal-title">{ i18n.tf("loading_modal.searching", &[("name", &props.name)]) }</p>
+                <p class="modal-subtitle mt-2">{ i18n.t("loading_modal.subtitle") }</p>
             </div>
         </div>
     }
@@ -1,9 +1,7 @@
+use crate::components::i18n::I18n;
+use crate::requests::login_requests::PasswordPolicy;
 use ammonia::Builder;
-use argon2::{
-    password_hash::{rand_core::OsRng, PasswordHasher, SaltString},
-    Argon2,
-};
-use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use chrono::{DateTime, NaiveDateTime, TimeZone, Timelike, Utc};
 use chrono_tz::Tz;
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -92,9 +90,20 @@ pub fn format_datetime(
     };
 
     match hour_preference {
-        Some(12) => datetime
-            .format(&format!("{} %l:%M %p", format_str))
-            .to_string(),
+        Some(12) => {
+            // chrono only knows the English markers, so the catalog supplies them
+            let i18n = I18n::current();
+            let marker = if datetime.hour() < 12 {
+                i18n.t("time.am")
+            } else {
+                i18n.t("time.pm")
+            };
+            format!(
+                "{} {}",
+                datetime.format(&format!("{} %l:%M", format_str)),
+                marker
+            )
+        }
         _ => datetime
             .format(&format!("{} %H:%M", format_str))
             .to_string(),
@@ -132,32 +141,104 @@ pub fn sanitize_html_with_blank_target(description: &str) -> String {
     builder.clean(description).to_string()
 }
 
-pub fn encode_password(password: &str) -> Result<String, argon2::password_hash::Error> {
-    let salt = SaltString::generate(&mut OsRng);
-    let argon2 = Argon2::default();
-    let password_hash = argon2
-        .hash_password(password.as_bytes(), &salt)?
-        .to_string();
-    Ok(password_hash)
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Splits `text` into runs, flagging the ones that match one of `terms` case-insensitively.
+/// With `skip_markup` set, tags and character references are never matched inside.
+fn highlight_runs(text: &str, terms: &[String], skip_markup: bool) -> Vec<(String, bool)> {
+    let terms: Vec<Vec<char>> = terms
+        .iter()
+        .map(|term| term.to_lowercase().chars().collect::<Vec<char>>())
+        .filter(|term| !term.is_empty() && !(skip_markup && term.contains(&'<')))
+        .collect();
+    let chars: Vec<char> = text.chars().collect();
+    let lowered: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    let mut runs: Vec<(String, bool)> = Vec::new();
+    let mut push = |run: &[char], hit: bool| match runs.last_mut() {
+        Some((last, last_hit)) if *last_hit == hit && !hit => last.extend(run),
+        _ => runs.push((run.iter().collect(), hit)),
+    };
+    let mut i = 0;
+    while i < chars.len() {
+        if skip_markup && (chars[i] == '<' || chars[i] == '&') {
+            let close = if chars[i] == '<' { '>' } else { ';' };
+            let end = chars[i..]
+                .iter()
+                .position(|c| *c == close)
+                .map_or(chars.len(), |offset| i + offset + 1);
+            push(&chars[i..end], false);
+            i = end;
+            continue;
+        }
+        let matched = terms
+            .iter()
+            .filter(|term| lowered[i..].starts_with(term))
+            .filter(|term| !skip_markup || !term.contains(&'&'))
+            .map(|term| term.len())
+            .max();
+        match matched {
+            Some(len) => {
+                push(&chars[i..i + len], true);
+                i += len;
+            }
+            None => {
+                push(&chars[i..i + 1], false);
+                i += 1;
+            }
+        }
+    }
+    runs
+}
+
+/// Plain text split into (run, is_match) pairs for rendering search highlights.
+pub fn highlight_segments(text: &str, terms: &[String]) -> Vec<(String, bool)> {
+    highlight_runs(text, terms, false)
+}
+
+/// Wraps search term matches in sanitized HTML with `<mark class="search-highlight">`,
+/// only touching text between tags.
+pub fn highlight_html(html: &str, terms: &[String]) -> String {
+    highlight_runs(html, terms, true)
+        .into_iter()
+        .map(|(run, hit)| {
+            if hit {
+                format!("<mark class=\"search-highlight\">{}</mark>", run)
+            } else {
+                run
+            }
+        })
+        .collect()
 }
 
 #[derive(Debug, PartialEq)]
 pub enum ValidationError {
     UsernameTooShort,
-    PasswordTooShort,
+    WeakPassword,
     InvalidEmail,
 }
 
-pub fn validate_user_input(username: &str, password: &str, email: &str) -> Vec<ValidationError> {
+pub fn validate_user_input(
+    username: &str,
+    password: &str,
+    email: &str,
+    policy: &PasswordPolicy,
+) -> Vec<ValidationError> {
     let mut errors = Vec::new();
 
     if username.len() < 4 {
         errors.push(ValidationError::UsernameTooShort);
     }
 
-    if password.len() < 6 {
-        errors.push(ValidationError::PasswordTooShort);
-    }
+    errors.extend(validate_password(password, policy));
 
     let email_regex = regex::Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").unwrap();
     if !email_regex.is_match(email) {
@@ -177,17 +258,79 @@ pub fn validate_username(username: &str) -> Vec<ValidationError> {
     errors
 }
 
-#[allow(dead_code)]
-pub fn validate_password(password: &str) -> Vec<ValidationError> {
+pub fn validate_password(password: &str, policy: &PasswordPolicy) -> Vec<ValidationError> {
     let mut errors = Vec::new();
 
-    if password.len() < 6 {
-        errors.push(ValidationError::PasswordTooShort);
+    if !password_policy_failures(password, policy).is_empty() {
+        errors.push(ValidationError::WeakPassword);
     }
 
     errors
 }
 
+/// What the password still needs under the server's policy, one sentence per rule in the
+/// interface language. The breached-password check is left to the server.
+pub fn password_policy_failures(password: &str, policy: &PasswordPolicy) -> Vec<String> {
+    let i18n = I18n::current();
+    let mut failures = Vec::new();
+
+    if password.chars().count() < policy.min_length {
+        failures.push(i18n.tn("password.rule_min_length", policy.min_length as i64, &[]));
+    }
+    if policy.require_upper && !password.chars().any(char::is_uppercase) {
+        failures.push(i18n.t("password.rule_upper"));
+    }
+    if policy.require_lower && !password.chars().any(char::is_lowercase) {
+        failures.push(i18n.t("password.rule_lower"));
+    }
+    if policy.require_digit && !password.chars().any(char::is_numeric) {
+        failures.push(i18n.t("password.rule_digit"));
+    }
+    if policy.require_symbol
+        && password
+            .chars()
+            .all(|c| c.is_alphanumeric() || c.is_whitespace())
+    {
+        failures.push(i18n.t("password.rule_symbol"));
+    }
+
+    failures
+}
+
+/// A rough 0-4 score for the strength meter, from length and how many kinds of character
+/// are mixed in.
+pub fn password_strength(password: &str) -> u8 {
+    if password.is_empty() {
+        return 0;
+    }
+    let length = password.chars().count();
+    let classes = [
+        password.chars().any(char::is_lowercase),
+        password.chars().any(char::is_uppercase),
+        password.chars().any(char::is_numeric),
+        password
+            .chars()
+            .any(|c| !c.is_alphanumeric() && !c.is_whitespace()),
+    ]
+    .iter()
+    .filter(|present| **present)
+    .count();
+
+    let mut score = match length {
+        0..=7 => 0,
+        8..=11 => 1,
+        12..=15 => 2,
+        _ => 3,
+    };
+    if classes >= 3 {
+        score += 1;
+    }
+    if classes <= 1 && score > 0 {
+        score -= 1;
+    }
+    score.min(4)
+}
+
 pub fn validate_email(email: &str) -> Vec<ValidationError> {
     let mut errors = Vec::new();
 
@@ -218,12 +361,46 @@ pub fn parse_opml(opml_content: &str) -> Vec<(String, String)> {
             let text = outline.get_attribute("text").unwrap_or_default();
             let final_title = if title.is_empty() { text } else { title };
             let xml_url = outline.get_attribute("xmlUrl").unwrap_or_default();
+            // Category folders and the bookmark entries nested in Pinepods backups have no feed
+            if xml_url.is_empty() {
+                continue;
+            }
             podcasts.push((final_title, xml_url));
         }
     }
     podcasts
 }
 
+/// MIME type of an episode enclosure, guessed from the file extension. Only a fallback for
+/// episodes stored before the feed's own enclosure type was kept.
+pub fn enclosure_mime_type(url: &str) -> Option<&'static str> {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let extension = path.rsplit_once('.')?.1.to_lowercase();
+    let mime_type = match extension.as_str() {
+        "mp3" => "audio/mpeg",
+        "m4a" | "aac" => "audio/mp4",
+        "ogg" | "oga" | "opus" => "audio/ogg",
+        "wav" => "audio/wav",
+        "flac" => "audio/flac",
+        "mp4" | "m4v" => "video/mp4",
+        "mov" => "video/quicktime",
+        "webm" => "video/webm",
+        "ogv" => "video/ogg",
+        _ => return None,
+    };
+    Some(mime_type)
+}
+
+pub fn is_video_enclosure(enclosure_type: Option<&str>, url: &str) -> bool {
+    match enclosure_type
+        .map(str::trim)
+        .filter(|mime_type| !mime_type.is_empty())
+    {
+        Some(mime_type) => mime_type.to_lowercase().starts_with("video/"),
+        None => enclosure_mime_type(url).map_or(false, |mime_type| mime_type.starts_with("video/")),
+    }
+}
+
 pub fn format_time(time_in_seconds: f64) -> String {
     let hours = (time_in_seconds / 3600.0).floor() as i32;
     let minutes = ((time_in_seconds % 3600.0) / 60.0).floor() as i32;
@@ -5,6 +5,8 @@ use super::gen_components::{
 use crate::components::audio::on_play_click;
 use crate::components::audio::AudioPlayer;
 use crate::components::context::{AppState, UIState};
+use crate::components::episode_filter::{filtered_episode_list, use_episode_filter};
+use crate::components::i18n::use_i18n;
 use crate::components::episodes_layout::AppStateMsg;
 use crate::components::gen_funcs::{
     format_datetime, match_date_format, parse_date, sanitize_html_with_blank_target,
@@ -20,6 +22,8 @@ use yewdux::prelude::*;
 
 #[function_component(PodHistory)]
 pub fn history() -> Html {
+    let i18n = use_i18n();
+    let episode_filter = use_episode_filter("history");
     let (state, dispatch) = use_store::<AppState>();
     let history = BrowserHistory::new();
 
@@ -151,7 +155,7 @@ pub fn history() -> Html {
                     {
                         html! {
                             <div>
-                            <h1 class="text-2xl item_container-text font-bold text-center mb-6">{"History"}</h1>
+                            <h1 class="text-2xl item_container-text font-bold text-center mb-6">{ i18n.t("history.title") }</h1>
                             </div>
                         }
                     }
@@ -163,12 +167,12 @@ pub fn history() -> Html {
                             if let Some(history_eps) = state.episode_history.clone() {
                                 if history_eps.data.is_empty() {
                                     empty_message(
-                                        "No Episode History Found",
-                                        "This one is pretty straightforward. You should get listening! Podcasts you listen to will show up here!."
+                                        &i18n.t("history.empty_title"),
+                                        &i18n.t("history.empty_body")
                                     )
                                 } else {
 
-                                    history_eps.data.into_iter().map(|episode| {
+                                    filtered_episode_list("history", &episode_filter, &state, history_eps.data, |episode| {
                                         let api_key = post_state.auth_details.as_ref().map(|ud| ud.api_key.clone());
                                         let user_id = post_state.user_details.as_ref().map(|ud| ud.UserID.clone());
                                         let server_name = post_state.auth_details.as_ref().map(|ud| ud.server_name.clone());
@@ -275,13 +279,13 @@ pub fn history() -> Html {
                                         );
 
                                         item
-                                    }).collect::<Html>()
+                                    })
                                 }
 
                             } else {
                                 empty_message(
-                                    "No Episode History Found",
-                                    "This one is pretty straightforward. You should get listening! Podcasts you listen to will show up here!."
+                                    &i18n.t("history.empty_title"),
+                                    &i18n.t("history.empty_body")
                                 )
                             }
                         }
@@ -5,9 +5,12 @@ use super::gen_components::{
 use crate::components::audio::on_play_click;
 use crate::components::audio::AudioPlayer;
 use crate::components::context::{AppState, ExpandedDescriptions, UIState};
+use crate::components::episode_filter::{filtered_episode_list, use_episode_filter};
+use crate::components::i18n::{use_i18n, I18n};
 use crate::components::gen_funcs::{
     format_datetime, parse_date, sanitize_html_with_blank_target, DateFormat,
 };
+use crate::requests::api_client::{api_error, ApiError};
 use crate::requests::pod_req;
 use crate::requests::pod_req::Episode as EpisodeData;
 use crate::requests::pod_req::RecentEps;
@@ -16,16 +19,14 @@ use yew::{function_component, html, Html};
 use yew_router::history::BrowserHistory;
 use yewdux::prelude::*;
 // use crate::components::gen_funcs::check_auth;
-use crate::components::episodes_layout::UIStateMsg;
 use crate::requests::login_requests::use_check_authentication;
-use wasm_bindgen::closure::Closure;
-use wasm_bindgen::JsCast;
-use web_sys::window;
 
 use wasm_bindgen::prelude::*;
 
 #[function_component(Home)]
 pub fn home() -> Html {
+    let i18n = use_i18n();
+    let episode_filter = use_episode_filter("home");
     let (state, dispatch) = use_store::<AppState>();
     let effect_dispatch = dispatch.clone();
 
@@ -66,35 +67,9 @@ pub fn home() -> Html {
 
     let error = use_state(|| None);
     let (post_state, _post_dispatch) = use_store::<AppState>();
-    let (audio_state, audio_dispatch) = use_store::<UIState>();
-    let error_message = audio_state.error_message.clone();
-    let info_message = audio_state.info_message.clone();
+    let (audio_state, _audio_dispatch) = use_store::<UIState>();
     let loading = use_state(|| true);
 
-    {
-        let ui_dispatch = audio_dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                ui_dispatch.apply(UIStateMsg::ClearErrorMessage);
-                ui_dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
 
     // Fetch episodes on component mount
     let loading_ep = loading.clone();
@@ -142,7 +117,12 @@ pub fn home() -> Html {
                                 loading_ep.set(false);
                             }
                             Err(e) => {
-                                error_clone.set(Some(e.to_string()));
+                                let message = if api_error(&e).map_or(false, ApiError::is_unauthorized) {
+                                    I18n::current().t("home.session_expired")
+                                } else {
+                                    e.to_string()
+                                };
+                                error_clone.set(Some(message));
                                 loading_ep.set(false); // Set loading to false here
                             }
                         }
@@ -178,28 +158,28 @@ pub fn home() -> Html {
                             if episodes.is_empty() {
                                 // Render "No Recent Episodes Found" if episodes list is empty
                                 empty_message(
-                                    "No Recent Episodes Found",
-                                    "You can add new podcasts by using the search bar above. Search for your favorite podcast and click the plus button to add it."
+                                    &i18n.t("home.empty_title"),
+                                    &i18n.t("home.empty_body")
                                 )
                             } else {
-                                episodes.into_iter().map(|episode| {
+                                filtered_episode_list("home", &episode_filter, &state, episodes, |episode| {
                                     html! {
                                         <Episode
                                             episode={episode.clone()}
                                         />
                                     }
-                                }).collect::<Html>()
+                                })
                             }
                         } else {
                             empty_message(
-                                "No Recent Episodes Found",
-                                "You can add new podcasts by using the search bar above. Search for your favorite podcast and click the plus button to add it."
+                                &i18n.t("home.empty_title"),
+                                &i18n.t("home.empty_body")
                             )
                         }
                     } else {
                         empty_message(
-                            "No Recent Episodes Found",
-                            "You can add new podcasts by using the search bar above. Search for your favorite podcast and click the plus button to add it."
+                            &i18n.t("home.empty_title"),
+                            &i18n.t("home.empty_body")
                         )
                     }
                 }
@@ -211,13 +191,6 @@ pub fn home() -> Html {
                 html! {}
             }
         }
-        // Conditional rendering for the error banner
-        if let Some(error) = error_message {
-            <div class="error-snackbar">{ error }</div>
-        }
-        if let Some(info) = info_message {
-            <div class="info-snackbar">{ info }</div>
-        }
         </div>
         <App_drawer />
         </>
@@ -0,0 +1,211 @@
+use serde_json::from_str;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::window;
+use yew::prelude::*;
+use yewdux::prelude::*;
+
+const LANGUAGE_KEY: &str = "language";
+
+/// Languages the interface has a catalog for. The catalogs live in `src/locales`, one flat
+/// JSON object of message id to text per language, and are compiled into the wasm. English is
+/// the source every other catalog translates and the fallback for anything one is missing.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Spanish];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+        }
+    }
+
+    /// The language's name for itself, as the picker lists it.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+        }
+    }
+
+    /// Accepts bare codes and regional tags alike, so `es-MX` is Spanish.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let primary = code.split(['-', '_']).next()?.to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code() == primary)
+    }
+
+    /// The language picked in settings, otherwise the browser's if we have it.
+    pub fn preferred() -> Self {
+        let window = window();
+        window
+            .as_ref()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(LANGUAGE_KEY).ok().flatten())
+            .and_then(|code| Self::from_code(&code))
+            .or_else(|| {
+                window
+                    .and_then(|window| window.navigator().language())
+                    .and_then(|code| Self::from_code(&code))
+            })
+            .unwrap_or_default()
+    }
+
+    /// The locale handed to the browser's `Intl` formatters.
+    fn locale_tag(self) -> &'static str {
+        match self {
+            Language::English => "en-US",
+            Language::Spanish => "es-ES",
+        }
+    }
+
+    /// CLDR plural category for `count`. English and Spanish only tell one from many; a
+    /// language with more forms adds its rule here and the matching `.few`/`.many` entries.
+    fn plural_category(self, count: i64) -> &'static str {
+        match self {
+            Language::English | Language::Spanish => {
+                if count == 1 {
+                    "one"
+                } else {
+                    "other"
+                }
+            }
+        }
+    }
+
+    fn catalog_source(self) -> &'static str {
+        match self {
+            Language::English => include_str!("../locales/en.json"),
+            Language::Spanish => include_str!("../locales/es.json"),
+        }
+    }
+}
+
+type Catalog = Rc<HashMap<String, String>>;
+
+thread_local! {
+    static CATALOGS: RefCell<HashMap<Language, Catalog>> = RefCell::new(HashMap::new());
+}
+
+fn catalog(language: Language) -> Catalog {
+    CATALOGS.with(|catalogs| {
+        catalogs
+            .borrow_mut()
+            .entry(language)
+            .or_insert_with(|| {
+                let parsed = from_str(language.catalog_source()).unwrap_or_else(|e| {
+                    web_sys::console::log_1(
+                        &format!("The {} catalog is unreadable: {}", language.code(), e).into(),
+                    );
+                    HashMap::new()
+                });
+                Rc::new(parsed)
+            })
+            .clone()
+    })
+}
+
+/// The interface language. `None` until one is picked this session, which means
+/// `Language::preferred`.
+#[derive(Default, Clone, PartialEq, Store, Debug)]
+pub struct I18nState {
+    pub language: Option<Language>,
+}
+
+/// Marks the page with the language so screen readers and hyphenation follow it.
+pub fn apply_document_language(language: Language) {
+    if let Some(root) = window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element())
+    {
+        let _ = root.set_attribute("lang", language.code());
+    }
+}
+
+/// Switch the interface language and remember the choice in this browser.
+pub fn set_language(language: Language) {
+    if let Some(storage) = window().and_then(|window| window.local_storage().ok().flatten()) {
+        let _ = storage.set_item(LANGUAGE_KEY, language.code());
+    }
+    apply_document_language(language);
+    Dispatch::<I18nState>::global().reduce_mut(|state| state.language = Some(language));
+}
+
+/// Looks up interface text in one language's catalog.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct I18n {
+    pub language: Language,
+}
+
+impl I18n {
+    /// For code outside components, like callbacks and formatting helpers. Components use
+    /// `use_i18n` so they redraw when the language changes.
+    pub fn current() -> Self {
+        Self {
+            language: Dispatch::<I18nState>::global()
+                .get()
+                .language
+                .unwrap_or_else(Language::preferred),
+        }
+    }
+
+    /// The text for `id`, falling back to English and then to the id itself, so a missing
+    /// entry is noticeable rather than blank.
+    pub fn t(&self, id: &str) -> String {
+        catalog(self.language)
+            .get(id)
+            .cloned()
+            .or_else(|| catalog(Language::English).get(id).cloned())
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    /// `t` with `{name}` placeholders filled in from `args`.
+    pub fn tf(&self, id: &str, args: &[(&str, &str)]) -> String {
+        args.iter().fold(self.t(id), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), value)
+        })
+    }
+
+    /// `tf` for text that depends on a count: looks up `id.one`, `id.other` and so on by the
+    /// language's plural rules, with the formatted number available as `{count}`.
+    pub fn tn(&self, id: &str, count: i64, args: &[(&str, &str)]) -> String {
+        let count_text = self.number(count as f64);
+        let mut all_args = vec![("count", count_text.as_str())];
+        all_args.extend_from_slice(args);
+        self.tf(
+            &format!("{}.{}", id, self.language.plural_category(count)),
+            &all_args,
+        )
+    }
+
+    /// A number written the way the language writes them, grouping and decimal mark included.
+    pub fn number(&self, value: f64) -> String {
+        let locales = js_sys::Array::of1(&JsValue::from_str(self.language.locale_tag()));
+        js_sys::Intl::NumberFormat::new(&locales, &js_sys::Object::new())
+            .format()
+            .call1(&JsValue::NULL, &JsValue::from_f64(value))
+            .ok()
+            .and_then(|formatted| formatted.as_string())
+            .unwrap_or_else(|| value.to_string())
+    }
+}
+
+/// The translator for the current language. Components that show text call this instead of
+/// `I18n::current` so switching languages redraws them.
+#[hook]
+pub fn use_i18n() -> I18n {
+    let state = use_store_value::<I18nState>();
+    I18n {
+        language: state.language.unwrap_or_else(Language::preferred),
+    }
+}
@@ -1,7 +1,14 @@
-use crate::components::context::{AppState, UIState};
-use crate::components::episodes_layout::UIStateMsg;
-use crate::components::gen_funcs::{encode_password, validate_user_input, ValidationError};
-use crate::requests::login_requests::{self, call_check_mfa_enabled};
+use crate::components::audio::restore_saved_player;
+use crate::components::context::{AppState, UIState, SERVER_PROFILES_KEY};
+use crate::components::notifications::{notify_error, notify_info, Toast};
+use crate::components::offline::clear_offline_data;
+use crate::components::oidc::OidcLoginButton;
+use crate::components::passkeys::{verify_passkey, PasskeyLoginButton};
+use crate::components::server_profiles::ServerProfiles;
+use crate::components::gen_funcs::{validate_password, validate_user_input, ValidationError};
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::password_strength::{use_password_policy, PasswordStrengthMeter};
+use crate::requests::login_requests::{self, call_check_mfa_enabled, take_return_route};
 use crate::requests::login_requests::{call_add_login_user, AddUserRequest};
 use crate::requests::login_requests::{
     call_first_login_done, call_get_time_info, call_reset_password_create_code,
@@ -12,7 +19,6 @@ use crate::requests::setting_reqs::call_get_theme;
 use chrono_tz::{Tz, TZ_VARIANTS};
 use md5;
 use rand::Rng;
-use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
 use web_sys::{console, window};
 use yew::prelude::*;
@@ -31,6 +37,7 @@ fn generate_gravatar_url(email: &Option<String>, size: usize) -> String {
 
 #[function_component(Login)]
 pub fn login() -> Html {
+    let i18n = use_i18n();
     let history = BrowserHistory::new();
     let username = use_state(|| "".to_string());
     let password = use_state(|| "".to_string());
@@ -42,10 +49,8 @@ pub fn login() -> Html {
     let new_password = use_state(|| "".to_string());
     let email = use_state(|| "".to_string());
     let fullname = use_state(|| "".to_string());
-    let (app_state, dispatch) = use_store::<AppState>();
+    let (_app_state, dispatch) = use_store::<AppState>();
     let (_state, _dispatch) = use_store::<UIState>();
-    let _error_message = app_state.error_message.clone();
-    let error_message = _state.error_message.clone();
     let time_zone = use_state(|| "".to_string());
     let date_format = use_state(|| "".to_string());
     let time_pref = use_state(|| 12);
@@ -53,7 +58,10 @@ pub fn login() -> Html {
     let temp_api_key = use_state(|| "".to_string());
     let temp_user_id = use_state(|| 0);
     let temp_server_name = use_state(|| "".to_string());
-    let info_message = _state.info_message.clone();
+    // Which second factors the account has, so the MFA prompt only offers those
+    let mfa_code_enabled = use_state(|| false);
+    let mfa_passkey_enabled = use_state(|| false);
+    let password_policy = use_password_policy(window().and_then(|w| w.location().origin().ok()));
     // Define the initial state
     let page_state = use_state(|| PageState::Default);
     let self_service_enabled = use_state(|| false); // State to store self-service status
@@ -87,35 +95,12 @@ pub fn login() -> Html {
         },
     );
 
-    {
-        let ui_dispatch = _dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                ui_dispatch.apply(UIStateMsg::ClearErrorMessage);
-                ui_dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
     let effect_displatch = dispatch.clone();
     // User Auto Login with saved state
     use_effect_with((), {
         // let error_clone_use = error_message_clone.clone();
         let history = history.clone();
+        let player_dispatch = _dispatch.clone();
         move |_| {
             if let Some(window) = web_sys::window() {
                 if let Ok(local_storage) = window.local_storage() {
@@ -202,6 +187,13 @@ pub fn login() -> Html {
                                                                             state.gravatar_url = Some(gravatar_url);
 
                                                                         });
+                                                                            // Bring back whatever was playing before the reload
+                                                                            restore_saved_player(
+                                                                                player_dispatch,
+                                                                                server_name.clone(),
+                                                                                Some(api_key.clone()),
+                                                                                wasm_user_id,
+                                                                            );
                                                                             // let mut error_message = app_state.error_message;
                                                                             // Retrieve the originally requested route, if any
                                                                             let session_storage = window.session_storage().unwrap().unwrap();
@@ -325,15 +317,17 @@ pub fn login() -> Html {
     let call_server_name = temp_server_name.clone();
     let call_api_key = temp_api_key.clone();
     let call_user_id = temp_user_id.clone();
-    let submit_post_state = _dispatch.clone();
+    let submit_code_enabled = mfa_code_enabled.clone();
+    let submit_passkey_enabled = mfa_passkey_enabled.clone();
     let on_submit = {
         let submit_dispatch = dispatch.clone();
         Callback::from(move |_| {
+            let mfa_code_enabled = submit_code_enabled.clone();
+            let mfa_passkey_enabled = submit_passkey_enabled.clone();
             let history = history_clone.clone();
             let username = username.clone();
             let password = password.clone();
             let dispatch = submit_dispatch.clone();
-            let post_state = submit_post_state.clone();
             let page_state = submit_state.clone();
             let temp_server_name = call_server_name.clone();
             let temp_api_key = call_api_key.clone();
@@ -391,7 +385,9 @@ pub fn login() -> Html {
                                     .await
                                     {
                                         Ok(response) => {
-                                            if response.mfa_enabled {
+                                            if response.mfa_enabled || response.passkey_enabled {
+                                                mfa_code_enabled.set(response.mfa_enabled);
+                                                mfa_passkey_enabled.set(response.passkey_enabled);
                                                 page_state.set(PageState::MFAPrompt);
                                             } else {
                                                 let theme_api = api_key.clone();
@@ -447,15 +443,11 @@ pub fn login() -> Html {
                                                         }
                                                     }
                                                 });
-                                                history.push("/home"); // Use the route path
+                                                history.push(&take_return_route());
                                             }
                                         }
                                         Err(_) => {
-                                            post_state.reduce_mut(|state| {
-                                                state.error_message = Option::from(
-                                                    "Error Checking MFA Status".to_string(),
-                                                )
-                                            });
+                                            notify_error(I18n::current().t("login.mfa_status_failed"));
                                         }
                                     }
                                 } else {
@@ -463,19 +455,12 @@ pub fn login() -> Html {
                                 }
                             }
                             Err(_) => {
-                                post_state.reduce_mut(|state| {
-                                    state.error_message = Option::from(
-                                        "Error checking first login status".to_string(),
-                                    )
-                                });
+                                notify_error(I18n::current().t("login.first_login_failed"));
                             }
                         }
                     }
                     Err(_) => {
-                        post_state.reduce_mut(|state| {
-                            state.error_message =
-                                Option::from("Your credentials appear to be incorrect".to_string())
-                        });
+                        notify_error(I18n::current().t("login.bad_credentials"));
                         // Handle error
                     }
                 }
@@ -603,7 +588,6 @@ pub fn login() -> Html {
     let password_error = use_state(|| password_error_notice::Hidden);
     let username_error = use_state(|| username_error_notice::Hidden);
 
-    let create_state = _dispatch.clone();
     let on_create_submit = {
         let page_state = page_state.clone();
         let fullname = fullname.clone().to_string();
@@ -613,8 +597,8 @@ pub fn login() -> Html {
         let username_error = username_error.clone();
         let password_error = password_error.clone();
         let email_error = email_error.clone();
+        let password_policy = password_policy.clone();
         Callback::from(move |e: MouseEvent| {
-            let create_state = create_state.clone();
             let window = window().expect("no global `window` exists");
             let location = window.location();
             let server_name = location.href().expect("should have a href");
@@ -627,8 +611,7 @@ pub fn login() -> Html {
 
             // let error_message_clone = error_message_create.clone();
             e.prevent_default();
-            // Hash the password and generate a salt
-            let errors = validate_user_input(&new_username, &new_password, &email);
+            let errors = validate_user_input(&new_username, &new_password, &email, &password_policy);
 
             if errors.contains(&ValidationError::UsernameTooShort) {
                 username_error.set(username_error_notice::Shown);
@@ -636,7 +619,7 @@ pub fn login() -> Html {
                 username_error.set(username_error_notice::Hidden);
             }
 
-            if errors.contains(&ValidationError::PasswordTooShort) {
+            if errors.contains(&ValidationError::WeakPassword) {
                 password_error.set(password_error_notice::Shown);
             } else {
                 password_error.set(password_error_notice::Hidden);
@@ -648,49 +631,33 @@ pub fn login() -> Html {
                 email_error.set(email_error_notice::Hidden);
             }
             if errors.is_empty() {
-                match encode_password(&new_password) {
-                    Ok(hash_pw) => {
-                        let user_settings = AddUserRequest {
-                            fullname: fullname.clone(),
-                            username: new_username.clone(),
-                            email: email.clone(),
-                            hash_pw: hash_pw.clone(),
-                        };
-                        let add_user_request = Some(user_settings);
-
-                        // let add_user_request = add_user_request.clone();
-                        wasm_bindgen_futures::spawn_local(async move {
-                            match call_add_login_user(server_name, &add_user_request).await {
-                                Ok(success) => {
-                                    if success {
-                                        page_state.set(PageState::Default);
-                                        create_state.reduce_mut(|state| {
-                                            state.info_message =
-                                                Option::from(format!("You can now login!"))
-                                        });
-                                    } else {
-                                        console::log_1(&"Error adding user".into());
-                                        page_state.set(PageState::Default);
-                                        create_state.reduce_mut(|state| {
-                                            state.error_message =
-                                                Option::from(format!("Error adding user"))
-                                        });
-                                    }
-                                }
-                                Err(e) => {
-                                    page_state.set(PageState::Default);
-                                    create_state.reduce_mut(|state| {
-                                        state.error_message =
-                                            Option::from(format!("Error adding user: {:?}", e))
-                                    });
-                                }
+                let user_settings = AddUserRequest {
+                    fullname: fullname.clone(),
+                    username: new_username.clone(),
+                    email: email.clone(),
+                    password: (*new_password).clone(),
+                };
+                let add_user_request = Some(user_settings);
+
+                // let add_user_request = add_user_request.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    match call_add_login_user(server_name, &add_user_request).await {
+                        Ok(success) => {
+                            if success {
+                                page_state.set(PageState::Default);
+                                notify_info(I18n::current().t("login.user_created"));
+                            } else {
+                                console::log_1(&"Error adding user".into());
+                                page_state.set(PageState::Default);
+                                notify_error(I18n::current().t("login.add_user_failed"));
                             }
-                        });
-                    }
-                    Err(_e) => {
-                        // console::log_1(&"User added successfully".into());
+                        }
+                        Err(e) => {
+                            page_state.set(PageState::Default);
+                            notify_error(I18n::current().tf("login.add_user_error", &[("error", &format!("{:?}", e))]));
+                        }
                     }
-                }
+                });
             }
         })
     };
@@ -701,52 +668,53 @@ pub fn login() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Create New User"}
+                            { i18n.t("login.create_user") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <div>
-                                <label for="username" class="block mb-2 text-sm font-medium">{"Username"}</label>
+                                <label for="username" class="block mb-2 text-sm font-medium">{ i18n.t("common.username") }</label>
                                 <input oninput={on_username_change.clone()} type="text" id="username" name="username" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
                                 {
                                     match *username_error {
                                         username_error_notice::Hidden => html! {},
-                                        username_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{"Username must be at least 4 characters long"}</p>},
+                                        username_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{ i18n.t("login.username_too_short") }</p>},
                                     }
                                 }
                             </div>
                             <div>
-                                <label for="fullname" class="block mb-2 text-sm font-medium">{"Full Name"}</label>
+                                <label for="fullname" class="block mb-2 text-sm font-medium">{ i18n.t("login.full_name") }</label>
                                 <input oninput={on_fullname_change} type="text" id="fullname" name="fullname" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
                             </div>
                             <div>
-                                <label for="email" class="block mb-2 text-sm font-medium">{"Email"}</label>
+                                <label for="email" class="block mb-2 text-sm font-medium">{ i18n.t("common.email") }</label>
                                 <input oninput={on_email_change} type="email" id="email" name="email" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
                                 {
                                     match *email_error {
                                         email_error_notice::Hidden => html! {},
-                                        email_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{"Invalid email address"}</p>},
+                                        email_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{ i18n.t("login.invalid_email") }</p>},
                                     }
                                 }
                             </div>
                             <div>
-                                <label for="password" class="block mb-2 text-sm font-medium">{"Password"}</label>
+                                <label for="password" class="block mb-2 text-sm font-medium">{ i18n.t("common.password") }</label>
                                 <input oninput={on_password_change.clone()} type="password" id="password" name="password" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
+                                <PasswordStrengthMeter password={(*new_password).clone()} policy={(*password_policy).clone()} />
                                 {
                                     match *password_error {
                                         password_error_notice::Hidden => html! {},
-                                        password_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{"Password must be at least 6 characters long"}</p>},
+                                        password_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{ i18n.t("login.password_requirements") }</p>},
                                     }
                                 }
                             </div>
-                            <button type="submit" onclick={on_create_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{"Submit"}</button>
+                            <button type="submit" onclick={on_create_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{ i18n.t("common.submit") }</button>
                         </form>
                     </div>
                 </div>
@@ -785,14 +753,12 @@ pub fn login() -> Html {
         let page_state = page_state.clone();
         let forgot_username = forgot_username.clone().to_string();
         let forgot_email = forgot_email.clone().to_string();
-        let dispatch_wasm = dispatch.clone();
         Callback::from(move |e: yew::events::MouseEvent| {
             e.prevent_default();
             let window = window().expect("no global `window` exists");
             let location = window.location();
             let server_name = location.href().expect("should have a href");
             let server_name = server_name.trim_end_matches('/').to_string();
-            let dispatch = dispatch_wasm.clone();
             let page_state = page_state.clone();
             page_state.set(PageState::Default);
             let reset_code_request = Some(ResetCodePayload {
@@ -809,18 +775,12 @@ pub fn login() -> Html {
                             page_state.set(PageState::EnterCode);
                         } else {
                             page_state.set(PageState::Default);
-                            dispatch.reduce_mut(|state| {
-                                state.error_message =
-                                    Option::from(format!("Error Sending Reset Email"))
-                            });
+                            notify_error(I18n::current().t("login.reset_email_failed"));
                         }
                     }
                     Err(e) => {
                         page_state.set(PageState::Default);
-                        dispatch.reduce_mut(|state| {
-                            state.error_message =
-                                Option::from(format!("Error sending reset: {:?}", e))
-                        });
+                        notify_error(I18n::current().tf("login.reset_send_error", &[("error", &format!("{:?}", e))]));
                     }
                 }
             });
@@ -833,29 +793,29 @@ pub fn login() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Forgot Password"}
+                            { i18n.t("login.forgot_password_title") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <p class="text-m font-semibold">
-                            {"Please enter your username and email to reset your password."}
+                            { i18n.t("login.forgot_password_body") }
                             </p>
                             <div>
-                                <label for="username" class="block mb-2 text-sm font-medium text-gray-900 dark:text-white">{"Username"}</label>
+                                <label for="username" class="block mb-2 text-sm font-medium text-gray-900 dark:text-white">{ i18n.t("common.username") }</label>
                                 <input oninput={on_forgot_username_change} type="text" id="username" name="username" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
                             </div>
                             <div>
-                                <label for="email" class="block mb-2 text-sm font-medium">{"Email"}</label>
+                                <label for="email" class="block mb-2 text-sm font-medium">{ i18n.t("common.email") }</label>
                                 <input oninput={on_forgot_email_change} type="email" id="email" name="email" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
                             </div>
-                            <button onclick={on_reset_submit} type="submit" class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{"Submit"}</button>
+                            <button onclick={on_reset_submit} type="submit" class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{ i18n.t("common.submit") }</button>
                         </form>
                     </div>
                 </div>
@@ -888,59 +848,44 @@ pub fn login() -> Html {
         let reset_password = reset_password.clone().to_string();
         let forgot_email = forgot_email.clone().to_string();
         let reset_code = reset_code.clone().to_string();
-        let dispatch_wasm = dispatch.clone();
+        let password_policy = password_policy.clone();
         Callback::from(move |_e: yew::events::MouseEvent| {
             let window = window().expect("no global `window` exists");
             let location = window.location();
             let server_name = location.href().expect("should have a href");
             let server_name = server_name.trim_end_matches('/').to_string();
-            let dispatch = dispatch_wasm.clone();
             let page_state = page_state.clone();
+            if !validate_password(&reset_password, &password_policy).is_empty() {
+                return;
+            }
             page_state.set(PageState::Default);
             // let forgot__deref = (*forgot_username.clone();
-            match encode_password(&reset_password) {
-                Ok(hash_pw) => {
-                    let reset_password_request = Some(ResetForgotPasswordPayload {
-                        reset_code: reset_code.clone(),
-                        email: forgot_email.clone(),
-                        new_password: hash_pw.clone(),
-                    });
-                    wasm_bindgen_futures::spawn_local(async move {
-                        match call_verify_and_reset_password(
-                            server_name,
-                            &reset_password_request.unwrap(),
-                        )
-                        .await
-                        {
-                            Ok(success) => {
-                                if success.message == "Password Reset Successfully" {
-                                    page_state.set(PageState::Default);
-                                } else {
-                                    page_state.set(PageState::Default);
-                                    dispatch.reduce_mut(|state| {
-                                        state.error_message =
-                                            Option::from(format!("Error Sending Reset Email"))
-                                    });
-                                }
-                            }
-                            Err(e) => {
-                                page_state.set(PageState::Default);
-                                dispatch.reduce_mut(|state| {
-                                    state.error_message =
-                                        Option::from(format!("Error Resetting Password: {:?}", e))
-                                });
-                            }
+            let reset_password_request = Some(ResetForgotPasswordPayload {
+                reset_code: reset_code.clone(),
+                email: forgot_email.clone(),
+                new_password: reset_password.clone(),
+            });
+            wasm_bindgen_futures::spawn_local(async move {
+                match call_verify_and_reset_password(
+                    server_name,
+                    &reset_password_request.unwrap(),
+                )
+                .await
+                {
+                    Ok(success) => {
+                        if success.message == "Password Reset Successfully" {
+                            page_state.set(PageState::Default);
+                        } else {
+                            page_state.set(PageState::Default);
+                            notify_error(I18n::current().t("login.reset_email_failed"));
                         }
-                    });
-                }
-                Err(e) => {
-                    dispatch.reduce_mut(|state| {
-                        state.error_message =
-                            Option::from(format!("Unable to hash new password: {:?}", e))
-                    });
-                    page_state.set(PageState::Default);
+                    }
+                    Err(e) => {
+                        page_state.set(PageState::Default);
+                        notify_error(I18n::current().tf("login.reset_error", &[("error", &format!("{:?}", e))]));
+                    }
                 }
-            }
+            });
         })
     };
 
@@ -950,23 +895,26 @@ pub fn login() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Password Reset"}
+                            { i18n.t("login.password_reset_title") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <p class="text-m font-semibold">
-                            {"An email has been sent to your email address. Please enter a new password and the code contained within the email to reset your password."}
+                            { i18n.t("login.password_reset_body") }
                             </p>
-                            <input oninput={on_reset_code_change} type="text" id="reset_code" name="reset_code" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" placeholder="Enter Password Reset Code" />
-                            <input oninput={on_reset_password_change} type="text" id="reset_password" name="reset_password" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" placeholder="Enter your new password" />
-                            <button type="submit" onclick={on_reset_code_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{"Submit"}</button>
+                            <input oninput={on_reset_code_change} type="text" id="reset_code" name="reset_code" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" placeholder={i18n.t("login.reset_code_placeholder")} />
+                            <div>
+                                <input oninput={on_reset_password_change} type="text" id="reset_password" name="reset_password" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" placeholder={i18n.t("login.new_password_placeholder")} />
+                                <PasswordStrengthMeter password={(*reset_password).clone()} policy={(*password_policy).clone()} />
+                            </div>
+                            <button type="submit" onclick={on_reset_code_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{ i18n.t("common.submit") }</button>
                         </form>
                     </div>
                 </div>
@@ -1024,10 +972,11 @@ pub fn login() -> Html {
         let temp_user_id = temp_user_id.clone();
         let history = history.clone();
         // let error_message_create = error_message.clone();
-        let dispatch_wasm = dispatch.clone();
+        let mfa_code_enabled = mfa_code_enabled.clone();
+        let mfa_passkey_enabled = mfa_passkey_enabled.clone();
         Callback::from(move |e: MouseEvent| {
-            let post_state = _dispatch.clone();
-            let dispatch = dispatch_wasm.clone();
+            let mfa_code_enabled = mfa_code_enabled.clone();
+            let mfa_passkey_enabled = mfa_passkey_enabled.clone();
             e.prevent_default();
             let server_name = (*temp_server_name).clone();
             let api_key = (*temp_api_key).clone();
@@ -1061,37 +1010,27 @@ pub fn login() -> Html {
                             .await
                             {
                                 Ok(response) => {
-                                    if response.mfa_enabled {
+                                    if response.mfa_enabled || response.passkey_enabled {
+                                        mfa_code_enabled.set(response.mfa_enabled);
+                                        mfa_passkey_enabled.set(response.passkey_enabled);
                                         page_state.set(PageState::MFAPrompt);
                                     } else {
-                                        history.push("/home"); // Use the route path
+                                        history.push(&take_return_route());
                                     }
                                 }
                                 Err(_) => {
-                                    post_state.reduce_mut(|state| {
-                                        state.error_message =
-                                            Option::from("Error Checking MFA Status".to_string())
-                                    });
+                                    notify_error(I18n::current().t("login.mfa_status_failed"));
                                 }
                             }
                         } else {
-                            post_state.reduce_mut(|state| {
-                                state.error_message =
-                                    Option::from("Error Setting up Time Zone".to_string())
-                            });
+                            notify_error(I18n::current().t("login.time_zone_failed"));
                             page_state.set(PageState::Default);
-                            dispatch.reduce_mut(|state| {
-                                state.error_message =
-                                    Option::from(format!("Error setting up time zone"))
-                            });
+                            notify_error(I18n::current().t("login.time_zone_failed"));
                         }
                     }
                     Err(e) => {
                         page_state.set(PageState::Default);
-                        dispatch.reduce_mut(|state| {
-                            state.error_message =
-                                Option::from(format!("Error setting up time zone: {:?}", e))
-                        });
+                        notify_error(I18n::current().tf("login.time_zone_error", &[("error", &format!("{:?}", e))]));
                     }
                 }
             });
@@ -1110,29 +1049,29 @@ pub fn login() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Time Zone Setup"}
+                            { i18n.t("login.time_zone_title") }
                         </h3>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <p class="text-m font-semibold">
-                            {"Welcome to Pinepods! This appears to be your first time logging in. To start, let's get some basic information about your time and time zone preferences. This will determine how times appear throughout the app."}
+                            { i18n.t("login.time_zone_body") }
                             </p>
                             <div>
-                                <label for="hour_format" style="margin-right: 10px;">{"Hour Format:"}</label>
+                                <label for="hour_format" style="margin-right: 10px;">{ i18n.t("login.hour_format") }</label>
                                 <select id="hour_format" name="hour_format" class="email-select border p-2 rounded" oninput={on_time_pref_change}>
-                                    <option value="12">{"12 Hour"}</option>
-                                    <option value="24">{"24 Hour"}</option>
+                                    <option value="12">{ i18n.t("login.hour_12") }</option>
+                                    <option value="24">{ i18n.t("login.hour_24") }</option>
                                 </select>
                             </div>
                             <div>
-                                <label for="time_zone" style="margin-right: 10px;">{"Time Zone:"}</label>
+                                <label for="time_zone" style="margin-right: 10px;">{ i18n.t("login.time_zone") }</label>
                                 <select id="time_zone" name="time_zone" class="email-select border p-2 rounded" oninput={on_tz_change}>
                                     { for TZ_VARIANTS.iter().map(|tz| render_time_zone_option(*tz)) }
                                 </select>
                             </div>
                             <div>
-                            <label for="date_format" style="margin-right: 10px;">{"Date Format:"}</label>
+                            <label for="date_format" style="margin-right: 10px;">{ i18n.t("login.date_format") }</label>
                             <select id="date_format" name="date_format" class="email-select border p-2 rounded" oninput={on_df_change}>
                                 <option value="MDY">{"MDY (MM-DD-YYYY)"}</option>
                                 <option value="DMY">{"DMY (DD-MM-YYYY)"}</option>
@@ -1144,7 +1083,7 @@ pub fn login() -> Html {
                                 <option value="JIS">{"JIS (YYYY-MM-DD)"}</option>
                             </select>
                         </div>
-                            <button type="submit" onclick={on_time_zone_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{"Submit"}</button>
+                            <button type="submit" onclick={on_time_zone_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{ i18n.t("common.submit") }</button>
                         </form>
                     </div>
                 </div>
@@ -1162,24 +1101,78 @@ pub fn login() -> Html {
         })
     };
 
-    let on_mfa_submit = {
+    // Either second factor checked out: finish loading the account and carry on
+    let on_mfa_verified = {
         let (state, dispatch) = use_store::<AppState>();
         let page_state = page_state.clone();
-        let mfa_code = mfa_code.clone();
         let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
         let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
         let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
         let history = history.clone();
+        Callback::from(move |_: ()| {
+            let dispatch = dispatch.clone();
+            let server_name = server_name.clone();
+            let api_key = api_key.clone();
+            let user_id = user_id.clone();
+            page_state.set(PageState::Default);
+            let theme_api = api_key.clone();
+            let theme_server = server_name.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match call_get_theme(
+                    theme_server.unwrap(),
+                    theme_api.unwrap().unwrap(),
+                    &user_id.unwrap(),
+                )
+                .await
+                {
+                    Ok(theme) => {
+                        crate::components::setting_components::theme_options::changeTheme(&theme);
+                    }
+                    Err(_e) => {
+                        // console::log_1(&format!("Error getting theme: {:?}", e).into());
+                    }
+                }
+            });
+            wasm_bindgen_futures::spawn_local(async move {
+                match call_get_time_info(
+                    server_name.unwrap(),
+                    api_key.unwrap().unwrap(),
+                    &user_id.unwrap(),
+                )
+                .await
+                {
+                    Ok(tz_response) => {
+                        dispatch.reduce_mut(move |state| {
+                            state.user_tz = Some(tz_response.timezone);
+                            state.hour_preference = Some(tz_response.hour_pref);
+                            state.date_format = Some(tz_response.date_format);
+                        });
+                    }
+                    Err(e) => {
+                        console::log_1(&format!("Error getting theme: {:?}", e).into());
+                    }
+                }
+            });
+            history.push(&take_return_route());
+        })
+    };
+
+    let on_mfa_submit = {
+        let (state, _dispatch) = use_store::<AppState>();
+        let page_state = page_state.clone();
+        let mfa_code = mfa_code.clone();
+        let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
+        let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
+        let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
+        let on_mfa_verified = on_mfa_verified.clone();
         // let error_message_create = error_message.clone();
-        let dispatch_wasm = dispatch.clone();
         Callback::from(move |e: MouseEvent| {
-            let dispatch = dispatch_wasm.clone();
             let mfa_code = mfa_code.clone();
             let server_name = server_name.clone();
             let api_key = api_key.clone();
             let user_id = user_id.clone();
             let page_state = page_state.clone();
-            let history = history.clone();
+            let on_mfa_verified = on_mfa_verified.clone();
             // let error_message_clone = error_message_create.clone();
             e.prevent_default();
 
@@ -1198,90 +1191,83 @@ pub fn login() -> Html {
                 {
                     Ok(response) => {
                         if response.verified {
-                            page_state.set(PageState::Default);
-                            let theme_api = api_key.clone();
-                            let theme_server = server_name.clone();
-                            wasm_bindgen_futures::spawn_local(async move {
-                                match call_get_theme(
-                                    theme_server.unwrap(),
-                                    theme_api.unwrap().unwrap(),
-                                    &user_id.unwrap(),
-                                )
-                                .await
-                                {
-                                    Ok(theme) => {
-                                        crate::components::setting_components::theme_options::changeTheme(&theme);
-                                    }
-                                    Err(_e) => {
-                                        // console::log_1(&format!("Error getting theme: {:?}", e).into());
-                                    }
-                                }
-                            });
-                            wasm_bindgen_futures::spawn_local(async move {
-                                match call_get_time_info(
-                                    server_name.unwrap(),
-                                    api_key.unwrap().unwrap(),
-                                    &user_id.unwrap(),
-                                )
-                                .await
-                                {
-                                    Ok(tz_response) => {
-                                        dispatch.reduce_mut(move |state| {
-                                            state.user_tz = Some(tz_response.timezone);
-                                            state.hour_preference = Some(tz_response.hour_pref);
-                                            state.date_format = Some(tz_response.date_format);
-                                        });
-                                    }
-                                    Err(e) => {
-                                        console::log_1(
-                                            &format!("Error getting theme: {:?}", e).into(),
-                                        );
-                                    }
-                                }
-                            });
-                            history.push("/home"); // Use the route path
+                            if response.recovery_code_used {
+                                Toast::warning(I18n::current().t("login.recovery_code_used"))
+                                    .persistent()
+                                    .show();
+                            }
+                            on_mfa_verified.emit(());
                         } else {
                             page_state.set(PageState::Default);
-                            dispatch.reduce_mut(|state| {
-                                state.error_message =
-                                    Option::from(format!("Error setting up time zone"))
-                            });
+                            notify_error(I18n::current().t("login.mfa_invalid"));
                         }
                     }
                     Err(e) => {
                         page_state.set(PageState::Default);
-                        dispatch.reduce_mut(|state| {
-                            state.error_message =
-                                Option::from(format!("Error setting up time zone: {:?}", e))
-                        });
+                        notify_error(I18n::current().tf("login.mfa_error", &[("error", &format!("{:?}", e))]));
                     }
                 }
             });
         })
     };
 
+    let on_passkey_submit = {
+        let (state, _dispatch) = use_store::<AppState>();
+        let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
+        let api_key = state.auth_details.as_ref().and_then(|ud| ud.api_key.clone());
+        let user_id = state.user_details.as_ref().map(|ud| ud.UserID);
+        let on_mfa_verified = on_mfa_verified.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            let (Some(server_name), Some(api_key), Some(user_id)) =
+                (server_name.clone(), api_key.clone(), user_id)
+            else {
+                return;
+            };
+            let on_mfa_verified = on_mfa_verified.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                // Stay on the prompt when it fails so the user can retry or use their code
+                match verify_passkey(&server_name, &api_key, user_id).await {
+                    Ok(true) => on_mfa_verified.emit(()),
+                    Ok(false) => notify_error(I18n::current().t("login.passkey_other_account")),
+                    Err(e) => notify_error(I18n::current().tf("login.passkey_error", &[("error", &e.to_string())])),
+                }
+            });
+        })
+    };
+
     let mfa_code_modal = html! {
         <div id="create-user-modal" tabindex="-1" aria-hidden="true" class="fixed top-0 right-0 left-0 z-50 flex justify-center items-center w-full h-[calc(100%-1rem)] max-h-full bg-black bg-opacity-25" onclick={on_background_click.clone()}>
             <div class="relative p-4 w-full max-w-md max-h-full bg-white rounded-lg shadow dark:bg-gray-700" onclick={stop_propagation.clone()}>
                 <div class="relative bg-white rounded-lg shadow dark:bg-gray-700">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t dark:border-gray-600">
                         <h3 class="text-xl font-semibold text-gray-900 dark:text-white">
-                            {"MFA Login"}
+                            { i18n.t("login.mfa_title") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
-                            <p class="text-m font-semibold text-gray-900 dark:text-white">
-                            {"Welcome to Pinepods! Please enter your MFA Code Below."}
-                            </p>
-                            <input oninput={on_mfa_change} type="text" id="mfa_code" name="mfa_code" class="w-full px-3 py-2 text-gray-700 border rounded-lg focus:outline-none" placeholder="Enter MFA Code" />
-                            <button type="submit" onclick={on_mfa_submit} class="w-full text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800">{"Submit"}</button>
+                            if *mfa_code_enabled {
+                                <p class="text-m font-semibold text-gray-900 dark:text-white">
+                                { i18n.t("login.mfa_body") }
+                                </p>
+                                <input oninput={on_mfa_change} type="text" id="mfa_code" name="mfa_code" class="w-full px-3 py-2 text-gray-700 border rounded-lg focus:outline-none" placeholder={i18n.t("login.mfa_placeholder")} />
+                                <button type="submit" onclick={on_mfa_submit} class="w-full text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800">{ i18n.t("common.submit") }</button>
+                            }
+                            if *mfa_passkey_enabled {
+                                if !*mfa_code_enabled {
+                                    <p class="text-m font-semibold text-gray-900 dark:text-white">
+                                    { i18n.t("login.passkey_body") }
+                                    </p>
+                                }
+                                <button onclick={on_passkey_submit} class="w-full text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800">{ i18n.t("login.use_passkey") }</button>
+                            }
                         </form>
                     </div>
                 </div>
@@ -1306,20 +1292,20 @@ pub fn login() -> Html {
             <div class="flex justify-center items-center h-screen">
                 <div class="modal-container flex flex-col space-y-4 w-full max-w-xs p-8 border rounded-lg shadow-lg">
                     <div class="flex justify-center items-center">
-                        <img class="object-scale-down h-20 w-66" src="static/assets/favicon.png" alt="Pinepods Logo" />
+                        <img class="object-scale-down h-20 w-66" src="static/assets/favicon.png" alt={i18n.t("login.logo_alt")} />
                     </div>
                     <h1 class="item_container-text text-xl font-bold mb-2 text-center">{"Pinepods"}</h1>
-                    <p class="item_container-text text-center">{"A Forest of Podcasts, Rooted in the Spirit of Self-Hosting"}</p>
+                    <p class="item_container-text text-center">{ i18n.t("login.tagline") }</p>
                     <input
                         type="text"
-                        placeholder="Username"
+                        placeholder={i18n.t("common.username")}
                         class="search-bar-input border text-sm rounded-lg block w-full p-2.5"
                         oninput={on_login_username_change}
                         onkeypress={on_key_press.clone()}
                     />
                     <input
                         type="password"
-                        placeholder="Password"
+                        placeholder={i18n.t("common.password")}
                         class="search-bar-input border text-sm rounded-lg block w-full p-2.5"
                         oninput={on_login_password_change}
                         onkeypress={on_key_press}
@@ -1330,13 +1316,13 @@ pub fn login() -> Html {
                             onclick={on_forgot_password}
                             class="login-link text-sm"
                         >
-                            {"Forgot Password?"}
+                            { i18n.t("login.forgot_password") }
                         </button>
                         // <button
                         //     onclick={on_create_new_user}
                         //     class="text-sm text-blue-500 hover:text-blue-700"
                         // >
-                        //     {"Create New User"}
+                        //     { i18n.t("login.create_user") }
                         // </button>
                         {
                             if *self_service_enabled {
@@ -1345,7 +1331,7 @@ pub fn login() -> Html {
                                         onclick={on_create_new_user.clone()}
                                         class="text-sm login-link"
                                     >
-                                        {"Create New User"}
+                                        { i18n.t("login.create_user") }
                                     </button>
                                 }
                             } else {
@@ -1357,30 +1343,19 @@ pub fn login() -> Html {
                         onclick={on_submit_click}
                         class="p-2 download-button rounded"
                     >
-                        {"Login"}
+                        { i18n.t("login.login") }
                     </button>
+                    <OidcLoginButton server_name={window().and_then(|w| w.location().origin().ok()).unwrap_or_default()} />
+                    <PasskeyLoginButton server_name={window().and_then(|w| w.location().origin().ok()).unwrap_or_default()} />
+                    <ServerProfiles />
                 </div>
-                {
-                    if app_state.error_message.as_ref().map_or(false, |msg| !msg.is_empty()) {
-                        html! { <div class="error-snackbar">{ &app_state.error_message }</div> }
-                    } else {
-                        html! {}
-                    }
-                }
-                        // Conditional rendering for the error banner
-                if let Some(error) = error_message {
-                    <div class="error-snackbar">{ error }</div>
-                }
-                if let Some(info) = info_message {
-                    <div class="info-snackbar">{ info }</div>
-                }
                 // Connect to Different Server button at bottom right
                 <div class="fixed bottom-4 right-4">
                     <button
                         onclick={on_different_server}
                         class="p-2 bg-gray-500 text-white rounded hover:bg-gray-600"
                     >
-                        {"Connect to Different Server"}
+                        { i18n.t("login.different_server") }
                     </button>
                 </div>
             </div>
@@ -1392,15 +1367,13 @@ pub fn login() -> Html {
 
 #[function_component(ChangeServer)]
 pub fn login() -> Html {
-    let (app_state, _app_dispatch) = use_store::<AppState>();
+    let i18n = use_i18n();
     let (_state, _dispatch) = use_store::<UIState>();
     let history = BrowserHistory::new();
     let server_name = use_state(|| "".to_string());
     let username = use_state(|| "".to_string());
     let password = use_state(|| "".to_string());
     let (_app_state, dispatch) = use_store::<AppState>();
-    let _error_message = app_state.error_message.clone();
-    let error_message = _state.error_message.clone();
     let time_zone = use_state(|| "".to_string());
     let date_format = use_state(|| "".to_string());
     let time_pref = use_state(|| 12);
@@ -1408,33 +1381,8 @@ pub fn login() -> Html {
     let temp_api_key = use_state(|| "".to_string());
     let temp_user_id = use_state(|| 0);
     let temp_server_name = use_state(|| "".to_string());
-    let info_message = _state.info_message.clone();
     let page_state = use_state(|| PageState::Default);
 
-    {
-        let ui_dispatch = _dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                ui_dispatch.apply(UIStateMsg::ClearErrorMessage);
-                ui_dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
 
     // This effect runs only once when the component mounts
     let background_image_url = use_state(|| String::new());
@@ -1490,7 +1438,6 @@ pub fn login() -> Html {
     let call_server_name = temp_server_name.clone();
     let call_api_key = temp_api_key.clone();
     let call_user_id = temp_user_id.clone();
-    let submit_post_state = _dispatch.clone();
     let on_submit = {
         let submit_dispatch = dispatch.clone();
         Callback::from(move |_| {
@@ -1498,7 +1445,6 @@ pub fn login() -> Html {
             let username = username.clone();
             let password = password.clone();
             let dispatch = submit_dispatch.clone();
-            let post_state = submit_post_state.clone();
             let server_name = server_name.clone();
             let page_state = submit_state.clone();
             let temp_server_name = call_server_name.clone();
@@ -1614,11 +1560,7 @@ pub fn login() -> Html {
                                             }
                                         }
                                         Err(_) => {
-                                            post_state.reduce_mut(|state| {
-                                                state.error_message = Option::from(
-                                                    "Error Checking MFA Status".to_string(),
-                                                )
-                                            });
+                                            notify_error(I18n::current().t("login.mfa_status_failed"));
                                         }
                                     }
                                 } else {
@@ -1626,20 +1568,13 @@ pub fn login() -> Html {
                                 }
                             }
                             Err(_) => {
-                                post_state.reduce_mut(|state| {
-                                    state.error_message = Option::from(
-                                        "Error checking first login status".to_string(),
-                                    )
-                                });
+                                notify_error(I18n::current().t("login.first_login_failed"));
                             }
                         }
                     }
                     Err(_) => {
                         // console::log_1(&format!("Error logging into server: {}", server_name).into());
-                        post_state.reduce_mut(|state| {
-                            state.error_message =
-                                Option::from("Your credentials appear to be incorrect".to_string())
-                        });
+                        notify_error(I18n::current().t("login.bad_credentials"));
                         // Handle error
                     }
                 }
@@ -1713,7 +1648,6 @@ pub fn login() -> Html {
             df.set(select_element.value());
         })
     };
-    let time_state_error = _dispatch.clone();
     let on_time_pref_change = {
         let time_pref = time_pref.clone();
         Callback::from(move |e: InputEvent| {
@@ -1722,13 +1656,10 @@ pub fn login() -> Html {
             if let Ok(value_int) = value_str.parse::<i32>() {
                 time_pref.set(value_int);
             } else {
-                time_state_error.reduce_mut(|state| {
-                    state.error_message = Option::from("Error parsing time preference".to_string())
-                });
+                notify_error(I18n::current().t("login.parse_time_failed"));
             }
         })
     };
-    let dispatch_time = _dispatch.clone();
     let on_time_zone_submit = {
         // let (state, dispatch) = use_store::<AppState>();
         let page_state = page_state.clone();
@@ -1744,7 +1675,6 @@ pub fn login() -> Html {
         let history = history.clone();
         // let error_message_create = error_message.clone();
         Callback::from(move |e: MouseEvent| {
-            let post_state = dispatch_time.clone();
             e.prevent_default();
             let server_name = (*temp_server_name).clone();
             let api_key = (*temp_api_key).clone();
@@ -1785,27 +1715,18 @@ pub fn login() -> Html {
                                     }
                                 }
                                 Err(_) => {
-                                    post_state.reduce_mut(|state| {
-                                        state.error_message =
-                                            Option::from("Error Checking MFA Status".to_string())
-                                    });
+                                    notify_error(I18n::current().t("login.mfa_status_failed"));
                                 }
                             }
                         } else {
-                            post_state.reduce_mut(|state| {
-                                state.error_message =
-                                    Option::from("Error Setting up Time Zone".to_string())
-                            });
+                            notify_error(I18n::current().t("login.time_zone_failed"));
                             page_state.set(PageState::Default);
                         }
                     }
                     Err(e) => {
                         page_state.set(PageState::Default);
                         // dispatch.reduce_mut(|state| state.error_message = Option::from(format!("Error setting up time zone: {:?}", e)));
-                        post_state.reduce_mut(|state| {
-                            state.error_message =
-                                Option::from(format!("Error setting up time zone: {:?}", e))
-                        });
+                        notify_error(I18n::current().tf("login.time_zone_error", &[("error", &format!("{:?}", e))]));
                     }
                 }
             });
@@ -1824,29 +1745,29 @@ pub fn login() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t dark:border-gray-600">
                         <h3 class="text-xl font-semibold">
-                            {"Time Zone Setup"}
+                            { i18n.t("login.time_zone_title") }
                         </h3>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <p class="text-m font-semibold">
-                            {"Welcome to Pinepods! This appears to be your first time logging in. To start, let's get some basic information about your time and time zone preferences. This will determine how times appear throughout the app."}
+                            { i18n.t("login.time_zone_body") }
                             </p>
                             <div>
-                                <label for="hour_format" style="margin-right: 10px;">{"Hour Format:"}</label>
+                                <label for="hour_format" style="margin-right: 10px;">{ i18n.t("login.hour_format") }</label>
                                 <select id="hour_format" name="hour_format" class="email-select border p-2 rounded" oninput={on_time_pref_change}>
-                                    <option value="12">{"12 Hour"}</option>
-                                    <option value="24">{"24 Hour"}</option>
+                                    <option value="12">{ i18n.t("login.hour_12") }</option>
+                                    <option value="24">{ i18n.t("login.hour_24") }</option>
                                 </select>
                             </div>
                             <div>
-                                <label for="time_zone" style="margin-right: 10px;">{"Time Zone:"}</label>
+                                <label for="time_zone" style="margin-right: 10px;">{ i18n.t("login.time_zone") }</label>
                                 <select id="time_zone" name="time_zone" class="email-select border p-2 rounded" oninput={on_tz_change}>
                                     { for TZ_VARIANTS.iter().map(|tz| render_time_zone_option(*tz)) }
                                 </select>
                             </div>
                             <div>
-                            <label for="date_format" style="margin-right: 10px;">{"Date Format:"}</label>
+                            <label for="date_format" style="margin-right: 10px;">{ i18n.t("login.date_format") }</label>
                             <select id="date_format" name="date_format" class="email-select border p-2 rounded" oninput={on_df_change}>
                                 <option value="MDY">{"MDY (MM-DD-YYYY)"}</option>
                                 <option value="DMY">{"DMY (DD-MM-YYYY)"}</option>
@@ -1858,7 +1779,7 @@ pub fn login() -> Html {
                                 <option value="JIS">{"JIS (YYYY-MM-DD)"}</option>
                             </select>
                         </div>
-                            <button type="submit" onclick={on_time_zone_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{"Submit"}</button>
+                            <button type="submit" onclick={on_time_zone_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{ i18n.t("common.submit") }</button>
                         </form>
                     </div>
                 </div>
@@ -1875,7 +1796,6 @@ pub fn login() -> Html {
             );
         })
     };
-    let post_state = _dispatch.clone();
     let on_mfa_submit = {
         let (state, dispatch) = use_store::<AppState>();
         let page_state = page_state.clone();
@@ -1894,7 +1814,6 @@ pub fn login() -> Html {
             let user_id = user_id.clone();
             let page_state = page_state.clone();
             let history = history.clone();
-            let post_state = post_state.clone();
             // let error_message_clone = error_message_create.clone();
             e.prevent_default();
 
@@ -1960,18 +1879,12 @@ pub fn login() -> Html {
                             history.push("/home"); // Use the route path
                         } else {
                             page_state.set(PageState::Default);
-                            post_state.reduce_mut(|state| {
-                                state.error_message =
-                                    Option::from(format!("Error validating MFA Code"))
-                            });
+                            notify_error(I18n::current().t("login.mfa_invalid"));
                         }
                     }
                     Err(e) => {
                         page_state.set(PageState::Default);
-                        post_state.reduce_mut(|state| {
-                            state.error_message =
-                                Option::from(format!("Error setting up time zone: {:?}", e))
-                        });
+                        notify_error(I18n::current().tf("login.mfa_error", &[("error", &format!("{:?}", e))]));
                     }
                 }
             });
@@ -1984,22 +1897,22 @@ pub fn login() -> Html {
                 <div class="relative bg-white rounded-lg shadow dark:bg-gray-700">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t dark:border-gray-600">
                         <h3 class="text-xl font-semibold text-gray-900 dark:text-white">
-                            {"MFA Login"}
+                            { i18n.t("login.mfa_title") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <p class="text-m font-semibold text-gray-900 dark:text-white">
-                            {"Welcome to Pinepods! Please enter your MFA Code Below."}
+                            { i18n.t("login.mfa_body_short") }
                             </p>
-                            <input oninput={on_mfa_change} type="text" id="mfa_code" name="mfa_code" class="w-full px-3 py-2 text-gray-700 border rounded-lg focus:outline-none" placeholder="Enter MFA Code" />
-                            <button type="submit" onclick={on_mfa_submit} class="w-full text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800">{"Submit"}</button>
+                            <input oninput={on_mfa_change} type="text" id="mfa_code" name="mfa_code" class="w-full px-3 py-2 text-gray-700 border rounded-lg focus:outline-none" placeholder={i18n.t("login.mfa_placeholder_short")} />
+                            <button type="submit" onclick={on_mfa_submit} class="w-full text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800">{ i18n.t("common.submit") }</button>
                         </form>
                     </div>
                 </div>
@@ -2020,47 +1933,41 @@ pub fn login() -> Html {
         <div class="flex justify-center items-center h-screen">
             <div class="modal-container flex flex-col space-y-4 w-full max-w-xs p-8 border rounded-lg shadow-lg">
                 <div class="flex justify-center items-center">
-                    <img class="object-scale-down h-20 w-66" src="static/assets/favicon.png" alt="Pinepods Logo" />
+                    <img class="object-scale-down h-20 w-66" src="static/assets/favicon.png" alt={i18n.t("login.logo_alt")} />
                 </div>
                 <h1 class="item_container-text text-xl font-bold mb-2 text-center">{"Pinepods"}</h1>
-                <p class="item_container-text text-center">{"A Forest of Podcasts, Rooted in the Spirit of Self-Hosting"}</p>
+                <p class="item_container-text text-center">{ i18n.t("login.tagline") }</p>
                 <input
                     type="text"
-                    placeholder="Server Name"
+                    placeholder={i18n.t("login.server_name")}
                     class="search-bar-input border text-sm rounded-lg block w-full p-2.5"
                     oninput={on_server_name_change}
                     onkeypress={handle_key_press.clone()}
                 />
                 <input
                     type="text"
-                    placeholder="Username"
+                    placeholder={i18n.t("common.username")}
                     class="search-bar-input border text-sm rounded-lg block w-full p-2.5"
                     oninput={on_username_change}
                     onkeypress={handle_key_press.clone()}
                 />
                 <input
                     type="password"
-                    placeholder="Password"
+                    placeholder={i18n.t("common.password")}
                     class="search-bar-input border text-sm rounded-lg block w-full p-2.5"
                     oninput={on_password_change}
                     onkeypress={handle_key_press.clone()}
                 />
                 <button onclick={on_submit_click} class="p-2 download-button rounded">
-                    {"Login"}
+                    { i18n.t("login.login") }
                 </button>
+                <ServerProfiles />
             </div>
-            // Conditional rendering for the error banner
-            if let Some(error) = error_message {
-                <div class="error-snackbar">{ error }</div>
-            }
-            if let Some(info) = info_message {
-                <div class="info-snackbar">{ info }</div>
-            }
 
             // Connect to Different Server button at bottom right
             <div class="fixed bottom-4 right-4">
                 <button onclick={on_different_server} class="p-2 bg-gray-500 text-white rounded hover:bg-gray-600">
-                    {"Connect to Local Server"}
+                    { i18n.t("login.local_server") }
                 </button>
             </div>
         </div>
@@ -2088,8 +1995,13 @@ pub fn logout() -> Html {
     let selected_theme = local_storage
         .get_item("selected_theme")
         .expect("failed to get 'selected_theme'");
+    // Saved accounts outlive signing out of any one of them
+    let server_profiles = local_storage
+        .get_item(SERVER_PROFILES_KEY)
+        .expect("failed to get saved accounts");
 
-    // Clear storages
+    // Clear storages, and the offline copies of this account's data
+    clear_offline_data();
     local_storage.clear().expect("failed to clear localStorage");
     session_storage
         .clear()
@@ -2101,6 +2013,11 @@ pub fn logout() -> Html {
             .set_item("selected_theme", &theme)
             .expect("failed to set 'selected_theme'");
     }
+    if let Some(profiles) = server_profiles {
+        local_storage
+            .set_item(SERVER_PROFILES_KEY, &profiles)
+            .expect("failed to restore saved accounts");
+    }
 
     // Redirect to root path
     history.push("/");
@@ -1,6 +1,10 @@
-use crate::components::context::{AppState, UIState};
-use crate::components::episodes_layout::UIStateMsg;
-use crate::requests::login_requests::{self, call_check_mfa_enabled};
+use crate::components::audio::restore_saved_player;
+use crate::components::context::{AppState, UIState, SERVER_PROFILES_KEY};
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::notify_error;
+use crate::components::oidc::OidcLoginButton;
+use crate::components::server_profiles::ServerProfiles;
+use crate::requests::login_requests::{self, call_check_mfa_enabled, take_return_route};
 use crate::requests::login_requests::{
     call_first_login_done, call_get_time_info, call_self_service_login_status,
     call_setup_timezone_info, call_verify_key, call_verify_mfa, TimeZoneInfo,
@@ -9,9 +13,7 @@ use crate::requests::setting_reqs::call_get_theme;
 use chrono_tz::{Tz, TZ_VARIANTS};
 use md5;
 use rand::Rng;
-use wasm_bindgen::closure::Closure;
-use wasm_bindgen::JsCast;
-use web_sys::{console, window};
+use web_sys::console;
 use yew::prelude::*;
 use yew_router::history::{BrowserHistory, History};
 use yewdux::prelude::*;
@@ -28,13 +30,12 @@ fn generate_gravatar_url(email: &Option<String>, size: usize) -> String {
 
 #[function_component(Login)]
 pub fn login() -> Html {
+    let i18n = use_i18n();
     let history = BrowserHistory::new();
     let username = use_state(|| "".to_string());
     let password = use_state(|| "".to_string());
-    let (app_state, dispatch) = use_store::<AppState>();
+    let (_app_state, dispatch) = use_store::<AppState>();
     let (_state, _dispatch) = use_store::<UIState>();
-    let _error_message = app_state.error_message.clone();
-    let error_message = _state.error_message.clone();
     let time_zone = use_state(|| "".to_string());
     let date_format = use_state(|| "".to_string());
     let time_pref = use_state(|| 12);
@@ -43,7 +44,6 @@ pub fn login() -> Html {
     let temp_api_key = use_state(|| "".to_string());
     let temp_user_id = use_state(|| 0);
     let temp_server_name = use_state(|| "".to_string());
-    let info_message = _state.info_message.clone();
     // Define the initial state
     let page_state = use_state(|| PageState::Default);
     let self_service_enabled = use_state(|| false); // State to store self-service status
@@ -77,35 +77,12 @@ pub fn login() -> Html {
         },
     );
 
-    {
-        let ui_dispatch = _dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                ui_dispatch.apply(UIStateMsg::ClearErrorMessage);
-                ui_dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
     let effect_displatch = dispatch.clone();
     // User Auto Login with saved state
     use_effect_with((), {
         // let error_clone_use = error_message_clone.clone();
         let history = history.clone();
+        let player_dispatch = _dispatch.clone();
         move |_| {
             if let Some(window) = web_sys::window() {
                 if let Ok(local_storage) = window.local_storage() {
@@ -192,6 +169,13 @@ pub fn login() -> Html {
                                                                             state.gravatar_url = Some(gravatar_url);
 
                                                                         });
+                                                                            // Bring back whatever was playing before the reload
+                                                                            restore_saved_player(
+                                                                                player_dispatch,
+                                                                                server_name.clone(),
+                                                                                Some(api_key.clone()),
+                                                                                wasm_user_id,
+                                                                            );
                                                                             // let mut error_message = app_state.error_message;
                                                                             // Retrieve the originally requested route, if any
                                                                             let session_storage = window.session_storage().unwrap().unwrap();
@@ -327,7 +311,7 @@ pub fn login() -> Html {
     let call_server_name = temp_server_name.clone();
     let call_api_key = temp_api_key.clone();
     let call_user_id = temp_user_id.clone();
-    let submit_post_state = _dispatch.clone();
+    let sso_server_name = (*server_name).clone();
     let on_submit = {
         let submit_dispatch = dispatch.clone();
         Callback::from(move |_| {
@@ -335,7 +319,6 @@ pub fn login() -> Html {
             let username = username.clone();
             let password = password.clone();
             let dispatch = submit_dispatch.clone();
-            let post_state = submit_post_state.clone();
             let server_name = server_name.clone();
             let page_state = submit_state.clone();
             let temp_server_name = call_server_name.clone();
@@ -447,15 +430,11 @@ pub fn login() -> Html {
                                                         }
                                                     }
                                                 });
-                                                history.push("/home"); // Use the route path
+                                                history.push(&take_return_route());
                                             }
                                         }
                                         Err(_) => {
-                                            post_state.reduce_mut(|state| {
-                                                state.error_message = Option::from(
-                                                    "Error Checking MFA Status".to_string(),
-                                                )
-                                            });
+                                            notify_error(I18n::current().t("login.mfa_status_failed"));
                                         }
                                     }
                                 } else {
@@ -463,20 +442,13 @@ pub fn login() -> Html {
                                 }
                             }
                             Err(_) => {
-                                post_state.reduce_mut(|state| {
-                                    state.error_message = Option::from(
-                                        "Error checking first login status".to_string(),
-                                    )
-                                });
+                                notify_error(I18n::current().t("login.first_login_failed"));
                             }
                         }
                     }
                     Err(_) => {
                         // console::log_1(&format!("Error logging into server: {}", server_name).into());
-                        post_state.reduce_mut(|state| {
-                            state.error_message =
-                                Option::from("Your credentials appear to be incorrect".to_string())
-                        });
+                        notify_error(I18n::current().t("login.bad_credentials"));
                         // Handle error
                     }
                 }
@@ -545,7 +517,6 @@ pub fn login() -> Html {
             df.set(select_element.value());
         })
     };
-    let time_state_error = _dispatch.clone();
     let on_time_pref_change = {
         let time_pref = time_pref.clone();
         Callback::from(move |e: InputEvent| {
@@ -554,13 +525,10 @@ pub fn login() -> Html {
             if let Ok(value_int) = value_str.parse::<i32>() {
                 time_pref.set(value_int);
             } else {
-                time_state_error.reduce_mut(|state| {
-                    state.error_message = Option::from("Error parsing time preference".to_string())
-                });
+                notify_error(I18n::current().t("login.parse_time_failed"));
             }
         })
     };
-    let dispatch_time = _dispatch.clone();
     let on_time_zone_submit = {
         // let (state, dispatch) = use_store::<AppState>();
         let page_state = page_state.clone();
@@ -576,7 +544,6 @@ pub fn login() -> Html {
         let history = history.clone();
         // let error_message_create = error_message.clone();
         Callback::from(move |e: MouseEvent| {
-            let post_state = dispatch_time.clone();
             e.prevent_default();
             let server_name = (*temp_server_name).clone();
             let api_key = (*temp_api_key).clone();
@@ -613,31 +580,22 @@ pub fn login() -> Html {
                                     if response.mfa_enabled {
                                         page_state.set(PageState::MFAPrompt);
                                     } else {
-                                        history.push("/home"); // Use the route path
+                                        history.push(&take_return_route());
                                     }
                                 }
                                 Err(_) => {
-                                    post_state.reduce_mut(|state| {
-                                        state.error_message =
-                                            Option::from("Error Checking MFA Status".to_string())
-                                    });
+                                    notify_error(I18n::current().t("login.mfa_status_failed"));
                                 }
                             }
                         } else {
-                            post_state.reduce_mut(|state| {
-                                state.error_message =
-                                    Option::from("Error Setting up Time Zone".to_string())
-                            });
+                            notify_error(I18n::current().t("login.time_zone_failed"));
                             page_state.set(PageState::Default);
                         }
                     }
                     Err(e) => {
                         page_state.set(PageState::Default);
                         // dispatch.reduce_mut(|state| state.error_message = Option::from(format!("Error setting up time zone: {:?}", e)));
-                        post_state.reduce_mut(|state| {
-                            state.error_message =
-                                Option::from(format!("Error setting up time zone: {:?}", e))
-                        });
+                        notify_error(I18n::current().tf("login.time_zone_error", &[("error", &format!("{:?}", e))]));
                     }
                 }
             });
@@ -656,35 +614,35 @@ pub fn login() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t dark:border-gray-600">
                         <h3 class="text-xl font-semibold">
-                            {"Time Zone Setup"}
+                            { i18n.t("login.time_zone_title") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <p class="text-m font-semibold">
-                            {"Welcome to Pinepods! This appears to be your first time logging in. To start, let's get some basic information about your time and time zone preferences. This will determine how times appear throughout the app."}
+                            { i18n.t("login.time_zone_body") }
                             </p>
                             <div>
-                                <label for="hour_format" style="margin-right: 10px;">{"Hour Format:"}</label>
+                                <label for="hour_format" style="margin-right: 10px;">{ i18n.t("login.hour_format") }</label>
                                 <select id="hour_format" name="hour_format" class="email-select border p-2 rounded" oninput={on_time_pref_change}>
-                                    <option value="12">{"12 Hour"}</option>
-                                    <option value="24">{"24 Hour"}</option>
+                                    <option value="12">{ i18n.t("login.hour_12") }</option>
+                                    <option value="24">{ i18n.t("login.hour_24") }</option>
                                 </select>
                             </div>
                             <div>
-                                <label for="time_zone" style="margin-right: 10px;">{"Time Zone:"}</label>
+                                <label for="time_zone" style="margin-right: 10px;">{ i18n.t("login.time_zone") }</label>
                                 <select id="time_zone" name="time_zone" class="email-select border p-2 rounded" oninput={on_tz_change}>
                                     { for TZ_VARIANTS.iter().map(|tz| render_time_zone_option(*tz)) }
                                 </select>
                             </div>
                             <div>
-                            <label for="date_format" style="margin-right: 10px;">{"Date Format:"}</label>
+                            <label for="date_format" style="margin-right: 10px;">{ i18n.t("login.date_format") }</label>
                             <select id="date_format" name="date_format" class="email-select border p-2 rounded" oninput={on_df_change}>
                                 <option value="MDY">{"MDY (MM-DD-YYYY)"}</option>
                                 <option value="DMY">{"DMY (DD-MM-YYYY)"}</option>
@@ -696,7 +654,7 @@ pub fn login() -> Html {
                                 <option value="JIS">{"JIS (YYYY-MM-DD)"}</option>
                             </select>
                         </div>
-                            <button type="submit" onclick={on_time_zone_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{"Submit"}</button>
+                            <button type="submit" onclick={on_time_zone_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{ i18n.t("common.submit") }</button>
                         </form>
                     </div>
                 </div>
@@ -713,7 +671,6 @@ pub fn login() -> Html {
             );
         })
     };
-    let post_state = _dispatch.clone();
     let on_mfa_submit = {
         let (state, dispatch) = use_store::<AppState>();
         let page_state = page_state.clone();
@@ -732,7 +689,6 @@ pub fn login() -> Html {
             let user_id = user_id.clone();
             let page_state = page_state.clone();
             let history = history.clone();
-            let post_state = post_state.clone();
             // let error_message_clone = error_message_create.clone();
             e.prevent_default();
 
@@ -795,21 +751,15 @@ pub fn login() -> Html {
                                     }
                                 }
                             });
-                            history.push("/home"); // Use the route path
+                            history.push(&take_return_route());
                         } else {
                             page_state.set(PageState::Default);
-                            post_state.reduce_mut(|state| {
-                                state.error_message =
-                                    Option::from(format!("Error validating MFA Code"))
-                            });
+                            notify_error(I18n::current().t("login.mfa_invalid"));
                         }
                     }
                     Err(e) => {
                         page_state.set(PageState::Default);
-                        post_state.reduce_mut(|state| {
-                            state.error_message =
-                                Option::from(format!("Error setting up time zone: {:?}", e))
-                        });
+                        notify_error(I18n::current().tf("login.mfa_error", &[("error", &format!("{:?}", e))]));
                     }
                 }
             });
@@ -822,22 +772,22 @@ pub fn login() -> Html {
                 <div class="relative bg-white rounded-lg shadow dark:bg-gray-700">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t dark:border-gray-600">
                         <h3 class="text-xl font-semibold text-gray-900 dark:text-white">
-                            {"MFA Login"}
+                            { i18n.t("login.mfa_title") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <p class="text-m font-semibold text-gray-900 dark:text-white">
-                            {"Welcome to Pinepods! Please enter your MFA Code Below."}
+                            { i18n.t("login.mfa_body_short") }
                             </p>
-                            <input oninput={on_mfa_change} type="text" id="mfa_code" name="mfa_code" class="w-full px-3 py-2 text-gray-700 border rounded-lg focus:outline-none" placeholder="Enter MFA Code" />
-                            <button type="submit" onclick={on_mfa_submit} class="w-full text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800">{"Submit"}</button>
+                            <input oninput={on_mfa_change} type="text" id="mfa_code" name="mfa_code" class="w-full px-3 py-2 text-gray-700 border rounded-lg focus:outline-none" placeholder={i18n.t("login.mfa_placeholder_short")} />
+                            <button type="submit" onclick={on_mfa_submit} class="w-full text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800">{ i18n.t("common.submit") }</button>
                         </form>
                     </div>
                 </div>
@@ -858,45 +808,40 @@ pub fn login() -> Html {
         <div class="flex justify-center items-center h-screen">
             <div class="modal-container flex flex-col space-y-4 w-full max-w-xs p-8 border rounded-lg shadow-lg">
                 <div class="flex justify-center items-center">
-                    <img class="object-scale-down h-20 w-66" src="static/assets/favicon.png" alt="Pinepods Logo" />
+                    <img class="object-scale-down h-20 w-66" src="static/assets/favicon.png" alt={i18n.t("login.logo_alt")} />
                 </div>
                 <h1 class="item_container-text text-xl font-bold mb-2 text-center">{"Pinepods"}</h1>
-                <p class="item_container-text text-center">{"A Forest of Podcasts, Rooted in the Spirit of Self-Hosting"}</p>
+                <p class="item_container-text text-center">{ i18n.t("login.tagline") }</p>
                 <input
                     type="text"
-                    placeholder="Server Name"
+                    placeholder={i18n.t("login.server_name")}
                     class="search-bar-input border text-sm rounded-lg block w-full p-2.5"
                     oninput={on_server_name_change}
                     onkeypress={handle_key_press.clone()}
                 />
                 <input
                     type="text"
-                    placeholder="Username"
+                    placeholder={i18n.t("common.username")}
                     class="search-bar-input border text-sm rounded-lg block w-full p-2.5"
                     oninput={on_username_change}
                     onkeypress={handle_key_press.clone()}
                 />
                 <input
                     type="password"
-                    placeholder="Password"
+                    placeholder={i18n.t("common.password")}
                     class="search-bar-input border text-sm rounded-lg block w-full p-2.5"
                     oninput={on_password_change}
                     onkeypress={handle_key_press.clone()}
                 />
                 <button onclick={on_submit_click} class="p-2 download-button rounded">
-                    {"Login"}
+                    { i18n.t("login.login") }
                 </button>
                 <button onclick={on_offline_mode} class="p-2 download-button rounded">
-                    {"Offline Mode"}
+                    { i18n.t("login.offline_mode") }
                 </button>
+                <OidcLoginButton server_name={sso_server_name} />
+                <ServerProfiles />
             </div>
-            // Conditional rendering for the error banner
-            if let Some(error) = error_message {
-                <div class="error-snackbar">{ error }</div>
-            }
-            if let Some(info) = info_message {
-                <div class="info-snackbar">{ info }</div>
-            }
         </div>
         </div>
         </>
@@ -905,8 +850,9 @@ pub fn login() -> Html {
 
 #[function_component(ChangeServer)]
 pub fn login() -> Html {
+    let i18n = use_i18n();
     html! {
-        <p>{"This route isn't used on the client version"}</p>
+        <p>{ i18n.t("login.change_server_unused") }</p>
     }
 }
 
@@ -929,6 +875,10 @@ pub fn logout() -> Html {
     let selected_theme = local_storage
         .get_item("selected_theme")
         .expect("failed to get 'selected_theme'");
+    // Saved accounts outlive signing out of any one of them
+    let server_profiles = local_storage
+        .get_item(SERVER_PROFILES_KEY)
+        .expect("failed to get saved accounts");
 
     // Clear storages
     local_storage.clear().expect("failed to clear localStorage");
@@ -942,6 +892,11 @@ pub fn logout() -> Html {
             .set_item("selected_theme", &theme)
             .expect("failed to set 'selected_theme'");
     }
+    if let Some(profiles) = server_profiles {
+        local_storage
+            .set_item(SERVER_PROFILES_KEY, &profiles)
+            .expect("failed to restore saved accounts");
+    }
 
     // Redirect to root path
     history.push("/");
@@ -11,22 +11,36 @@ pub(crate) mod settings;
 pub(crate) mod user_stats;
 
 mod audio;
+pub(crate) mod audio_graph;
+pub(crate) mod bookmarks;
 mod click_events;
 pub(crate) mod context;
 pub(crate) mod desc_impl;
 pub mod downloads;
 pub(crate) mod episode;
+pub(crate) mod episode_filter;
+pub(crate) mod episode_stream;
 pub(crate) mod episodes_layout;
 pub(crate) mod gen_components;
 pub mod gen_funcs;
+pub(crate) mod i18n;
 #[cfg(feature = "server_build")]
 pub mod login;
+pub(crate) mod notifications;
+#[cfg(feature = "server_build")]
+pub(crate) mod offline;
+pub(crate) mod oidc;
+pub(crate) mod passkeys;
+pub(crate) mod password_strength;
 pub(crate) mod person;
+pub(crate) mod playlists;
 pub(crate) mod podcast_layout;
 pub(crate) mod podcasts;
 pub(crate) mod search_new;
+pub(crate) mod server_profiles;
 pub mod setting_components;
 pub(crate) mod shared_episode;
+pub(crate) mod transcript;
 
 #[cfg(not(feature = "server_build"))]
 pub mod downloads_tauri;
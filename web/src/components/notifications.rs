@@ -0,0 +1,239 @@
+use crate::components::i18n::use_i18n;
+use gloo_timers::callback::Timeout;
+use serde::{Deserialize, Serialize};
+use serde_json::from_str;
+use std::rc::Rc;
+use web_sys::window;
+use yew::prelude::*;
+use yewdux::prelude::*;
+
+const TOAST_TIMEOUT_MS: u32 = 5000;
+/// Long enough to reach for an undo button.
+const ACTION_TOAST_TIMEOUT_MS: u32 = 8000;
+/// Older toasts that would clear themselves anyway make room past this.
+const MAX_TOASTS: usize = 4;
+const PERSISTENT_TOASTS_KEY: &str = "persistentToasts";
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum Severity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    fn class(self) -> &'static str {
+        match self {
+            Severity::Info => "toast-info",
+            Severity::Success => "toast-success",
+            Severity::Warning => "toast-warning",
+            Severity::Error => "toast-error",
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Success => "check_circle",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct ToastAction {
+    pub label: String,
+    pub on_click: Callback<()>,
+}
+
+/// One notification. Build it with the severity constructors, then `show` it.
+#[derive(Clone, PartialEq)]
+pub struct Toast {
+    pub id: u32,
+    pub severity: Severity,
+    pub message: String,
+    pub action: Option<ToastAction>,
+    /// Stays until dismissed, and comes back after a reload in the same tab.
+    pub persistent: bool,
+}
+
+impl Toast {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            id: 0,
+            severity,
+            message: message.into(),
+            action: None,
+            persistent: false,
+        }
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(Severity::Info, message)
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(Severity::Success, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    /// A button on the toast, like "Undo". Clicking it also dismisses the toast.
+    pub fn with_action(mut self, label: impl Into<String>, on_click: impl Fn() + 'static) -> Self {
+        self.action = Some(ToastAction {
+            label: label.into(),
+            on_click: Callback::from(move |_| on_click()),
+        });
+        self
+    }
+
+    pub fn persistent(mut self) -> Self {
+        self.persistent = true;
+        self
+    }
+
+    /// Add the toast to the stack and return its id for `dismiss`.
+    pub fn show(mut self) -> u32 {
+        let dispatch = Dispatch::<Notifications>::global();
+        let mut id = 0;
+        dispatch.reduce_mut(|notifications| {
+            notifications.next_id += 1;
+            id = notifications.next_id;
+            self.id = id;
+            let timeout = match (&self.action, self.persistent) {
+                (_, true) => None,
+                (Some(_), false) => Some(ACTION_TOAST_TIMEOUT_MS),
+                (None, false) => Some(TOAST_TIMEOUT_MS),
+            };
+            notifications.toasts.push(self);
+            while notifications.toasts.len() > MAX_TOASTS {
+                match notifications
+                    .toasts
+                    .iter()
+                    .position(|toast| !toast.persistent)
+                {
+                    Some(oldest) => notifications.toasts.remove(oldest),
+                    None => break,
+                };
+            }
+            if let Some(timeout) = timeout {
+                Timeout::new(timeout, move || dismiss(id)).forget();
+            }
+            store_persistent(&notifications.toasts);
+        });
+        id
+    }
+}
+
+#[derive(Default, Clone, PartialEq, Store)]
+pub struct Notifications {
+    pub toasts: Vec<Toast>,
+    next_id: u32,
+}
+
+pub fn notify_info(message: impl Into<String>) {
+    Toast::info(message).show();
+}
+
+pub fn notify_success(message: impl Into<String>) {
+    Toast::success(message).show();
+}
+
+pub fn notify_warning(message: impl Into<String>) {
+    Toast::warning(message).show();
+}
+
+pub fn notify_error(message: impl Into<String>) {
+    Toast::error(message).show();
+}
+
+pub fn dismiss(id: u32) {
+    Dispatch::<Notifications>::global().reduce_mut(|notifications| {
+        notifications.toasts.retain(|toast| toast.id != id);
+        store_persistent(&notifications.toasts);
+    });
+}
+
+/// What's kept of a persistent toast across reloads. Actions can't be, so they're dropped.
+#[derive(Serialize, Deserialize)]
+struct StoredToast {
+    severity: Severity,
+    message: String,
+}
+
+fn store_persistent(toasts: &[Toast]) {
+    let stored: Vec<StoredToast> = toasts
+        .iter()
+        .filter(|toast| toast.persistent)
+        .map(|toast| StoredToast {
+            severity: toast.severity,
+            message: toast.message.clone(),
+        })
+        .collect();
+    if let (Some(storage), Ok(serialized)) = (
+        window().and_then(|window| window.session_storage().ok().flatten()),
+        serde_json::to_string(&stored),
+    ) {
+        let _ = storage.set_item(PERSISTENT_TOASTS_KEY, &serialized);
+    }
+}
+
+fn restore_persistent() {
+    let stored: Vec<StoredToast> = window()
+        .and_then(|window| window.session_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(PERSISTENT_TOASTS_KEY).ok().flatten())
+        .and_then(|serialized| from_str(&serialized).ok())
+        .unwrap_or_default();
+    for toast in stored {
+        Toast::new(toast.severity, toast.message)
+            .persistent()
+            .show();
+    }
+}
+
+/// The notification stack. Rendered once, above the router, so toasts outlive page changes.
+#[function_component(ToastStack)]
+pub fn toast_stack() -> Html {
+    let i18n = use_i18n();
+    let notifications: Rc<Notifications> = use_store_value::<Notifications>();
+
+    use_effect_with((), |_| {
+        restore_persistent();
+        || ()
+    });
+
+    html! {
+        <div class="toast-stack" aria-live="polite">
+            { for notifications.toasts.iter().map(|toast| {
+                let id = toast.id;
+                let on_dismiss = Callback::from(move |_: MouseEvent| dismiss(id));
+                let action = toast.action.as_ref().map(|action| {
+                    let on_click = action.on_click.clone();
+                    let onclick = Callback::from(move |_: MouseEvent| {
+                        on_click.emit(());
+                        dismiss(id);
+                    });
+                    html! { <button class="toast-action" {onclick}>{ &action.label }</button> }
+                });
+                html! {
+                    <div key={id} class={classes!("toast", toast.severity.class())} role={if toast.severity == Severity::Error { "alert" } else { "status" }}>
+                        <span class="material-icons toast-icon">{ toast.severity.icon() }</span>
+                        <span class="toast-message">{ &toast.message }</span>
+                        { action.unwrap_or_default() }
+                        <button class="toast-dismiss" title={i18n.t("toast.dismiss")} onclick={on_dismiss}>
+                            <span class="material-icons">{"close"}</span>
+                        </button>
+                    </div>
+                }
+            })}
+        </div>
+    }
+}
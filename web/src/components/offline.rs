@@ -0,0 +1,320 @@
+use crate::components::audio::AudioPlayerProps;
+use crate::components::context::UIState;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::notify_error;
+use crate::requests::pod_req::EpisodeInfo;
+use anyhow::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::from_str;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::window;
+use yew::prelude::*;
+use yewdux::prelude::*;
+
+/// Must match the cache names in `static/service-worker.js`.
+const API_CACHE: &str = "pinepods-api-v2";
+const EPISODE_CACHE: &str = "pinepods-episodes-v1";
+const OFFLINE_EPISODES_KEY: &str = "offlineEpisodes";
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["navigator", "serviceWorker"], js_name = register, catch)]
+    fn register_worker(url: &str) -> Result<js_sys::Promise, JsValue>;
+
+    type Cache;
+    #[wasm_bindgen(js_namespace = caches, js_name = open, catch)]
+    fn open_cache(name: &str) -> Result<js_sys::Promise, JsValue>;
+    #[wasm_bindgen(js_namespace = caches, js_name = delete, catch)]
+    fn delete_cache(name: &str) -> Result<js_sys::Promise, JsValue>;
+    #[wasm_bindgen(method, catch)]
+    fn add(this: &Cache, url: &str) -> Result<js_sys::Promise, JsValue>;
+    #[wasm_bindgen(method, js_name = delete, catch)]
+    fn remove(this: &Cache, url: &str) -> Result<js_sys::Promise, JsValue>;
+}
+
+fn has_global(path: &[&str]) -> bool {
+    let mut value: JsValue = js_sys::global().into();
+    for name in path {
+        match js_sys::Reflect::get(&value, &JsValue::from_str(name)) {
+            Ok(next) if !next.is_undefined() && !next.is_null() => value = next,
+            _ => return false,
+        }
+    }
+    true
+}
+
+fn js_error(error: JsValue) -> Error {
+    Error::msg(
+        js_sys::Reflect::get(&error, &JsValue::from_str("message"))
+            .ok()
+            .and_then(|message| message.as_string())
+            .unwrap_or_else(|| format!("{:?}", error)),
+    )
+}
+
+/// Install the service worker that keeps the web client working offline. Browsers only allow
+/// them over HTTPS or on localhost, so elsewhere this quietly does nothing.
+pub fn register_service_worker() {
+    if !has_global(&["navigator", "serviceWorker"]) {
+        return;
+    }
+    spawn_local(async {
+        let registered = match register_worker("/service-worker.js") {
+            Ok(promise) => JsFuture::from(promise).await.map(|_| ()),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = registered {
+            web_sys::console::log_1(
+                &format!("Service worker registration failed: {}", js_error(e)).into(),
+            );
+        }
+    });
+}
+
+async fn episode_cache() -> Result<Cache, Error> {
+    if !has_global(&["caches"]) {
+        return Err(Error::msg(
+            "This browser can't save episodes for offline listening",
+        ));
+    }
+    let cache = JsFuture::from(open_cache(EPISODE_CACHE).map_err(js_error)?)
+        .await
+        .map_err(js_error)?;
+    Ok(cache.unchecked_into())
+}
+
+/// An episode kept in this browser's cache, with what's needed to list and play it offline.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct OfflineEpisode {
+    pub episode_id: i32,
+    pub title: String,
+    pub podcast_name: String,
+    pub artwork_url: String,
+    pub url: String,
+    pub duration: i32,
+}
+
+impl OfflineEpisode {
+    pub fn load_all() -> Vec<Self> {
+        window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(OFFLINE_EPISODES_KEY).ok().flatten())
+            .and_then(|stored| from_str(&stored).ok())
+            .unwrap_or_default()
+    }
+
+    fn store_all(episodes: &[Self]) {
+        if let (Some(storage), Ok(serialized)) = (
+            window().and_then(|window| window.local_storage().ok().flatten()),
+            serde_json::to_string(episodes),
+        ) {
+            let _ = storage.set_item(OFFLINE_EPISODES_KEY, &serialized);
+        }
+    }
+
+    pub fn is_saved(episode_id: i32) -> bool {
+        Self::load_all()
+            .iter()
+            .any(|episode| episode.episode_id == episode_id)
+    }
+
+    /// Download the episode into the browser's cache. The podcast's host has to allow
+    /// cross-origin requests for this to work, which most do.
+    pub async fn save(info: &EpisodeInfo) -> Result<(), Error> {
+        let cache = episode_cache().await?;
+        JsFuture::from(cache.add(&info.episodeurl).map_err(js_error)?)
+            .await
+            .map_err(|e| {
+                Error::msg(format!(
+                    "Couldn't save the episode, its host may not allow it: {}",
+                    js_error(e)
+                ))
+            })?;
+
+        let mut episodes = Self::load_all();
+        episodes.retain(|episode| episode.episode_id != info.episodeid);
+        episodes.insert(
+            0,
+            Self {
+                episode_id: info.episodeid,
+                title: info.episodetitle.clone(),
+                podcast_name: info.podcastname.clone(),
+                artwork_url: info.episodeartwork.clone(),
+                url: info.episodeurl.clone(),
+                duration: info.episodeduration,
+            },
+        );
+        Self::store_all(&episodes);
+        Ok(())
+    }
+
+    pub async fn remove(episode_id: i32) -> Result<(), Error> {
+        let mut episodes = Self::load_all();
+        if let Some(episode) = episodes.iter().find(|e| e.episode_id == episode_id) {
+            let cache = episode_cache().await?;
+            JsFuture::from(cache.remove(&episode.url).map_err(js_error)?)
+                .await
+                .map_err(js_error)?;
+        }
+        episodes.retain(|episode| episode.episode_id != episode_id);
+        Self::store_all(&episodes);
+        Ok(())
+    }
+
+    /// Play from the cache. Progress is only sent to the server while we can reach it.
+    pub fn play(&self, ui_dispatch: &Dispatch<UIState>) {
+        let episode = self.clone();
+        ui_dispatch.reduce_mut(move |ui_state| {
+            let offline = ui_state.app_offline_mode.unwrap_or(false);
+            ui_state.audio_playing = Some(true);
+            ui_state.apply_podcast_playback_settings(None, 100);
+            ui_state.audio_volume = 100.0;
+            ui_state.offline = Some(offline);
+            ui_state.currently_playing = Some(AudioPlayerProps {
+                src: episode.url.clone(),
+                title: episode.title.clone(),
+                artwork_url: episode.artwork_url.clone(),
+                duration: episode.duration.to_string(),
+                episode_id: episode.episode_id,
+                duration_sec: episode.duration as f64,
+                start_pos_sec: 0.0,
+                end_pos_sec: 0.0,
+                offline,
+                enclosure_type: None,
+            });
+            ui_state.set_audio_source(episode.url.clone());
+            if let Some(audio) = &ui_state.audio_element {
+                let _ = audio.play();
+            }
+        });
+    }
+}
+
+/// Forget everything cached for the signed-in account, for logging out.
+pub fn clear_offline_data() {
+    if !has_global(&["caches"]) {
+        return;
+    }
+    spawn_local(async {
+        for name in [API_CACHE, EPISODE_CACHE] {
+            if let Ok(promise) = delete_cache(name) {
+                let _ = JsFuture::from(promise).await;
+            }
+        }
+    });
+}
+
+fn browser_online() -> bool {
+    window().map_or(true, |window| window.navigator().on_line())
+}
+
+/// Keeps `UIState::app_offline_mode` in step with the browser's connection, so pages know
+/// they're looking at cached data, and says so while it lasts.
+#[function_component(OfflineWatcher)]
+pub fn offline_watcher() -> Html {
+    let i18n = use_i18n();
+    let (ui_state, ui_dispatch) = use_store::<UIState>();
+
+    use_effect_with((), move |_| {
+        let set_mode = move |offline: bool| {
+            ui_dispatch.reduce_mut(|ui_state| ui_state.app_offline_mode = Some(offline));
+        };
+        set_mode(!browser_online());
+
+        let on_online = {
+            let set_mode = set_mode.clone();
+            Closure::<dyn Fn()>::new(move || set_mode(false))
+        };
+        let on_offline = Closure::<dyn Fn()>::new(move || set_mode(true));
+        let window = window().expect("no global `window` exists");
+        let _ =
+            window.add_event_listener_with_callback("online", on_online.as_ref().unchecked_ref());
+        let _ =
+            window.add_event_listener_with_callback("offline", on_offline.as_ref().unchecked_ref());
+
+        move || {
+            let _ = window
+                .remove_event_listener_with_callback("online", on_online.as_ref().unchecked_ref());
+            let _ = window.remove_event_listener_with_callback(
+                "offline",
+                on_offline.as_ref().unchecked_ref(),
+            );
+        }
+    });
+
+    if ui_state.app_offline_mode.unwrap_or(false) {
+        html! {
+            <div class="offline-banner">
+                <span class="material-icons icon-space">{"cloud_off"}</span>
+                { i18n.t("offline.banner") }
+            </div>
+        }
+    } else {
+        html! {}
+    }
+}
+
+/// The episodes saved in this browser, listed on the downloads page.
+#[function_component(BrowserSavedEpisodes)]
+pub fn browser_saved_episodes() -> Html {
+    let i18n = use_i18n();
+    let (_ui_state, ui_dispatch) = use_store::<UIState>();
+    let episodes = use_state(OfflineEpisode::load_all);
+
+    if episodes.is_empty() {
+        return html! {};
+    }
+
+    let on_remove = {
+        let episodes = episodes.clone();
+        Callback::from(move |episode_id: i32| {
+            let episodes = episodes.clone();
+            spawn_local(async move {
+                match OfflineEpisode::remove(episode_id).await {
+                    Ok(()) => episodes.set(OfflineEpisode::load_all()),
+                    Err(e) => notify_error(
+                        I18n::current().tf("offline.remove_failed", &[("error", &e.to_string())]),
+                    ),
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class="mb-6">
+            <h2 class="text-xl item_container-text font-bold mb-2">{ i18n.t("offline.saved_heading") }</h2>
+            <ul class="offline-episode-list">
+                { for episodes.iter().map(|episode| {
+                    let onplay = {
+                        let episode = episode.clone();
+                        let ui_dispatch = ui_dispatch.clone();
+                        Callback::from(move |_: MouseEvent| episode.play(&ui_dispatch))
+                    };
+                    let onremove = {
+                        let on_remove = on_remove.clone();
+                        let episode_id = episode.episode_id;
+                        Callback::from(move |_: MouseEvent| on_remove.emit(episode_id))
+                    };
+                    html! {
+                        <li class="offline-episode-item">
+                            <img src={episode.artwork_url.clone()} alt="" class="offline-episode-artwork" />
+                            <div class="offline-episode-text">
+                                <span class="item_container-text text-sm font-bold">{ &episode.title }</span>
+                                <span class="item_container-text text-xs">{ &episode.podcast_name }</span>
+                            </div>
+                            <button onclick={onplay} class="item-container-button selector-button rounded-full" title={i18n.t("offline.play")}>
+                                <span class="material-icons">{"play_arrow"}</span>
+                            </button>
+                            <button onclick={onremove} class="item-container-button selector-button rounded-full" title={i18n.t("offline.remove")}>
+                                <span class="material-icons">{"delete"}</span>
+                            </button>
+                        </li>
+                    }
+                })}
+            </ul>
+        </div>
+    }
+}
@@ -0,0 +1,464 @@
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::notify_error;
+use crate::components::passkeys::{get_passkey, passkeys_supported};
+use crate::requests::login_requests::{
+    call_get_oidc_config, call_oidc_callback, call_oidc_passkey_options, call_oidc_verify_mfa,
+    call_oidc_verify_passkey, start_session_with_key, take_return_route, OidcCallbackRequest,
+    OidcConfig,
+};
+use anyhow::Error;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use gloo_timers::callback::Timeout;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+use yew_router::history::{BrowserHistory, History};
+
+/// Session storage slot for the sign-in we're waiting on the identity provider to finish.
+#[cfg(feature = "server_build")]
+const PENDING_LOGIN_KEY: &str = "oidc_pending_login";
+
+/// What we need to remember across the trip to the identity provider and back.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct PendingLogin {
+    server_name: String,
+    state: String,
+    code_verifier: String,
+    redirect_uri: String,
+}
+
+impl PendingLogin {
+    fn new(server_name: String, redirect_uri: String) -> Self {
+        Self {
+            server_name,
+            state: random_token(),
+            code_verifier: random_token(),
+            redirect_uri,
+        }
+    }
+
+    fn authorization_url(&self, config: &OidcConfig) -> String {
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(self.code_verifier.as_bytes()));
+        let separator = if config.authorization_endpoint.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        format!(
+            "{}{}response_type=code&client_id={}&redirect_uri={}&scope={}&state={}&code_challenge={}&code_challenge_method=S256",
+            config.authorization_endpoint,
+            separator,
+            urlencoding::encode(&config.client_id),
+            urlencoding::encode(&self.redirect_uri),
+            urlencoding::encode(&config.scopes),
+            urlencoding::encode(&self.state),
+            challenge
+        )
+    }
+
+    /// Check the provider's redirect belongs to this sign-in and pull out the code.
+    fn code_from_redirect(&self, query: &str) -> Result<String, Error> {
+        let param = |name: &str| {
+            url::form_urlencoded::parse(query.trim_start_matches('?').as_bytes())
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+        if let Some(error) = param("error") {
+            let description = param("error_description").unwrap_or(error);
+            return Err(Error::msg(
+                I18n::current().tf("oidc.cancelled", &[("error", &description)]),
+            ));
+        }
+        if param("state").as_deref() != Some(self.state.as_str()) {
+            return Err(Error::msg(I18n::current().t("oidc.state_mismatch")));
+        }
+        param("code").ok_or_else(|| Error::msg(I18n::current().t("oidc.no_code")))
+    }
+
+    /// Redeem the code with the Pinepods server and make the resulting account the session.
+    /// Accounts with MFA come back as a challenge instead, to be finished in `OidcMfaPrompt`.
+    async fn complete(self, code: String) -> Result<Option<OidcMfaChallenge>, Error> {
+        let request = OidcCallbackRequest {
+            code,
+            code_verifier: self.code_verifier,
+            redirect_uri: self.redirect_uri,
+        };
+        let response = call_oidc_callback(&self.server_name, &request).await?;
+        match (response.retrieved_key, response.mfa_token) {
+            (Some(api_key), _) => {
+                start_session_with_key(self.server_name, response.username, api_key).await?;
+                Ok(None)
+            }
+            (None, Some(mfa_token)) => Ok(Some(OidcMfaChallenge {
+                server_name: self.server_name,
+                mfa_token,
+                mfa_enabled: response.mfa_enabled,
+                passkey_enabled: response.passkey_enabled,
+            })),
+            (None, None) => Err(Error::msg(I18n::current().t("oidc.not_finished"))),
+        }
+    }
+}
+
+/// A sign-in the provider has vouched for that still needs the account's second factor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OidcMfaChallenge {
+    server_name: String,
+    mfa_token: String,
+    mfa_enabled: bool,
+    passkey_enabled: bool,
+}
+
+fn random_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(64)
+        .map(char::from)
+        .collect()
+}
+
+/// The web client leaves for the provider's sign-in page; `OidcCallback` picks up from there.
+#[cfg(feature = "server_build")]
+async fn run_oidc_login(
+    server_name: String,
+    config: OidcConfig,
+) -> Result<Option<OidcMfaChallenge>, Error> {
+    let window = web_sys::window().ok_or_else(|| Error::msg("no global `window` exists"))?;
+    let origin = window
+        .location()
+        .origin()
+        .map_err(|e| Error::msg(format!("{:?}", e)))?;
+    let pending = PendingLogin::new(server_name, format!("{}/oauth/callback", origin));
+    if let Ok(Some(session_storage)) = window.session_storage() {
+        let _ = session_storage.set_item(PENDING_LOGIN_KEY, &serde_json::to_string(&pending)?);
+    }
+    window
+        .location()
+        .set_href(&pending.authorization_url(&config))
+        .map_err(|e| Error::msg(format!("{:?}", e)))?;
+    Ok(None)
+}
+
+/// The app can't be redirected to, so the system browser sends the provider's answer to a
+/// listener on the loopback interface instead.
+#[cfg(not(feature = "server_build"))]
+async fn run_oidc_login(
+    server_name: String,
+    config: OidcConfig,
+) -> Result<Option<OidcMfaChallenge>, Error> {
+    use tauri_sys::core;
+
+    #[derive(Serialize)]
+    struct AwaitRedirectArgs {
+        url: String,
+    }
+
+    let port = core::invoke_result::<_, u16>("start_oauth_listener", &())
+        .await
+        .map_err(|e| {
+            Error::msg(
+                I18n::current().tf("oidc.listener_failed", &[("error", &format!("{:?}", e))]),
+            )
+        })?;
+    let pending = PendingLogin::new(server_name, format!("http://127.0.0.1:{}/callback", port));
+    let args = AwaitRedirectArgs {
+        url: pending.authorization_url(&config),
+    };
+    let query = core::invoke_result::<_, String>("await_oauth_redirect", &args)
+        .await
+        .map_err(|e| Error::msg(format!("{:?}", e)))?;
+    let code = pending.code_from_redirect(&query)?;
+    let challenge = pending.complete(code).await?;
+    if challenge.is_none() {
+        BrowserHistory::new().push(&take_return_route());
+    }
+    Ok(challenge)
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct OidcLoginButtonProps {
+    pub server_name: String,
+}
+
+/// "Sign in with ..." for servers that have an identity provider configured. Renders nothing
+/// for servers that don't.
+#[function_component(OidcLoginButton)]
+pub fn oidc_login_button(props: &OidcLoginButtonProps) -> Html {
+    let i18n = use_i18n();
+    let config = use_state(|| None::<OidcConfig>);
+    let signing_in = use_state(|| false);
+    let error = use_state(|| None::<String>);
+    let mfa_challenge = use_state(|| None::<OidcMfaChallenge>);
+
+    {
+        let config = config.clone();
+        use_effect_with(props.server_name.clone(), move |server_name| {
+            config.set(None);
+            let server_name = server_name.trim().trim_end_matches('/').to_string();
+            // Wait for the server name to stop changing before asking it anything
+            let timer = (!server_name.is_empty()).then(|| {
+                Timeout::new(500, move || {
+                    spawn_local(async move {
+                        if let Ok(fetched) = call_get_oidc_config(&server_name).await {
+                            config.set(Some(fetched).filter(|c| c.enabled));
+                        }
+                    });
+                })
+            });
+            move || drop(timer)
+        });
+    }
+
+    let Some(current_config) = (*config).clone() else {
+        return html! {};
+    };
+
+    let onclick = {
+        let server_name = props.server_name.trim().trim_end_matches('/').to_string();
+        let signing_in = signing_in.clone();
+        let error = error.clone();
+        let mfa_challenge = mfa_challenge.clone();
+        Callback::from(move |_: MouseEvent| {
+            let server_name = server_name.clone();
+            let config = current_config.clone();
+            let signing_in = signing_in.clone();
+            let error = error.clone();
+            let mfa_challenge = mfa_challenge.clone();
+            signing_in.set(true);
+            error.set(None);
+            spawn_local(async move {
+                match run_oidc_login(server_name, config).await {
+                    Ok(challenge) => mfa_challenge.set(challenge),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+                signing_in.set(false);
+            });
+        })
+    };
+
+    let on_mfa_cancel = {
+        let mfa_challenge = mfa_challenge.clone();
+        Callback::from(move |_| mfa_challenge.set(None))
+    };
+
+    html! {
+        <>
+            <button {onclick} disabled={*signing_in} class="p-2 download-button rounded">
+                { if *signing_in {
+                    i18n.t("oidc.waiting")
+                } else {
+                    i18n.tf("oidc.sign_in_with", &[("provider", config.as_ref().map_or("", |c| c.provider_name.as_str()))])
+                } }
+            </button>
+            if let Some(error) = (*error).clone() {
+                <p class="server-profile-error text-xs">{ error }</p>
+            }
+            if let Some(challenge) = (*mfa_challenge).clone() {
+                <OidcMfaPrompt {challenge} on_cancel={on_mfa_cancel} />
+            }
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct OidcMfaPromptProps {
+    pub challenge: OidcMfaChallenge,
+    pub on_cancel: Callback<()>,
+}
+
+/// Asks for the MFA code or passkey of an account the identity provider has just signed in.
+#[function_component(OidcMfaPrompt)]
+pub fn oidc_mfa_prompt(props: &OidcMfaPromptProps) -> Html {
+    let i18n = use_i18n();
+    let code = use_state(String::new);
+    let busy = use_state(|| false);
+    let challenge = &props.challenge;
+
+    let on_code_input = {
+        let code = code.clone();
+        Callback::from(move |e: InputEvent| {
+            code.set(
+                e.target_unchecked_into::<web_sys::HtmlInputElement>()
+                    .value(),
+            );
+        })
+    };
+
+    let on_code_submit = {
+        let challenge = challenge.clone();
+        let code = code.clone();
+        let busy = busy.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let challenge = challenge.clone();
+            let mfa_code = code.trim().to_string();
+            let busy = busy.clone();
+            busy.set(true);
+            spawn_local(async move {
+                let result = async {
+                    let response = call_oidc_verify_mfa(
+                        &challenge.server_name,
+                        challenge.mfa_token.clone(),
+                        mfa_code,
+                    )
+                    .await?;
+                    start_session_with_key(
+                        challenge.server_name,
+                        response.username,
+                        response.retrieved_key,
+                    )
+                    .await
+                }
+                .await;
+                finish_mfa_prompt(result);
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_passkey = {
+        let challenge = challenge.clone();
+        let busy = busy.clone();
+        Callback::from(move |_: MouseEvent| {
+            let challenge = challenge.clone();
+            let busy = busy.clone();
+            busy.set(true);
+            spawn_local(async move {
+                let result = async {
+                    let options = call_oidc_passkey_options(
+                        &challenge.server_name,
+                        challenge.mfa_token.clone(),
+                    )
+                    .await?;
+                    let credential = get_passkey(&options.options).await?;
+                    let response = call_oidc_verify_passkey(
+                        &challenge.server_name,
+                        challenge.mfa_token.clone(),
+                        options.challenge_id,
+                        credential,
+                    )
+                    .await?;
+                    start_session_with_key(
+                        challenge.server_name,
+                        response.username,
+                        response.retrieved_key,
+                    )
+                    .await
+                }
+                .await;
+                finish_mfa_prompt(result);
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_cancel = props.on_cancel.clone();
+        Callback::from(move |_: MouseEvent| on_cancel.emit(()))
+    };
+
+    html! {
+        <div class="fixed top-0 right-0 left-0 z-50 flex justify-center items-center w-full h-[calc(100%-1rem)] max-h-full bg-black bg-opacity-25">
+            <div class="relative p-4 w-full max-w-md max-h-full bg-white rounded-lg shadow dark:bg-gray-700">
+                <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t dark:border-gray-600">
+                    <h3 class="text-xl font-semibold text-gray-900 dark:text-white">{ i18n.t("login.mfa_title") }</h3>
+                </div>
+                <div class="p-4 md:p-5 space-y-4">
+                    if challenge.mfa_enabled {
+                        <form class="space-y-4" onsubmit={on_code_submit}>
+                            <p class="text-m font-semibold text-gray-900 dark:text-white">
+                                { i18n.t("oidc.mfa_body") }
+                            </p>
+                            <input oninput={on_code_input} value={(*code).clone()} type="text" autocomplete="one-time-code" class="w-full px-3 py-2 text-gray-700 border rounded-lg focus:outline-none" placeholder={i18n.t("login.mfa_placeholder")} />
+                            <button type="submit" disabled={*busy} class="w-full text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800">{ i18n.t("common.submit") }</button>
+                        </form>
+                    }
+                    if challenge.passkey_enabled && passkeys_supported() {
+                        if !challenge.mfa_enabled {
+                            <p class="text-m font-semibold text-gray-900 dark:text-white">
+                                { i18n.t("oidc.passkey_body") }
+                            </p>
+                        }
+                        <button onclick={on_passkey} disabled={*busy} class="w-full text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800">{ i18n.t("login.use_passkey") }</button>
+                    }
+                    <button onclick={on_cancel} class="w-full p-2 download-button rounded">{ i18n.t("common.cancel") }</button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+fn finish_mfa_prompt(result: Result<(), Error>) {
+    match result {
+        Ok(()) => BrowserHistory::new().push(&take_return_route()),
+        Err(e) => notify_error(e.to_string()),
+    }
+}
+
+/// Where the identity provider sends the web client back to after sign-in.
+#[function_component(OidcCallback)]
+pub fn oidc_callback() -> Html {
+    let i18n = use_i18n();
+    let mfa_challenge = use_state(|| None::<OidcMfaChallenge>);
+
+    {
+        let mfa_challenge = mfa_challenge.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let history = BrowserHistory::new();
+                match finish_redirected_login().await {
+                    Ok(None) => history.push(&take_return_route()),
+                    Ok(challenge) => mfa_challenge.set(challenge),
+                    Err(e) => {
+                        notify_error(e.to_string());
+                        history.push("/");
+                    }
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_mfa_cancel = Callback::from(|_| BrowserHistory::new().push("/"));
+
+    html! {
+        <div class="flex justify-center items-center h-screen">
+            if let Some(challenge) = (*mfa_challenge).clone() {
+                <OidcMfaPrompt {challenge} on_cancel={on_mfa_cancel} />
+            } else {
+                <p class="item_container-text">{ i18n.t("oidc.signing_in") }</p>
+            }
+        </div>
+    }
+}
+
+#[cfg(feature = "server_build")]
+async fn finish_redirected_login() -> Result<Option<OidcMfaChallenge>, Error> {
+    let window = web_sys::window().ok_or_else(|| Error::msg("no global `window` exists"))?;
+    let session_storage = window
+        .session_storage()
+        .ok()
+        .flatten()
+        .ok_or_else(|| Error::msg(I18n::current().t("oidc.no_session_storage")))?;
+    let pending = session_storage
+        .get_item(PENDING_LOGIN_KEY)
+        .ok()
+        .flatten()
+        .and_then(|stored| serde_json::from_str::<PendingLogin>(&stored).ok())
+        .ok_or_else(|| Error::msg(I18n::current().t("oidc.no_pending_login")))?;
+    // A code is only good once, so neither is the pending sign-in
+    let _ = session_storage.remove_item(PENDING_LOGIN_KEY);
+
+    let query = window.location().search().unwrap_or_default();
+    let code = pending.code_from_redirect(&query)?;
+    pending.complete(code).await
+}
+
+#[cfg(not(feature = "server_build"))]
+async fn finish_redirected_login() -> Result<Option<OidcMfaChallenge>, Error> {
+    Err(Error::msg(I18n::current().t("oidc.desktop_only")))
+}
@@ -0,0 +1,163 @@
+use crate::components::i18n::{use_i18n, I18n};
+use crate::requests::login_requests::{
+    call_webauthn_login, call_webauthn_login_options, call_webauthn_verify,
+    call_webauthn_verify_options, start_session_with_key, take_return_route,
+};
+use anyhow::Error;
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+use yew_router::history::{BrowserHistory, History};
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = PublicKeyCredential, js_name = parseCreationOptionsFromJSON, catch)]
+    fn parse_creation_options(options: &JsValue) -> Result<JsValue, JsValue>;
+    #[wasm_bindgen(js_namespace = PublicKeyCredential, js_name = parseRequestOptionsFromJSON, catch)]
+    fn parse_request_options(options: &JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["navigator", "credentials"], js_name = create, catch)]
+    fn credentials_create(options: &JsValue) -> Result<js_sys::Promise, JsValue>;
+    #[wasm_bindgen(js_namespace = ["navigator", "credentials"], js_name = get, catch)]
+    fn credentials_get(options: &JsValue) -> Result<js_sys::Promise, JsValue>;
+}
+
+/// Whether this browser can create and use passkeys. We rely on the JSON helpers on
+/// `PublicKeyCredential`, which some webviews don't have even where WebAuthn itself exists.
+pub fn passkeys_supported() -> bool {
+    let global = js_sys::global();
+    js_sys::Reflect::get(&global, &JsValue::from_str("PublicKeyCredential"))
+        .ok()
+        .filter(|class| !class.is_undefined())
+        .map_or(false, |class| {
+            js_sys::Reflect::has(&class, &JsValue::from_str("parseRequestOptionsFromJSON"))
+                .unwrap_or(false)
+        })
+}
+
+/// Ask the browser to make a new passkey for the server's registration options.
+pub async fn create_passkey(options: &Value) -> Result<Value, Error> {
+    let public_key = parse_creation_options(&to_js(options)?).map_err(js_error)?;
+    let promise = credentials_create(&public_key_options(&public_key)?).map_err(js_error)?;
+    from_js(JsFuture::from(promise).await.map_err(js_error)?)
+}
+
+/// Ask the browser to sign the server's challenge with one of the user's passkeys.
+pub async fn get_passkey(options: &Value) -> Result<Value, Error> {
+    let public_key = parse_request_options(&to_js(options)?).map_err(js_error)?;
+    let promise = credentials_get(&public_key_options(&public_key)?).map_err(js_error)?;
+    from_js(JsFuture::from(promise).await.map_err(js_error)?)
+}
+
+/// Use a passkey as the second factor for an account that's already given its password.
+pub async fn verify_passkey(
+    server_name: &String,
+    api_key: &String,
+    user_id: i32,
+) -> Result<bool, Error> {
+    let challenge = call_webauthn_verify_options(server_name, api_key, user_id).await?;
+    let credential = get_passkey(&challenge.options).await?;
+    let response = call_webauthn_verify(
+        server_name,
+        api_key,
+        user_id,
+        challenge.challenge_id,
+        credential,
+    )
+    .await?;
+    Ok(response.verified)
+}
+
+/// Sign in with nothing but a passkey: the server works out whose it is.
+async fn login_with_passkey(server_name: String) -> Result<(), Error> {
+    let challenge = call_webauthn_login_options(&server_name).await?;
+    let credential = get_passkey(&challenge.options).await?;
+    let response = call_webauthn_login(&server_name, challenge.challenge_id, credential).await?;
+    start_session_with_key(server_name, response.username, response.retrieved_key).await
+}
+
+fn to_js(value: &Value) -> Result<JsValue, Error> {
+    js_sys::JSON::parse(&value.to_string()).map_err(js_error)
+}
+
+/// The credential's `toJSON()` is what the server expects, and `JSON.stringify` calls it for us.
+fn from_js(credential: JsValue) -> Result<Value, Error> {
+    let json: String = js_sys::JSON::stringify(&credential)
+        .map_err(js_error)?
+        .into();
+    Ok(serde_json::from_str(&json)?)
+}
+
+fn public_key_options(public_key: &JsValue) -> Result<JsValue, Error> {
+    let options = js_sys::Object::new();
+    js_sys::Reflect::set(&options, &JsValue::from_str("publicKey"), public_key)
+        .map_err(js_error)?;
+    Ok(options.into())
+}
+
+fn js_error(error: JsValue) -> Error {
+    let field = |name: &str| {
+        js_sys::Reflect::get(&error, &JsValue::from_str(name))
+            .ok()
+            .and_then(|value| value.as_string())
+    };
+    match field("name").as_deref() {
+        // Covers the user closing the prompt as well as it timing out
+        Some("NotAllowedError") => Error::msg(I18n::current().t("passkeys.cancelled")),
+        Some("InvalidStateError") => Error::msg(I18n::current().t("passkeys.already_registered")),
+        _ => Error::msg(
+            field("message")
+                .or_else(|| error.as_string())
+                .unwrap_or_else(|| format!("{:?}", error)),
+        ),
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct PasskeyLoginButtonProps {
+    pub server_name: String,
+}
+
+/// "Sign in with a passkey", for browsers that support them.
+#[function_component(PasskeyLoginButton)]
+pub fn passkey_login_button(props: &PasskeyLoginButtonProps) -> Html {
+    let i18n = use_i18n();
+    let signing_in = use_state(|| false);
+    let error = use_state(|| None::<String>);
+
+    if !passkeys_supported() {
+        return html! {};
+    }
+
+    let onclick = {
+        let server_name = props.server_name.trim().trim_end_matches('/').to_string();
+        let signing_in = signing_in.clone();
+        let error = error.clone();
+        Callback::from(move |_: MouseEvent| {
+            let server_name = server_name.clone();
+            let signing_in = signing_in.clone();
+            let error = error.clone();
+            signing_in.set(true);
+            error.set(None);
+            spawn_local(async move {
+                match login_with_passkey(server_name).await {
+                    Ok(()) => BrowserHistory::new().push(&take_return_route()),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+                signing_in.set(false);
+            });
+        })
+    };
+
+    html! {
+        <>
+            <button {onclick} disabled={*signing_in} class="p-2 download-button rounded">
+                { i18n.t(if *signing_in { "passkeys.waiting" } else { "passkeys.sign_in" }) }
+            </button>
+            if let Some(error) = (*error).clone() {
+                <p class="server-profile-error text-xs">{ error }</p>
+            }
+        </>
+    }
+}
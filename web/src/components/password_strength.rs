@@ -0,0 +1,76 @@
+use crate::components::gen_funcs::{password_policy_failures, password_strength};
+use crate::components::i18n::use_i18n;
+use crate::requests::login_requests::{call_get_password_policy, PasswordPolicy};
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+/// The password policy of `server_name`, starting from the defaults until the server answers.
+#[hook]
+pub fn use_password_policy(server_name: Option<String>) -> UseStateHandle<PasswordPolicy> {
+    let policy = use_state(PasswordPolicy::default);
+    {
+        let policy = policy.clone();
+        use_effect_with(server_name, move |server_name| {
+            if let Some(server_name) = server_name.clone() {
+                spawn_local(async move {
+                    if let Ok(fetched) = call_get_password_policy(&server_name).await {
+                        policy.set(fetched);
+                    }
+                });
+            }
+            || ()
+        });
+    }
+    policy
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct PasswordStrengthMeterProps {
+    pub password: String,
+    pub policy: PasswordPolicy,
+}
+
+/// A strength bar for a password being typed, with whatever the policy still wants from it.
+#[function_component(PasswordStrengthMeter)]
+pub fn password_strength_meter(props: &PasswordStrengthMeterProps) -> Html {
+    let i18n = use_i18n();
+    if props.password.is_empty() {
+        return html! {};
+    }
+
+    let failures = password_policy_failures(&props.password, &props.policy);
+    let score = password_strength(&props.password);
+    // A password the policy won't accept never shows as better than weak
+    let score = if failures.is_empty() {
+        score
+    } else {
+        score.min(1)
+    };
+    let (label, level_class) = match score {
+        0 => ("password.very_weak", "strength-0"),
+        1 => ("password.weak", "strength-1"),
+        2 => ("password.fair", "strength-2"),
+        3 => ("password.good", "strength-3"),
+        _ => ("password.strong", "strength-4"),
+    };
+
+    html! {
+        <div class="password-strength">
+            <div class="password-strength-track">
+                <div
+                    class={classes!("password-strength-bar", level_class)}
+                    style={format!("width: {}%", (score as u32 + 1) * 20)}
+                />
+            </div>
+            <p class="item_container-text text-xs">{ i18n.t(label) }</p>
+            if !failures.is_empty() {
+                <ul class="text-red-500 text-xs italic">
+                    { for failures.iter().map(|failure| html! { <li>{ failure }</li> }) }
+                </ul>
+            }
+            if props.policy.check_breached {
+                <p class="item_container-text text-xs">{ i18n.t("password.breached_note") }</p>
+            }
+        </div>
+    }
+}
@@ -8,10 +8,12 @@ use crate::components::episodes_layout::AppStateMsg as EpisodeMsg;
 use crate::components::episodes_layout::SafeHtml;
 use crate::components::gen_components::{Search_nav, UseScrollToTop};
 use crate::components::gen_components::on_shownotes_click;
+use crate::components::i18n::{use_i18n, I18n};
 use crate::components::gen_funcs::{
     format_datetime, format_time, match_date_format, parse_date,
     sanitize_html_with_blank_target, truncate_description,
 };
+use crate::components::notifications::{notify_error, notify_info};
 use crate::requests::login_requests::use_check_authentication;
 use crate::requests::pod_req::{
     call_add_podcast, call_remove_podcasts_name, PodcastValues, RemovePodcastValuesName,
@@ -93,6 +95,7 @@ pub struct PersonProps {
 #[function_component(Person)]
 pub fn person(PersonProps { name }: &PersonProps) -> Html {
     let (state, dispatch) = use_store::<AppState>();
+    let i18n = use_i18n();
     let (desc_state, desc_dispatch) = use_store::<ExpandedDescriptions>();
 
     let session_dispatch = dispatch.clone();
@@ -139,8 +142,6 @@ pub fn person(PersonProps { name }: &PersonProps) -> Html {
         .auth_details
         .as_ref()
         .map(|ud| ud.server_name.clone());
-    let error_message = audio_state.error_message.clone();
-    let info_message = audio_state.info_message.clone();
     let history = BrowserHistory::new();
     let history_clone = history.clone();
 
@@ -215,15 +216,13 @@ pub fn person(PersonProps { name }: &PersonProps) -> Html {
                                 new_set.remove(&podcast_id);
                                 added_podcasts_callback.set(new_set);
                                 dispatch_callback.reduce_mut(|state| {
-                                    state.info_message =
-                                        Some("Podcast successfully removed".to_string());
+                                    notify_info(I18n::current().t("podcast.removed"));
                                     state.is_loading = Some(false);
                                 });
                             }
                             Err(e) => {
                                 dispatch_callback.reduce_mut(|state| {
-                                    state.error_message =
-                                        Some(format!("Error removing podcast: {:?}", e));
+                                    notify_error(I18n::current().tf("podcast.remove_error", &[("error", &format!("{:?}", e))]));
                                     state.is_loading = Some(false);
                                 });
                             }
@@ -281,8 +280,7 @@ pub fn person(PersonProps { name }: &PersonProps) -> Html {
                                             }
                                         }
                                     }
-                                    state.info_message =
-                                        Some("Podcast successfully added".to_string());
+                                    notify_info(I18n::current().t("podcast.added"));
                                     state.is_loading = Some(false);
                                 });
                                 let mut new_set = (*added_podcasts_callback).clone();
@@ -292,8 +290,7 @@ pub fn person(PersonProps { name }: &PersonProps) -> Html {
                             }
                             Err(e) => {
                                 dispatch_callback.reduce_mut(|state| {
-                                    state.error_message =
-                                        Some(format!("Error adding podcast: {:?}", e));
+                                    notify_error(I18n::current().tf("podcast.add_error", &[("error", &format!("{:?}", e))]));
                                     state.is_loading = Some(false);
                                 });
                             }
@@ -310,9 +307,9 @@ pub fn person(PersonProps { name }: &PersonProps) -> Html {
                 <Search_nav />
                 <UseScrollToTop />
                 <div class="p-4">
-                    <h1 class="text-2xl item_container-text font-bold text-center mb-6">{ format!("Podcasts and Episodes featuring {}", name) }</h1>
+                    <h1 class="text-2xl item_container-text font-bold text-center mb-6">{ i18n.tf("person.title", &[("name", name)]) }</h1>
                     <div class="mb-8">
-                        <h2 class="item_container-text text-xl font-semibold">{"Podcasts this person appears in"}</h2>
+                        <h2 class="item_container-text text-xl font-semibold">{ i18n.t("person.podcasts_heading") }</h2>
                         {
                             if let Some(podcasts) = state.podcast_feed_return.clone() {
                                 let int_podcasts = podcasts.clone();
@@ -321,9 +318,9 @@ pub fn person(PersonProps { name }: &PersonProps) -> Html {
                                                                 // Render "No Recent Episodes Found" if episodes list is empty
                                         html! {
                                     <div class="empty-episodes-container">
-                                        <img src="static/assets/favicon.png" alt="Logo" class="logo"/>
-                                        <h1>{ "No Podcasts Found" }</h1>
-                                        <p>{"You can add new podcasts by using the search bar above. Search for your favorite podcast and click the plus button to add it."}</p>
+                                        <img src="static/assets/favicon.png" alt={i18n.t("common.logo_alt")} class="logo"/>
+                                        <h1>{ i18n.t("podcasts.empty_title") }</h1>
+                                        <p>{ i18n.t("podcasts.empty_body") }</p>
                                     </div>
                                         }
                                     } else {
@@ -409,7 +406,7 @@ pub fn person(PersonProps { name }: &PersonProps) -> Html {
                                                         <img
                                                             src={podcast.artworkurl.clone()}
                                                             onclick={on_title_click.clone()}
-                                                            alt={format!("Cover for {}", podcast.podcastname.clone())}
+                                                            alt={i18n.tf("podcast.cover_alt", &[("podcast", &podcast.podcastname)])}
                                                             class="episode-image"
                                                         />
                                                     </div>
@@ -432,7 +429,7 @@ pub fn person(PersonProps { name }: &PersonProps) -> Html {
                                                             }
                                                         }
 
-                                                        <p class="item_container-text">{ format!("Episode Count: {}", &podcast.episodecount) }</p>
+                                                        <p class="item_container-text">{ i18n.tf("podcast.episode_count", &[("count", &i18n.number(podcast.episodecount as f64))]) }</p>
                                                     </div>
                                                     // <button class={"item-container-button border selector-button font-bold py-2 px-4 rounded-full self-center mr-8"} style="width: 60px; height: 60px;">
                                                     //     <span class="material-icons" onclick={toggle_delete.reform(move |_| podcast_id_loop)}>{"delete"}</span>
@@ -450,24 +447,24 @@ pub fn person(PersonProps { name }: &PersonProps) -> Html {
                                 } else {
                                     html! {
                                         <div class="empty-episodes-container">
-                                            <img src="static/assets/favicon.png" alt="Logo" class="logo"/>
-                                            <h1>{ "No Podcasts Found" }</h1>
-                                            <p>{"You can add new podcasts by using the search bar above. Search for your favorite podcast and click the plus button to add it."}</p>
+                                            <img src="static/assets/favicon.png" alt={i18n.t("common.logo_alt")} class="logo"/>
+                                            <h1>{ i18n.t("podcasts.empty_title") }</h1>
+                                            <p>{ i18n.t("podcasts.empty_body") }</p>
                                         </div>
                                     }
                                 }
                             } else {
                                 html! {
                                     <div class="empty-episodes-container">
-                                        <img src="static/assets/favicon.png" alt="Logo" class="logo"/>
-                                        <h1>{ "No Podcasts Found" }</h1>
-                                        <p>{"You can add new podcasts by using the search bar above. Search for your favorite podcast and click the plus button to add it."}</p>
+                                        <img src="static/assets/favicon.png" alt={i18n.t("common.logo_alt")} class="logo"/>
+                                        <h1>{ i18n.t("podcasts.empty_title") }</h1>
+                                        <p>{ i18n.t("podcasts.empty_body") }</p>
                                     </div>
                                 }
                             }
                         }
                     </div>
-                    <h2 class="item_container-text text-xl font-semibold">{"Episodes this person appears in"}</h2>
+                    <h2 class="item_container-text text-xl font-semibold">{ i18n.t("person.episodes_heading") }</h2>
                     {
                         if let Some(results) = &state.people_feed_results {
                             html! {
@@ -566,7 +563,7 @@ pub fn person(PersonProps { name }: &PersonProps) -> Html {
                                             <div class="item-container flex items-center mb-4 shadow-md rounded-lg">
                                                 <img
                                                     src={episode.feedImage.clone().unwrap_or_default()}
-                                                    alt={format!("Cover for {}", &episode.title.clone().unwrap_or_default())}
+                                                    alt={i18n.tf("podcast.cover_alt", &[("podcast", &episode.title.clone().unwrap_or_default())])}
                                                     class="episode-image"/>
                                                 <div class="flex flex-col p-4 space-y-2 flex-grow md:w-7/12">
                                                     <p class="item_container-text episode-title font-semibold"
@@ -636,9 +633,9 @@ pub fn person(PersonProps { name }: &PersonProps) -> Html {
                         } else {
                             html! {
                                 <div class="empty-episodes-container" id="episode-container">
-                                    <img src="static/assets/favicon.png" alt="Logo" class="logo"/>
-                                    <h1 class="page-subtitles">{ "No Episodes Found" }</h1>
-                                    <p class="page-paragraphs">{"This podcast strangely doesn't have any episodes. Try a more mainstream one maybe?"}</p>
+                                    <img src="static/assets/favicon.png" alt={i18n.t("common.logo_alt")} class="logo"/>
+                                    <h1 class="page-subtitles">{ i18n.t("podcast.no_episodes_title") }</h1>
+                                    <p class="page-paragraphs">{ i18n.t("podcast.no_episodes_body") }</p>
                                 </div>
                             }
                         }
@@ -652,13 +649,6 @@ pub fn person(PersonProps { name }: &PersonProps) -> Html {
                         html! {}
                     }
                 }
-                // Conditional rendering for the error banner
-                if let Some(error) = error_message {
-                    <div class="error-snackbar">{ error }</div>
-                }
-                if let Some(info) = info_message {
-                    <div class="info-snackbar">{ info }</div>
-                }
             </div>
             <App_drawer />
         </>
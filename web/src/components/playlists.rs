@@ -0,0 +1,769 @@
+use super::app_drawer::App_drawer;
+use super::gen_components::{
+    empty_message, episode_item, on_shownotes_click, Search_nav, UseScrollToTop,
+};
+use crate::components::audio::{on_play_click, play_playlist_episode, AudioPlayer};
+use crate::components::context::{AppState, PlaylistPlayback, UIState};
+use crate::components::episode_filter::{filtered_episode_list, use_episode_filter};
+use crate::components::episodes_layout::AppStateMsg;
+use crate::components::gen_funcs::{
+    format_datetime, match_date_format, parse_date, sanitize_html_with_blank_target,
+    truncate_description,
+};
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, notify_success};
+use crate::components::routes::Route;
+use crate::requests::login_requests::use_check_authentication;
+use crate::requests::pod_req::{
+    call_create_playlist, call_delete_playlist, call_get_playlist_episodes, call_get_playlists,
+    call_update_playlist, Playlist, PlaylistRequest, PlaylistRules, SavePlaylistRequest,
+};
+use std::rc::Rc;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+use yew_router::history::{BrowserHistory, History};
+use yew_router::prelude::Link;
+use yewdux::prelude::*;
+
+const PLAY_STATES: [&str; 4] = ["any", "unplayed", "in_progress", "played"];
+const SORTS: [&str; 4] = ["newest", "oldest", "shortest", "longest"];
+
+/// Fetch the user's playlists into the app state for the drawer and the playlists page.
+pub fn load_playlists(
+    dispatch: Dispatch<AppState>,
+    server_name: String,
+    api_key: Option<String>,
+    user_id: i32,
+) {
+    wasm_bindgen_futures::spawn_local(async move {
+        match call_get_playlists(&server_name, &api_key, user_id).await {
+            Ok(playlists) => dispatch.reduce_mut(|state| state.playlists = Some(playlists)),
+            Err(e) => notify_error(format!("{}", e)),
+        }
+    });
+}
+
+/// Play a playlist from the top. Its episodes are fetched fresh so smart playlists are current.
+fn play_all(
+    server_name: String,
+    api_key: Option<String>,
+    user_id: i32,
+    playlist_id: i32,
+    audio_dispatch: Dispatch<UIState>,
+) {
+    wasm_bindgen_futures::spawn_local(async move {
+        let episodes =
+            match call_get_playlist_episodes(&server_name, &api_key, user_id, playlist_id).await {
+                Ok(response) => response.episodes,
+                Err(e) => {
+                    notify_error(format!("{}", e));
+                    return;
+                }
+            };
+        let Some(first) = episodes.first().cloned() else {
+            notify_error(I18n::current().t("playlist.nothing_to_play"));
+            return;
+        };
+        audio_dispatch.reduce_mut(|state| {
+            state.playlist_playback = Some(PlaylistPlayback {
+                playlist_id,
+                episodes,
+            })
+        });
+        play_playlist_episode(
+            &first,
+            api_key.unwrap_or_default(),
+            user_id,
+            server_name,
+            audio_dispatch.clone(),
+            audio_dispatch.get(),
+        );
+    });
+}
+
+/// One line description of a smart playlist's rules, e.g. "Unplayed · up to 30 min · News".
+fn rules_summary(i18n: &I18n, rules: &PlaylistRules) -> String {
+    let mut parts = vec![i18n.t(&format!("playlist.play_state.{}", rules.play_state))];
+    match (rules.min_minutes, rules.max_minutes) {
+        (Some(min), Some(max)) => parts.push(i18n.tf(
+            "playlist.summary.between_minutes",
+            &[("min", &min.to_string()), ("max", &max.to_string())],
+        )),
+        (Some(min), None) => {
+            parts.push(i18n.tf("playlist.summary.min_minutes", &[("min", &min.to_string())]))
+        }
+        (None, Some(max)) => {
+            parts.push(i18n.tf("playlist.summary.max_minutes", &[("max", &max.to_string())]))
+        }
+        (None, None) => {}
+    }
+    if let Some(days) = rules.released_within_days {
+        parts.push(i18n.tn("playlist.summary.released_within", days as i64, &[]));
+    }
+    if let Some(category) = &rules.category {
+        parts.push(category.clone());
+    }
+    parts.push(i18n.t(&format!("playlist.sort.{}", rules.sort)));
+    parts.join(" · ")
+}
+
+fn playlist_subtitle(i18n: &I18n, playlist: &Playlist) -> String {
+    let count = i18n.tn("playlist.episode_count", playlist.episode_count as i64, &[]);
+    match (&playlist.rules, playlist.is_smart) {
+        (Some(rules), true) => format!("{} · {}", count, rules_summary(i18n, rules)),
+        _ => count,
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct PlaylistFormProps {
+    /// The playlist being edited; a new one is created when empty.
+    #[prop_or_default]
+    pub playlist: Option<Playlist>,
+    pub on_saved: Callback<i32>,
+    pub on_cancel: Callback<()>,
+}
+
+/// Name and, for smart playlists, rules. Used to create playlists and to edit them.
+#[function_component(PlaylistForm)]
+pub fn playlist_form(props: &PlaylistFormProps) -> Html {
+    let i18n = use_i18n();
+    let (state, dispatch) = use_store::<AppState>();
+    let name = use_state(|| {
+        props
+            .playlist
+            .as_ref()
+            .map(|playlist| playlist.name.clone())
+            .unwrap_or_default()
+    });
+    let is_smart = use_state(|| {
+        props
+            .playlist
+            .as_ref()
+            .map_or(false, |playlist| playlist.is_smart)
+    });
+    let rules = use_state(|| {
+        props
+            .playlist
+            .as_ref()
+            .and_then(|playlist| playlist.rules.clone())
+            .unwrap_or_default()
+    });
+    let saving = use_state(|| false);
+
+    let api_key = state
+        .auth_details
+        .as_ref()
+        .and_then(|ud| ud.api_key.clone());
+    let user_id = state.user_details.as_ref().map(|ud| ud.UserID);
+    let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
+
+    let on_name = {
+        let name = name.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                name.set(input.value());
+            }
+        })
+    };
+    let on_kind = {
+        let is_smart = is_smart.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                is_smart.set(select.value() == "smart");
+            }
+        })
+    };
+    let edit_rule = |edit: fn(&mut PlaylistRules, String)| {
+        let rules = rules.clone();
+        Callback::from(move |e: Event| {
+            let value = if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                select.value()
+            } else if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                input.value()
+            } else {
+                return;
+            };
+            let mut updated = (*rules).clone();
+            edit(&mut updated, value);
+            rules.set(updated);
+        })
+    };
+    let on_play_state = edit_rule(|rules, value| rules.play_state = value);
+    let on_min_minutes = edit_rule(|rules, value| rules.min_minutes = value.parse().ok());
+    let on_max_minutes = edit_rule(|rules, value| rules.max_minutes = value.parse().ok());
+    let on_released_within =
+        edit_rule(|rules, value| rules.released_within_days = value.parse().ok());
+    let on_category = edit_rule(|rules, value| {
+        rules.category = Some(value.trim().to_string()).filter(|value| !value.is_empty())
+    });
+    let on_sort = edit_rule(|rules, value| rules.sort = value);
+    let on_limit = edit_rule(|rules, value| rules.limit = value.parse().ok());
+
+    let on_submit = {
+        let name = name.clone();
+        let is_smart = is_smart.clone();
+        let rules = rules.clone();
+        let saving = saving.clone();
+        let editing = props.playlist.as_ref().map(|playlist| playlist.playlist_id);
+        let on_saved = props.on_saved.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let (Some(server_name), Some(user_id)) = (server_name.clone(), user_id) else {
+                return;
+            };
+            if name.trim().is_empty() {
+                notify_error(I18n::current().t("playlist.name_required"));
+                return;
+            }
+            let request = SavePlaylistRequest {
+                playlist_id: editing,
+                user_id,
+                name: name.trim().to_string(),
+                rules: is_smart.then(|| (*rules).clone()),
+            };
+            let api_key = api_key.clone();
+            let dispatch = dispatch.clone();
+            let saving = saving.clone();
+            let on_saved = on_saved.clone();
+            saving.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = match editing {
+                    Some(playlist_id) => call_update_playlist(&server_name, &api_key, &request)
+                        .await
+                        .map(|_| playlist_id),
+                    None => call_create_playlist(&server_name, &api_key, &request).await,
+                };
+                saving.set(false);
+                match result {
+                    Ok(playlist_id) => {
+                        notify_success(I18n::current().t("playlist.saved"));
+                        load_playlists(dispatch, server_name, api_key, user_id);
+                        on_saved.emit(playlist_id);
+                    }
+                    Err(e) => notify_error(format!("{}", e)),
+                }
+            });
+        })
+    };
+    let on_cancel = {
+        let on_cancel = props.on_cancel.clone();
+        Callback::from(move |_: MouseEvent| on_cancel.emit(()))
+    };
+
+    let number_value = |value: Option<u32>| value.map(|v| v.to_string()).unwrap_or_default();
+
+    html! {
+        <form class="playlist-form filter-dropdown mb-4" onsubmit={on_submit}>
+            <input
+                type="text"
+                class="search-bar-input border text-sm rounded-lg block w-full p-2.5"
+                placeholder={i18n.t("playlist.name_placeholder")}
+                value={(*name).clone()}
+                oninput={on_name}
+            />
+            // A playlist keeps its kind once created
+            if props.playlist.is_none() {
+                <select onchange={on_kind} title={i18n.t("playlist.kind")}>
+                    <option value="manual" selected={!*is_smart}>{ i18n.t("playlist.kind.manual") }</option>
+                    <option value="smart" selected={*is_smart}>{ i18n.t("playlist.kind.smart") }</option>
+                </select>
+            }
+            if *is_smart {
+                <div class="episode-filter-panel">
+                    <select onchange={on_play_state} title={i18n.t("filter.play_state")}>
+                        { for PLAY_STATES.iter().map(|play_state| html! {
+                            <option value={*play_state} selected={rules.play_state == *play_state}>
+                                { i18n.t(&format!("playlist.play_state.{}", play_state)) }
+                            </option>
+                        })}
+                    </select>
+                    <label class="episode-filter-range item_container-text">
+                        <span>{ i18n.t("filter.length") }</span>
+                        <input type="number" min="0" class="search-bar-input border text-sm rounded-lg p-2" placeholder={i18n.t("filter.min_minutes")} value={number_value(rules.min_minutes)} onchange={on_min_minutes} />
+                        <span>{"–"}</span>
+                        <input type="number" min="0" class="search-bar-input border text-sm rounded-lg p-2" placeholder={i18n.t("filter.max_minutes")} value={number_value(rules.max_minutes)} onchange={on_max_minutes} />
+                    </label>
+                    <label class="episode-filter-range item_container-text">
+                        <span>{ i18n.t("playlist.released_within") }</span>
+                        <input type="number" min="1" class="search-bar-input border text-sm rounded-lg p-2" placeholder={i18n.t("playlist.days")} value={number_value(rules.released_within_days)} onchange={on_released_within} />
+                    </label>
+                    <input
+                        type="text"
+                        class="search-bar-input border text-sm rounded-lg p-2.5"
+                        placeholder={i18n.t("playlist.category_placeholder")}
+                        value={rules.category.clone().unwrap_or_default()}
+                        onchange={on_category}
+                    />
+                    <select onchange={on_sort} title={i18n.t("filter.sort")}>
+                        { for SORTS.iter().map(|sort| html! {
+                            <option value={*sort} selected={rules.sort == *sort}>
+                                { i18n.t(&format!("playlist.sort.{}", sort)) }
+                            </option>
+                        })}
+                    </select>
+                    <label class="episode-filter-range item_container-text">
+                        <span>{ i18n.t("playlist.limit") }</span>
+                        <input type="number" min="1" class="search-bar-input border text-sm rounded-lg p-2" placeholder="100" value={number_value(rules.limit)} onchange={on_limit} />
+                    </label>
+                </div>
+            }
+            <div class="flex items-center space-x-2">
+                <button type="submit" class="settings-button font-bold py-2 px-4 rounded" disabled={*saving}>
+                    { i18n.t("playlist.save") }
+                </button>
+                <button type="button" class="download-button font-bold py-2 px-4 rounded" onclick={on_cancel}>
+                    { i18n.t("common.cancel") }
+                </button>
+            </div>
+        </form>
+    }
+}
+
+/// Keeps the reload check every page does before it trusts the stored session.
+#[hook]
+fn use_session_check() {
+    let (state, dispatch) = use_store::<AppState>();
+    use_effect_with((), move |_| {
+        if !state.reload_occured.unwrap_or(false) {
+            let window = web_sys::window().expect("no global `window` exists");
+            let performance = window.performance().expect("should have performance");
+            if performance.navigation().type_() == 1 {
+                // 1 stands for reload
+                let session_storage = window.session_storage().unwrap().unwrap();
+                session_storage
+                    .set_item("isAuthenticated", "false")
+                    .unwrap();
+            }
+            let current_route = window.location().href().unwrap_or_default();
+            use_check_authentication(dispatch.clone(), &current_route);
+            dispatch.reduce_mut(|state| state.reload_occured = Some(true));
+        }
+        || ()
+    });
+}
+
+#[function_component(Playlists)]
+pub fn playlists() -> Html {
+    let i18n = use_i18n();
+    use_session_check();
+    let (state, dispatch) = use_store::<AppState>();
+    let (audio_state, audio_dispatch) = use_store::<UIState>();
+    let creating = use_state(|| false);
+    let history = BrowserHistory::new();
+
+    let api_key = state
+        .auth_details
+        .as_ref()
+        .and_then(|ud| ud.api_key.clone());
+    let user_id = state.user_details.as_ref().map(|ud| ud.UserID);
+    let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
+
+    {
+        let dispatch = dispatch.clone();
+        use_effect_with(
+            (api_key.clone(), user_id, server_name.clone()),
+            move |(api_key, user_id, server_name)| {
+                if let (Some(server_name), Some(user_id)) = (server_name.clone(), *user_id) {
+                    load_playlists(dispatch, server_name, api_key.clone(), user_id);
+                }
+                || ()
+            },
+        );
+    }
+
+    let toggle_create = {
+        let creating = creating.clone();
+        Callback::from(move |_: MouseEvent| creating.set(!*creating))
+    };
+    let on_created = {
+        let creating = creating.clone();
+        let history = history.clone();
+        Callback::from(move |playlist_id: i32| {
+            creating.set(false);
+            history.push(format!("/playlist/{}", playlist_id));
+        })
+    };
+    let on_cancel = {
+        let creating = creating.clone();
+        Callback::from(move |_| creating.set(false))
+    };
+
+    let playlist_card = |playlist: &Playlist| {
+        let playlist_id = playlist.playlist_id;
+        let on_play_all = {
+            let server_name = server_name.clone();
+            let api_key = api_key.clone();
+            let audio_dispatch = audio_dispatch.clone();
+            Callback::from(move |_: MouseEvent| {
+                if let (Some(server_name), Some(user_id)) = (server_name.clone(), user_id) {
+                    play_all(
+                        server_name,
+                        api_key.clone(),
+                        user_id,
+                        playlist_id,
+                        audio_dispatch.clone(),
+                    );
+                }
+            })
+        };
+        let on_delete = {
+            let server_name = server_name.clone();
+            let api_key = api_key.clone();
+            let dispatch = dispatch.clone();
+            let name = playlist.name.clone();
+            Callback::from(move |_: MouseEvent| {
+                let (Some(server_name), Some(user_id)) = (server_name.clone(), user_id) else {
+                    return;
+                };
+                let i18n = I18n::current();
+                let confirmed = web_sys::window()
+                    .and_then(|window| {
+                        window
+                            .confirm_with_message(
+                                &i18n.tf("playlist.delete_confirm", &[("name", &name)]),
+                            )
+                            .ok()
+                    })
+                    .unwrap_or(false);
+                if !confirmed {
+                    return;
+                }
+                let api_key = api_key.clone();
+                let dispatch = dispatch.clone();
+                let request = PlaylistRequest {
+                    playlist_id,
+                    user_id,
+                };
+                wasm_bindgen_futures::spawn_local(async move {
+                    match call_delete_playlist(&server_name, &api_key, &request).await {
+                        Ok(()) => dispatch.reduce_mut(|state| {
+                            if let Some(playlists) = state.playlists.as_mut() {
+                                playlists.retain(|playlist| playlist.playlist_id != playlist_id);
+                            }
+                        }),
+                        Err(e) => notify_error(format!("{}", e)),
+                    }
+                });
+            })
+        };
+        html! {
+            <div class="item-container border-solid border flex items-center mb-4 shadow-md rounded-lg p-4">
+                <span class="material-icons item_container-text icon-space">
+                    { if playlist.is_smart { "auto_awesome" } else { "playlist_play" } }
+                </span>
+                <div class="flex flex-col flex-grow min-w-0">
+                    <Link<Route> to={Route::Playlist { playlist_id }} classes="item_container-text text-xl font-semibold">
+                        { playlist.name.clone() }
+                    </Link<Route>>
+                    <p class="item_container-text text-sm">{ playlist_subtitle(&i18n, playlist) }</p>
+                </div>
+                <button class="download-button rounded p-2 inline-flex items-center" title={i18n.t("playlist.play_all")} onclick={on_play_all}>
+                    <span class="material-icons">{"play_arrow"}</span>
+                </button>
+                <button class="download-button rounded p-2 ml-2 inline-flex items-center" title={i18n.t("playlist.delete")} onclick={on_delete}>
+                    <span class="material-icons">{"delete"}</span>
+                </button>
+            </div>
+        }
+    };
+
+    html! {
+        <>
+        <div class="main-container">
+            <Search_nav />
+            <UseScrollToTop />
+            <h1 class="text-2xl item_container-text font-bold text-center mb-6">{ i18n.t("playlist.title") }</h1>
+            if *creating {
+                <PlaylistForm on_saved={on_created} {on_cancel} />
+            } else {
+                <button class="download-button font-bold py-2 px-4 mb-4 rounded inline-flex items-center" onclick={toggle_create}>
+                    <span class="material-icons icon-space">{"playlist_add"}</span>
+                    <span class="text-lg">{ i18n.t("playlist.new") }</span>
+                </button>
+            }
+            {
+                match state.playlists.as_ref() {
+                    Some(playlists) if !playlists.is_empty() => html! {
+                        { for playlists.iter().map(playlist_card) }
+                    },
+                    _ => empty_message(
+                        &i18n.t("playlist.empty_title"),
+                        &i18n.t("playlist.empty_body"),
+                    ),
+                }
+            }
+            if let Some(audio_props) = &audio_state.currently_playing {
+                <AudioPlayer src={audio_props.src.clone()} title={audio_props.title.clone()} artwork_url={audio_props.artwork_url.clone()} duration={audio_props.duration.clone()} episode_id={audio_props.episode_id.clone()} duration_sec={audio_props.duration_sec.clone()} start_pos_sec={audio_props.start_pos_sec.clone()} end_pos_sec={audio_props.end_pos_sec.clone()} offline={audio_props.offline.clone()} />
+            }
+        </div>
+        <App_drawer />
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct PlaylistPageProps {
+    pub playlist_id: i32,
+}
+
+#[function_component(PlaylistPage)]
+pub fn playlist_page(props: &PlaylistPageProps) -> Html {
+    let i18n = use_i18n();
+    use_session_check();
+    let episode_filter = use_episode_filter("playlist");
+    let (state, dispatch) = use_store::<AppState>();
+    let (audio_state, audio_dispatch) = use_store::<UIState>();
+    let editing = use_state(|| false);
+    let loading = use_state(|| true);
+    let history = BrowserHistory::new();
+
+    let api_key = state
+        .auth_details
+        .as_ref()
+        .and_then(|ud| ud.api_key.clone());
+    let user_id = state.user_details.as_ref().map(|ud| ud.UserID);
+    let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
+    let playlist_id = props.playlist_id;
+
+    // Refetched after edits too, since changing a smart playlist's rules changes its episodes
+    let reload = {
+        let dispatch = dispatch.clone();
+        let loading = loading.clone();
+        let api_key = api_key.clone();
+        let server_name = server_name.clone();
+        Rc::new(move || {
+            let (Some(server_name), Some(user_id)) = (server_name.clone(), user_id) else {
+                return;
+            };
+            let dispatch = dispatch.clone();
+            let loading = loading.clone();
+            let api_key = api_key.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match call_get_playlist_episodes(&server_name, &api_key, user_id, playlist_id).await
+                {
+                    Ok(response) => {
+                        let completed: Vec<i32> = response
+                            .episodes
+                            .iter()
+                            .filter(|ep| ep.completed)
+                            .map(|ep| ep.episodeid)
+                            .collect();
+                        dispatch.reduce_mut(move |state| {
+                            state.playlist_episodes = Some(response);
+                            state.completed_episodes = Some(completed);
+                        });
+                    }
+                    Err(e) => {
+                        dispatch.reduce_mut(|state| state.playlist_episodes = None);
+                        notify_error(format!("{}", e));
+                    }
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with(
+            (api_key.clone(), user_id, server_name.clone(), playlist_id),
+            move |_| {
+                reload();
+                || ()
+            },
+        );
+    }
+
+    let open_playlist = state
+        .playlist_episodes
+        .as_ref()
+        .filter(|open| open.playlist.playlist_id == playlist_id);
+
+    let on_play_all = {
+        let server_name = server_name.clone();
+        let api_key = api_key.clone();
+        let audio_dispatch = audio_dispatch.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let (Some(server_name), Some(user_id)) = (server_name.clone(), user_id) {
+                play_all(
+                    server_name,
+                    api_key.clone(),
+                    user_id,
+                    playlist_id,
+                    audio_dispatch.clone(),
+                );
+            }
+        })
+    };
+    let toggle_edit = {
+        let editing = editing.clone();
+        Callback::from(move |_: MouseEvent| editing.set(!*editing))
+    };
+    let on_saved = {
+        let editing = editing.clone();
+        let reload = reload.clone();
+        Callback::from(move |_| {
+            editing.set(false);
+            reload();
+        })
+    };
+    let on_cancel = {
+        let editing = editing.clone();
+        Callback::from(move |_| editing.set(false))
+    };
+
+    let episode_list = match open_playlist {
+        Some(open) if open.episodes.is_empty() => {
+            if open.playlist.is_smart {
+                empty_message(
+                    &i18n.t("playlist.no_matches_title"),
+                    &i18n.t("playlist.no_matches_body"),
+                )
+            } else {
+                empty_message(
+                    &i18n.t("playlist.empty_episodes_title"),
+                    &i18n.t("playlist.empty_episodes_body"),
+                )
+            }
+        }
+        Some(open) => filtered_episode_list(
+            "playlist",
+            &episode_filter,
+            &state,
+            open.episodes.clone(),
+            |episode| {
+                let id_string = episode.episodeid.to_string();
+                let is_expanded = state.expanded_descriptions.contains(&id_string);
+                let sanitized_description =
+                    sanitize_html_with_blank_target(&episode.episodedescription);
+                let (description, _is_truncated) = if is_expanded {
+                    (sanitized_description, false)
+                } else {
+                    truncate_description(sanitized_description, 300)
+                };
+
+                let toggle_expanded = {
+                    let dispatch = dispatch.clone();
+                    let is_expanded = is_expanded;
+                    Callback::from(move |_: MouseEvent| {
+                        let guid = id_string.clone();
+                        if is_expanded {
+                            dispatch.apply(AppStateMsg::CollapseEpisode(guid));
+                        } else {
+                            dispatch.apply(AppStateMsg::ExpandEpisode(guid));
+                        }
+                    })
+                };
+
+                let on_play_click = on_play_click(
+                    episode.episodeurl.clone(),
+                    episode.episodetitle.clone(),
+                    episode.episodeartwork.clone(),
+                    episode.episodeduration,
+                    episode.episodeid,
+                    episode.listenduration,
+                    api_key.clone().unwrap_or_default(),
+                    user_id.unwrap_or_default(),
+                    server_name.clone().unwrap_or_default(),
+                    audio_dispatch.clone(),
+                    audio_state.clone(),
+                    None,
+                );
+
+                let on_shownotes_click = on_shownotes_click(
+                    history.clone(),
+                    dispatch.clone(),
+                    Some(episode.episodeid),
+                    Some(String::from("playlist")),
+                    Some(String::from("playlist")),
+                    Some(String::from("playlist")),
+                    true,
+                );
+
+                let date_format = match_date_format(state.date_format.as_deref());
+                let datetime = parse_date(&episode.episodepubdate, &state.user_tz);
+                let format_release =
+                    format_datetime(&datetime, &state.hour_preference, date_format);
+                let is_completed = state
+                    .completed_episodes
+                    .as_ref()
+                    .map_or(false, |completed| completed.contains(&episode.episodeid));
+                let episode_duration = episode.episodeduration;
+                let listen_duration = episode.listenduration;
+                let episode_url = episode.episodeurl.clone();
+                episode_item(
+                    Box::new(episode),
+                    description,
+                    is_expanded,
+                    &format_release,
+                    on_play_click,
+                    on_shownotes_click,
+                    toggle_expanded,
+                    episode_duration,
+                    listen_duration,
+                    "playlist",
+                    Callback::from(|_| {}),
+                    false,
+                    episode_url,
+                    is_completed,
+                )
+            },
+        ),
+        None if *loading => html! {},
+        None => empty_message(
+            &i18n.t("playlist.not_found_title"),
+            &i18n.t("playlist.not_found_body"),
+        ),
+    };
+
+    html! {
+        <>
+        <div class="main-container">
+            <Search_nav />
+            <UseScrollToTop />
+            if *loading && open_playlist.is_none() {
+                <div class="loading-animation">
+                    <div class="frame1"></div>
+                    <div class="frame2"></div>
+                    <div class="frame3"></div>
+                    <div class="frame4"></div>
+                    <div class="frame5"></div>
+                    <div class="frame6"></div>
+                </div>
+            }
+            if let Some(open) = open_playlist {
+                <div class="text-center mb-6">
+                    <h1 class="text-2xl item_container-text font-bold">{ &open.playlist.name }</h1>
+                    <p class="item_container-text text-sm">
+                        {
+                            match (&open.playlist.rules, open.playlist.is_smart) {
+                                (Some(rules), true) => rules_summary(&i18n, rules),
+                                _ => i18n.t("playlist.kind.manual"),
+                            }
+                        }
+                    </p>
+                </div>
+                <div class="flex items-center space-x-2 mb-4">
+                    <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center" onclick={on_play_all} disabled={open.episodes.is_empty()}>
+                        <span class="material-icons icon-space">{"play_arrow"}</span>
+                        <span class="text-lg">{ i18n.t("playlist.play_all") }</span>
+                    </button>
+                    <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center" onclick={toggle_edit}>
+                        <span class="material-icons icon-space">{"edit"}</span>
+                        <span class="text-lg">{ i18n.t("playlist.edit") }</span>
+                    </button>
+                </div>
+                if *editing {
+                    <PlaylistForm playlist={Some(open.playlist.clone())} {on_saved} {on_cancel} />
+                }
+            }
+            { episode_list }
+            if let Some(audio_props) = &audio_state.currently_playing {
+                <AudioPlayer src={audio_props.src.clone()} title={audio_props.title.clone()} artwork_url={audio_props.artwork_url.clone()} duration={audio_props.duration.clone()} episode_id={audio_props.episode_id.clone()} duration_sec={audio_props.duration_sec.clone()} start_pos_sec={audio_props.start_pos_sec.clone()} end_pos_sec={audio_props.end_pos_sec.clone()} offline={audio_props.offline.clone()} />
+            }
+        </div>
+        <App_drawer />
+        </>
+    }
+}
@@ -2,7 +2,9 @@ use super::app_drawer::App_drawer;
 use super::gen_components::{empty_message, Search_nav, UseScrollToTop};
 use crate::components::audio::AudioPlayer;
 use crate::components::context::{AppState, ExpandedDescriptions, UIState};
+use crate::components::i18n::{use_i18n, I18n};
 use crate::components::episodes_layout::SafeHtml;
+use crate::components::notifications::{notify_error, notify_info};
 use crate::requests::login_requests::use_check_authentication;
 use crate::requests::pod_req::{
     call_add_podcast, call_check_podcast, call_remove_podcasts_name, PodcastValues,
@@ -35,6 +37,7 @@ pub struct ClickedFeedURL {
 
 #[function_component(PodLayout)]
 pub fn pod_layout() -> Html {
+    let i18n = use_i18n();
     // let dispatch = Dispatch::<AppState>::global();
     // let state: Rc<AppState> = dispatch.get();
     let (state, dispatch) = use_store::<AppState>();
@@ -82,7 +85,7 @@ pub fn pod_layout() -> Html {
             <div class="main-container">
                 <Search_nav />
                 <UseScrollToTop />
-                <h1 class="item_container-text text-2xl font-bold my-4 center-text">{ "Podcast Search Results" }</h1>
+                <h1 class="item_container-text text-2xl font-bold my-4 center-text">{ i18n.t("podcast_search.title") }</h1>
                 {
                     if let Some(results) = search_results {
                         let podcasts = results.feeds.as_ref().map_or_else(
@@ -132,6 +135,7 @@ pub struct PodcastProps {
 // Assuming you have a PodcastItem component
 #[function_component(PodcastItem)]
 pub fn podcast_item(props: &PodcastProps) -> Html {
+    let i18n = use_i18n();
     // Local state to track if this particular podcast is added
     let is_added = use_state(|| false);
     let podcast = props.podcast.clone();
@@ -258,17 +262,11 @@ pub fn podcast_item(props: &PodcastProps) -> Html {
                             let mut new_set = current_set.clone();
                             new_set.remove(&podcast_url);
                             added_podcasts.set(new_set);
-                            dispatch.reduce_mut(|state| {
-                                state.info_message =
-                                    Some("Podcast successfully removed".to_string());
-                            });
+                            notify_info(I18n::current().t("podcast.removed"));
                             dispatch.reduce_mut(|state| state.is_loading = Some(false));
                         }
                         Err(e) => {
-                            dispatch.reduce_mut(|state| {
-                                state.error_message =
-                                    Some(format!("Error removing podcast: {:?}", e));
-                            });
+                            notify_error(I18n::current().tf("podcast.remove_error", &[("error", &format!("{:?}", e))]));
                             dispatch.reduce_mut(|state| state.is_loading = Some(false));
                         }
                     }
@@ -321,16 +319,11 @@ pub fn podcast_item(props: &PodcastProps) -> Html {
                             let mut new_set = current_set.clone();
                             new_set.insert(podcast_url.clone());
                             added_podcasts.set(new_set);
-                            dispatch.reduce_mut(|state| {
-                                state.info_message = Some("Podcast successfully added".to_string());
-                            });
+                            notify_info(I18n::current().t("podcast.added"));
                             dispatch.reduce_mut(|state| state.is_loading = Some(false));
                         }
                         Err(e) => {
-                            dispatch.reduce_mut(|state| {
-                                state.error_message =
-                                    Some(format!("Error adding podcast: {:?}", e));
-                            });
+                            notify_error(I18n::current().tf("podcast.add_error", &[("error", &format!("{:?}", e))]));
                             dispatch.reduce_mut(|state| state.is_loading = Some(false));
                         }
                     }
@@ -453,7 +446,7 @@ pub fn podcast_item(props: &PodcastProps) -> Html {
                             <img
                                 src={podcast.image.clone()}
                                 onclick={on_title_click.clone()}
-                                alt={format!("Cover for {}", podcast.title.clone())}
+                                alt={i18n.tf("podcast.cover_alt", &[("podcast", &podcast.title)])}
                                 class="object-cover align-top-cover w-full item-container img"
                             />
                         </div>
@@ -475,7 +468,7 @@ pub fn podcast_item(props: &PodcastProps) -> Html {
                                 }
                             }
 
-                            <p class="header-text">{ format!("Episode Count: {}", &podcast.episodeCount) }</p>
+                            <p class="header-text">{ i18n.tf("podcast.episode_count", &[("count", &i18n.number(podcast.episodeCount as f64))]) }</p>
                         </div>
                         <button 
                             class={format!("item-container-button border selector-button font-bold py-2 px-4 rounded-full self-center mr-8")} 
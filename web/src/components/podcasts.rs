@@ -1,33 +1,185 @@
 use super::app_drawer::App_drawer;
 use crate::components::audio::AudioPlayer;
 use crate::components::click_events::create_on_title_click;
-use crate::components::context::{AppState, ExpandedDescriptions, FilterState, UIState};
+use crate::components::context::{
+    AppState, ExpandedDescriptions, FilterState, LibraryView, UIState,
+};
+use crate::components::i18n::{use_i18n, I18n};
 use crate::components::episodes_layout::SafeHtml;
 use crate::components::gen_components::{Search_nav, UseScrollToTop};
+use crate::components::notifications::{notify_error, notify_success};
 use crate::requests::login_requests::use_check_authentication;
 use crate::requests::pod_req;
 use crate::requests::pod_req::Podcast;
-use crate::requests::pod_req::{call_remove_podcasts, PodcastResponse, RemovePodcastValues};
+use crate::requests::pod_req::{
+    call_add_category, call_delete_selected_podcasts, call_enable_auto_download,
+    AddCategoryRequest, AutoDownloadRequest, DeleteSelectedPodcastsRequest, PodcastResponse,
+};
 use crate::requests::setting_reqs::call_add_custom_feed;
-use gloo_timers::callback::Timeout;
-use serde::Deserialize;
-use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+use std::future::Future;
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
-use web_sys::HtmlInputElement;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 use yew::{function_component, html, Html};
 use yew_router::history::BrowserHistory;
 use yewdux::prelude::*;
 
 // Add this enum to define the layout options
-#[derive(Clone, PartialEq, Debug, Deserialize, Default)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Default)]
 pub enum PodcastLayout {
     #[default]
     List,
     Grid,
 }
 
+/// Orders the library can be sorted in.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize, Default)]
+pub enum LibrarySort {
+    #[default]
+    Name,
+    LastEpisode,
+    Unplayed,
+    DateAdded,
+    ListenTime,
+}
+
+impl LibrarySort {
+    pub const ALL: [LibrarySort; 5] = [
+        LibrarySort::Name,
+        LibrarySort::LastEpisode,
+        LibrarySort::Unplayed,
+        LibrarySort::DateAdded,
+        LibrarySort::ListenTime,
+    ];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            LibrarySort::Name => "name",
+            LibrarySort::LastEpisode => "last_episode",
+            LibrarySort::Unplayed => "unplayed",
+            LibrarySort::DateAdded => "date_added",
+            LibrarySort::ListenTime => "listen_time",
+        }
+    }
+
+    fn compare(self, a: &Podcast, b: &Podcast) -> Ordering {
+        match self {
+            LibrarySort::Name => Ordering::Equal,
+            // Release dates come back as ISO 8601, so they order correctly as text
+            LibrarySort::LastEpisode => a.lastepisodedate.cmp(&b.lastepisodedate),
+            LibrarySort::Unplayed => a.unplayedcount.cmp(&b.unplayedcount),
+            // Podcast ids are handed out in the order podcasts were added
+            LibrarySort::DateAdded => a.podcastid.cmp(&b.podcastid),
+            LibrarySort::ListenTime => a.totallistentime.cmp(&b.totallistentime),
+        }
+    }
+}
+
+fn podcast_categories(podcast: &Podcast) -> Vec<String> {
+    podcast
+        .categories
+        .split(',')
+        .map(|category| category.trim().to_string())
+        .filter(|category| !category.is_empty() && category != "{}")
+        .collect()
+}
+
+/// The podcasts the library view shows, sorted, and split into one group per category when
+/// grouping is on. A podcast is listed under each of its categories; ones without any come
+/// last under `None`.
+fn arrange_podcasts(
+    podcasts: &[Podcast],
+    view: &LibraryView,
+) -> Vec<(Option<String>, Vec<Podcast>)> {
+    let mut podcasts: Vec<Podcast> = podcasts
+        .iter()
+        .filter(|podcast| {
+            view.category.as_ref().map_or(true, |category| {
+                podcast_categories(podcast).contains(category)
+            })
+        })
+        .cloned()
+        .collect();
+    podcasts.sort_by(|a, b| {
+        let order = view.sort.compare(a, b).then_with(|| {
+            a.podcastname
+                .to_lowercase()
+                .cmp(&b.podcastname.to_lowercase())
+        });
+        if view.descending {
+            order.reverse()
+        } else {
+            order
+        }
+    });
+
+    if !view.group_by_category {
+        return vec![(None, podcasts)];
+    }
+    let mut groups: BTreeMap<String, Vec<Podcast>> = BTreeMap::new();
+    let mut uncategorized = Vec::new();
+    for podcast in podcasts {
+        let categories = podcast_categories(&podcast);
+        if categories.is_empty() {
+            uncategorized.push(podcast);
+            continue;
+        }
+        for category in categories {
+            groups.entry(category).or_default().push(podcast.clone());
+        }
+    }
+    let mut arranged: Vec<(Option<String>, Vec<Podcast>)> = groups
+        .into_iter()
+        .map(|(category, podcasts)| (Some(category), podcasts))
+        .collect();
+    if !uncategorized.is_empty() {
+        arranged.push((None, uncategorized));
+    }
+    arranged
+}
+
+/// Runs `action` for each podcast in turn, then hands the ids it worked for to `done` and
+/// reports how it went.
+fn run_bulk_action<F, Fut>(
+    podcast_ids: Vec<i32>,
+    busy: UseStateHandle<bool>,
+    action: F,
+    done: impl FnOnce(Vec<i32>) + 'static,
+) where
+    F: Fn(i32) -> Fut + 'static,
+    Fut: Future<Output = bool>,
+{
+    busy.set(true);
+    wasm_bindgen_futures::spawn_local(async move {
+        let total = podcast_ids.len();
+        let mut succeeded = Vec::new();
+        for podcast_id in podcast_ids {
+            if action(podcast_id).await {
+                succeeded.push(podcast_id);
+            }
+        }
+        report_bulk_result(total, succeeded.len());
+        done(succeeded);
+        busy.set(false);
+    });
+}
+
+/// Tells the user how many podcasts a bulk action worked for, and how many it didn't.
+fn report_bulk_result(total: usize, succeeded: usize) {
+    let i18n = I18n::current();
+    let failed = total - succeeded;
+    if succeeded > 0 {
+        notify_success(i18n.tn("podcasts.bulk_done", succeeded as i64, &[]));
+    }
+    if failed > 0 {
+        notify_error(i18n.tn("podcasts.bulk_failed", failed as i64, &[]));
+    }
+}
+
 enum AppStateMsg {
     // ... other messages ...
     RemovePodcast(i32), // Add this line
@@ -59,17 +211,19 @@ impl Reducer<AppState> for AppStateMsg {
     }
 }
 
-fn render_layout_toggle(
-    dispatch: Dispatch<AppState>,
-    current_layout: Option<PodcastLayout>,
-) -> Html {
-    let onclick = dispatch.reduce_mut_callback(|state| {
-        state.podcast_layout = match state.podcast_layout {
-            Some(PodcastLayout::List) => Some(PodcastLayout::Grid),
-            Some(PodcastLayout::Grid) => Some(PodcastLayout::List),
-            None => Some(PodcastLayout::Grid),
-        };
-    });
+fn render_layout_toggle(view: &LibraryView, set_view: Callback<LibraryView>) -> Html {
+    let onclick = {
+        let view = view.clone();
+        Callback::from(move |_: MouseEvent| {
+            set_view.emit(LibraryView {
+                layout: match view.layout {
+                    PodcastLayout::List => PodcastLayout::Grid,
+                    PodcastLayout::Grid => PodcastLayout::List,
+                },
+                ..view.clone()
+            })
+        })
+    };
 
     html! {
         <button
@@ -77,20 +231,19 @@ fn render_layout_toggle(
             onclick={onclick}
         >
             <span class="material-icons icon-space">
-                {match current_layout {
-                    Some(PodcastLayout::List) => "grid_view",
-                    Some(PodcastLayout::Grid) => "view_list",
-                    None => "view_list",
+                {match view.layout {
+                    PodcastLayout::List => "grid_view",
+                    PodcastLayout::Grid => "view_list",
                 }}
             </span>
-            <span class="text-lg">{"Toggle Layout"}</span>
+            <span class="text-lg">{ I18n::current().t("podcasts.toggle_layout") }</span>
         </button>
     }
 }
 
 fn render_podcasts(
     podcasts: &[Podcast],
-    layout: Option<PodcastLayout>,
+    layout: &PodcastLayout,
     dispatch: Dispatch<AppState>,
     history: &BrowserHistory,
     api_key: Option<Option<String>>,
@@ -98,9 +251,12 @@ fn render_podcasts(
     user_id: Option<i32>,
     desc_state: Rc<ExpandedDescriptions>,
     desc_dispatch: Dispatch<ExpandedDescriptions>,
+    selection: Option<&HashSet<i32>>,
+    on_select: Callback<i32>,
 ) -> Html {
+    let i18n = I18n::current();
     match layout {
-        None | Some(PodcastLayout::List) => {
+        PodcastLayout::List => {
             html! {
                 <div>
                     {podcasts.iter().map(|podcast| {
@@ -162,11 +318,16 @@ fn render_podcasts(
 
                         html! {
                             <div class="item-container border-solid border flex items-start mb-4 shadow-md rounded-lg h-full">
+                                if let Some(selected) = selection {
+                                    <input type="checkbox" class="form-checkbox h-5 w-5 self-center ml-4"
+                                        checked={selected.contains(&podcast_id_loop)}
+                                        onchange={on_select.reform(move |_| podcast_id_loop)} />
+                                }
                                 <div class="flex flex-col w-auto object-cover pl-4">
                                     <img
                                         src={podcast.artworkurl.clone()}
                                         onclick={on_title_click.clone()}
-                                        alt={format!("Cover for {}", podcast.podcastname.clone())}
+                                        alt={i18n.tf("podcast.cover_alt", &[("podcast", &podcast.podcastname)])}
                                         class="episode-image"
                                     />
                                 </div>
@@ -184,7 +345,12 @@ fn render_podcasts(
                                             <SafeHtml html={podcast_description_clone.unwrap_or_default()} />
                                         </div>
                                     </div>
-                                    <p class="item_container-text">{ format!("Episode Count: {}", &podcast.episodecount) }</p>
+                                    <p class="item_container-text">
+                                        { i18n.tn("podcasts.episode_count", podcast.episodecount as i64, &[]) }
+                                        if podcast.unplayedcount > 0 {
+                                            { " · " }{ i18n.tn("podcasts.unplayed_count", podcast.unplayedcount as i64, &[]) }
+                                        }
+                                    </p>
                                 </div>
                                 <button
                                     class={"item-container-button border selector-button font-bold py-2 px-4 rounded-full self-center mr-8"}
@@ -212,10 +378,11 @@ fn render_podcasts(
                 </div>
             }
         }
-        Some(PodcastLayout::Grid) => {
+        PodcastLayout::Grid => {
             html! {
                 <div class="podcast-grid">
                     {podcasts.iter().map(|podcast| {
+                        let podcast_id = podcast.podcastid;
                         let on_click = create_on_title_click(
                             dispatch.clone(),
                             server_name.clone().unwrap(),
@@ -232,15 +399,25 @@ fn render_podcasts(
                             podcast.websiteurl.clone().unwrap_or_else(|| String::from("No Website Provided")),
                             user_id.unwrap(),
                         );
+                        // While selecting, a click picks the podcast instead of opening it
+                        let is_selected = selection.map_or(false, |selected| selected.contains(&podcast_id));
+                        let on_click = if selection.is_some() {
+                            on_select.reform(move |_: MouseEvent| podcast_id)
+                        } else {
+                            on_click
+                        };
                         html! {
                             <div
-                                class="podcast-grid-item"
+                                class={classes!("podcast-grid-item", is_selected.then_some("podcast-grid-item-selected"))}
                                 onclick={on_click}
                             >
+                                if is_selected {
+                                    <span class="material-icons podcast-grid-check">{"check_circle"}</span>
+                                }
                                 <div class="podcast-image-container">
                                     <img
                                         src={podcast.artworkurl.clone().unwrap_or_default()}
-                                        alt={format!("Cover for {}", podcast.podcastname)}
+                                        alt={i18n.tf("podcast.cover_alt", &[("podcast", &podcast.podcastname)])}
                                         class="podcast-image"
                                     />
                                 </div>
@@ -258,6 +435,7 @@ fn render_podcasts(
 
 #[function_component(Podcasts)]
 pub fn podcasts() -> Html {
+    let i18n = use_i18n();
     let (state, dispatch) = use_store::<AppState>();
     let (audio_state, _audio_dispatch) = use_store::<UIState>();
     let (desc_state, desc_dispatch) = use_store::<ExpandedDescriptions>();
@@ -268,11 +446,6 @@ pub fn podcasts() -> Html {
     let feed_url = use_state(|| "".to_string());
     let pod_user = use_state(|| "".to_string());
     let pod_pass = use_state(|| "".to_string());
-    let error_message = use_state(|| None::<String>);
-    let info_message = use_state(|| None::<String>);
-
-    // filter selections
-    let selected_category = use_state(|| None as Option<String>);
 
     let session_dispatch = dispatch.clone();
     let session_state = state.clone();
@@ -312,6 +485,37 @@ pub fn podcasts() -> Html {
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
+
+    // Sorting, grouping, layout and category filter, remembered for each account
+    let view = use_state(LibraryView::default);
+    {
+        let view = view.clone();
+        use_effect_with(
+            (server_name.clone(), user_id),
+            move |(server_name, user_id)| {
+                if let (Some(server_name), Some(user_id)) = (server_name, user_id) {
+                    view.set(LibraryView::load(server_name, *user_id));
+                }
+                || ()
+            },
+        );
+    }
+    let set_view = {
+        let view = view.clone();
+        let server_name = server_name.clone();
+        Callback::from(move |next: LibraryView| {
+            if let (Some(server_name), Some(user_id)) = (server_name.as_ref(), user_id) {
+                next.store(server_name, user_id);
+            }
+            view.set(next);
+        })
+    };
+
+    // Some(selected ids) while picking podcasts for a bulk action
+    let selection = use_state(|| None::<HashSet<i32>>);
+    let bulk_busy = use_state(|| false);
+    let bulk_category = use_state(String::new);
+
     // Fetch podcasts on component mount
     let filter_effect = filter_dispatch.clone();
     {
@@ -384,7 +588,7 @@ pub fn podcasts() -> Html {
     }
 
     let page_state = use_state(|| PageState::Hidden);
-    let podcast_to_delete = use_state(|| None::<i32>);
+    let podcasts_to_delete = use_state(Vec::<i32>::new);
 
     let on_close_modal = {
         let page_state = page_state.clone();
@@ -410,53 +614,44 @@ pub fn podcasts() -> Html {
 
     let on_remove_click = {
         let dispatch_remove = dispatch.clone();
-        let podcast_to_delete = podcast_to_delete.clone();
+        let podcasts_to_delete = podcasts_to_delete.clone();
+        let selection = selection.clone();
+        let bulk_busy = bulk_busy.clone();
         let user_id = user_id.clone();
         let api_key_rm = api_key.clone();
         let server_name = server_name.clone();
         let on_close_remove = on_close_modal.clone();
 
         Callback::from(move |_: MouseEvent| {
-            if let Some(podcast_id) = *podcast_to_delete {
+            let podcast_ids = (*podcasts_to_delete).clone();
+            if let (Some(server_name), Some(api_key), Some(user_id), false) = (
+                server_name.clone(),
+                api_key_rm.clone(),
+                user_id,
+                podcast_ids.is_empty(),
+            ) {
                 let dispatch_call = dispatch_remove.clone();
-                let api_key_call = api_key_rm.clone();
-                let server_name_call = server_name.clone();
-                let user_id_call = user_id.unwrap();
-
-                let remove_values = RemovePodcastValues {
-                    podcast_id,
-                    user_id: user_id_call,
-                };
-
+                let selection = selection.clone();
+                let bulk_busy = bulk_busy.clone();
+                bulk_busy.set(true);
+                // One request for the whole selection, rather than one per podcast
                 wasm_bindgen_futures::spawn_local(async move {
-                    match call_remove_podcasts(
-                        &server_name_call.unwrap(),
-                        &api_key_call.unwrap(),
-                        &remove_values,
-                    )
-                    .await
-                    {
-                        Ok(success) => {
-                            if success {
-                                dispatch_call.apply(AppStateMsg::RemovePodcast(podcast_id));
-                                dispatch_call.reduce_mut(|state| {
-                                    state.info_message =
-                                        Some("Podcast successfully removed".to_string())
-                                });
-                            } else {
-                                dispatch_call.reduce_mut(|state| {
-                                    state.error_message =
-                                        Some("Failed to remove podcast".to_string())
-                                });
-                            }
-                        }
-                        Err(e) => {
-                            dispatch_call.reduce_mut(|state| {
-                                state.error_message =
-                                    Some(format!("Error removing podcast: {:?}", e))
-                            });
-                        }
+                    let total = podcast_ids.len();
+                    let request = DeleteSelectedPodcastsRequest {
+                        delete_list: podcast_ids,
+                        user_id,
+                    };
+                    let removed = call_delete_selected_podcasts(&server_name, &api_key, &request)
+                        .await
+                        .unwrap_or_default();
+                    report_bulk_result(total, removed.len());
+                    for podcast_id in removed {
+                        dispatch_call.apply(AppStateMsg::RemovePodcast(podcast_id));
+                    }
+                    if selection.is_some() {
+                        selection.set(Some(HashSet::new()));
                     }
+                    bulk_busy.set(false);
                 });
             }
             on_close_remove.emit(MouseEvent::new("click").unwrap());
@@ -470,25 +665,25 @@ pub fn podcasts() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Delete Podcast"}
+                            { i18n.tn("podcasts.delete_title", podcasts_to_delete.len() as i64, &[]) }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <div>
-                                <label for="download_schedule" class="block mb-2 text-sm font-medium">{"Are you sure you want to delete the podcast from the database? This will remove it from every aspect of the app. Meaning this will remove any saved, downloaded, or queued episodes for this podcast. It will also remove any history that includes it."}</label>
+                                <label for="download_schedule" class="block mb-2 text-sm font-medium">{ i18n.tn("podcasts.delete_body", podcasts_to_delete.len() as i64, &[]) }</label>
                                 <div class="flex justify-between space-x-4">
-                                    <button onclick={on_remove_click} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                                        {"Yes, Delete Podcast"}
+                                    <button type="button" onclick={on_remove_click} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
+                                        { i18n.tn("podcasts.delete_confirm", podcasts_to_delete.len() as i64, &[]) }
                                     </button>
-                                    <button onclick={on_close_modal.clone()} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                                        {"No, take me back"}
+                                    <button type="button" onclick={on_close_modal.clone()} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
+                                        { i18n.t("podcasts.delete_cancel") }
                                     </button>
                                 </div>
                             </div>
@@ -499,12 +694,16 @@ pub fn podcasts() -> Html {
         </div>
     };
 
-    let toggle_delete = {
+    let confirm_bulk_delete = {
         let page_state = page_state.clone();
-        let podcast_to_delete = podcast_to_delete.clone();
-        Callback::from(move |podcast_id: i32| {
-            podcast_to_delete.set(Some(podcast_id));
-            page_state.set(PageState::Delete);
+        let podcasts_to_delete = podcasts_to_delete.clone();
+        let selection = selection.clone();
+        Callback::from(move |_: MouseEvent| {
+            let selected: Vec<i32> = selection.iter().flatten().copied().collect();
+            if !selected.is_empty() {
+                podcasts_to_delete.set(selected);
+                page_state.set(PageState::Delete);
+            }
         })
     };
 
@@ -530,21 +729,6 @@ pub fn podcasts() -> Html {
             pod_pass.set(input.value());
         })
     };
-    // Function to clear message
-    let clear_error = {
-        let error_message = error_message.clone();
-        Callback::from(move |_| {
-            error_message.set(None);
-        })
-    };
-
-    let clear_info = {
-        let info_message = info_message.clone();
-        Callback::from(move |_| {
-            info_message.set(None);
-        })
-    };
-
     // Ensure `onclick_restore` is correctly used
     let custom_loading = is_loading.clone();
     let add_custom_feed = {
@@ -553,21 +737,13 @@ pub fn podcasts() -> Html {
         let server_name = server_name.clone().unwrap_or_default();
         let user_id = user_id;
         let feed_url = (*feed_url).clone();
-        let error_message = error_message.clone();
-        let info_message = info_message.clone();
-        let clear_info = clear_info.clone();
-        let clear_error = clear_error.clone();
         let is_loading_call = custom_loading.clone();
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
             let dispatch_call = dispatch_remove.clone();
-            let clear_info = clear_info.clone();
-            let clear_error = clear_error.clone();
             let server_name = server_name.clone();
             let api_key = api_key.clone();
             let feed_url = feed_url.clone();
-            let error_message = error_message.clone();
-            let info_message = info_message.clone();
             is_loading_call.set(true);
             let is_loading_wasm = is_loading_call.clone();
             let unstate_pod_user = (*pod_user).clone();
@@ -584,7 +760,7 @@ pub fn podcasts() -> Html {
                 .await
                 {
                     Ok(new_podcast) => {
-                        info_message.set(Some("Podcast Successfully Added".to_string()));
+                        notify_success(I18n::current().t("custom_feed.added"));
                         dispatch_call.reduce_mut(move |state| {
                             if let Some(ref mut podcast_response) = state.podcast_feed_return {
                                 if let Some(ref mut pods) = podcast_response.pods {
@@ -600,11 +776,9 @@ pub fn podcasts() -> Html {
                                 });
                             }
                         });
-                        Timeout::new(5000, move || clear_info.emit(())).forget();
                     }
                     Err(e) => {
-                        error_message.set(Some(e.to_string()));
-                        Timeout::new(5000, move || clear_error.emit(())).forget();
+                        notify_error(e.to_string());
                     }
                 }
                 is_loading_wasm.set(false);
@@ -619,19 +793,19 @@ pub fn podcasts() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Add Custom Podcast"}
+                            { i18n.t("custom_feed.modal_title") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <div>
-                                <label for="download_schedule" class="block mb-2 text-sm font-medium">{"Simply enter the feed url, optional credentials, and click the button below. This is great in case you subscibe to premium podcasts and they aren't availble in The Pocast Index or other indexing services."}</label>
+                                <label for="download_schedule" class="block mb-2 text-sm font-medium">{ i18n.t("custom_feed.modal_body") }</label>
                                 <div class="justify-between space-x-4">
                                     <div>
                                         <input id="feed_url" oninput={update_feed.clone()} class="search-bar-input border text-sm rounded-lg block w-full p-2.5" placeholder="https://bestpodcast.com/feed.xml" />
@@ -639,29 +813,20 @@ pub fn podcasts() -> Html {
                                 </div>
                                 <div class="flex justify-between space-x-4">
                                     <div>
-                                        <input id="username" oninput={update_pod_user.clone()} class="search-bar-input border text-sm rounded-lg block w-full p-2.5 mt-2" placeholder="Username (optional)" />
+                                        <input id="username" oninput={update_pod_user.clone()} class="search-bar-input border text-sm rounded-lg block w-full p-2.5 mt-2" placeholder={i18n.t("custom_feed.username_placeholder")} />
                                     </div>
                                     <div>
-                                        <input id="password" type="password" oninput={update_pod_pass.clone()} class="search-bar-input border text-sm rounded-lg block w-full p-2.5 mt-2" placeholder="Password (optional)" />
+                                        <input id="password" type="password" oninput={update_pod_pass.clone()} class="search-bar-input border text-sm rounded-lg block w-full p-2.5 mt-2" placeholder={i18n.t("custom_feed.password_placeholder")} />
                                     </div>
                                 </div>
                                 <div>
                                     <button onclick={add_custom_feed} class="mt-2 settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline" disabled={*is_loading}>
-                                    {"Add Feed"}
+                                    { i18n.t("custom_feed.add") }
                                     if *is_loading {
                                         <span class="ml-2 spinner-border animate-spin inline-block w-4 h-4 border-2 rounded-full"></span>
                                     }
                                     </button>
                                 </div>
-                                <div>
-                                if let Some(error) = &*error_message {
-                                    <span class="text-red-600 text-xs">{ error }</span>
-                                }
-                                // Display informational message inline right below the text input
-                                if let Some(info) = &*info_message {
-                                    <span class="text-green-600 text-xs">{ info }</span>
-                                }
-                                </div>
                             </div>
                         </form>
                     </div>
@@ -685,36 +850,215 @@ pub fn podcasts() -> Html {
         })
     };
 
-    // Create a memoized filtered podcast list
-    let filtered_pods = use_memo(
-        (state.podcast_feed_return.clone(), selected_category.clone()),
-        |(podcasts, selected_cat)| {
-            if let Some(pods) = podcasts.as_ref().and_then(|p| p.pods.as_ref()) {
-                if let Some(cat) = selected_cat.as_ref() {
-                    pods.iter()
-                        .filter(|podcast| podcast.categories.split(',').any(|c| c.trim() == cat))
-                        .cloned()
-                        .collect::<Vec<_>>()
-                } else {
-                    pods.clone()
-                }
-            } else {
-                vec![]
-            }
+    // Create a memoized filtered podcast list, sorted and grouped the way the view asks
+    let arranged_pods = use_memo(
+        (state.podcast_feed_return.clone(), (*view).clone()),
+        |(podcasts, view)| {
+            let pods = podcasts
+                .as_ref()
+                .and_then(|p| p.pods.as_ref())
+                .cloned()
+                .unwrap_or_default();
+            arrange_podcasts(&pods, view)
         },
     );
+    let no_matches = arranged_pods.iter().all(|(_, pods)| pods.is_empty());
+
+    let view_change = |edit: fn(&mut LibraryView, String)| {
+        let view = view.clone();
+        let set_view = set_view.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                let mut next = (*view).clone();
+                edit(&mut next, select.value());
+                set_view.emit(next);
+            }
+        })
+    };
+    let on_filter_click = view_change(|view, category| {
+        view.category = Some(category).filter(|category| !category.is_empty())
+    });
+    let on_sort_change = view_change(|view, code| {
+        view.sort = LibrarySort::ALL
+            .into_iter()
+            .find(|sort| sort.code() == code)
+            .unwrap_or_default()
+    });
+    let view_toggle = |edit: fn(&mut LibraryView)| {
+        let view = view.clone();
+        let set_view = set_view.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*view).clone();
+            edit(&mut next);
+            set_view.emit(next);
+        })
+    };
+    let toggle_direction = view_toggle(|view| view.descending = !view.descending);
+    let toggle_grouping = view_toggle(|view| view.group_by_category = !view.group_by_category);
+    let clear_filter = view_toggle(|view| view.category = None);
 
-    let on_filter_click = {
-        let selected_category = selected_category.clone();
-        Callback::from(move |category: String| {
-            selected_category.set(Some(category.clone()));
+    let toggle_selection = {
+        let selection = selection.clone();
+        Callback::from(move |_: MouseEvent| {
+            selection.set(match *selection {
+                Some(_) => None,
+                None => Some(HashSet::new()),
+            })
         })
     };
+    let on_select = {
+        let selection = selection.clone();
+        Callback::from(move |podcast_id: i32| {
+            if let Some(selected) = selection.as_ref() {
+                let mut selected = selected.clone();
+                if !selected.remove(&podcast_id) {
+                    selected.insert(podcast_id);
+                }
+                selection.set(Some(selected));
+            }
+        })
+    };
+    let select_all = {
+        let selection = selection.clone();
+        let arranged_pods = arranged_pods.clone();
+        Callback::from(move |_: MouseEvent| {
+            selection.set(Some(
+                arranged_pods
+                    .iter()
+                    .flat_map(|(_, pods)| pods.iter().map(|podcast| podcast.podcastid))
+                    .collect(),
+            ))
+        })
+    };
+    let select_none = {
+        let selection = selection.clone();
+        Callback::from(move |_: MouseEvent| selection.set(Some(HashSet::new())))
+    };
+    let selected_ids: Vec<i32> = selection.iter().flatten().copied().collect();
 
-    let clear_filter = Callback::from(move |_| {
-        selected_category.set(None);
-        // Reset other filter states here as well
-    });
+    let on_bulk_category_input = {
+        let bulk_category = bulk_category.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                bulk_category.set(input.value());
+            }
+        })
+    };
+    let apply_bulk_category = {
+        let dispatch = dispatch.clone();
+        let filter_dispatch = filter_dispatch.clone();
+        let bulk_category = bulk_category.clone();
+        let bulk_busy = bulk_busy.clone();
+        let selected_ids = selected_ids.clone();
+        let api_key = api_key.clone().flatten();
+        let server_name = server_name.clone();
+        Callback::from(move |_: MouseEvent| {
+            let category = bulk_category.trim().to_string();
+            let (Some(server_name), Some(user_id), false) =
+                (server_name.clone(), user_id, category.is_empty())
+            else {
+                return;
+            };
+            let api_key = api_key.clone();
+            let dispatch = dispatch.clone();
+            let filter_dispatch = filter_dispatch.clone();
+            let action_category = category.clone();
+            bulk_category.set(String::new());
+            run_bulk_action(
+                selected_ids.clone(),
+                bulk_busy.clone(),
+                move |podcast_id| {
+                    let server_name = server_name.clone();
+                    let api_key = api_key.clone();
+                    let request = AddCategoryRequest {
+                        podcast_id,
+                        user_id,
+                        category: action_category.clone(),
+                    };
+                    async move {
+                        call_add_category(&server_name, &api_key, &request)
+                            .await
+                            .is_ok()
+                    }
+                },
+                move |updated| {
+                    dispatch.reduce_mut(|state| {
+                        let pods = state
+                            .podcast_feed_return
+                            .as_mut()
+                            .and_then(|response| response.pods.as_mut());
+                        for podcast in pods.into_iter().flatten() {
+                            if updated.contains(&podcast.podcastid)
+                                && !podcast_categories(podcast).contains(&category)
+                            {
+                                // The server keeps categories as a ", " separated list
+                                let mut categories = podcast_categories(podcast);
+                                categories.push(category.clone());
+                                podcast.categories = categories.join(", ");
+                            }
+                        }
+                    });
+                    filter_dispatch.reduce_mut(|filter_state| {
+                        let list = filter_state
+                            .category_filter_list
+                            .get_or_insert_with(Vec::new);
+                        if !list.contains(&category) {
+                            list.push(category);
+                        }
+                    });
+                },
+            );
+        })
+    };
+    let set_bulk_auto_download = |auto_download: bool| {
+        let dispatch = dispatch.clone();
+        let bulk_busy = bulk_busy.clone();
+        let selected_ids = selected_ids.clone();
+        let api_key = api_key.clone().flatten();
+        let server_name = server_name.clone();
+        Callback::from(move |_: MouseEvent| {
+            let (Some(server_name), Some(api_key), Some(user_id)) =
+                (server_name.clone(), api_key.clone(), user_id)
+            else {
+                return;
+            };
+            let dispatch = dispatch.clone();
+            run_bulk_action(
+                selected_ids.clone(),
+                bulk_busy.clone(),
+                move |podcast_id| {
+                    let server_name = server_name.clone();
+                    let api_key = api_key.clone();
+                    let request = AutoDownloadRequest {
+                        podcast_id,
+                        user_id,
+                        auto_download,
+                    };
+                    async move {
+                        call_enable_auto_download(&server_name, &api_key, &request)
+                            .await
+                            .is_ok()
+                    }
+                },
+                move |updated| {
+                    dispatch.reduce_mut(|state| {
+                        let pods = state
+                            .podcast_feed_return
+                            .as_mut()
+                            .and_then(|response| response.pods.as_mut());
+                        for podcast in pods.into_iter().flatten() {
+                            if updated.contains(&podcast.podcastid) {
+                                podcast.autodownload = auto_download;
+                            }
+                        }
+                    });
+                },
+            );
+        })
+    };
+    let bulk_auto_download_on = set_bulk_auto_download(true);
+    let bulk_auto_download_off = set_bulk_auto_download(false);
+    let nothing_selected = selected_ids.is_empty() || *bulk_busy;
 
     html! {
         <>
@@ -735,13 +1079,17 @@ pub fn podcasts() -> Html {
                             <div>
                                 <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center" onclick={toggle_filter_dropdown}>
                                     <span class="material-icons icon-space">{"filter_alt"}</span>
-                                    <span class="text-lg">{"Filter"}</span>
+                                    <span class="text-lg">{ i18n.t("podcasts.filter") }</span>
+                                </button>
+                                {render_layout_toggle(&view, set_view.clone())}
+                                <button class={classes!("download-button", "font-bold", "py-2", "px-4", "rounded", "inline-flex", "items-center", selection.is_some().then_some("filter-active"))} onclick={toggle_selection}>
+                                    <span class="material-icons icon-space">{"checklist"}</span>
+                                    <span class="text-lg">{ i18n.t("podcasts.select") }</span>
                                 </button>
-                                {render_layout_toggle(dispatch.clone(), state.podcast_layout.clone())}
                             </div>
                             <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center" onclick={toggle_custom_modal}>
                                 <span class="material-icons icon-space">{"add_box"}</span>
-                                <span class="text-lg">{"Add Custom Feed"}</span>
+                                <span class="text-lg">{ i18n.t("podcasts.add_custom_feed") }</span>
                             </button>
                         </div>
                     </div>
@@ -750,70 +1098,140 @@ pub fn podcasts() -> Html {
             {
                 if *show_filter_dropdown {
                     html! {
-                        <div class="filter-container flex items-center space-x-4">
+                        <div class="filter-container flex flex-wrap items-center gap-4">
                             // Clear Filter button
                             <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center" onclick={clear_filter}>
                                 <span class="material-icons icon-space">{"clear"}</span>
-                                <span class="text-lg">{"Clear Filter"}</span>
+                                <span class="text-lg">{ i18n.t("podcasts.clear_filter") }</span>
                             </button>
                             // Category dropdown
                             <div class="filter-dropdown font-bold rounded">
                                 {
                                     if let Some(categories) = &filter_state.category_filter_list {
-                                        let categories_clone = categories.clone();
+                                        let mut categories_clone = categories.clone();
+                                        categories_clone.sort_by_key(|category| category.to_lowercase());
                                         html! {
                                             <>
-                                                <select class="category-select" onchange={Callback::from(move |e: web_sys::Event| {
-                                                    let target = e.target_dyn_into::<web_sys::HtmlSelectElement>().unwrap();
-                                                    let selected_value = target.value();
-                                                    on_filter_click.emit(selected_value);
-                                                })}>
-                                                    <option value="" disabled=true selected=true>{ "Select Category" }</option>
+                                                <select class="category-select" onchange={on_filter_click}>
+                                                    <option value="" selected={view.category.is_none()}>{ i18n.t("podcasts.select_category") }</option>
                                                     { for categories_clone.iter().map(|category| html! {
-                                                        <option value={category.clone()}>{ category }</option>
+                                                        <option value={category.clone()} selected={view.category.as_ref() == Some(category)}>{ category }</option>
                                                     }) }
                                                 </select>
                                             </>
                                         }
                                     } else {
-                                        html! { <p>{ "No categories available" }</p> }
+                                        html! { <p>{ i18n.t("podcasts.no_categories") }</p> }
                                     }
                                 }
                             </div>
+                            <div class="filter-dropdown font-bold rounded flex items-center space-x-2">
+                                <select class="category-select" onchange={on_sort_change} title={i18n.t("podcasts.sort")}>
+                                    { for LibrarySort::ALL.iter().map(|sort| html! {
+                                        <option value={sort.code()} selected={*sort == view.sort}>
+                                            { i18n.t(&format!("podcasts.sort.{}", sort.code())) }
+                                        </option>
+                                    })}
+                                </select>
+                                <button class="download-button rounded p-2 inline-flex items-center" onclick={toggle_direction}
+                                    title={i18n.t(if view.descending { "filter.descending" } else { "filter.ascending" })}>
+                                    <span class="material-icons">{ if view.descending { "arrow_downward" } else { "arrow_upward" } }</span>
+                                </button>
+                            </div>
+                            <button class={classes!("download-button", "font-bold", "py-2", "px-4", "rounded", "inline-flex", "items-center", view.group_by_category.then_some("filter-active"))} onclick={toggle_grouping}>
+                                <span class="material-icons icon-space">{"category"}</span>
+                                <span class="text-lg">{ i18n.t("podcasts.group_by_category") }</span>
+                            </button>
                         </div>
                     }
                 } else {
                     html! {}
                 }
             }
+            if selection.is_some() {
+                <div class="library-bulk-bar filter-container flex flex-wrap items-center gap-2">
+                    <span class="item_container-text font-bold">{ i18n.tn("podcasts.selected_count", selected_ids.len() as i64, &[]) }</span>
+                    <button class="download-button py-2 px-4 rounded" onclick={select_all}>{ i18n.t("podcasts.select_all") }</button>
+                    <button class="download-button py-2 px-4 rounded" onclick={select_none}>{ i18n.t("podcasts.select_none") }</button>
+                    <input
+                        type="text"
+                        list="library-categories"
+                        class="search-bar-input border text-sm rounded-lg p-2"
+                        placeholder={i18n.t("podcasts.category_placeholder")}
+                        value={(*bulk_category).clone()}
+                        oninput={on_bulk_category_input}
+                    />
+                    <datalist id="library-categories">
+                        { for filter_state.category_filter_list.iter().flatten().map(|category| html! {
+                            <option value={category.clone()} />
+                        })}
+                    </datalist>
+                    <button class="download-button py-2 px-4 rounded inline-flex items-center" disabled={nothing_selected || bulk_category.trim().is_empty()} onclick={apply_bulk_category}>
+                        <span class="material-icons icon-space">{"label"}</span>
+                        { i18n.t("podcasts.add_category") }
+                    </button>
+                    <button class="download-button py-2 px-4 rounded inline-flex items-center" disabled={nothing_selected} onclick={bulk_auto_download_on}>
+                        <span class="material-icons icon-space">{"download_for_offline"}</span>
+                        { i18n.t("podcasts.auto_download_on") }
+                    </button>
+                    <button class="download-button py-2 px-4 rounded inline-flex items-center" disabled={nothing_selected} onclick={bulk_auto_download_off}>
+                        <span class="material-icons icon-space">{"file_download_off"}</span>
+                        { i18n.t("podcasts.auto_download_off") }
+                    </button>
+                    <button class="download-button py-2 px-4 rounded inline-flex items-center" disabled={nothing_selected} onclick={confirm_bulk_delete}>
+                        <span class="material-icons icon-space">{"delete"}</span>
+                        { i18n.t("podcasts.unsubscribe") }
+                    </button>
+                    if *bulk_busy {
+                        <span class="spinner-border animate-spin inline-block w-4 h-4 border-2 rounded-full"></span>
+                    }
+                </div>
+            }
 
 
             {
                 if let Some(podcasts) = state.podcast_feed_return.clone() {
                     let int_podcasts = podcasts.clone();
-                    if let Some(pods) = int_podcasts.pods.clone() {
-                        if filtered_pods.is_empty() {
+                    if let Some(_pods) = int_podcasts.pods.clone() {
+                        if no_matches {
                             // Render "No Podcasts Found" message
                             html! {
                                 <div class="empty-episodes-container">
-                                    <img src="static/assets/favicon.png" alt="Logo" class="logo"/>
-                                    <h1>{ "No Podcasts Found" }</h1>
-                                    <p>{"You can add new podcasts by using the search bar above. Search for your favorite podcast and click the plus button to add it."}</p>
+                                    <img src="static/assets/favicon.png" alt={i18n.t("common.logo_alt")} class="logo"/>
+                                    <h1>{ i18n.t("podcasts.empty_title") }</h1>
+                                    <p>{ i18n.t("podcasts.empty_body") }</p>
                                 </div>
                                     }
                         } else {
                             // render_podcasts(&filtered_pods, state.podcast_layout.clone(), dispatch.clone(), &history)
-                            render_podcasts(
-                                &filtered_pods,
-                                state.podcast_layout.clone(),
-                                dispatch.clone(),
-                                &history,
-                                api_key.clone(),
-                                server_name.clone(),
-                                user_id,
-                                desc_state,
-                                desc_dispatch.clone(),
-                            )
+                            arranged_pods.iter().map(|(category, pods)| {
+                                let list = render_podcasts(
+                                    pods,
+                                    &view.layout,
+                                    dispatch.clone(),
+                                    &history,
+                                    api_key.clone(),
+                                    server_name.clone(),
+                                    user_id,
+                                    desc_state.clone(),
+                                    desc_dispatch.clone(),
+                                    selection.as_ref(),
+                                    on_select.clone(),
+                                );
+                                if view.group_by_category {
+                                    html! {
+                                        <div class="library-group">
+                                            <h2 class="library-group-header item_container-text">
+                                                { category.clone().unwrap_or_else(|| i18n.t("podcasts.uncategorized")) }
+                                                <span class="library-group-count">{ format!(" ({})", pods.len()) }</span>
+                                            </h2>
+                                            { list }
+                                        </div>
+                                    }
+                                } else {
+                                    list
+                                }
+                            }).collect::<Html>()
                             // { filtered_pods.iter().map(|podcast| {
                             //     // Your existing podcast rendering logic
                             //     let api_key_iter = api_key.clone();
@@ -924,18 +1342,18 @@ pub fn podcasts() -> Html {
                     } else {
                         html! {
                             <div class="empty-episodes-container">
-                                <img src="static/assets/favicon.png" alt="Logo" class="logo"/>
-                                <h1>{ "No Podcasts Found" }</h1>
-                                <p>{"You can add new podcasts by using the search bar above. Search for your favorite podcast and click the plus button to add it."}</p>
+                                <img src="static/assets/favicon.png" alt={i18n.t("common.logo_alt")} class="logo"/>
+                                <h1>{ i18n.t("podcasts.empty_title") }</h1>
+                                <p>{ i18n.t("podcasts.empty_body") }</p>
                             </div>
                         }
                     }
                 } else {
                     html! {
                         <div class="empty-episodes-container">
-                            <img src="static/assets/favicon.png" alt="Logo" class="logo"/>
-                            <h1>{ "No Podcasts Found" }</h1>
-                            <p>{"You can add new podcasts by using the search bar above. Search for your favorite podcast and click the plus button to add it."}</p>
+                            <img src="static/assets/favicon.png" alt={i18n.t("common.logo_alt")} class="logo"/>
+                            <h1>{ i18n.t("podcasts.empty_title") }</h1>
+                            <p>{ i18n.t("podcasts.empty_body") }</p>
                         </div>
                     }
                 }
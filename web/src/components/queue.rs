@@ -5,6 +5,8 @@ use super::gen_components::{
 use crate::components::audio::on_play_click;
 use crate::components::audio::AudioPlayer;
 use crate::components::context::{AppState, UIState};
+use crate::components::episode_filter::{filtered_episode_list, use_episode_filter};
+use crate::components::i18n::use_i18n;
 use crate::components::episodes_layout::AppStateMsg;
 use crate::components::gen_funcs::{
     format_datetime, match_date_format, parse_date, sanitize_html_with_blank_target,
@@ -17,15 +19,15 @@ use yew::{function_component, html, Html};
 use yew_router::history::BrowserHistory;
 use yewdux::prelude::*;
 // use crate::components::gen_funcs::check_auth;
-use crate::components::episodes_layout::UIStateMsg;
 use crate::requests::login_requests::use_check_authentication;
-use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
 use web_sys::Element;
-use web_sys::{window, DragEvent, HtmlElement};
+use web_sys::{DragEvent, HtmlElement};
 
 #[function_component(Queue)]
 pub fn queue() -> Html {
+    let i18n = use_i18n();
+    let episode_filter = use_episode_filter("queue");
     let (state, dispatch) = use_store::<AppState>();
     let history = BrowserHistory::new();
 
@@ -34,8 +36,6 @@ pub fn queue() -> Html {
     let error = use_state(|| None);
     let (post_state, _post_dispatch) = use_store::<AppState>();
     let (audio_state, audio_dispatch) = use_store::<UIState>();
-    let error_message = audio_state.error_message.clone();
-    let info_message = audio_state.info_message.clone();
 
     let session_dispatch = _post_dispatch.clone();
     let session_state = post_state.clone();
@@ -88,30 +88,6 @@ pub fn queue() -> Html {
         || ()
     });
 
-    {
-        let ui_dispatch = audio_dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                ui_dispatch.apply(UIStateMsg::ClearErrorMessage);
-                ui_dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
 
     // Fetch episodes on component mount
     let loading_ep = loading.clone();
@@ -194,7 +170,7 @@ pub fn queue() -> Html {
                     {
                         html! {
                             <div>
-                            <h1 class="text-2xl item_container-text font-bold text-center mb-6">{"Queue"}</h1>
+                            <h1 class="text-2xl item_container-text font-bold text-center mb-6">{ i18n.t("queue.title") }</h1>
                             </div>
                         }
                     }
@@ -204,8 +180,8 @@ pub fn queue() -> Html {
                             if queued_eps.episodes.is_empty() {
                                 // Render "No Queued Episodes Found" if episodes list is empty
                                 empty_message(
-                                    "No Queued Episodes Found",
-                                    "You can queue episodes by clicking the context button on each episode and clicking 'Queue Episode'. Doing this will play episodes in order of the queue after the currently playing episode is complete."
+                                    &i18n.t("queue.empty_title"),
+                                    &i18n.t("queue.empty_body")
                                 )
                             } else {
                                 let ondragstart = {
@@ -454,7 +430,7 @@ pub fn queue() -> Html {
 
 
 
-                                queued_eps.episodes.into_iter().map(|episode| {
+                                filtered_episode_list("queue", &episode_filter, &state, queued_eps.episodes, |episode| {
                             let api_key = post_state.auth_details.as_ref().map(|ud| ud.api_key.clone());
                             let user_id = post_state.user_details.as_ref().map(|ud| ud.UserID.clone());
                             let server_name = post_state.auth_details.as_ref().map(|ud| ud.server_name.clone());
@@ -568,12 +544,12 @@ pub fn queue() -> Html {
                             );
 
                             item
-                        }).collect::<Html>()
+                        })
                         }
                     } else {
                         empty_message(
-                            "No Queued Episodes Found - State is None",
-                            "You can queue episodes by clicking the context button on each episode and clicking 'Queue Episode'. Doing this will play episodes in order of the queue after the currently playing episode is complete."
+                            &i18n.t("queue.empty_title"),
+                            &i18n.t("queue.empty_body")
                         )
                     }
                 }
@@ -585,13 +561,6 @@ pub fn queue() -> Html {
                 html! {}
             }
         }
-        // Conditional rendering for the error banner
-        if let Some(error) = error_message {
-            <div class="error-snackbar">{ error }</div>
-        }
-        if let Some(info) = info_message {
-            <div class="info-snackbar">{ info }</div>
-        }
         </div>
         <App_drawer />
         </>
@@ -15,6 +15,10 @@ pub enum Route {
     Queue,
     #[at("/saved")]
     Saved,
+    #[at("/playlists")]
+    Playlists,
+    #[at("/playlist/:playlist_id")]
+    Playlist { playlist_id: i32 },
     #[at("/settings")]
     Settings,
     #[at("/history")]
@@ -43,4 +47,6 @@ pub enum Route {
     Episode,
     #[at("/shared_episode/:url_key")]
     SharedEpisode { url_key: String },
+    #[at("/oauth/callback")]
+    OidcCallback,
 }
@@ -5,6 +5,8 @@ use super::gen_components::{
 use crate::components::audio::on_play_click;
 use crate::components::audio::AudioPlayer;
 use crate::components::context::{AppState, UIState};
+use crate::components::episode_filter::{filtered_episode_list, use_episode_filter};
+use crate::components::i18n::use_i18n;
 use crate::components::episodes_layout::AppStateMsg;
 use crate::components::gen_funcs::{
     format_datetime, match_date_format, parse_date, sanitize_html_with_blank_target,
@@ -17,14 +19,12 @@ use yew::{function_component, html, Html};
 use yew_router::history::BrowserHistory;
 use yewdux::prelude::*;
 // use crate::components::gen_funcs::check_auth;
-use crate::components::episodes_layout::UIStateMsg;
 use crate::requests::login_requests::use_check_authentication;
-use wasm_bindgen::closure::Closure;
-use wasm_bindgen::JsCast;
-use web_sys::window;
 
 #[function_component(Saved)]
 pub fn saved() -> Html {
+    let i18n = use_i18n();
+    let episode_filter = use_episode_filter("saved");
     let (state, dispatch) = use_store::<AppState>();
     let history = BrowserHistory::new();
 
@@ -33,8 +33,6 @@ pub fn saved() -> Html {
     let error = use_state(|| None);
     let (post_state, _post_dispatch) = use_store::<AppState>();
     let (audio_state, audio_dispatch) = use_store::<UIState>();
-    let error_message = audio_state.error_message.clone();
-    let info_message = audio_state.info_message.clone();
     let dropdown_open = use_state(|| false);
 
     let session_dispatch = _post_dispatch.clone();
@@ -80,30 +78,6 @@ pub fn saved() -> Html {
         })
     };
 
-    {
-        let ui_dispatch = audio_dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                ui_dispatch.apply(UIStateMsg::ClearErrorMessage);
-                ui_dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
 
     // Fetch episodes on component mount
     let loading_ep = loading.clone();
@@ -187,7 +161,7 @@ pub fn saved() -> Html {
                     {
                         html! {
                             <div>
-                            <h1 class="text-2xl item_container-text font-bold text-center mb-6">{"Saved"}</h1>
+                            <h1 class="text-2xl item_container-text font-bold text-center mb-6">{ i18n.t("saved.title") }</h1>
                             </div>
                         }
                     }
@@ -199,11 +173,11 @@ pub fn saved() -> Html {
                         if saved_eps.episodes.is_empty() {
                             // Render "No Queued Episodes Found" if episodes list is empty
                             empty_message(
-                                "No Saved Episodes Found",
-                                "You can save episodes by clicking the context button on each episode and clicking 'Save Episode'. Doing this will save episodes here for easy access when you want to return to them."
+                                &i18n.t("saved.empty_title"),
+                                &i18n.t("saved.empty_body")
                             )
                         } else {
-                            saved_eps.episodes.into_iter().map(|episode| {
+                            filtered_episode_list("saved", &episode_filter, &state, saved_eps.episodes, |episode| {
                                 let api_key = post_state.auth_details.as_ref().map(|ud| ud.api_key.clone());
                                 let user_id = post_state.user_details.as_ref().map(|ud| ud.UserID.clone());
                                 let server_name = post_state.auth_details.as_ref().map(|ud| ud.server_name.clone());
@@ -311,24 +285,17 @@ pub fn saved() -> Html {
                                 );
 
                                 item
-                            }).collect::<Html>()
+                            })
                         }
 
                     } else {
                         empty_message(
-                            "No Saved Episodes Found",
-                            "You can save episodes by clicking the context button on each episode and clicking 'Save Episode'. Doing this will save episodes here for easy access when you want to return to them."
+                            &i18n.t("saved.empty_title"),
+                            &i18n.t("saved.empty_body")
                         )
                     }
                 }
             }
-        // Conditional rendering for the error banner
-        if let Some(error) = error_message {
-            <div class="error-snackbar">{ error }</div>
-        }
-        if let Some(info) = info_message {
-            <div class="info-snackbar">{ info }</div>
-        }
         {
             if let Some(audio_props) = &audio_state.currently_playing {
                 html! { <AudioPlayer src={audio_props.src.clone()} title={audio_props.title.clone()} artwork_url={audio_props.artwork_url.clone()} duration={audio_props.duration.clone()} episode_id={audio_props.episode_id.clone()} duration_sec={audio_props.duration_sec.clone()} start_pos_sec={audio_props.start_pos_sec.clone()} end_pos_sec={audio_props.end_pos_sec.clone()} offline={audio_props.offline.clone()} /> }
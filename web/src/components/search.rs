@@ -7,25 +7,49 @@ use crate::components::audio::AudioPlayer;
 use crate::components::context::{AppState, UIState};
 use crate::components::episodes_layout::AppStateMsg;
 use crate::components::gen_funcs::{
-    format_datetime, match_date_format, parse_date, sanitize_html_with_blank_target,
-    truncate_description,
+    escape_html, format_datetime, highlight_html, match_date_format, parse_date,
+    sanitize_html_with_blank_target, truncate_description,
 };
-use crate::requests::search_pods::{call_search_database, SearchRequest, SearchResponse};
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::notify_error;
+use crate::requests::search_pods::{call_search_database, SearchRequest};
 use yew::prelude::*;
 use yew::{function_component, html, use_node_ref, Callback, Html, MouseEvent, Properties};
 use yew_router::history::BrowserHistory;
 use yewdux::prelude::*;
 // use crate::components::gen_funcs::check_auth;
-use crate::components::episodes_layout::UIStateMsg;
 use crate::requests::login_requests::use_check_authentication;
 use async_std::task::sleep;
 use std::time::Duration;
-use wasm_bindgen::closure::Closure;
-use wasm_bindgen::JsCast;
 use wasm_bindgen_futures::spawn_local;
-use web_sys::window;
 use web_sys::HtmlElement;
-use web_sys::HtmlInputElement;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+
+const SEARCH_PAGE_SIZE: usize = 25;
+const LISTEN_STATES: [&str; 4] = ["any", "unplayed", "in_progress", "played"];
+const SEARCH_FIELDS: [&str; 3] = ["title", "notes", "transcript"];
+
+fn blank_search() -> SearchRequest {
+    SearchRequest {
+        page: 1,
+        page_size: SEARCH_PAGE_SIZE,
+        ..Default::default()
+    }
+}
+
+fn filters_active(request: &SearchRequest) -> bool {
+    let blank = blank_search();
+    SearchRequest {
+        search_term: String::new(),
+        user_id: 0,
+        page: 1,
+        ..request.clone()
+    } != blank
+}
+
+fn non_empty(value: String) -> Option<String> {
+    Some(value.trim().to_string()).filter(|value| !value.is_empty())
+}
 
 #[derive(Properties, Clone, PartialEq)]
 pub struct SearchProps {
@@ -34,8 +58,8 @@ pub struct SearchProps {
 
 #[function_component(Search)]
 pub fn search(_props: &SearchProps) -> Html {
+    let i18n = use_i18n();
     let (state, dispatch) = use_store::<AppState>();
-    let search_dispatch = dispatch.clone();
 
     let session_dispatch = dispatch.clone();
     let session_state = state.clone();
@@ -75,34 +99,8 @@ pub fn search(_props: &SearchProps) -> Html {
     // let error = use_state(|| None);
     let (post_state, _post_dispatch) = use_store::<AppState>();
     let (audio_state, audio_dispatch) = use_store::<UIState>();
-    let error_message = audio_state.error_message.clone();
-    let info_message = audio_state.info_message.clone();
     let history = BrowserHistory::new();
 
-    {
-        let ui_dispatch = audio_dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                ui_dispatch.apply(UIStateMsg::ClearErrorMessage);
-                ui_dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
     // let search_results = use_state(|| Vec::new());
     // let search_results_clone = search_results.clone();
 
@@ -130,71 +128,164 @@ pub fn search(_props: &SearchProps) -> Html {
     //     }
     // });
 
-    let api_key_submit = api_key.clone();
-    let user_id_submit = user_id.clone();
-    let server_name_submit = server_name.clone();
-
-    let on_submit = Callback::from(move |event: SubmitEvent| {
-        event.prevent_default();
-        event.prevent_default();
-        let container_ref_submit_clone1 = container_ref_clone1.clone();
-
-        if let Some(form) = form_ref_clone1.cast::<HtmlElement>() {
-            form.class_list().add_1("move-to-top").unwrap();
-        }
-
-        if let Some(form) = input_ref_clone1.cast::<HtmlElement>() {
-            form.class_list().add_1("move-to-top").unwrap();
-        }
-
-        // Clone the necessary variables
-        let server_name_submit = server_name_submit.clone();
-        let api_key_submit = api_key_submit.clone();
-        let user_id_submit = user_id_submit.clone();
-        // let search_results = search_results_clone.clone();
-        let mut search_request = None;
-        if let Some(input_element) = input_ref_clone2.cast::<HtmlInputElement>() {
-            let search_term = input_element.value();
-            search_request = Some(SearchRequest {
-                search_term,
-                user_id: user_id_submit.unwrap(), // replace with the actual user id
-            });
-        } else {
-            // web_sys::console::log_1(&"input_ref_clone2 is not an HtmlInputElement".into());
-        }
-        let future_dispatch = search_dispatch.clone();
-        let future = async move {
-            sleep(Duration::from_secs(1)).await;
-            if let Some(container) = container_ref_submit_clone1.cast::<HtmlElement>() {
-                container.class_list().add_1("shrink-input").unwrap();
-            }
-            if let Some(search_request) = search_request {
-                let dispatch = future_dispatch.clone();
-                match call_search_database(
-                    &server_name_submit.unwrap(),
-                    &api_key_submit.flatten(),
-                    &search_request,
-                )
-                .await
-                {
-                    Ok(results) => {
+    // Filters from the panel, kept between searches and seeded from the last search so
+    // coming back from an episode keeps them
+    let filters = use_state(|| state.search_request.clone().unwrap_or_else(blank_search));
+    let show_filters = use_state(|| false);
+    let searching = use_state(|| false);
+
+    let run_search = {
+        let dispatch = dispatch.clone();
+        let searching = searching.clone();
+        let server_name = server_name.clone();
+        let api_key = api_key.clone();
+        Callback::from(move |request: SearchRequest| {
+            let (Some(server_name), Some(api_key)) = (server_name.clone(), api_key.clone()) else {
+                return;
+            };
+            let dispatch = dispatch.clone();
+            let searching = searching.clone();
+            searching.set(true);
+            spawn_local(async move {
+                match call_search_database(&server_name, &api_key, &request).await {
+                    Ok(response) => {
                         dispatch.reduce_mut(move |state| {
-                            state.search_episodes = Some(SearchResponse { data: results });
+                            state.search_episodes = Some(response);
+                            state.search_request = Some(request);
                         });
-                        // Update the search results state
-                        // search_results.set(results);
                     }
                     Err(e) => {
-                        // Handle the error
-                        web_sys::console::log_1(
-                            &format!("Failed to search database: {:?}", e).into(),
-                        ); // Log for debugging
+                        notify_error(
+                            I18n::current().tf("search.failed", &[("error", &e.to_string())]),
+                        );
+                    }
+                }
+                searching.set(false);
+            });
+        })
+    };
+
+    let on_submit = {
+        let filters = filters.clone();
+        let run_search = run_search.clone();
+        let user_id = user_id.clone();
+        Callback::from(move |event: SubmitEvent| {
+            event.prevent_default();
+            let container_ref_submit_clone1 = container_ref_clone1.clone();
+
+            if let Some(form) = form_ref_clone1.cast::<HtmlElement>() {
+                form.class_list().add_1("move-to-top").unwrap();
+            }
+
+            if let Some(form) = input_ref_clone1.cast::<HtmlElement>() {
+                form.class_list().add_1("move-to-top").unwrap();
+            }
+
+            let (Some(input_element), Some(user_id)) =
+                (input_ref_clone2.cast::<HtmlInputElement>(), user_id)
+            else {
+                return;
+            };
+            let search_request = SearchRequest {
+                search_term: input_element.value(),
+                user_id,
+                page: 1,
+                ..(*filters).clone()
+            };
+            let run_search = run_search.clone();
+            spawn_local(async move {
+                // Only the first search waits for the input to finish moving to the top
+                if let Some(container) = container_ref_submit_clone1.cast::<HtmlElement>() {
+                    if !container.class_list().contains("shrink-input") {
+                        sleep(Duration::from_secs(1)).await;
+                        container.class_list().add_1("shrink-input").unwrap();
+                    }
+                }
+                run_search.emit(search_request);
+            });
+        })
+    };
+
+    let go_to_page = {
+        let run_search = run_search.clone();
+        let last_request = state.search_request.clone();
+        move |page: usize| {
+            let run_search = run_search.clone();
+            let last_request = last_request.clone();
+            Callback::from(move |_: MouseEvent| {
+                if let Some(request) = last_request.clone() {
+                    run_search.emit(SearchRequest { page, ..request });
+                    if let Some(window) = web_sys::window() {
+                        window.scroll_to_with_x_and_y(0.0, 0.0);
                     }
                 }
+            })
+        }
+    };
+
+    let toggle_filters = {
+        let show_filters = show_filters.clone();
+        Callback::from(move |_: MouseEvent| show_filters.set(!*show_filters))
+    };
+    let clear_filters = {
+        let filters = filters.clone();
+        Callback::from(move |_: MouseEvent| filters.set(blank_search()))
+    };
+    let update = |edit: fn(&mut SearchRequest, String)| {
+        let filters = filters.clone();
+        move |value: String| {
+            let mut next = (*filters).clone();
+            edit(&mut next, value);
+            filters.set(next);
+        }
+    };
+    let on_input = |edit: fn(&mut SearchRequest, String)| {
+        let update = update(edit);
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                update(input.value());
             }
-        };
-        spawn_local(future);
+        })
+    };
+    let on_change = |edit: fn(&mut SearchRequest, String)| {
+        let update = update(edit);
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                update(select.value());
+            } else if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                update(input.value());
+            }
+        })
+    };
+    let on_podcast = on_input(|filters, value| filters.podcast = non_empty(value));
+    let on_person = on_input(|filters, value| filters.person = non_empty(value));
+    let on_after = on_change(|filters, value| filters.after = non_empty(value));
+    let on_before = on_change(|filters, value| filters.before = non_empty(value));
+    let on_listen_state = on_change(|filters, value| {
+        filters.listen_state = Some(value).filter(|value| value != "any")
     });
+    let on_min_minutes = on_input(|filters, value| filters.min_minutes = value.parse().ok());
+    let on_max_minutes = on_input(|filters, value| filters.max_minutes = value.parse().ok());
+    let on_field = |field: &'static str| {
+        let filters = filters.clone();
+        Callback::from(move |_: Event| {
+            let mut next = (*filters).clone();
+            let mut fields = next.fields.take().unwrap_or_default();
+            if fields.iter().any(|f| f == field) {
+                fields.retain(|f| f != field);
+            } else {
+                fields.push(field.to_string());
+            }
+            next.fields = Some(fields).filter(|fields| !fields.is_empty());
+            filters.set(next);
+        })
+    };
+    let field_checked = |field: &str| {
+        filters
+            .fields
+            .as_ref()
+            .map_or(false, |fields| fields.iter().any(|f| f == field))
+    };
 
     html! {
         <>
@@ -203,30 +294,86 @@ pub fn search(_props: &SearchProps) -> Html {
             <UseScrollToTop />
             <div class="search-container" ref={container_ref.clone()}>
                 <form class="search-page-input" onsubmit={on_submit} ref={form_ref.clone()}>
-                    <label for="search" class="mb-2 text-sm font-medium text-gray-900 sr-only dark:text-white">{ "Search" }</label>
+                    <label for="search" class="mb-2 text-sm font-medium text-gray-900 sr-only dark:text-white">{ i18n.t("search.button") }</label>
                     <div class="relative">
                         <div class="absolute inset-y-0 start-0 flex items-center ps-3 pointer-events-none">
                             <svg class="w-4 h-4 text-gray-500 dark:text-gray-400" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 20 20">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m19 19-4-4m0-7A7 7 0 1 1 1 8a7 7 0 0 1 14 0Z"/>
                             </svg>
                         </div>
-                        <input type="search" id="search" class="search-bar-input block w-full p-4 ps-10 text-sm border rounded-lg" placeholder="Search for a podcast, episode, or description" ref={input_ref.clone()}/>
-                        <button class="search-page-button absolute end-2.5 bottom-2.5 focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-4 py-2">{ "Search" }</button>
+                        <input type="search" id="search" class="search-bar-input block w-full p-4 ps-10 text-sm border rounded-lg" placeholder={i18n.t("search.database_placeholder")} value={state.search_request.as_ref().map(|request| request.search_term.clone())} ref={input_ref.clone()}/>
+                        <button class="search-page-button absolute end-2.5 bottom-2.5 focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-4 py-2" disabled={*searching}>{ i18n.t("search.button") }</button>
+                    </div>
+                    <div class="episode-filter mt-4">
+                        <div class="flex items-center space-x-2">
+                            <button type="button" class={classes!("download-button", "font-bold", "py-2", "px-4", "rounded", "inline-flex", "items-center", filters_active(&filters).then_some("filter-active"))} onclick={toggle_filters}>
+                                <span class="material-icons icon-space">{"tune"}</span>
+                                <span class="text-lg">{ i18n.t("search.advanced") }</span>
+                            </button>
+                            if filters_active(&filters) {
+                                <button type="button" class="download-button font-bold py-2 px-4 rounded inline-flex items-center" onclick={clear_filters}>
+                                    <span class="material-icons icon-space">{"clear"}</span>
+                                    <span class="text-lg">{ i18n.t("filter.clear") }</span>
+                                </button>
+                            }
+                        </div>
+                        if *show_filters {
+                            <div class="episode-filter-panel filter-dropdown">
+                                <input type="text" class="search-bar-input border text-sm rounded-lg p-2.5" placeholder={i18n.t("search.podcast_placeholder")} value={filters.podcast.clone().unwrap_or_default()} oninput={on_podcast} />
+                                <input type="text" class="search-bar-input border text-sm rounded-lg p-2.5" placeholder={i18n.t("search.person_placeholder")} value={filters.person.clone().unwrap_or_default()} oninput={on_person} />
+                                <select onchange={on_listen_state} title={i18n.t("filter.play_state")}>
+                                    { for LISTEN_STATES.iter().map(|listen_state| html! {
+                                        <option value={*listen_state} selected={filters.listen_state.as_deref().unwrap_or("any") == *listen_state}>
+                                            { i18n.t(&format!("filter.play_state.{}", listen_state)) }
+                                        </option>
+                                    })}
+                                </select>
+                                <label class="episode-filter-range item_container-text">
+                                    <span>{ i18n.t("filter.released") }</span>
+                                    <input type="date" class="search-bar-input border text-sm rounded-lg p-2" value={filters.after.clone().unwrap_or_default()} onchange={on_after} title={i18n.t("filter.released_after")} />
+                                    <span>{"–"}</span>
+                                    <input type="date" class="search-bar-input border text-sm rounded-lg p-2" value={filters.before.clone().unwrap_or_default()} onchange={on_before} title={i18n.t("search.released_before")} />
+                                </label>
+                                <label class="episode-filter-range item_container-text">
+                                    <span>{ i18n.t("filter.length") }</span>
+                                    <input type="number" min="0" class="search-bar-input border text-sm rounded-lg p-2" placeholder={i18n.t("filter.min_minutes")} value={filters.min_minutes.map(|m| m.to_string()).unwrap_or_default()} oninput={on_min_minutes} />
+                                    <span>{"–"}</span>
+                                    <input type="number" min="0" class="search-bar-input border text-sm rounded-lg p-2" placeholder={i18n.t("filter.max_minutes")} value={filters.max_minutes.map(|m| m.to_string()).unwrap_or_default()} oninput={on_max_minutes} />
+                                </label>
+                                <div class="episode-filter-range item_container-text">
+                                    <span>{ i18n.t("search.search_in") }</span>
+                                    { for SEARCH_FIELDS.iter().map(|field| html! {
+                                        <label class="inline-flex items-center space-x-1">
+                                            <input type="checkbox" checked={field_checked(field)} onchange={on_field(field)} />
+                                            <span>{ i18n.t(&format!("search.field.{}", field)) }</span>
+                                        </label>
+                                    })}
+                                </div>
+                                <p class="search-syntax-help item_container-text text-sm">{ i18n.t("search.syntax_help") }</p>
+                            </div>
+                        }
                     </div>
                 </form>
             </div>
             {
                 if let Some(search_eps) = state.search_episodes.clone() {
-                    let int_search_eps = search_eps.clone();
-                    let episodes = int_search_eps.data;
+                    let page = search_eps.page.max(1);
+                    let page_count = search_eps.page_count();
+                    let terms = search_eps.terms.clone();
+                    let episodes = search_eps.data;
                     if episodes.is_empty() {
                                 // Render "No Recent Episodes Found" if episodes list is empty
                                 empty_message(
-                                    "No Search Results Found",
-                                    "Perhaps try again, but search for something slightly different :/"
+                                    &i18n.t("search.empty_title"),
+                                    &i18n.t("search.empty_body")
                                 )
                             } else {
-                                episodes.into_iter().map(|episode| {
+                                html! {
+                                <>
+                                <p class="item_container-text search-result-count">
+                                    { i18n.tn("search.result_count", search_eps.total as i64, &[]) }
+                                </p>
+                                { episodes.into_iter().map(|episode| {
                                     let id_string = &episode.episodeid.to_string();
 
                                     let is_expanded = state.expanded_descriptions.contains(id_string);
@@ -247,6 +394,27 @@ pub fn search(_props: &SearchProps) -> Html {
                                     } else {
                                         truncate_description(sanitized_description, 300)
                                     };
+                                    let mut description = highlight_html(&description, &terms);
+                                    if let Some(snippet) = &episode.snippet {
+                                        description = format!(
+                                            "<p class=\"search-snippet\">{}</p>{}",
+                                            highlight_html(&escape_html(snippet), &terms),
+                                            description
+                                        );
+                                    }
+                                    if !episode.matched_in.is_empty() {
+                                        let fields = episode
+                                            .matched_in
+                                            .iter()
+                                            .map(|field| i18n.t(&format!("search.field.{}", field)))
+                                            .collect::<Vec<_>>()
+                                            .join(", ");
+                                        description = format!(
+                                            "<p class=\"search-matched-in\">{}</p>{}",
+                                            escape_html(&i18n.tf("search.matched_in", &[("fields", &fields)])),
+                                            description
+                                        );
+                                    }
 
                                     let toggle_expanded = {
                                         let search_dispatch_clone = dispatch.clone();
@@ -331,7 +499,24 @@ pub fn search(_props: &SearchProps) -> Html {
                                     );
 
                                     item
-                                }).collect::<Html>()
+                                }).collect::<Html>() }
+                                if page_count > 1 {
+                                    <div class="search-pagination flex items-center justify-center space-x-4 mb-4">
+                                        <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center" disabled={page <= 1 || *searching} onclick={go_to_page(page - 1)}>
+                                            <span class="material-icons">{"chevron_left"}</span>
+                                            <span>{ i18n.t("search.previous_page") }</span>
+                                        </button>
+                                        <span class="item_container-text">
+                                            { i18n.tf("search.page_of", &[("page", &page.to_string()), ("pages", &page_count.to_string())]) }
+                                        </span>
+                                        <button class="download-button font-bold py-2 px-4 rounded inline-flex items-center" disabled={page >= page_count || *searching} onclick={go_to_page(page + 1)}>
+                                            <span>{ i18n.t("search.next_page") }</span>
+                                            <span class="material-icons">{"chevron_right"}</span>
+                                        </button>
+                                    </div>
+                                }
+                                </>
+                                }
                             }
                     // } else {
                     //     empty_message(
@@ -351,12 +536,6 @@ pub fn search(_props: &SearchProps) -> Html {
                     html! {}
                 }
             }
-            if let Some(error) = error_message {
-                <div class="error-snackbar">{ error }</div>
-            }
-            if let Some(info) = info_message {
-                <div class="info-snackbar">{ info }</div>
-            }
         </div>
         </>
     }
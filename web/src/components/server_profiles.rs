@@ -0,0 +1,182 @@
+use crate::components::context::{AppState, SavedPlayerState, ServerProfile, UIState};
+use crate::components::i18n::{use_i18n, I18n};
+use crate::requests::api_client::{api_error, ApiError};
+use crate::requests::login_requests::login_with_api_key;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+use yew_router::history::{BrowserHistory, History};
+use yewdux::prelude::*;
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct ServerProfilesProps {
+    /// Heading above the list; nothing is rendered at all when there are no profiles to show.
+    #[prop_or_else(|| I18n::current().t("profiles.saved_accounts"))]
+    pub title: String,
+    /// Called once a profile has been switched to, e.g. to close the drawer.
+    #[prop_or_default]
+    pub on_switched: Callback<()>,
+}
+
+/// The accounts this browser has signed in to, for switching between Pinepods servers without
+/// retyping credentials. The signed-in account isn't listed.
+#[function_component(ServerProfiles)]
+pub fn server_profiles(props: &ServerProfilesProps) -> Html {
+    let i18n = use_i18n();
+    let (state, dispatch) = use_store::<AppState>();
+    let (_ui_state, ui_dispatch) = use_store::<UIState>();
+    let profiles = use_state(ServerProfile::load_all);
+    let switching_to = use_state(|| None::<usize>);
+    let error = use_state(|| None::<String>);
+
+    let current = state
+        .auth_details
+        .as_ref()
+        .map(|auth| (auth.server_name.clone(), auth.username.clone()));
+
+    let on_switch = {
+        let profiles = profiles.clone();
+        let switching_to = switching_to.clone();
+        let error = error.clone();
+        let on_switched = props.on_switched.clone();
+        Callback::from(move |index: usize| {
+            let Some(profile) = profiles.get(index).cloned() else {
+                return;
+            };
+            let profiles = profiles.clone();
+            let switching_to = switching_to.clone();
+            let error = error.clone();
+            let dispatch = dispatch.clone();
+            let ui_dispatch = ui_dispatch.clone();
+            let on_switched = on_switched.clone();
+            switching_to.set(Some(index));
+            error.set(None);
+            spawn_local(async move {
+                match login_with_api_key(
+                    profile.server_name.clone(),
+                    profile.username.clone(),
+                    profile.api_key.clone(),
+                )
+                .await
+                {
+                    Ok((user_details, auth_details, server_details)) => {
+                        // Whatever the other account was playing belongs to another server
+                        ui_dispatch.reduce_mut(|ui_state| {
+                            if let Some(audio_element) = ui_state.audio_element.as_ref() {
+                                let _ = audio_element.pause();
+                            }
+                            ui_state.audio_playing = Some(false);
+                            ui_state.currently_playing = None;
+                        });
+                        SavedPlayerState::clear();
+
+                        let gravatar_url = user_details.Email.as_ref().map(|email| {
+                            format!(
+                                "https://gravatar.com/avatar/{:x}?s=80",
+                                md5::compute(email.to_lowercase())
+                            )
+                        });
+                        // Start from a clean state so nothing cached from the old server lingers
+                        let new_state = AppState {
+                            user_details: Some(user_details),
+                            auth_details: Some(auth_details),
+                            server_details: Some(server_details),
+                            gravatar_url,
+                            ..AppState::default()
+                        };
+                        new_state.store_app_state();
+                        dispatch.set(new_state);
+                        switching_to.set(None);
+                        profiles.set(ServerProfile::load_all());
+                        on_switched.emit(());
+                        BrowserHistory::new().push("/home");
+                    }
+                    Err(e) => {
+                        switching_to.set(None);
+                        if api_error(&e).map_or(false, ApiError::is_unauthorized) {
+                            ServerProfile::forget(&profile.server_name, &profile.username);
+                            profiles.set(ServerProfile::load_all());
+                            error.set(Some(I18n::current().tf(
+                                "profiles.expired",
+                                &[
+                                    ("username", &profile.username),
+                                    ("server", &profile.server_name),
+                                ],
+                            )));
+                        } else {
+                            error.set(Some(I18n::current().tf(
+                                "profiles.switch_failed",
+                                &[("server", &profile.server_name), ("error", &e.to_string())],
+                            )));
+                        }
+                    }
+                }
+            });
+        })
+    };
+
+    let on_remove = {
+        let profiles = profiles.clone();
+        Callback::from(move |index: usize| {
+            if let Some(profile) = profiles.get(index) {
+                ServerProfile::forget(&profile.server_name, &profile.username);
+                profiles.set(ServerProfile::load_all());
+            }
+        })
+    };
+
+    let others: Vec<(usize, &ServerProfile)> = profiles
+        .iter()
+        .enumerate()
+        .filter(|(_, profile)| {
+            current.as_ref().map_or(true, |(server, user)| {
+                !profile.is_same_account(server, user)
+            })
+        })
+        .collect();
+    if others.is_empty() && error.is_none() {
+        return html! {};
+    }
+
+    html! {
+        <div class="server-profiles">
+            <p class="item_container-text text-sm font-bold">{ &props.title }</p>
+            <ul class="server-profile-list">
+                { for others.into_iter().map(|(index, profile)| {
+                    let onclick = {
+                        let on_switch = on_switch.clone();
+                        Callback::from(move |_: MouseEvent| on_switch.emit(index))
+                    };
+                    let onremove = {
+                        let on_remove = on_remove.clone();
+                        Callback::from(move |e: MouseEvent| {
+                            e.stop_propagation();
+                            on_remove.emit(index);
+                        })
+                    };
+                    let host = profile
+                        .server_name
+                        .trim_start_matches("https://")
+                        .trim_start_matches("http://")
+                        .to_string();
+                    html! {
+                        <li class="server-profile-item cursor-pointer" {onclick}>
+                            <span class="material-icons icon-space">
+                                { if *switching_to == Some(index) { "hourglass_empty" } else { "account_circle" } }
+                            </span>
+                            <div class="server-profile-text">
+                                <span class="item_container-text text-sm">{ &profile.username }</span>
+                                <span class="item_container-text text-xs">{ host }</span>
+                            </div>
+                            <button class="server-profile-remove" title={i18n.t("profiles.forget")} onclick={onremove}>
+                                <i class="material-icons">{ "close" }</i>
+                            </button>
+                        </li>
+                    }
+                })}
+            </ul>
+            if let Some(error) = (*error).clone() {
+                <p class="server-profile-error text-xs">{ error }</p>
+            }
+        </div>
+    }
+}
@@ -1,26 +1,44 @@
-use crate::components::context::{AppState, UIState};
+use crate::components::context::AppState;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, notify_info};
 use crate::requests::setting_reqs::{
-    call_create_api_key, call_delete_api_key, call_get_api_info, DeleteAPIRequest,
+    call_create_api_key, call_delete_api_key, call_get_api_info, CreateAPIKeyRequest,
+    DeleteAPIRequest,
 };
 use yew::prelude::*;
 use yewdux::prelude::*;
 // use crate::gen_components::_ErrorMessageProps::error_message;
 use wasm_bindgen::JsCast;
 
+/// The scopes a key can be limited to, with the message ids they're labelled by.
+const API_KEY_SCOPES: [(&str, &str); 3] = [
+    ("admin", "api_keys.scope.admin"),
+    ("sync", "api_keys.scope.sync"),
+    ("read", "api_keys.scope.read"),
+];
+const API_KEY_EXPIRY_DAYS: [u32; 4] = [7, 30, 90, 365];
+
+fn scope_label_id(scope: &Option<String>) -> &'static str {
+    let scope = scope.as_deref().unwrap_or("admin");
+    API_KEY_SCOPES
+        .iter()
+        .find(|(value, _)| *value == scope)
+        .map_or("api_keys.scope.admin", |(_, label)| *label)
+}
+
 #[function_component(APIKeys)]
 pub fn api_keys() -> Html {
+    let i18n = use_i18n();
     let (state, _dispatch) = use_store::<AppState>();
-    let (audio_state, audio_dispatch) = use_store::<UIState>();
     let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let api_infos = use_state(|| Vec::new());
     let new_api_key = use_state(|| String::new());
     let selected_api_key_id: UseStateHandle<Option<i32>> = use_state(|| None);
-    let _error_message = audio_state.error_message.clone();
-    let _info_message = audio_state.info_message.clone();
-    let audio_dispatch_effect = audio_dispatch.clone();
-    let audio_dispatch_call = audio_dispatch.clone();
+    let new_key_name = use_state(String::new);
+    let new_key_scope = use_state(|| "admin".to_string());
+    let new_key_expiry = use_state(|| None::<u32>);
     // Define the type of user in the Vec
     // let users: UseStateHandle<Vec<SettingsUser>> = use_state(|| Vec::new());
 
@@ -48,10 +66,7 @@ pub fn api_keys() -> Html {
                                     api_infos.set(response.api_info);
                                 }
                                 Err(e) => {
-                                    audio_dispatch_effect.reduce_mut(|audio_state| {
-                                        audio_state.error_message =
-                                            Option::from(format!("Error getting API Info: {}", e))
-                                    });
+                                    notify_error(I18n::current().tf("api_keys.info_error", &[("error", &e.to_string())]));
                                 }
                             }
                         }
@@ -63,7 +78,6 @@ pub fn api_keys() -> Html {
         );
     }
 
-    let audio_dispatch_refresh = audio_dispatch.clone();
 
     // Add a new `use_effect_with` to re-fetch the API keys when a new API key is added
     {
@@ -89,10 +103,7 @@ pub fn api_keys() -> Html {
                                     api_infos.set(response.api_info);
                                 }
                                 Err(e) => {
-                                    audio_dispatch_refresh.reduce_mut(|audio_state| {
-                                        audio_state.error_message =
-                                            Option::from(format!("Error getting API Info: {}", e))
-                                    });
+                                    notify_error(I18n::current().tf("api_keys.info_error", &[("error", &e.to_string())]));
                                 }
                             }
                         }
@@ -108,6 +119,7 @@ pub fn api_keys() -> Html {
     #[derive(Clone, PartialEq)]
     enum PageState {
         Hidden,
+        Create,
         Shown,
         Delete,
     }
@@ -138,6 +150,49 @@ pub fn api_keys() -> Html {
         e.stop_propagation();
     });
 
+    let open_create_modal = {
+        let page_state = page_state.clone();
+        let new_key_name = new_key_name.clone();
+        let new_key_scope = new_key_scope.clone();
+        let new_key_expiry = new_key_expiry.clone();
+        Callback::from(move |_| {
+            new_key_name.set(String::new());
+            new_key_scope.set("admin".to_string());
+            new_key_expiry.set(None);
+            page_state.set(PageState::Create);
+        })
+    };
+
+    let on_key_name_change = {
+        let new_key_name = new_key_name.clone();
+        Callback::from(move |e: InputEvent| {
+            new_key_name.set(
+                e.target_unchecked_into::<web_sys::HtmlInputElement>()
+                    .value(),
+            );
+        })
+    };
+
+    let on_key_scope_change = {
+        let new_key_scope = new_key_scope.clone();
+        Callback::from(move |e: Event| {
+            new_key_scope.set(
+                e.target_unchecked_into::<web_sys::HtmlSelectElement>()
+                    .value(),
+            );
+        })
+    };
+
+    let on_key_expiry_change = {
+        let new_key_expiry = new_key_expiry.clone();
+        Callback::from(move |e: Event| {
+            let value = e
+                .target_unchecked_into::<web_sys::HtmlSelectElement>()
+                .value();
+            new_key_expiry.set(value.parse().ok());
+        })
+    };
+
     // Define the function to open the modal and request a new API key
     let request_state = state.clone();
     let request_api_key = {
@@ -145,8 +200,13 @@ pub fn api_keys() -> Html {
         let new_api_key = new_api_key.clone();
         let api_key = api_key.clone();
         let server_name = server_name.clone();
+        let new_key_name = new_key_name.clone();
+        let new_key_scope = new_key_scope.clone();
+        let new_key_expiry = new_key_expiry.clone();
         Callback::from(move |_| {
-            let audio_dispatch = audio_dispatch.clone();
+            let name = new_key_name.trim().to_string();
+            let scope = (*new_key_scope).clone();
+            let expires_in_days = *new_key_expiry;
             let api_key = api_key.clone();
             let user_id = request_state
                 .user_details
@@ -155,10 +215,16 @@ pub fn api_keys() -> Html {
             let server_name = server_name.clone();
             let page_state = page_state.clone();
             let new_api_key = new_api_key.clone();
+            let request = CreateAPIKeyRequest {
+                user_id: user_id.unwrap(),
+                name: (!name.is_empty()).then_some(name),
+                scope,
+                expires_in_days,
+            };
             wasm_bindgen_futures::spawn_local(async move {
                 match call_create_api_key(
                     &server_name.unwrap(),
-                    user_id.unwrap(),
+                    &request,
                     &api_key.unwrap().unwrap(),
                 )
                 .await
@@ -168,9 +234,7 @@ pub fn api_keys() -> Html {
                         page_state.set(PageState::Shown); // Move to the edit page state
                     }
                     Err(e) => {
-                        audio_dispatch.reduce_mut(|audio_state| {
-                            audio_state.error_message = Option::from(e.to_string())
-                        });
+                        notify_error(e.to_string());
                     }
                 }
             });
@@ -186,7 +250,6 @@ pub fn api_keys() -> Html {
         // Assume you have user_id and api_key from context or props
         let user_id = 1; // Example user_id
         Callback::from(move |_| {
-            let audio_dispatch = audio_dispatch_call.clone();
             let api_key = api_key.clone();
             // let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
             let server_name = server_name.clone();
@@ -206,17 +269,11 @@ pub fn api_keys() -> Html {
                 .await
                 {
                     Ok(_) => {
-                        audio_dispatch.reduce_mut(|audio_state| {
-                            audio_state.info_message =
-                                Option::from(format!("API key deleted successfully"))
-                        });
+                        notify_info(I18n::current().t("api_keys.deleted"));
                         // Update UI accordingly, e.g., remove the deleted API key from the list
                     }
                     Err(e) => {
-                        audio_dispatch.reduce_mut(|audio_state| {
-                            audio_state.error_message =
-                                Option::from(format!("Error Deleting API Key: {}", e))
-                        });
+                        notify_error(I18n::current().tf("api_keys.delete_error", &[("error", &e.to_string())]));
                     }
                 }
                 page_state.set(PageState::Hidden); // Hide modal after deletion
@@ -245,20 +302,20 @@ pub fn api_keys() -> Html {
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                         <h3 class="text-xl font-semibold item_container-text">
-                            {"Delete Api Key"}
+                            { i18n.t("api_keys.delete_title") }
                         </h3>
                         <p class="text-m font-semibold">
-                        {"Are you sure you want to delete this API Key? This action cannot be undone."}
+                        { i18n.t("api_keys.delete_body") }
                         </p>
                         <div class="flex justify-between space-x-4">
                             <button onclick={delete_api_key} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                                {"Delete"}
+                                { i18n.t("common.delete") }
                             </button>
                             <button onclick={close_modal.clone()} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                                {"Cancel"}
+                                { i18n.t("common.cancel") }
                             </button>
                         </div>
                     </div>
@@ -267,6 +324,49 @@ pub fn api_keys() -> Html {
         </div>
     };
 
+    let new_key_modal = html! {
+        <div id="new-api-key-modal" tabindex="-1" aria-hidden="true" class="fixed top-0 right-0 left-0 z-50 flex justify-center items-center w-full h-[calc(100%-1rem)] max-h-full bg-black bg-opacity-25" onclick={on_background_click.clone()}>
+            <div class="modal-container relative p-4 w-full max-w-md max-h-full rounded-lg shadow" onclick={stop_propagation.clone()}>
+                <div class="flex flex-col items-start justify-between p-4 md:p-5 border-b rounded-t ">
+                    <button onclick={close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
+                        <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
+                            <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
+                        </svg>
+                        <span class="sr-only">{ i18n.t("common.close_modal") }</span>
+                    </button>
+                    <h3 class="item_container-text text-xl font-semibold">
+                        { i18n.t("api_keys.new_key") }
+                    </h3>
+                    <label for="api-key-name" class="block mb-2 mt-2 text-sm font-medium item_container-text">{ i18n.t("api_keys.name") }</label>
+                    <input oninput={on_key_name_change} value={(*new_key_name).clone()} type="text" id="api-key-name" placeholder={i18n.t("api_keys.name_placeholder")} class="search-bar-input border text-sm rounded-lg block w-full p-2.5" />
+                    <label for="api-key-scope" class="block mb-2 mt-2 text-sm font-medium item_container-text">{ i18n.t("api_keys.access") }</label>
+                    <select id="api-key-scope" onchange={on_key_scope_change} class="search-bar-input border text-sm rounded-lg block w-full p-2.5">
+                        { for API_KEY_SCOPES.iter().map(|(value, label)| html! {
+                            <option value={*value} selected={*new_key_scope == *value}>{ i18n.t(label) }</option>
+                        }) }
+                    </select>
+                    <p class="item_container-text text-xs mt-1">
+                        { match new_key_scope.as_str() {
+                            "read" => i18n.t("api_keys.scope.read_description"),
+                            "sync" => i18n.t("api_keys.scope.sync_description"),
+                            _ => i18n.t("api_keys.scope.admin_description"),
+                        } }
+                    </p>
+                    <label for="api-key-expiry" class="block mb-2 mt-2 text-sm font-medium item_container-text">{ i18n.t("api_keys.expires") }</label>
+                    <select id="api-key-expiry" onchange={on_key_expiry_change} class="search-bar-input border text-sm rounded-lg block w-full p-2.5">
+                        <option value="" selected={new_key_expiry.is_none()}>{ i18n.t("common.never") }</option>
+                        { for API_KEY_EXPIRY_DAYS.iter().map(|days| html! {
+                            <option value={days.to_string()} selected={*new_key_expiry == Some(*days)}>{ i18n.tn("api_keys.expires_in_days", *days as i64, &[]) }</option>
+                        }) }
+                    </select>
+                    <button onclick={request_api_key} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
+                        { i18n.t("api_keys.create") }
+                    </button>
+                </div>
+            </div>
+        </div>
+    };
+
     let create_api_modal = html! {
         <div id="create-user-modal" tabindex="-1" aria-hidden="true" class="fixed top-0 right-0 left-0 z-50 flex justify-center items-center w-full h-[calc(100%-1rem)] max-h-full bg-black bg-opacity-25" onclick={on_background_click.clone()}>
             <div class="modal-container relative p-4 w-full max-w-md max-h-full rounded-lg shadow" onclick={stop_propagation.clone()}>
@@ -275,19 +375,19 @@ pub fn api_keys() -> Html {
                         <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                             <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                         </svg>
-                        <span class="sr-only">{"Close modal"}</span>
+                        <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                     </button>
                     <h3 class="item_container-text text-xl font-semibold">
-                        {"New Api Key Created"}
+                        { i18n.t("api_keys.created_title") }
                     </h3>
                     <p class="text-m font-semibold item_container-text">
-                    {"Copy the API Key Listed Below. Be sure to save it in a safe place. You will only ever be able to view it once. You can always just create a new one if you lose it."}
+                    { i18n.t("api_keys.created_body") }
                     </p>
                     <div class="mfa-code-box mt-4 p-4 rounded-md overflow-x-auto whitespace-nowrap max-w-full">
                         {api_key_display}
                     </div>
                     <button onclick={close_modal.clone()} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                        {"OK"}
+                        { i18n.t("common.ok") }
                     </button>
                 </div>
             </div>
@@ -298,26 +398,31 @@ pub fn api_keys() -> Html {
         <>
         {
             match *page_state {
+            PageState::Create => new_key_modal,
             PageState::Shown => create_api_modal,
             PageState::Delete => delete_api_modal,
             _ => html! {},
             }
         }
             <div class="p-4">
-                <p class="item_container-text text-lg font-bold mb-4">{"API Keys:"}</p>
-                <p class="item_container-text text-md mb-4">{"You can request a Pinepods API Key here. These keys can then be used in conjunction with other Pinepods apps (like Pinepods Firewood) to connect them to the Pinepods server. In addition, you can also use an API Key to authenticate to this server from any other Pinepods server. Sort of like using a different server as a client for this one."}</p>
-                <button onclick={request_api_key} class="mt-4 settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                    {"Request API Key"}
+                <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("api_keys.title") }</p>
+                <p class="item_container-text text-md mb-4">{ i18n.t("api_keys.description") }</p>
+                <button onclick={open_create_modal} class="mt-4 settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
+                    { i18n.t("api_keys.request") }
                 </button>
             </div>
             <div class="relative overflow-x-auto">
                 <table class="w-full text-sm text-left rtl:text-right">
                     <thead class="text-xs uppercase table-header">
                         <tr>
-                            <th scope="col" class="px-6 py-3">{"API ID"}</th>
-                            <th scope="col" class="px-6 py-3">{"Last 4 Digits"}</th>
-                            <th scope="col" class="px-6 py-3">{"Date Created"}</th>
-                            <th scope="col" class="px-6 py-3">{"User"}</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("api_keys.api_id") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("api_keys.name") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("api_keys.last_four") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("api_keys.access") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("api_keys.date_created") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("api_keys.expires") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("api_keys.last_used") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("api_keys.user") }</th>
                         </tr>
                     </thead>
                     <tbody>
@@ -328,8 +433,12 @@ pub fn api_keys() -> Html {
                             html! {
                                 <tr class="table-row border-b cursor-pointer" onclick={row_click_callback}>
                                     <td class="px-6 py-4">{ api_info.apikeyid }</td>
+                                    <td class="px-6 py-4">{ api_info.name.clone().unwrap_or_default() }</td>
                                     <td class="px-6 py-4">{ &api_info.lastfourdigits }</td>
+                                    <td class="px-6 py-4">{ i18n.t(scope_label_id(&api_info.scope)) }</td>
                                     <td class="px-6 py-4">{ &api_info.created }</td>
+                                    <td class="px-6 py-4">{ api_info.expires.clone().unwrap_or_else(|| i18n.t("common.never")) }</td>
+                                    <td class="px-6 py-4">{ api_info.lastused.clone().unwrap_or_else(|| i18n.t("common.never")) }</td>
                                     <td class="px-6 py-4">{ &api_info.username }</td>
                                 </tr>
                             }
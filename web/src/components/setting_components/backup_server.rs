@@ -3,14 +3,16 @@ use wasm_bindgen::JsCast;
 use web_sys::{window, Blob, Url, BlobPropertyBag};
 use wasm_bindgen::JsValue;
 use yewdux::prelude::*;
-use crate::components::context::{UIState, AppState};
+use crate::components::context::AppState;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::notify_error;
 use crate::requests::setting_reqs::call_backup_server;
 
 #[function_component(BackupServer)]
 pub fn backup_server() -> Html {
     let database_password = use_state(|| "".to_string());
     let (state, _dispatch) = use_store::<AppState>();
-    let (_audio_state, audio_dispatch) = use_store::<UIState>();
+    let i18n = use_i18n();
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
     let blob_property_bag = BlobPropertyBag::new();
@@ -20,13 +22,11 @@ pub fn backup_server() -> Html {
         let api_key = api_key.clone();
         let server_name = server_name.clone();
         let blob_property_bag = blob_property_bag.clone();
-        let audio_dispatch_call = audio_dispatch.clone();
         Callback::from(move |_| {
-            let audio_dispatch_call = audio_dispatch_call.clone();
             let db_pass = (*database_password).trim().to_string();
             if db_pass.is_empty() {
 
-                audio_dispatch.reduce_mut(|audio_state| audio_state.error_message = Option::from("Database password cannot be empty.".to_string()));
+                notify_error(I18n::current().t("backup.password_required"));
                 return;
             }
             let api_key = api_key.clone().unwrap_or_default();
@@ -54,7 +54,7 @@ pub fn backup_server() -> Html {
                         }
                     },
                     Err(e) => {
-                        audio_dispatch_call.reduce_mut(|audio_state| audio_state.error_message = Option::from(format!("Error backing up server - Maybe wrong password?: {}", e).to_string()));
+                        notify_error(I18n::current().tf("backup.error", &[("error", &e.to_string())]));
                     }
                 }
             });
@@ -63,8 +63,8 @@ pub fn backup_server() -> Html {
 
     html! {
         <div class="p-4">
-            <p class="item_container-text text-lg font-bold mb-4">{"Backup Server Data:"}</p>
-            <p class="item_container-text text-md mb-4">{"Download a backup of the entire server database here. This includes all users, podcasts, episodes, settings, and API keys. Use this to migrate to a new server or restore your current server."}</p>
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("backup.title") }</p>
+            <p class="item_container-text text-md mb-4">{ i18n.t("backup.description") }</p>
             <br/>
             <div class="flex items-center">
                 <input type="text" id="db=pw"                    
@@ -74,7 +74,7 @@ pub fn backup_server() -> Html {
                 })} 
                 class="search-bar-input border text-sm rounded-lg block w-full p-2.5" placeholder="mYDBp@ss!" />
                 <button onclick={on_download_click} class="mt-2 settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                {"Authenticate"}
+                { i18n.t("nextcloud.authenticate") }
                 </button>
             </div>
         </div>
@@ -1,6 +1,7 @@
 use crate::components::context::AppState;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, notify_success};
 use crate::requests::setting_reqs::call_add_custom_feed;
-use gloo_timers::callback::Timeout;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 use yewdux::prelude::*;
@@ -11,12 +12,11 @@ pub fn custom_feed() -> Html {
 
     let pod_user = use_state(|| "".to_string());
     let pod_pass = use_state(|| "".to_string());
-    let error_message = use_state(|| None::<String>);
-    let info_message = use_state(|| None::<String>);
     let is_loading = use_state(|| false);
 
     // API key, server name, and other data can be fetched from AppState if required
     let (state, _) = use_store::<AppState>();
+    let i18n = use_i18n();
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
@@ -43,21 +43,6 @@ pub fn custom_feed() -> Html {
             pod_pass.set(input.value());
         })
     };
-    // Function to clear message
-    let clear_error = {
-        let error_message = error_message.clone();
-        Callback::from(move |_| {
-            error_message.set(None);
-        })
-    };
-
-    let clear_info = {
-        let info_message = info_message.clone();
-        Callback::from(move |_| {
-            info_message.set(None);
-        })
-    };
-
     // Ensure `onclick_restore` is correctly used
     let custom_loading = is_loading.clone();
     let add_custom_feed = {
@@ -65,19 +50,11 @@ pub fn custom_feed() -> Html {
         let server_name = server_name.unwrap_or_default();
         let user_id = user_id;
         let feed_url = (*feed_url).clone();
-        let error_message = error_message.clone();
-        let info_message = info_message.clone();
-        let clear_info = clear_info.clone();
-        let clear_error = clear_error.clone();
         let is_loading_call = custom_loading.clone();
         Callback::from(move |_| {
-            let clear_info = clear_info.clone();
-            let clear_error = clear_error.clone();
             let server_name = server_name.clone();
             let api_key = api_key.clone();
             let feed_url = feed_url.clone();
-            let error_message = error_message.clone();
-            let info_message = info_message.clone();
             is_loading_call.set(true);
             let is_loading_wasm = is_loading_call.clone();
             let unstate_pod_user = (*pod_user).clone();
@@ -94,12 +71,10 @@ pub fn custom_feed() -> Html {
                 .await
                 {
                     Ok(_) => {
-                        info_message.set(Some("Podcast Successfully Added".to_string()));
-                        Timeout::new(5000, move || clear_info.emit(())).forget();
+                        notify_success(I18n::current().t("custom_feed.added"));
                     }
                     Err(e) => {
-                        error_message.set(Some(e.to_string()));
-                        Timeout::new(5000, move || clear_error.emit(())).forget();
+                        notify_error(e.to_string());
                     }
                 }
                 is_loading_wasm.set(false);
@@ -109,8 +84,8 @@ pub fn custom_feed() -> Html {
 
     html! {
         <div class="p-4">
-            <p class="item_container-text text-lg font-bold mb-4">{"Add Feed:"}</p>
-            <p class="item_container-text text-md mb-4">{"Use this to add a custom feed to your podcasts. Simply enter the feed url and click the button below. This is great in case you subscibe to premium podcasts and they aren't availble in The Pocast Index or other indexing services. After adding here, podcasts will show up and be available just like any others."}</p>
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("custom_feed.title") }</p>
+            <p class="item_container-text text-md mb-4">{ i18n.t("custom_feed.description") }</p>
 
             <br/>
             <div>
@@ -118,22 +93,14 @@ pub fn custom_feed() -> Html {
                     <input id="feed_url" oninput={update_feed.clone()} class="search-bar-input border text-sm rounded-lg block w-full p-2.5" placeholder="https://bestpodcast.com/feed.xml" />
                 </div>
                 <div>
-                    <input id="username" oninput={update_pod_user.clone()} class="search-bar-input border text-sm rounded-lg block w-full p-2.5 mt-2" placeholder="Username (optional)" />
+                    <input id="username" oninput={update_pod_user.clone()} class="search-bar-input border text-sm rounded-lg block w-full p-2.5 mt-2" placeholder={i18n.t("custom_feed.username_placeholder")} />
                 </div>
                 <div>
-                    <input id="password" type="password" oninput={update_pod_pass.clone()} class="search-bar-input border text-sm rounded-lg block w-full p-2.5 mt-2" placeholder="Password (optional)" />
+                    <input id="password" type="password" oninput={update_pod_pass.clone()} class="search-bar-input border text-sm rounded-lg block w-full p-2.5 mt-2" placeholder={i18n.t("custom_feed.password_placeholder")} />
                 </div>
-                // Display error message inline right below the text input
-                if let Some(error) = &*error_message {
-                    <span class="text-red-600 text-xs">{ error }</span>
-                }
-                // Display informational message inline right below the text input
-                if let Some(info) = &*info_message {
-                    <span class="text-green-600 text-xs">{ info }</span>
-                }
             </div>
             <button onclick={add_custom_feed} class="mt-2 settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline" disabled={*is_loading}>
-            {"Add Feed"}
+            { i18n.t("custom_feed.add") }
             if *is_loading {
                 <span class="ml-2 spinner-border animate-spin inline-block w-4 h-4 border-2 rounded-full"></span>
             }
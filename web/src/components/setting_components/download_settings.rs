@@ -1,20 +1,20 @@
 use yew::prelude::*;
 use yewdux::prelude::*;
-use crate::components::context::{AppState, UIState};
+use crate::components::context::AppState;
 use yew::platform::spawn_local;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::notify_error;
 use crate::requests::setting_reqs::{call_download_status, call_enable_disable_downloads};
 use std::borrow::Borrow;
 
 #[function_component(DownloadSettings)]
 pub fn download_settings() -> Html {
+    let i18n = use_i18n();
     let (state, _dispatch) = use_store::<AppState>();
-    let (_audio_state, audio_dispatch) = use_store::<UIState>();
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let _user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
-    let _error_message = state.error_message.clone();
     let download_status = use_state(|| false);
-    let audio_dispatch_effect = audio_dispatch.clone();
 
     {
         let download_status = download_status.clone();
@@ -30,7 +30,7 @@ pub fn download_settings() -> Html {
                             download_status.set(download_status_response);
                         },
                         Err(e) => {
-                            audio_dispatch_effect.reduce_mut(|audio_state| audio_state.error_message = Option::from(format!("Error getting download status: {}", e)));
+                            notify_error(I18n::current().tf("download_settings.status_error", &[("error", &e.to_string())]));
 
 
                         },
@@ -47,14 +47,13 @@ pub fn download_settings() -> Html {
 
     html! {
         <div class="p-4"> // You can adjust the padding as needed
-            <p class="item_container-text text-lg font-bold mb-4">{"Server Download Settings:"}</p> // Styled paragraph
-            <p class="item_container-text text-md mb-4">{"You can choose to enable or disable server downloads here. This does not effect local downloads. There's two types of downloads in Pinepods. Local and Server. Local downloads would be where a user clicks download and it downloads the podcast to their local machine. A server download is when a user downloads the podcast to the server specifically. This is meant as an archival option. If you're concerned the podcast may not be always available you may want to archive it using this option. See the Pinepods documentation for mapping a specific location (like a NAS) as the location server downloads download to. You might want to turn this option off if you have self service enabled or your Pinepods server accessible to the internet. You wouldn't want any random user filling up your server."}</p> // Styled paragraph
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("download_settings.title") }</p> // Styled paragraph
+            <p class="item_container-text text-md mb-4">{ i18n.t("download_settings.description") }</p> // Styled paragraph
             <label class="relative inline-flex items-center cursor-pointer">
             <input type="checkbox" disabled={**loading.borrow()} checked={**download_status.borrow()} class="sr-only peer" onclick={Callback::from(move |_| {
                 let api_key = api_key.clone();
                 let server_name = server_name.clone();
                 let download_status = html_download.clone();
-                let audio_dispatch = audio_dispatch.clone();
                 let loading = loading.clone();
                 let future = async move {
                     loading.set(true);
@@ -66,7 +65,7 @@ pub fn download_settings() -> Html {
                                 download_status.set(!*current_status);
                             },
                             Err(e) => {
-                                audio_dispatch.reduce_mut(|audio_state| audio_state.error_message = Option::from(format!("Error enabling/disabling downloads: {}", e)));
+                                notify_error(I18n::current().tf("download_settings.toggle_error", &[("error", &e.to_string())]));
 
                             },
                         }
@@ -76,7 +75,7 @@ pub fn download_settings() -> Html {
                 spawn_local(future);
             })} />
                 <div class="w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-blue-300 dark:peer-focus:ring-blue-800 rounded-full peer dark:bg-gray-700 peer-checked:after:translate-x-full rtl:peer-checked:after:-translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:start-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all dark:border-gray-600 peer-checked:bg-blue-600"></div>
-                <span class="ms-3 text-sm font-medium item_container-text">{"Enable Server Downloads"}</span>
+                <span class="ms-3 text-sm font-medium item_container-text">{ i18n.t("download_settings.enable") }</span>
             </label>
         </div>
     }
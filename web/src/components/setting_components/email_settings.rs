@@ -1,21 +1,21 @@
 use yew::prelude::*;
 use yewdux::prelude::*;
-use crate::components::context::{AppState, UIState};
+use crate::components::context::AppState;
 use yew::platform::spawn_local;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, notify_info};
 use crate::requests::setting_reqs::{call_get_email_settings, EmailSettingsResponse, SendEmailSettings, call_save_email_settings, call_send_test_email, call_send_email, TestEmailSettings};
 use std::ops::Deref;
 // use crate::gen_components::_ErrorMessageProps::error_message;
 
 #[function_component(EmailSettings)]
 pub fn email_settings() -> Html {
+    let i18n = use_i18n();
     let (state, _dispatch) = use_store::<AppState>();
-    let (audio_state, audio_dispatch) = use_store::<UIState>();
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
     let _user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
     let user_email = state.user_details.as_ref().map(|ud| ud.Email.clone());
-    let _error_message = audio_state.error_message.clone();
-    let _info_message = audio_state.info_message.clone();
     let auth_required = use_state(|| false);
 
     let toggle_auth_required = {
@@ -24,7 +24,6 @@ pub fn email_settings() -> Html {
     };
         // Define the type of user in the Vec
     let email_values: UseStateHandle<EmailSettingsResponse> = use_state(EmailSettingsResponse::default);
-    let audio_dispatch_effect = audio_dispatch.clone();
 
     {
         let email_values = email_values.clone();
@@ -40,7 +39,7 @@ pub fn email_settings() -> Html {
                             email_values.set(email_info);
                         },
                         Err(e) => {
-                            audio_dispatch_effect.reduce_mut(|audio_state| audio_state.error_message = Option::from(format!("Error getting user info: {}", e)));
+                            notify_error(I18n::current().tf("email_settings.load_error", &[("error", &e.to_string())]));
                         },
                     }
                 }
@@ -142,7 +141,6 @@ pub fn email_settings() -> Html {
         let password_ref = password_ref.clone();
         let auth_required = auth_required.clone();
         let page_state = page_state.clone();
-        let audio_dispatch_call = audio_dispatch.clone();
         Callback::from(move |_: MouseEvent| {
             let server_name = edit_server_name.clone();
             let server_name_ref = server_name_ref.clone().deref().to_string();
@@ -172,7 +170,7 @@ pub fn email_settings() -> Html {
             };
             spawn_local(future);
             page_state.set(PageState::Hidden);
-            audio_dispatch_call.reduce_mut(|audio_state| audio_state.info_message = Option::from("Email Settings Saved!".to_string()));
+            notify_info(I18n::current().t("email_settings.saved"));
         })
     };
 
@@ -183,29 +181,28 @@ pub fn email_settings() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Email sent!"}
+                            { i18n.t("email_settings.sent_title") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <p class="text-m font-semibold">
-                    {"Once you verify you recieved it click Save below to save the email settings to the server."}
+                    { i18n.t("email_settings.sent_body") }
                     </p>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
-                            <button type="submit" onclick={on_edit_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{"Verify and Save Email Settings"}</button>
-                            <button type="submit" onclick={on_close_modal} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{"Cancel"}</button>
+                            <button type="submit" onclick={on_edit_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{ i18n.t("email_settings.verify_and_save") }</button>
+                            <button type="submit" onclick={on_close_modal} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{ i18n.t("common.cancel") }</button>
                         </form>
                     </div>
                 </div>
             </div>
         </div>
     };
-    let audio_send_test = audio_dispatch.clone();
     let api_test = api_key.clone();
     let submit_email = user_email.clone();
     let on_submit = {
@@ -219,7 +216,6 @@ pub fn email_settings() -> Html {
         let auth_required = auth_required.clone();
         let page_state = page_state_edit.clone();
         Callback::from(move |_: MouseEvent| {
-            let audio_dispatch_call = audio_send_test.clone();
             let server_name = server_name.clone();
             let server_name_ref = server_name_ref.clone().deref().to_string();
             let server_port = server_port_ref.clone().deref().to_string();
@@ -240,7 +236,7 @@ pub fn email_settings() -> Html {
                 email_username: email_username.clone(),
                 email_password: email_password.clone(),
                 to_email: submit_email.clone().unwrap().unwrap(),
-                message: "If you got this email Pinepods emailing works! Be sure to verify your settings to confirm!".to_string(),
+                message: I18n::current().t("email_settings.test_message"),
             };
             let server_name = server_name.clone();
             let api_key = api_test.clone().unwrap_or_default();
@@ -251,7 +247,7 @@ pub fn email_settings() -> Html {
                         page_state.set(PageState::Shown);
                     },
                     Err(e) => {
-                        audio_dispatch_call.reduce_mut(|audio_state| audio_state.error_message = Option::from(format!("Error: {}", e)));
+                        notify_error(I18n::current().tf("common.error", &[("error", &e.to_string())]));
                         // Handle the error, e.g., by showing an error message to the user
                     }
                 }
@@ -263,27 +259,25 @@ pub fn email_settings() -> Html {
     let on_test_email_send = {
         let server_name = server_name.clone(); // Assuming you have these values in your component's state
         let api_key = api_key.clone(); // Assuming you have API key in your component's state
-        let audio_dispatch_call = audio_dispatch.clone();
         
         Callback::from(move |_: MouseEvent| {
             let api_key = api_key.clone();
             let server_name = server_name.clone().unwrap_or_default(); // Ensure server_name has a default value if it's an Option
-            let audio_dispatch_call = audio_dispatch_call.clone();
             // Setting up the email settings. Adjust these values as necessary.
             let email_settings = SendEmailSettings {
                 to_email: user_email.clone().unwrap().unwrap(), // This should be dynamically set based on your application's needs
-                subject: "Test Pinepods Email".to_string(),
-                message: "This is an email from Pinepods. If you got this your email setup works!".to_string(),
+                subject: I18n::current().t("email_settings.test_subject"),
+                message: I18n::current().t("email_settings.test_body"),
             };
     
             let future = async move {
                 match call_send_email(server_name, api_key.unwrap_or_default().unwrap(), email_settings).await {
                     Ok(_) => {
-                        audio_dispatch_call.reduce_mut(|audio_state| audio_state.info_message = Option::from("Email sent successfully!".to_string()));
+                        notify_info(I18n::current().t("email_settings.test_sent"));
                         // Optionally, use dispatch_callback to update a global state or trigger other app-wide effects
                     },
                     Err(e) => {
-                        audio_dispatch_call.reduce_mut(|audio_state| audio_state.error_message = Option::from(format!("Error: {}", e)));
+                        notify_error(I18n::current().tf("common.error", &[("error", &e.to_string())]));
                         // Handle the error, e.g., by updating a state with the error message
                     }
                 }
@@ -302,19 +296,19 @@ pub fn email_settings() -> Html {
             }
         }
         <div class="p-4">
-            <h2 class="item_container-text text-xl font-bold mb-4">{"Email Setup:"}</h2>
-            <p class="item_container-text text-md mb-4">{"You can setup server Email settings here. Email is mostly used for self service password resets. The server will require that you verify your email settings setup before it will allow you to submit the settings you've entered."}</p>
-            <p class="item_container-text text-lg font-bold mb-4">{"Current Settings:"}</p>
+            <h2 class="item_container-text text-xl font-bold mb-4">{ i18n.t("email_settings.title") }</h2>
+            <p class="item_container-text text-md mb-4">{ i18n.t("email_settings.description") }</p>
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("email_settings.current") }</p>
             <div class="relative overflow-x-auto">
                 <table class="w-full text-sm text-left rtl:text-right text-gray-500 dark:text-gray-400">
                     <thead class="text-xs uppercase table-header">
                         <tr>
-                            <th scope="col" class="px-6 py-3">{"Server"}</th>
-                            <th scope="col" class="px-6 py-3">{"From Email"}</th>
-                            <th scope="col" class="px-6 py-3">{"Send Mode"}</th>
-                            <th scope="col" class="px-6 py-3">{"Encryption"}</th>
-                            <th scope="col" class="px-6 py-3">{"Auth Required"}</th>
-                            <th scope="col" class="px-6 py-3">{"Username"}</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("email_settings.server") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("email_settings.from_email") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("email_settings.send_mode") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("email_settings.encryption") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("email_settings.auth_required") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("common.username") }</th>
                         </tr>
                     </thead>
                     <tbody>
@@ -326,7 +320,7 @@ pub fn email_settings() -> Html {
                                 <td class="px-6 py-4">{ &email_values.FromEmail }</td>
                                 <td class="px-6 py-4">{ &email_values.SendMode }</td>
                                 <td class="px-6 py-4">{ &email_values.Encryption }</td>
-                                <td class="px-6 py-4">{ if email_values.AuthRequired == 1 { "Yes" } else { "No" } }</td>
+                                <td class="px-6 py-4">{ if email_values.AuthRequired == 1 { i18n.t("common.yes") } else { i18n.t("common.no") } }</td>
                                 <td class="px-6 py-4">{ &email_values.Username }</td>
                             </tr>
                         }
@@ -336,45 +330,45 @@ pub fn email_settings() -> Html {
             </div>
             <div class="flex mt-4">
             <button onclick={on_test_email_send} class="settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline mr-2">
-                {"Test Current Settings"}
+                { i18n.t("email_settings.test_current") }
             </button>
         
         </div>
-        <p class="item_container-text text-lg font-bold mb-4">{"Update Settings:"}</p>
+        <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("email_settings.update") }</p>
 
         <div class="flex mt-4">
-            <input oninput={on_server_name_change.clone()} type="text" placeholder="Server Name" class="email-input border p-2 mr-2 rounded"/>
+            <input oninput={on_server_name_change.clone()} type="text" placeholder={i18n.t("email_settings.server_name")} class="email-input border p-2 mr-2 rounded"/>
             <span class="item_container-text">{":"}</span>
-            <input oninput={on_server_port_change.clone()} type="text" placeholder="Port" class="email-input border p-2 ml-2 rounded"/>
+            <input oninput={on_server_port_change.clone()} type="text" placeholder={i18n.t("email_settings.port")} class="email-input border p-2 ml-2 rounded"/>
         </div>
 
         <div class="mt-4">
-            <p class="item_container-text font-medium">{"Send Mode:"}</p>
+            <p class="item_container-text font-medium">{ i18n.t("email_settings.send_mode_label") }</p>
             <select oninput={on_send_mode_change.clone()} class="email-select border p-2 rounded mr-2">
                 <option value="SMTP" selected=true>{"SMTP"}</option>
             </select>
         </div>
         <div class="mt-4">
-            <p class="item_container-text font-medium">{"Encryption:"}</p>
+            <p class="item_container-text font-medium">{ i18n.t("email_settings.encryption_label") }</p>
             <select oninput={on_encryption_change.clone()} class="email-select border p-2 rounded">
-                <option value="none" selected=true>{"None"}</option>
+                <option value="none" selected=true>{ i18n.t("email_settings.encryption_none") }</option>
                 <option>{"SSL/TLS"}</option>
                 <option>{"StartTLS"}</option>
             </select>
         </div>
 
-        <input oninput={on_from_email_change.clone()} type="text" placeholder="From Address" class="email-input border p-2 mt-4 rounded"/>
+        <input oninput={on_from_email_change.clone()} type="text" placeholder={i18n.t("email_settings.from_address")} class="email-input border p-2 mt-4 rounded"/>
 
         <div class="flex items-center mt-4">
             <input type="checkbox" id="auth_required" checked={*auth_required} onclick={toggle_auth_required}/>
-            <label for="auth_required" class="item_container-text ml-2">{"Authentication Required"}</label>
+            <label for="auth_required" class="item_container-text ml-2">{ i18n.t("email_settings.authentication_required") }</label>
         </div>
         {
             if *auth_required {
                 html! {
                                 <>
-                                    <input oninput={on_username_change.clone()} type="text" placeholder="Username" class="email-input border p-2 mt-4 rounded"/>
-                                    <input oninput={on_password_change.clone()} type="password" placeholder="Password" class="email-input border p-2 mt-4 rounded"/>
+                                    <input oninput={on_username_change.clone()} type="text" placeholder={i18n.t("common.username")} class="email-input border p-2 mt-4 rounded"/>
+                                    <input oninput={on_password_change.clone()} type="password" placeholder={i18n.t("common.password")} class="email-input border p-2 mt-4 rounded"/>
                                 </>
                             }
             } else {
@@ -383,7 +377,7 @@ pub fn email_settings() -> Html {
         }
         <div class="flex mt-4">
             <button onclick={on_submit} class="settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline mr-2">
-                {"Test & Submit"}
+                { i18n.t("email_settings.test_and_submit") }
             </button>
         </div>
         </div>
@@ -1,15 +1,17 @@
 use yew::prelude::*;
 use wasm_bindgen::JsCast;
 use yewdux::prelude::*;
-use crate::components::context::{AppState, UIState};
+use crate::components::context::AppState;
+use crate::components::i18n::{use_i18n, I18n};
 use web_sys::{window, Blob, Url, BlobPropertyBag};
 use wasm_bindgen::JsValue;
+use crate::components::notifications::notify_error;
 use crate::requests::setting_reqs::{call_backup_user};
 
 #[function_component(ExportOptions)]
 pub fn export_options() -> Html {
     let (state, _dispatch) = use_store::<AppState>();
-    let (_audio_state, audio_dispatch) = use_store::<UIState>();
+    let i18n = use_i18n();
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
@@ -20,7 +22,6 @@ pub fn export_options() -> Html {
     let onclick = {
         let blob_property_bag = blob_property_bag.clone();
         Callback::from(move |_| {
-            let audio_dispatch = audio_dispatch.clone();
             let bloberty_bag = blob_property_bag.clone();
             let api_key = api_key.clone();
             let server_name = server_name.clone();
@@ -48,7 +49,7 @@ pub fn export_options() -> Html {
                         }
                     }
                     Err(e) => {
-                        audio_dispatch.reduce_mut(|audio_state| audio_state.error_message = Option::from(format!("Error exporting OPML: {}", e)));
+                        notify_error(I18n::current().tf("export.error", &[("error", &e.to_string())]));
                     }
                 }
             });
@@ -59,11 +60,11 @@ pub fn export_options() -> Html {
 
     html! {
         <div class="p-4"> // You can adjust the padding as needed
-            <p class="item_container-text text-lg font-bold mb-4">{"Export Options:"}</p> // Styled paragraph
-            <p class="item_container-text text-md mb-4">{"You can export an OPML file containing your Podcasts here. This file can then be imported if you want to switch to a different podcast app or simply want a backup of your files just in case. Note, if you are exporting to add your podcasts to AntennaPod the Nextcloud Options below might better suit your needs. If you're an admin a full server backup might be a better solution as well on the Admin Settings Page."}</p> // Styled paragraph
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("export.title") }</p> // Styled paragraph
+            <p class="item_container-text text-md mb-4">{ i18n.t("export.description") }</p> // Styled paragraph
 
             <button onclick={onclick} class="mt-4 settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                {"Download/Export OPML"}
+                { i18n.t("export.download") }
             </button>
         </div>
     }
@@ -1,21 +1,21 @@
 use yew::prelude::*;
 use yewdux::prelude::*;
-use crate::components::context::{AppState, UIState};
+use crate::components::context::AppState;
 use yew::platform::spawn_local;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::notify_error;
 use crate::requests::setting_reqs::{call_guest_status, call_enable_disable_guest};
 use std::borrow::Borrow;
 
 
 #[function_component(GuestSettings)]
 pub fn guest_settings() -> Html {
+    let i18n = use_i18n();
     let (state, _dispatch) = use_store::<AppState>();
-    let (_audio_state, audio_dispatch) = use_store::<UIState>();
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let _user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
-    let _error_message = state.error_message.clone();
     let guest_status = use_state(|| false);
-    let audio_dispatch_effect = audio_dispatch.clone();
 
     {
         let guest_status = guest_status.clone();
@@ -31,7 +31,7 @@ pub fn guest_settings() -> Html {
                             guest_status.set(guest_status_response);
                         },
                         Err(e) => {
-                            audio_dispatch_effect.reduce_mut(|audio_state| audio_state.error_message = Option::from(format!("Error getting guest status: {}", e)));
+                            notify_error(I18n::current().tf("guest_settings.status_error", &[("error", &e.to_string())]));
                         },
 
                     }
@@ -48,12 +48,11 @@ pub fn guest_settings() -> Html {
 
     html! {
         <div class="p-4"> // You can adjust the padding as needed
-            <p class="item_container-text text-lg font-bold mb-4">{"Guest User Settings:"}</p> // Styled paragraph
-            <p class="item_container-text text-md mb-4">{"You can choose to enable or disable the Guest user here. It's always disabled by default. Basically, enabling the guest user enables a button on the login page to login as guest. This guest user essentially has access to add podcasts and listen to them in an ephemeral sense. Once logged out, the session is deleted along with any podcasts the Guest saved. If your Pinepods server is exposed to the internet you probably want to disable this option. It's meant more for demos or if you want to allow people to quickly listen to a podcast using your server."}</p> // Styled paragraph
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("guest_settings.title") }</p> // Styled paragraph
+            <p class="item_container-text text-md mb-4">{ i18n.t("guest_settings.description") }</p> // Styled paragraph
 
             <label class="relative inline-flex items-center cursor-pointer">
             <input type="checkbox" disabled={**loading.borrow()} checked={**guest_status.borrow()} class="sr-only peer" onclick={Callback::from(move |_| {
-                let audio_dispatch = audio_dispatch.clone();
                 let api_key = api_key.clone();
                 let server_name = server_name.clone();
                 let guest_status = html_guest.clone();
@@ -69,7 +68,7 @@ pub fn guest_settings() -> Html {
                             },
 
                             Err(e) => {
-                                audio_dispatch.reduce_mut(|audio_state| audio_state.error_message = Option::from(format!("Error enabling/disabling guest access: {}", e)));
+                                notify_error(I18n::current().tf("guest_settings.toggle_error", &[("error", &e.to_string())]));
                             },
                         }
                     }
@@ -80,9 +79,9 @@ pub fn guest_settings() -> Html {
             <div class="w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-blue-300 dark:peer-focus:ring-blue-800 rounded-full peer dark:bg-gray-700 peer-checked:after:translate-x-full rtl:peer-checked:after:-translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:start-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all dark:border-gray-600 peer-checked:bg-blue-600"></div>
             <span class="ms-3 text-sm font-medium item_container-text">{
                 if **guest_status.borrow() {
-                    "Disable Guest User"
+                    i18n.t("guest_settings.disable")
                 } else {
-                    "Enable Guest User"
+                    i18n.t("guest_settings.enable")
                 }
             }</span>
         </label>
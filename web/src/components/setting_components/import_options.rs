@@ -2,10 +2,12 @@ use std::collections::HashMap;
 use yew::prelude::*;
 use wasm_bindgen::JsCast;
 use yewdux::prelude::*;
-use crate::components::context::{UIState, AppState};
+use crate::components::context::AppState;
+use crate::components::i18n::{use_i18n, I18n};
 use web_sys::{FileReader, HtmlInputElement};
 use wasm_bindgen::closure::Closure;
 use crate::components::gen_funcs::parse_opml;
+use crate::components::notifications::notify_info;
 use crate::requests::pod_req::{call_add_podcast, PodcastValues};
 use crate::requests::search_pods::{call_parse_podcast_channel_info, PodcastInfo};
 use gloo::timers::callback::Interval;
@@ -90,12 +92,12 @@ async fn add_podcasts(server_name: &str, api_key: &Option<String>, user_id: i32,
 #[function_component(ImportOptions)]
 pub fn import_options() -> Html {
     let (state, _dispatch) = use_store::<AppState>();
+    let i18n = use_i18n();
     let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let import_pods = use_state(|| Vec::new());
     let show_verification = use_state(|| false);
-    let (_audio_state, audio_dispatch) = use_store::<UIState>();
     let import_progress = use_state(|| 0);
     let total_podcasts = use_state(|| 0);
     let current_podcast = use_state(String::default);
@@ -149,7 +151,6 @@ pub fn import_options() -> Html {
     
         Callback::from(move |_| {
             let dispatch_wasm_call = dispatch_wasm_conf.clone();
-            let audio_dispatch_call = audio_dispatch.clone();
             dispatch_wasm_call.reduce_mut(|state| state.is_loading = Some(true));
             let selected_podcasts: Vec<String> = (*import_pods)
                 .iter()
@@ -177,7 +178,6 @@ pub fn import_options() -> Html {
                             
                                 let callback = Closure::wrap(Box::new(move || {
                                     let dispatch_wasm = dispatch_wasm_call.clone();
-                                    let audio_dispatch = audio_dispatch_call.clone();
                                     let server_name = server_name.clone();
                                     let api_key = api_key.clone();
                                     let user_id = user_id;
@@ -200,7 +200,7 @@ pub fn import_options() -> Html {
                                                         interval.cancel();
                                                     }
                                                     dispatch_wasm.reduce_mut(|state| state.is_loading = Some(false));
-                                                    audio_dispatch.reduce_mut(|audio_state| audio_state.info_message = Option::from("OPML Import Completed!".to_string()));
+                                                    notify_info(I18n::current().t("import.completed"));
                                                 }
                                             }
                                             Err(e) => {
@@ -220,7 +220,7 @@ pub fn import_options() -> Html {
                             Err(e) => {
                                 log::error!("Failed to import OPML: {:?}", e);
                                 dispatch_wasm_call.reduce_mut(|state| state.is_loading = Some(false));
-                                audio_dispatch_call.reduce_mut(|audio_state| audio_state.info_message = Option::from("Failed to import OPML".to_string()));
+                                notify_info(I18n::current().t("import.failed"));
                             }
                         }
                     }
@@ -232,10 +232,10 @@ pub fn import_options() -> Html {
 
     html! {
         <div class="p-4">
-            <p class="item_container-text text-lg font-bold mb-4">{"Import Options:"}</p>
-            <p class="item_container-text text-md mb-4">{"You can Import an OPML of podcasts here. If you're migrating from a different podcast app this is probably the solution you want. Most podcast apps allow you to export a backup of your saved podcasts to an OPML file and this option can easily import them into Pinepods."}</p>
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("import.title") }</p>
+            <p class="item_container-text text-md mb-4">{ i18n.t("import.description") }</p>
             // <input class="settings-button" type="file" accept=".opml" onchange={onclick} />
-            <label class="input-button-label" for="fileInput">{ "Choose File" }</label>
+            <label class="input-button-label" for="fileInput">{ i18n.t("import.choose_file") }</label>
             <input id="fileInput" class="input-button" type="file" accept=".opml" onchange={onclick} />
             // Optionally display the content of the OPML file for debugging
             {
@@ -244,16 +244,16 @@ pub fn import_options() -> Html {
                         <div class="import-box">
                             <div>
                                 <p class="item_container-text">
-                                    {"The following podcasts were found. Please unselect any podcasts you don't want to add, and then click the button below. A large amount of podcasts will take a little while to parse all the feeds and add them. The loading animation will disappear once all complete. Be patient!"}
+                                    { i18n.t("import.found_podcasts") }
                                 </p>
-                                <button class="settings-button" onclick={on_confirm}>{"Add them!"}</button>
+                                <button class="settings-button" onclick={on_confirm}>{ i18n.t("import.add_them") }</button>
                             </div>
                             <div class="mt-4">
                                 <p class="item_container-text">
-                                    {format!("Progress: {}/{}", *import_progress, *total_podcasts)}
+                                    { i18n.tf("import.progress", &[("done", &import_progress.to_string()), ("total", &total_podcasts.to_string())]) }
                                 </p>
                                 <p class="item_container-text">
-                                    {format!("Currently importing: {}", *current_podcast)}
+                                    { i18n.tf("import.current", &[("podcast", &current_podcast)]) }
                                 </p>
                             </div>
                             {
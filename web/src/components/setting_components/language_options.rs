@@ -0,0 +1,41 @@
+use crate::components::i18n::{set_language, use_i18n, I18n, Language};
+use crate::components::notifications::notify_success;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+/// Picks the interface language. Dates and numbers follow it too; the date order and clock
+/// style stay whatever was chosen at first login.
+#[function_component(LanguageOptions)]
+pub fn language_options() -> Html {
+    let i18n = use_i18n();
+
+    let on_change = Callback::from(move |e: Event| {
+        let Some(select) = e.target_dyn_into::<HtmlSelectElement>() else {
+            return;
+        };
+        if let Some(language) = Language::from_code(&select.value()) {
+            set_language(language);
+            // Worded in the language just picked
+            notify_success(I18n { language }.t("language.saved"));
+        }
+    });
+
+    html! {
+        <div class="p-4">
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("language.heading") }</p>
+            <p class="item_container-text text-md mb-4">{ i18n.t("language.description") }</p>
+            <div class="theme-select-dropdown relative inline-block">
+                <select onchange={on_change} class="theme-select-dropdown appearance-none w-full border px-4 py-2 pr-8 rounded shadow leading-tight focus:outline-none focus:shadow-outline">
+                    { for Language::ALL.iter().map(|language| html! {
+                        <option value={language.code()} selected={*language == i18n.language}>
+                            { language.native_name() }
+                        </option>
+                    })}
+                </select>
+                <div class="theme-dropdown-arrow pointer-events-none absolute inset-y-0 right-0 flex items-center px-2">
+                    <svg class="fill-current h-4 w-4" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 20 20"><path d="M6.293 9.293a1 1 0 0 1 1.414 0L10 10.586l2.293-2.293a1 1 0 1 1 1.414 1.414l-3 3a1 1 0 0 1-1.414 0l-3-3a1 1 0 0 1 0-1.414z"/></svg>
+                </div>
+            </div>
+        </div>
+    }
+}
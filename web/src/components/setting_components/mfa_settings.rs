@@ -1,29 +1,85 @@
-use crate::components::context::{AppState, UIState};
+use crate::components::context::AppState;
 use crate::components::episodes_layout::SafeHtml;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::notify_error;
+use crate::components::setting_components::passkey_settings::PasskeyOptions;
 use crate::requests::setting_reqs::{
-    call_disable_mfa, call_generate_mfa_secret, call_mfa_settings, call_verify_temp_mfa,
+    call_disable_mfa, call_generate_mfa_secret, call_generate_recovery_codes,
+    call_get_recovery_code_count, call_mfa_settings, call_verify_temp_mfa,
 };
 use std::borrow::Borrow;
-use wasm_bindgen::JsCast;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{window, Blob, BlobPropertyBag, Url};
 use yew::platform::spawn_local;
 use yew::prelude::*;
 use yewdux::prelude::*;
 
+fn recovery_codes_text(codes: &[String]) -> String {
+    format!(
+        "{}\n\n{}\n",
+        I18n::current().t("mfa.recovery_codes_file_intro"),
+        codes.join("\n")
+    )
+}
+
+fn download_recovery_codes(codes: &[String]) {
+    let array = js_sys::Array::new();
+    array.push(&JsValue::from_str(&recovery_codes_text(codes)));
+    let blob_property_bag = BlobPropertyBag::new();
+    blob_property_bag.set_type("text/plain");
+    let Ok(blob) = Blob::new_with_str_sequence_and_options(&array, &blob_property_bag) else {
+        return;
+    };
+    let Ok(url) = Url::create_object_url_with_blob(&blob) else {
+        return;
+    };
+    if let Some(document) = window().and_then(|w| w.document()) {
+        if let Ok(a) = document
+            .create_element("a")
+            .map(|a| a.unchecked_into::<web_sys::HtmlAnchorElement>())
+        {
+            a.set_href(&url);
+            a.set_download("pinepods-recovery-codes.txt");
+            a.click();
+        }
+    }
+    let _ = Url::revoke_object_url(&url);
+}
+
+/// Print just the codes, from a window of their own rather than the settings page.
+fn print_recovery_codes(codes: &[String]) {
+    let Some(print_window) = window()
+        .and_then(|w| w.open_with_url_and_target("", "_blank").ok())
+        .flatten()
+    else {
+        return;
+    };
+    if let Some(body) = print_window.document().and_then(|d| d.body()) {
+        let pre = print_window
+            .document()
+            .and_then(|d| d.create_element("pre").ok());
+        if let Some(pre) = pre {
+            pre.set_text_content(Some(&recovery_codes_text(codes)));
+            let _ = body.append_child(&pre);
+        }
+    }
+    let _ = print_window.print();
+    let _ = print_window.close();
+}
+
 #[function_component(MFAOptions)]
 pub fn mfa_options() -> Html {
+    let i18n = use_i18n();
     let (state, _dispatch) = use_store::<AppState>();
-    let (_audio_state, audio_dispatch) = use_store::<UIState>();
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
-    let _error_message = state.error_message.clone();
     let mfa_status = use_state(|| false);
     let code = use_state(|| "".to_string());
 
     let effect_user_id = user_id.clone();
     let effect_api_key = api_key.clone();
     let effect_server_name = server_name.clone();
-    let audio_dispatch_effect = audio_dispatch.clone();
     {
         let mfa_status = mfa_status.clone();
         use_effect_with(
@@ -43,10 +99,7 @@ pub fn mfa_options() -> Html {
                                 mfa_status.set(mfa_settings_response);
                             }
                             Err(e) => {
-                                audio_dispatch_effect.reduce_mut(|audio_state| {
-                                    audio_state.error_message =
-                                        Option::from(format!("Error getting MFA status: {}", e))
-                                });
+                                notify_error(I18n::current().tf("mfa.status_error", &[("error", &e.to_string())]));
                             }
                         }
                     }
@@ -57,7 +110,6 @@ pub fn mfa_options() -> Html {
             },
         );
     }
-    let audio_dispatch_refresh = audio_dispatch.clone();
     // Re-fetch MFA status after setup is complete
     {
         let mfa_status = mfa_status.clone();
@@ -78,10 +130,7 @@ pub fn mfa_options() -> Html {
                             mfa_status.set(mfa_settings_response);
                         }
                         Err(e) => {
-                            audio_dispatch_refresh.reduce_mut(|audio_state| {
-                                audio_state.error_message =
-                                    Option::from(format!("Error getting MFA status: {}", e))
-                            });
+                            notify_error(I18n::current().tf("mfa.status_error", &[("error", &e.to_string())]));
                         }
                     }
                 }
@@ -98,18 +147,75 @@ pub fn mfa_options() -> Html {
     enum PageState {
         Hidden,
         Setup,
+        RecoveryCodes,
     }
 
     // Define the initial state
     let page_state = use_state(|| PageState::Hidden);
     let mfa_code = use_state(|| String::new());
     let mfa_secret = use_state(|| String::new());
+    // Only ever held between generating the codes and closing the modal that shows them
+    let recovery_codes = use_state(Vec::<String>::new);
+    let recovery_remaining = use_state(|| None::<i64>);
+
+    {
+        let recovery_remaining = recovery_remaining.clone();
+        let api_key = api_key.clone();
+        let server_name = server_name.clone();
+        let user_id = user_id.clone();
+        use_effect_with(
+            (*mfa_status, recovery_codes.len()),
+            move |(mfa_enabled, _)| {
+                recovery_remaining.set(None);
+                if let (true, Some(Some(api_key)), Some(server_name), Some(user_id)) =
+                    (*mfa_enabled, api_key, server_name, user_id)
+                {
+                    wasm_bindgen_futures::spawn_local(async move {
+                        if let Ok(remaining) =
+                            call_get_recovery_code_count(&server_name, &api_key, user_id).await
+                        {
+                            recovery_remaining.set(Some(remaining));
+                        }
+                    });
+                }
+                || ()
+            },
+        );
+    }
+
+    let generate_recovery_codes = {
+        let page_state = page_state.clone();
+        let recovery_codes = recovery_codes.clone();
+        let api_key = api_key.clone();
+        let server_name = server_name.clone();
+        let user_id = user_id.clone();
+        Callback::from(move |_: ()| {
+            let (Some(Some(api_key)), Some(server_name), Some(user_id)) =
+                (api_key.clone(), server_name.clone(), user_id)
+            else {
+                return;
+            };
+            let page_state = page_state.clone();
+            let recovery_codes = recovery_codes.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match call_generate_recovery_codes(&server_name, &api_key, user_id).await {
+                    Ok(codes) => {
+                        recovery_codes.set(codes);
+                        page_state.set(PageState::RecoveryCodes);
+                    }
+                    Err(e) => notify_error(I18n::current().tf("mfa.recovery_codes_error", &[("error", &e.to_string())])),
+                }
+            });
+        })
+    };
 
     // Define the function to close the modal
     let close_modal = {
         let page_state = page_state.clone();
+        let recovery_codes = recovery_codes.clone();
         Callback::from(move |_| {
             page_state.set(PageState::Hidden);
+            recovery_codes.set(Vec::new());
         })
     };
 
@@ -200,6 +306,7 @@ pub fn mfa_options() -> Html {
         let server_name = server_name.clone();
         let code = code.clone();
         let mfa_status_clone = mfa_status.clone();
+        let generate_recovery_codes = generate_recovery_codes.clone();
 
         Callback::from(move |_| {
             let api_key = api_key.clone();
@@ -207,8 +314,8 @@ pub fn mfa_options() -> Html {
             let server_name = server_name.clone();
             let page_state = page_state.clone();
             let code = code.clone();
-            let audio_dispatch = audio_dispatch.clone();
             let mfa_status_update = mfa_status_clone.clone();
+            let generate_recovery_codes = generate_recovery_codes.clone();
 
             wasm_bindgen_futures::spawn_local(async move {
                 match call_verify_temp_mfa(
@@ -225,19 +332,15 @@ pub fn mfa_options() -> Html {
                             page_state.set(PageState::Hidden); // Example: hiding MFA prompt
                             mfa_status_update.set(true); // Update MFA status
                                                          // refresh_mfa_status.emit(());
+                            // A fresh authenticator always comes with a fresh set of codes
+                            generate_recovery_codes.emit(());
                         } else {
-                            audio_dispatch.reduce_mut(|audio_state| {
-                                audio_state.error_message =
-                                    Option::from("MFA code verification failed".to_string())
-                            });
+                            notify_error(I18n::current().t("mfa.verify_failed"));
                             // Handle failed verification, e.g., showing an error message
                         }
                     }
                     Err(e) => {
-                        audio_dispatch.reduce_mut(|audio_state| {
-                            audio_state.error_message =
-                                Option::from(format!("Failed to verify MFA code: {}", e))
-                        });
+                        notify_error(I18n::current().tf("mfa.verify_error", &[("error", &e.to_string())]));
                         // Handle error appropriately, e.g., showing an error message
                     }
                 }
@@ -265,13 +368,13 @@ pub fn mfa_options() -> Html {
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                         <h3 class="text-xl font-semibold">
-                            {"Setup MFA"}
+                            { i18n.t("mfa.setup_title") }
                         </h3>
                         <p class="item_container-text text-m font-semibold">
-                            {"Either scan the QR code with your authenticator app or enter the code manually. Then enter the code from your authenticator app to verify."}
+                            { i18n.t("mfa.setup_body") }
                         </p>
                         <div class="mt-4 self-center bg-white rounded-lg overflow-hidden p-4 shadow-lg">
                             <SafeHtml html={qr_code_svg} />
@@ -282,15 +385,58 @@ pub fn mfa_options() -> Html {
                             {(*mfa_secret).clone()}
                         </div>
                         <div>
-                            <label for="fullname" class="block mb-2 mt-2 text-sm font-semibold font-medium">{"Verify Code:"}</label>
+                            <label for="fullname" class="block mb-2 mt-2 text-sm font-semibold font-medium">{ i18n.t("mfa.verify_code") }</label>
                             <input oninput={on_code_change} type="text" id="fullname" name="fullname" class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-600 dark:border-gray-500 dark:placeholder-gray-400 dark:text-white" required=true />
                         </div>
                         <div class="flex justify-between space-x-4">
                             <button onclick={verify_code.clone()} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                                {"Verify"}
+                                { i18n.t("mfa.verify") }
                             </button>
                             <button onclick={close_modal.clone()} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                                {"Close"}
+                                { i18n.t("common.close") }
+                            </button>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        </div>
+    };
+
+    let on_download_codes = {
+        let recovery_codes = recovery_codes.clone();
+        Callback::from(move |_: MouseEvent| download_recovery_codes(&recovery_codes))
+    };
+    let on_print_codes = {
+        let recovery_codes = recovery_codes.clone();
+        Callback::from(move |_: MouseEvent| print_recovery_codes(&recovery_codes))
+    };
+    let on_regenerate_codes = {
+        let generate_recovery_codes = generate_recovery_codes.clone();
+        Callback::from(move |_: MouseEvent| generate_recovery_codes.emit(()))
+    };
+    let recovery_codes_modal = html! {
+        <div id="recovery-codes-modal" tabindex="-1" aria-hidden="true" class="fixed top-0 right-0 left-0 z-50 flex justify-center items-center w-full h-[calc(100%-1rem)] max-h-full bg-black bg-opacity-25">
+            <div class="modal-container relative p-4 w-full max-w-md max-h-full rounded-lg shadow" onclick={stop_propagation.clone()}>
+                <div class="modal-container relative rounded-lg shadow">
+                    <div class="flex flex-col items-start justify-between p-4 md:p-5 border-b rounded-t dark:border-gray-600">
+                        <h3 class="text-xl font-semibold">
+                            { i18n.t("mfa.recovery_codes") }
+                        </h3>
+                        <p class="item_container-text text-m font-semibold">
+                            { i18n.t("mfa.recovery_codes_body") }
+                        </p>
+                        <div class="mfa-code-box recovery-code-grid mt-4 p-4 rounded-md max-w-full">
+                            { for recovery_codes.iter().map(|code| html! { <span>{ code }</span> }) }
+                        </div>
+                        <div class="flex justify-between space-x-4">
+                            <button onclick={on_download_codes} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
+                                { i18n.t("mfa.download") }
+                            </button>
+                            <button onclick={on_print_codes} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
+                                { i18n.t("mfa.print") }
+                            </button>
+                            <button onclick={close_modal.clone()} class="mt-4 download-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
+                                { i18n.t("mfa.saved_them") }
                             </button>
                         </div>
                     </div>
@@ -304,19 +450,33 @@ pub fn mfa_options() -> Html {
         {
             match *page_state {
             PageState::Setup => setup_mfa_modal,
+            PageState::RecoveryCodes => recovery_codes_modal,
             _ => html! {},
             }
         }
         <div class="p-4"> // You can adjust the padding as needed
-            <p class="item_container-text text-lg font-bold mb-4">{"MFA Options:"}</p>
-            <p class="item_container-text text-md mb-4">{"You can setup edit, or remove MFA for your account here. MFA will only be prompted when new authentication is needed."}</p> // Styled paragraph
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("mfa.title") }</p>
+            <p class="item_container-text text-md mb-4">{ i18n.t("mfa.description") }</p> // Styled paragraph
 
             <label class="relative inline-flex items-center cursor-pointer">
             <input type="checkbox" disabled={**loading.borrow()} checked={**mfa_status.borrow()} class="sr-only peer" onclick={open_setup_modal.clone()} />
                 <div class="w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-blue-300 dark:peer-focus:ring-blue-800 rounded-full peer dark:bg-gray-700 peer-checked:after:translate-x-full rtl:peer-checked:after:-translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:start-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all dark:border-gray-600 peer-checked:bg-blue-600"></div>
-                <span class="ms-3 text-sm font-medium item_container-text">{"Enable MFA"}</span>
+                <span class="ms-3 text-sm font-medium item_container-text">{ i18n.t("mfa.enable") }</span>
             </label>
+            if *mfa_status {
+                <p class="item_container-text text-md mt-4 mb-2">
+                    { match *recovery_remaining {
+                        Some(0) => i18n.t("mfa.recovery_codes_none_left"),
+                        Some(remaining) => i18n.tn("mfa.recovery_codes_remaining", remaining, &[]),
+                        None => i18n.t("mfa.recovery_codes_hint"),
+                    } }
+                </p>
+                <button onclick={on_regenerate_codes} class="settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
+                    { i18n.t("mfa.generate_recovery_codes") }
+                </button>
+            }
         </div>
+        <PasskeyOptions />
         </>
     }
 }
@@ -1,5 +1,7 @@
 pub mod theme_options;
+pub mod language_options;
 pub mod mfa_settings;
+pub mod passkey_settings;
 pub mod export_settings;
 pub mod import_options;
 pub mod nextcloud_options;
@@ -8,6 +10,7 @@ pub mod user_settings;
 pub mod guest_settings;
 pub mod download_settings;
 pub mod user_self_service;
+pub mod password_policy;
 pub mod email_settings;
 pub mod backup_server;
 pub mod restore_server;
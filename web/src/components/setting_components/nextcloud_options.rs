@@ -1,4 +1,6 @@
-use crate::components::context::{AppState, UIState};
+use crate::components::context::AppState;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, notify_info};
 use crate::requests::pod_req::connect_to_episode_websocket;
 use crate::requests::setting_reqs::{
     call_add_gpodder_server, call_add_nextcloud_server, call_check_nextcloud_server,
@@ -36,7 +38,7 @@ async fn open_nextcloud_login(url: &str) -> Result<(), JsValue> {
 #[function_component(NextcloudOptions)]
 pub fn nextcloud_options() -> Html {
     let (state, _dispatch) = use_store::<AppState>();
-    let (audio_state, audio_dispatch) = use_store::<UIState>();
+    let i18n = use_i18n();
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let user_id = state.user_details.as_ref().map(|ud| ud.UserID.clone());
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
@@ -45,8 +47,6 @@ pub fn nextcloud_options() -> Html {
     let server_pass = use_state(|| String::new());
     let auth_status = use_state(|| String::new());
     let nextcloud_url = use_state(|| String::new()); // State to hold the Nextcloud server URL
-    let _error_message = audio_state.error_message.clone();
-    let _info_message = audio_state.info_message.clone();
 
     // Handler for server URL input change
     let on_server_url_change = {
@@ -119,9 +119,7 @@ pub fn nextcloud_options() -> Html {
         let api_key = api_key.clone();
         let user_id = user_id.clone();
         let auth_status = auth_status.clone();
-        let audio_dispatch_call = audio_dispatch.clone();
         Callback::from(move |_| {
-            let audio_dispatch = audio_dispatch_call.clone();
             let auth_status = auth_status.clone();
             let server = (*server_url_initiate).clone().trim().to_string();
             let server_name = server_name.clone();
@@ -173,7 +171,7 @@ pub fn nextcloud_options() -> Html {
                                             Ok(response) => {
                                                 if response.data {
                                                     log::info!("gPodder settings have been set up");
-                                                    audio_dispatch.reduce_mut(|audio_state| audio_state.info_message = Option::from("Nextcloud server has been authenticated successfully".to_string()));
+                                                    notify_info(I18n::current().t("nextcloud.authenticated"));
 
                                                     // Set `is_refreshing` to true and start the WebSocket refresh
                                                     let server_name_call = server_name.clone();
@@ -229,12 +227,7 @@ pub fn nextcloud_options() -> Html {
                                 }
                                 Err(e) => {
                                     log::error!("Error calling add_nextcloud_server: {:?}", e);
-                                    audio_dispatch.reduce_mut(|audio_state| {
-                                        audio_state.error_message = Option::from(
-                                            format!("Error calling add_nextcloud_server: {}", e)
-                                                .to_string(),
-                                        )
-                                    });
+                                    notify_error(I18n::current().tf("nextcloud.add_error", &[("error", &e.to_string())]));
                                 }
                             }
                         }
@@ -243,20 +236,16 @@ pub fn nextcloud_options() -> Html {
                                 "Failed to initiate Nextcloud login: {:?}",
                                 e
                             )));
-                            audio_dispatch.reduce_mut(|audio_state| audio_state.error_message = Option::from("Failed to initiate Nextcloud login. Please check the server URL.".to_string()));
-                            auth_status.set(
-                                "Failed to initiate Nextcloud login. Please check the server URL."
-                                    .to_string(),
-                            );
+                            let message = I18n::current().t("nextcloud.login_failed");
+                            notify_error(message.clone());
+                            auth_status.set(message);
                         }
                     }
                 });
             } else {
-                auth_status.set("Please enter a Nextcloud server URL.".to_string());
-                audio_dispatch.reduce_mut(|audio_state| {
-                    audio_state.error_message =
-                        Option::from("Please enter a Nextcloud Server URL".to_string())
-                });
+                let message = I18n::current().t("nextcloud.url_required");
+                auth_status.set(message.clone());
+                notify_error(message);
             }
         })
     };
@@ -273,7 +262,6 @@ pub fn nextcloud_options() -> Html {
         let user_id = user_id.clone();
         let auth_status = auth_status.clone();
         Callback::from(move |_| {
-            let audio_dispatch = audio_dispatch.clone();
             let auth_status = auth_status.clone();
             let server = (*server_url_initiate).clone().trim().to_string();
             let server_user = server_user.clone();
@@ -317,12 +305,7 @@ pub fn nextcloud_options() -> Html {
                                         log::info!(
                                             "Gpodder server now added and podcasts syncing!"
                                         );
-                                        audio_dispatch.reduce_mut(|audio_state| {
-                                            audio_state.info_message = Option::from(
-                                                "Gpodder server now added and podcasts syncing!"
-                                                    .to_string(),
-                                            )
-                                        });
+                                        notify_info(I18n::current().t("nextcloud.gpodder_added"));
                                         // Set `is_refreshing` to true and start the WebSocket refresh
                                         let server_name_call = server_name.clone();
                                         let user_id_call = user_id.clone();
@@ -367,27 +350,20 @@ pub fn nextcloud_options() -> Html {
                                             "Failed to add Gpodder server: {:?}",
                                             e
                                         )));
-                                        audio_dispatch.reduce_mut(|audio_state| audio_state.error_message = Option::from("Failed to add Gpodder server. Please check the server URL.".to_string()));
-                                        auth_status.set(
-                                            format!("Failed to add Gpodder server. Please check the server URL and credentials. {:?}", e)
-                                                .to_string(),
-                                        );
+                                        notify_error(I18n::current().t("nextcloud.gpodder_add_failed"));
+                                        auth_status.set(I18n::current().tf(
+                                            "nextcloud.gpodder_add_error",
+                                            &[("error", &format!("{:?}", e))],
+                                        ));
                                     }
                                 }
                             } else {
                                 web_sys::console::log_1(&JsValue::from_str(
                                     "Authentication failed.",
                                 ));
-                                audio_dispatch.reduce_mut(|audio_state| {
-                                    audio_state.error_message = Option::from(
-                                        "Authentication failed. Please check your credentials."
-                                            .to_string(),
-                                    )
-                                });
-                                auth_status.set(
-                                    "Authentication failed. Please check your credentials."
-                                        .to_string(),
-                                );
+                                let message = I18n::current().t("nextcloud.gpodder_auth_failed");
+                                notify_error(message.clone());
+                                auth_status.set(message);
                             }
                         }
                         Err(e) => {
@@ -395,50 +371,41 @@ pub fn nextcloud_options() -> Html {
                                 "Failed to verify Gpodder auth: {:?}",
                                 e
                             )));
-                            audio_dispatch.reduce_mut(|audio_state| {
-                                audio_state.error_message = Option::from(
-                                    "Failed to verify Gpodder auth. Please check the server URL."
-                                        .to_string(),
-                                )
-                            });
-                            auth_status.set(
-                                "Failed to verify Gpodder auth. Please check the server URL."
-                                    .to_string(),
-                            );
+                            let message = I18n::current().t("nextcloud.gpodder_verify_failed");
+                            notify_error(message.clone());
+                            auth_status.set(message);
                         }
                     }
                 });
             } else {
-                auth_status.set("Please enter a Gpodder server URL.".to_string());
-                audio_dispatch.reduce_mut(|audio_state| {
-                    audio_state.error_message =
-                        Option::from("Please enter a Gpodder Server URL".to_string())
-                });
+                let message = I18n::current().t("nextcloud.gpodder_url_required");
+                auth_status.set(message.clone());
+                notify_error(message);
             }
         })
     };
 
     html! {
         <div class="p-4"> // You can adjust the padding as needed
-            <p class="item_container-text text-lg font-bold mb-4">{"Nextcloud Podcast Sync:"}</p> // Styled paragraph
-            <p class="item_container-text text-md mb-4">{"With this option you can authenticate with a Nextcloud or Gpodder server to use as a podcast sync client. This option works great with AntennaPod on Android so you can have the same exact feed there while on mobile. In addition, if you're already using AntennaPod with Nextcloud Podcast sync you can connect your existing sync feed to quickly import everything right into Pinepods! You'll only enter information for one of the below options. Nextcloud requires that you have the gpodder sync add-on in nextcloud and the gpodder option requires you to have an external gpodder podcast sync server that authenticates via user and pass. Such as this: https://github.com/kd2org/opodsync."}</p> // Styled paragraph
-            <p class="item_container-text text-md mb-4">{"Current Podcast Sync Server: "}<span class="item_container-text font-bold">{(*nextcloud_url).clone()}</span></p> // Styled paragraph
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("nextcloud.title") }</p> // Styled paragraph
+            <p class="item_container-text text-md mb-4">{ i18n.t("nextcloud.description") }</p> // Styled paragraph
+            <p class="item_container-text text-md mb-4">{ i18n.t("nextcloud.current_server") }{" "}<span class="item_container-text font-bold">{(*nextcloud_url).clone()}</span></p> // Styled paragraph
             <br/>
-            <label for="server_url" class="item_container-text block mb-2 text-sm font-medium">{ "New Nextcloud Server" }</label>
+            <label for="server_url" class="item_container-text block mb-2 text-sm font-medium">{ i18n.t("nextcloud.new_server") }</label>
             <div class="flex items-center">
                 <input type="text" id="first_name" oninput={on_server_url_change.clone()} class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500" placeholder="https://nextcloud.com" />
                 <button onclick={on_authenticate_click} class="mt-2 settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                {"Authenticate"}
+                { i18n.t("nextcloud.authenticate") }
                 </button>
             </div>
 
-            <label for="server_url" class="item_container-text block mb-2 text-sm font-medium">{ "GPodder-compatible Server" }</label>
+            <label for="server_url" class="item_container-text block mb-2 text-sm font-medium">{ i18n.t("nextcloud.gpodder_server") }</label>
             <div class="flex items-center">
                 <input type="text" id="url" oninput={on_server_url_change} class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500" placeholder="https://mypodcastsync.mydomain.com" />
-                <input type="text" id="username" oninput={on_username_change} class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500" placeholder="myusername" />
-                <input type="password" id="password" oninput={on_password_change} class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500" placeholder="mypassword" />
+                <input type="text" id="username" oninput={on_username_change} class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500" placeholder={i18n.t("common.username")} />
+                <input type="password" id="password" oninput={on_password_change} class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500" placeholder={i18n.t("common.password")} />
                 <button onclick={on_authenticate_server_click} class="mt-2 settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                {"Authenticate"}
+                { i18n.t("nextcloud.authenticate") }
                 </button>
             </div>
             // <input type="text" class="input" placeholder="Enter Nextcloud server URL" value={(*server_url).clone()} oninput={on_server_url_change} />
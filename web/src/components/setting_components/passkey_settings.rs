@@ -0,0 +1,193 @@
+use crate::components::context::AppState;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, notify_info};
+use crate::components::passkeys::{create_passkey, passkeys_supported};
+use crate::requests::setting_reqs::{
+    call_delete_passkey, call_get_passkeys, call_webauthn_register, call_webauthn_register_options,
+    Passkey,
+};
+use anyhow::Error;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+use yewdux::prelude::*;
+
+fn date_only(timestamp: &Option<String>) -> String {
+    timestamp
+        .as_deref()
+        .and_then(|t| t.split('T').next())
+        .map(str::to_string)
+        .unwrap_or_else(|| I18n::current().t("common.never"))
+}
+
+async fn register_passkey(
+    server_name: String,
+    api_key: String,
+    user_id: i32,
+    name: String,
+) -> Result<(), Error> {
+    let challenge = call_webauthn_register_options(&server_name, &api_key, user_id).await?;
+    let credential = create_passkey(&challenge.options).await?;
+    call_webauthn_register(
+        &server_name,
+        &api_key,
+        user_id,
+        challenge.challenge_id,
+        name,
+        credential,
+    )
+    .await
+}
+
+/// Passkeys registered to the signed-in account. Each one can stand in for the MFA code, or
+/// for the whole login.
+#[function_component(PasskeyOptions)]
+pub fn passkey_options() -> Html {
+    let i18n = use_i18n();
+    let (state, _dispatch) = use_store::<AppState>();
+    let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
+    let api_key = state
+        .auth_details
+        .as_ref()
+        .and_then(|ud| ud.api_key.clone());
+    let user_id = state.user_details.as_ref().map(|ud| ud.UserID);
+    let passkeys = use_state(Vec::<Passkey>::new);
+    let new_name = use_state(String::new);
+    let registering = use_state(|| false);
+    // Bumped whenever the list on the server changes
+    let refresh = use_state(|| 0u32);
+
+    {
+        let passkeys = passkeys.clone();
+        let deps = (server_name.clone(), api_key.clone(), user_id, *refresh);
+        use_effect_with(deps, move |(server_name, api_key, user_id, _)| {
+            if let (Some(server_name), Some(api_key), Some(user_id)) =
+                (server_name.clone(), api_key.clone(), *user_id)
+            {
+                spawn_local(async move {
+                    match call_get_passkeys(&server_name, &api_key, user_id).await {
+                        Ok(fetched) => passkeys.set(fetched),
+                        Err(e) => notify_error(
+                            I18n::current().tf("passkeys.load_error", &[("error", &e.to_string())]),
+                        ),
+                    }
+                });
+            }
+            || ()
+        });
+    }
+
+    let on_name_change = {
+        let new_name = new_name.clone();
+        Callback::from(move |e: InputEvent| {
+            new_name.set(
+                e.target_unchecked_into::<web_sys::HtmlInputElement>()
+                    .value(),
+            );
+        })
+    };
+
+    let on_add = {
+        let server_name = server_name.clone();
+        let api_key = api_key.clone();
+        let new_name = new_name.clone();
+        let registering = registering.clone();
+        let refresh = refresh.clone();
+        Callback::from(move |_: MouseEvent| {
+            let (Some(server_name), Some(api_key), Some(user_id)) =
+                (server_name.clone(), api_key.clone(), user_id)
+            else {
+                return;
+            };
+            let name = new_name.trim().to_string();
+            let new_name = new_name.clone();
+            let registering = registering.clone();
+            let refresh = refresh.clone();
+            registering.set(true);
+            spawn_local(async move {
+                match register_passkey(server_name, api_key, user_id, name).await {
+                    Ok(()) => {
+                        new_name.set(String::new());
+                        refresh.set(*refresh + 1);
+                        notify_info(I18n::current().t("passkeys.added"));
+                    }
+                    Err(e) => notify_error(
+                        I18n::current().tf("passkeys.add_error", &[("error", &e.to_string())]),
+                    ),
+                }
+                registering.set(false);
+            });
+        })
+    };
+
+    let on_remove = {
+        let server_name = server_name.clone();
+        let api_key = api_key.clone();
+        let refresh = refresh.clone();
+        Callback::from(move |credential_id: String| {
+            let (Some(server_name), Some(api_key), Some(user_id)) =
+                (server_name.clone(), api_key.clone(), user_id)
+            else {
+                return;
+            };
+            let refresh = refresh.clone();
+            spawn_local(async move {
+                match call_delete_passkey(&server_name, &api_key, user_id, credential_id).await {
+                    Ok(()) => refresh.set(*refresh + 1),
+                    Err(e) => notify_error(
+                        I18n::current().tf("passkeys.remove_error", &[("error", &e.to_string())]),
+                    ),
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class="p-4">
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("passkeys.title") }</p>
+            <p class="item_container-text text-md mb-4">{ i18n.t("passkeys.description") }</p>
+            if passkeys_supported() {
+                <div class="flex items-center space-x-4 mb-4">
+                    <input oninput={on_name_change} value={(*new_name).clone()} type="text" placeholder={i18n.t("passkeys.name_placeholder")} class="search-bar-input border text-sm rounded-lg block p-2.5" />
+                    <button onclick={on_add} disabled={*registering} class="settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
+                        { i18n.t(if *registering { "passkeys.waiting" } else { "passkeys.add" }) }
+                    </button>
+                </div>
+            } else {
+                <p class="item_container-text text-sm mb-4">{ i18n.t("passkeys.unsupported") }</p>
+            }
+            if !passkeys.is_empty() {
+                <div class="relative overflow-x-auto">
+                    <table class="w-full text-sm text-left rtl:text-right">
+                        <thead class="text-xs uppercase table-header">
+                            <tr>
+                                <th scope="col" class="px-6 py-3">{ i18n.t("api_keys.name") }</th>
+                                <th scope="col" class="px-6 py-3">{ i18n.t("passkeys.added_column") }</th>
+                                <th scope="col" class="px-6 py-3">{ i18n.t("api_keys.last_used") }</th>
+                                <th scope="col" class="px-6 py-3"></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                        { for passkeys.iter().map(|passkey| {
+                            let onclick = {
+                                let on_remove = on_remove.clone();
+                                let credential_id = passkey.credential_id.clone();
+                                Callback::from(move |_: MouseEvent| on_remove.emit(credential_id.clone()))
+                            };
+                            html! {
+                                <tr class="table-row border-b">
+                                    <td class="px-6 py-4">{ &passkey.name }</td>
+                                    <td class="px-6 py-4">{ date_only(&passkey.created) }</td>
+                                    <td class="px-6 py-4">{ date_only(&passkey.last_used) }</td>
+                                    <td class="px-6 py-4">
+                                        <button {onclick} class="settings-button font-bold py-1 px-3 rounded">{ i18n.t("passkeys.remove") }</button>
+                                    </td>
+                                </tr>
+                            }
+                        })}
+                        </tbody>
+                    </table>
+                </div>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,100 @@
+use crate::components::context::AppState;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, notify_success};
+use crate::components::password_strength::use_password_policy;
+use crate::requests::login_requests::PasswordPolicy;
+use crate::requests::setting_reqs::call_set_password_policy;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+use yewdux::prelude::*;
+
+/// What new passwords on this server have to look like. It applies to accounts added by an
+/// admin, self service signups, password resets and password changes.
+#[function_component(PasswordPolicySettings)]
+pub fn password_policy_settings() -> Html {
+    let (state, _dispatch) = use_store::<AppState>();
+    let i18n = use_i18n();
+    let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
+    let api_key = state
+        .auth_details
+        .as_ref()
+        .and_then(|ud| ud.api_key.clone());
+    let policy = use_password_policy(server_name.clone());
+    let saving = use_state(|| false);
+
+    let update = {
+        let policy = policy.clone();
+        move |change: fn(&mut PasswordPolicy, &web_sys::HtmlInputElement)| {
+            let policy = policy.clone();
+            Callback::from(move |e: Event| {
+                let input = e.target_unchecked_into::<web_sys::HtmlInputElement>();
+                let mut updated = (*policy).clone();
+                change(&mut updated, &input);
+                policy.set(updated);
+            })
+        }
+    };
+    let on_min_length = update(|policy, input| {
+        if let Ok(length) = input.value().parse::<usize>() {
+            policy.min_length = length.clamp(1, 128);
+        }
+    });
+    let on_upper = update(|policy, input| policy.require_upper = input.checked());
+    let on_lower = update(|policy, input| policy.require_lower = input.checked());
+    let on_digit = update(|policy, input| policy.require_digit = input.checked());
+    let on_symbol = update(|policy, input| policy.require_symbol = input.checked());
+    let on_breached = update(|policy, input| policy.check_breached = input.checked());
+
+    let on_save = {
+        let policy = policy.clone();
+        let saving = saving.clone();
+        Callback::from(move |_: MouseEvent| {
+            let (Some(server_name), Some(api_key)) = (server_name.clone(), api_key.clone()) else {
+                return;
+            };
+            let policy = (*policy).clone();
+            let saving = saving.clone();
+            saving.set(true);
+            spawn_local(async move {
+                match call_set_password_policy(server_name, api_key, &policy).await {
+                    Ok(_) => notify_success(I18n::current().t("password_policy.saved")),
+                    Err(e) => notify_error(
+                        I18n::current().tf("password_policy.save_error", &[("error", &e.to_string())]),
+                    ),
+                }
+                saving.set(false);
+            });
+        })
+    };
+
+    let checkbox = |label: String, checked: bool, onchange: Callback<Event>| {
+        html! {
+            <label class="flex items-center space-x-2 mb-2">
+                <input type="checkbox" {checked} {onchange} class="rounded" />
+                <span class="item_container-text text-sm">{ label }</span>
+            </label>
+        }
+    };
+
+    html! {
+        <div class="p-4">
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("password_policy.title") }</p>
+            <p class="item_container-text text-md mb-4">{ i18n.t("password_policy.description") }</p>
+            <div class="flex items-center space-x-4 mb-4">
+                <label for="password-min-length" class="item_container-text text-sm">{ i18n.t("password_policy.min_length") }</label>
+                <input id="password-min-length" type="number" min="1" max="128" value={policy.min_length.to_string()} onchange={on_min_length} class="search-bar-input border text-sm rounded-lg block w-24 p-2.5" />
+            </div>
+            { checkbox(i18n.t("password_policy.require_upper"), policy.require_upper, on_upper) }
+            { checkbox(i18n.t("password_policy.require_lower"), policy.require_lower, on_lower) }
+            { checkbox(i18n.t("password_policy.require_digit"), policy.require_digit, on_digit) }
+            { checkbox(i18n.t("password_policy.require_symbol"), policy.require_symbol, on_symbol) }
+            { checkbox(i18n.t("password_policy.check_breached"), policy.check_breached, on_breached) }
+            if policy.check_breached && !policy.breached_list_loaded {
+                <p class="text-red-500 text-xs italic mb-2">{ i18n.t("password_policy.no_breached_list") }</p>
+            }
+            <button onclick={on_save} disabled={*saving} class="settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline mt-2">
+                { if *saving { i18n.t("common.saving") } else { i18n.t("password_policy.save") } }
+            </button>
+        </div>
+    }
+}
@@ -3,6 +3,8 @@ use yew::prelude::*;
 use yewdux::prelude::*;
 use yew_router::history::{BrowserHistory, History};
 use crate::components::context::AppState;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, Toast};
 use crate::requests::setting_reqs::call_restore_server;
 use web_sys::{HtmlInputElement, Event};
 use web_sys::{Blob, FileReader};
@@ -12,11 +14,10 @@ use wasm_bindgen::closure::Closure;
 pub fn restore_server() -> Html {
     let database_password = use_state(|| "".to_string());
     let file_content = use_state(|| "".to_string());
-    let error_message = use_state(|| None::<String>);
-    let info_message = use_state(|| None::<String>);
 
     // API key, server name, and other data can be fetched from AppState if required
     let (state, _) = use_store::<AppState>();
+    let i18n = use_i18n();
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
 
@@ -32,10 +33,8 @@ pub fn restore_server() -> Html {
     // Correct setup for `on_file_change`
     let on_file_change = {
         let file_content = file_content.clone();
-        let error_message = error_message.clone();
         Callback::from(move |e: Event| {
             let file_content = file_content.clone();
-            let error_message = error_message.clone();
             let input: HtmlInputElement = e.target_unchecked_into();
             if let Some(files) = input.files() {
                 if let Some(file) = files.get(0) { // Directly get the File, no conversion needed
@@ -47,7 +46,7 @@ pub fn restore_server() -> Html {
                             let text = result.as_string().unwrap_or_default();
                             file_content.set(text);
                         } else {
-                            error_message.set(Some("Failed to read file".into()));
+                            notify_error(I18n::current().t("restore.read_failed"));
                         }
                     }) as Box<dyn FnMut(_)>);
     
@@ -69,25 +68,22 @@ pub fn restore_server() -> Html {
         let server_name = server_name.unwrap_or_default();
         let database_password = (*database_password).clone();
         let file_content = (*file_content).clone();
-        let error_message = error_message.clone();
-        let info_message = info_message.clone();
         Callback::from(move |_| {
             let server_name = server_name.clone();
             let api_key = api_key.clone();
             let database_password = database_password.clone();
             let file_content = file_content.clone();
-            let error_message = error_message.clone();
-            let info_message = info_message.clone();
             let history = history.clone();  // Clone history for use in the async block
             wasm_bindgen_futures::spawn_local(async move {
                 match call_restore_server(&server_name, &database_password, &file_content, &api_key.unwrap()).await {
                     Ok(message) => {
-                        info_message.set(Some(message));
+                        // Kept up through the sign out that follows
+                        Toast::info(message).persistent().show();
                         // Navigate to the logout route after initiating the restore process
                         history.push("/sign_out");
                     },
                     Err(e) => {
-                        error_message.set(Some(e.to_string()));
+                        notify_error(e.to_string());
                     }
                 }
             });
@@ -96,24 +92,17 @@ pub fn restore_server() -> Html {
 
     html! {
         <div class="p-4">
-            <p class="item_container-text text-lg font-bold mb-4">{"Restore Server:"}</p>
-            <p class="item_container-text text-md mb-4">{"With this option you can restore your entire server with all its previous settings, users, and data from a backup. Take a backup above to restore here. WARNING: This will delete everything on your server now and restore to the point that the backup contains."}</p>
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("restore.title") }</p>
+            <p class="item_container-text text-md mb-4">{ i18n.t("restore.description") }</p>
             
             <br/>
             <input onchange={on_file_change} type="file" accept=".sql"/>
             <div class="flex items-center">
                 <input type="password" id="db_pw" oninput={on_password_change.clone()} class="search-bar-input border text-sm rounded-lg block w-full p-2.5" placeholder="mYDBp@ss!" />
                 <button onclick={onclick_restore} class="mt-2 settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                {"Restore Server"}
+                { i18n.t("restore.restore") }
                 </button>
             </div>
-            // Conditional rendering for the error banner
-            // if let Some(error) = error_message {
-            //     <div class="error-snackbar">{ error }</div>
-            // }
-            // if let Some(info) = info_message {
-            //     <div class="info-snackbar">{ info }</div>
-            // }
         </div>
     }
 }
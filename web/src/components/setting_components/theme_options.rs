@@ -1,7 +1,9 @@
 use web_sys::{Element, HtmlSelectElement};
 use yew::prelude::*;
 use yewdux::prelude::*;
-use crate::components::{context::{AppState, UIState}};
+use crate::components::notifications::{notify_error, notify_info};
+use crate::components::context::AppState;
+use crate::components::i18n::{use_i18n, I18n};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 use web_sys::window;
@@ -11,7 +13,7 @@ use web_sys::console;
 #[function_component(ThemeOptions)]
 pub fn theme() -> Html {
     let (state, _dispatch) = use_store::<AppState>();
-    let (_audio_state, audio_dispatch) = use_store::<UIState>();
+    let i18n = use_i18n();
     // Use state to manage the selected theme
     let selected_theme = use_state(|| "Light".to_string());
     // let selected_theme = state.selected_theme.as_ref();
@@ -30,7 +32,6 @@ pub fn theme() -> Html {
         let selected_theme = selected_theme.clone();
         let state = state.clone();
         Callback::from(move |_| {
-            let audio_dispatch = audio_dispatch.clone();
             let theme = (*selected_theme).to_string();
             changeTheme(&theme);
             if let Some(window) = web_sys::window() {
@@ -61,10 +62,10 @@ pub fn theme() -> Html {
 
             spawn_local(async move {
                 if let Ok(_) = call_set_theme(&server_name, &Some(api_key), &request).await {
-                    audio_dispatch.reduce_mut(|audio_state| audio_state.info_message = Option::from("Theme Settings Updated!".to_string()));
+                    notify_info(I18n::current().t("theme.updated"));
 
                 } else {
-                    audio_dispatch.reduce_mut(|audio_state| audio_state.error_message = Option::from("Error Updating Theme".to_string()));
+                    notify_error(I18n::current().t("theme.update_failed"));
 
                 }
             });
@@ -73,13 +74,13 @@ pub fn theme() -> Html {
 
     html! {
         <div class="p-4"> // You can adjust the padding as needed
-            <p class="item_container-text text-lg font-bold mb-4">{"Theme Select:"}</p> // Styled paragraph
-            <p class="item_container-text text-md mb-4">{"You can select your application theme here. Choosing a theme will follow you to any official Pinepods application as your theme preference gets saved to your user settings."}</p> // Styled paragraph
+            <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("theme.title") }</p> // Styled paragraph
+            <p class="item_container-text text-md mb-4">{ i18n.t("theme.description") }</p> // Styled paragraph
 
             <div class="theme-select-dropdown relative inline-block">
                 <select onchange={on_change} class="theme-select-dropdown appearance-none w-full border px-4 py-2 pr-8 rounded shadow leading-tight focus:outline-none focus:shadow-outline">
-                    <option value="Light" selected={(*selected_theme) == "Light"}>{"Light"}</option>
-                    <option value="Dark" selected={(*selected_theme) == "Dark"}>{"Dark"}</option>
+                    <option value="Light" selected={(*selected_theme) == "Light"}>{ i18n.t("theme.light") }</option>
+                    <option value="Dark" selected={(*selected_theme) == "Dark"}>{ i18n.t("theme.dark") }</option>
                     <option value="Github Light" selected={(*selected_theme) == "Github Light"}>{"Github Light"}</option>   
                     <option value="Nordic Light" selected={(*selected_theme) == "Nordic Light"}>{"Nordic Light"}</option>                                     
                     <option value="Nordic" selected={(*selected_theme) == "Nordic"}>{"Nordic"}</option>
@@ -99,7 +100,7 @@ pub fn theme() -> Html {
             </div>
 
             <button onclick={on_submit} class="theme-submit-button mt-4 font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline" type="button">
-                {"Submit"}
+                { i18n.t("common.submit") }
             </button>
         </div>
     }
@@ -1,23 +1,24 @@
 use yew::prelude::*;
 use yewdux::prelude::*;
-use crate::components::context::{UIState, AppState};
+use crate::components::context::AppState;
 use yew::platform::spawn_local;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::{notify_error, notify_info, notify_warning};
 use crate::requests::setting_reqs::call_get_user_info;
 use web_sys::console;
 use std::borrow::Borrow;
 use crate::requests::setting_reqs::{SettingsUser, call_add_user, call_delete_user, AddSettingsUserRequest, call_set_password, call_set_email, call_set_fullname, call_set_username, call_check_admin, call_set_isadmin};
-use crate::components::gen_funcs::{ValidationError, encode_password, validate_email, validate_username};
+use crate::components::gen_funcs::{ValidationError, validate_email, validate_password, validate_username};
 use crate::components::gen_funcs::validate_user_input;
+use crate::components::password_strength::{use_password_policy, PasswordStrengthMeter};
 use wasm_bindgen::JsCast;
 // use crate::gen_components::_ErrorMessageProps::error_message;
 
 
 #[function_component(UserSettings)]
 pub fn user_settings() -> Html {
+    let i18n = use_i18n();
     let (state, _dispatch) = use_store::<AppState>();
-    let (audio_state, audio_dispatch) = use_store::<UIState>();
-    let ui_user = audio_dispatch.clone();
-    let ui_wasm = audio_dispatch.clone();
     let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
     let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
     let new_username = use_state(|| "".to_string());
@@ -26,11 +27,10 @@ pub fn user_settings() -> Html {
     let fullname = use_state(|| "".to_string());
     let admin_status = use_state(|| false);
     let selected_user_id = use_state(|| None);
-    let _error_message = audio_state.error_message.clone();
-    let _info_message = audio_state.info_message.clone();
     let error_message_container = use_state(|| "".to_string());
     let admin_edit_status = use_state(|| 0);
     let update_trigger = use_state(|| false);
+    let password_policy = use_password_policy(server_name.clone());
 
     // Define the type of user in the Vec
     let users: UseStateHandle<Vec<SettingsUser>> = use_state(|| Vec::new());
@@ -178,6 +178,7 @@ pub fn user_settings() -> Html {
         let password_error = password_error.clone();
         let email_error = email_error.clone();
         let on_update_trigger = update_trigger.clone();
+        let password_policy = password_policy.clone();
         Callback::from(move |e: MouseEvent| {
             let error_container = error_container_create.clone();
             let error_message_container = error_message_container_create.clone();
@@ -189,8 +190,7 @@ pub fn user_settings() -> Html {
             let fullname = fullname.clone();
             let email = email.clone();
             e.prevent_default();
-            // Hash the password and generate a salt
-            let errors = validate_user_input(&new_username, &new_password, &email);
+            let errors = validate_user_input(&new_username, &new_password, &email, &password_policy);
 
             if errors.contains(&ValidationError::UsernameTooShort) {
                 username_error.set(username_error_notice::Shown);
@@ -198,7 +198,7 @@ pub fn user_settings() -> Html {
                 username_error.set(username_error_notice::Hidden);
             }
             
-            if errors.contains(&ValidationError::PasswordTooShort) {
+            if errors.contains(&ValidationError::WeakPassword) {
                 password_error.set(password_error_notice::Shown);
             } else {
                 password_error.set(password_error_notice::Hidden);
@@ -211,42 +211,33 @@ pub fn user_settings() -> Html {
             }
 
             if errors.is_empty() {
-                match encode_password(&new_password) {
-                    Ok(hash_pw) => {
-                        let user_settings = AddSettingsUserRequest {
-                            fullname: fullname.clone(),
-                            username: new_username.clone(),
-                            email: email.clone(),
-                            hash_pw: hash_pw.clone(),
-                        };
-                        let add_user_request = Some(user_settings);
-                        page_state.set(PageState::Hidden);
-                        wasm_bindgen_futures::spawn_local(async move {
-                            let on_update_trigger = update_trigger.clone();
-                            if let Some(add_user_request_value) = add_user_request {
-                                match call_add_user(call_server.unwrap(), call_api.unwrap().unwrap(), &add_user_request_value).await {
-                                    Ok(_success) => {
-                                        on_update_trigger.set(!*update_trigger);
-                                    },
-                                    Err(e) => {
-                                        console::log_1(&format!("Error adding user: {}", e).into());
-                                        error_container.set(error_container_state::Shown);
-                                        error_message_container.set("Error adding user".to_string());
-                                    },
-                                }
-                            } else {
-                                console::log_1(&format!("Error adding user").into());
+                let user_settings = AddSettingsUserRequest {
+                    fullname: fullname.clone(),
+                    username: new_username.clone(),
+                    email: email.clone(),
+                    password: (*new_password).clone(),
+                };
+                let add_user_request = Some(user_settings);
+                page_state.set(PageState::Hidden);
+                wasm_bindgen_futures::spawn_local(async move {
+                    let on_update_trigger = update_trigger.clone();
+                    if let Some(add_user_request_value) = add_user_request {
+                        match call_add_user(call_server.unwrap(), call_api.unwrap().unwrap(), &add_user_request_value).await {
+                            Ok(_success) => {
+                                on_update_trigger.set(!*update_trigger);
+                            },
+                            Err(e) => {
+                                console::log_1(&format!("Error adding user: {}", e).into());
                                 error_container.set(error_container_state::Shown);
-                                error_message_container.set("Error adding user".to_string());
-                            }
-                        });
-                    },
-                    Err(e) => {
-                        console::log_1(&format!("Error adding user: {}", e).into());
+                                error_message_container.set(I18n::current().tf("login.add_user_error", &[("error", &e.to_string())]));
+                            },
+                        }
+                    } else {
+                        console::log_1(&format!("Error adding user").into());
                         error_container.set(error_container_state::Shown);
-                        error_message_container.set("Error adding user".to_string());
+                        error_message_container.set(I18n::current().t("login.add_user_failed"));
                     }
-                }
+                });
             }
         })
     };
@@ -258,52 +249,53 @@ pub fn user_settings() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Create New User"}
+                            { i18n.t("login.create_user") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <div>
-                                <label for="username" class="block mb-2 text-sm font-medium">{"Username"}</label>
+                                <label for="username" class="block mb-2 text-sm font-medium">{ i18n.t("common.username") }</label>
                                 <input oninput={on_username_change.clone()} placeholder="pinepods_user1" type="text" id="username" name="username" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
                                 {
                                     match *username_error {
                                         username_error_notice::Hidden => html! {},
-                                        username_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{"Username must be at least 4 characters long"}</p>},
+                                        username_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{ i18n.t("login.username_too_short") }</p>},
                                     }
                                 }
                             </div>
                             <div>
-                                <label for="fullname" class="block mb-2 text-sm font-medium">{"Full Name"}</label>
-                                <input oninput={on_fullname_change.clone()} placeholder="Pinepods User" type="text" id="fullname" name="fullname" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
+                                <label for="fullname" class="block mb-2 text-sm font-medium">{ i18n.t("login.full_name") }</label>
+                                <input oninput={on_fullname_change.clone()} placeholder={i18n.t("user_settings.full_name_placeholder")} type="text" id="fullname" name="fullname" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
                             </div>
                             <div>
-                                <label for="email" class="block mb-2 text-sm font-medium">{"Email"}</label>
+                                <label for="email" class="block mb-2 text-sm font-medium">{ i18n.t("common.email") }</label>
                                 <input oninput={on_email_change.clone()} placeholder="user@pinepods.online" type="email" id="email" name="email" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
                                 {
                                     match *email_error {
                                         email_error_notice::Hidden => html! {},
-                                        email_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{"Invalid email address"}</p>},
+                                        email_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{ i18n.t("login.invalid_email") }</p>},
                                     }
                                 }
                             </div>
                             <div>
-                                <label for="password" class="block mb-2 text-sm font-medium">{"Password"}</label>
+                                <label for="password" class="block mb-2 text-sm font-medium">{ i18n.t("common.password") }</label>
                                 <input oninput={on_password_change.clone()} placeholder="my_S3creT_P@$$" type="password" id="password" name="password" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
+                                <PasswordStrengthMeter password={(*new_password).clone()} policy={(*password_policy).clone()} />
                                 {
                                     match *password_error {
                                         password_error_notice::Hidden => html! {},
-                                        password_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{"Password must be at least 6 characters long"}</p>},
+                                        password_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{ i18n.t("login.password_requirements") }</p>},
                                     }
                                 }
                             </div>
-                            <button type="submit" onclick={on_create_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{"Submit"}</button>
+                            <button type="submit" onclick={on_create_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{ i18n.t("common.submit") }</button>
                         </form>
                     </div>
                 </div>
@@ -311,7 +303,6 @@ pub fn user_settings() -> Html {
         </div>
     };
 
-    let user_dispatch = ui_user.clone();
     let edit_admin_call = admin_edit_status.clone();
     let on_user_row_click = {
         let selected_user_id = selected_user_id.clone();
@@ -320,7 +311,7 @@ pub fn user_settings() -> Html {
             // admin_edit_status.set(is_admin);
             Callback::from(move |_| {
                 if select_user_id == 1 {
-                    user_dispatch.reduce_mut(|state| state.error_message = Option::from("You cannot edit the guest user.".to_string()));
+                    notify_warning(I18n::current().t("user_settings.guest_not_editable"));
                     return;
                 }
                 edit_admin_call.set(is_admin);
@@ -333,35 +324,32 @@ pub fn user_settings() -> Html {
     let error_message_container_edit = error_message_container.clone();
     let error_container_edit = error_container.clone();
     
-    let delete_dispatch = ui_user.clone();
     let on_delete_click = {
         let server_name = server_name.clone();
         let api_key = api_key.clone();
         let selected_user_id = selected_user_id.clone();
         let page_state = page_state.clone();
-        let user_dispatch = delete_dispatch.clone();
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
             if let Some(Some(user_id)) = *selected_user_id {
                 let server_name = server_name.clone();
                 let api_key = api_key.clone();
                 let page_state = page_state.clone();
-                let user_dispatch = user_dispatch.clone();
                 wasm_bindgen_futures::spawn_local(async move {
                     match call_delete_user(server_name.unwrap(), api_key.unwrap().unwrap(), user_id).await {
                         Ok(response) => {
                             web_sys::console::log_1(&format!("User deleted: {}", response.status).into());
-                            user_dispatch.reduce_mut(|state| state.info_message = Some("User deleted successfully".to_string()));
+                            notify_info(I18n::current().t("user_settings.user_deleted"));
                             page_state.set(PageState::Hidden); // Navigate back to the list page state
                         }
                         Err(e) => {
                             web_sys::console::log_1(&format!("Failed to delete user: {}", e).into());
-                            user_dispatch.reduce_mut(|state| state.error_message = Some("Failed to delete user".to_string()));
+                            notify_error(I18n::current().t("user_settings.delete_failed"));
                         }
                     }
                 });
             } else {
-                user_dispatch.reduce_mut(|state| state.error_message = Some("No user selected".to_string()));
+                notify_error(I18n::current().t("user_settings.no_user_selected"));
             }
         })
     };
@@ -375,7 +363,7 @@ pub fn user_settings() -> Html {
         let api_key = api_key.clone();
         let email = email.clone().to_string();
         let new_password = new_password.clone();
-        let dispatch_wasm = ui_wasm.clone();
+        let password_policy = password_policy.clone();
         let edit_selected_user_id = selected_user_id.clone();
         let username_error_edit = username_error.clone();
         let email_error_edit = email_error.clone();
@@ -389,7 +377,6 @@ pub fn user_settings() -> Html {
             let email_error = email_error_edit.clone();
             let password_error = password_error_edit.clone();
             
-            let dispatch_wasm = dispatch_wasm.clone();
             let new_username = new_username.clone();
             let new_password = new_password.clone();
             let fullname = fullname.clone();
@@ -399,7 +386,6 @@ pub fn user_settings() -> Html {
             e.prevent_default();
             
             // Check if each field has input and call the corresponding API function
-            let fullname_dispatch = dispatch_wasm.clone();
             let page_state_name = page_state.clone();
             let page_state_user = page_state.clone();
             let page_state_pass = page_state.clone();
@@ -435,17 +421,17 @@ pub fn user_settings() -> Html {
                                         },
                                         Err(e) => {
                                             error_container_name.set(error_container_state::Shown);
-                                            error_message_container_name.set(format!("Error updating name: {}", e).to_string());
+                                            error_message_container_name.set(I18n::current().tf("user_settings.name_error", &[("error", &e.to_string())]));
                                         },
                                     }
                                 } else {
-                                    fullname_dispatch.reduce_mut(|state| state.error_message = Option::from("User ID not available for name update.".to_string()));
+                                    notify_error(I18n::current().t("user_settings.name_no_user_id"));
                                 }
                             } else {
-                                fullname_dispatch.reduce_mut(|state| state.error_message = Option::from("API key not available for name update.".to_string()));
+                                notify_error(I18n::current().t("user_settings.name_no_api_key"));
                             }
                         } else {
-                            fullname_dispatch.reduce_mut(|state| state.error_message = Option::from("Server name not available for name update.".to_string()));
+                            notify_error(I18n::current().t("user_settings.name_no_server"));
                         }
                     }
                 });
@@ -477,20 +463,20 @@ pub fn user_settings() -> Html {
                                             },
                                             Err(_e) => {
                                                 error_container_user.set(error_container_state::Shown);
-                                                error_message_container_user.set("Error updating username. Usernames must be at least 4 characters long.".to_string());
+                                                error_message_container_user.set(I18n::current().t("user_settings.username_error"));
                                             },
                                         }
                                     }
 
                                 } else {
-                                    dispatch_wasm.reduce_mut(|state| state.error_message = Option::from("API key not available for username update.".to_string()));
+                                    notify_error(I18n::current().t("user_settings.username_no_api_key"));
 
                                 }
                             } else {
-                                dispatch_wasm.reduce_mut(|state| state.error_message = Option::from("API key not available for username update.".to_string()));
+                                notify_error(I18n::current().t("user_settings.username_no_api_key"));
                             }
                         } else {
-                            dispatch_wasm.reduce_mut(|state| state.error_message = Option::from("Server name not available for username update.".to_string()));
+                            notify_error(I18n::current().t("user_settings.username_no_server"));
                         }
                     }
                 });
@@ -521,7 +507,7 @@ pub fn user_settings() -> Html {
                                             },
                                             Err(_e) => {
                                                 error_container_email.set(error_container_state::Shown);
-                                                error_message_container_email.set(format!("Error updating email. The formatting didn't look quite right").to_string());
+                                                error_message_container_email.set(I18n::current().t("user_settings.email_error"));
                                             },
                                         }
                                     }
@@ -547,6 +533,7 @@ pub fn user_settings() -> Html {
                     let server_name_cloned = server_name.clone();
                     let api_key_cloned = api_key.clone();
                     let new_password_cloned = new_password.clone();
+                    let password_policy = password_policy.clone();
                     let selected_user_id_cloned = (*call_selected_user_id).clone();
                     let update_trigger_in_check = update_trigger_pass.clone();
 
@@ -554,29 +541,20 @@ pub fn user_settings() -> Html {
                         if let Some(server_name_unwrapped) = server_name_cloned {
                             if let Some(api_key_unwrapped) = api_key_cloned.as_ref().and_then(|key| key.as_ref()) {
                                 if let Some(Some(user_id)) = selected_user_id_cloned {
-                                    match encode_password(&new_password_cloned) {
-                                        Ok(hash_pw) => {
-                                            let errors = validate_email(&new_password_cloned.clone().as_str());
-
-                                            if errors.contains(&ValidationError::PasswordTooShort) {
-                                                password_error.set(password_error_notice::Shown);
-                                            } else {
-                                                page_state_pass.set(PageState::Hidden);
-                                                web_sys::console::log_1(&format!("pw: {}", hash_pw.clone()).into());
-                                                match call_set_password(server_name_unwrapped, api_key_unwrapped.clone(), user_id, hash_pw).await {
-                                                    Ok(_) => {
-                                                        update_trigger_in_check.set(!*update_trigger_in_check);
-                                                    },
-                                                    Err(_e) => {
-                                                        error_container_pass.set(error_container_state::Shown);
-                                                        error_message_container_pass.set(format!("Error updating password. Passwords must be at least 6 characters long.").to_string());
-                                                    },
-                                                }
-                                            }
+                                    let errors = validate_password(&new_password_cloned, &password_policy);
 
-                                        },
-                                        Err(e) => {
-                                            console::log_1(&format!("Password encoding failed: {:?}", e).into());
+                                    if errors.contains(&ValidationError::WeakPassword) {
+                                        password_error.set(password_error_notice::Shown);
+                                    } else {
+                                        page_state_pass.set(PageState::Hidden);
+                                        match call_set_password(server_name_unwrapped, api_key_unwrapped.clone(), user_id, (*new_password_cloned).clone()).await {
+                                            Ok(_) => {
+                                                update_trigger_in_check.set(!*update_trigger_in_check);
+                                            },
+                                            Err(e) => {
+                                                error_container_pass.set(error_container_state::Shown);
+                                                error_message_container_pass.set(I18n::current().tf("user_settings.password_error", &[("error", &e.to_string())]));
+                                            },
                                         }
                                     }
                                 } else {
@@ -615,7 +593,7 @@ pub fn user_settings() -> Html {
                                         },
                                         Err(e) => {
                                             error_container_admin.set(error_container_state::Shown);
-                                            error_message_container_admin.set(format!("Error updating admin status: {:?}", e).to_string());
+                                            error_message_container_admin.set(I18n::current().tf("user_settings.admin_error", &[("error", &format!("{:?}", e))]));
                                         },
 
                                     }
@@ -647,7 +625,7 @@ pub fn user_settings() -> Html {
                                         Ok(final_admin) => {
                                             if final_admin.final_admin == true {
                                                 error_container.set(error_container_state::Shown);
-                                                error_message_container.set(format!("Unable to remove admin status from final administrator").to_string());
+                                                error_message_container.set(I18n::current().t("user_settings.last_admin"));
                                             } else {
                                                 if *admin_edit_status_false == 1 {
                                                     page_state_false.set(PageState::Hidden);
@@ -659,7 +637,7 @@ pub fn user_settings() -> Html {
                                                     },
                                                     Err(e) => {
                                                         error_container.set(error_container_state::Shown);
-                                                        error_message_container.set(format!("Error updating admin status: {:?}", e).to_string());
+                                                        error_message_container.set(I18n::current().tf("user_settings.admin_error", &[("error", &format!("{:?}", e))]));
                                                     },
 
                                                 }
@@ -693,57 +671,58 @@ pub fn user_settings() -> Html {
                 <div class="modal-container relative rounded-lg shadow">
                     <div class="flex items-center justify-between p-4 md:p-5 border-b rounded-t">
                         <h3 class="text-xl font-semibold">
-                            {"Edit Existing User"}
+                            { i18n.t("user_settings.edit_user") }
                         </h3>
                         <button onclick={on_close_modal.clone()} class="end-2.5 text-gray-400 bg-transparent hover:bg-gray-200 hover:text-gray-900 rounded-lg text-sm w-8 h-8 ms-auto inline-flex justify-center items-center dark:hover:bg-gray-600 dark:hover:text-white">
                             <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 14 14">
                                 <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 6 6m0 0 6 6M7 7l6-6M7 7l-6 6"/>
                             </svg>
-                            <span class="sr-only">{"Close modal"}</span>
+                            <span class="sr-only">{ i18n.t("common.close_modal") }</span>
                         </button>
                     </div>
                     <p class="text-m font-semibold">
-                    {"Change the fields below coresponding to the user details you want to edit. Do not add values to fields you don't want to change. Leave those blank."}
+                    { i18n.t("user_settings.edit_user_body") }
                     </p>
                     <div class="p-4 md:p-5">
                         <form class="space-y-4" action="#">
                             <div>
-                                <label for="username" class="block mb-2 text-sm font-medium">{"Username"}</label>
+                                <label for="username" class="block mb-2 text-sm font-medium">{ i18n.t("common.username") }</label>
                                 <input oninput={on_username_change.clone()} placeholder="pinepods_user1" type="text" id="username" name="username" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
                                 {
                                     match *username_error {
                                         username_error_notice::Hidden => html! {},
-                                        username_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{"Username must be at least 4 characters long"}</p>},
+                                        username_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{ i18n.t("login.username_too_short") }</p>},
                                     }
                                 }
                             </div>
                             <div>
-                                <label for="fullname" class="block mb-2 text-sm font-medium">{"Full Name"}</label>
-                                <input oninput={on_fullname_change} placeholder="Pinepods User" type="text" id="fullname" name="fullname" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
+                                <label for="fullname" class="block mb-2 text-sm font-medium">{ i18n.t("login.full_name") }</label>
+                                <input oninput={on_fullname_change} placeholder={i18n.t("user_settings.full_name_placeholder")} type="text" id="fullname" name="fullname" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
                             </div>
                             <div>
-                                <label for="email" class="block mb-2 text-sm font-medium">{"Email"}</label>
+                                <label for="email" class="block mb-2 text-sm font-medium">{ i18n.t("common.email") }</label>
                                 <input oninput={on_email_change} placeholder="user@pinepods.online" type="email" id="email" name="email" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
                                 {
                                     match *email_error {
                                         email_error_notice::Hidden => html! {},
-                                        email_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{"Invalid email address"}</p>},
+                                        email_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{ i18n.t("login.invalid_email") }</p>},
                                     }
                                 }
                             </div>
                             <div>
-                                <label for="password" class="block mb-2 text-sm font-medium">{"Password"}</label>
+                                <label for="password" class="block mb-2 text-sm font-medium">{ i18n.t("common.password") }</label>
                                 <input oninput={on_password_change.clone()} placeholder="my_S3creT_P@$$" type="password" id="password" name="password" class="search-bar-input border text-sm rounded-lg block w-full p-2.5" required=true />
+                                <PasswordStrengthMeter password={(*new_password).clone()} policy={(*password_policy).clone()} />
                                 {
                                     match *password_error {
                                         password_error_notice::Hidden => html! {},
-                                        password_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{"Password must be at least 6 characters long"}</p>},
+                                        password_error_notice::Shown => html! {<p class="text-red-500 text-xs italic">{ i18n.t("login.password_requirements") }</p>},
                                     }
                                 }
                             </div>
                             <div class="flex items-center justify-between">
                                 <div class="flex items-center">
-                                    <label for="admin" class="mr-2 text-sm font-medium">{"Admin User?"}</label>
+                                    <label for="admin" class="mr-2 text-sm font-medium">{ i18n.t("user_settings.admin_user") }</label>
                                     <input oninput={on_admin_change} type="checkbox" id="admin" name="admin" class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 p-2.5 dark:bg-gray-600 dark:border-gray-500 dark:placeholder-gray-400 dark:text-white" required=true />
                                 </div>
                                 <button
@@ -751,11 +730,11 @@ pub fn user_settings() -> Html {
                                     onclick={on_delete_click}
                                     class="delete-button bg-red-500 hover:bg-red-700 text-white font-bold py-2 px-4 rounded"
                                 >
-                                    {"Delete User"}
+                                    { i18n.t("user_settings.delete_user") }
                             
                                 </button>
                             </div>
-                            <button type="submit" onclick={on_edit_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{"Submit"}</button>
+                            <button type="submit" onclick={on_edit_submit} class="download-button w-full focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center">{ i18n.t("common.submit") }</button>
 
                         </form>
                     </div>
@@ -774,10 +753,10 @@ pub fn user_settings() -> Html {
             }
         }
             <div class="p-4">
-                <p class="item_container-text text-lg font-bold mb-4">{"User Management:"}</p>
-                <p class="item_container-text text-md mb-4">{"You can manage users here. Click a user in the table to manage settings for that existing user or click 'Create New' to add a new user. Note that the guest user will always show regardless of whether it's enabled or not. View the Guest Settings Area to properly manage that."}</p>
+                <p class="item_container-text text-lg font-bold mb-4">{ i18n.t("user_settings.title") }</p>
+                <p class="item_container-text text-md mb-4">{ i18n.t("user_settings.description") }</p>
                 <button onclick={on_create_new_user} class="mt-4 settings-button font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline">
-                    {"Create New User"}
+                    { i18n.t("login.create_user") }
                 </button>
             </div>
             <div class="relative overflow-x-auto">
@@ -790,11 +769,11 @@ pub fn user_settings() -> Html {
                 <table class="w-full text-sm text-left rtl:text-right text-gray-500 dark:text-gray-400">
                     <thead class="text-xs uppercase table-header">
                         <tr>
-                            <th scope="col" class="px-6 py-3">{"User ID"}</th>
-                            <th scope="col" class="px-6 py-3">{"Fullname"}</th>
-                            <th scope="col" class="px-6 py-3">{"Email"}</th>
-                            <th scope="col" class="px-6 py-3">{"Username"}</th>
-                            <th scope="col" class="px-6 py-3">{"Admin Status"}</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("user_settings.user_id") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("user_settings.fullname") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("common.email") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("common.username") }</th>
+                            <th scope="col" class="px-6 py-3">{ i18n.t("user_settings.admin_status") }</th>
                         </tr>
                     </thead>
                     <tbody>
@@ -809,7 +788,7 @@ pub fn user_settings() -> Html {
                                         <td class="px-6 py-4">{ &user.fullname }</td>
                                         <td class="px-6 py-4">{ &user.email }</td>
                                         <td class="px-6 py-4">{ &user.username }</td>
-                                        <td class="px-6 py-4">{ if user.isadmin == 1 { "Yes" } else { "No" } }</td>
+                                        <td class="px-6 py-4">{ if user.isadmin == 1 { i18n.t("common.yes") } else { i18n.t("common.no") } }</td>
                                     </tr>
                                 }
                             }
@@ -2,13 +2,11 @@ use super::app_drawer::App_drawer;
 use super::gen_components::{Search_nav, UseScrollToTop};
 use crate::components::audio::AudioPlayer;
 use crate::components::context::{AppState, UIState};
-use crate::components::episodes_layout::UIStateMsg;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::components::notifications::notify_error;
 use crate::components::setting_components;
 use crate::requests::login_requests::use_check_authentication;
 use crate::requests::setting_reqs::call_user_admin_check;
-use wasm_bindgen::closure::Closure;
-use wasm_bindgen::JsCast;
-use web_sys::window;
 use yew::prelude::*;
 use yewdux::{prelude::*};
 // use crate::components::gen_funcs::check_auth;
@@ -108,6 +106,7 @@ pub fn accordion_item(
 
 #[function_component(Settings)]
 pub fn settings() -> Html {
+    let i18n = use_i18n();
     let (state, dispatch) = use_store::<AppState>();
     let effect_dispatch = dispatch.clone();
 
@@ -148,10 +147,8 @@ pub fn settings() -> Html {
 
 
     let (_post_state, _post_dispatch) = use_store::<AppState>();
-    let (audio_state, audio_dispatch) = use_store::<UIState>();
+    let (audio_state, _audio_dispatch) = use_store::<UIState>();
 
-    let error_message = audio_state.error_message.clone();
-    let info_message = audio_state.info_message.clone();
     let active_tab = use_state(|| "user");
 
     let api_key = _post_state
@@ -168,7 +165,6 @@ pub fn settings() -> Html {
         .map(|ud| ud.server_name.clone());
 
     let is_admin = use_state(|| false);
-    let audio_admin = audio_dispatch.clone();
 
     {
         let is_admin = is_admin.clone();
@@ -191,10 +187,7 @@ pub fn settings() -> Html {
                             is_admin.set(response.is_admin);
                         }
                         Err(e) => {
-                            audio_admin.reduce_mut(|state| {
-                                state.error_message =
-                                    Some(format!("Failed to check admin status: {:?}", e))
-                            });
+                            notify_error(I18n::current().tf("settings.admin_check_failed", &[("error", &format!("{:?}", e))]));
                             // console::log_1(&format!("Failed to check admin status: {:?}", e).into());
                         }
                     }
@@ -204,30 +197,6 @@ pub fn settings() -> Html {
         });
     }
 
-    {
-        let ui_dispatch = audio_dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                ui_dispatch.apply(UIStateMsg::ClearErrorMessage);
-                ui_dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
 
     let on_user_tab_click = {
         let active_tab = active_tab.clone();
@@ -245,14 +214,14 @@ pub fn settings() -> Html {
             <Search_nav />
             <UseScrollToTop />
             <div class="my-4">
-                <h1 class="item_container-text text-2xl font-bold mb-3">{ "Settings" }</h1>
+                <h1 class="item_container-text text-2xl font-bold mb-3">{ i18n.t("settings.title") }</h1>
                 <div class="item_container-text tabs flex flex-wrap text-sm font-medium text-center border-b border-gray-200">
-                    <Tab is_active={*active_tab == "user"} class="me-2" label={"User Settings".to_string()} onclick={on_user_tab_click.clone()} />
+                    <Tab is_active={*active_tab == "user"} class="me-2" label={i18n.t("settings.user_tab")} onclick={on_user_tab_click.clone()} />
                     // <Tab is_active={*active_tab == "admin"} class="me-2" label={"Admin Settings".to_string()} onclick={on_admin_tab_click.clone()} />
                     {
                         if *is_admin {
                             html! {
-                                <Tab is_active={*active_tab == "admin"} class="me-2" label={"Admin Settings".to_string()} onclick={on_admin_tab_click.clone()} />
+                                <Tab is_active={*active_tab == "admin"} class="me-2" label={i18n.t("settings.admin_tab")} onclick={on_admin_tab_click.clone()} />
                             }
                         } else {
                             html! {}
@@ -264,24 +233,26 @@ pub fn settings() -> Html {
                     if *active_tab == "user" {
                         html! {
                         <div id="accordion-collapse" data-accordion="collapse" class="bg-custom-light">
-                            <AccordionItem title="Change Theme" content={html!{ <setting_components::theme_options::ThemeOptions /> }} position={AccordionItemPosition::First}/>
-                            <AccordionItem title="MFA Settings" content={html!{ <setting_components::mfa_settings::MFAOptions /> }} position={AccordionItemPosition::Middle}/>
-                            <AccordionItem title="Export/Backup Podcasts" content={html!{ <setting_components::export_settings::ExportOptions /> }} position={AccordionItemPosition::Middle}/>
-                            <AccordionItem title="Import Podcasts" content={html!{ <setting_components::import_options::ImportOptions /> }} position={AccordionItemPosition::Middle}/>
-                            <AccordionItem title="Add Custom Feed" content={html!{ <setting_components::custom_feed::CustomFeed /> }} position={AccordionItemPosition::Middle}/>
-                            <AccordionItem title="Connect Nextcloud/Gpodder Podcast Sync" content={html!{ <setting_components::nextcloud_options::NextcloudOptions /> }} position={AccordionItemPosition::Middle}/>
-                            <AccordionItem title="Api Keys" content={html!{ <setting_components::api_keys::APIKeys /> }} position={AccordionItemPosition::Middle}/>
+                            <AccordionItem title={i18n.t("settings.theme")} content={html!{ <setting_components::theme_options::ThemeOptions /> }} position={AccordionItemPosition::First}/>
+                            <AccordionItem title={i18n.t("settings.language")} content={html!{ <setting_components::language_options::LanguageOptions /> }} position={AccordionItemPosition::Middle}/>
+                            <AccordionItem title={i18n.t("settings.mfa")} content={html!{ <setting_components::mfa_settings::MFAOptions /> }} position={AccordionItemPosition::Middle}/>
+                            <AccordionItem title={i18n.t("settings.export")} content={html!{ <setting_components::export_settings::ExportOptions /> }} position={AccordionItemPosition::Middle}/>
+                            <AccordionItem title={i18n.t("settings.import")} content={html!{ <setting_components::import_options::ImportOptions /> }} position={AccordionItemPosition::Middle}/>
+                            <AccordionItem title={i18n.t("settings.custom_feed")} content={html!{ <setting_components::custom_feed::CustomFeed /> }} position={AccordionItemPosition::Middle}/>
+                            <AccordionItem title={i18n.t("settings.nextcloud")} content={html!{ <setting_components::nextcloud_options::NextcloudOptions /> }} position={AccordionItemPosition::Middle}/>
+                            <AccordionItem title={i18n.t("settings.api_keys")} content={html!{ <setting_components::api_keys::APIKeys /> }} position={AccordionItemPosition::Middle}/>
                         </div>
                         }
                     } else if *active_tab == "admin" {
                         html! {
                         <div id="accordion-collapse" data-accordion="collapse" class="bg-custom-light">
-                            <AccordionItem title="User Management" content={html!{ <setting_components::user_settings::UserSettings /> }} position={AccordionItemPosition::First}/>
+                            <AccordionItem title={i18n.t("settings.user_management")} content={html!{ <setting_components::user_settings::UserSettings /> }} position={AccordionItemPosition::First}/>
                             // <AccordionItem title="Guest Settings" content={html!{ <setting_components::guest_settings::GuestSettings /> }} position={AccordionItemPosition::Middle}/>
-                            <AccordionItem title="Download Settings" content={html!{ <setting_components::download_settings::DownloadSettings /> }} position={AccordionItemPosition::Middle}/>
-                            <AccordionItem title="User Self Service Settings" content={html!{ <setting_components::user_self_service::SelfServiceSettings /> }} position={AccordionItemPosition::Middle}/>
-                            <AccordionItem title="Email Settings" content={html!{ <setting_components::email_settings::EmailSettings /> }} position={AccordionItemPosition::Middle}/>
-                            <AccordionItem title="Backup Server" content={html!{ <setting_components::backup_server::BackupServer /> }} position={AccordionItemPosition::Middle}/>
+                            <AccordionItem title={i18n.t("settings.downloads")} content={html!{ <setting_components::download_settings::DownloadSettings /> }} position={AccordionItemPosition::Middle}/>
+                            <AccordionItem title={i18n.t("settings.self_service")} content={html!{ <setting_components::user_self_service::SelfServiceSettings /> }} position={AccordionItemPosition::Middle}/>
+                            <AccordionItem title={i18n.t("settings.password_policy")} content={html!{ <setting_components::password_policy::PasswordPolicySettings /> }} position={AccordionItemPosition::Middle}/>
+                            <AccordionItem title={i18n.t("settings.email")} content={html!{ <setting_components::email_settings::EmailSettings /> }} position={AccordionItemPosition::Middle}/>
+                            <AccordionItem title={i18n.t("settings.backup_server")} content={html!{ <setting_components::backup_server::BackupServer /> }} position={AccordionItemPosition::Middle}/>
                             // <AccordionItem title="Restore Server" content={html!{ <setting_components::restore_server::RestoreServer /> }} position={AccordionItemPosition::Middle}/>
                         </div>
                         }
@@ -291,13 +262,6 @@ pub fn settings() -> Html {
                 }
                 </div>
             </div>
-            // Conditional rendering for the error banner
-            if let Some(error) = error_message {
-                <div class="error-snackbar">{ error }</div>
-            }
-            if let Some(info) = info_message {
-                <div class="info-snackbar">{ info }</div>
-            }
             {
                 if let Some(audio_props) = &audio_state.currently_playing {
                     html! { <AudioPlayer src={audio_props.src.clone()} title={audio_props.title.clone()} artwork_url={audio_props.artwork_url.clone()} duration={audio_props.duration.clone()} episode_id={audio_props.episode_id.clone()} duration_sec={audio_props.duration_sec.clone()} start_pos_sec={audio_props.start_pos_sec.clone()} end_pos_sec={audio_props.end_pos_sec.clone()} offline={audio_props.offline.clone()} /> }
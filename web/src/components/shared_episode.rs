@@ -3,9 +3,10 @@ use crate::components::audio::on_play_click_shared;
 use crate::components::audio::AudioPlayer;
 use crate::components::context::{AppState, UIState};
 use crate::components::episodes_layout::SafeHtml;
-use crate::components::episodes_layout::{HostDropdown, UIStateMsg};
+use crate::components::episodes_layout::HostDropdown;
+use crate::components::i18n::{use_i18n, I18n};
 use crate::components::gen_funcs::{
-    format_datetime, format_time, match_date_format, parse_date,
+    format_datetime, format_time, format_time_rm_hour, match_date_format, parse_date,
     sanitize_html_with_blank_target,
 };
 use crate::requests::pod_req::{
@@ -36,6 +37,7 @@ pub struct SharedProps {
 pub fn shared_episode(props: &SharedProps) -> Html {
     web_sys::console::log_1(&wasm_bindgen::JsValue::from_str("shared ep hit"));
     let (state, dispatch) = use_store::<AppState>();
+    let i18n = use_i18n();
 
     let error = use_state(|| None);
 
@@ -45,8 +47,6 @@ pub fn shared_episode(props: &SharedProps) -> Html {
     //     .auth_details
     //     .as_ref()
     //     .map(|ud| ud.server_name.clone());
-    let error_message = audio_state.error_message.clone();
-    let info_message = audio_state.info_message.clone();
     let loading = use_state(|| true); // Initial loading state set to true
 
     {
@@ -82,30 +82,6 @@ pub fn shared_episode(props: &SharedProps) -> Html {
     }
     web_sys::console::log_1(&wasm_bindgen::JsValue::from_str("window type check"));
 
-    {
-        let ui_dispatch = audio_dispatch.clone();
-        use_effect(move || {
-            let window = window().unwrap();
-            let document = window.document().unwrap();
-
-            let closure = Closure::wrap(Box::new(move |_event: Event| {
-                ui_dispatch.apply(UIStateMsg::ClearErrorMessage);
-                ui_dispatch.apply(UIStateMsg::ClearInfoMessage);
-            }) as Box<dyn Fn(_)>);
-
-            document
-                .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            // Return cleanup function
-            move || {
-                document
-                    .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
-                    .unwrap();
-                closure.forget(); // Prevents the closure from being dropped
-            }
-        });
-    }
     web_sys::console::log_1(&wasm_bindgen::JsValue::from_str("messaging setup"));
 
     // Fetch episode on component mount
@@ -149,8 +125,10 @@ pub fn shared_episode(props: &SharedProps) -> Html {
                             loading_clone.set(false);
                         }
                         Err(e) => {
-                            error_clone
-                                .set(Some(format!("Error fetching shared episode: {}", e)));
+                            error_clone.set(Some(
+                                I18n::current()
+                                    .tf("shared_episode.load_error", &[("error", &e.to_string())]),
+                            ));
                         }
                     }
                 });
@@ -206,9 +184,25 @@ pub fn shared_episode(props: &SharedProps) -> Html {
                             episode_artwork_for_closure.clone(),
                             episode_duration_for_closure.clone(),
                             episode_id_for_closure.clone(),
+                            episode.start_time,
+                            episode.end_time,
                             audio_dispatch.clone(),
                         );
 
+                        // Links made from the clip picker only cover part of the episode
+                        let is_clip = episode.start_time.is_some() || episode.end_time.is_some();
+                        let play_label = if is_clip { i18n.t("shared_episode.play_clip") } else { i18n.t("episode_page.play") };
+                        let clip_range = if is_clip {
+                            let start = format_time_rm_hour(episode.start_time.unwrap_or(0) as f64);
+                            let end = episode.end_time.map_or_else(
+                                || i18n.t("shared_episode.clip_end"),
+                                |end| format_time_rm_hour(end as f64),
+                            );
+                            html! { <p class="episode-duration shared-clip-range">{ i18n.tf("shared_episode.clip_range", &[("start", &start), ("end", &end)]) }</p> }
+                        } else {
+                            html! {}
+                        };
+
                         let datetime = parse_date(&episode.episode.episodepubdate, &state.user_tz);
                         let date_format = match_date_format(state.date_format.as_deref());
                         let format_duration = format_time(episode.episode.episodeduration as f64);
@@ -245,13 +239,14 @@ pub fn shared_episode(props: &SharedProps) -> Html {
                                                 <span class="episode-duration">{"\u{00a0}-\u{00a0}"}</span>
                                                 <p class="episode-release-date">{ format_release }</p>
                                             </div>
+                                            { clip_range.clone() }
                                         </div>
                                     <div class="episode-action-buttons">
                                         <div class="button-row">
                                             <button onclick={on_play_click} class="play-button">
                                             // <button class="play-button">
                                                 <i class="material-icons">{ "play_arrow" }</i>
-                                                {"Play"}
+                                                { play_label }
                                             </button>
                                         </div>
                                     </div>
@@ -277,6 +272,7 @@ pub fn shared_episode(props: &SharedProps) -> Html {
                                             // <h2 class="episode-title">{ &episode.episode.episodetitle }</h2>
                                             <p class="episode-duration">{ format_duration }</p>
                                             <p class="episode-release-date">{ format_release }</p>
+                                            { clip_range }
                                             {
                                                 if let Some(transcript) = &audio_state.episode_page_transcript {
                                                     if !transcript.is_empty() {
@@ -290,10 +286,10 @@ pub fn shared_episode(props: &SharedProps) -> Html {
                                                                     <div class="header-info pb-2 pt-2">
                                                                         <button
                                                                             onclick={Callback::from(move |_| open_in_new_tab.emit(url.clone()))}
-                                                                            title={"Transcript"}
+                                                                            title={i18n.t("transcript.title")}
                                                                             class="font-bold item-container-button"
                                                                         >
-                                                                            { "Episode Transcript" }
+                                                                            { i18n.t("transcript.show") }
                                                                         </button>
                                                                     </div>
                                                                 }
@@ -312,7 +308,7 @@ pub fn shared_episode(props: &SharedProps) -> Html {
                                                     if !people.is_empty() {
                                                         html! {
                                                             <div class="header-info">
-                                                                <HostDropdown title="In This Episode" hosts={people.clone()} />
+                                                                <HostDropdown title={i18n.t("episode_page.people")} hosts={people.clone()} />
                                                             </div>
                                                         }
                                                     } else {
@@ -328,7 +324,7 @@ pub fn shared_episode(props: &SharedProps) -> Html {
                                     <button onclick={on_play_click} class="play-button">
                                     // <button class="play-button">
                                         <i class="material-icons">{ "play_arrow" }</i>
-                                        {"Play"}
+                                        { play_label }
                                     </button>
 
                                     </div>
@@ -347,8 +343,8 @@ pub fn shared_episode(props: &SharedProps) -> Html {
                         layout
                     } else {
                         empty_message(
-                            "Unable to display episode",
-                            "Something seems to have gone wrong. A straightup server disconnect maybe? Did you browse here directly? That's not how this app works. It needs the context to browse around. I honestly don't have anything else for you as this shouldn't happen. This is embarrasing."
+                            &i18n.t("episode_page.unavailable_title"),
+                            &i18n.t("episode_page.unavailable_body")
                         )
                     }
                 }
@@ -360,13 +356,6 @@ pub fn shared_episode(props: &SharedProps) -> Html {
                 html! {}
             }
         }
-        // Conditional rendering for the error banner
-        if let Some(error) = error_message {
-            <div class="error-snackbar">{ error }</div>
-        }
-        if let Some(info) = info_message {
-            <div class="info-snackbar">{ info }</div>
-        }
         </div>
         </>
     }
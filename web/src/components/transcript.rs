@@ -0,0 +1,616 @@
+use crate::components::context::{AppState, UIState};
+use crate::components::gen_funcs::format_time_rm_hour;
+use crate::components::i18n::{use_i18n, I18n};
+use crate::requests::pod_req::{call_fetch_transcript, Transcript};
+use serde::Deserialize;
+use wasm_bindgen::JsCast;
+use web_sys::{DomParser, HtmlElement, SupportedType};
+use yew::prelude::*;
+use yewdux::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TranscriptCue {
+    pub start: Option<f64>,
+    pub end: Option<f64>,
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TranscriptFormat {
+    Json,
+    WebVtt,
+    Srt,
+    Html,
+}
+
+impl TranscriptFormat {
+    fn from_mime_type(mime_type: &str) -> Option<Self> {
+        // Feeds sometimes tack a charset onto the type, so only look at the essence
+        let essence = mime_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+        match essence.as_str() {
+            "application/json" => Some(TranscriptFormat::Json),
+            "text/vtt" => Some(TranscriptFormat::WebVtt),
+            "application/srt" | "application/x-subrip" | "text/srt" => Some(TranscriptFormat::Srt),
+            "text/html" => Some(TranscriptFormat::Html),
+            _ => None,
+        }
+    }
+
+    // Lower is better. Timed formats win over HTML, which usually has no timing at all.
+    fn rank(&self) -> u8 {
+        match self {
+            TranscriptFormat::Json => 0,
+            TranscriptFormat::WebVtt => 1,
+            TranscriptFormat::Srt => 2,
+            TranscriptFormat::Html => 3,
+        }
+    }
+}
+
+/// Picks the transcript we know how to display, preferring the formats with the best timing data.
+pub fn pick_transcript(transcripts: &[Transcript]) -> Option<Transcript> {
+    transcripts
+        .iter()
+        .filter_map(|t| TranscriptFormat::from_mime_type(&t.mime_type).map(|f| (f.rank(), t)))
+        .min_by_key(|(rank, _)| *rank)
+        .map(|(_, t)| t.clone())
+}
+
+pub fn parse_transcript(mime_type: &str, body: &str) -> Vec<TranscriptCue> {
+    match TranscriptFormat::from_mime_type(mime_type) {
+        Some(TranscriptFormat::Json) => parse_json_transcript(body),
+        Some(TranscriptFormat::WebVtt) | Some(TranscriptFormat::Srt) => parse_cue_blocks(body),
+        Some(TranscriptFormat::Html) => parse_html_transcript(body),
+        None => Vec::new(),
+    }
+}
+
+// Accepts both "HH:MM:SS,mmm" (SRT) and "MM:SS.mmm" / "HH:MM:SS.mmm" (WebVTT)
+fn parse_timestamp(value: &str) -> Option<f64> {
+    let value = value.trim().replace(',', ".");
+    let parts: Vec<&str> = value.split(':').collect();
+    let (hours, minutes, seconds) = match parts.len() {
+        3 => (
+            parts[0].parse::<f64>().ok()?,
+            parts[1].parse::<f64>().ok()?,
+            parts[2].parse::<f64>().ok()?,
+        ),
+        2 => (
+            0.0,
+            parts[0].parse::<f64>().ok()?,
+            parts[1].parse::<f64>().ok()?,
+        ),
+        1 => (0.0, 0.0, parts[0].parse::<f64>().ok()?),
+        _ => return None,
+    };
+    Some(hours * 3600.0 + minutes * 60.0 + seconds)
+}
+
+fn strip_tags(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => result.push(c),
+            _ => {}
+        }
+    }
+    result
+}
+
+// WebVTT voice spans look like `<v Speaker Name>text` or `<v.loud Speaker Name>text`
+fn split_voice_tag(text: &str) -> (Option<String>, &str) {
+    if let Some(rest) = text.strip_prefix("<v") {
+        if let Some(close) = rest.find('>') {
+            let tag = rest[..close].trim_start_matches(|c: char| c != ' ' && c != '\t');
+            let speaker = tag.trim();
+            if !speaker.is_empty() {
+                return (Some(speaker.to_string()), &rest[close + 1..]);
+            }
+        }
+    }
+    (None, text)
+}
+
+// SRT and WebVTT are both blank-line separated blocks with a "start --> end" timing line.
+// Blocks without a timing line (WEBVTT header, NOTE, STYLE, REGION) are skipped.
+fn parse_cue_blocks(body: &str) -> Vec<TranscriptCue> {
+    let normalized = body.replace("\r\n", "\n").replace('\r', "\n");
+    let mut cues = Vec::new();
+
+    for block in normalized.split("\n\n") {
+        let lines: Vec<&str> = block
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .collect();
+        let Some(timing_index) = lines.iter().position(|l| l.contains("-->")) else {
+            continue;
+        };
+
+        let mut timing = lines[timing_index].split("-->");
+        let start = timing.next().and_then(parse_timestamp);
+        // WebVTT allows cue settings after the end time, e.g. "00:04.000 align:start"
+        let end = timing
+            .next()
+            .and_then(|t| t.split_whitespace().next())
+            .and_then(parse_timestamp);
+
+        let raw_text = lines[timing_index + 1..].join(" ");
+        let (speaker, text) = split_voice_tag(&raw_text);
+        let text = strip_tags(text).trim().to_string();
+        if text.is_empty() {
+            continue;
+        }
+
+        cues.push(TranscriptCue {
+            start,
+            end,
+            speaker,
+            text,
+        });
+    }
+
+    cues
+}
+
+#[derive(Deserialize)]
+struct JsonTranscript {
+    segments: Vec<JsonSegment>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonSegment {
+    speaker: Option<String>,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    body: String,
+}
+
+fn parse_json_transcript(body: &str) -> Vec<TranscriptCue> {
+    let transcript: JsonTranscript = match serde_json::from_str(body) {
+        Ok(transcript) => transcript,
+        Err(e) => {
+            web_sys::console::log_1(&format!("Error parsing JSON transcript: {}", e).into());
+            return Vec::new();
+        }
+    };
+
+    // JSON transcripts are frequently word-level, so fold runs of words from the same
+    // speaker together until a sentence ends.
+    let mut cues: Vec<TranscriptCue> = Vec::new();
+    for segment in transcript.segments {
+        let text = segment.body.trim();
+        if text.is_empty() {
+            continue;
+        }
+        if let Some(last) = cues.last_mut() {
+            let sentence_open = !last.text.ends_with(['.', '?', '!']);
+            if sentence_open && last.speaker == segment.speaker && !text.contains(' ') {
+                last.text.push(' ');
+                last.text.push_str(text);
+                last.end = segment.end_time.or(last.end);
+                continue;
+            }
+        }
+        cues.push(TranscriptCue {
+            start: segment.start_time,
+            end: segment.end_time,
+            speaker: segment.speaker,
+            text: text.to_string(),
+        });
+    }
+
+    cues
+}
+
+// The Podcasting 2.0 HTML format is a flat run of <cite>speaker</cite>, <time>start</time>
+// and <p>text</p> elements. Anything else falls back to one cue per paragraph.
+fn parse_html_transcript(body: &str) -> Vec<TranscriptCue> {
+    let parser = match DomParser::new() {
+        Ok(parser) => parser,
+        Err(_) => return Vec::new(),
+    };
+    let doc = match parser.parse_from_string(body, SupportedType::TextHtml) {
+        Ok(doc) => doc,
+        Err(_) => return Vec::new(),
+    };
+    let elements = match doc.query_selector_all("cite, time, p") {
+        Ok(elements) => elements,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut cues = Vec::new();
+    let mut speaker: Option<String> = None;
+    let mut start: Option<f64> = None;
+    for i in 0..elements.length() {
+        let Some(element) = elements
+            .item(i)
+            .and_then(|n| n.dyn_into::<web_sys::Element>().ok())
+        else {
+            continue;
+        };
+        let text = element
+            .text_content()
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        match element.tag_name().to_lowercase().as_str() {
+            "cite" => speaker = Some(text.trim_end_matches(':').trim().to_string()),
+            "time" => start = parse_timestamp(&text),
+            _ => {
+                if !text.is_empty() {
+                    cues.push(TranscriptCue {
+                        start: start.take(),
+                        end: None,
+                        speaker: speaker.clone(),
+                        text,
+                    });
+                }
+            }
+        }
+    }
+
+    cues
+}
+
+fn active_cue_index(cues: &[TranscriptCue], current_time: f64) -> Option<usize> {
+    cues.iter()
+        .rposition(|cue| cue.start.map_or(false, |start| start <= current_time))
+        .filter(|&i| cues[i].end.map_or(true, |end| current_time <= end + 1.0))
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct TranscriptViewerProps {
+    pub transcripts: Vec<Transcript>,
+    pub episode_id: i32,
+}
+
+#[function_component(TranscriptViewer)]
+pub fn transcript_viewer(props: &TranscriptViewerProps) -> Html {
+    let i18n = use_i18n();
+    let (state, _dispatch) = use_store::<AppState>();
+    let (audio_state, audio_dispatch) = use_store::<UIState>();
+    let api_key = state.auth_details.as_ref().map(|ud| ud.api_key.clone());
+    let server_name = state.auth_details.as_ref().map(|ud| ud.server_name.clone());
+
+    let is_open = use_state(|| false);
+    let cues: UseStateHandle<Option<Vec<TranscriptCue>>> = use_state(|| None);
+    let loading = use_state(|| false);
+    let error = use_state(|| None::<String>);
+    // URL of the transcript currently held in `cues`, so switching episodes refetches
+    let loaded_url = use_state(|| None::<String>);
+    let list_ref = use_node_ref();
+
+    let selected = pick_transcript(&props.transcripts);
+
+    // Fetch and parse the transcript the first time the viewer is opened
+    {
+        let cues = cues.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let loaded_url = loaded_url.clone();
+        let episode_id = props.episode_id;
+        use_effect_with((*is_open, selected.clone()), move |(is_open, selected)| {
+            if let (true, Some(transcript), Some(server_name), Some(api_key)) =
+                (*is_open, selected.clone(), server_name, api_key)
+            {
+                if loaded_url.as_deref() != Some(transcript.url.as_str()) {
+                    loaded_url.set(Some(transcript.url.clone()));
+                    cues.set(None);
+                    error.set(None);
+                    loading.set(true);
+                    wasm_bindgen_futures::spawn_local(async move {
+                        match call_fetch_transcript(
+                            &server_name,
+                            &api_key,
+                            &transcript.url,
+                            episode_id,
+                        )
+                        .await
+                        {
+                            Ok(body) => {
+                                let parsed = parse_transcript(&transcript.mime_type, &body);
+                                if parsed.is_empty() {
+                                    error.set(Some(I18n::current().t("transcript.empty")));
+                                }
+                                cues.set(Some(parsed));
+                            }
+                            Err(e) => {
+                                error.set(Some(
+                                    I18n::current()
+                                        .tf("transcript.load_error", &[("error", &e.to_string())]),
+                                ));
+                            }
+                        }
+                        loading.set(false);
+                    });
+                }
+            }
+            || ()
+        });
+    }
+
+    // Cues only follow along (and seek) when this episode is the one in the player
+    let is_current_episode = audio_state
+        .currently_playing
+        .as_ref()
+        .map_or(false, |playing| playing.episode_id == props.episode_id);
+    let active_index = if is_current_episode {
+        cues.as_ref()
+            .and_then(|cues| active_cue_index(cues, audio_state.current_time_seconds))
+    } else {
+        None
+    };
+
+    // Keep the active cue centered in the transcript pane
+    {
+        let list_ref = list_ref.clone();
+        use_effect_with(active_index, move |active_index| {
+            if let (Some(index), Some(list)) = (active_index, list_ref.cast::<HtmlElement>()) {
+                let selector = format!("[data-cue-index='{}']", index);
+                if let Some(cue) = list
+                    .query_selector(&selector)
+                    .ok()
+                    .flatten()
+                    .and_then(|el| el.dyn_into::<HtmlElement>().ok())
+                {
+                    let target =
+                        cue.offset_top() - list.client_height() / 2 + cue.client_height() / 2;
+                    list.set_scroll_top(target.max(0));
+                }
+            }
+            || ()
+        });
+    }
+
+    let toggle_open = {
+        let is_open = is_open.clone();
+        Callback::from(move |_: MouseEvent| is_open.set(!*is_open))
+    };
+
+    let on_cue_click = {
+        let audio_dispatch = audio_dispatch.clone();
+        Callback::from(move |start: f64| {
+            audio_dispatch.reduce_mut(move |state| {
+                if let Some(audio_element) = state.audio_element.as_ref() {
+                    audio_element.set_current_time(start);
+                }
+                state.update_current_time(start);
+            });
+        })
+    };
+
+    let Some(transcript) = selected else {
+        // Nothing we can render inline, so keep offering the raw files
+        return html! {
+            <>
+            { for props.transcripts.iter().map(|transcript| {
+                html! {
+                    <div class="header-info pb-2 pt-2">
+                        <a href={transcript.url.clone()} target="_blank" rel="noopener noreferrer" title={i18n.t("transcript.title")} class="font-bold item-container-button">
+                            { i18n.t("transcript.show") }
+                        </a>
+                    </div>
+                }
+            })}
+            </>
+        };
+    };
+
+    html! {
+        <div class="transcript-viewer header-info pb-2 pt-2">
+            <div class="flex items-center space-x-2">
+                <button onclick={toggle_open} title={i18n.t("transcript.title")} class="font-bold item-container-button">
+                    { if *is_open { i18n.t("transcript.hide") } else { i18n.t("transcript.show") } }
+                </button>
+                <a href={transcript.url.clone()} target="_blank" rel="noopener noreferrer" title={i18n.t("transcript.open_original")} class="transcript-source-link">
+                    <i class="material-icons">{ "open_in_new" }</i>
+                </a>
+            </div>
+            {
+                if *is_open {
+                    if *loading {
+                        html! { <p class="item_container-text">{ i18n.t("transcript.loading") }</p> }
+                    } else if let Some(error) = (*error).clone() {
+                        html! { <p class="item_container-text">{ error }</p> }
+                    } else if let Some(cues) = cues.as_ref() {
+                        html! {
+                            <div class="transcript-cues" ref={list_ref.clone()}>
+                                { for cues.iter().enumerate().map(|(index, cue)| {
+                                    let seekable = is_current_episode && cue.start.is_some();
+                                    let onclick = {
+                                        let on_cue_click = on_cue_click.clone();
+                                        let start = cue.start;
+                                        Callback::from(move |_: MouseEvent| {
+                                            if let (true, Some(start)) = (seekable, start) {
+                                                on_cue_click.emit(start);
+                                            }
+                                        })
+                                    };
+                                    let cue_class = classes!(
+                                        "transcript-cue",
+                                        (active_index == Some(index)).then_some("active"),
+                                        seekable.then_some("seekable")
+                                    );
+                                    html! {
+                                        <div class={cue_class} data-cue-index={index.to_string()} {onclick}>
+                                            {
+                                                if let Some(start) = cue.start {
+                                                    html! { <span class="transcript-cue-time">{ format_time_rm_hour(start) }</span> }
+                                                } else {
+                                                    html! {}
+                                                }
+                                            }
+                                            {
+                                                if let Some(speaker) = &cue.speaker {
+                                                    html! { <span class="transcript-cue-speaker">{ speaker }</span> }
+                                                } else {
+                                                    html! {}
+                                                }
+                                            }
+                                            <span class="transcript-cue-text item_container-text">{ &cue.text }</span>
+                                        </div>
+                                    }
+                                })}
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                } else {
+                    html! {}
+                }
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cue(start: f64, end: Option<f64>, speaker: Option<&str>, text: &str) -> TranscriptCue {
+        TranscriptCue {
+            start: Some(start),
+            end,
+            speaker: speaker.map(str::to_string),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_srt_and_vtt_timestamps() {
+        assert_eq!(parse_timestamp("01:02:03,500"), Some(3723.5));
+        assert_eq!(parse_timestamp("01:02:03.500"), Some(3723.5));
+        assert_eq!(parse_timestamp("02:03.250"), Some(123.25));
+        assert_eq!(parse_timestamp(" 00:00:07,000 "), Some(7.0));
+        assert_eq!(parse_timestamp("42.5"), Some(42.5));
+    }
+
+    #[test]
+    fn rejects_malformed_timestamps() {
+        assert_eq!(parse_timestamp(""), None);
+        assert_eq!(parse_timestamp("aa:bb"), None);
+        assert_eq!(parse_timestamp("1:2:3:4"), None);
+    }
+
+    #[test]
+    fn parses_srt_cues() {
+        let body = "1\r\n00:00:00,000 --> 00:00:02,500\r\nWelcome back to the show.\r\n\r\n\
+                    2\r\n00:00:02,500 --> 00:00:05,000\r\nToday we talk about\r\n<i>self-hosting</i>.\r\n";
+        assert_eq!(
+            parse_cue_blocks(body),
+            vec![
+                cue(0.0, Some(2.5), None, "Welcome back to the show."),
+                cue(2.5, Some(5.0), None, "Today we talk about self-hosting."),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_vtt_cues_without_hours() {
+        let body = "WEBVTT\n\nNOTE recorded live\n\n\
+                    00:01.000 --> 00:04.000 align:start\n<v Alice>Hi there.\n\n\
+                    intro-2\n00:04.000 --> 00:06.500\n<v.loud Bob Smith>Hello!</v>\n\n\
+                    00:07.000 --> 00:08.000\n<b></b>\n";
+        assert_eq!(
+            parse_cue_blocks(body),
+            vec![
+                cue(1.0, Some(4.0), Some("Alice"), "Hi there."),
+                cue(4.0, Some(6.5), Some("Bob Smith"), "Hello!"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_json_segments_and_folds_words() {
+        let body = r#"{
+            "version": "1.0.0",
+            "segments": [
+                { "speaker": "Alice", "startTime": 0.0, "endTime": 0.4, "body": "Good" },
+                { "speaker": "Alice", "startTime": 0.4, "endTime": 0.9, "body": "morning." },
+                { "speaker": "Bob", "startTime": 1.0, "endTime": 2.0, "body": "Hi Alice, how are you?" },
+                { "speaker": "Bob", "startTime": 2.0, "body": " " },
+                { "speaker": "Alice", "startTime": 2.5, "body": "Great" }
+            ]
+        }"#;
+        assert_eq!(
+            parse_json_transcript(body),
+            vec![
+                cue(0.0, Some(0.9), Some("Alice"), "Good morning."),
+                cue(1.0, Some(2.0), Some("Bob"), "Hi Alice, how are you?"),
+                cue(2.5, None, Some("Alice"), "Great"),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_words_without_end_time_keep_previous_end() {
+        let body = r#"{"segments": [
+            { "startTime": 3.0, "endTime": 3.5, "body": "one" },
+            { "startTime": 3.5, "body": "two" }
+        ]}"#;
+        assert_eq!(
+            parse_json_transcript(body),
+            vec![cue(3.0, Some(3.5), None, "one two")]
+        );
+    }
+
+    #[test]
+    fn finds_active_cue() {
+        let cues = vec![
+            cue(0.0, Some(2.0), None, "first"),
+            cue(5.0, None, None, "second"),
+            TranscriptCue {
+                start: None,
+                end: None,
+                speaker: None,
+                text: "untimed".to_string(),
+            },
+        ];
+        assert_eq!(active_cue_index(&cues, 1.0), Some(0));
+        // A short grace period after the end keeps the cue highlighted across gaps
+        assert_eq!(active_cue_index(&cues, 2.5), Some(0));
+        assert_eq!(active_cue_index(&cues, 4.0), None);
+        assert_eq!(active_cue_index(&cues, 60.0), Some(1));
+        assert_eq!(active_cue_index(&[], 1.0), None);
+    }
+
+    // DomParser only exists in a browser, so this one runs under `wasm-pack test --headless`
+    #[cfg(target_arch = "wasm32")]
+    mod browser {
+        use super::*;
+        use wasm_bindgen_test::*;
+
+        wasm_bindgen_test_configure!(run_in_browser);
+
+        #[wasm_bindgen_test]
+        fn parses_html_transcript() {
+            let body = "<cite>Alice:</cite><time>00:00:01,000</time><p>Hello there.</p>\
+                        <cite>Bob:</cite><time>01:05.5</time><p>Hi!</p><p>Still Bob.</p>";
+            assert_eq!(
+                parse_html_transcript(body),
+                vec![
+                    cue(1.0, None, Some("Alice"), "Hello there."),
+                    cue(65.5, None, Some("Bob"), "Hi!"),
+                    TranscriptCue {
+                        start: None,
+                        end: None,
+                        speaker: Some("Bob".to_string()),
+                        text: "Still Bob.".to_string(),
+                    },
+                ]
+            );
+        }
+    }
+}
@@ -3,6 +3,7 @@ use super::gen_components::Search_nav;
 use crate::components::audio::AudioPlayer;
 use crate::components::context::{AppState, UIState, UserStatsStore};
 use crate::components::gen_funcs::{format_date, format_time_mins};
+use crate::components::i18n::use_i18n;
 use crate::requests::pod_req::call_get_pinepods_version;
 use crate::requests::stat_reqs;
 use crate::requests::login_requests::use_check_authentication;
@@ -13,6 +14,7 @@ use yewdux::prelude::*;
 
 #[function_component(UserStats)]
 pub fn user_stats() -> Html {
+    let i18n = use_i18n();
     let (state, dispatch) = use_store::<AppState>();
     let (stat_state, stat_dispatch) = use_store::<UserStatsStore>();
     let effect_dispatch = dispatch.clone();
@@ -124,7 +126,7 @@ pub fn user_stats() -> Html {
         <>
         <div class="main-container">
             <Search_nav />
-            <h1 class="text-2xl item_container-text font-bold text-center mb-6">{"User Statistics"}</h1>
+            <h1 class="text-2xl item_container-text font-bold text-center mb-6">{ i18n.t("stats.title") }</h1>
             <div class="item-container mx-auto p-6 shadow-md rounded">
                 <div class="grid grid-cols-1 md:grid-cols-3 gap-4">
 
@@ -132,45 +134,51 @@ pub fn user_stats() -> Html {
                             if let Some(stats) = user_stats {
                                 let formatted_date = format_date(&stats.UserCreated);
                                 let time_formatted = format_time_mins(stats.TimeListened);
+                                let time_saved_formatted = format_time_mins(stats.TimeSaved.unwrap_or(0) / 60);
                                 html! {
                                     <>
                                         <div class="stats-card">
-                                            <p class="stats-label">{"User Created"}</p>
+                                            <p class="stats-label">{ i18n.t("stats.user_created") }</p>
                                             <p class="stats-value">{&formatted_date}</p>
                                         </div>
 
                                         <div class="stats-card">
-                                            <p class="stats-label">{"Podcasts Played"}</p>
-                                            <p class="stats-value">{ &stats.PodcastsPlayed }</p>
+                                            <p class="stats-label">{ i18n.t("stats.podcasts_played") }</p>
+                                            <p class="stats-value">{ i18n.number(stats.PodcastsPlayed as f64) }</p>
                                         </div>
 
                                         <div class="stats-card">
-                                            <p class="stats-label">{"Time Listened"}</p>
+                                            <p class="stats-label">{ i18n.t("stats.time_listened") }</p>
                                             <p class="stats-value">{ &time_formatted }</p>
                                         </div>
 
                                         <div class="stats-card">
-                                            <p class="stats-label">{"Podcasts Added"}</p>
-                                            <p class="stats-value">{ &stats.PodcastsAdded }</p>
+                                            <p class="stats-label">{ i18n.t("stats.time_saved") }</p>
+                                            <p class="stats-value">{ &time_saved_formatted }</p>
                                         </div>
 
                                         <div class="stats-card">
-                                            <p class="stats-label">{"Episodes Saved"}</p>
-                                            <p class="stats-value">{ &stats.EpisodesSaved }</p>
+                                            <p class="stats-label">{ i18n.t("stats.podcasts_added") }</p>
+                                            <p class="stats-value">{ i18n.number(stats.PodcastsAdded as f64) }</p>
                                         </div>
 
                                         <div class="stats-card">
-                                            <p class="stats-label">{"Episodes Downloaded"}</p>
-                                            <p class="stats-value">{ &stats.EpisodesDownloaded }</p>
+                                            <p class="stats-label">{ i18n.t("stats.episodes_saved") }</p>
+                                            <p class="stats-value">{ i18n.number(stats.EpisodesSaved as f64) }</p>
+                                        </div>
+
+                                        <div class="stats-card">
+                                            <p class="stats-label">{ i18n.t("stats.episodes_downloaded") }</p>
+                                            <p class="stats-value">{ i18n.number(stats.EpisodesDownloaded as f64) }</p>
                                         </div>
                                         <div class="large-card col-span-1 md:col-span-3">
-                                            <img src="static/assets/favicon.png" alt="Pinepods Logo" class="large-card-image"/>
-                                            <p class="large-card-paragraph item_container-text">{ format!("Current Version: {}", display_version) }</p>
-                                            <p class="large-card-paragraph item_container-text">{"Thanks for using Pinepods! This app was born from a love for podcasts, of homelabs, and a desire to have a secure and central location to manage personal data. Feel free to reach out for questions and open an issue if you have ideas for new features. Pull Requests on this software are welcome and encouraged. If you feel that you've gotten use out of this software and are thankful for it's existence donations to my Buymeacoffee are welcome but never required. Lastly, this app will ALWAYS remain open source."}</p>
+                                            <img src="static/assets/favicon.png" alt={i18n.t("stats.logo_alt")} class="large-card-image"/>
+                                            <p class="large-card-paragraph item_container-text">{ i18n.tf("stats.version", &[("version", display_version)]) }</p>
+                                            <p class="large-card-paragraph item_container-text">{ i18n.t("stats.thanks") }</p>
                                             <div class="large-card-content flex flex-col space-y-2">
-                                                <a href="https://pinepods.online" target="_blank" class="large-card-button focus:ring-4 font-medium rounded-lg text-sm px-5 py-2.5 focus:outline-none">{"Pinepods Documentation"}</a>
-                                                <a href="https://github.com/madeofpendletonwool/pinepods" target="_blank" class="large-card-button focus:ring-4 font-medium rounded-lg text-sm px-5 py-2.5 focus:outline-none">{"Pinepods Github Repo"}</a>
-                                                <a href="https://www.buymeacoffee.com/collinscoffee" target="_blank" class="large-card-button focus:ring-4 font-medium rounded-lg text-sm px-5 py-2.5 focus:outline-none">{"Buy me a Coffee"}</a>
+                                                <a href="https://pinepods.online" target="_blank" class="large-card-button focus:ring-4 font-medium rounded-lg text-sm px-5 py-2.5 focus:outline-none">{ i18n.t("stats.documentation") }</a>
+                                                <a href="https://github.com/madeofpendletonwool/pinepods" target="_blank" class="large-card-button focus:ring-4 font-medium rounded-lg text-sm px-5 py-2.5 focus:outline-none">{ i18n.t("stats.repo") }</a>
+                                                <a href="https://www.buymeacoffee.com/collinscoffee" target="_blank" class="large-card-button focus:ring-4 font-medium rounded-lg text-sm px-5 py-2.5 focus:outline-none">{ i18n.t("stats.coffee") }</a>
 
                                                 // Additional content...
                                             </div>
@@ -179,7 +187,7 @@ pub fn user_stats() -> Html {
                                     </>
                                 }
                             } else {
-                                html! { <p class="item_container-text">{"Loading user stats..."}</p> } // or handle the `None` case appropriately
+                                html! { <p class="item_container-text">{ i18n.t("stats.loading") }</p> } // or handle the `None` case appropriately
                             }
                         }
                     // </div>
@@ -8,10 +8,17 @@ use components::routes::Route;
 // use components::login::LogOut;
 use components::downloads::Downloads;
 use components::episode::Episode;
+use components::episode_stream::EpisodeStream;
 use components::episodes_layout::EpisodeLayout;
 use components::history::PodHistory;
+use components::i18n::{apply_document_language, use_i18n, Language};
+use components::notifications::ToastStack;
+#[cfg(feature = "server_build")]
+use components::offline::{register_service_worker, OfflineWatcher};
+use components::oidc::OidcCallback;
 use components::home::Home;
 use components::person::Person;
+use components::playlists::{PlaylistPage, Playlists};
 use components::podcast_layout::PodLayout;
 use components::podcasts::Podcasts;
 use components::queue::Queue;
@@ -38,12 +45,13 @@ use yew_router::prelude::*;
 
 #[function_component(NotFound)]
 pub fn not_found() -> Html {
+    let i18n = use_i18n();
     html! {
         <>
             <div class="empty-episodes-container">
-                <img src="static/assets/favicon.png" alt="Logo" class="logo"/>
-                <h1>{ "Page not found" }</h1>
-                <p>{"Sorry for the inconvenience. You could eat a taco to cheer you up :)"}</p>
+                <img src="static/assets/favicon.png" alt={i18n.t("common.logo_alt")} class="logo"/>
+                <h1>{ i18n.t("not_found.title") }</h1>
+                <p>{ i18n.t("not_found.body") }</p>
             </div>
         </>
     }
@@ -56,6 +64,8 @@ fn switch(route: Route) -> Html {
         Route::ChangeServer => html! { <ChangeServer /> },
         Route::Queue => html! { <Queue /> },
         Route::Saved => html! { <Saved /> },
+        Route::Playlists => html! { <Playlists /> },
+        Route::Playlist { playlist_id } => html! { <PlaylistPage {playlist_id} /> },
         Route::Settings => html! { <Settings /> },
         Route::PodHistory => html! { <PodHistory /> },
         Route::Downloads => html! { <Downloads /> },
@@ -67,12 +77,13 @@ fn switch(route: Route) -> Html {
         Route::SharedEpisode { url_key } => html! { <SharedEpisode url_key={url_key.clone()} /> },
         Route::EpisodeLayout => html! { <EpisodeLayout /> },
         Route::Podcasts => html! { <Podcasts /> },
+        Route::OidcCallback => html! { <OidcCallback /> },
         Route::Episode => html! { <Episode /> },
         Route::Person { name } => html! { <Person name={name.clone()} /> },
         #[cfg(not(feature = "server_build"))]
         Route::LocalDownloads => html! { <LocalDownloads /> },
         #[cfg(feature = "server_build")]
-        Route::LocalDownloads => html! { <div>{"Local downloads not available on the web"}</div> },
+        Route::LocalDownloads => html! { <div>{ components::i18n::I18n::current().t("downloads.local_unavailable") }</div> },
     }
 }
 
@@ -84,10 +95,27 @@ fn main_component() -> Html {
     html! {
         <BrowserRouter>
             <Switch<Route> render={switch} />
+            <EpisodeStream />
+            <ToastStack />
+            { offline_watcher() }
         </BrowserRouter>
     }
 }
 
+#[cfg(feature = "server_build")]
+fn offline_watcher() -> Html {
+    html! { <OfflineWatcher /> }
+}
+
+// The desktop app has its own offline mode and local downloads
+#[cfg(not(feature = "server_build"))]
+fn offline_watcher() -> Html {
+    html! {}
+}
+
 fn main() {
+    apply_document_language(Language::preferred());
+    #[cfg(feature = "server_build")]
+    register_service_worker();
     yew::Renderer::<Main>::new().render();
 }
@@ -0,0 +1,318 @@
+use crate::requests::login_requests::handle_unauthorized;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use gloo_net::http::{Request, RequestBuilder, Response};
+use gloo_timers::callback::Timeout;
+use gloo_timers::future::TimeoutFuture;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+use web_sys::AbortController;
+
+/// How long a request may take before it's aborted, in milliseconds.
+const DEFAULT_TIMEOUT_MS: u32 = 60_000;
+/// For endpoints that do real work on the server, like parsing a whole feed or building a backup.
+pub const LONG_TIMEOUT_MS: u32 = 300_000;
+/// GETs are safe to repeat, so transient failures get this many attempts in total.
+const GET_ATTEMPTS: u32 = 3;
+const RETRY_BACKOFF_MS: u32 = 500;
+
+/// Everything that can go wrong talking to the Pinepods server.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    /// The API key is missing, invalid or has expired (HTTP 401/403).
+    Unauthorized(String),
+    /// HTTP 404, with the server's explanation.
+    NotFound(String),
+    /// Any other unsuccessful status, with the server's `detail` or raw body.
+    Server { status: u16, body: String },
+    /// The request never got a response.
+    Network(String),
+    /// The server took longer than the client's timeout to answer.
+    Timeout,
+    /// The response didn't have the shape we expected.
+    Decode(String),
+}
+
+impl ApiError {
+    pub fn is_unauthorized(&self) -> bool {
+        matches!(self, ApiError::Unauthorized(_))
+    }
+
+    /// Worth trying the same request again: the failure wasn't the request's fault.
+    fn is_transient(&self) -> bool {
+        match self {
+            ApiError::Network(_) | ApiError::Timeout => true,
+            ApiError::Server { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Unauthorized(detail) => write!(f, "Not authorized: {}", detail),
+            ApiError::NotFound(detail) => write!(f, "Not found: {}", detail),
+            ApiError::Server { status, body } => write!(f, "Server error ({}): {}", status, body),
+            ApiError::Network(message) => write!(f, "Network error: {}", message),
+            ApiError::Timeout => write!(f, "The server took too long to respond"),
+            ApiError::Decode(message) => write!(f, "Unexpected response from server: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// The `ApiError` behind an error returned by one of the `call_*` functions, if there is one.
+pub fn api_error(error: &anyhow::Error) -> Option<&ApiError> {
+    error.downcast_ref::<ApiError>()
+}
+
+#[derive(Deserialize)]
+struct ErrorDetail {
+    detail: serde_json::Value,
+}
+
+/// Message-only responses like `{"detail": "Podcast removed"}`.
+#[derive(Deserialize, Debug)]
+pub struct DetailResponse {
+    pub detail: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+/// Talks to one Pinepods server, optionally as a user.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApiClient {
+    server_name: String,
+    api_key: Option<String>,
+    /// `Authorization` header value, only used to trade a username and password for a key.
+    authorization: Option<String>,
+    timeout_ms: u32,
+}
+
+impl ApiClient {
+    pub fn new(server_name: &str, api_key: Option<&str>) -> Self {
+        Self {
+            server_name: server_name.trim_end_matches('/').to_string(),
+            api_key: api_key.map(str::to_string),
+            authorization: None,
+            timeout_ms: DEFAULT_TIMEOUT_MS,
+        }
+    }
+
+    /// A client that authenticates with `api_key`, failing early when there isn't one.
+    pub fn authed(server_name: &str, api_key: &Option<String>) -> Result<Self, ApiError> {
+        let api_key = api_key
+            .as_deref()
+            .ok_or_else(|| ApiError::Unauthorized("API key is missing".to_string()))?;
+        Ok(Self::new(server_name, Some(api_key)))
+    }
+
+    pub fn with_basic_auth(mut self, username: &str, password: &str) -> Self {
+        let credentials = STANDARD.encode(format!("{}:{}", username, password).as_bytes());
+        self.authorization = Some(format!("Basic {}", credentials));
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout_ms: u32) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.server_name, path)
+    }
+
+    pub async fn get<R: DeserializeOwned>(&self, path: &str) -> Result<R, ApiError> {
+        let text = self.get_text(path).await?;
+        decode(&text)
+    }
+
+    pub async fn get_text(&self, path: &str) -> Result<String, ApiError> {
+        let mut attempt = 1;
+        loop {
+            let result = match self.execute(Method::Get, path, None).await {
+                Ok(response) => read_text(&response).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Err(e) if e.is_transient() && attempt < GET_ATTEMPTS => {
+                    TimeoutFuture::new(RETRY_BACKOFF_MS * attempt).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    pub async fn post<B, R>(&self, path: &str, body: &B) -> Result<R, ApiError>
+    where
+        B: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        self.send(Method::Post, path, Some(body)).await
+    }
+
+    /// POST whose response body is handed back as-is rather than decoded.
+    pub async fn post_text<B: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<String, ApiError> {
+        let body = serde_json::to_string(body).map_err(|e| ApiError::Decode(e.to_string()))?;
+        let response = self.execute(Method::Post, path, Some(body)).await?;
+        read_text(&response).await
+    }
+
+    /// POST without a request body.
+    pub async fn post_empty<R: DeserializeOwned>(&self, path: &str) -> Result<R, ApiError> {
+        self.send::<(), R>(Method::Post, path, None).await
+    }
+
+    pub async fn put<B, R>(&self, path: &str, body: &B) -> Result<R, ApiError>
+    where
+        B: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        self.send(Method::Put, path, Some(body)).await
+    }
+
+    /// PUT without a request body.
+    pub async fn put_empty<R: DeserializeOwned>(&self, path: &str) -> Result<R, ApiError> {
+        self.send::<(), R>(Method::Put, path, None).await
+    }
+
+    pub async fn delete<B, R>(&self, path: &str, body: &B) -> Result<R, ApiError>
+    where
+        B: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        self.send(Method::Delete, path, Some(body)).await
+    }
+
+    pub async fn send<B, R>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<R, ApiError>
+    where
+        B: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        let body = body
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| ApiError::Decode(e.to_string()))?;
+        let response = self.execute(method, path, body).await?;
+        decode(&read_text(&response).await?)
+    }
+
+    /// Send one request and check its status. Successful responses are handed back unread for
+    /// the few endpoints that don't answer with JSON.
+    pub async fn execute(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<String>,
+    ) -> Result<Response, ApiError> {
+        let url = self.url(path);
+        let mut builder: RequestBuilder = match method {
+            Method::Get => Request::get(&url),
+            Method::Post => Request::post(&url),
+            Method::Put => Request::put(&url),
+            Method::Delete => Request::delete(&url),
+        };
+        if let Some(api_key) = &self.api_key {
+            builder = builder.header("Api-Key", api_key);
+        }
+        if let Some(authorization) = &self.authorization {
+            builder = builder.header("Authorization", authorization);
+        }
+
+        let controller =
+            AbortController::new().map_err(|e| ApiError::Network(format!("{:?}", e)))?;
+        let timed_out = Rc::new(Cell::new(false));
+        builder = builder.abort_signal(Some(&controller.signal()));
+        let request = match body {
+            Some(body) => builder
+                .header("Content-Type", "application/json")
+                .body(body),
+            None => builder.build(),
+        }
+        .map_err(|e| ApiError::Network(e.to_string()))?;
+
+        // Dropping the timer at the end of this function cancels it
+        let _timer = {
+            let timed_out = timed_out.clone();
+            Timeout::new(self.timeout_ms, move || {
+                timed_out.set(true);
+                controller.abort();
+            })
+        };
+        let response = request.send().await.map_err(|e| {
+            if timed_out.get() {
+                ApiError::Timeout
+            } else {
+                ApiError::Network(e.to_string())
+            }
+        })?;
+
+        if response.ok() {
+            return Ok(response);
+        }
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        // FastAPI puts its explanation in `detail`; fall back to the raw body, then the status text
+        let detail = serde_json::from_str::<ErrorDetail>(&body)
+            .ok()
+            .map(|error| match error.detail {
+                serde_json::Value::String(detail) => detail,
+                other => other.to_string(),
+            })
+            .filter(|detail| !detail.is_empty())
+            .unwrap_or_else(|| {
+                if body.is_empty() {
+                    response.status_text()
+                } else {
+                    body
+                }
+            });
+        Err(match status {
+            401 | 403 => {
+                // Only a rejected API key says anything about the session; a rejected
+                // username and password is just a failed login
+                if let (Some(api_key), None) = (&self.api_key, &self.authorization) {
+                    handle_unauthorized(self.server_name.clone(), api_key.clone());
+                }
+                ApiError::Unauthorized(detail)
+            }
+            404 => ApiError::NotFound(detail),
+            _ => ApiError::Server {
+                status,
+                body: detail,
+            },
+        })
+    }
+}
+
+async fn read_text(response: &Response) -> Result<String, ApiError> {
+    response
+        .text()
+        .await
+        .map_err(|e| ApiError::Network(e.to_string()))
+}
+
+fn decode<R: DeserializeOwned>(text: &str) -> Result<R, ApiError> {
+    serde_json::from_str(text).map_err(|e| ApiError::Decode(e.to_string()))
+}
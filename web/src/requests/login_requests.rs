@@ -1,13 +1,16 @@
-use base64::engine::general_purpose::STANDARD;
-use base64::Engine;
-use gloo_net::http::Request;
 use serde::{Deserialize, Serialize};
 
 use yew_router::history::{BrowserHistory, History};
 use yewdux::Dispatch;
 // Add imports for your context modules
-use crate::components::context::AppState;
-use anyhow::{Context, Error};
+use crate::components::context::{AppState, ServerProfile};
+use crate::components::notifications::notify_error;
+use crate::components::routes::Route;
+use crate::requests::api_client::{api_error, ApiClient, ApiError};
+use crate::requests::setting_reqs::call_get_theme;
+use anyhow::Error;
+use std::cell::Cell;
+use yew_router::Routable;
 
 #[derive(Serialize)]
 pub struct LoginRequest {
@@ -39,18 +42,14 @@ pub struct PinepodsCheckResponse {
 pub async fn verify_pinepods_instance(
     server_name: &str,
 ) -> Result<PinepodsCheckResponse, anyhow::Error> {
-    let url = format!("{}/api/pinepods_check", server_name);
-    let response = Request::get(&url).send().await?;
-
-    if response.ok() {
-        let check_data: PinepodsCheckResponse = response.json().await?;
-        if check_data.pinepods_instance.unwrap_or(false) {
-            Ok(check_data)
-        } else {
-            Err(anyhow::Error::msg("Pinepods instance not found"))
-        }
+    let check_data: PinepodsCheckResponse = ApiClient::new(server_name, None)
+        .get("/api/pinepods_check")
+        .await
+        .map_err(|_| anyhow::Error::msg("Failed to verify Pinepods instance"))?;
+    if check_data.pinepods_instance.unwrap_or(false) {
+        Ok(check_data)
     } else {
-        Err(anyhow::Error::msg("Failed to verify Pinepods instance"))
+        Err(anyhow::Error::msg("Pinepods instance not found"))
     }
 }
 
@@ -64,16 +63,10 @@ pub async fn call_verify_key(
     server_name: &str,
     api_key: &str,
 ) -> Result<crate::requests::login_requests::KeyVerification, anyhow::Error> {
-    let url = format!("{}/api/data/verify_key", server_name);
-
-    let response = Request::get(&url).header("Api-Key", api_key).send().await?;
-
-    if response.ok() {
-        let key_verify: crate::requests::login_requests::KeyVerification = response.json().await?;
-        Ok(key_verify)
-    } else {
-        Err(anyhow::Error::msg("Failed to get user data"))
-    }
+    let key_verify = ApiClient::new(server_name, Some(api_key))
+        .get("/api/data/verify_key")
+        .await?;
+    Ok(key_verify)
 }
 #[derive(Deserialize, Debug)]
 pub struct GetUserResponse {
@@ -110,16 +103,10 @@ pub async fn call_get_user_id(
     server_name: &str,
     api_key: &str,
 ) -> Result<GetUserIdResponse, anyhow::Error> {
-    let url = format!("{}/api/data/get_user", server_name);
-
-    let response = Request::get(&url).header("Api-Key", api_key).send().await?;
-
-    if response.ok() {
-        let user_id_data: GetUserIdResponse = response.json().await?;
-        Ok(user_id_data)
-    } else {
-        Err(anyhow::Error::msg("Failed to get user ID"))
-    }
+    let user_id_data = ApiClient::new(server_name, Some(api_key))
+        .get("/api/data/get_user")
+        .await?;
+    Ok(user_id_data)
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
@@ -139,19 +126,10 @@ pub async fn call_get_user_details(
     api_key: &str,
     user_id: &i32,
 ) -> Result<crate::requests::login_requests::GetUserDetails, anyhow::Error> {
-    let url = format!("{}/api/data/user_details_id/{}", server_name, user_id);
-
-    let response = Request::get(&url).header("Api-Key", api_key).send().await?;
-
-    if response.ok() {
-        let body_str = response.text().await?;
-
-        let user_data: crate::requests::login_requests::GetUserDetails =
-            serde_json::from_str(&body_str)?;
-        Ok(user_data)
-    } else {
-        Err(anyhow::Error::msg("Failed to get user information"))
-    }
+    let user_data = ApiClient::new(server_name, Some(api_key))
+        .get(&format!("/api/data/user_details_id/{}", user_id))
+        .await?;
+    Ok(user_data)
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
@@ -169,16 +147,207 @@ pub async fn call_get_api_config(
     server_name: &str,
     api_key: &str,
 ) -> Result<crate::requests::login_requests::GetApiDetails, anyhow::Error> {
-    let url = format!("{}/api/data/config", server_name);
+    let server_data = ApiClient::new(server_name, Some(api_key))
+        .get("/api/data/config")
+        .await?;
+    Ok(server_data)
+}
+
+/// How the server's OpenID Connect provider, if it has one, wants to be asked.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct OidcConfig {
+    pub enabled: bool,
+    #[serde(default)]
+    pub provider_name: String,
+    #[serde(default)]
+    pub authorization_endpoint: String,
+    #[serde(default)]
+    pub client_id: String,
+    #[serde(default)]
+    pub scopes: String,
+}
 
-    let response = Request::get(&url).header("Api-Key", api_key).send().await?;
+pub async fn call_get_oidc_config(server_name: &str) -> Result<OidcConfig, Error> {
+    Ok(ApiClient::new(server_name, None)
+        .get("/api/auth/oidc/config")
+        .await?)
+}
 
-    if response.ok() {
-        let server_data: GetApiDetails = response.json().await?;
-        Ok(server_data)
-    } else {
-        Err(anyhow::Error::msg("Failed to get user information"))
+#[derive(Serialize, Debug)]
+pub struct OidcCallbackRequest {
+    pub code: String,
+    pub code_verifier: String,
+    pub redirect_uri: String,
+}
+
+/// Either the account's key, or for accounts with MFA a token to finish signing in with.
+#[derive(Deserialize, Debug)]
+pub struct OidcCallbackResponse {
+    #[serde(default)]
+    pub retrieved_key: Option<String>,
+    pub username: String,
+    #[serde(default)]
+    pub mfa_token: Option<String>,
+    #[serde(default)]
+    pub mfa_enabled: bool,
+    #[serde(default)]
+    pub passkey_enabled: bool,
+}
+
+/// Trade the provider's authorization code for the matching user's API key.
+pub async fn call_oidc_callback(
+    server_name: &str,
+    request: &OidcCallbackRequest,
+) -> Result<OidcCallbackResponse, Error> {
+    Ok(ApiClient::new(server_name, None)
+        .post("/api/auth/oidc/callback", request)
+        .await?)
+}
+
+#[derive(Serialize)]
+struct OidcVerifyMfaBody {
+    mfa_token: String,
+    mfa_code: String,
+}
+
+/// Finish a single sign-on that's waiting on the account's MFA code or a recovery code.
+pub async fn call_oidc_verify_mfa(
+    server_name: &str,
+    mfa_token: String,
+    mfa_code: String,
+) -> Result<WebAuthnLoginResponse, Error> {
+    let body = OidcVerifyMfaBody {
+        mfa_token,
+        mfa_code,
+    };
+    Ok(ApiClient::new(server_name, None)
+        .post("/api/auth/oidc/verify_mfa", &body)
+        .await?)
+}
+
+#[derive(Serialize)]
+struct OidcMfaTokenBody {
+    mfa_token: String,
+}
+
+pub async fn call_oidc_passkey_options(
+    server_name: &str,
+    mfa_token: String,
+) -> Result<WebAuthnOptions, Error> {
+    Ok(ApiClient::new(server_name, None)
+        .post(
+            "/api/auth/oidc/passkey_options",
+            &OidcMfaTokenBody { mfa_token },
+        )
+        .await?)
+}
+
+#[derive(Serialize)]
+struct OidcVerifyPasskeyBody {
+    mfa_token: String,
+    challenge_id: String,
+    credential: serde_json::Value,
+}
+
+/// Finish a single sign-on that's waiting on one of the account's passkeys.
+pub async fn call_oidc_verify_passkey(
+    server_name: &str,
+    mfa_token: String,
+    challenge_id: String,
+    credential: serde_json::Value,
+) -> Result<WebAuthnLoginResponse, Error> {
+    let body = OidcVerifyPasskeyBody {
+        mfa_token,
+        challenge_id,
+        credential,
+    };
+    Ok(ApiClient::new(server_name, None)
+        .post("/api/auth/oidc/verify_passkey", &body)
+        .await?)
+}
+
+/// Sign in with a key we already hold, as when switching to a saved profile.
+pub async fn login_with_api_key(
+    server_name: String,
+    username: String,
+    api_key: String,
+) -> Result<(GetUserDetails, LoginServerRequest, GetApiDetails), anyhow::Error> {
+    let verify_response = call_verify_key(&server_name, &api_key).await?;
+    if verify_response.status != "success" {
+        return Err(anyhow::Error::msg("API key verification failed"));
+    }
+
+    let user_id_response = call_get_user_id(&server_name, &api_key).await?;
+    if user_id_response.status != "success" {
+        return Err(anyhow::Error::msg("Failed to get user ID"));
+    }
+
+    let login_request = LoginServerRequest {
+        server_name: server_name.clone(),
+        username,
+        password: String::new(),
+        api_key: Some(api_key.clone()),
+    };
+
+    let user_details = call_get_user_details(
+        &server_name,
+        &api_key,
+        &user_id_response.retrieved_id.unwrap(),
+    )
+    .await?;
+    if user_details.Username.is_none() {
+        return Err(anyhow::Error::msg("Failed to get user details"));
+    }
+
+    let server_details = call_get_api_config(&server_name, &api_key).await?;
+    if server_details.api_url.is_none() {
+        return Err(anyhow::Error::msg("Failed to get server details"));
+    }
+
+    Ok((user_details, login_request, server_details))
+}
+
+/// Make an account we've just been handed a key for the signed-in session, the way a password
+/// login would: load the account, apply its theme and time settings and remember it.
+pub(crate) async fn start_session_with_key(
+    server_name: String,
+    username: String,
+    api_key: String,
+) -> Result<(), Error> {
+    let (user_details, auth_details, server_details) =
+        login_with_api_key(server_name.clone(), username, api_key.clone()).await?;
+    let user_id = user_details.UserID;
+
+    if let Ok(theme) = call_get_theme(server_name.clone(), api_key.clone(), &user_id).await {
+        crate::components::setting_components::theme_options::changeTheme(&theme);
+        if let Some(Ok(Some(local_storage))) = web_sys::window().map(|w| w.local_storage()) {
+            let _ = local_storage.set_item("selected_theme", &theme);
+        }
+    }
+    let time_info = call_get_time_info(server_name, api_key, &user_id).await.ok();
+
+    let gravatar_url = user_details.Email.as_ref().map(|email| {
+        format!(
+            "https://gravatar.com/avatar/{:x}?s=80",
+            md5::compute(email.to_lowercase())
+        )
+    });
+    Dispatch::<AppState>::global().reduce_mut(move |state| {
+        state.user_details = Some(user_details);
+        state.auth_details = Some(auth_details);
+        state.server_details = Some(server_details);
+        state.gravatar_url = gravatar_url;
+        if let Some(time_info) = time_info {
+            state.user_tz = Some(time_info.timezone);
+            state.hour_preference = Some(time_info.hour_pref);
+            state.date_format = Some(time_info.date_format);
+        }
+        state.store_app_state();
+    });
+    if let Some(Ok(Some(session_storage))) = web_sys::window().map(|w| w.session_storage()) {
+        let _ = session_storage.set_item("isAuthenticated", "true");
     }
+    Ok(())
 }
 
 pub async fn login_new_server(
@@ -186,10 +355,6 @@ pub async fn login_new_server(
     username: String,
     password: String,
 ) -> Result<(GetUserDetails, LoginServerRequest, GetApiDetails), anyhow::Error> {
-    let credentials = STANDARD.encode(format!("{}:{}", username, password).as_bytes());
-    let auth_header = format!("Basic {}", credentials);
-    let url = format!("{}/api/data/get_key", server_name);
-
     // Step 1: Verify Server
     match verify_pinepods_instance(&server_name).await {
         Ok(check_data) => {
@@ -199,55 +364,20 @@ pub async fn login_new_server(
                 ));
             }
             // Step 2: Get API key
-            let response = Request::get(&url)
-                .header("Authorization", &auth_header)
-                .send()
-                .await?;
-
-            if !response.ok() {
-                return Err(anyhow::Error::msg(
-                    "Failed to authenticate user. Incorrect credentials?",
-                ));
-            }
-
-            let api_key = response.json::<LoginResponse>().await?.retrieved_key;
-
-            // Step 2: Verify the API key
-            let verify_response = call_verify_key(&server_name, &api_key).await?;
-            if verify_response.status != "success" {
-                return Err(anyhow::Error::msg("API key verification failed"));
-            }
-
-            // Step 3: Get user ID
-            let user_id_response = call_get_user_id(&server_name, &api_key).await?;
-            if user_id_response.status != "success" {
-                return Err(anyhow::Error::msg("Failed to get user ID"));
-            }
-
-            let login_request = LoginServerRequest {
-                server_name: server_name.clone(),
-                username: username.clone(),
-                password: password.clone(),
-                api_key: Some(api_key.clone()), // or None, depending on the context
-            };
-
-            // Step 4: Get user details
-            let user_details = call_get_user_details(
-                &server_name,
-                &api_key,
-                &user_id_response.retrieved_id.unwrap(),
-            )
-            .await?;
-            if user_details.Username.is_none() {
-                return Err(anyhow::Error::msg("Failed to get user details"));
-            }
-
-            // Step 5: Get server details
-            let server_details = call_get_api_config(&server_name, &api_key).await?;
-            if server_details.api_url.is_none() {
-                return Err(anyhow::Error::msg("Failed to get server details"));
-            }
-
+            let login_response: LoginResponse = ApiClient::new(&server_name, None)
+                .with_basic_auth(&username, &password)
+                .get("/api/data/get_key")
+                .await
+                .map_err(|e| match e {
+                    ApiError::Unauthorized(_) => anyhow::Error::msg(
+                        "Failed to authenticate user. Incorrect credentials?",
+                    ),
+                    e => anyhow::Error::new(e),
+                })?;
+            let api_key = login_response.retrieved_key;
+            let (user_details, mut login_request, server_details) =
+                login_with_api_key(server_name, username, api_key).await?;
+            login_request.password = password;
             Ok((user_details, login_request, server_details))
         }
         Err(e) => {
@@ -257,6 +387,85 @@ pub async fn login_new_server(
     }
 }
 
+/// Where to send the user once they've logged back in after their session expired.
+const RETURN_ROUTE_KEY: &str = "session_return_route";
+
+thread_local! {
+    // Set while a key is being re-checked, so the burst of failures from one page doesn't
+    // start a check per request (or loop through the check's own request)
+    static CHECKING_SESSION: Cell<bool> = Cell::new(false);
+}
+
+/// Called by the API client whenever the server turns a keyed request down with 401/403. The
+/// server also answers 403 for things like another user's episode, so the key is verified
+/// again before the session is thrown away.
+pub(crate) fn handle_unauthorized(server_name: String, api_key: String) {
+    if CHECKING_SESSION.with(|checking| checking.replace(true)) {
+        return;
+    }
+    wasm_bindgen_futures::spawn_local(async move {
+        let expired = match call_verify_key(&server_name, &api_key).await {
+            Ok(verification) => verification.status != "success",
+            Err(e) => api_error(&e).map_or(false, ApiError::is_unauthorized),
+        };
+        CHECKING_SESSION.with(|checking| checking.set(false));
+        if expired {
+            expire_session(&api_key);
+        }
+    });
+}
+
+/// Log out of a session whose key stopped working, remembering the page the user was on.
+fn expire_session(api_key: &str) {
+    let dispatch = Dispatch::<AppState>::global();
+    // A request still in flight from an earlier session mustn't log out the current one
+    let current_key = dispatch
+        .get()
+        .auth_details
+        .as_ref()
+        .and_then(|auth| auth.api_key.clone());
+    if current_key.as_deref() != Some(api_key) {
+        return;
+    }
+
+    let window = web_sys::window().expect("no global `window` exists");
+    let location = window.location();
+    let route = format!(
+        "{}{}",
+        location.pathname().unwrap_or_default(),
+        location.search().unwrap_or_default()
+    );
+    if let Ok(Some(session_storage)) = window.session_storage() {
+        let _ = session_storage.set_item("isAuthenticated", "false");
+        if route != Route::Login.to_path() {
+            let _ = session_storage.set_item(RETURN_ROUTE_KEY, &route);
+        }
+    }
+
+    dispatch.reduce_mut(|state| {
+        // The key is dead, so the saved account can't be switched back to either
+        if let Some(auth) = state.auth_details.take() {
+            ServerProfile::forget(&auth.server_name, &auth.username);
+        }
+        notify_error("Your session has expired. Please log in again.".to_string());
+        state.store_app_state();
+    });
+    BrowserHistory::new().push(Route::Login.to_path());
+}
+
+/// The page to land on after logging in: wherever an expired session interrupted the user,
+/// or home.
+pub(crate) fn take_return_route() -> String {
+    web_sys::window()
+        .and_then(|window| window.session_storage().ok().flatten())
+        .and_then(|session_storage| {
+            let route = session_storage.get_item(RETURN_ROUTE_KEY).ok().flatten();
+            let _ = session_storage.remove_item(RETURN_ROUTE_KEY);
+            route
+        })
+        .unwrap_or_else(|| "/home".to_string())
+}
+
 pub(crate) fn use_check_authentication(_dispatch: Dispatch<AppState>, current_route: &str) {
     let window = web_sys::window().expect("no global `window` exists");
     let session_storage = window.session_storage().unwrap().unwrap();
@@ -285,7 +494,7 @@ pub struct AddUserRequest {
     pub(crate) fullname: String,
     pub(crate) username: String,
     pub(crate) email: String,
-    pub(crate) hash_pw: String,
+    pub(crate) password: String,
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -298,27 +507,11 @@ pub async fn call_add_login_user(
     server_name: String,
     add_user: &Option<AddUserRequest>,
 ) -> Result<bool, Error> {
-    let server = server_name.clone();
-    let url = format!("{}/api/data/add_login_user", server);
     let add_user_req = add_user.as_ref().unwrap();
-
-    // Serialize `add_user` into JSON
-    let json_body = serde_json::to_string(&add_user_req)?;
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .body(json_body)?
-        .send()
+    let _: AddUserResponse = ApiClient::new(&server_name, None)
+        .post("/api/data/add_login_user", add_user_req)
         .await?;
-
-    if response.ok() {
-        Ok(true)
-    } else {
-        Err(Error::msg(format!(
-            "Error adding user: {}",
-            response.status_text()
-        )))
-    }
+    Ok(true)
 }
 
 #[derive(Deserialize, Debug)]
@@ -332,23 +525,10 @@ pub async fn call_first_login_done(
     api_key: String,
     user_id: &i32,
 ) -> Result<bool, Error> {
-    let url = format!("{}/api/data/first_login_done/{}", server_name, user_id);
-
-    let response = Request::get(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", &api_key)
-        .send()
+    let response: FirstLoginResponse = ApiClient::new(&server_name, Some(&api_key))
+        .get(&format!("/api/data/first_login_done/{}", user_id))
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<FirstLoginResponse>().await?;
-        Ok(response_body.FirstLogin) // Use the struct field to get the boolean value
-    } else {
-        Err(Error::msg(format!(
-            "Error checking first login status: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response.FirstLogin)
 }
 
 #[derive(Serialize, Debug, Deserialize, PartialEq, Clone)]
@@ -369,29 +549,10 @@ pub async fn call_setup_timezone_info(
     api_key: String,
     time_zone_info: TimeZoneInfo,
 ) -> Result<SetupTimeZoneInfoResponse, anyhow::Error> {
-    let url = format!("{}/api/data/setup_time_info", server_name);
-    let request_body = serde_json::to_string(&time_zone_info).context("Serialization Error")?;
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key.as_str())
-        .body(request_body)
-        .context("Request Building Error")?
-        .send()
-        .await
-        .context("Network Request Error")?;
-
-    if response.ok() {
-        response
-            .json::<SetupTimeZoneInfoResponse>()
-            .await
-            .context("Response Parsing Error")
-    } else {
-        Err(anyhow::anyhow!(
-            "Error setting up time info. Server Response: {}",
-            response.status_text()
-        ))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .post("/api/data/setup_time_info", &time_zone_info)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug)]
@@ -406,31 +567,17 @@ pub async fn call_get_time_info(
     api_key: String,
     user_id: &i32,
 ) -> Result<TimeInfoResponse, anyhow::Error> {
-    let url = format!("{}/api/data/get_time_info?user_id={}", server_name, user_id);
-
-    let response = Request::get(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", &api_key)
-        .send()
-        .await
-        .context("Network Request Error")?;
-
-    if response.ok() {
-        response
-            .json::<TimeInfoResponse>()
-            .await
-            .context("Response Parsing Error")
-    } else {
-        Err(anyhow::anyhow!(
-            "Error fetching time info. Server Response: {}",
-            response.status_text()
-        ))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .get(&format!("/api/data/get_time_info?user_id={}", user_id))
+        .await?;
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug)]
 pub struct CheckMfaEnabledResponse {
     pub(crate) mfa_enabled: bool,
+    #[serde(default)]
+    pub(crate) passkey_enabled: bool,
 }
 
 pub async fn call_check_mfa_enabled(
@@ -438,31 +585,10 @@ pub async fn call_check_mfa_enabled(
     api_key: String,
     user_id: &i32,
 ) -> Result<CheckMfaEnabledResponse, Error> {
-    let url = format!("{}/api/data/check_mfa_enabled/{}", server_name, user_id);
-
-    let response = Request::get(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key.as_str())
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network Request Error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<CheckMfaEnabledResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Response Parsing Error: {}", e)))
-    } else {
-        let status_text = response.status_text();
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(Error::msg(format!(
-            "Error checking MFA enabled status: {} - {}",
-            status_text, error_text
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .get(&format!("/api/data/check_mfa_enabled/{}", user_id))
+        .await?;
+    Ok(response)
 }
 
 #[derive(Serialize)]
@@ -474,6 +600,9 @@ pub struct VerifyMFABody {
 #[derive(Deserialize, Debug)]
 pub struct VerifyMFAResponse {
     pub(crate) verified: bool,
+    /// The code was one of the account's recovery codes, which is now spent.
+    #[serde(default)]
+    pub(crate) recovery_code_used: bool,
 }
 
 pub async fn call_verify_mfa(
@@ -482,26 +611,96 @@ pub async fn call_verify_mfa(
     user_id: i32,
     mfa_code: String,
 ) -> Result<VerifyMFAResponse, Error> {
-    let url = format!("{}/api/data/verify_mfa", server_name);
     let body = VerifyMFABody { user_id, mfa_code };
-    let request_body = serde_json::to_string(&body)?;
+    let response = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/verify_mfa", &body)
+        .await?;
+    Ok(response)
+}
 
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .body(&request_body)?
-        .send()
+/// A WebAuthn challenge from the server, as `PublicKeyCredential*OptionsJSON`, and the id to
+/// answer it under.
+#[derive(Deserialize, Debug, Clone)]
+pub struct WebAuthnOptions {
+    pub(crate) challenge_id: String,
+    pub(crate) options: serde_json::Value,
+}
+
+#[derive(Serialize)]
+struct WebAuthnUserBody {
+    user_id: i32,
+}
+
+pub async fn call_webauthn_verify_options(
+    server_name: &String,
+    api_key: &String,
+    user_id: i32,
+) -> Result<WebAuthnOptions, Error> {
+    let response = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/webauthn/verify_options", &WebAuthnUserBody { user_id })
         .await?;
+    Ok(response)
+}
 
-    if response.ok() {
-        let response_body = response.json::<VerifyMFAResponse>().await?;
-        Ok(response_body)
-    } else {
-        Err(anyhow::Error::msg(format!(
-            "Error verifying MFA: {}",
-            response.status_text()
-        )))
-    }
+#[derive(Serialize)]
+struct WebAuthnVerifyBody {
+    user_id: i32,
+    challenge_id: String,
+    credential: serde_json::Value,
+}
+
+/// Check a passkey assertion as the second factor, the passkey twin of `call_verify_mfa`.
+pub async fn call_webauthn_verify(
+    server_name: &String,
+    api_key: &String,
+    user_id: i32,
+    challenge_id: String,
+    credential: serde_json::Value,
+) -> Result<VerifyMFAResponse, Error> {
+    let body = WebAuthnVerifyBody {
+        user_id,
+        challenge_id,
+        credential,
+    };
+    let response = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/webauthn/verify", &body)
+        .await?;
+    Ok(response)
+}
+
+pub async fn call_webauthn_login_options(server_name: &str) -> Result<WebAuthnOptions, Error> {
+    let response = ApiClient::new(server_name, None)
+        .post_empty("/api/auth/webauthn/login_options")
+        .await?;
+    Ok(response)
+}
+
+#[derive(Serialize)]
+struct WebAuthnLoginBody {
+    challenge_id: String,
+    credential: serde_json::Value,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct WebAuthnLoginResponse {
+    pub retrieved_key: String,
+    pub username: String,
+}
+
+/// Trade a passkey assertion for the API key of whoever it belongs to.
+pub async fn call_webauthn_login(
+    server_name: &str,
+    challenge_id: String,
+    credential: serde_json::Value,
+) -> Result<WebAuthnLoginResponse, Error> {
+    let body = WebAuthnLoginBody {
+        challenge_id,
+        credential,
+    };
+    let response = ApiClient::new(server_name, None)
+        .post("/api/auth/webauthn/login", &body)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -510,27 +709,47 @@ pub struct SelfServiceStatusResponse {
 }
 
 pub async fn call_self_service_login_status(server_name: String) -> Result<bool, Error> {
-    let url = format!("{}/api/data/self_service_status", server_name);
+    let status_response: SelfServiceStatusResponse = ApiClient::new(&server_name, None)
+        .get("/api/data/self_service_status")
+        .await?;
+    Ok(status_response.status)
+}
 
-    let response = Request::get(&url)
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        let status_response: SelfServiceStatusResponse = response
-            .json()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))?;
-        Ok(status_response.status)
-    } else {
-        Err(Error::msg(format!(
-            "Error fetching self service status: {}",
-            response.status_text()
-        )))
+/// The server's rules for new passwords. The breached-password check can only be done by
+/// the server, so the client just says it applies.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub require_upper: bool,
+    pub require_lower: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    pub check_breached: bool,
+    #[serde(default, skip_serializing)]
+    pub breached_list_loaded: bool,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 6,
+            require_upper: false,
+            require_lower: false,
+            require_digit: false,
+            require_symbol: false,
+            check_breached: false,
+            breached_list_loaded: false,
+        }
     }
 }
 
+pub async fn call_get_password_policy(server_name: &str) -> Result<PasswordPolicy, Error> {
+    let policy = ApiClient::new(server_name, None)
+        .get("/api/data/password_policy")
+        .await?;
+    Ok(policy)
+}
+
 #[derive(Serialize)]
 pub struct ResetCodePayload {
     pub(crate) email: String,
@@ -542,43 +761,15 @@ pub struct ResetCodeResponse {
     pub code_created: bool,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-pub struct ErrorResponse {
-    pub detail: String,
-}
-
 #[allow(dead_code)]
 pub async fn call_reset_password_create_code(
     server_name: String,
     create_code: &ResetCodePayload,
 ) -> Result<bool, Error> {
-    let url = format!("{}/api/data/reset_password_create_code", server_name);
-
-    let json_body = serde_json::to_string(&create_code)?;
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .body(json_body)?
-        .send()
+    let response: ResetCodeResponse = ApiClient::new(&server_name, None)
+        .post("/api/data/reset_password_create_code", create_code)
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<ResetCodeResponse>().await?;
-        Ok(response_body.code_created)
-    } else {
-        let error_response: Result<ErrorResponse, _> = response.json().await;
-        match error_response {
-            Ok(err) => Err(Error::msg(err.detail)),
-            Err(_) => {
-                // If parsing the detailed error fails, fall back to the status text
-                let status_text = response.status_text();
-                Err(Error::msg(format!(
-                    "Error creating reset code: {}",
-                    status_text
-                )))
-            }
-        }
-    }
+    Ok(response.code_created)
 }
 
 #[derive(Serialize)]
@@ -598,23 +789,8 @@ pub async fn call_verify_and_reset_password(
     server_name: String,
     verify_and_reset: &ResetForgotPasswordPayload,
 ) -> Result<ForgotResetPasswordResponse, Error> {
-    let url = format!("{}/api/data/verify_and_reset_password", server_name);
-
-    let json_body = serde_json::to_string(&verify_and_reset)?;
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .body(json_body)?
-        .send()
+    let response = ApiClient::new(&server_name, None)
+        .post("/api/data/verify_and_reset_password", verify_and_reset)
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<ForgotResetPasswordResponse>().await?;
-        Ok(response_body)
-    } else {
-        Err(Error::msg(format!(
-            "Error creating reset code: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response)
 }
@@ -1,3 +1,4 @@
+pub(crate) mod api_client;
 pub(crate) mod login_requests;
 pub(crate) mod models;
 pub(crate) mod pod_req;
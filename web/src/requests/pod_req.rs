@@ -1,9 +1,11 @@
-use anyhow::{Context, Error};
+use crate::requests::api_client::{ApiClient, DetailResponse, LONG_TIMEOUT_MS};
+use anyhow::Error;
 // use futures_util::stream::StreamExt;
-use futures::StreamExt;
+use futures::future::Either;
+use futures::{SinkExt, StreamExt};
 use gloo::net::websocket::WebSocketError;
 use gloo::net::websocket::{futures::WebSocket, Message};
-use gloo_net::http::Request;
+use gloo_timers::future::TimeoutFuture;
 use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
@@ -71,34 +73,10 @@ pub async fn call_get_recent_eps(
     api_key: &Option<String>,
     user_id: &i32,
 ) -> Result<Vec<Episode>, anyhow::Error> {
-    let url = format!("{}/api/data/return_episodes/{}", server_name, user_id);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response: RecentEps = ApiClient::authed(server_name, api_key)?
+        .get(&format!("/api/data/return_episodes/{}", user_id))
         .await?;
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to fetch episodes: {}",
-            response.status_text()
-        )));
-    }
-
-    // First, capture the response text for diagnostic purposes
-    let response_text = response
-        .text()
-        .await
-        .unwrap_or_else(|_| "Failed to get response text".to_string());
-    // Try to deserialize the response text
-    match serde_json::from_str::<RecentEps>(&response_text) {
-        Ok(response_body) => Ok(response_body.episodes.unwrap_or_else(Vec::new)),
-        Err(_e) => Err(anyhow::Error::msg("Failed to deserialize response")),
-    }
+    Ok(response.episodes.unwrap_or_default())
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -129,79 +107,37 @@ pub async fn call_add_podcast(
     _user_id: i32,
     added_podcast: &PodcastValues,
 ) -> Result<PodcastStatusResponse, Error> {
-    let url = format!("{}/api/data/add_podcast", server_name);
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| Error::msg("API key is missing"))?;
-
-    // Serialize `added_podcast` into JSON
-    let json_body = serde_json::to_string(added_podcast)?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(json_body)?
-        .send()
+    // Adding a podcast fetches and stores its whole feed before the server answers
+    let response: PodcastStatusResponse = ApiClient::authed(server_name, api_key)?
+        .with_timeout(LONG_TIMEOUT_MS)
+        .post("/api/data/add_podcast", added_podcast)
         .await?;
-
-    if response.ok() {
-        // Read the response as JSON
-        let response_body = response.json::<PodcastStatusResponse>().await?;
-        // Optionally, you can log the response body as JSON string
-        web_sys::console::log_1(&serde_json::to_string(&response_body).unwrap().into());
-        Ok(response_body)
-    } else {
-        Err(Error::msg(format!(
-            "Error adding podcast: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response)
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct RemovePodcastValues {
-    pub podcast_id: i32,
+pub struct DeleteSelectedPodcastsRequest {
+    pub delete_list: Vec<i32>,
     pub user_id: i32,
 }
 
-pub async fn call_remove_podcasts(
-    server_name: &String,
-    api_key: &Option<String>,
-    remove_podcast: &RemovePodcastValues,
-) -> Result<bool, Error> {
-    let url = format!("{}/api/data/remove_podcast_id", server_name);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    // Serialize `added_podcast` into JSON
-    let json_body = serde_json::to_string(remove_podcast)?;
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeleteSelectedPodcastsResponse {
+    #[serde(default)]
+    pub deleted: Vec<i32>,
+}
 
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(json_body)?
-        .send()
+/// Unsubscribes from several podcasts in one request, returning the ids that were removed.
+pub async fn call_delete_selected_podcasts(
+    server_name: &str,
+    api_key: &Option<String>,
+    request: &DeleteSelectedPodcastsRequest,
+) -> Result<Vec<i32>, Error> {
+    let response: DeleteSelectedPodcastsResponse = ApiClient::authed(server_name, api_key)?
+        .with_timeout(LONG_TIMEOUT_MS)
+        .post("/api/data/delete_selected_podcasts", request)
         .await?;
-
-    let response_text = response
-        .text()
-        .await
-        .unwrap_or_else(|_| "Failed to get response text".to_string());
-
-    if response.ok() {
-        match serde_json::from_str::<PodcastStatusResponse>(&response_text) {
-            Ok(parsed_response) => Ok(parsed_response.success),
-            Err(_parse_error) => Err(anyhow::Error::msg("Failed to parse response")),
-        }
-    } else {
-        Err(anyhow::Error::msg(format!(
-            "Error removing podcast: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response.deleted)
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -216,39 +152,10 @@ pub async fn call_remove_podcasts_name(
     api_key: &Option<String>,
     remove_podcast: &RemovePodcastValuesName,
 ) -> Result<bool, Error> {
-    let url = format!("{}/api/data/remove_podcast", server_name);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    // Serialize `added_podcast` into JSON
-    let json_body = serde_json::to_string(remove_podcast)?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(json_body)?
-        .send()
+    let response: PodcastStatusResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/remove_podcast", remove_podcast)
         .await?;
-
-    let response_text = response
-        .text()
-        .await
-        .unwrap_or_else(|_| "Failed to get response text".to_string());
-
-    if response.ok() {
-        match serde_json::from_str::<PodcastStatusResponse>(&response_text) {
-            Ok(parsed_response) => Ok(parsed_response.success),
-            Err(_parse_error) => Err(anyhow::Error::msg("Failed to parse response")),
-        }
-    } else {
-        Err(anyhow::Error::msg(format!(
-            "Error removing podcast: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response.success)
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -271,6 +178,15 @@ pub struct Podcast {
     pub categories: String, // Keeping as String since it's handled as empty string "{}" or "{}"
     #[serde(deserialize_with = "bool_from_int")]
     pub explicit: bool,
+    // Library stats from return_pods, absent when a podcast comes from anywhere else
+    #[serde(default)]
+    pub lastepisodedate: Option<String>,
+    #[serde(default)]
+    pub unplayedcount: i32,
+    #[serde(default)]
+    pub totallistentime: i32,
+    #[serde(default, deserialize_with = "bool_from_int")]
+    pub autodownload: bool,
 }
 
 pub async fn call_get_podcasts(
@@ -278,41 +194,10 @@ pub async fn call_get_podcasts(
     api_key: &Option<String>,
     user_id: &i32,
 ) -> Result<Vec<Podcast>, anyhow::Error> {
-    let url = format!("{}/api/data/return_pods/{}", server_name, user_id);
-
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response: PodcastResponse = ApiClient::authed(server_name, api_key)?
+        .get(&format!("/api/data/return_pods/{}", user_id))
         .await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to fetch podcasts: {}",
-            response.status_text()
-        )));
-    }
-
-    let response_text = response
-        .text()
-        .await
-        .unwrap_or_else(|_| "Failed to get response text".to_string());
-
-    match serde_json::from_str::<PodcastResponse>(&response_text) {
-        Ok(response_body) => Ok(response_body.pods.unwrap_or_else(Vec::new)),
-        Err(e) => {
-            web_sys::console::log_1(
-                &format!("Unable to parse Podcasts: {}", &response_text).into(),
-            );
-            Err(anyhow::Error::msg(format!(
-                "Failed to deserialize response: {}",
-                e
-            )))
-        }
-    }
+    Ok(response.pods.unwrap_or_default())
 }
 
 #[derive(Deserialize, Debug)]
@@ -326,24 +211,10 @@ pub async fn call_get_time_info(
     key: String,
     user_id: i32,
 ) -> Result<TimeInfoResponse, anyhow::Error> {
-    let endpoint = format!("{}/api/data/get_time_info?user_id={}", server, user_id);
-
-    let resp = Request::get(&endpoint)
-        .header("Api-Key", key.as_str())
-        .send()
-        .await
-        .context("Network Request Error")?;
-
-    if resp.ok() {
-        resp.json::<TimeInfoResponse>()
-            .await
-            .context("Response Parsing Error")
-    } else {
-        Err(anyhow::anyhow!(
-            "Error fetching time info. Server Response: {}",
-            resp.status_text()
-        ))
-    }
+    let response = ApiClient::new(server, Some(&key))
+        .get(&format!("/api/data/get_time_info?user_id={}", user_id))
+        .await?;
+    Ok(response)
 }
 
 #[derive(Default, Deserialize, Debug)]
@@ -360,27 +231,13 @@ pub async fn call_check_podcast(
 ) -> Result<CheckPodcastResponse, Error> {
     let encoded_name = utf8_percent_encode(podcast_name, NON_ALPHANUMERIC).to_string();
     let encoded_url = utf8_percent_encode(podcast_url, NON_ALPHANUMERIC).to_string();
-    let endpoint = format!(
-        "{}/api/data/check_podcast?user_id={}&podcast_name={}&podcast_url={}",
-        server, user_id, encoded_name, encoded_url
-    );
-
-    let resp = Request::get(&endpoint)
-        .header("Api-Key", api_key)
-        .send()
-        .await
-        .context("Network Request Error")?;
-
-    if resp.ok() {
-        resp.json::<CheckPodcastResponse>()
-            .await
-            .context("Response Parsing Error")
-    } else {
-        Err(anyhow::anyhow!(
-            "Error checking podcast. Server Response: {}",
-            resp.status_text()
+    let response = ApiClient::new(server, Some(api_key))
+        .get(&format!(
+            "/api/data/check_podcast?user_id={}&podcast_name={}&podcast_url={}",
+            user_id, encoded_name, encoded_url
         ))
-    }
+        .await?;
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug)]
@@ -398,39 +255,38 @@ pub async fn call_check_episode_in_db(
 ) -> Result<bool, anyhow::Error> {
     let encoded_title = utf8_percent_encode(episode_title, NON_ALPHANUMERIC).to_string();
     let encoded_url = utf8_percent_encode(episode_url, NON_ALPHANUMERIC).to_string();
-    let endpoint = format!(
-        "{}/api/data/check_episode_in_db/{}?episode_title={}&episode_url={}",
-        server, user_id, encoded_title, encoded_url
-    );
-
-    let resp = Request::get(&endpoint)
-        .header("Api-Key", api_key)
-        .send()
-        .await
-        .context("Network Request Error")?;
-
-    if resp.ok() {
-        let episode_in_db_response = resp
-            .json::<EpisodeInDbResponse>()
-            .await
-            .context("Response Parsing Error")?;
-        Ok(episode_in_db_response.episode_in_db)
-    } else {
-        Err(anyhow::anyhow!(
-            "Error checking episode in db. Server Response: {}",
-            resp.status_text()
+    let response: EpisodeInDbResponse = ApiClient::new(server, Some(api_key))
+        .get(&format!(
+            "/api/data/check_episode_in_db/{}?episode_title={}&episode_url={}",
+            user_id, encoded_title, encoded_url
         ))
-    }
+        .await?;
+    Ok(response.episode_in_db)
 }
 
 // Queue calls
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct QueuePodcastRequest {
     pub episode_id: i32,
     pub user_id: i32,
 }
 
+/// Where a newly queued episode lands: at the head of the queue ("play next") or at the end.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueueInsert {
+    Next,
+    Last,
+}
+
+#[derive(Serialize)]
+struct QueueEpisodeBody<'a> {
+    #[serde(flatten)]
+    request: &'a QueuePodcastRequest,
+    position: QueueInsert,
+}
+
 // Define a struct to match the response JSON structure
 #[derive(Deserialize, Debug)]
 struct QueueResponse {
@@ -441,40 +297,16 @@ pub async fn call_queue_episode(
     server_name: &String,
     api_key: &Option<String>,
     request_data: &QueuePodcastRequest,
+    position: QueueInsert,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/queue_pod", server_name);
-
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let body = QueueEpisodeBody {
+        request: request_data,
+        position,
+    };
+    let response: QueueResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/queue_pod", &body)
         .await?;
-
-    if response.ok() {
-        // Deserialize the JSON response into QueueResponse
-        let response_body: QueueResponse =
-            response.json().await.map_err(|e| anyhow::Error::new(e))?;
-        // Extract and return the data string
-        Ok(response_body.data)
-    } else {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to queue episode: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response.data)
 }
 
 pub async fn call_remove_queued_episode(
@@ -482,42 +314,10 @@ pub async fn call_remove_queued_episode(
     api_key: &Option<String>,
     request_data: &QueuePodcastRequest,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/remove_queued_pod", server_name);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response = ApiClient::authed(server_name, api_key)?
+        .post_text("/api/data/remove_queued_pod", request_data)
         .await?;
-
-    if response.ok() {
-        // Read the success message from the response body as text
-        let success_message = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Episode queued successfully"));
-        Ok(success_message)
-    } else {
-        // Read the error response body as text to include in the error
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to queue episode: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response)
 }
 
 #[derive(Debug, Deserialize, PartialEq, Clone)]
@@ -554,32 +354,10 @@ pub async fn call_get_queued_episodes(
     api_key: &Option<String>,
     user_id: &i32,
 ) -> Result<Vec<QueuedEpisode>, anyhow::Error> {
-    // Append the user_id as a query parameter
-    let url = format!(
-        "{}/api/data/get_queued_episodes?user_id={}",
-        server_name, user_id
-    );
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response: DataResponse = ApiClient::authed(server_name, api_key)?
+        .get(&format!("/api/data/get_queued_episodes?user_id={}", user_id))
         .await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to fetch queued episodes: {}",
-            response.status_text()
-        )));
-    }
-    let response_text = response.text().await?;
-
-    let response_data: DataResponse = serde_json::from_str(&response_text)?;
-    Ok(response_data.data)
+    Ok(response.data)
 }
 
 #[derive(Serialize)]
@@ -593,33 +371,15 @@ pub async fn call_reorder_queue(
     user_id: &i32,
     episode_ids: &Vec<i32>,
 ) -> Result<(), Error> {
-    // Build the URL
-    let url = format!("{}/api/data/reorder_queue?user_id={}", server_name, user_id);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    // Create the payload
     let payload = ReorderPayload {
         episode_ids: episode_ids.clone(),
     };
-
-    // Send the request
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .json(&payload)?
-        .send()
+    let _: serde_json::Value = ApiClient::authed(server_name, api_key)?
+        .post(
+            &format!("/api/data/reorder_queue?user_id={}", user_id),
+            &payload,
+        )
         .await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to reorder queue: {}",
-            response.status_text()
-        )));
-    }
-
     Ok(())
 }
 
@@ -657,31 +417,10 @@ pub async fn call_get_saved_episodes(
     api_key: &Option<String>,
     user_id: &i32,
 ) -> Result<Vec<SavedEpisode>, anyhow::Error> {
-    // Append the user_id as a query parameter
-    let url = format!("{}/api/data/saved_episode_list/{}", server_name, user_id);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response: SavedDataResponse = ApiClient::authed(server_name, api_key)?
+        .get(&format!("/api/data/saved_episode_list/{}", user_id))
         .await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to fetch saved episodes: {}",
-            response.status_text()
-        )));
-    }
-
-    let response_text = response.text().await?;
-    // let response_text = response.text().await?;
-
-    let response_data: SavedDataResponse = serde_json::from_str(&response_text)?;
-    Ok(response_data.saved_episodes)
+    Ok(response.saved_episodes)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -690,51 +429,15 @@ pub struct SavePodcastRequest {
     pub user_id: i32,
 }
 
-#[derive(Deserialize, Debug)]
-struct SaveResponse {
-    detail: String,
-}
-
 pub async fn call_save_episode(
     server_name: &String,
     api_key: &Option<String>,
     request_data: &SavePodcastRequest,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/save_episode", server_name);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response: DetailResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/save_episode", request_data)
         .await?;
-
-    if response.ok() {
-        // Deserialize the JSON response into SaveResponse
-        let response_body: SaveResponse =
-            response.json().await.map_err(|e| anyhow::Error::new(e))?;
-        // Extract and return the detail string
-        Ok(response_body.detail)
-    } else {
-        // Read the error response body as text to include in the error
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to save episode: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response.detail)
 }
 
 pub async fn call_remove_saved_episode(
@@ -742,42 +445,10 @@ pub async fn call_remove_saved_episode(
     api_key: &Option<String>,
     request_data: &SavePodcastRequest,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/remove_saved_episode", server_name);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response = ApiClient::authed(server_name, api_key)?
+        .post_text("/api/data/remove_saved_episode", request_data)
         .await?;
-
-    if response.ok() {
-        // Read the success message from the response body as text
-        let success_message = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Episode saved successfully"));
-        Ok(success_message)
-    } else {
-        // Read the error response body as text to include in the error
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to save episode: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response)
 }
 
 // History calls
@@ -813,30 +484,10 @@ pub async fn call_get_user_history(
     api_key: &Option<String>,
     user_id: &i32,
 ) -> Result<Vec<HistoryEpisode>, anyhow::Error> {
-    // Append the user_id as a query parameter
-    let url = format!("{}/api/data/user_history/{}", server_name, user_id);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response: HistoryDataResponse = ApiClient::authed(server_name, api_key)?
+        .get(&format!("/api/data/user_history/{}", user_id))
         .await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to fetch history: {}",
-            response.status_text()
-        )));
-    }
-
-    let response_text = response.text().await?;
-
-    let response_data: HistoryDataResponse = serde_json::from_str(&response_text)?;
-    Ok(response_data.data)
+    Ok(response.data)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -851,28 +502,9 @@ pub async fn call_add_history(
     api_key: String,
     request_data: &HistoryAddRequest,
 ) -> Result<(), Error> {
-    let url = format!("{}/api/data/record_podcast_history", server_name);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key.as_str();
-
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let _: DetailResponse = ApiClient::new(server_name, Some(&api_key))
+        .post("/api/data/record_podcast_history", request_data)
         .await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to record history: {}",
-            response.status_text()
-        )));
-    }
-
     Ok(())
 }
 
@@ -912,33 +544,10 @@ pub async fn call_get_episode_downloads(
     api_key: &Option<String>,
     user_id: &i32,
 ) -> Result<Vec<EpisodeDownload>, anyhow::Error> {
-    // Append the user_id as a query parameter
-    let url = format!(
-        "{}/api/data/download_episode_list?user_id={}",
-        server_name, user_id
-    );
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response: DownloadDataResponse = ApiClient::authed(server_name, api_key)?
+        .get(&format!("/api/data/download_episode_list?user_id={}", user_id))
         .await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to episode downloads: {}",
-            response.status_text()
-        )));
-    }
-
-    let response_text = response.text().await?;
-
-    let response_data: DownloadDataResponse = serde_json::from_str(&response_text)?;
-    Ok(response_data.episodes)
+    Ok(response.episodes)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -947,51 +556,15 @@ pub struct DownloadEpisodeRequest {
     pub user_id: i32,
 }
 
-#[derive(Deserialize, Debug)]
-struct DownloadResponse {
-    detail: String,
-}
-
 pub async fn call_download_episode(
     server_name: &String,
     api_key: &Option<String>,
     request_data: &DownloadEpisodeRequest,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/download_podcast", server_name);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response: DetailResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/download_podcast", request_data)
         .await?;
-
-    if response.ok() {
-        // Deserialize the JSON response into DownloadResponse
-        let response_body: DownloadResponse =
-            response.json().await.map_err(|e| anyhow::Error::new(e))?;
-        // Extract and return the detail string
-        Ok(response_body.detail)
-    } else {
-        // Read the error response body as text to include in the error
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to download episode: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response.detail)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -1005,41 +578,11 @@ pub async fn call_download_all_podcast(
     api_key: &Option<String>,
     request_data: &DownloadAllPodcastRequest,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/download_all_podcast", server_name);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response: DetailResponse = ApiClient::authed(server_name, api_key)?
+        .with_timeout(LONG_TIMEOUT_MS)
+        .post("/api/data/download_all_podcast", request_data)
         .await?;
-
-    if response.ok() {
-        // Deserialize the JSON response into DownloadResponse
-        let response_body: DownloadResponse =
-            response.json().await.map_err(|e| anyhow::Error::new(e))?;
-        // Extract and return the detail string
-        Ok(response_body.detail)
-    } else {
-        // Read the error response body as text to include in the error
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to download all episodes: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response.detail)
 }
 
 pub async fn call_remove_downloaded_episode(
@@ -1047,42 +590,10 @@ pub async fn call_remove_downloaded_episode(
     api_key: &Option<String>,
     request_data: &DownloadEpisodeRequest,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/delete_episode", server_name);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response = ApiClient::authed(server_name, api_key)?
+        .post_text("/api/data/delete_episode", request_data)
         .await?;
-
-    if response.ok() {
-        // Read the success message from the response body as text
-        let success_message = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Episode downloaded successfully"));
-        Ok(success_message)
-    } else {
-        // Read the error response body as text to include in the error
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to download episode: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response)
 }
 
 // Get Single Epsiode
@@ -1102,6 +613,8 @@ pub struct EpisodeInfo {
     pub listenduration: Option<i32>,
     pub episodeid: i32,
     pub completed: bool,
+    #[serde(default)]
+    pub enclosuretype: Option<String>,
 }
 #[derive(Serialize, Deserialize, Debug)]
 pub struct EpisodeRequest {
@@ -1119,59 +632,39 @@ pub async fn call_get_episode_metadata(
     api_key: Option<String>,
     episode_request: &EpisodeRequest,
 ) -> Result<EpisodeInfo, anyhow::Error> {
-    let url = format!("{}/api/data/get_episode_metadata", server_name);
-
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request_body = serde_json::to_string(episode_request)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response: EpisodeMetadataResponse = ApiClient::authed(server_name, &api_key)?
+        .post("/api/data/get_episode_metadata", episode_request)
         .await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to episode downloads: {}",
-            response.status_text()
-        )));
-    }
-
-    let response_text = response.text().await?;
-
-    let response_data: EpisodeMetadataResponse = serde_json::from_str(&response_text)
-        .map_err(|e| anyhow::Error::msg(format!("Deserialization Error: {}", e)))?;
-
-    Ok(response_data.episode)
+    Ok(response.episode)
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[allow(non_snake_case)]
 pub struct Chapter {
-    #[serde(deserialize_with = "deserialize_start_time")]
+    #[serde(deserialize_with = "deserialize_chapter_time")]
     pub startTime: Option<i32>, // Changed to Option<i32> with custom deserializer
     pub title: String,
     pub url: Option<String>,
     pub img: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_chapter_time")]
+    pub endTime: Option<i32>,
+    // `toc: false` marks a silent chapter that only swaps artwork/links and is left out of the chapter list
+    #[serde(default)]
+    pub toc: Option<bool>,
 }
 
-fn deserialize_start_time<'de, D>(deserializer: D) -> Result<Option<i32>, D::Error>
+fn deserialize_chapter_time<'de, D>(deserializer: D) -> Result<Option<i32>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    struct StartTimeVisitor;
+    struct ChapterTimeVisitor;
 
-    impl<'de> Visitor<'de> for StartTimeVisitor {
+    impl<'de> Visitor<'de> for ChapterTimeVisitor {
         type Value = Option<i32>;
 
         fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("an integer or a floating point number as start time")
+            formatter.write_str("an integer or a floating point number as chapter time")
         }
 
         fn visit_none<E>(self) -> Result<Self::Value, E>
@@ -1220,7 +713,7 @@ where
         }
     }
 
-    deserializer.deserialize_option(StartTimeVisitor)
+    deserializer.deserialize_option(ChapterTimeVisitor)
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -1261,123 +754,354 @@ pub async fn call_fetch_podcasting_2_data(
     api_key: &Option<String>,
     episode_request: &FetchPodcasting2DataRequest,
 ) -> Result<Podcasting2Data, Error> {
-    let url = format!(
-        "{}/api/data/fetch_podcasting_2_data?episode_id={}&user_id={}",
-        server_name, episode_request.episode_id, episode_request.user_id
-    );
-
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Request Error: {}", e)))?;
-
-    if !response.ok() {
-        return Err(Error::msg(format!(
-            "Failed to fetch podcasting 2.0 data: {}",
-            response.status()
-        )));
-    }
-
-    let response_text = response
-        .text()
-        .await
-        .map_err(|e| Error::msg(format!("Failed to read response text: {}", e)))?;
-
-    let response_data: Podcasting2Data = serde_json::from_str(&response_text)
-        .map_err(|e| Error::msg(format!("Deserialization Error: {}", e)))?;
+    let response = ApiClient::authed(server_name, api_key)?
+        .get(&format!(
+            "/api/data/fetch_podcasting_2_data?episode_id={}&user_id={}",
+            episode_request.episode_id, episode_request.user_id
+        ))
+        .await?;
+    Ok(response)
+}
 
-    Ok(response_data)
+pub async fn call_fetch_transcript(
+    server_name: &str,
+    api_key: &Option<String>,
+    transcript_url: &str,
+    episode_id: i32,
+) -> Result<String, Error> {
+    // Transcripts are proxied through the server since most hosts don't allow cross-origin reads.
+    // The server only fetches transcripts that the episode's own feed lists.
+    let endpoint = format!(
+        "/api/data/fetch_transcript?url={}&episode_id={}",
+        urlencoding::encode(transcript_url),
+        episode_id
+    );
+    let transcript = ApiClient::authed(server_name, api_key)?
+        .get_text(&endpoint)
+        .await?;
+    Ok(transcript)
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub struct PodrollItem {
-    pub feed_guid: String,
+pub struct Bookmark {
+    pub bookmark_id: i32,
+    pub episode_id: i32,
+    pub time: i32,
+    pub note: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub struct Funding {
-    pub url: String,
-    pub description: String,
+#[derive(Serialize, Debug)]
+pub struct AddBookmarkRequest {
+    pub episode_id: i32,
+    pub user_id: i32,
+    pub time: i32,
+    pub note: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub struct ValueRecipient {
-    pub name: String,
-    pub r#type: String,
-    pub address: String,
-    pub split: String,
+#[derive(Deserialize, Debug)]
+struct AddBookmarkResponse {
+    bookmark_id: i32,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub struct Value {
-    pub r#type: String,
-    pub method: String,
-    pub suggested: Option<String>,
-    pub recipients: Vec<ValueRecipient>,
+pub async fn call_add_bookmark(
+    server_name: &str,
+    api_key: &Option<String>,
+    request_data: &AddBookmarkRequest,
+) -> Result<i32, Error> {
+    let response: AddBookmarkResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/add_bookmark", request_data)
+        .await?;
+    Ok(response.bookmark_id)
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub struct Podcasting2PodData {
-    pub people: Vec<Person>,
-    pub podroll: Vec<PodrollItem>,
-    pub funding: Vec<Funding>,
-    pub value: Vec<Value>,
+#[derive(Deserialize, Debug)]
+struct BookmarksResponse {
+    bookmarks: Vec<Bookmark>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct FetchPodcasting2PodDataRequest {
-    pub podcast_id: i32,
+pub async fn call_get_bookmarks(
+    server_name: &str,
+    api_key: &Option<String>,
+    user_id: i32,
+    episode_id: i32,
+) -> Result<Vec<Bookmark>, Error> {
+    let response: BookmarksResponse = ApiClient::authed(server_name, api_key)?
+        .get(&format!(
+            "/api/data/get_bookmarks?user_id={}&episode_id={}",
+            user_id, episode_id
+        ))
+        .await?;
+    Ok(response.bookmarks)
+}
+
+#[derive(Serialize, Debug)]
+pub struct DeleteBookmarkRequest {
+    pub bookmark_id: i32,
     pub user_id: i32,
 }
 
-pub async fn call_fetch_podcasting_2_pod_data(
+pub async fn call_delete_bookmark(
     server_name: &str,
     api_key: &Option<String>,
-    podcast_request: &FetchPodcasting2PodDataRequest,
-) -> Result<Podcasting2PodData, Error> {
-    let url = format!(
-        "{}/api/data/fetch_podcasting_2_pod_data?podcast_id={}&user_id={}",
-        server_name, podcast_request.podcast_id, podcast_request.user_id
-    );
-
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| Error::msg("API key is missing"))?;
+    request_data: &DeleteBookmarkRequest,
+) -> Result<(), Error> {
+    let _: DetailResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/delete_bookmark", request_data)
+        .await?;
+    Ok(())
+}
 
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Request Error: {}", e)))?;
+// Playlists
 
-    if !response.ok() {
-        return Err(Error::msg(format!(
-            "Failed to fetch podcasting 2.0 pod data: {}",
-            response.status()
-        )));
+/// Rules a smart playlist picks its episodes by. Lengths are in minutes.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct PlaylistRules {
+    pub play_state: String,
+    pub min_minutes: Option<u32>,
+    pub max_minutes: Option<u32>,
+    pub released_within_days: Option<u32>,
+    pub category: Option<String>,
+    pub podcast_id: Option<i32>,
+    pub sort: String,
+    pub limit: Option<u32>,
+}
+
+impl Default for PlaylistRules {
+    fn default() -> Self {
+        Self {
+            play_state: "any".to_string(),
+            min_minutes: None,
+            max_minutes: None,
+            released_within_days: None,
+            category: None,
+            podcast_id: None,
+            sort: "newest".to_string(),
+            limit: None,
+        }
     }
-
-    let response_text = response
-        .text()
-        .await
-        .map_err(|e| Error::msg(format!("Failed to read response text: {}", e)))?;
-
-    let response_data: Podcasting2PodData = serde_json::from_str(&response_text)
-        .map_err(|e| Error::msg(format!("Deserialization Error: {}", e)))?;
-
-    Ok(response_data)
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct Playlist {
+    pub playlist_id: i32,
+    pub name: String,
+    pub is_smart: bool,
+    pub rules: Option<PlaylistRules>,
+    #[serde(default)]
+    pub episode_count: usize,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub struct PlaylistEpisode {
+    pub episodetitle: String,
+    pub podcastname: String,
+    pub episodepubdate: String,
+    pub episodedescription: String,
+    pub episodeartwork: String,
+    pub episodeurl: String,
+    pub episodeduration: i32,
+    pub listenduration: Option<i32>,
+    pub episodeid: i32,
+    pub completed: bool,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct PlaylistEpisodesResponse {
+    pub playlist: Playlist,
+    pub episodes: Vec<PlaylistEpisode>,
+}
+
+#[derive(Deserialize, Debug)]
+struct PlaylistsResponse {
+    playlists: Vec<Playlist>,
+}
+
+pub async fn call_get_playlists(
+    server_name: &str,
+    api_key: &Option<String>,
+    user_id: i32,
+) -> Result<Vec<Playlist>, Error> {
+    let response: PlaylistsResponse = ApiClient::authed(server_name, api_key)?
+        .get(&format!("/api/data/get_playlists?user_id={}", user_id))
+        .await?;
+    Ok(response.playlists)
+}
+
+pub async fn call_get_playlist_episodes(
+    server_name: &str,
+    api_key: &Option<String>,
+    user_id: i32,
+    playlist_id: i32,
+) -> Result<PlaylistEpisodesResponse, Error> {
+    let response = ApiClient::authed(server_name, api_key)?
+        .get(&format!(
+            "/api/data/get_playlist_episodes?user_id={}&playlist_id={}",
+            user_id, playlist_id
+        ))
+        .await?;
+    Ok(response)
+}
+
+/// Leave `rules` empty for a manual playlist.
+#[derive(Serialize, Debug)]
+pub struct SavePlaylistRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub playlist_id: Option<i32>,
+    pub user_id: i32,
+    pub name: String,
+    pub rules: Option<PlaylistRules>,
+}
+
+#[derive(Deserialize, Debug)]
+struct CreatePlaylistResponse {
+    playlist_id: i32,
+}
+
+pub async fn call_create_playlist(
+    server_name: &str,
+    api_key: &Option<String>,
+    request_data: &SavePlaylistRequest,
+) -> Result<i32, Error> {
+    let response: CreatePlaylistResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/create_playlist", request_data)
+        .await?;
+    Ok(response.playlist_id)
+}
+
+pub async fn call_update_playlist(
+    server_name: &str,
+    api_key: &Option<String>,
+    request_data: &SavePlaylistRequest,
+) -> Result<(), Error> {
+    let _: DetailResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/update_playlist", request_data)
+        .await?;
+    Ok(())
+}
+
+#[derive(Serialize, Debug)]
+pub struct PlaylistRequest {
+    pub playlist_id: i32,
+    pub user_id: i32,
+}
+
+pub async fn call_delete_playlist(
+    server_name: &str,
+    api_key: &Option<String>,
+    request_data: &PlaylistRequest,
+) -> Result<(), Error> {
+    let _: DetailResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/delete_playlist", request_data)
+        .await?;
+    Ok(())
+}
+
+#[derive(Serialize, Debug)]
+pub struct PlaylistEpisodeRequest {
+    pub playlist_id: i32,
+    pub user_id: i32,
+    pub episode_id: i32,
+}
+
+pub async fn call_add_to_playlist(
+    server_name: &str,
+    api_key: &Option<String>,
+    request_data: &PlaylistEpisodeRequest,
+) -> Result<String, Error> {
+    let response: DetailResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/add_to_playlist", request_data)
+        .await?;
+    Ok(response.detail)
+}
+
+pub async fn call_remove_from_playlist(
+    server_name: &str,
+    api_key: &Option<String>,
+    request_data: &PlaylistEpisodeRequest,
+) -> Result<(), Error> {
+    let _: DetailResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/remove_from_playlist", request_data)
+        .await?;
+    Ok(())
+}
+
+#[derive(Serialize, Debug)]
+pub struct ReorderPlaylistRequest {
+    pub playlist_id: i32,
+    pub user_id: i32,
+    pub episode_ids: Vec<i32>,
+}
+
+pub async fn call_reorder_playlist(
+    server_name: &str,
+    api_key: &Option<String>,
+    request_data: &ReorderPlaylistRequest,
+) -> Result<(), Error> {
+    let _: DetailResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/reorder_playlist", request_data)
+        .await?;
+    Ok(())
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct PodrollItem {
+    pub feed_guid: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct Funding {
+    pub url: String,
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct ValueRecipient {
+    pub name: String,
+    pub r#type: String,
+    pub address: String,
+    pub split: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct Value {
+    pub r#type: String,
+    pub method: String,
+    pub suggested: Option<String>,
+    pub recipients: Vec<ValueRecipient>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct Podcasting2PodData {
+    pub people: Vec<Person>,
+    pub podroll: Vec<PodrollItem>,
+    pub funding: Vec<Funding>,
+    pub value: Vec<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FetchPodcasting2PodDataRequest {
+    pub podcast_id: i32,
+    pub user_id: i32,
+}
+
+pub async fn call_fetch_podcasting_2_pod_data(
+    server_name: &str,
+    api_key: &Option<String>,
+    podcast_request: &FetchPodcasting2PodDataRequest,
+) -> Result<Podcasting2PodData, Error> {
+    let response = ApiClient::authed(server_name, api_key)?
+        .get(&format!(
+            "/api/data/fetch_podcasting_2_pod_data?podcast_id={}&user_id={}",
+            podcast_request.podcast_id, podcast_request.user_id
+        ))
+        .await?;
+    Ok(response)
 }
 
 #[derive(Serialize)]
@@ -1397,30 +1121,10 @@ pub async fn call_record_listen_duration(
     api_key: &str,
     request_data: RecordListenDurationRequest,
 ) -> Result<RecordListenDurationResponse, Error> {
-    let url = format!("{}/api/data/record_listen_duration", server_name);
-    let request_body = serde_json::to_string(&request_data)
-        .map_err(|e| Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .body(&request_body)
-        .map_err(|e| Error::msg(format!("Request Building Error: {}", e)))?
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network Request Error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<RecordListenDurationResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Response Parsing Error: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error recording listen duration. Server Response: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/record_listen_duration", &request_data)
+        .await?;
+    Ok(response)
 }
 
 pub async fn call_increment_listen_time(
@@ -1428,23 +1132,30 @@ pub async fn call_increment_listen_time(
     api_key: &str,
     user_id: i32, // Assuming user_id is an integer based on your endpoint definition
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/increment_listen_time/{}", server_name, user_id);
+    let _: serde_json::Value = ApiClient::new(server_name, Some(api_key))
+        .put_empty(&format!("/api/data/increment_listen_time/{}", user_id))
+        .await?;
+    Ok("Listen time incremented.".to_string())
+}
 
-    let response = Request::put(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network Request Error: {}", e)))?;
+#[derive(Serialize)]
+struct TimeSavedRequest {
+    seconds: i32,
+}
 
-    if response.ok() {
-        Ok("Listen time incremented.".to_string()) // Assuming a simple success message for now
-    } else {
-        Err(Error::msg(format!(
-            "Error incrementing listen time. Server Response: {}",
-            response.status_text()
-        )))
-    }
+pub async fn call_increment_time_saved(
+    server_name: &str,
+    api_key: &str,
+    user_id: i32,
+    seconds: i32,
+) -> Result<String, Error> {
+    let _: serde_json::Value = ApiClient::new(server_name, Some(api_key))
+        .put(
+            &format!("/api/data/increment_time_saved/{}", user_id),
+            &TimeSavedRequest { seconds },
+        )
+        .await?;
+    Ok("Time saved incremented.".to_string())
 }
 
 pub async fn call_increment_played(
@@ -1452,23 +1163,10 @@ pub async fn call_increment_played(
     api_key: &str,
     user_id: i32, // Assuming user_id is an integer based on your endpoint definition
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/increment_played/{}", server_name, user_id);
-
-    let response = Request::put(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network Request Error: {}", e)))?;
-
-    if response.ok() {
-        Ok("Played count incremented.".to_string()) // Assuming a simple success message for now
-    } else {
-        Err(Error::msg(format!(
-            "Error incrementing played count. Server Response: {}",
-            response.status_text()
-        )))
-    }
+    let _: serde_json::Value = ApiClient::new(server_name, Some(api_key))
+        .put_empty(&format!("/api/data/increment_played/{}", user_id))
+        .await?;
+    Ok("Played count incremented.".to_string())
 }
 
 #[derive(Debug, Deserialize, PartialEq, Clone)]
@@ -1483,35 +1181,15 @@ pub async fn call_get_podcast_id(
     podcast_feed: &str,
     podcast_title: &str,
 ) -> Result<i32, anyhow::Error> {
-    // Append the user_id, podcast_feed, and podcast_title as query parameters
     let encoded_feed = utf8_percent_encode(podcast_feed, NON_ALPHANUMERIC).to_string();
     let encoded_title = utf8_percent_encode(podcast_title, NON_ALPHANUMERIC).to_string();
-    let url = format!(
-        "{}/api/data/get_podcast_id?user_id={}&podcast_feed={}&podcast_title={}",
-        server_name, user_id, encoded_feed, encoded_title
-    );
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response: PodcastIdResponse = ApiClient::authed(server_name, api_key)?
+        .get(&format!(
+            "/api/data/get_podcast_id?user_id={}&podcast_feed={}&podcast_title={}",
+            user_id, encoded_feed, encoded_title
+        ))
         .await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to get podcast id: {}",
-            response.status_text()
-        )));
-    }
-
-    let response_text = response.text().await?;
-
-    let response_data: PodcastIdResponse = serde_json::from_str(&response_text)?;
-    Ok(response_data.episodes)
+    Ok(response.episodes)
 }
 
 pub async fn call_get_episode_id(
@@ -1521,32 +1199,16 @@ pub async fn call_get_episode_id(
     episode_title: &str,
     episode_url: &str,
 ) -> Result<i32, anyhow::Error> {
-    // Append the user_id, podcast_feed, and podcast_title as query parameters
     let encoded_feed = utf8_percent_encode(episode_url, NON_ALPHANUMERIC).to_string();
     let encoded_title = utf8_percent_encode(episode_title, NON_ALPHANUMERIC).to_string();
-    let url = format!(
-        "{}/api/data/get_episode_id_ep_name?user_id={}&episode_url={}&episode_title={}",
-        server_name, user_id, encoded_feed, encoded_title
-    );
-
-    let response = Request::get(&url).header("Api-Key", api_key).send().await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to get podcast id: {}",
-            response.status_text()
-        )));
-    }
-
-    let response_text = response.text().await?;
-
-    // Log the response text
-    web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!(
-        "Response text: {}",
-        response_text
-    )));
+    // The endpoint answers with a bare number rather than a JSON object
+    let response_text = ApiClient::new(server_name, Some(api_key))
+        .get_text(&format!(
+            "/api/data/get_episode_id_ep_name?user_id={}&episode_url={}&episode_title={}",
+            user_id, encoded_feed, encoded_title
+        ))
+        .await?;
 
-    // Try to parse the response text into an i32
     match response_text.trim().parse::<i32>() {
         Ok(episode_id) => Ok(episode_id),
         Err(_) => Err(anyhow::Error::msg(
@@ -1566,31 +1228,13 @@ pub async fn call_get_podcast_id_from_ep(
     episode_id: i32,
     user_id: i32,
 ) -> Result<i32, Error> {
-    let url = format!(
-        "{}/api/data/get_podcast_id_from_ep_id?episode_id={}&user_id={}",
-        server_name, episode_id, user_id
-    );
-
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response: PodcastIdEpResponse = ApiClient::authed(server_name, api_key)?
+        .get(&format!(
+            "/api/data/get_podcast_id_from_ep_id?episode_id={}&user_id={}",
+            episode_id, user_id
+        ))
         .await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to get podcast id: {}",
-            response.status_text()
-        )));
-    }
-
-    let response_text = response.text().await?;
-
-    let response_data: PodcastIdEpResponse = serde_json::from_str(&response_text)?;
-    Ok(response_data.podcast_id)
+    Ok(response.podcast_id)
 }
 
 pub async fn call_get_podcast_id_from_ep_name(
@@ -1600,34 +1244,15 @@ pub async fn call_get_podcast_id_from_ep_name(
     episode_url: String,
     user_id: i32,
 ) -> Result<i32, Error> {
-    let url = format!(
-        "{}/api/data/get_podcast_id_from_ep_name?episode_name={}&episode_url={}&user_id={}",
-        server_name,
-        urlencoding::encode(&episode_name),
-        urlencoding::encode(&episode_url),
-        user_id
-    );
-
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response: PodcastIdEpResponse = ApiClient::authed(server_name, api_key)?
+        .get(&format!(
+            "/api/data/get_podcast_id_from_ep_name?episode_name={}&episode_url={}&user_id={}",
+            urlencoding::encode(&episode_name),
+            urlencoding::encode(&episode_url),
+            user_id
+        ))
         .await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to get podcast id: {}",
-            response.status_text()
-        )));
-    }
-
-    let response_text = response.text().await?;
-
-    let response_data: PodcastIdEpResponse = serde_json::from_str(&response_text)?;
-    Ok(response_data.podcast_id)
+    Ok(response.podcast_id)
 }
 
 #[derive(Deserialize, Debug, Clone, Serialize)]
@@ -1656,31 +1281,13 @@ pub async fn call_get_podcast_details(
     user_id: i32,
     podcast_id: &i32,
 ) -> Result<PodcastDetails, Error> {
-    let url = format!(
-        "{}/api/data/get_podcast_details?user_id={}&podcast_id={}",
-        server_name, user_id, podcast_id
-    );
-
-    let response = Request::get(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network request error: {}", e)))?;
-
-    if response.ok() {
-        let response_data: PodcastDetailsResponse = response
-            .json()
-            .await
-            .map_err(|e| Error::msg(format!("Failed to parse response: {}", e)))?;
-
-        Ok(response_data.details)
-    } else {
-        Err(Error::msg(format!(
-            "Error retrieving podcast details. Server response: {}",
-            response.status_text()
-        )))
-    }
+    let response: PodcastDetailsResponse = ApiClient::new(server_name, Some(api_key))
+        .get(&format!(
+            "/api/data/get_podcast_details?user_id={}&podcast_id={}",
+            user_id, podcast_id
+        ))
+        .await?;
+    Ok(response.details)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -1689,46 +1296,15 @@ pub struct MarkEpisodeCompletedRequest {
     pub user_id: i32,
 }
 
-#[derive(Deserialize, Debug)]
-struct MarkEpisodeCompletedResponse {
-    detail: String,
-}
-
 pub async fn call_mark_episode_completed(
     server_name: &String,
     api_key: &Option<String>,
     request_data: &MarkEpisodeCompletedRequest,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/mark_episode_completed", server_name);
-
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response: DetailResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/mark_episode_completed", request_data)
         .await?;
-
-    if response.ok() {
-        let response_body: MarkEpisodeCompletedResponse =
-            response.json().await.map_err(|e| anyhow::Error::new(e))?;
-        Ok(response_body.detail)
-    } else {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to mark episode completed: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response.detail)
 }
 
 pub async fn call_mark_episode_uncompleted(
@@ -1736,36 +1312,10 @@ pub async fn call_mark_episode_uncompleted(
     api_key: &Option<String>,
     request_data: &MarkEpisodeCompletedRequest,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/mark_episode_uncompleted", server_name);
-
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response: DetailResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/mark_episode_uncompleted", request_data)
         .await?;
-
-    if response.ok() {
-        let response_body: MarkEpisodeCompletedResponse =
-            response.json().await.map_err(|e| anyhow::Error::new(e))?;
-        Ok(response_body.detail)
-    } else {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to mark episode completed: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response.detail)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -1775,43 +1325,54 @@ pub struct AutoDownloadRequest {
     pub auto_download: bool,
 }
 
-#[derive(Deserialize, Debug)]
-struct AutoDownloadResponse {
-    detail: String,
-}
-
 pub async fn call_enable_auto_download(
     server_name: &String,
     api_key: &String,
     request_data: &AutoDownloadRequest,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/enable_auto_download", server_name);
+    let response: DetailResponse = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/enable_auto_download", request_data)
+        .await?;
+    Ok(response.detail)
+}
 
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AutoQueueRequest {
+    pub podcast_id: i32,
+    pub user_id: i32,
+    pub auto_queue: bool,
+}
 
-    let response = Request::post(&url)
-        .header("Api-Key", api_key)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+pub async fn call_enable_auto_queue(
+    server_name: &String,
+    api_key: &String,
+    request_data: &AutoQueueRequest,
+) -> Result<String, Error> {
+    let response: DetailResponse = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/enable_auto_queue", request_data)
         .await?;
+    Ok(response.detail)
+}
 
-    if response.ok() {
-        let response_body: AutoDownloadResponse =
-            response.json().await.map_err(|e| anyhow::Error::new(e))?;
-        Ok(response_body.detail)
-    } else {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to enable auto-download: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+#[derive(Deserialize, Debug)]
+pub struct AutoQueueStatusResponse {
+    pub auto_queue: bool,
+}
+
+pub async fn call_get_auto_queue_status(
+    server_name: &str,
+    user_id: i32,
+    api_key: &Option<String>,
+    podcast_id: i32,
+) -> Result<bool, anyhow::Error> {
+    let request = AutoDownloadStatusRequest {
+        podcast_id,
+        user_id,
+    };
+    let response: AutoQueueStatusResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/get_auto_queue_status", &request)
+        .await?;
+    Ok(response.auto_queue)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -1831,39 +1392,14 @@ pub async fn call_get_auto_download_status(
     api_key: &Option<String>,
     podcast_id: i32,
 ) -> Result<bool, anyhow::Error> {
-    let url = format!("{}/api/data/get_auto_download_status", server_name);
-
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-    let request_body = serde_json::to_string(&AutoDownloadStatusRequest {
+    let request = AutoDownloadStatusRequest {
         podcast_id,
         user_id,
-    })
-    .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    };
+    let response: AutoDownloadStatusResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/get_auto_download_status", &request)
         .await?;
-
-    if response.ok() {
-        let response_body: AutoDownloadStatusResponse =
-            response.json().await.map_err(|e| anyhow::Error::new(e))?;
-        Ok(response_body.auto_download)
-    } else {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to get auto-download status: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response.auto_download)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -1871,49 +1407,22 @@ pub struct SkipTimesRequest {
     pub podcast_id: i32,
     pub start_skip: i32,
     pub end_skip: i32,
+    /// `None` follows the player's remembered default speed.
+    pub playback_speed: Option<f64>,
+    /// Gain applied on top of the player volume, in percent (100 = unchanged).
+    pub volume_boost: i32,
     pub user_id: i32,
 }
 
-#[derive(Deserialize, Debug)]
-struct SkipTimesResponse {
-    detail: String,
-}
-
 pub async fn call_adjust_skip_times(
     server_name: &String,
     api_key: &Option<String>,
     request_data: &SkipTimesRequest,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/adjust_skip_times", server_name);
-
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response: DetailResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/adjust_skip_times", request_data)
         .await?;
-
-    if response.ok() {
-        let response_body: SkipTimesResponse =
-            response.json().await.map_err(|e| anyhow::Error::new(e))?;
-        Ok(response_body.detail)
-    } else {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to adjust skip times: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response.detail)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -1922,10 +1431,19 @@ pub struct AutoSkipTimesRequest {
     pub user_id: i32,
 }
 
-#[derive(Deserialize, Debug)]
+fn default_volume_boost() -> i32 {
+    100
+}
+
+/// Per-podcast playback preferences: skip times plus speed and volume boost.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct AutoSkipTimesResponse {
     pub start_skip: i32,
     pub end_skip: i32,
+    #[serde(default)]
+    pub playback_speed: Option<f64>,
+    #[serde(default = "default_volume_boost")]
+    pub volume_boost: i32,
 }
 
 pub async fn call_get_auto_skip_times(
@@ -1933,37 +1451,15 @@ pub async fn call_get_auto_skip_times(
     api_key: &Option<String>,
     user_id: i32,
     podcast_id: i32,
-) -> Result<(i32, i32), Error> {
-    let url = format!("{}/api/data/get_auto_skip_times", server_name);
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request_body = serde_json::to_string(&AutoSkipTimesRequest {
+) -> Result<AutoSkipTimesResponse, Error> {
+    let request = AutoSkipTimesRequest {
         podcast_id,
         user_id,
-    })?;
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key_ref)
-        .body(request_body)?
-        .send()
+    };
+    let response = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/get_auto_skip_times", &request)
         .await?;
-
-    if response.ok() {
-        let response_data: AutoSkipTimesResponse = response.json().await?;
-        Ok((response_data.start_skip, response_data.end_skip))
-    } else {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| "Failed to read error message".to_string());
-        Err(Error::msg(format!(
-            "Failed to get auto skip times: {}",
-            error_text
-        )))
-    }
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug)]
@@ -1981,35 +1477,10 @@ pub async fn call_get_pinepods_version(
     server_name: String,
     api_key: &Option<String>,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/get_pinepods_version", server_name);
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response: VersionResponse = ApiClient::authed(&server_name, api_key)?
+        .get("/api/data/get_pinepods_version")
         .await?;
-
-    if response.ok() {
-        let response_text: String = response.text().await?;
-
-        // Deserialize the JSON response
-        let version_response: VersionResponse = serde_json::from_str(&response_text)
-            .map_err(|e| anyhow::Error::msg(format!("Failed to parse response: {}", e)))?;
-
-        Ok(version_response.data)
-    } else {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| "Failed to read error message".to_string());
-        Err(Error::msg(format!(
-            "Failed to get Pinepods Version: {}",
-            error_text
-        )))
-    }
+    Ok(response.data)
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -2024,6 +1495,26 @@ pub struct EpisodeWebsocketResponse {
     pub release_datetime: String,
     pub duration: i32,
     pub completed: bool,
+    #[serde(default)]
+    pub podcast_name: Option<String>,
+}
+
+impl From<EpisodeWebsocketResponse> for Episode {
+    fn from(episode: EpisodeWebsocketResponse) -> Self {
+        Episode {
+            podcastname: episode.podcast_name.unwrap_or_default(),
+            episodetitle: episode.title,
+            // The server sends "2024-01-31 12:00:00"; the feeds use the ISO form
+            episodepubdate: episode.release_datetime.replacen(' ', "T", 1),
+            episodedescription: episode.description.unwrap_or_default(),
+            episodeartwork: episode.artwork_url.unwrap_or_default(),
+            episodeurl: episode.audio_url,
+            episodeduration: episode.duration,
+            listenduration: None,
+            episodeid: episode.episode_id,
+            completed: episode.completed,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -2031,33 +1522,80 @@ pub struct EpisodeResponse {
     pub new_episode: EpisodeWebsocketResponse,
 }
 
+#[derive(Deserialize, Debug)]
+enum EpisodeStreamMessage {
+    #[serde(rename = "new_episode")]
+    NewEpisode(EpisodeWebsocketResponse),
+    #[serde(rename = "detail")]
+    Detail(String),
+}
+
+const STREAM_INITIAL_BACKOFF_MS: u32 = 1_000;
+const STREAM_MAX_BACKOFF_MS: u32 = 60_000;
+/// Some reverse proxies drop a WebSocket after a minute or so without traffic.
+const STREAM_PING_INTERVAL_MS: u32 = 30_000;
+
+/// Where the server's WebSocket endpoints live: `wss://` for servers behind TLS, `ws://`
+/// otherwise. A server name without a scheme follows the page it was loaded from.
+pub fn websocket_url(server_name: &str, path: &str) -> String {
+    let server_name = server_name.trim_end_matches('/');
+    let (secure, host) = if let Some(host) = server_name.strip_prefix("https://") {
+        (true, host)
+    } else if let Some(host) = server_name.strip_prefix("http://") {
+        (false, host)
+    } else {
+        let page_protocol = web_sys::window()
+            .and_then(|window| window.location().protocol().ok())
+            .unwrap_or_default();
+        (page_protocol == "https:", server_name)
+    };
+    format!("{}://{}{}", if secure { "wss" } else { "ws" }, host, path)
+}
+
+fn open_websocket(
+    url: &str,
+) -> Result<
+    (
+        futures::stream::SplitSink<WebSocket, Message>,
+        futures::stream::SplitStream<WebSocket>,
+    ),
+    Error,
+> {
+    let websocket = WebSocket::open(url)
+        .map_err(|e| Error::msg(format!("Failed to open WebSocket: {:?}", e)))?;
+    let (write, read) = websocket.split();
+    Ok((write, read))
+}
+
+/// The key goes in the first message rather than the URL, where it would end up in proxy and
+/// server access logs.
+async fn send_websocket_auth(
+    write: &mut futures::stream::SplitSink<WebSocket, Message>,
+    api_key: &str,
+) -> Result<(), Error> {
+    let auth = serde_json::json!({ "api_key": api_key }).to_string();
+    write
+        .send(Message::Text(auth))
+        .await
+        .map_err(|e| Error::msg(format!("Failed to authenticate WebSocket: {:?}", e)))
+}
+
 pub async fn connect_to_episode_websocket(
     server_name: &String,
     user_id: &i32,
     api_key: &str,
     nextcloud_refresh: bool,
 ) -> Result<Vec<EpisodeWebsocketResponse>, Error> {
-    let clean_server_name = server_name
-        .trim_start_matches("http://")
-        .trim_start_matches("https://");
-
-    let url = format!(
-        "ws://{}/ws/api/data/episodes/{}?api_key={}&nextcloud_refresh={}",
-        clean_server_name, user_id, api_key, nextcloud_refresh
+    let url = websocket_url(
+        server_name,
+        &format!(
+            "/ws/api/data/episodes/{}?nextcloud_refresh={}",
+            user_id, nextcloud_refresh
+        ),
     );
 
-    // Open WebSocket connection
-    let ws_result = WebSocket::open(&url);
-
-    if ws_result.is_err() {
-        return Err(Error::msg(format!(
-            "Failed to open WebSocket: {:?}",
-            ws_result.err()
-        )));
-    }
-
-    let websocket = ws_result.unwrap();
-    let (_write, mut read) = websocket.split();
+    let (mut write, mut read) = open_websocket(&url)?;
+    send_websocket_auth(&mut write, api_key).await?;
 
     let mut episodes = Vec::new();
 
@@ -2115,38 +1653,112 @@ pub async fn connect_to_episode_websocket(
     Ok(episodes)
 }
 
+/// Keep a connection to the server's episode stream open for as long as `is_active` says so,
+/// handing each new episode to `on_episode`. Dropped connections are retried with exponential
+/// backoff; a rejected API key ends the subscription.
+pub async fn subscribe_to_new_episodes(
+    server_name: String,
+    user_id: i32,
+    api_key: String,
+    is_active: impl Fn() -> bool,
+    on_episode: impl Fn(EpisodeWebsocketResponse),
+) {
+    let url = websocket_url(
+        &server_name,
+        &format!("/ws/api/data/episode_stream/{}", user_id),
+    );
+    let mut backoff_ms = STREAM_INITIAL_BACKOFF_MS;
+
+    while is_active() {
+        match open_websocket(&url) {
+            Ok((mut write, mut read)) => {
+                if send_websocket_auth(&mut write, &api_key).await.is_ok() {
+                    loop {
+                        let next = read.next();
+                        let ping = TimeoutFuture::new(STREAM_PING_INTERVAL_MS);
+                        futures::pin_mut!(next, ping);
+                        let msg = match futures::future::select(next, ping).await {
+                            Either::Left((Some(msg), _)) => msg,
+                            Either::Left((None, _)) => break,
+                            Either::Right(_) => {
+                                if !is_active()
+                                    || write.send(Message::Text("ping".into())).await.is_err()
+                                {
+                                    break;
+                                }
+                                continue;
+                            }
+                        };
+                        if !is_active() {
+                            break;
+                        }
+                        let text = match msg {
+                            Ok(Message::Text(text)) => text,
+                            Ok(Message::Bytes(_)) => continue,
+                            Err(e) => {
+                                console::log_1(&format!("Episode stream closed: {:?}", e).into());
+                                break;
+                            }
+                        };
+                        match serde_json::from_str::<EpisodeStreamMessage>(&text) {
+                            Ok(EpisodeStreamMessage::NewEpisode(episode)) => on_episode(episode),
+                            Ok(EpisodeStreamMessage::Detail(detail)) => {
+                                if detail.starts_with("Subscribed") {
+                                    backoff_ms = STREAM_INITIAL_BACKOFF_MS;
+                                } else {
+                                    // The server only closes on us up front, when it won't take the key
+                                    console::log_1(
+                                        &format!("Episode stream refused: {}", detail).into(),
+                                    );
+                                    return;
+                                }
+                            }
+                            Err(e) => console::log_1(
+                                &format!("Unexpected episode stream message: {}", e).into(),
+                            ),
+                        }
+                    }
+                }
+                let _ = write.close().await;
+            }
+            Err(e) => console::log_1(&format!("{}", e).into()),
+        }
+
+        if !is_active() {
+            break;
+        }
+        TimeoutFuture::new(backoff_ms).await;
+        backoff_ms = (backoff_ms * 2).min(STREAM_MAX_BACKOFF_MS);
+    }
+}
+
 #[derive(Deserialize, Debug)]
 struct ShareLinkResponse {
     url_key: String,
 }
 
+/// Creates a public link to an episode. `start_time` and `end_time` (in seconds) turn it into
+/// a clip that starts and stops at those points.
 pub async fn call_create_share_link(
     server_name: &String,
     api_key: &String,
     episode_id: i32,
+    start_time: Option<i32>,
+    end_time: Option<i32>,
 ) -> Result<String, anyhow::Error> {
-    let url = format!("{}/api/data/share_episode/{}", server_name, episode_id);
+    let mut path = format!("/api/data/share_episode/{}", episode_id);
+    let bounds: Vec<String> = [("start_time", start_time), ("end_time", end_time)]
+        .iter()
+        .filter_map(|(name, value)| value.map(|value| format!("{}={}", name, value)))
+        .collect();
+    if !bounds.is_empty() {
+        path = format!("{}?{}", path, bounds.join("&"));
+    }
 
-    let response = Request::post(&url)
-        .header("Api-Key", api_key)
-        .send()
+    let response: ShareLinkResponse = ApiClient::new(server_name, Some(api_key))
+        .post_empty(&path)
         .await?;
-
-    if response.ok() {
-        let response_body: ShareLinkResponse =
-            response.json().await.map_err(|e| anyhow::Error::new(e))?;
-        Ok(response_body.url_key)
-    } else {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to create share link: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response.url_key)
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -2168,30 +1780,21 @@ pub struct EpisodeMetadata {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct SharedEpisodeResponse {
     pub episode: EpisodeMetadata,
+    #[serde(default)]
+    pub start_time: Option<i32>,
+    #[serde(default)]
+    pub end_time: Option<i32>,
 }
 
 pub async fn call_get_episode_by_url_key(
     server_name: &String,
     url_key: &str,
 ) -> Result<SharedEpisodeResponse, anyhow::Error> {
-    let url = format!("{}/api/data/episode_by_url/{}", server_name, url_key);
-
-    let response = Request::get(&url).send().await?;
-
-    if response.ok() {
-        let response_body: SharedEpisodeResponse = response.json().await?;
-        Ok(response_body)
-    } else {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to fetch episode data"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to fetch episode by url_key: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    // Shared links are opened by people without an account, so no API key is sent
+    let response = ApiClient::new(server_name, None)
+        .get(&format!("/api/data/episode_by_url/{}", url_key))
+        .await?;
+    Ok(response)
 }
 
 #[derive(Serialize)]
@@ -2206,40 +1809,10 @@ pub async fn call_add_category(
     api_key: &Option<String>,
     request_data: &AddCategoryRequest,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/add_category", server_name);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response = ApiClient::authed(server_name, api_key)?
+        .post_text("/api/data/add_category", request_data)
         .await?;
-
-    if response.ok() {
-        let success_message = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Category added successfully"));
-        Ok(success_message)
-    } else {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to add category: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response)
 }
 
 #[derive(Serialize)]
@@ -2254,38 +1827,8 @@ pub async fn call_remove_category(
     api_key: &Option<String>,
     request_data: &RemoveCategoryRequest,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/remove_category", server_name);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response = ApiClient::authed(server_name, api_key)?
+        .post_text("/api/data/remove_category", request_data)
         .await?;
-
-    if response.ok() {
-        let success_message = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Category removed successfully"));
-        Ok(success_message)
-    } else {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| String::from("Failed to read error message"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to remove category: {} - {}",
-            response.status_text(),
-            error_text
-        )))
-    }
+    Ok(response)
 }
@@ -1,11 +1,11 @@
 use crate::components::podcast_layout::ClickedFeedURL;
+use crate::requests::api_client::ApiClient;
 use anyhow::Error;
 use chrono::DateTime;
 use gloo_net::http::Request;
 use rss::Channel;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
-use wasm_bindgen::JsValue;
 
 #[derive(Deserialize, Debug)]
 pub struct RecentEps {
@@ -247,35 +247,13 @@ pub async fn call_get_podcast_info(
     search_api_url: &Option<String>,
     search_index: &str,
 ) -> Result<PodcastSearchResult, anyhow::Error> {
-    web_sys::console::log_1(&JsValue::from_str("Calling get podcast info"));
-    web_sys::console::log_1(&JsValue::from_str(podcast_value));
-    let url = if let Some(api_url) = search_api_url {
-        format!("{}?query={}&index={}", api_url, podcast_value, search_index)
-    } else {
-        return Err(anyhow::Error::msg("API URL is not provided"));
-    };
-    web_sys::console::log_1(&JsValue::from_str(&url));
-
-    let response = Request::get(&url)
-        .send()
-        .await
-        .map_err(|err| anyhow::Error::new(err))?;
-
-    if response.ok() {
-        let response_text = response
-            .text()
-            .await
-            .map_err(|err| anyhow::Error::new(err))?;
-
-        let search_results: PodcastSearchResult = serde_json::from_str(&response_text)?;
-        // web_sys::console::log_1(search_results.clone());
-        Ok(search_results)
-    } else {
-        Err(anyhow::Error::msg(format!(
-            "Failed to fetch podcast info: {}",
-            response.status_text()
-        )))
-    }
+    let api_url = search_api_url
+        .as_deref()
+        .ok_or_else(|| anyhow::Error::msg("API URL is not provided"))?;
+    let search_results = ApiClient::new(api_url, None)
+        .get(&format!("?query={}&index={}", podcast_value, search_index))
+        .await?;
+    Ok(search_results)
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -319,63 +297,24 @@ pub async fn call_get_person_info(
     search_api_url: &Option<String>,
     search_index: &str,
 ) -> Result<PeopleFeedResult, anyhow::Error> {
-    let url = if let Some(api_url) = search_api_url {
-        format!(
-            "{}?query={}&index={}&search_type=person",
-            api_url, person_name, search_index
-        )
-    } else {
-        return Err(anyhow::Error::msg("API URL is not provided"));
-    };
-    web_sys::console::log_1(&JsValue::from_str(&url));
-    web_sys::console::log_1(&JsValue::from_str("Calling get person info"));
-
-    let response = Request::get(&url)
-        .send()
-        .await
-        .map_err(|err| anyhow::Error::new(err))?;
-    web_sys::console::log_1(&JsValue::from_str("post request"));
-
-    if response.ok() {
-        web_sys::console::log_1(&JsValue::from_str("inside ok"));
-        let response_text = response
-            .text()
-            .await
-            .map_err(|err| anyhow::Error::new(err))?;
-        web_sys::console::log_1(&JsValue::from_str("post text"));
-
-        web_sys::console::log_1(&JsValue::from_str(&response_text));
-
-        let search_results: PeopleFeedResult = serde_json::from_str(&response_text)?;
-
-        Ok(search_results)
-    } else {
-        web_sys::console::log_1(&JsValue::from_str("inside error"));
-        Err(anyhow::Error::msg(format!(
-            "Failed to fetch person info: {}",
-            response.status_text()
-        )))
-    }
+    let api_url = search_api_url
+        .as_deref()
+        .ok_or_else(|| anyhow::Error::msg("API URL is not provided"))?;
+    let search_results = ApiClient::new(api_url, None)
+        .get(&format!(
+            "?query={}&index={}&search_type=person",
+            person_name, search_index
+        ))
+        .await?;
+    Ok(search_results)
 }
 
 pub async fn test_connection(search_api_url: &Option<String>) -> Result<(), Error> {
     let url = search_api_url
-        .as_ref()
+        .as_deref()
         .ok_or_else(|| Error::msg("API URL is missing"))?;
-
-    match Request::get(url).send().await {
-        Ok(response) => {
-            if response.ok() {
-                Ok(())
-            } else {
-                Err(Error::msg(format!(
-                    "HTTP error occurred: {}",
-                    response.status_text()
-                )))
-            }
-        }
-        Err(err) => Err(Error::new(err)),
-    }
+    ApiClient::new(url, None).get_text("").await?;
+    Ok(())
 }
 
 #[derive(Debug, Deserialize, PartialEq, Clone)]
@@ -389,30 +328,13 @@ pub async fn call_get_podcast_episodes(
     user_id: &i32,
     podcast_id: &i32,
 ) -> Result<PodcastFeedResult, anyhow::Error> {
-    let url = format!(
-        "{}/api/data/podcast_episodes?user_id={}&podcast_id={}",
-        server_name, user_id, podcast_id
-    );
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response_data: PodcastEpisodesResponse = ApiClient::authed(server_name, api_key)?
+        .get(&format!(
+            "/api/data/podcast_episodes?user_id={}&podcast_id={}",
+            user_id, podcast_id
+        ))
         .await?;
 
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to get podcast episodes: {}",
-            response.status_text()
-        )));
-    }
-
-    let response_text = response.text().await?;
-
-    let response_data: PodcastEpisodesResponse = serde_json::from_str(&response_text)?;
-
     let episodes = response_data
         .episodes
         .into_iter()
@@ -433,80 +355,64 @@ pub async fn call_parse_podcast_url(
     podcast_url: &str,
 ) -> Result<PodcastFeedResult, Error> {
     let encoded_podcast_url = urlencoding::encode(podcast_url);
-    let endpoint = format!(
-        "{}/api/data/fetch_podcast_feed?podcast_feed={}",
-        server_name, encoded_podcast_url
-    );
-
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request = Request::get(&endpoint)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key_ref)
-        .send()
+    // The server fetches the feed for us and hands back the raw XML
+    let response_text = ApiClient::authed(&server_name, api_key)?
+        .get_text(&format!(
+            "/api/data/fetch_podcast_feed?podcast_feed={}",
+            encoded_podcast_url
+        ))
         .await?;
+    let channel = Channel::read_from(response_text.as_bytes())?;
+
+    let podcast_artwork_url = channel
+        .image()
+        .map(|img| img.url().to_string())
+        .or_else(|| {
+            channel
+                .itunes_ext()
+                .and_then(|ext| ext.image())
+                .map(|url| url.to_string())
+        });
 
-    if request.ok() {
-        let response_text = request.text().await?;
-        let channel = Channel::read_from(response_text.as_bytes())?;
+    let episodes = channel
+        .items()
+        .iter()
+        .map(|item| {
+            let duration = item
+                .itunes_ext()
+                .and_then(|ext| ext.duration())
+                .map(|d| d.to_string());
+            if duration.is_none() {
+                web_sys::console::log_1(
+                    &format!("Missing duration for episode: {:?}", item.title()).into(),
+                );
+            }
 
-        let podcast_artwork_url = channel
-            .image()
-            .map(|img| img.url().to_string())
-            .or_else(|| {
-                channel
+            Episode {
+                title: item.title().map(|t| t.to_string()),
+                description: item.description().map(|d| d.to_string()),
+                content: item.content().map(|c| c.to_string()),
+                enclosure_url: item.enclosure().map(|e| e.url().to_string()),
+                enclosure_length: item.enclosure().map(|e| e.length().to_string()),
+                pub_date: item.pub_date().map(|p| p.to_string()),
+                authors: item
+                    .author()
+                    .map(|a| vec![a.to_string()])
+                    .unwrap_or_default(),
+                links: item.link().map(|l| vec![l.to_string()]).unwrap_or_default(),
+                artwork: item
                     .itunes_ext()
                     .and_then(|ext| ext.image())
                     .map(|url| url.to_string())
-            });
-
-        let episodes = channel
-            .items()
-            .iter()
-            .map(|item| {
-                let duration = item
-                    .itunes_ext()
-                    .and_then(|ext| ext.duration())
-                    .map(|d| d.to_string());
-                if duration.is_none() {
-                    web_sys::console::log_1(
-                        &format!("Missing duration for episode: {:?}", item.title()).into(),
-                    );
-                }
-
-                Episode {
-                    title: item.title().map(|t| t.to_string()),
-                    description: item.description().map(|d| d.to_string()),
-                    content: item.content().map(|c| c.to_string()),
-                    enclosure_url: item.enclosure().map(|e| e.url().to_string()),
-                    enclosure_length: item.enclosure().map(|e| e.length().to_string()),
-                    pub_date: item.pub_date().map(|p| p.to_string()),
-                    authors: item
-                        .author()
-                        .map(|a| vec![a.to_string()])
-                        .unwrap_or_default(),
-                    links: item.link().map(|l| vec![l.to_string()]).unwrap_or_default(),
-                    artwork: item
-                        .itunes_ext()
-                        .and_then(|ext| ext.image())
-                        .map(|url| url.to_string())
-                        .or_else(|| podcast_artwork_url.clone()),
-                    guid: item.guid().map(|g| g.value().to_string()),
-                    duration: Some(duration.unwrap_or_else(|| "00:00:00".to_string())),
-                    episode_id: None,
-                }
-            })
-            .collect();
+                    .or_else(|| podcast_artwork_url.clone()),
+                guid: item.guid().map(|g| g.value().to_string()),
+                duration: Some(duration.unwrap_or_else(|| "00:00:00".to_string())),
+                episode_id: None,
+            }
+        })
+        .collect();
 
-        Ok(PodcastFeedResult { episodes })
-    } else {
-        Err(anyhow::Error::msg(format!(
-            "Failed to fetch podcast feed: HTTP {}",
-            request.status()
-        )))
-    }
+    Ok(PodcastFeedResult { episodes })
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -582,44 +488,63 @@ pub async fn call_get_podcast_details_dynamic(
     display_only: Option<bool>,
 ) -> Result<ClickedFeedURL, Error> {
     let display_only = display_only.unwrap_or(false); // Default to false if not provided
-    let url = format!(
-        "{}/api/data/get_podcast_details_dynamic?user_id={}&podcast_title={}&podcast_url={}&added={}&display_only={}",
-        server_name, user_id, podcast_title, podcast_url, added, display_only
-    );
-
-    let response = Request::get(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network request error: {}", e)))?;
-
-    if response.ok() {
-        let podcast_details: ClickedFeedURL = response
-            .json()
-            .await
-            .map_err(|e| Error::msg(format!("Failed to parse response: {}", e)))?;
-
-        Ok(podcast_details)
-    } else {
-        Err(Error::msg(format!(
-            "Error retrieving podcast details. Server response: {}",
-            response.status_text()
-        )))
-    }
+    let podcast_details = ApiClient::new(server_name, Some(api_key))
+        .get(&format!(
+            "/api/data/get_podcast_details_dynamic?user_id={}&podcast_title={}&podcast_url={}&added={}&display_only={}",
+            user_id, podcast_title, podcast_url, added, display_only
+        ))
+        .await?;
+    Ok(podcast_details)
 }
 
 // In Databases
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
 pub struct SearchRequest {
     pub search_term: String,
     pub user_id: i32,
+    // Filters left as None fall back to whatever qualifiers are typed into search_term
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub podcast: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub person: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listen_state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<String>>,
+    pub page: usize,
+    pub page_size: usize,
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
 pub struct SearchResponse {
     pub data: Vec<SearchEpisode>,
+    #[serde(default)]
+    pub total: usize,
+    #[serde(default)]
+    pub page: usize,
+    #[serde(default)]
+    pub page_size: usize,
+    /// Free text terms the server matched on, for highlighting
+    #[serde(default)]
+    pub terms: Vec<String>,
+}
+
+impl SearchResponse {
+    pub fn page_count(&self) -> usize {
+        if self.page_size == 0 {
+            return 1;
+        }
+        self.total.div_ceil(self.page_size).max(1)
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
@@ -646,41 +571,28 @@ pub struct SearchEpisode {
     pub episodeduration: i32,
     pub listenduration: Option<i32>,
     pub completed: bool,
+    /// Which of title, notes and transcript contained a search term
+    #[serde(default)]
+    pub matched_in: Vec<String>,
+    /// Plain text around the first match in the transcript or show notes
+    #[serde(default)]
+    pub snippet: Option<String>,
+    /// Copied from the response so the title can be highlighted where it is rendered
+    #[serde(default, skip_serializing)]
+    pub highlight_terms: Vec<String>,
 }
 
 pub async fn call_search_database(
     server_name: &String,
     api_key: &Option<String>,
     request_data: &SearchRequest,
-) -> Result<Vec<SearchEpisode>, Error> {
-    let url = format!("{}/api/data/search_data", server_name);
-
-    // Convert Option<String> to Option<&str>
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| anyhow::Error::msg("API key is missing"))?;
-
-    let request_body = serde_json::to_string(request_data)
-        .map_err(|e| anyhow::Error::msg(format!("Serialization Error: {}", e)))?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+) -> Result<SearchResponse, Error> {
+    let mut search_response: SearchResponse = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/search_data", request_data)
         .await?;
-
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!(
-            "Failed to search database: {}",
-            response.status_text()
-        )));
+    let terms = search_response.terms.clone();
+    for episode in search_response.data.iter_mut() {
+        episode.highlight_terms = terms.clone();
     }
-    // Deserialize the response body into a SearchResponse
-    let search_response: SearchResponse = response.json().await?;
-
-    // Extract the vector of episodes from the SearchResponse
-    let results = search_response.data;
-
-    Ok(results)
+    Ok(search_response)
 }
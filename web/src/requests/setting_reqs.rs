@@ -1,9 +1,9 @@
+use crate::requests::api_client::{ApiClient, Method, LONG_TIMEOUT_MS};
+use crate::requests::login_requests::{PasswordPolicy, WebAuthnOptions};
 use crate::requests::pod_req::Podcast;
 use anyhow::Error;
-use gloo_net::http::Request;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use wasm_bindgen::JsValue;
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
 pub struct GetThemeResponse {
@@ -14,24 +14,10 @@ pub async fn call_get_theme(
     api_key: String,
     user_id: &i32,
 ) -> Result<String, anyhow::Error> {
-    let url = format!("{}/api/data/get_theme/{}", server_name, user_id);
-    let api_key_ref = api_key.as_str();
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .send()
+    let response: GetThemeResponse = ApiClient::new(&server_name, Some(&api_key))
+        .get(&format!("/api/data/get_theme/{}", user_id))
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<GetThemeResponse>().await?;
-        Ok(response_body.theme)
-    } else {
-        Err(Error::msg(format!(
-            "Error getting theme. Is the server reachable? Server Response: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response.theme)
 }
 
 #[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
@@ -50,30 +36,10 @@ pub async fn call_set_theme(
     set_theme: &SetThemeRequest,
 ) -> Result<bool, Error> {
     let server = server_name.clone().unwrap();
-    let url = format!("{}/api/data/user/set_theme", server);
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| Error::msg("API key is missing"))?;
-
-    // Serialize `added_podcast` into JSON
-    let json_body = serde_json::to_string(set_theme)?;
-
-    let response = Request::put(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(json_body)?
-        .send()
+    let response: SetThemeResponse = ApiClient::authed(&server, api_key)?
+        .put("/api/data/user/set_theme", set_theme)
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<SetThemeResponse>().await?;
-        Ok(response_body.message == "Success")
-    } else {
-        Err(Error::msg(format!(
-            "Error updating theme: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response.message == "Success")
 }
 
 // Admin Only API Calls
@@ -97,25 +63,10 @@ pub async fn call_get_user_info(
     server_name: String,
     api_key: String,
 ) -> Result<Vec<SettingsUser>, anyhow::Error> {
-    let url = format!("{}/api/data/get_user_info", server_name);
-    let api_key_ref = api_key.as_str();
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .send()
+    let users = ApiClient::new(&server_name, Some(&api_key))
+        .get("/api/data/get_user_info")
         .await?;
-
-    if response.ok() {
-        let response_text = response.text().await?;
-        let users: Vec<SettingsUser> = serde_json::from_str(&response_text)?;
-        Ok(users)
-    } else {
-        Err(Error::msg(format!(
-            "Error getting user info. Is the server reachable? Server Response: {}",
-            response.status_text()
-        )))
-    }
+    Ok(users)
 }
 
 #[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
@@ -123,7 +74,7 @@ pub struct AddSettingsUserRequest {
     pub(crate) fullname: String,
     pub(crate) username: String,
     pub(crate) email: String,
-    pub(crate) hash_pw: String,
+    pub(crate) password: String,
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -136,30 +87,10 @@ pub async fn call_add_user(
     api_key: String,
     add_user: &AddSettingsUserRequest,
 ) -> Result<bool, Error> {
-    let server = server_name.clone();
-    let url = format!("{}/api/data/add_user", server);
-
-    // let add_user_req = add_user.as_ref().unwrap();
-
-    // Serialize `add_user` into JSON
-    let json_body = serde_json::to_string(&add_user)?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .body(json_body)?
-        .send()
+    let response: AddUserResponse = ApiClient::new(&server_name, Some(&api_key))
+        .post("/api/data/add_user", add_user)
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<AddUserResponse>().await?;
-        Ok(response_body.detail == "Success")
-    } else {
-        Err(Error::msg(format!(
-            "Error adding user: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response.detail == "Success")
 }
 
 #[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
@@ -173,58 +104,26 @@ pub async fn call_set_fullname(
     user_id: i32,
     new_name: String,
 ) -> Result<DetailResponse, Error> {
-    let url = format!(
-        "{}/api/data/set_fullname/{}?new_name={}",
-        server_name, user_id, new_name
-    );
-    let response = Request::put(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<DetailResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error setting fullname: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .put_empty(&format!(
+            "/api/data/set_fullname/{}?new_name={}",
+            user_id, new_name
+        ))
+        .await?;
+    Ok(response)
 }
 
 pub async fn call_set_password(
     server_name: String,
     api_key: String,
     user_id: i32,
-    hash_pw: String,
+    password: String,
 ) -> Result<DetailResponse, Error> {
-    let url = format!("{}/api/data/set_password/{}", server_name, user_id);
-    let body = serde_json::json!({ "hash_pw": hash_pw });
-
-    let response = Request::put(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .body(body.to_string())?
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<DetailResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error setting password: {}",
-            response.status_text()
-        )))
-    }
+    let body = serde_json::json!({ "password": password });
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .put(&format!("/api/data/set_password/{}", user_id), &body)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Debug, Deserialize)]
@@ -237,26 +136,14 @@ pub async fn call_delete_user(
     api_key: String,
     user_id: i32,
 ) -> Result<DeleteUserResponse, Error> {
-    let url = format!("{}/api/data/user/delete/{}", server_name, user_id);
-
-    let response = Request::delete(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<DeleteUserResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error deleting user: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .send::<(), _>(
+            Method::Delete,
+            &format!("/api/data/user/delete/{}", user_id),
+            None,
+        )
+        .await?;
+    Ok(response)
 }
 
 pub async fn call_set_email(
@@ -265,28 +152,11 @@ pub async fn call_set_email(
     user_id: i32,
     new_email: String,
 ) -> Result<DetailResponse, Error> {
-    let url = format!("{}/api/data/user/set_email", server_name);
     let body = serde_json::json!({ "user_id": user_id, "new_email": new_email });
-
-    let response = Request::put(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .body(&body.to_string())?
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<DetailResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error setting email: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .put("/api/data/user/set_email", &body)
+        .await?;
+    Ok(response)
 }
 
 pub async fn call_set_username(
@@ -295,28 +165,11 @@ pub async fn call_set_username(
     user_id: i32,
     new_username: String,
 ) -> Result<DetailResponse, Error> {
-    let url = format!("{}/api/data/user/set_username", server_name);
     let body = serde_json::json!({ "user_id": user_id, "new_username": new_username });
-
-    let response = Request::put(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .body(&body.to_string())?
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<DetailResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error setting username: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .put("/api/data/user/set_username", &body)
+        .await?;
+    Ok(response)
 }
 #[allow(dead_code)]
 pub async fn call_set_isadmin(
@@ -325,28 +178,11 @@ pub async fn call_set_isadmin(
     user_id: i32,
     isadmin: bool,
 ) -> Result<DetailResponse, Error> {
-    let url = format!("{}/api/data/user/set_isadmin", server_name);
     let body = serde_json::json!({ "user_id": user_id, "isadmin": isadmin });
-
-    let response = Request::put(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .body(&body.to_string())?
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<DetailResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error setting admin status: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .put("/api/data/user/set_isadmin", &body)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Deserialize, PartialEq)]
@@ -360,25 +196,10 @@ pub async fn call_check_admin(
     api_key: String,
     user_id: i32,
 ) -> Result<FinalAdminResponse, Error> {
-    let url = format!("{}/api/data/user/final_admin/{}", server_name, user_id);
-
-    let response = Request::get(&url)
-        .header("Api-Key", &api_key)
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<FinalAdminResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error getting admin status: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .get(&format!("/api/data/user/final_admin/{}", user_id))
+        .await?;
+    Ok(response)
 }
 
 #[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
@@ -386,7 +207,7 @@ pub struct EditSettingsUserRequest {
     pub(crate) fullname: String,
     pub(crate) new_username: String,
     pub(crate) email: String,
-    pub(crate) hash_pw: String,
+    pub(crate) password: String,
     pub(crate) admin_status: bool,
 }
 
@@ -404,78 +225,30 @@ pub async fn call_enable_disable_guest(
     server_name: String,
     api_key: String,
 ) -> Result<SuccessResponse, Error> {
-    let url = format!("{}/api/data/enable_disable_guest", server_name);
-
-    let response = Request::post(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<SuccessResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error enabling/disabling guest access: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .post_empty("/api/data/enable_disable_guest")
+        .await?;
+    Ok(response)
 }
 
 pub async fn call_enable_disable_downloads(
     server_name: String,
     api_key: String,
 ) -> Result<SuccessResponse, Error> {
-    let url = format!("{}/api/data/enable_disable_downloads", server_name);
-
-    let response = Request::post(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<SuccessResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error enabling/disabling downloads: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .post_empty("/api/data/enable_disable_downloads")
+        .await?;
+    Ok(response)
 }
 
 pub async fn call_enable_disable_self_service(
     server_name: String,
     api_key: String,
 ) -> Result<SuccessResponse, Error> {
-    let url = format!("{}/api/data/enable_disable_self_service", server_name);
-
-    let response = Request::post(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<SuccessResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error enabling/disabling self service: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .post_empty("/api/data/enable_disable_self_service")
+        .await?;
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -484,25 +257,10 @@ pub struct GuestStatusResponse {
 }
 
 pub async fn call_guest_status(server_name: String, api_key: String) -> Result<bool, Error> {
-    let url = format!("{}/api/data/guest_status", server_name);
-
-    let response = Request::get(&url)
-        .header("Api-Key", &api_key)
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<bool>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error fetching guest status: {}",
-            response.status_text()
-        )))
-    }
+    let status = ApiClient::new(&server_name, Some(&api_key))
+        .get("/api/data/guest_status")
+        .await?;
+    Ok(status)
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -511,25 +269,10 @@ pub struct DownloadStatusResponse {
 }
 
 pub async fn call_download_status(server_name: String, api_key: String) -> Result<bool, Error> {
-    let url = format!("{}/api/data/download_status", server_name);
-
-    let response = Request::get(&url)
-        .header("Api-Key", &api_key)
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<bool>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error fetching download status: {}",
-            response.status_text()
-        )))
-    }
+    let status = ApiClient::new(&server_name, Some(&api_key))
+        .get("/api/data/download_status")
+        .await?;
+    Ok(status)
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -538,26 +281,21 @@ pub struct SelfServiceStatusResponse {
 }
 
 pub async fn call_self_service_status(server_name: String, api_key: String) -> Result<bool, Error> {
-    let url = format!("{}/api/data/self_service_status", server_name);
-
-    let response = Request::get(&url)
-        .header("Api-Key", &api_key)
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        let status_response: SelfServiceStatusResponse = response
-            .json()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))?;
-        Ok(status_response.status)
-    } else {
-        Err(Error::msg(format!(
-            "Error fetching self service status: {}",
-            response.status_text()
-        )))
-    }
+    let status_response: SelfServiceStatusResponse = ApiClient::new(&server_name, Some(&api_key))
+        .get("/api/data/self_service_status")
+        .await?;
+    Ok(status_response.status)
+}
+
+pub async fn call_set_password_policy(
+    server_name: String,
+    api_key: String,
+    policy: &PasswordPolicy,
+) -> Result<DetailResponse, Error> {
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .put("/api/data/password_policy", policy)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -582,28 +320,11 @@ pub async fn call_save_email_settings(
     api_key: String,
     email_settings: EmailSettings,
 ) -> Result<DetailResponse, Error> {
-    let url = format!("{}/api/data/save_email_settings", server_name);
     let body = EmailSettingsRequest { email_settings };
-
-    let response = Request::post(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .body(serde_json::to_string(&body)?)?
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<DetailResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error saving email settings: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .post("/api/data/save_email_settings", &body)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -631,28 +352,10 @@ pub async fn call_send_test_email(
     api_key: String,
     email_settings: TestEmailSettings,
 ) -> Result<EmailSendResponse, Error> {
-    let url = format!("{}/api/data/send_test_email", server_name);
-    let body = serde_json::to_string(&email_settings)?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .body(&body)?
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<EmailSendResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error sending email: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .post("/api/data/send_test_email", &email_settings)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -667,28 +370,10 @@ pub async fn call_send_email(
     api_key: String,
     email_settings: SendEmailSettings,
 ) -> Result<EmailSendResponse, Error> {
-    let url = format!("{}/api/data/send_email", server_name);
-    let body = email_settings;
-
-    let response = Request::post(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .body(serde_json::to_string(&body)?)?
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<EmailSendResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error sending email: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .post("/api/data/send_email", &email_settings)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone, Default)]
@@ -710,28 +395,10 @@ pub async fn call_get_email_settings(
     server_name: String,
     api_key: String,
 ) -> Result<EmailSettingsResponse, Error> {
-    let url = format!("{}/api/data/get_email_settings", server_name);
-
-    let response = Request::get(&url)
-        .header("Api-Key", &api_key)
-        .header("Content-Type", "application/json")
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| Error::msg(format!("Error getting response text: {}", e)))?;
-        serde_json::from_str::<EmailSettingsResponse>(&response_text)
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error retrieving email settings: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .get("/api/data/get_email_settings")
+        .await?;
+    Ok(response)
 }
 
 // User Setting Requests
@@ -744,6 +411,14 @@ pub struct APIInfo {
     pub username: String,
     pub lastfourdigits: String,
     pub created: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub scope: Option<String>,
+    #[serde(default)]
+    pub expires: Option<String>,
+    #[serde(default)]
+    pub lastused: Option<String>,
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -756,25 +431,10 @@ pub async fn call_get_api_info(
     user_id: i32,
     api_key: String,
 ) -> Result<APIInfoResponse, Error> {
-    let url = format!("{}/api/data/get_api_info/{}", server_name, user_id);
-
-    let response = Request::get(&url)
-        .header("Api-Key", &api_key)
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    if response.ok() {
-        response
-            .json::<APIInfoResponse>()
-            .await
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error retrieving API info: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .get(&format!("/api/data/get_api_info/{}", user_id))
+        .await?;
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -782,30 +442,25 @@ pub struct CreateAPIKeyResponse {
     pub api_key: String,
 }
 
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateAPIKeyRequest {
+    pub user_id: i32,
+    pub name: Option<String>,
+    /// One of "read", "sync" or "admin".
+    pub scope: String,
+    /// Never expires when left out.
+    pub expires_in_days: Option<u32>,
+}
+
 pub async fn call_create_api_key(
     server_name: &str,
-    user_id: i32,
+    request: &CreateAPIKeyRequest,
     api_key: &str,
 ) -> Result<CreateAPIKeyResponse, anyhow::Error> {
-    let url = format!("{}/api/data/create_api_key", server_name);
-    let request_body = serde_json::json!({ "user_id": user_id });
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .body(serde_json::to_string(&request_body)?)?
-        .send()
-        .await
-        .map_err(anyhow::Error::msg)?;
-
-    if response.ok() {
-        response
-            .json::<CreateAPIKeyResponse>()
-            .await
-            .map_err(anyhow::Error::msg)
-    } else {
-        Err(anyhow::Error::msg("Error creating API key"))
-    }
+    let response = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/create_api_key", request)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -824,33 +479,10 @@ pub async fn call_delete_api_key(
     request_body: DeleteAPIRequest,
     api_key: &str,
 ) -> Result<DeleteAPIKeyResponse, anyhow::Error> {
-    let url = format!("{}/api/data/delete_api_key", server_name);
-    let body = request_body;
-
-    let response = Request::delete(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .body(serde_json::to_string(&body)?)?
-        .send()
-        .await
-        .map_err(anyhow::Error::msg)?;
-
-    if response.ok() {
-        response
-            .json::<DeleteAPIKeyResponse>()
-            .await
-            .map_err(anyhow::Error::msg)
-    } else {
-        // If the response is not ok(), read the response body to extract the error message
-        let error_message = response
-            .text()
-            .await
-            .unwrap_or_else(|_| "Unknown error".to_string());
-        Err(anyhow::Error::msg(format!(
-            "Error deleting API key: {}",
-            error_message
-        )))
-    }
+    let response = ApiClient::new(server_name, Some(api_key))
+        .delete("/api/data/delete_api_key", &request_body)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -863,23 +495,12 @@ pub async fn call_backup_user(
     user_id: i32,
     api_key: &str,
 ) -> Result<String, anyhow::Error> {
-    // Assuming the OPML content is returned as a plain string
-    let url = format!("{}/api/data/backup_user", server_name);
+    // The OPML content is returned as a plain string
     let request_body = BackupUserRequest { user_id };
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .body(serde_json::to_string(&request_body)?)?
-        .send()
-        .await
-        .map_err(anyhow::Error::msg)?;
-
-    if response.ok() {
-        response.text().await.map_err(anyhow::Error::msg)
-    } else {
-        Err(anyhow::Error::msg("Error backing up user data"))
-    }
+    let opml = ApiClient::new(server_name, Some(api_key))
+        .post_text("/api/data/backup_user", &request_body)
+        .await?;
+    Ok(opml)
 }
 
 pub async fn call_backup_server(
@@ -887,27 +508,15 @@ pub async fn call_backup_server(
     database_pass: &str,
     api_key: &str,
 ) -> Result<String, anyhow::Error> {
-    let url = format!("{}/api/data/backup_server", server_name);
     let request_body = serde_json::json!({
         "database_pass": database_pass
     });
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .body(serde_json::to_string(&request_body)?)?
-        .send()
-        .await
-        .map_err(anyhow::Error::msg)?;
-
-    if response.ok() {
-        response.text().await.map_err(anyhow::Error::msg)
-    } else {
-        Err(anyhow::Error::msg(format!(
-            "Error backing up server data: {}",
-            response.status_text()
-        )))
-    }
+    // Dumping the whole database can take a while on larger servers
+    let backup = ApiClient::new(server_name, Some(api_key))
+        .with_timeout(LONG_TIMEOUT_MS)
+        .post_text("/api/data/backup_server", &request_body)
+        .await?;
+    Ok(backup)
 }
 
 #[derive(Serialize, Deserialize)]
@@ -922,30 +531,15 @@ pub async fn call_restore_server(
     server_restore_data: &str,
     api_key: &str,
 ) -> Result<String, Error> {
-    let url = format!("{}/api/data/restore_server", server_name);
     let request_body = RestoreServerRequest {
-        database_pass: database_pass.to_string().clone(),
-        server_restore_data: server_restore_data.to_string().clone(),
+        database_pass: database_pass.to_string(),
+        server_restore_data: server_restore_data.to_string(),
     };
-    log::info!("db: {:?}", database_pass.to_string().clone());
-    log::info!("restore: {:?}", server_restore_data.to_string().clone());
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .body(serde_json::to_string(&request_body)?)?
-        .send()
-        .await
-        .map_err(Error::msg)?;
-
-    if response.ok() {
-        response.text().await.map_err(Error::msg)
-    } else {
-        Err(Error::msg(format!(
-            "Error restoring server data: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(server_name, Some(api_key))
+        .with_timeout(LONG_TIMEOUT_MS)
+        .post_text("/api/data/restore_server", &request_body)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug)]
@@ -960,24 +554,10 @@ pub async fn call_generate_mfa_secret(
     api_key: String,
     user_id: i32,
 ) -> Result<GenerateMFAResponse, anyhow::Error> {
-    let url = format!("{}/api/data/generate_mfa_secret/{}", server_name, user_id);
-    let api_key_ref = &api_key;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .send()
+    let response = ApiClient::new(&server_name, Some(&api_key))
+        .get(&format!("/api/data/generate_mfa_secret/{}", user_id))
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<GenerateMFAResponse>().await?;
-        Ok(response_body)
-    } else {
-        Err(anyhow::Error::msg(format!(
-            "Error generating MFA secret. Server Response: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response)
 }
 
 #[derive(Serialize)]
@@ -997,31 +577,11 @@ pub async fn call_verify_temp_mfa(
     user_id: i32,
     mfa_code: String,
 ) -> Result<VerifyTempMFAResponse, Error> {
-    let url = format!("{}/api/data/verify_temp_mfa", server_name);
     let body = VerifyTempMFABody { user_id, mfa_code };
-    let request_body = serde_json::to_string(&body).map_err(Error::msg)?;
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .body(request_body)?
-        .send()
-        .await
-        .map_err(Error::msg)?;
-
-    if response.ok() {
-        response
-            .json::<VerifyTempMFAResponse>()
-            .await
-            .map_err(Error::msg)
-    } else {
-        let status_text = response.status_text();
-        let error_text = response.text().await.unwrap_or_default();
-        Err(Error::msg(format!(
-            "Error verifying temp MFA: {} - {}",
-            status_text, error_text
-        )))
-    }
+    let response = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/verify_temp_mfa", &body)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -1033,24 +593,10 @@ pub async fn call_mfa_settings(
     api_key: String,
     user_id: i32,
 ) -> Result<bool, anyhow::Error> {
-    let url = format!("{}/api/data/check_mfa_enabled/{}", server_name, user_id);
-    let api_key_ref = api_key.as_str();
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .send()
+    let response: GetMFAResponse = ApiClient::new(&server_name, Some(&api_key))
+        .get(&format!("/api/data/check_mfa_enabled/{}", user_id))
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<GetMFAResponse>().await?;
-        Ok(response_body.mfa_enabled)
-    } else {
-        Err(Error::msg(format!(
-            "Error getting MFA status. Is the server reachable? Server Response: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response.mfa_enabled)
 }
 
 #[derive(Serialize, Debug)]
@@ -1070,30 +616,14 @@ pub async fn call_save_mfa_secret(
     user_id: i32,
     mfa_secret: String,
 ) -> Result<SaveMFASecretResponse, anyhow::Error> {
-    let url = format!("{}/api/data/save_mfa_secret", server_name);
-    let api_key_ref = api_key.as_str();
     let body = SaveMFASecretRequest {
         user_id,
         mfa_secret,
     };
-    let json_body = serde_json::to_string(&body)?;
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(json_body)?
-        .send()
+    let response = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/save_mfa_secret", &body)
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<SaveMFASecretResponse>().await?;
-        Ok(response_body)
-    } else {
-        Err(Error::msg(format!(
-            "Error saving MFA secret. Is the server reachable? Server Response: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response)
 }
 
 #[derive(Serialize)]
@@ -1112,27 +642,141 @@ pub async fn call_disable_mfa(
     api_key: &String,
     user_id: i32,
 ) -> Result<DeleteMFAResponse, anyhow::Error> {
-    let url = format!("{}/api/data/delete_mfa", server_name);
-    let api_key_ref = api_key.as_str();
     let body = DeleteMFARequest { user_id };
-    let json_body = serde_json::to_string(&body)?;
+    let response = ApiClient::new(server_name, Some(api_key))
+        .delete("/api/data/delete_mfa", &body)
+        .await?;
+    Ok(response)
+}
+
+#[derive(Serialize)]
+struct RecoveryCodesRequest {
+    user_id: i32,
+}
+
+#[derive(Deserialize, Debug)]
+struct RecoveryCodesResponse {
+    codes: Vec<String>,
+}
 
-    let response = Request::delete(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(json_body)?
-        .send()
+/// Replace the user's MFA recovery codes with a fresh set. This is the only time the codes
+/// themselves are ever sent back.
+pub async fn call_generate_recovery_codes(
+    server_name: &String,
+    api_key: &String,
+    user_id: i32,
+) -> Result<Vec<String>, Error> {
+    let body = RecoveryCodesRequest { user_id };
+    let response: RecoveryCodesResponse = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/mfa_recovery_codes", &body)
         .await?;
+    Ok(response.codes)
+}
 
-    if response.ok() {
-        let response_body = response.json::<DeleteMFAResponse>().await?;
-        Ok(response_body)
-    } else {
-        Err(Error::msg(format!(
-            "Error disabling MFA. Is the server reachable? Server Response: {}",
-            response.status()
-        )))
-    }
+#[derive(Deserialize, Debug)]
+struct RecoveryCodeCountResponse {
+    remaining: i64,
+}
+
+pub async fn call_get_recovery_code_count(
+    server_name: &String,
+    api_key: &String,
+    user_id: i32,
+) -> Result<i64, Error> {
+    let response: RecoveryCodeCountResponse = ApiClient::new(server_name, Some(api_key))
+        .get(&format!("/api/data/mfa_recovery_codes/{}", user_id))
+        .await?;
+    Ok(response.remaining)
+}
+
+#[derive(Serialize)]
+struct PasskeyUserBody {
+    user_id: i32,
+}
+
+pub async fn call_webauthn_register_options(
+    server_name: &String,
+    api_key: &String,
+    user_id: i32,
+) -> Result<WebAuthnOptions, Error> {
+    let response = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/webauthn/register_options", &PasskeyUserBody { user_id })
+        .await?;
+    Ok(response)
+}
+
+#[derive(Serialize)]
+struct RegisterPasskeyBody {
+    user_id: i32,
+    challenge_id: String,
+    name: String,
+    credential: serde_json::Value,
+}
+
+pub async fn call_webauthn_register(
+    server_name: &String,
+    api_key: &String,
+    user_id: i32,
+    challenge_id: String,
+    name: String,
+    credential: serde_json::Value,
+) -> Result<(), Error> {
+    let body = RegisterPasskeyBody {
+        user_id,
+        challenge_id,
+        name,
+        credential,
+    };
+    let _: DetailResponse = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/webauthn/register", &body)
+        .await?;
+    Ok(())
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct Passkey {
+    pub(crate) credential_id: String,
+    pub(crate) name: String,
+    pub(crate) created: Option<String>,
+    pub(crate) last_used: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct PasskeysResponse {
+    credentials: Vec<Passkey>,
+}
+
+pub async fn call_get_passkeys(
+    server_name: &String,
+    api_key: &String,
+    user_id: i32,
+) -> Result<Vec<Passkey>, Error> {
+    let response: PasskeysResponse = ApiClient::new(server_name, Some(api_key))
+        .get(&format!("/api/data/webauthn/credentials/{}", user_id))
+        .await?;
+    Ok(response.credentials)
+}
+
+#[derive(Serialize)]
+struct DeletePasskeyBody {
+    user_id: i32,
+    credential_id: String,
+}
+
+pub async fn call_delete_passkey(
+    server_name: &String,
+    api_key: &String,
+    user_id: i32,
+    credential_id: String,
+) -> Result<(), Error> {
+    let body = DeletePasskeyBody {
+        user_id,
+        credential_id,
+    };
+    let _: DetailResponse = ApiClient::new(server_name, Some(api_key))
+        .delete("/api/data/webauthn/credentials", &body)
+        .await?;
+    Ok(())
 }
 
 // #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -1166,41 +810,14 @@ pub async fn initiate_nextcloud_login(
     api_key: &str,
     user_id: i32,
 ) -> Result<NextcloudInitiateResponse, Error> {
-    // Construct the URL with query parameters
-    let url = format!("{}/api/data/initiate_nextcloud_login", server_name);
-    web_sys::console::log_1(&url.clone().into());
     let request_body = LoginInitiateRequest {
         user_id,
         nextcloud_url: nextcloud_url.to_string(),
     };
-    let json_body = serde_json::to_string(&request_body)
-        .map_err(|e| Error::msg(format!("Failed to serialize request body: {}", e)))?;
-    web_sys::console::log_1(&api_key.into());
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .body(json_body)?
-        .send()
-        .await
-        .map_err(|e| Error::msg(format!("Network error: {}", e)))?;
-
-    // Get the response body as text
-    let response_body = response.text().await?;
-
-    // Log the response body for debugging
-    web_sys::console::log_1(&response_body.clone().into());
-
-    // Parse the JSON response into the NextcloudLoginResponse struct
-    if response.ok() {
-        serde_json::from_str::<NextcloudInitiateResponse>(&response_body)
-            .map_err(|e| Error::msg(format!("Error parsing JSON: {}", e)))
-    } else {
-        Err(Error::msg(format!(
-            "Error initiating Nextcloud login: {}",
-            response.status_text()
-        )))
-    }
+    let response = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/initiate_nextcloud_login", &request_body)
+        .await?;
+    Ok(response)
 }
 
 #[derive(Serialize)]
@@ -1221,26 +838,10 @@ pub async fn call_add_nextcloud_server(
     api_key: &String,
     auth_request: NextcloudAuthRequest,
 ) -> Result<NextcloudAuthResponse, anyhow::Error> {
-    let url = format!("{}/api/data/add_nextcloud_server", server_name);
-    let api_key_ref = api_key.as_str();
-    let request_body = serde_json::to_string(&auth_request)?;
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key_ref)
-        .body(request_body)?
-        .send()
+    let response = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/add_nextcloud_server", &auth_request)
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<NextcloudAuthResponse>().await?;
-        Ok(response_body)
-    } else {
-        Err(Error::msg(format!(
-            "Error saving Nextcloud Server Info. Is the server reachable? Server Response: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response)
 }
 
 #[derive(Serialize)]
@@ -1260,24 +861,10 @@ pub async fn call_verify_gpodder_auth(
     server_name: &String,
     auth_request: GpodderCheckRequest,
 ) -> Result<GpodderCheckResponse, Error> {
-    let url = format!("{}/api/data/verify_gpodder_auth", server_name);
-    let request_body = serde_json::to_string(&auth_request)?;
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .body(request_body)?
-        .send()
+    let response = ApiClient::new(server_name, None)
+        .post("/api/data/verify_gpodder_auth", &auth_request)
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<GpodderCheckResponse>().await?;
-        Ok(response_body)
-    } else {
-        Err(Error::msg(format!(
-            "Error verifying gPodder auth. Is the server reachable? Server Response: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response)
 }
 
 #[derive(Serialize)]
@@ -1298,26 +885,10 @@ pub async fn call_add_gpodder_server(
     api_key: &String,
     auth_request: GpodderAuthRequest,
 ) -> Result<NextcloudAuthResponse, anyhow::Error> {
-    let url = format!("{}/api/data/add_gpodder_server", server_name);
-    let api_key_ref = api_key.as_str();
-    let request_body = serde_json::to_string(&auth_request)?;
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key_ref)
-        .body(request_body)?
-        .send()
+    let response = ApiClient::new(server_name, Some(api_key))
+        .post("/api/data/add_gpodder_server", &auth_request)
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<NextcloudAuthResponse>().await?;
-        Ok(response_body)
-    } else {
-        Err(Error::msg(format!(
-            "Error saving Nextcloud Server Info. Is the server reachable? Server Response: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug)]
@@ -1331,27 +902,10 @@ pub async fn call_check_nextcloud_server(
     api_key: &String,
     user_id: i32,
 ) -> Result<NextcloudCheckResponse, anyhow::Error> {
-    let url = format!(
-        "{}/api/data/check_gpodder_settings/{}",
-        server_name, user_id
-    );
-    let api_key_ref = api_key.as_str();
-
-    let response = Request::get(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response = ApiClient::new(server_name, Some(api_key))
+        .get(&format!("/api/data/check_gpodder_settings/{}", user_id))
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<NextcloudCheckResponse>().await?;
-        Ok(response_body)
-    } else {
-        Err(Error::msg(format!(
-            "Error pulling Nextcloud Server Info. Is the server reachable? Server Response: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response)
 }
 
 #[derive(Deserialize, Debug, Serialize)]
@@ -1369,40 +923,13 @@ pub async fn call_get_nextcloud_server(
     api_key: &String,
     user_id: i32,
 ) -> Result<String, anyhow::Error> {
-    let url = format!("{}/api/data/get_gpodder_settings/{}", server_name, user_id);
-    let api_key_ref = api_key.as_str();
-
-    let response = Request::get(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response: NextcloudGetResponse = ApiClient::new(server_name, Some(api_key))
+        .get(&format!("/api/data/get_gpodder_settings/{}", user_id))
         .await?;
-
-    let status_text = response.status_text();
-    let response_text = response.text().await.unwrap_or_default();
-
-    if response.ok() {
-        match serde_json::from_str::<NextcloudGetResponse>(&response_text) {
-            Ok(response_body) => {
-                if !response_body.data.gpodderurl.trim().is_empty() {
-                    Ok(response_body.data.gpodderurl.clone())
-                } else {
-                    Ok(String::from("Not currently syncing with Nextcloud server"))
-                }
-            }
-            Err(e) => {
-                web_sys::console::error_1(&JsValue::from_str(&format!(
-                    "Error parsing JSON: {:?}",
-                    e
-                )));
-                Err(anyhow::Error::new(e))
-            }
-        }
+    if !response.data.gpodderurl.trim().is_empty() {
+        Ok(response.data.gpodderurl)
     } else {
-        Err(anyhow::Error::msg(format!(
-            "Failed to get Nextcloud settings: {}",
-            status_text
-        )))
+        Ok(String::from("Not currently syncing with Nextcloud server"))
     }
 }
 
@@ -1416,24 +943,10 @@ pub async fn call_user_admin_check(
     api_key: &String,
     user_id: i32,
 ) -> Result<AdminCheckResponse, anyhow::Error> {
-    let url = format!("{}/api/data/user_admin_check/{}", server_name, user_id);
-    let api_key_ref = api_key.as_str();
-
-    let response = Request::get(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key_ref)
-        .send()
+    let response = ApiClient::new(server_name, Some(api_key))
+        .get(&format!("/api/data/user_admin_check/{}", user_id))
         .await?;
-
-    if response.ok() {
-        let response_body = response.json::<AdminCheckResponse>().await?;
-        Ok(response_body)
-    } else {
-        Err(anyhow::Error::msg(format!(
-            "Error checking admin status. Is the server reachable? Server Response: {}",
-            response.status_text()
-        )))
-    }
+    Ok(response)
 }
 
 #[derive(Serialize, Deserialize)]
@@ -1457,47 +970,17 @@ pub async fn call_add_custom_feed(
     username: Option<String>,
     password: Option<String>,
 ) -> Result<Podcast, Error> {
-    let url = format!("{}/api/data/add_custom_podcast", server_name);
     let request_body = CustomFeedRequest {
         feed_url: feed_url.to_string(),
         user_id: *user_id,
         username,
         password,
     };
-
-    let response = Request::post(&url)
-        .header("Content-Type", "application/json")
-        .header("Api-Key", api_key)
-        .body(serde_json::to_string(&request_body)?)?
-        .send()
-        .await
-        .map_err(Error::msg)?;
-
-    web_sys::console::log_1(&JsValue::from_str(&format!(
-        "Response Status: {}",
-        response.status()
-    )));
-    web_sys::console::log_1(&JsValue::from_str(&format!(
-        "Response Headers: {:?}",
-        response.headers()
-    )));
-
-    if response.ok() {
-        let response_text = response.text().await.map_err(Error::msg)?;
-        web_sys::console::log_1(&JsValue::from_str(&format!(
-            "Response Body: {}",
-            response_text
-        )));
-
-        let add_custom_podcast_response: AddCustomPodcastResponse =
-            serde_json::from_str(&response_text).map_err(Error::msg)?;
-        Ok(add_custom_podcast_response.data)
-    } else {
-        Err(Error::msg(format!(
-            "Error adding feed: {}",
-            response.status_text()
-        )))
-    }
+    let response: AddCustomPodcastResponse = ApiClient::new(server_name, Some(api_key))
+        .with_timeout(LONG_TIMEOUT_MS)
+        .post("/api/data/add_custom_podcast", &request_body)
+        .await?;
+    Ok(response.data)
 }
 
 pub async fn call_podcast_opml_import(
@@ -1506,31 +989,14 @@ pub async fn call_podcast_opml_import(
     user_id: i32,
     podcasts: Vec<String>,
 ) -> Result<(), Error> {
-    let url = format!("{}/api/data/import_opml", server_name);
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| Error::msg("API key is missing"))?;
-
     let request_body = serde_json::json!({
         "podcasts": podcasts,
         "user_id": user_id
     });
-
-    let response = Request::post(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .body(serde_json::to_string(&request_body)?)?
-        .send()
+    let _: serde_json::Value = ApiClient::authed(server_name, api_key)?
+        .post("/api/data/import_opml", &request_body)
         .await?;
-
-    if response.ok() {
-        Ok(())
-    } else {
-        Err(Error::msg(format!(
-            "Error importing OPML: {}",
-            response.status_text()
-        )))
-    }
+    Ok(())
 }
 
 #[derive(Debug, Deserialize)]
@@ -1545,25 +1011,12 @@ pub async fn fetch_import_progress(
     api_key: &Option<String>,
     user_id: i32,
 ) -> Result<(i32, i32, String), Error> {
-    let url = format!("{}/api/data/import_progress/{}", server_name, user_id);
-    let api_key_ref = api_key
-        .as_deref()
-        .ok_or_else(|| Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .send()
+    let progress_response: ImportProgressResponse = ApiClient::authed(server_name, api_key)?
+        .get(&format!("/api/data/import_progress/{}", user_id))
         .await?;
-    web_sys::console::log_1(
-        &format!("import prog: {:?}", &response).into(),
-    );
-    if response.ok() {
-        let progress_response: ImportProgressResponse = response.json().await?;
-        Ok((progress_response.current, progress_response.total, progress_response.current_podcast))
-    } else {
-        Err(Error::msg(format!(
-            "Error fetching import progress: {}",
-            response.status_text()
-        )))
-    }
-}
\ No newline at end of file
+    Ok((
+        progress_response.current,
+        progress_response.total,
+        progress_response.current_podcast,
+    ))
+}
@@ -1,5 +1,4 @@
-use anyhow::Error;
-use gloo_net::http::Request;
+use crate::requests::api_client::ApiClient;
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -11,31 +10,12 @@ pub struct UserStats {
     pub(crate) PodcastsAdded: i32,
     pub(crate) EpisodesSaved: i32,
     pub(crate) EpisodesDownloaded: i32,
+    #[serde(default)]
+    pub(crate) TimeSaved: Option<i32>,
 }
 pub async fn call_get_stats(server_name: String, api_key: Option<String>, user_id: &i32) -> Result<UserStats, anyhow::Error> {
-    let url = format!("{}/api/data/get_stats?user_id={}", server_name, user_id);
-    let api_key_ref = api_key.as_deref().ok_or_else(|| Error::msg("API key is missing"))?;
-
-    let response = Request::get(&url)
-        .header("Api-Key", api_key_ref)
-        .header("Content-Type", "application/json")
-        .send()
+    let stats = ApiClient::authed(&server_name, &api_key)?
+        .get(&format!("/api/data/get_stats?user_id={}", user_id))
         .await?;
-    if !response.ok() {
-        return Err(anyhow::Error::msg(format!("Failed to get stats: {}", response.status_text())));
-    }
-
-    // First, capture the response text for diagnostic purposes
-    let response_text = response.text().await.unwrap_or_else(|_| "Failed to get response text".to_string());
-
-    // Try to deserialize the response text
-    match serde_json::from_str::<UserStats>(&response_text) {
-        Ok(response_body) => {
-            Ok(response_body)
-        }
-        Err(_e) => {
-            Err(anyhow::Error::msg("Failed to deserialize response"))
-        }
-    }
+    Ok(stats)
 }
-
@@ -0,0 +1,217 @@
+// Pinepods service worker: keeps the web client usable without a connection.
+//
+// - The app shell (index.html and the hashed wasm/js/css trunk produces) is served from
+//   cache and refreshed in the background.
+// - The API lists the offline pages need go to the network first; the last good answer is
+//   kept, per API key, and handed back when the server can't be reached. Nothing else from
+//   the API is ever cached, since much of it is account secrets.
+// - Episodes the user saves for offline listening live in their own cache, which the page
+//   fills and empties itself. They're served from there, with Range support so seeking works.
+//
+// The page also clears the API and episode caches itself on logout.
+
+const SHELL_CACHE = "pinepods-shell-v1";
+const API_CACHE = "pinepods-api-v2";
+const EPISODE_CACHE = "pinepods-episodes-v1";
+const KNOWN_CACHES = [SHELL_CACHE, API_CACHE, EPISODE_CACHE];
+
+// Enough for the pages someone's likely to open offline without growing without bound
+const MAX_API_ENTRIES = 300;
+
+const SHELL_URLS = [
+  "/",
+  "/static/styles.css",
+  "/static/js_func.js",
+  "/static/manifest.json",
+  "/static/assets/favicon.png",
+  "/static/assets/icons/material-icons.css",
+];
+
+// The only API responses worth keeping for offline use. Entries ending in "/" take a path
+// parameter after them; the rest have to match exactly.
+const CACHED_API_PATHS = [
+  "/api/data/return_pods/",
+  "/api/data/return_episodes/",
+  "/api/data/podcast_episodes",
+  "/api/data/get_podcast_details",
+  "/api/data/get_queued_episodes",
+  "/api/data/saved_episode_list/",
+  "/api/data/user_history/",
+  "/api/data/download_episode_list",
+  "/api/data/get_playlists",
+  "/api/data/get_playlist_episodes",
+  "/api/data/get_bookmarks",
+  "/api/data/get_theme/",
+];
+
+function isCachedApiPath(pathname) {
+  return CACHED_API_PATHS.some((path) =>
+    path.endsWith("/") ? pathname.startsWith(path) : pathname === path,
+  );
+}
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches
+      .open(SHELL_CACHE)
+      .then((cache) => cache.addAll(SHELL_URLS))
+      .then(() => self.skipWaiting()),
+  );
+});
+
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((names) =>
+        Promise.all(
+          names
+            .filter((name) => name.startsWith("pinepods-") && !KNOWN_CACHES.includes(name))
+            .map((name) => caches.delete(name)),
+        ),
+      )
+      .then(() => self.clients.claim()),
+  );
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  if (request.method !== "GET") {
+    return;
+  }
+  const url = new URL(request.url);
+
+  event.respondWith(
+    savedEpisode(request).then((saved) => {
+      if (saved) {
+        return saved;
+      }
+      if (url.origin !== self.location.origin) {
+        return fetch(request);
+      }
+      if (url.pathname.startsWith("/api/")) {
+        return isCachedApiPath(url.pathname) ? networkFirst(request) : fetch(request);
+      }
+      if (request.mode === "navigate") {
+        return navigation(request);
+      }
+      return staleWhileRevalidate(request);
+    }),
+  );
+});
+
+async function savedEpisode(request) {
+  const cache = await caches.open(EPISODE_CACHE);
+  const cached = await cache.match(request.url, { ignoreVary: true });
+  if (!cached) {
+    return null;
+  }
+  const range = request.headers.get("Range");
+  return range ? rangeResponse(cached, range) : cached;
+}
+
+// Media elements ask for byte ranges, which the Cache API won't answer on its own
+async function rangeResponse(cached, range) {
+  const blob = await cached.blob();
+  const match = /^bytes=(\d*)-(\d*)$/.exec(range.trim());
+  if (!match) {
+    return new Response(null, {
+      status: 416,
+      headers: { "Content-Range": `bytes */${blob.size}` },
+    });
+  }
+  let start = match[1] === "" ? null : Number(match[1]);
+  let end = match[2] === "" ? null : Number(match[2]);
+  if (start === null) {
+    // "bytes=-500" is the last 500 bytes
+    start = Math.max(blob.size - (end || 0), 0);
+    end = blob.size - 1;
+  } else if (end === null || end >= blob.size) {
+    end = blob.size - 1;
+  }
+  if (start >= blob.size || start > end) {
+    return new Response(null, {
+      status: 416,
+      headers: { "Content-Range": `bytes */${blob.size}` },
+    });
+  }
+  const headers = new Headers(cached.headers);
+  headers.set("Content-Range", `bytes ${start}-${end}/${blob.size}`);
+  headers.set("Content-Length", String(end - start + 1));
+  headers.set("Accept-Ranges", "bytes");
+  return new Response(blob.slice(start, end + 1), {
+    status: 206,
+    statusText: "Partial Content",
+    headers,
+  });
+}
+
+// The Cache API keys on the URL alone, so fold a hash of the Api-Key header into it. Otherwise
+// one account's lists could answer for another account signed in on the same browser.
+async function apiCacheKey(request) {
+  const apiKey = request.headers.get("Api-Key") || "";
+  const digest = await crypto.subtle.digest("SHA-256", new TextEncoder().encode(apiKey));
+  const keyHash = Array.from(new Uint8Array(digest), (byte) => byte.toString(16).padStart(2, "0")).join("");
+  const url = new URL(request.url);
+  url.searchParams.set("__pinepods_key", keyHash);
+  return url.toString();
+}
+
+async function networkFirst(request) {
+  const cache = await caches.open(API_CACHE);
+  const cacheKey = await apiCacheKey(request);
+  try {
+    const response = await fetch(request);
+    if (response.ok) {
+      await cache.put(cacheKey, response.clone());
+      trimCache(cache, MAX_API_ENTRIES);
+    }
+    return response;
+  } catch (error) {
+    const cached = await cache.match(cacheKey);
+    if (cached) {
+      return cached;
+    }
+    return new Response(JSON.stringify({ detail: "You're offline and this hasn't been saved yet" }), {
+      status: 503,
+      headers: { "Content-Type": "application/json" },
+    });
+  }
+}
+
+async function navigation(request) {
+  const cache = await caches.open(SHELL_CACHE);
+  try {
+    const response = await fetch(request);
+    if (response.ok) {
+      // Every route serves the same index.html
+      await cache.put("/", response.clone());
+    }
+    return response;
+  } catch (error) {
+    const cached = await cache.match("/");
+    return cached || Response.error();
+  }
+}
+
+async function staleWhileRevalidate(request) {
+  const cache = await caches.open(SHELL_CACHE);
+  const cached = await cache.match(request);
+  const refreshed = fetch(request)
+    .then((response) => {
+      if (response.ok) {
+        cache.put(request, response.clone());
+      }
+      return response;
+    })
+    .catch(() => cached || Response.error());
+  return cached || refreshed;
+}
+
+// Cache keys come back oldest first
+async function trimCache(cache, maxEntries) {
+  const keys = await cache.keys();
+  for (const key of keys.slice(0, Math.max(keys.length - maxEntries, 0))) {
+    await cache.delete(key);
+  }
+}